@@ -0,0 +1,45 @@
+#![feature(test)]
+
+#[cfg(test)]
+mod benches {
+    extern crate serde_json;
+    extern crate serenity;
+    extern crate test;
+
+    use self::serde_json::Value;
+    use self::serenity::model::event::{MessageCreateEvent, PresenceUpdateEvent};
+    use self::test::Bencher;
+
+    const MESSAGE: &str = include_str!("../tests/resources/message_create_1.json");
+    const PRESENCE: &str = include_str!("../tests/resources/presence_update_1.json");
+
+    #[bench]
+    fn message_create_through_value(b: &mut Bencher) {
+        b.iter(|| {
+            let value = serde_json::from_str::<Value>(MESSAGE).unwrap();
+            serde_json::from_value::<MessageCreateEvent>(value).unwrap();
+        })
+    }
+
+    #[bench]
+    fn message_create_direct(b: &mut Bencher) {
+        b.iter(|| {
+            serde_json::from_str::<MessageCreateEvent>(MESSAGE).unwrap();
+        })
+    }
+
+    #[bench]
+    fn presence_update_through_value(b: &mut Bencher) {
+        b.iter(|| {
+            let value = serde_json::from_str::<Value>(PRESENCE).unwrap();
+            serde_json::from_value::<PresenceUpdateEvent>(value).unwrap();
+        })
+    }
+
+    #[bench]
+    fn presence_update_direct(b: &mut Bencher) {
+        b.iter(|| {
+            serde_json::from_str::<PresenceUpdateEvent>(PRESENCE).unwrap();
+        })
+    }
+}
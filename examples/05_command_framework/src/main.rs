@@ -130,8 +130,11 @@ fn main() {
         // reason or another. For example, when a user has exceeded a rate-limit or a command
         // can only be performed by the bot owner.
         .on_dispatch_error(|_ctx, msg, error| {
-            if let DispatchError::RateLimited(seconds) = error {
-                let _ = msg.channel_id.say(&format!("Try this again in {} seconds.", seconds));
+            if let DispatchError::RateLimited(info) = error {
+                // Only reply the first time, to not spam the channel.
+                if info.hits == 1 {
+                    let _ = msg.channel_id.say(&format!("Try this again in {} seconds.", info.remaining));
+                }
             }
         })
         // Can't be used more than once per 5 seconds:
@@ -9,35 +9,14 @@
 
 #[macro_use] extern crate serenity;
 
-extern crate typemap;
-
-// Import the client's bridge to the voice manager. Since voice is a standalone
-// feature, it's not as ergonomic to work with as it could be. The client
-// provides a clean bridged integration with voice.
-use serenity::client::bridge::voice::ClientVoiceManager;
 use serenity::client::{CACHE, Client, Context, EventHandler};
 use serenity::framework::StandardFramework;
 use serenity::model::channel::Message;
 use serenity::model::gateway::Ready;
 use serenity::model::misc::Mentionable;
-// Import the `Context` from the client and `parking_lot`'s `Mutex`.
-//
-// `parking_lot` offers much more efficient implementations of `std::sync`'s
-// types. You can read more about it here:
-//
-// <https://github.com/Amanieu/parking_lot#features>
-use serenity::prelude::Mutex;
 use serenity::voice;
 use serenity::Result as SerenityResult;
 use std::env;
-use std::sync::Arc;
-use typemap::Key;
-
-struct VoiceManager;
-
-impl Key for VoiceManager {
-    type Value = Arc<Mutex<ClientVoiceManager>>;
-}
 
 struct Handler;
 
@@ -53,14 +32,6 @@ fn main() {
         .expect("Expected a token in the environment");
     let mut client = Client::new(&token, Handler).expect("Err creating client");
 
-    // Obtain a lock to the data owned by the client, and insert the client's
-    // voice manager into it. This allows the voice manager to be accessible by
-    // event handlers and framework commands.
-    {
-        let mut data = client.data.lock();
-        data.insert::<VoiceManager>(Arc::clone(&client.voice_manager));
-    }
-
     client.with_framework(StandardFramework::new()
         .configure(|c| c
             .prefix("~")
@@ -87,8 +58,9 @@ command!(deafen(ctx, msg) {
         },
     };
 
-    let mut manager_lock = ctx.data.lock().get::<VoiceManager>().cloned().unwrap();
-    let mut manager = manager_lock.lock();
+    // The client's voice manager is accessible through the context of every
+    // event handler and framework command.
+    let mut manager = ctx.voice_manager.lock();
 
     let handler = match manager.get_mut(guild_id) {
         Some(handler) => handler,
@@ -135,8 +107,7 @@ command!(join(ctx, msg) {
         }
     };
 
-    let mut manager_lock = ctx.data.lock().get::<VoiceManager>().cloned().unwrap();
-    let mut manager = manager_lock.lock();
+    let mut manager = ctx.voice_manager.lock();
 
     if manager.join(guild_id, connect_to).is_some() {
         check_msg(msg.channel_id.say(&format!("Joined {}", connect_to.mention())));
@@ -155,8 +126,7 @@ command!(leave(ctx, msg) {
         },
     };
 
-    let mut manager_lock = ctx.data.lock().get::<VoiceManager>().cloned().unwrap();
-    let mut manager = manager_lock.lock();
+    let mut manager = ctx.voice_manager.lock();
     let has_handler = manager.get(guild_id).is_some();
 
     if has_handler {
@@ -178,8 +148,7 @@ command!(mute(ctx, msg) {
         },
     };
 
-    let mut manager_lock = ctx.data.lock().get::<VoiceManager>().cloned().unwrap();
-    let mut manager = manager_lock.lock();
+    let mut manager = ctx.voice_manager.lock();
 
     let handler = match manager.get_mut(guild_id) {
         Some(handler) => handler,
@@ -228,8 +197,7 @@ command!(play(ctx, msg, args) {
         },
     };
 
-    let mut manager_lock = ctx.data.lock().get::<VoiceManager>().cloned().unwrap();
-    let mut manager = manager_lock.lock();
+    let mut manager = ctx.voice_manager.lock();
 
     if let Some(handler) = manager.get_mut(guild_id) {
         let source = match voice::ytdl(&url) {
@@ -261,8 +229,7 @@ command!(undeafen(ctx, msg) {
         },
     };
 
-    let mut manager_lock = ctx.data.lock().get::<VoiceManager>().cloned().unwrap();
-    let mut manager = manager_lock.lock();
+    let mut manager = ctx.voice_manager.lock();
 
     if let Some(handler) = manager.get_mut(guild_id) {
         handler.deafen(false);
@@ -282,8 +249,7 @@ command!(unmute(ctx, msg) {
             return Ok(());
         },
     };
-    let mut manager_lock = ctx.data.lock().get::<VoiceManager>().cloned().unwrap();
-    let mut manager = manager_lock.lock();
+    let mut manager = ctx.voice_manager.lock();
 
     if let Some(handler) = manager.get_mut(guild_id) {
         handler.mute(false);
@@ -22,7 +22,6 @@ use serenity::model::event::ResumedEvent;
 use serenity::model::gateway::Ready;
 use serenity::prelude::*;
 use serenity::http;
-use std::collections::HashSet;
 use std::env;
 
 struct Handler;
@@ -54,12 +53,7 @@ fn main() {
     let mut client = Client::new(&token, Handler).expect("Err creating client");
 
     let owners = match http::get_current_application_info() {
-        Ok(info) => {
-            let mut set = HashSet::new();
-            set.insert(info.owner.id);
-
-            set
-        },
+        Ok(info) => info.owner_ids(true),
         Err(why) => panic!("Couldn't get application info: {:?}", why),
     };
 
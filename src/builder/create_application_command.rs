@@ -0,0 +1,176 @@
+use internal::prelude::*;
+use model::interaction::ApplicationCommandOptionType;
+use std::fmt::Display;
+use utils::{self, VecMap};
+
+/// A builder to create or edit an [`ApplicationCommand`], through
+/// [`ApplicationId::create_command`] and its siblings.
+///
+/// A command needs a [`name`] and a [`description`].
+///
+/// [`ApplicationCommand`]: ../model/interaction/struct.ApplicationCommand.html
+/// [`ApplicationId::create_command`]: ../model/id/struct.ApplicationId.html#method.create_command
+/// [`description`]: #method.description
+/// [`name`]: #method.name
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommand(pub VecMap<&'static str, Value>);
+
+impl CreateApplicationCommand {
+    /// Set the description of the command.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    #[inline]
+    pub fn description<D: Display>(self, description: D) -> Self {
+        self._description(description.to_string())
+    }
+
+    fn _description(mut self, description: String) -> Self {
+        self.0.insert("description", Value::String(description));
+
+        self
+    }
+
+    /// Set the name of the command.
+    ///
+    /// **Note**: Must be between 1 and 32 characters long, and lowercase.
+    #[inline]
+    pub fn name<D: Display>(self, name: D) -> Self {
+        self._name(name.to_string())
+    }
+
+    fn _name(mut self, name: String) -> Self {
+        self.0.insert("name", Value::String(name));
+
+        self
+    }
+
+    /// Adds an option to the command, which is either an argument or a
+    /// sub-command.
+    ///
+    /// Refer to [`CreateApplicationCommandOption`] for more information.
+    ///
+    /// **Note**: A command can have up to 25 options, and required options have
+    /// to be added before others.
+    ///
+    /// [`CreateApplicationCommandOption`]: struct.CreateApplicationCommandOption.html
+    pub fn option<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateApplicationCommandOption) -> CreateApplicationCommandOption {
+        push_option(&mut self.0, f);
+
+        self
+    }
+}
+
+/// A builder for an option of an [`ApplicationCommand`], to be added through
+/// [`CreateApplicationCommand::option`].
+///
+/// An option needs a [`kind`], a [`name`] and a [`description`].
+///
+/// [`ApplicationCommand`]: ../model/interaction/struct.ApplicationCommand.html
+/// [`CreateApplicationCommand::option`]: struct.CreateApplicationCommand.html#method.option
+/// [`description`]: #method.description
+/// [`kind`]: #method.kind
+/// [`name`]: #method.name
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommandOption(pub VecMap<&'static str, Value>);
+
+impl CreateApplicationCommandOption {
+    /// Adds a value that can be given for an option of the [`Integer`] type.
+    ///
+    /// **Note**: An option can have up to 25 choices.
+    ///
+    /// [`Integer`]: ../model/interaction/enum.ApplicationCommandOptionType.html#variant.Integer
+    pub fn add_int_choice<D: Display>(self, name: D, value: i64) -> Self {
+        self._add_choice(name.to_string(), Value::Number(Number::from(value)))
+    }
+
+    /// Adds a value that can be given for an option of the [`String`] type.
+    ///
+    /// **Note**: An option can have up to 25 choices.
+    ///
+    /// [`String`]: ../model/interaction/enum.ApplicationCommandOptionType.html#variant.String
+    pub fn add_string_choice<D: Display, V: Display>(self, name: D, value: V) -> Self {
+        self._add_choice(name.to_string(), Value::String(value.to_string()))
+    }
+
+    fn _add_choice(mut self, name: String, value: Value) -> Self {
+        let choices = self.0
+            .entry("choices")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut choices) = *choices {
+            choices.push(json!({
+                "name": name,
+                "value": value,
+            }));
+        }
+
+        self
+    }
+
+    /// Set the description of the option.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    #[inline]
+    pub fn description<D: Display>(self, description: D) -> Self {
+        self._description(description.to_string())
+    }
+
+    fn _description(mut self, description: String) -> Self {
+        self.0.insert("description", Value::String(description));
+
+        self
+    }
+
+    /// Set the type of the option.
+    pub fn kind(mut self, kind: ApplicationCommandOptionType) -> Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+
+        self
+    }
+
+    /// Set the name of the option.
+    ///
+    /// **Note**: Must be between 1 and 32 characters long, and lowercase.
+    #[inline]
+    pub fn name<D: Display>(self, name: D) -> Self {
+        self._name(name.to_string())
+    }
+
+    fn _name(mut self, name: String) -> Self {
+        self.0.insert("name", Value::String(name));
+
+        self
+    }
+
+    /// Set whether the option has to be given to invoke the command.
+    ///
+    /// Defaults to `false`.
+    pub fn required(mut self, required: bool) -> Self {
+        self.0.insert("required", Value::Bool(required));
+
+        self
+    }
+
+    /// Adds an option to a sub-command, or a sub-command to a sub-command
+    /// group.
+    pub fn sub_option<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateApplicationCommandOption) -> CreateApplicationCommandOption {
+        push_option(&mut self.0, f);
+
+        self
+    }
+}
+
+fn push_option<F>(map: &mut VecMap<&'static str, Value>, f: F)
+    where F: FnOnce(CreateApplicationCommandOption) -> CreateApplicationCommandOption {
+    let option = utils::vecmap_to_json_map(f(CreateApplicationCommandOption::default()).0);
+
+    let options = map
+        .entry("options")
+        .or_insert_with(|| Value::Array(vec![]));
+
+    if let Value::Array(ref mut options) = *options {
+        options.push(Value::Object(option));
+    }
+}
@@ -0,0 +1,433 @@
+use internal::prelude::*;
+use model::channel::{
+    ButtonStyle,
+    ChannelType,
+    ComponentType,
+    ReactionType,
+    SelectMenuDefaultValue,
+};
+use std::fmt::Display;
+use utils::{self, VecMap};
+
+/// A builder for a row of components of a message, to be added through
+/// [`CreateMessage::action_row`], [`EditMessage::action_row`] or
+/// [`CreateInteractionResponse::action_row`].
+///
+/// A row holds either up to 5 buttons or a single select menu.
+///
+/// # Examples
+///
+/// Sending a message with a button to confirm and a button to cancel:
+///
+/// ```rust,no_run
+/// use serenity::model::channel::ButtonStyle;
+/// use serenity::model::id::ChannelId;
+///
+/// let _ = ChannelId(7).send_message(|m| m
+///     .content("Are you sure?")
+///     .action_row(|r| r
+///         .button(|b| b
+///             .style(ButtonStyle::Success)
+///             .label("Yes")
+///             .custom_id("confirm"))
+///         .button(|b| b
+///             .style(ButtonStyle::Danger)
+///             .label("No")
+///             .custom_id("cancel"))));
+/// ```
+///
+/// [`CreateInteractionResponse::action_row`]: struct.CreateInteractionResponse.html#method.action_row
+/// [`CreateMessage::action_row`]: struct.CreateMessage.html#method.action_row
+/// [`EditMessage::action_row`]: struct.EditMessage.html#method.action_row
+#[derive(Clone, Debug)]
+pub struct CreateActionRow(pub VecMap<&'static str, Value>);
+
+impl CreateActionRow {
+    /// Adds a button to the row.
+    ///
+    /// Refer to [`CreateButton`] for more information.
+    ///
+    /// [`CreateButton`]: struct.CreateButton.html
+    pub fn button<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateButton) -> CreateButton {
+        let button = utils::vecmap_to_json_map(f(CreateButton::default()).0);
+
+        push_component(&mut self.0, "components", button);
+
+        self
+    }
+
+    /// Adds a select menu to the row.
+    ///
+    /// Refer to [`CreateSelectMenu`] for more information.
+    ///
+    /// [`CreateSelectMenu`]: struct.CreateSelectMenu.html
+    pub fn select_menu<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateSelectMenu) -> CreateSelectMenu {
+        let menu = utils::vecmap_to_json_map(f(CreateSelectMenu::default()).0);
+
+        push_component(&mut self.0, "components", menu);
+
+        self
+    }
+}
+
+impl Default for CreateActionRow {
+    /// Creates a map for a row of components, setting its type.
+    fn default() -> CreateActionRow {
+        let mut map = VecMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::ActionRow.num())));
+
+        CreateActionRow(map)
+    }
+}
+
+/// A builder for a button, to be added through [`CreateActionRow::button`].
+///
+/// A button needs a [`style`], and a [`custom_id`] unless it is a [`Link`]
+/// button, which needs a [`url`] instead. It also needs a [`label`] or an
+/// [`emoji`].
+///
+/// [`CreateActionRow::button`]: struct.CreateActionRow.html#method.button
+/// [`Link`]: ../model/channel/enum.ButtonStyle.html#variant.Link
+/// [`custom_id`]: #method.custom_id
+/// [`emoji`]: #method.emoji
+/// [`label`]: #method.label
+/// [`style`]: #method.style
+/// [`url`]: #method.url
+#[derive(Clone, Debug)]
+pub struct CreateButton(pub VecMap<&'static str, Value>);
+
+impl CreateButton {
+    /// Set the Id that the interaction of a click on the button is sent with.
+    ///
+    /// **Note**: Must be at most 100 characters long, and unique among the
+    /// components of the message.
+    #[inline]
+    pub fn custom_id<D: Display>(self, custom_id: D) -> Self {
+        self._custom_id(custom_id.to_string())
+    }
+
+    fn _custom_id(mut self, custom_id: String) -> Self {
+        self.0.insert("custom_id", Value::String(custom_id));
+
+        self
+    }
+
+    /// Set whether the button can not be clicked.
+    ///
+    /// Defaults to `false`.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.0.insert("disabled", Value::Bool(disabled));
+
+        self
+    }
+
+    /// Set the emoji on the button.
+    pub fn emoji<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.0.insert("emoji", json!(emoji.into()));
+
+        self
+    }
+
+    /// Set the text on the button.
+    ///
+    /// **Note**: Must be at most 80 characters long.
+    #[inline]
+    pub fn label<D: Display>(self, label: D) -> Self {
+        self._label(label.to_string())
+    }
+
+    fn _label(mut self, label: String) -> Self {
+        self.0.insert("label", Value::String(label));
+
+        self
+    }
+
+    /// Set the style of the button.
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.0.insert("style", Value::Number(Number::from(style.num())));
+
+        self
+    }
+
+    /// Set the URL opened by a [`Link`] button.
+    ///
+    /// [`Link`]: ../model/channel/enum.ButtonStyle.html#variant.Link
+    #[inline]
+    pub fn url<D: Display>(self, url: D) -> Self {
+        self._url(url.to_string())
+    }
+
+    fn _url(mut self, url: String) -> Self {
+        self.0.insert("url", Value::String(url));
+
+        self
+    }
+}
+
+impl Default for CreateButton {
+    /// Creates a map for a button, setting its type.
+    fn default() -> CreateButton {
+        let mut map = VecMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::Button.num())));
+
+        CreateButton(map)
+    }
+}
+
+/// A builder for a dropdown menu, to be added through
+/// [`CreateActionRow::select_menu`].
+///
+/// A menu needs a [`custom_id`]. A menu of the default [`SelectMenu`] kind
+/// also needs at least one [`option`], while the options of the other
+/// [`kind`]s are filled in by Discord with users, roles or channels.
+///
+/// # Examples
+///
+/// Sending a message with a menu to pick text channels from, with one of them
+/// selected by default:
+///
+/// ```rust,no_run
+/// use serenity::model::channel::{ChannelType, ComponentType, SelectMenuDefaultValue};
+/// use serenity::model::id::ChannelId;
+///
+/// let _ = ChannelId(7).send_message(|m| m
+///     .content("Where should the logs go?")
+///     .action_row(|r| r
+///         .select_menu(|s| s
+///             .kind(ComponentType::ChannelSelect)
+///             .custom_id("log_channel")
+///             .channel_types(vec![ChannelType::Text])
+///             .default_values(vec![SelectMenuDefaultValue::Channel(ChannelId(7))]))));
+/// ```
+///
+/// [`CreateActionRow::select_menu`]: struct.CreateActionRow.html#method.select_menu
+/// [`SelectMenu`]: ../model/channel/enum.ComponentType.html#variant.SelectMenu
+/// [`custom_id`]: #method.custom_id
+/// [`kind`]: #method.kind
+/// [`option`]: #method.option
+#[derive(Clone, Debug)]
+pub struct CreateSelectMenu(pub VecMap<&'static str, Value>);
+
+impl CreateSelectMenu {
+    /// Set the types of the channels that can be selected in a
+    /// [`ChannelSelect`].
+    ///
+    /// Defaults to all types.
+    ///
+    /// [`ChannelSelect`]: ../model/channel/enum.ComponentType.html#variant.ChannelSelect
+    pub fn channel_types<It: IntoIterator<Item=ChannelType>>(mut self, channel_types: It) -> Self {
+        let channel_types = channel_types
+            .into_iter()
+            .map(|kind| Value::Number(Number::from(kind.num())))
+            .collect();
+
+        self.0.insert("channel_types", Value::Array(channel_types));
+
+        self
+    }
+
+    /// Set the Id that the interaction of a selection is sent with.
+    ///
+    /// **Note**: Must be at most 100 characters long, and unique among the
+    /// components of the message.
+    #[inline]
+    pub fn custom_id<D: Display>(self, custom_id: D) -> Self {
+        self._custom_id(custom_id.to_string())
+    }
+
+    fn _custom_id(mut self, custom_id: String) -> Self {
+        self.0.insert("custom_id", Value::String(custom_id));
+
+        self
+    }
+
+    /// Set the users, roles or channels selected by default, if the menu is
+    /// not of the [`SelectMenu`] kind.
+    ///
+    /// **Note**: The number of values must be within the [`min_values`] and
+    /// [`max_values`] of the menu.
+    ///
+    /// [`SelectMenu`]: ../model/channel/enum.ComponentType.html#variant.SelectMenu
+    /// [`max_values`]: #method.max_values
+    /// [`min_values`]: #method.min_values
+    pub fn default_values<It>(mut self, default_values: It) -> Self
+        where It: IntoIterator<Item=SelectMenuDefaultValue> {
+        let default_values = default_values
+            .into_iter()
+            .map(|value| json!(value))
+            .collect();
+
+        self.0.insert("default_values", Value::Array(default_values));
+
+        self
+    }
+
+    /// Set whether the menu can not be used.
+    ///
+    /// Defaults to `false`.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.0.insert("disabled", Value::Bool(disabled));
+
+        self
+    }
+
+    /// Set the kind of the menu, which is one of [`SelectMenu`],
+    /// [`UserSelect`], [`RoleSelect`], [`MentionableSelect`] and
+    /// [`ChannelSelect`].
+    ///
+    /// Defaults to [`SelectMenu`], a menu of the options given by the bot.
+    ///
+    /// [`ChannelSelect`]: ../model/channel/enum.ComponentType.html#variant.ChannelSelect
+    /// [`MentionableSelect`]: ../model/channel/enum.ComponentType.html#variant.MentionableSelect
+    /// [`RoleSelect`]: ../model/channel/enum.ComponentType.html#variant.RoleSelect
+    /// [`SelectMenu`]: ../model/channel/enum.ComponentType.html#variant.SelectMenu
+    /// [`UserSelect`]: ../model/channel/enum.ComponentType.html#variant.UserSelect
+    pub fn kind(mut self, kind: ComponentType) -> Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+
+        self
+    }
+
+    /// Set the maximum number of options that can be selected.
+    ///
+    /// Defaults to `1`, and must be at most 25.
+    pub fn max_values(mut self, max_values: u64) -> Self {
+        self.0.insert("max_values", Value::Number(Number::from(max_values)));
+
+        self
+    }
+
+    /// Set the minimum number of options that have to be selected.
+    ///
+    /// Defaults to `1`, and must be at most 25.
+    pub fn min_values(mut self, min_values: u64) -> Self {
+        self.0.insert("min_values", Value::Number(Number::from(min_values)));
+
+        self
+    }
+
+    /// Adds an option that can be selected, of which there can be up to 25,
+    /// if the menu is of the [`SelectMenu`] kind.
+    ///
+    /// Refer to [`CreateSelectMenuOption`] for more information.
+    ///
+    /// [`CreateSelectMenuOption`]: struct.CreateSelectMenuOption.html
+    /// [`SelectMenu`]: ../model/channel/enum.ComponentType.html#variant.SelectMenu
+    pub fn option<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateSelectMenuOption) -> CreateSelectMenuOption {
+        let option = utils::vecmap_to_json_map(f(CreateSelectMenuOption::default()).0);
+
+        push_component(&mut self.0, "options", option);
+
+        self
+    }
+
+    /// Set the text shown while no option is selected.
+    ///
+    /// **Note**: Must be at most 150 characters long.
+    #[inline]
+    pub fn placeholder<D: Display>(self, placeholder: D) -> Self {
+        self._placeholder(placeholder.to_string())
+    }
+
+    fn _placeholder(mut self, placeholder: String) -> Self {
+        self.0.insert("placeholder", Value::String(placeholder));
+
+        self
+    }
+}
+
+impl Default for CreateSelectMenu {
+    /// Creates a map for a select menu, setting its type.
+    fn default() -> CreateSelectMenu {
+        let mut map = VecMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::SelectMenu.num())));
+
+        CreateSelectMenu(map)
+    }
+}
+
+/// A builder for an option of a select menu, to be added through
+/// [`CreateSelectMenu::option`].
+///
+/// An option needs a [`label`] and a [`value`].
+///
+/// [`CreateSelectMenu::option`]: struct.CreateSelectMenu.html#method.option
+/// [`label`]: #method.label
+/// [`value`]: #method.value
+#[derive(Clone, Debug, Default)]
+pub struct CreateSelectMenuOption(pub VecMap<&'static str, Value>);
+
+impl CreateSelectMenuOption {
+    /// Set whether the option is selected by default.
+    ///
+    /// Defaults to `false`.
+    pub fn default_selection(mut self, default: bool) -> Self {
+        self.0.insert("default", Value::Bool(default));
+
+        self
+    }
+
+    /// Set the description shown below the label.
+    ///
+    /// **Note**: Must be at most 100 characters long.
+    #[inline]
+    pub fn description<D: Display>(self, description: D) -> Self {
+        self._description(description.to_string())
+    }
+
+    fn _description(mut self, description: String) -> Self {
+        self.0.insert("description", Value::String(description));
+
+        self
+    }
+
+    /// Set the emoji shown next to the label.
+    pub fn emoji<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.0.insert("emoji", json!(emoji.into()));
+
+        self
+    }
+
+    /// Set the text of the option.
+    ///
+    /// **Note**: Must be at most 100 characters long.
+    #[inline]
+    pub fn label<D: Display>(self, label: D) -> Self {
+        self._label(label.to_string())
+    }
+
+    fn _label(mut self, label: String) -> Self {
+        self.0.insert("label", Value::String(label));
+
+        self
+    }
+
+    /// Set the value that the interaction of a selection of the option is
+    /// sent with.
+    ///
+    /// **Note**: Must be at most 100 characters long, and unique among the
+    /// options of the menu.
+    #[inline]
+    pub fn value<D: Display>(self, value: D) -> Self {
+        self._value(value.to_string())
+    }
+
+    fn _value(mut self, value: String) -> Self {
+        self.0.insert("value", Value::String(value));
+
+        self
+    }
+}
+
+fn push_component(map: &mut VecMap<&'static str, Value>, key: &'static str, component: JsonMap) {
+    let components = map
+        .entry(key)
+        .or_insert_with(|| Value::Array(vec![]));
+
+    if let Value::Array(ref mut components) = *components {
+        components.push(Value::Object(component));
+    }
+}
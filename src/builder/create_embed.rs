@@ -15,13 +15,17 @@
 //! [`ExecuteWebhook::embeds`]: struct.ExecuteWebhook.html#method.embeds
 //! [here]: https://discordapp.com/developers/docs/resources/channel#embed-object
 
+#[cfg(feature = "chrono")]
 use chrono::{DateTime, TimeZone};
+use constants;
 use internal::prelude::*;
 use model::channel::Embed;
+use model::Timestamp as ModelTimestamp;
 use serde_json::Value;
 use std::{
     default::Default,
-    fmt::Display
+    error::Error as StdError,
+    fmt::{self, Display}
 };
 use utils::{self, VecMap};
 
@@ -220,7 +224,9 @@ impl CreateEmbed {
     ///
     /// This timestamp must be in ISO-8601 format. It must also be in UTC format.
     ///
-    /// You can also pass anything that implements `chrono::TimeZone`.
+    /// You can also pass a [`model::Timestamp`], such as the one a member
+    /// joined at, or, with the `chrono` feature enabled, a `chrono::DateTime`
+    /// of any time zone.
     ///
     /// # Examples
     ///
@@ -296,6 +302,8 @@ impl CreateEmbed {
     ///
     /// client.start().unwrap();
     /// ```
+    ///
+    /// [`model::Timestamp`]: ../model/timestamp/struct.Timestamp.html
     #[inline]
     pub fn timestamp<T: Into<Timestamp>>(self, timestamp: T) -> Self {
         self._timestamp(timestamp.into())
@@ -347,6 +355,61 @@ impl CreateEmbed {
     fn _attachment(self, filename: &str) -> Self {
         self.image(&format!("attachment://{}", filename))
     }
+
+    /// Checks that the embed stays within Discord's limits, both for each of
+    /// its texts and for their total length.
+    ///
+    /// Sending an embed only checks the total length, so this can be used to
+    /// find out which part of an embed is too long before Discord rejects it.
+    /// [`ChannelId::send_message_validated`] does so before sending.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::{CreateEmbed, EmbedValidationError};
+    ///
+    /// let embed = CreateEmbed::default().title("a".repeat(300));
+    ///
+    /// assert_eq!(embed.validate(), Err(EmbedValidationError::Title(44)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`EmbedValidationError`] found, containing by how
+    /// much the limit was exceeded.
+    ///
+    /// [`ChannelId::send_message_validated`]: ../model/id/struct.ChannelId.html#method.send_message_validated
+    /// [`EmbedValidationError`]: enum.EmbedValidationError.html
+    pub fn validate(&self) -> StdResult<(), EmbedValidationError> {
+        validate_embed(|key| self.0.get(&key))
+    }
+
+    /// Creates a builder with the values of an existing embed, such as one of
+    /// a received message, so that it can be altered and sent again.
+    ///
+    /// Refer to the [`From<Embed>`] implementation for which values are kept.
+    ///
+    /// # Examples
+    ///
+    /// Changing the title of the first embed of a message, keeping the rest:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// # fn retitle(mut msg: Message) -> serenity::Result<()> {
+    /// use serenity::builder::CreateEmbed;
+    ///
+    /// if let Some(embed) = msg.embeds.first().cloned() {
+    ///     msg.edit(|m| m.embed(|_| CreateEmbed::from_existing(&embed).title("Updated")))?;
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`From<Embed>`]: #impl-From%3CEmbed%3E
+    pub fn from_existing(embed: &Embed) -> Self {
+        CreateEmbed::from(embed.clone())
+    }
 }
 
 impl Default for CreateEmbed {
@@ -359,6 +422,14 @@ impl Default for CreateEmbed {
     }
 }
 
+impl<'a> From<&'a Embed> for CreateEmbed {
+    /// Converts the fields of an embed into the values for a new embed builder,
+    /// refer to the [`From<Embed>`] implementation.
+    ///
+    /// [`From<Embed>`]: #impl-From%3CEmbed%3E
+    fn from(embed: &'a Embed) -> CreateEmbed { CreateEmbed::from(embed.clone()) }
+}
+
 impl From<Embed> for CreateEmbed {
     /// Converts the fields of an embed into the values for a new embed builder.
     ///
@@ -507,6 +578,7 @@ impl<'a> From<&'a str> for Timestamp {
     }
 }
 
+#[cfg(feature = "chrono")]
 impl<'a, Tz: TimeZone> From<&'a DateTime<Tz>> for Timestamp
     where Tz::Offset: Display {
     fn from(dt: &'a DateTime<Tz>) -> Self {
@@ -516,12 +588,189 @@ impl<'a, Tz: TimeZone> From<&'a DateTime<Tz>> for Timestamp
     }
 }
 
+impl From<ModelTimestamp> for Timestamp {
+    fn from(timestamp: ModelTimestamp) -> Self {
+        Timestamp {
+            ts: timestamp.to_rfc3339(),
+        }
+    }
+}
+
+impl<'a> From<&'a ModelTimestamp> for Timestamp {
+    fn from(timestamp: &'a ModelTimestamp) -> Self {
+        Timestamp {
+            ts: timestamp.to_rfc3339(),
+        }
+    }
+}
+
+/// A part of an embed exceeding one of Discord's limits, as found by
+/// [`CreateEmbed::validate`].
+///
+/// Unless noted otherwise, the variants contain by how many characters the
+/// limit is exceeded.
+///
+/// [`CreateEmbed::validate`]: struct.CreateEmbed.html#method.validate
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EmbedValidationError {
+    /// The author's name is longer than 256 characters.
+    AuthorName(usize),
+    /// The description is longer than 4096 characters.
+    Description(usize),
+    /// The embed has more than 25 fields, containing the amount of fields
+    /// too many.
+    FieldCount(usize),
+    /// The name of the field at `index` is longer than 256 characters.
+    FieldName {
+        index: usize,
+        overflow: usize,
+    },
+    /// The value of the field at `index` is longer than 1024 characters.
+    FieldValue {
+        index: usize,
+        overflow: usize,
+    },
+    /// The footer's text is longer than 2048 characters.
+    FooterText(usize),
+    /// The title is longer than 256 characters.
+    Title(usize),
+    /// All texts combined are longer than 6000 characters.
+    Total(usize),
+}
+
+impl fmt::Display for EmbedValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::EmbedValidationError::*;
+
+        match *self {
+            FieldCount(overflow) => write!(f, "{} by {} fields", self.description(), overflow),
+            FieldName { index, overflow } | FieldValue { index, overflow } => {
+                write!(f, "{} at index {} by {} characters", self.description(), index, overflow)
+            },
+            AuthorName(overflow) | Description(overflow) | FooterText(overflow)
+                | Title(overflow) | Total(overflow) => {
+                write!(f, "{} by {} characters", self.description(), overflow)
+            },
+        }
+    }
+}
+
+impl StdError for EmbedValidationError {
+    fn description(&self) -> &str {
+        use self::EmbedValidationError::*;
+
+        match *self {
+            AuthorName(_) => "Embed author name too long",
+            Description(_) => "Embed description too long",
+            FieldCount(_) => "Embed has too many fields",
+            FieldName { .. } => "Embed field name too long",
+            FieldValue { .. } => "Embed field value too long",
+            FooterText(_) => "Embed footer text too long",
+            Title(_) => "Embed title too long",
+            Total(_) => "Embed too large",
+        }
+    }
+}
+
+fn text_length(value: Option<&Value>) -> usize {
+    match value {
+        Some(&Value::String(ref text)) => text.chars().count(),
+        _ => 0,
+    }
+}
+
+fn excess(length: usize, limit: u16) -> Option<usize> {
+    let limit = limit as usize;
+
+    if length > limit {
+        Some(length - limit)
+    } else {
+        None
+    }
+}
+
+/// Calculates the total length of an embed's texts, retrieving its values by
+/// key through `get`.
+pub(crate) fn embed_length<'a, F>(get: F) -> usize
+    where F: Fn(&str) -> Option<&'a Value> {
+    let mut total = text_length(get("title")) + text_length(get("description"));
+
+    total += text_length(get("author").and_then(|author| author.get("name")));
+    total += text_length(get("footer").and_then(|footer| footer.get("text")));
+
+    if let Some(&Value::Array(ref fields)) = get("fields") {
+        for field in fields {
+            total += text_length(field.get("name")) + text_length(field.get("value"));
+        }
+    }
+
+    total
+}
+
+/// Checks an embed's texts against their limits, retrieving its values by key
+/// through `get`.
+pub(crate) fn validate_embed<'a, F>(get: F) -> StdResult<(), EmbedValidationError>
+    where F: Fn(&str) -> Option<&'a Value> {
+    use self::EmbedValidationError::*;
+
+    if let Some(overflow) = excess(text_length(get("title")), constants::EMBED_TITLE_LIMIT) {
+        return Err(Title(overflow));
+    }
+
+    if let Some(overflow) = excess(text_length(get("description")), constants::EMBED_DESCRIPTION_LIMIT) {
+        return Err(Description(overflow));
+    }
+
+    let author_name = text_length(get("author").and_then(|author| author.get("name")));
+
+    if let Some(overflow) = excess(author_name, constants::EMBED_AUTHOR_NAME_LIMIT) {
+        return Err(AuthorName(overflow));
+    }
+
+    let footer_text = text_length(get("footer").and_then(|footer| footer.get("text")));
+
+    if let Some(overflow) = excess(footer_text, constants::EMBED_FOOTER_TEXT_LIMIT) {
+        return Err(FooterText(overflow));
+    }
+
+    if let Some(&Value::Array(ref fields)) = get("fields") {
+        if let Some(overflow) = excess(fields.len(), constants::EMBED_FIELD_LIMIT) {
+            return Err(FieldCount(overflow));
+        }
+
+        for (index, field) in fields.iter().enumerate() {
+            let name = text_length(field.get("name"));
+
+            if let Some(overflow) = excess(name, constants::EMBED_FIELD_NAME_LIMIT) {
+                return Err(FieldName { index, overflow });
+            }
+
+            let value = text_length(field.get("value"));
+
+            if let Some(overflow) = excess(value, constants::EMBED_FIELD_VALUE_LIMIT) {
+                return Err(FieldValue { index, overflow });
+            }
+        }
+    }
+
+    match excess(embed_length(get), constants::EMBED_MAX_LENGTH) {
+        Some(overflow) => Err(Total(overflow)),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use model::channel::{Embed, EmbedField, EmbedFooter, EmbedImage, EmbedVideo};
     use serde_json::Value;
-    use super::CreateEmbed;
+    use super::{CreateEmbed, EmbedValidationError};
     use utils::{self, Colour};
+    #[cfg(feature = "model")]
+    use builder::CreateMessage;
+    #[cfg(feature = "model")]
+    use model::{channel::Message, ModelError};
+    #[cfg(feature = "model")]
+    use Error;
 
     #[test]
     fn test_from_embed() {
@@ -603,4 +852,100 @@ mod test {
 
         assert_eq!(built, obj);
     }
+
+    #[test]
+    fn test_from_embed_round_trip() {
+        let embed: Embed = ::serde_json::from_str(include_str!("../../tests/resources/embed_1.json"))
+            .unwrap();
+
+        let built = Value::Object(utils::vecmap_to_json_map(CreateEmbed::from_existing(&embed).0));
+
+        // Proxied URLs and image sizes are set by Discord, so they are left
+        // out.
+        let obj = json!({
+            "author": {
+                "icon_url": "https://cdn.discordapp.com/avatars/1/a.png",
+                "name": "hakase",
+                "url": "https://github.com/serenity-rs/serenity",
+            },
+            "color": 0xFF0011,
+            "description": "An embed of a received message.",
+            "fields": [
+                {
+                    "inline": false,
+                    "name": "a",
+                    "value": "b",
+                },
+                {
+                    "inline": true,
+                    "name": "c",
+                    "value": "d",
+                },
+            ],
+            "footer": {
+                "icon_url": "https://i.imgur.com/XfWpfCV.gif",
+                "text": "A footer",
+            },
+            "image": {
+                "url": "https://i.imgur.com/image.png",
+            },
+            "thumbnail": {
+                "url": "https://i.imgur.com/thumbnail.png",
+            },
+            "timestamp": "2021-01-01T12:30:00+00:00",
+            "title": "Round trip",
+            "type": "rich",
+            "url": "https://github.com/serenity-rs/serenity",
+        });
+
+        assert_eq!(built, obj);
+
+        let owned = Value::Object(utils::vecmap_to_json_map(CreateEmbed::from(embed).0));
+        assert_eq!(owned, obj);
+    }
+
+    #[test]
+    fn test_validate() {
+        let embed = CreateEmbed::default()
+            .title("a")
+            .field("a", "b".repeat(1025), false);
+
+        assert_eq!(embed.validate(), Err(EmbedValidationError::FieldValue {
+            index: 0,
+            overflow: 1,
+        }));
+
+        let embed = CreateEmbed::default()
+            .description("a".repeat(4000))
+            .footer(|f| f.text("b".repeat(2001)));
+
+        assert_eq!(embed.validate(), Err(EmbedValidationError::Total(1)));
+
+        let embed = CreateEmbed::default()
+            .title("a")
+            .author(|a| a.name("b"));
+
+        assert_eq!(embed.validate(), Ok(()));
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn validated_message_reports_field() {
+        let message = CreateMessage::default()
+            .embed(|e| e.field("a".repeat(300), "value", false));
+        let map = utils::vecmap_to_json_map(message.0);
+
+        // Only the total length is checked by default.
+        assert!(Message::check_embed_length(&map).is_ok());
+
+        match Message::check_embed_limits(&map) {
+            Err(Error::Model(ModelError::InvalidEmbed(why))) => {
+                assert_eq!(why, EmbedValidationError::FieldName { index: 0, overflow: 44 });
+            },
+            other => panic!("Expected an invalid embed, got: {:?}", other),
+        }
+
+        let map = utils::vecmap_to_json_map(CreateMessage::default().content("text").0);
+        assert!(Message::check_embed_limits(&map).is_ok());
+    }
 }
@@ -0,0 +1,111 @@
+use internal::prelude::*;
+use std::fmt::Display;
+use super::{CreateActionRow, CreateEmbed};
+use utils::{self, VecMap};
+
+/// A builder for the message responding to an [`Interaction`], sent through
+/// [`Interaction::create_response`], and for its follow-up messages, sent
+/// through [`Interaction::create_followup_message`].
+///
+/// A message needs [`content`] or at least one [`embed`]. Leave the builder
+/// empty when acknowledging a ping or deferring the response.
+///
+/// # Examples
+///
+/// Defer the response to a command taking a while, and send a follow-up only
+/// the invoking user can see once it is done:
+///
+/// ```rust,ignore
+/// use serenity::model::interaction::InteractionResponseType;
+///
+/// interaction.create_response(InteractionResponseType::DeferredChannelMessageWithSource, |r| r)?;
+///
+/// // ...
+///
+/// interaction.edit_original_response(|r| r.content("Done!"))?;
+/// interaction.create_followup_message(|r| r
+///     .content("Only you can see this.")
+///     .ephemeral(true))?;
+/// ```
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+/// [`Interaction::create_followup_message`]: ../model/interaction/struct.Interaction.html#method.create_followup_message
+/// [`Interaction::create_response`]: ../model/interaction/struct.Interaction.html#method.create_response
+/// [`content`]: #method.content
+/// [`embed`]: #method.embed
+#[derive(Clone, Debug, Default)]
+pub struct CreateInteractionResponse(pub VecMap<&'static str, Value>);
+
+impl CreateInteractionResponse {
+    /// Adds a row of components to the message, such as buttons, of which
+    /// there can be up to 5.
+    ///
+    /// Refer to [`CreateActionRow`] for more information.
+    ///
+    /// [`CreateActionRow`]: struct.CreateActionRow.html
+    pub fn action_row<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateActionRow) -> CreateActionRow {
+        let row = utils::vecmap_to_json_map(f(CreateActionRow::default()).0);
+
+        let components = self.0
+            .entry("components")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut components) = *components {
+            components.push(Value::Object(row));
+        }
+
+        self
+    }
+
+    /// Set the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    #[inline]
+    pub fn content<D: Display>(self, content: D) -> Self {
+        self._content(content.to_string())
+    }
+
+    fn _content(mut self, content: String) -> Self {
+        self.0.insert("content", Value::String(content));
+
+        self
+    }
+
+    /// Adds an embed to the message, of which there can be up to 10.
+    pub fn embed<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateEmbed) -> CreateEmbed {
+        let embed = utils::vecmap_to_json_map(f(CreateEmbed::default()).0);
+
+        let embeds = self.0
+            .entry("embeds")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut embeds) = *embeds {
+            embeds.push(Value::Object(embed));
+        }
+
+        self
+    }
+
+    /// Set whether the message is only shown to the user that invoked the
+    /// interaction.
+    ///
+    /// Defaults to `false`.
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        let flags: u64 = if ephemeral { 1 << 6 } else { 0 };
+
+        self.0.insert("flags", Value::Number(Number::from(flags)));
+
+        self
+    }
+
+    /// Set whether the message is text-to-speech.
+    ///
+    /// Defaults to `false`.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.0.insert("tts", Value::Bool(tts));
+
+        self
+    }
+}
@@ -1,7 +1,10 @@
 use internal::prelude::*;
 use model::channel::ReactionType;
+use model::id::StickerId;
 use std::fmt::Display;
-use super::CreateEmbed;
+#[cfg(feature = "scheduler")]
+use std::time::Duration;
+use super::{CreateActionRow, CreateEmbed, CreatePoll};
 use utils::{self, VecMap};
 
 /// A builder to specify the contents of an [`http::send_message`] request,
@@ -9,7 +12,8 @@ use utils::{self, VecMap};
 ///
 /// There are two situations where different field requirements are present:
 ///
-/// 1. When sending an [`embed`], no other field is required;
+/// 1. When sending an [`embed`], a [`poll`] or [`sticker_ids`], no other field
+/// is required;
 /// 2. Otherwise, [`content`] is the only required field that is required to be
 /// set.
 ///
@@ -39,10 +43,64 @@ use utils::{self, VecMap};
 /// [`content`]: #method.content
 /// [`embed`]: #method.embed
 /// [`http::send_message`]: ../http/fn.send_message.html
+/// [`poll`]: #method.poll
+/// [`sticker_ids`]: #method.sticker_ids
 #[derive(Clone, Debug)]
-pub struct CreateMessage(pub VecMap<&'static str, Value>, pub Option<Vec<ReactionType>>);
+pub struct CreateMessage(
+    pub VecMap<&'static str, Value>,
+    pub Option<Vec<ReactionType>>,
+    /// The delay after which the sent message is deleted.
+    #[cfg(feature = "scheduler")]
+    pub(crate) Option<Duration>,
+);
 
 impl CreateMessage {
+    /// Adds a row of components to the message, such as buttons, of which
+    /// there can be up to 5.
+    ///
+    /// Refer to [`CreateActionRow`] for more information.
+    ///
+    /// [`CreateActionRow`]: struct.CreateActionRow.html
+    pub fn action_row<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateActionRow) -> CreateActionRow {
+        let row = utils::vecmap_to_json_map(f(CreateActionRow::default()).0);
+
+        let components = self.0
+            .entry("components")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut components) = *components {
+            components.push(Value::Object(row));
+        }
+
+        self
+    }
+
+    /// Set the description of the file at the given index, which is the alt
+    /// text shown by screen readers.
+    ///
+    /// The index refers to the order the files are given in, starting from
+    /// `0`. Descriptions only apply when sending files, such as through
+    /// [`ChannelId::send_files`].
+    ///
+    /// **Note**: Descriptions must be at most 1024 unicode code points.
+    ///
+    /// [`ChannelId::send_files`]: ../model/id/struct.ChannelId.html#method.send_files
+    pub fn attachment_description<D: Display>(mut self, index: u64, description: D) -> Self {
+        let attachments = self.0
+            .entry("attachments")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut attachments) = *attachments {
+            attachments.push(json!({
+                "id": index,
+                "description": description.to_string(),
+            }));
+        }
+
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -68,6 +126,20 @@ impl CreateMessage {
         self
     }
 
+    /// Attaches a poll to the message.
+    ///
+    /// Refer to [`CreatePoll`] for more information.
+    ///
+    /// [`CreatePoll`]: struct.CreatePoll.html
+    pub fn poll<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreatePoll) -> CreatePoll {
+        let map = utils::vecmap_to_json_map(f(CreatePoll::default()).0);
+
+        self.0.insert("poll", Value::Object(map));
+
+        self
+    }
+
     /// Set whether the message is text-to-speech.
     ///
     /// Think carefully before setting this to `true`.
@@ -90,6 +162,35 @@ impl CreateMessage {
 
         self
     }
+
+    /// Sets the stickers to send with the message, of which there can be up
+    /// to 3.
+    ///
+    /// **Note**: Stickers of a guild can only be sent in that guild.
+    pub fn sticker_ids<S: Into<StickerId>, It: IntoIterator<Item=S>>(mut self, sticker_ids: It) -> Self {
+        let ids = sticker_ids
+            .into_iter()
+            .map(|id| Value::Number(Number::from(id.into().0)))
+            .collect();
+
+        self.0.insert("sticker_ids", Value::Array(ids));
+
+        self
+    }
+
+    /// Deletes the message once the delay elapsed after it was sent.
+    ///
+    /// Refer to [`Message::delete_after`] for more information.
+    ///
+    /// **Note**: Requires the `scheduler` feature.
+    ///
+    /// [`Message::delete_after`]: ../model/channel/struct.Message.html#method.delete_after
+    #[cfg(feature = "scheduler")]
+    pub fn delete_after(mut self, delay: Duration) -> Self {
+        self.2 = Some(delay);
+
+        self
+    }
 }
 
 impl Default for CreateMessage {
@@ -102,6 +203,37 @@ impl Default for CreateMessage {
         let mut map = VecMap::new();
         map.insert("tts", Value::Bool(false));
 
-        CreateMessage(map, None)
+        #[cfg(not(feature = "scheduler"))]
+        let message = CreateMessage(map, None);
+        #[cfg(feature = "scheduler")]
+        let message = CreateMessage(map, None, None);
+
+        message
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::id::StickerId;
+    use super::CreateMessage;
+
+    #[test]
+    fn attachment_descriptions() {
+        let message = CreateMessage::default()
+            .attachment_description(0, "A cat")
+            .attachment_description(2, "A dog");
+
+        assert_eq!(message.0.get(&"attachments"), Some(&json!([
+            {"id": 0, "description": "A cat"},
+            {"id": 2, "description": "A dog"},
+        ])));
+    }
+
+    #[test]
+    fn sticker_ids() {
+        let ids = vec![StickerId(749054660769218631), StickerId(1)];
+        let message = CreateMessage::default().sticker_ids(ids);
+
+        assert_eq!(message.0.get(&"sticker_ids"), Some(&json!([749054660769218631u64, 1])));
     }
 }
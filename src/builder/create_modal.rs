@@ -0,0 +1,256 @@
+use internal::prelude::*;
+use model::channel::{ComponentType, InputTextStyle};
+use std::fmt::Display;
+use utils::{self, VecMap};
+
+/// A builder for a modal, which is a form for the user to fill in, to be sent
+/// through [`Interaction::create_modal`].
+///
+/// A modal needs a [`custom_id`], a [`title`] and at least one
+/// [`input_text`], of which there can be up to 5.
+///
+/// # Examples
+///
+/// Asking for feedback, and reading it once the modal is submitted:
+///
+/// ```rust,ignore
+/// use serenity::model::channel::InputTextStyle;
+/// use serenity::model::interaction::InteractionData;
+///
+/// interaction.create_modal(|m| m
+///     .custom_id("feedback")
+///     .title("Feedback")
+///     .input_text(|i| i
+///         .custom_id("title")
+///         .label("Title")
+///         .style(InputTextStyle::Short))
+///     .input_text(|i| i
+///         .custom_id("body")
+///         .label("What do you think?")
+///         .style(InputTextStyle::Paragraph)
+///         .max_length(1000)))?;
+///
+/// // Later, in the interaction of the submission:
+/// if let Some(InteractionData::ModalSubmit(ref data)) = submission.data {
+///     println!("{}: {}", data.value("title").unwrap_or_default(), data.value("body").unwrap_or_default());
+/// }
+/// ```
+///
+/// [`Interaction::create_modal`]: ../model/interaction/struct.Interaction.html#method.create_modal
+/// [`custom_id`]: #method.custom_id
+/// [`input_text`]: #method.input_text
+/// [`title`]: #method.title
+#[derive(Clone, Debug, Default)]
+pub struct CreateModal(pub VecMap<&'static str, Value>);
+
+impl CreateModal {
+    /// Set the Id that the interaction of the submission of the modal is sent
+    /// with.
+    ///
+    /// **Note**: Must be at most 100 characters long.
+    #[inline]
+    pub fn custom_id<D: Display>(self, custom_id: D) -> Self {
+        self._custom_id(custom_id.to_string())
+    }
+
+    fn _custom_id(mut self, custom_id: String) -> Self {
+        self.0.insert("custom_id", Value::String(custom_id));
+
+        self
+    }
+
+    /// Adds a field to enter text into, on a row of its own.
+    ///
+    /// Refer to [`CreateInputText`] for more information.
+    ///
+    /// [`CreateInputText`]: struct.CreateInputText.html
+    pub fn input_text<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateInputText) -> CreateInputText {
+        let input = utils::vecmap_to_json_map(f(CreateInputText::default()).0);
+
+        let row = json!({
+            "type": ComponentType::ActionRow.num(),
+            "components": [input],
+        });
+
+        let rows = self.0
+            .entry("components")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut rows) = *rows {
+            rows.push(row);
+        }
+
+        self
+    }
+
+    /// Set the title of the modal.
+    ///
+    /// **Note**: Must be at most 45 characters long.
+    #[inline]
+    pub fn title<D: Display>(self, title: D) -> Self {
+        self._title(title.to_string())
+    }
+
+    fn _title(mut self, title: String) -> Self {
+        self.0.insert("title", Value::String(title));
+
+        self
+    }
+}
+
+/// A builder for a field to enter text into, to be added through
+/// [`CreateModal::input_text`].
+///
+/// A field needs a [`custom_id`], a [`label`] and a [`style`].
+///
+/// [`CreateModal::input_text`]: struct.CreateModal.html#method.input_text
+/// [`custom_id`]: #method.custom_id
+/// [`label`]: #method.label
+/// [`style`]: #method.style
+#[derive(Clone, Debug)]
+pub struct CreateInputText(pub VecMap<&'static str, Value>);
+
+impl CreateInputText {
+    /// Set the Id that the entered text is sent with when the modal is
+    /// submitted.
+    ///
+    /// **Note**: Must be at most 100 characters long, and unique among the
+    /// fields of the modal.
+    #[inline]
+    pub fn custom_id<D: Display>(self, custom_id: D) -> Self {
+        self._custom_id(custom_id.to_string())
+    }
+
+    fn _custom_id(mut self, custom_id: String) -> Self {
+        self.0.insert("custom_id", Value::String(custom_id));
+
+        self
+    }
+
+    /// Set the text above the field.
+    ///
+    /// **Note**: Must be at most 45 characters long.
+    #[inline]
+    pub fn label<D: Display>(self, label: D) -> Self {
+        self._label(label.to_string())
+    }
+
+    fn _label(mut self, label: String) -> Self {
+        self.0.insert("label", Value::String(label));
+
+        self
+    }
+
+    /// Set the maximum number of characters that can be entered.
+    ///
+    /// **Note**: Must be between 1 and 4000.
+    pub fn max_length(mut self, max_length: u64) -> Self {
+        self.0.insert("max_length", Value::Number(Number::from(max_length)));
+
+        self
+    }
+
+    /// Set the minimum number of characters that have to be entered.
+    ///
+    /// **Note**: Must be at most 4000.
+    pub fn min_length(mut self, min_length: u64) -> Self {
+        self.0.insert("min_length", Value::Number(Number::from(min_length)));
+
+        self
+    }
+
+    /// Set the text shown while the field is empty.
+    ///
+    /// **Note**: Must be at most 100 characters long.
+    #[inline]
+    pub fn placeholder<D: Display>(self, placeholder: D) -> Self {
+        self._placeholder(placeholder.to_string())
+    }
+
+    fn _placeholder(mut self, placeholder: String) -> Self {
+        self.0.insert("placeholder", Value::String(placeholder));
+
+        self
+    }
+
+    /// Set whether text has to be entered to submit the modal.
+    ///
+    /// Defaults to `true`.
+    pub fn required(mut self, required: bool) -> Self {
+        self.0.insert("required", Value::Bool(required));
+
+        self
+    }
+
+    /// Set whether the field is a single line or a paragraph.
+    pub fn style(mut self, style: InputTextStyle) -> Self {
+        self.0.insert("style", Value::Number(Number::from(style.num())));
+
+        self
+    }
+
+    /// Set the text that is filled in beforehand.
+    ///
+    /// **Note**: Must be at most 4000 characters long.
+    #[inline]
+    pub fn value<D: Display>(self, value: D) -> Self {
+        self._value(value.to_string())
+    }
+
+    fn _value(mut self, value: String) -> Self {
+        self.0.insert("value", Value::String(value));
+
+        self
+    }
+}
+
+impl Default for CreateInputText {
+    /// Creates a map for a field to enter text into, setting its type.
+    fn default() -> CreateInputText {
+        let mut map = VecMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::InputText.num())));
+
+        CreateInputText(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::channel::InputTextStyle;
+    use super::CreateModal;
+
+    #[test]
+    fn input_texts_are_put_on_rows() {
+        let modal = CreateModal::default()
+            .custom_id("feedback")
+            .title("Feedback")
+            .input_text(|i| i.custom_id("title").label("Title").style(InputTextStyle::Short))
+            .input_text(|i| i
+                .custom_id("body")
+                .label("What do you think?")
+                .style(InputTextStyle::Paragraph)
+                .max_length(1000)
+                .required(false));
+
+        assert_eq!(modal.0.get(&"custom_id"), Some(&json!("feedback")));
+        assert_eq!(modal.0.get(&"title"), Some(&json!("Feedback")));
+        assert_eq!(modal.0.get(&"components"), Some(&json!([
+            {
+                "type": 1,
+                "components": [{"type": 4, "custom_id": "title", "label": "Title", "style": 1}],
+            },
+            {
+                "type": 1,
+                "components": [{
+                    "type": 4,
+                    "custom_id": "body",
+                    "label": "What do you think?",
+                    "style": 2,
+                    "max_length": 1000,
+                    "required": false,
+                }],
+            },
+        ])));
+    }
+}
@@ -0,0 +1,114 @@
+use internal::prelude::*;
+use model::channel::ReactionType;
+use std::fmt::Display;
+use utils::VecMap;
+
+/// A builder to create a poll, to be attached to a message through
+/// [`CreateMessage::poll`].
+///
+/// A poll needs a [`question`] and at least one [`answer`], of which there can
+/// be up to 10.
+///
+/// # Examples
+///
+/// Ask which language to use, with the poll ending after 2 hours:
+///
+/// ```rust,no_run
+/// use serenity::model::id::ChannelId;
+///
+/// let _ = ChannelId(7).send_message(|m| m
+///     .poll(|p| p
+///         .question("Which language should we use?")
+///         .answer("Rust")
+///         .answer_with_emoji("Ferris", '🦀')
+///         .duration(2)));
+/// ```
+///
+/// [`CreateMessage::poll`]: struct.CreateMessage.html#method.poll
+/// [`answer`]: #method.answer
+/// [`question`]: #method.question
+#[derive(Clone, Debug, Default)]
+pub struct CreatePoll(pub VecMap<&'static str, Value>);
+
+impl CreatePoll {
+    /// Set whether users can vote for more than one answer.
+    ///
+    /// Defaults to `false`.
+    pub fn allow_multiselect(mut self, allow: bool) -> Self {
+        self.0.insert("allow_multiselect", Value::Bool(allow));
+
+        self
+    }
+
+    /// Adds an answer that can be voted for.
+    ///
+    /// **Note**: The text of an answer must be at most 55 unicode code points.
+    #[inline]
+    pub fn answer<D: Display>(self, text: D) -> Self {
+        self._answer(text.to_string(), None)
+    }
+
+    /// Adds an answer that can be voted for, displayed with an emoji.
+    ///
+    /// **Note**: The text of an answer must be at most 55 unicode code points.
+    #[inline]
+    pub fn answer_with_emoji<D, R>(self, text: D, emoji: R) -> Self
+        where D: Display, R: Into<ReactionType> {
+        self._answer(text.to_string(), Some(emoji.into()))
+    }
+
+    fn _answer(mut self, text: String, emoji: Option<ReactionType>) -> Self {
+        let mut media = json!({
+            "text": text,
+        });
+
+        if let Some(emoji) = emoji {
+            media["emoji"] = match emoji {
+                ReactionType::Custom { id, .. } => json!({
+                    "id": id.0.to_string(),
+                }),
+                ReactionType::Unicode(name) => json!({
+                    "name": name,
+                }),
+            };
+        }
+
+        let answers = self.0
+            .entry("answers")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut answers) = *answers {
+            answers.push(json!({
+                "poll_media": media,
+            }));
+        }
+
+        self
+    }
+
+    /// Set the number of hours the poll is open for, of which there can be up
+    /// to 768, or 32 days.
+    ///
+    /// Defaults to 24 hours.
+    pub fn duration(mut self, hours: u64) -> Self {
+        self.0.insert("duration", Value::Number(Number::from(hours)));
+
+        self
+    }
+
+    /// Set the question of the poll.
+    ///
+    /// **Note**: The question must be at most 300 unicode code points.
+    #[inline]
+    pub fn question<D: Display>(self, question: D) -> Self {
+        self._question(question.to_string())
+    }
+
+    fn _question(mut self, question: String) -> Self {
+        self.0.insert("question", json!({
+            "text": question,
+        }));
+
+        self
+    }
+}
@@ -0,0 +1,52 @@
+use internal::prelude::*;
+use model::channel::StagePrivacyLevel;
+use std::fmt::Display;
+use utils::VecMap;
+
+/// A builder to start a stage through [`ChannelId::create_stage_instance`], or
+/// to edit one through [`ChannelId::edit_stage_instance`].
+///
+/// Starting a stage needs a [`topic`].
+///
+/// # Examples
+///
+/// Starting a stage in a stage channel:
+///
+/// ```rust,no_run
+/// use serenity::model::id::ChannelId;
+///
+/// let _ = ChannelId(7).create_stage_instance(|s| s.topic("Weekly town hall"));
+/// ```
+///
+/// [`ChannelId::create_stage_instance`]: ../model/id/struct.ChannelId.html#method.create_stage_instance
+/// [`ChannelId::edit_stage_instance`]: ../model/id/struct.ChannelId.html#method.edit_stage_instance
+/// [`topic`]: #method.topic
+#[derive(Clone, Debug, Default)]
+pub struct CreateStageInstance(pub VecMap<&'static str, Value>);
+
+impl CreateStageInstance {
+    /// Set who can see the stage.
+    ///
+    /// Defaults to [`GuildOnly`].
+    ///
+    /// [`GuildOnly`]: ../model/channel/enum.StagePrivacyLevel.html#variant.GuildOnly
+    pub fn privacy_level(mut self, privacy_level: StagePrivacyLevel) -> Self {
+        self.0.insert("privacy_level", Value::Number(Number::from(privacy_level.num())));
+
+        self
+    }
+
+    /// Set the topic of the stage.
+    ///
+    /// **Note**: Must be between 1 and 120 characters long.
+    #[inline]
+    pub fn topic<D: Display>(self, topic: D) -> Self {
+        self._topic(topic.to_string())
+    }
+
+    fn _topic(mut self, topic: String) -> Self {
+        self.0.insert("topic", Value::String(topic));
+
+        self
+    }
+}
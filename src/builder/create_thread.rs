@@ -0,0 +1,83 @@
+use internal::prelude::*;
+use model::channel::ChannelType;
+use std::fmt::Display;
+use utils::VecMap;
+
+/// A builder to start a thread, through [`ChannelId::create_thread`] or
+/// [`ChannelId::create_thread_from_message`].
+///
+/// A thread needs a [`name`].
+///
+/// # Examples
+///
+/// Starting a thread on a message, which is archived after a day without
+/// activity:
+///
+/// ```rust,no_run
+/// # use serenity::model::channel::Message;
+/// #
+/// # fn discuss(msg: &Message) -> serenity::Result<()> {
+/// let thread = msg.channel_id.create_thread_from_message(msg.id, |t| t
+///     .name("Discussion")
+///     .auto_archive_duration(1440))?;
+///
+/// thread.say("Discuss the message here.")?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`ChannelId::create_thread`]: ../model/id/struct.ChannelId.html#method.create_thread
+/// [`ChannelId::create_thread_from_message`]: ../model/id/struct.ChannelId.html#method.create_thread_from_message
+/// [`name`]: #method.name
+#[derive(Clone, Debug, Default)]
+pub struct CreateThread(pub VecMap<&'static str, Value>);
+
+impl CreateThread {
+    /// Set the number of minutes without activity after which the thread is
+    /// archived.
+    ///
+    /// **Note**: Must be either `60`, `1440`, `4320` or `10080`.
+    pub fn auto_archive_duration(mut self, minutes: u64) -> Self {
+        self.0.insert("auto_archive_duration", Value::Number(Number::from(minutes)));
+
+        self
+    }
+
+    /// Set whether members that are not moderators can add other members to
+    /// the thread.
+    ///
+    /// **Note**: This only applies to private threads.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.0.insert("invitable", Value::Bool(invitable));
+
+        self
+    }
+
+    /// Set the type of the thread, which is either a [`PublicThread`] or a
+    /// [`PrivateThread`].
+    ///
+    /// Defaults to [`PrivateThread`]. This is ignored when starting a thread
+    /// from a message, which is always public.
+    ///
+    /// [`PrivateThread`]: ../model/channel/enum.ChannelType.html#variant.PrivateThread
+    /// [`PublicThread`]: ../model/channel/enum.ChannelType.html#variant.PublicThread
+    pub fn kind(mut self, kind: ChannelType) -> Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+
+        self
+    }
+
+    /// Set the name of the thread.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    #[inline]
+    pub fn name<D: Display>(self, name: D) -> Self {
+        self._name(name.to_string())
+    }
+
+    fn _name(mut self, name: String) -> Self {
+        self.0.insert("name", Value::String(name));
+
+        self
+    }
+}
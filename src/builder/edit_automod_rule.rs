@@ -0,0 +1,242 @@
+use internal::prelude::*;
+use model::guild::{
+    AutoModActionType,
+    AutoModEventType,
+    AutoModKeywordPresetType,
+    AutoModTriggerType
+};
+use model::id::{ChannelId, RoleId};
+use std::fmt::Display;
+use utils::VecMap;
+
+/// A builder to create or edit an [`AutoModRule`], through
+/// [`GuildId::create_automod_rule`], [`GuildId::edit_automod_rule`] or
+/// [`AutoModRule::edit`].
+///
+/// Creating a rule needs a [`name`], a [`trigger_type`] and at least one
+/// action, such as [`block_message`]. The [`event_type`] defaults to
+/// [`MessageSend`].
+///
+/// **Note**: Setting any action replaces all actions of an edited rule.
+///
+/// # Examples
+///
+/// Blocking messages containing a word, and logging them to a channel:
+///
+/// ```rust,no_run
+/// use serenity::model::guild::AutoModTriggerType;
+/// use serenity::model::id::{ChannelId, GuildId};
+///
+/// let _ = GuildId(7).create_automod_rule(|r| r
+///     .name("No spoilers")
+///     .trigger_type(AutoModTriggerType::Keyword)
+///     .keyword_filter(vec!["*spoiler*"])
+///     .block_message(Some("Please do not spoil the ending."))
+///     .send_alert_message(ChannelId(8))
+///     .enabled(true));
+/// ```
+///
+/// [`AutoModRule`]: ../model/guild/struct.AutoModRule.html
+/// [`AutoModRule::edit`]: ../model/guild/struct.AutoModRule.html#method.edit
+/// [`GuildId::create_automod_rule`]: ../model/id/struct.GuildId.html#method.create_automod_rule
+/// [`GuildId::edit_automod_rule`]: ../model/id/struct.GuildId.html#method.edit_automod_rule
+/// [`MessageSend`]: ../model/guild/enum.AutoModEventType.html#variant.MessageSend
+/// [`block_message`]: #method.block_message
+/// [`event_type`]: #method.event_type
+/// [`name`]: #method.name
+/// [`trigger_type`]: #method.trigger_type
+#[derive(Clone, Debug)]
+pub struct EditAutoModRule(pub VecMap<&'static str, Value>);
+
+impl EditAutoModRule {
+    /// Set the words that do not trigger a [`Keyword`] or [`KeywordPreset`]
+    /// rule.
+    ///
+    /// [`Keyword`]: ../model/guild/enum.AutoModTriggerType.html#variant.Keyword
+    /// [`KeywordPreset`]: ../model/guild/enum.AutoModTriggerType.html#variant.KeywordPreset
+    pub fn allow_list<D: Display, It: IntoIterator<Item=D>>(self, words: It) -> Self {
+        self.insert_metadata("allow_list", strings(words))
+    }
+
+    /// Adds an action that prevents a message triggering the rule from being
+    /// sent, optionally showing a custom message to the member.
+    pub fn block_message(self, custom_message: Option<&str>) -> Self {
+        let metadata = match custom_message {
+            Some(message) => json!({ "custom_message": message }),
+            None => json!({}),
+        };
+
+        self.push_action(AutoModActionType::BlockMessage, metadata)
+    }
+
+    /// Set whether the rule is enabled.
+    ///
+    /// Defaults to `false`.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+
+        self
+    }
+
+    /// Set the event that the rule is checked on.
+    pub fn event_type(mut self, event_type: AutoModEventType) -> Self {
+        self.0.insert("event_type", Value::Number(Number::from(event_type.num())));
+
+        self
+    }
+
+    /// Set the channels in which the rule is not checked, of which there can
+    /// be up to 50.
+    pub fn exempt_channels<C: Into<ChannelId>, It: IntoIterator<Item=C>>(mut self, channels: It) -> Self {
+        let ids = channels
+            .into_iter()
+            .map(|id| Value::Number(Number::from(id.into().0)))
+            .collect();
+
+        self.0.insert("exempt_channels", Value::Array(ids));
+
+        self
+    }
+
+    /// Set the roles of which the members are not affected by the rule, of
+    /// which there can be up to 20.
+    pub fn exempt_roles<R: Into<RoleId>, It: IntoIterator<Item=R>>(mut self, roles: It) -> Self {
+        let ids = roles
+            .into_iter()
+            .map(|id| Value::Number(Number::from(id.into().0)))
+            .collect();
+
+        self.0.insert("exempt_roles", Value::Array(ids));
+
+        self
+    }
+
+    /// Set the words that trigger a [`Keyword`] rule, of which there can be up
+    /// to 1000.
+    ///
+    /// A word can be prefixed or suffixed with `*` to also match it as part of
+    /// other words.
+    ///
+    /// [`Keyword`]: ../model/guild/enum.AutoModTriggerType.html#variant.Keyword
+    pub fn keyword_filter<D: Display, It: IntoIterator<Item=D>>(self, words: It) -> Self {
+        self.insert_metadata("keyword_filter", strings(words))
+    }
+
+    /// Set the number of unique mentions of roles and users in a message that
+    /// trigger a [`MentionSpam`] rule, which is at most 50.
+    ///
+    /// [`MentionSpam`]: ../model/guild/enum.AutoModTriggerType.html#variant.MentionSpam
+    pub fn mention_total_limit(self, limit: u64) -> Self {
+        self.insert_metadata("mention_total_limit", Value::Number(Number::from(limit)))
+    }
+
+    /// Set the name of the rule.
+    #[inline]
+    pub fn name<D: Display>(self, name: D) -> Self {
+        self._name(name.to_string())
+    }
+
+    fn _name(mut self, name: String) -> Self {
+        self.0.insert("name", Value::String(name));
+
+        self
+    }
+
+    /// Set the lists of words of Discord that trigger a [`KeywordPreset`]
+    /// rule.
+    ///
+    /// [`KeywordPreset`]: ../model/guild/enum.AutoModTriggerType.html#variant.KeywordPreset
+    pub fn presets<It: IntoIterator<Item=AutoModKeywordPresetType>>(self, presets: It) -> Self {
+        let presets = presets
+            .into_iter()
+            .map(|preset| Value::Number(Number::from(preset.num())))
+            .collect();
+
+        self.insert_metadata("presets", Value::Array(presets))
+    }
+
+    /// Set the regular expressions that trigger a [`Keyword`] rule, of which
+    /// there can be up to 10.
+    ///
+    /// [`Keyword`]: ../model/guild/enum.AutoModTriggerType.html#variant.Keyword
+    pub fn regex_patterns<D: Display, It: IntoIterator<Item=D>>(self, patterns: It) -> Self {
+        self.insert_metadata("regex_patterns", strings(patterns))
+    }
+
+    /// Adds an action that logs a message triggering the rule to a channel.
+    pub fn send_alert_message<C: Into<ChannelId>>(self, channel_id: C) -> Self {
+        let metadata = json!({ "channel_id": channel_id.into().0 });
+
+        self.push_action(AutoModActionType::SendAlertMessage, metadata)
+    }
+
+    /// Adds an action that times out the member sending a message triggering
+    /// the rule, for a number of seconds which is at most 4 weeks.
+    ///
+    /// **Note**: Can only be added to [`Keyword`] and [`MentionSpam`] rules.
+    ///
+    /// [`Keyword`]: ../model/guild/enum.AutoModTriggerType.html#variant.Keyword
+    /// [`MentionSpam`]: ../model/guild/enum.AutoModTriggerType.html#variant.MentionSpam
+    pub fn timeout(self, duration_seconds: u64) -> Self {
+        let metadata = json!({ "duration_seconds": duration_seconds });
+
+        self.push_action(AutoModActionType::Timeout, metadata)
+    }
+
+    /// Set the kind of content that triggers the rule.
+    ///
+    /// **Note**: Can not be changed after the rule was created.
+    pub fn trigger_type(mut self, trigger_type: AutoModTriggerType) -> Self {
+        self.0.insert("trigger_type", Value::Number(Number::from(trigger_type.num())));
+
+        self
+    }
+
+    fn insert_metadata(mut self, key: &str, value: Value) -> Self {
+        let metadata = self.0
+            .entry("trigger_metadata")
+            .or_insert_with(|| Value::Object(JsonMap::new()));
+
+        if let Value::Object(ref mut metadata) = *metadata {
+            metadata.insert(key.to_string(), value);
+        }
+
+        self
+    }
+
+    fn push_action(mut self, kind: AutoModActionType, metadata: Value) -> Self {
+        let action = json!({
+            "type": kind.num(),
+            "metadata": metadata,
+        });
+
+        let actions = self.0
+            .entry("actions")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut actions) = *actions {
+            actions.push(action);
+        }
+
+        self
+    }
+}
+
+impl Default for EditAutoModRule {
+    /// Creates a map for an [`AutoModRule`], setting the [`event_type`] to
+    /// [`MessageSend`].
+    ///
+    /// [`AutoModRule`]: ../model/guild/struct.AutoModRule.html
+    /// [`MessageSend`]: ../model/guild/enum.AutoModEventType.html#variant.MessageSend
+    /// [`event_type`]: #method.event_type
+    fn default() -> EditAutoModRule {
+        let mut map = VecMap::new();
+        map.insert("event_type", Value::Number(Number::from(AutoModEventType::MessageSend.num())));
+
+        EditAutoModRule(map)
+    }
+}
+
+fn strings<D: Display, It: IntoIterator<Item=D>>(values: It) -> Value {
+    Value::Array(values.into_iter().map(|value| Value::String(value.to_string())).collect())
+}
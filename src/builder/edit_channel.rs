@@ -50,6 +50,25 @@ impl EditChannel {
         self
     }
 
+    /// The voice region of the channel, by the Id of a [`VoiceRegion`]. Pass
+    /// `None` to choose the region automatically.
+    ///
+    /// The available regions can be retrieved through [`http::get_voice_regions`].
+    ///
+    /// This is for [voice] channels only.
+    ///
+    /// [`VoiceRegion`]: ../model/voice/struct.VoiceRegion.html
+    /// [`http::get_voice_regions`]: ../http/fn.get_voice_regions.html
+    /// [voice]: ../model/channel/enum.ChannelType.html#variant.Voice
+    pub fn rtc_region(mut self, region: Option<&str>) -> Self {
+        self.0.insert("rtc_region", match region {
+            Some(region) => Value::String(region.to_string()),
+            None => Value::Null,
+        });
+
+        self
+    }
+
     /// The topic of the channel. Can be empty.
     ///
     /// Must be between 0 and 1024 characters long.
@@ -94,3 +113,18 @@ impl EditChannel {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use super::EditChannel;
+
+    #[test]
+    fn rtc_region() {
+        let edit = EditChannel::default().rtc_region(Some("rotterdam"));
+        assert_eq!(edit.0.get(&"rtc_region"), Some(&json!("rotterdam")));
+
+        let edit = EditChannel::default().rtc_region(None);
+        assert_eq!(edit.0.get(&"rtc_region"), Some(&Value::Null));
+    }
+}
@@ -0,0 +1,49 @@
+use internal::prelude::*;
+use utils::VecMap;
+
+/// A builder to edit the settings of a guild's listing in the guild
+/// discovery, to be used in conjunction with
+/// [`GuildId::edit_discovery_metadata`].
+///
+/// [`GuildId::edit_discovery_metadata`]: ../model/id/struct.GuildId.html#method.edit_discovery_metadata
+#[derive(Clone, Debug, Default)]
+pub struct EditDiscoveryMetadata(pub VecMap<&'static str, Value>);
+
+impl EditDiscoveryMetadata {
+    /// Whether the guild's custom emojis are shown to users who are not in
+    /// the guild.
+    pub fn emoji_discoverability_enabled(mut self, enabled: bool) -> Self {
+        self.0.insert("emoji_discoverability_enabled", Value::Bool(enabled));
+
+        self
+    }
+
+    /// Set the search keywords of the guild, of which there can be up to 10.
+    /// Pass `None` to remove the keywords.
+    pub fn keywords<It, S>(mut self, keywords: Option<It>) -> Self
+        where It: IntoIterator<Item = S>, S: Into<String> {
+        let keywords = match keywords {
+            Some(keywords) => Value::Array(keywords
+                .into_iter()
+                .map(|keyword| Value::String(keyword.into()))
+                .collect()),
+            None => Value::Null,
+        };
+
+        self.0.insert("keywords", keywords);
+
+        self
+    }
+
+    /// Set the primary category of the guild.
+    ///
+    /// Refer to [`http::get_discovery_categories`] for the categories that
+    /// can be used as the primary category.
+    ///
+    /// [`http::get_discovery_categories`]: ../http/fn.get_discovery_categories.html
+    pub fn primary_category_id(mut self, category_id: u64) -> Self {
+        self.0.insert("primary_category_id", Value::Number(Number::from(category_id)));
+
+        self
+    }
+}
@@ -28,9 +28,13 @@ impl EditGuild {
         self._afk_channel(channel.map(Into::into))
     }
 
-    fn _afk_channel(mut self, channel: Option<ChannelId>) -> Self {
+    fn _afk_channel(self, channel: Option<ChannelId>) -> Self {
+        self._channel("afk_channel_id", channel)
+    }
+
+    fn _channel(mut self, key: &'static str, channel: Option<ChannelId>) -> Self {
         self.0.insert(
-            "afk_channel_id",
+            key,
             match channel {
                 Some(channel) => Value::Number(Number::from(channel.0)),
                 None => Value::Null,
@@ -40,6 +44,15 @@ impl EditGuild {
         self
     }
 
+    fn _image(mut self, key: &'static str, image: Option<&str>) -> Self {
+        self.0.insert(
+            key,
+            image.map_or(Value::Null, |x| Value::String(x.to_string())),
+        );
+
+        self
+    }
+
     /// Set the amount of time a user is to be moved to the AFK channel -
     /// configured via [`afk_channel`] - after being AFK.
     ///
@@ -53,6 +66,51 @@ impl EditGuild {
         self
     }
 
+    /// Set the banner of the guild, shown at the top of its channel list. Pass
+    /// `None` to remove the banner.
+    ///
+    /// Requires that the guild have the `BANNER` feature enabled. Refer to
+    /// [`icon`] for how to read an image.
+    ///
+    /// [`icon`]: #method.icon
+    pub fn banner(self, banner: Option<&str>) -> Self {
+        self._image("banner", banner)
+    }
+
+    /// Set the level at which members are notified of messages by default.
+    ///
+    /// Refer to the documentation for [`DefaultMessageNotificationLevel`] for
+    /// more information on each variant.
+    ///
+    /// [`DefaultMessageNotificationLevel`]: ../model/guild/enum.DefaultMessageNotificationLevel.html
+    pub fn default_message_notifications(mut self, level: DefaultMessageNotificationLevel) -> Self {
+        let num = Value::Number(Number::from(level.num()));
+        self.0.insert("default_message_notifications", num);
+
+        self
+    }
+
+    /// Set the splash image of the guild shown in the guild discovery. Pass
+    /// `None` to remove the splash.
+    ///
+    /// Requires that the guild have the `DISCOVERABLE` feature enabled.
+    pub fn discovery_splash(self, splash: Option<&str>) -> Self {
+        self._image("discovery_splash", splash)
+    }
+
+    /// Set which members' messages are scanned for explicit content.
+    ///
+    /// Refer to the documentation for [`ExplicitContentFilter`] for more
+    /// information on each variant.
+    ///
+    /// [`ExplicitContentFilter`]: ../model/guild/enum.ExplicitContentFilter.html
+    pub fn explicit_content_filter(mut self, filter: ExplicitContentFilter) -> Self {
+        let num = Value::Number(Number::from(filter.num()));
+        self.0.insert("explicit_content_filter", num);
+
+        self
+    }
+
     /// Set the icon of the guild. Pass `None` to remove the icon.
     ///
     /// # Examples
@@ -115,8 +173,29 @@ impl EditGuild {
         self
     }
 
+    /// Set the locale of the guild, used for its discovery listing and the
+    /// notices sent by Discord. Pass `None` to reset it to `en-US`.
+    pub fn preferred_locale(mut self, locale: Option<&str>) -> Self {
+        self.0.insert(
+            "preferred_locale",
+            locale.map_or(Value::Null, |x| Value::String(x.to_string())),
+        );
+
+        self
+    }
+
+    /// Set the channel in which Discord posts notices to the moderators of a
+    /// community guild. Pass `None` to unset the channel.
+    #[inline]
+    pub fn public_updates_channel<C: Into<ChannelId>>(self, channel: Option<C>) -> Self {
+        self._channel("public_updates_channel_id", channel.map(Into::into))
+    }
+
     /// Set the voice region of the server.
     ///
+    /// **Note**: Guild voice regions are deprecated by Discord in favour of
+    /// per-channel regions, which are set through [`EditChannel::rtc_region`].
+    ///
     /// # Examples
     ///
     /// Setting the region to [`Region::UsWest`]:
@@ -140,6 +219,7 @@ impl EditGuild {
     /// # }
     /// ```
     ///
+    /// [`EditChannel::rtc_region`]: struct.EditChannel.html#method.rtc_region
     /// [`Region::UsWest`]: ../model/guild/enum.Region.html#variant.UsWest
     pub fn region(mut self, region: Region) -> Self {
         self.0.insert("region", Value::String(region.name().to_string()));
@@ -147,15 +227,57 @@ impl EditGuild {
         self
     }
 
+    /// Set the channel displaying the rules of a community guild. Pass `None`
+    /// to unset the channel.
+    #[inline]
+    pub fn rules_channel<C: Into<ChannelId>>(self, channel: Option<C>) -> Self {
+        self._channel("rules_channel_id", channel.map(Into::into))
+    }
+
     /// Set the splash image of the guild on the invitation page.
     ///
     /// Requires that the guild have the `INVITE_SPLASH` feature enabled.
     /// You can check this through a guild's [`features`] list.
     ///
     /// [`features`]: ../model/guild/struct.Guild.html#structfield.features
-    pub fn splash(mut self, splash: Option<&str>) -> Self {
-        let splash = splash.map_or(Value::Null, |x| Value::String(x.to_string()));
-        self.0.insert("splash", splash);
+    pub fn splash(self, splash: Option<&str>) -> Self {
+        self._image("splash", splash)
+    }
+
+    /// Set the channel to which system messages, such as welcome messages,
+    /// are sent. Pass `None` to disable system messages.
+    #[inline]
+    pub fn system_channel<C: Into<ChannelId>>(self, channel: Option<C>) -> Self {
+        self._channel("system_channel_id", channel.map(Into::into))
+    }
+
+    /// Set which kinds of system messages are suppressed in the
+    /// [system channel].
+    ///
+    /// # Examples
+    ///
+    /// Suppressing the messages sent when members join:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// #     let mut guild = GuildId(0).to_partial_guild()?;
+    /// use serenity::model::guild::SystemChannelFlags;
+    ///
+    /// guild.edit(|g| g.system_channel_flags(SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS))?;
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [system channel]: #method.system_channel
+    pub fn system_channel_flags(mut self, flags: SystemChannelFlags) -> Self {
+        self.0.insert("system_channel_flags", Value::Number(Number::from(flags.bits())));
 
         self
     }
@@ -203,3 +325,52 @@ impl EditGuild {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use model::guild::{DefaultMessageNotificationLevel, ExplicitContentFilter, SystemChannelFlags};
+    use model::id::ChannelId;
+    use serde_json::Value;
+    use super::EditGuild;
+
+    #[test]
+    fn channels() {
+        let edit = EditGuild::default()
+            .public_updates_channel(Some(ChannelId(1)))
+            .rules_channel(Some(ChannelId(2)))
+            .system_channel(None::<ChannelId>);
+
+        assert_eq!(edit.0.get(&"public_updates_channel_id"), Some(&json!(1)));
+        assert_eq!(edit.0.get(&"rules_channel_id"), Some(&json!(2)));
+        assert_eq!(edit.0.get(&"system_channel_id"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn images() {
+        let edit = EditGuild::default()
+            .banner(Some("data:image/png;base64,banner"))
+            .discovery_splash(None)
+            .splash(Some("data:image/png;base64,splash"));
+
+        assert_eq!(edit.0.get(&"banner"), Some(&json!("data:image/png;base64,banner")));
+        assert_eq!(edit.0.get(&"discovery_splash"), Some(&Value::Null));
+        assert_eq!(edit.0.get(&"splash"), Some(&json!("data:image/png;base64,splash")));
+    }
+
+    #[test]
+    fn settings() {
+        let flags = SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS
+            | SystemChannelFlags::SUPPRESS_GUILD_REMINDER_NOTIFICATIONS;
+
+        let edit = EditGuild::default()
+            .default_message_notifications(DefaultMessageNotificationLevel::Mentions)
+            .explicit_content_filter(ExplicitContentFilter::All)
+            .preferred_locale(Some("de"))
+            .system_channel_flags(flags);
+
+        assert_eq!(edit.0.get(&"default_message_notifications"), Some(&json!(1)));
+        assert_eq!(edit.0.get(&"explicit_content_filter"), Some(&json!(2)));
+        assert_eq!(edit.0.get(&"preferred_locale"), Some(&json!("de")));
+        assert_eq!(edit.0.get(&"system_channel_flags"), Some(&json!(5)));
+    }
+}
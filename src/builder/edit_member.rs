@@ -1,6 +1,11 @@
 use internal::prelude::*;
 use model::id::{ChannelId, RoleId};
-use utils::VecMap;
+use model::Timestamp;
+use utils::{self, VecMap};
+
+/// The key under which the audit log reason is kept until the request is made,
+/// as it is sent as a header rather than in the body.
+const AUDIT_LOG_REASON: &str = "audit_log_reason";
 
 /// A builder which edits the properties of a [`Member`], to be used in
 /// conjunction with [`Member::edit`].
@@ -11,6 +16,13 @@ use utils::VecMap;
 pub struct EditMember(pub VecMap<&'static str, Value>);
 
 impl EditMember {
+    /// Set the reason for the edit, displayed in the guild's audit log.
+    pub fn audit_log_reason(mut self, reason: &str) -> Self {
+        self.0.insert(AUDIT_LOG_REASON, Value::String(reason.to_string()));
+
+        self
+    }
+
     /// Whether to deafen the member.
     ///
     /// Requires the [Deafen Members] permission.
@@ -33,6 +45,17 @@ impl EditMember {
         self
     }
 
+    /// Disconnects the member from their voice channel, if they are in one.
+    ///
+    /// Requires the [Move Members] permission.
+    ///
+    /// [Move Members]: ../model/permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    pub fn disconnect_member(mut self) -> Self {
+        self.0.insert("channel_id", Value::Null);
+
+        self
+    }
+
     /// Changes the member's nickname. Pass an empty string to reset the
     /// nickname.
     ///
@@ -45,7 +68,32 @@ impl EditMember {
         self
     }
 
-    /// Set the list of roles that the member should have.
+    /// Removes the member's nickname, so that their username is displayed.
+    ///
+    /// Requires the [Manage Nicknames] permission.
+    ///
+    /// [Manage Nicknames]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_NICKNAMES
+    pub fn reset_nickname(mut self) -> Self {
+        self.0.insert("nick", Value::Null);
+
+        self
+    }
+
+    /// Removes a timeout set by [`timeout_until`], allowing the member to
+    /// communicate again.
+    ///
+    /// Requires the [Moderate Members] permission.
+    ///
+    /// [`timeout_until`]: #method.timeout_until
+    /// [Moderate Members]: https://discord.com/developers/docs/topics/permissions
+    pub fn remove_timeout(mut self) -> Self {
+        self.0.insert("communication_disabled_until", Value::Null);
+
+        self
+    }
+
+    /// Set the list of roles that the member should have, replacing all of
+    /// their current roles.
     ///
     /// Requires the [Manage Roles] permission to modify.
     ///
@@ -65,6 +113,45 @@ impl EditMember {
         self
     }
 
+    /// Times the member out until the given time, preventing them from
+    /// sending messages, reacting, and speaking in voice channels. The time
+    /// may be at most 28 days in the future.
+    ///
+    /// Requires the [Moderate Members] permission.
+    ///
+    /// # Examples
+    ///
+    /// Timing a member out for an hour:
+    ///
+    /// ```rust,no_run
+    /// # extern crate serenity;
+    /// #
+    /// # use serenity::model::id::{GuildId, UserId};
+    /// #
+    /// # fn main() {
+    /// use serenity::model::Timestamp;
+    ///
+    /// let in_an_hour = Timestamp::now().unix_timestamp() + 3600;
+    /// let until = Timestamp::from_unix_timestamp(in_an_hour).unwrap();
+    ///
+    /// let _ = GuildId(7).edit_member(UserId(8), |m| m
+    ///     .timeout_until(&until)
+    ///     .audit_log_reason("Spamming"));
+    /// # }
+    /// ```
+    ///
+    /// [Moderate Members]: https://discord.com/developers/docs/topics/permissions
+    #[inline]
+    pub fn timeout_until<T: Into<Timestamp>>(self, time: T) -> Self {
+        self._timeout_until(time.into())
+    }
+
+    fn _timeout_until(mut self, time: Timestamp) -> Self {
+        self.0.insert("communication_disabled_until", Value::String(time.to_rfc3339()));
+
+        self
+    }
+
     /// The Id of the voice channel to move the member to.
     ///
     /// Requires the [Move Members] permission.
@@ -80,5 +167,61 @@ impl EditMember {
         self.0.insert("channel_id", num);
 
         self
+    }}
+
+    /// Splits the builder into the body of the request and the reason for the
+    /// audit log.
+    pub(crate) fn into_request(mut self) -> (JsonMap, Option<String>) {
+        let mut reason = None;
+
+        while let Some(value) = self.0.remove(&AUDIT_LOG_REASON) {
+            if let Value::String(value) = value {
+                reason = Some(value);
+            }
+        }
+
+        (utils::vecmap_to_json_map(self.0), reason)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::Timestamp;
+    use serde_json::Value;
+    use super::EditMember;
+
+    #[test]
+    fn resetting_fields() {
+        let (map, reason) = EditMember::default()
+            .disconnect_member()
+            .reset_nickname()
+            .remove_timeout()
+            .into_request();
+
+        assert_eq!(map.get("channel_id"), Some(&Value::Null));
+        assert_eq!(map.get("nick"), Some(&Value::Null));
+        assert_eq!(map.get("communication_disabled_until"), Some(&Value::Null));
+        assert!(reason.is_none());
+    }
+
+    #[test]
+    fn timeout_until() {
+        let until = Timestamp::from_unix_timestamp(951_782_400).unwrap();
+        let (map, _) = EditMember::default().timeout_until(until).into_request();
+
+        assert_eq!(map.get("communication_disabled_until"), Some(&json!("2000-02-29T00:00:00+00:00")));
+    }
+
+    #[test]
+    fn audit_log_reason_is_not_in_the_body() {
+        let (map, reason) = EditMember::default()
+            .audit_log_reason("Spamming")
+            .mute(true)
+            .audit_log_reason("Spamming links")
+            .into_request();
+
+        assert_eq!(reason, Some("Spamming links".to_string()));
+        assert!(!map.contains_key("audit_log_reason"));
+        assert_eq!(map.get("mute"), Some(&Value::Bool(true)));
     }
 }
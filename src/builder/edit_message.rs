@@ -1,6 +1,6 @@
 use internal::prelude::*;
 use std::fmt::Display;
-use super::CreateEmbed;
+use super::{CreateActionRow, CreateEmbed};
 use utils::{self, VecMap};
 
 /// A builder to specify the fields to edit in an existing message.
@@ -23,6 +23,27 @@ use utils::{self, VecMap};
 pub struct EditMessage(pub VecMap<&'static str, Value>);
 
 impl EditMessage {
+    /// Adds a row of components to the message, such as buttons, of which
+    /// there can be up to 5.
+    ///
+    /// Refer to [`CreateActionRow`] for more information.
+    ///
+    /// [`CreateActionRow`]: struct.CreateActionRow.html
+    pub fn action_row<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateActionRow) -> CreateActionRow {
+        let row = utils::vecmap_to_json_map(f(CreateActionRow::default()).0);
+
+        let components = self.0
+            .entry("components")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut components) = *components {
+            components.push(Value::Object(row));
+        }
+
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
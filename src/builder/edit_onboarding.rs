@@ -0,0 +1,58 @@
+use internal::prelude::*;
+use model::guild::{OnboardingMode, OnboardingPrompt};
+use model::id::ChannelId;
+use serde_json;
+use utils::VecMap;
+
+/// A builder to edit the onboarding of a guild, to be used in conjunction
+/// with [`GuildId::edit_onboarding`].
+///
+/// [`GuildId::edit_onboarding`]: ../model/id/struct.GuildId.html#method.edit_onboarding
+#[derive(Clone, Debug, Default)]
+pub struct EditOnboarding(pub VecMap<&'static str, Value>);
+
+impl EditOnboarding {
+    /// Set the channels that members are opted into by default.
+    pub fn default_channels<It>(mut self, channels: It) -> Self
+        where It: IntoIterator<Item = ChannelId> {
+        let channels = channels
+            .into_iter()
+            .map(|channel_id| Value::Number(Number::from(channel_id.0)))
+            .collect();
+
+        self.0.insert("default_channel_ids", Value::Array(channels));
+
+        self
+    }
+
+    /// Whether the onboarding is shown to new members.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+
+        self
+    }
+
+    /// Set the criteria the default channels and prompts have to meet for the
+    /// onboarding to be enabled.
+    pub fn mode(mut self, mode: OnboardingMode) -> Self {
+        self.0.insert("mode", Value::Number(Number::from(mode.num())));
+
+        self
+    }
+
+    /// Set the prompts of the onboarding, replacing all current prompts.
+    ///
+    /// New prompts and options need an Id that is unique within the
+    /// onboarding, but which is otherwise replaced by Discord.
+    pub fn prompts<It>(mut self, prompts: It) -> Self
+        where It: IntoIterator<Item = OnboardingPrompt> {
+        let prompts = prompts
+            .into_iter()
+            .filter_map(|prompt| serde_json::to_value(prompt).ok())
+            .collect();
+
+        self.0.insert("prompts", Value::Array(prompts));
+
+        self
+    }
+}
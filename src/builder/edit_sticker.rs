@@ -0,0 +1,74 @@
+use internal::prelude::*;
+use std::fmt::Display;
+use utils::VecMap;
+
+/// A builder to create or edit a sticker of a guild, through
+/// [`GuildId::create_sticker`] or [`GuildId::edit_sticker`].
+///
+/// Creating a sticker needs a [`name`] and [`tags`].
+///
+/// # Examples
+///
+/// Uploading a sticker from a file:
+///
+/// ```rust,no_run
+/// use serenity::model::id::GuildId;
+/// use std::path::Path;
+///
+/// let _ = GuildId(7).create_sticker(Path::new("./ferris.png"), |s| s
+///     .name("ferris")
+///     .description("A happy crab")
+///     .tags("crab"));
+/// ```
+///
+/// [`GuildId::create_sticker`]: ../model/id/struct.GuildId.html#method.create_sticker
+/// [`GuildId::edit_sticker`]: ../model/id/struct.GuildId.html#method.edit_sticker
+/// [`name`]: #method.name
+/// [`tags`]: #method.tags
+#[derive(Clone, Debug, Default)]
+pub struct EditSticker(pub VecMap<&'static str, Value>);
+
+impl EditSticker {
+    /// Set the description of the sticker.
+    ///
+    /// **Note**: Must be either empty or between 2 and 100 characters long.
+    #[inline]
+    pub fn description<D: Display>(self, description: D) -> Self {
+        self._description(description.to_string())
+    }
+
+    fn _description(mut self, description: String) -> Self {
+        self.0.insert("description", Value::String(description));
+
+        self
+    }
+
+    /// Set the name of the sticker.
+    ///
+    /// **Note**: Must be between 2 and 30 characters long.
+    #[inline]
+    pub fn name<D: Display>(self, name: D) -> Self {
+        self._name(name.to_string())
+    }
+
+    fn _name(mut self, name: String) -> Self {
+        self.0.insert("name", Value::String(name));
+
+        self
+    }
+
+    /// Set the name of the emoji that autocompletes to the sticker, or a
+    /// comma separated list of such names.
+    ///
+    /// **Note**: Must be at most 200 characters long.
+    #[inline]
+    pub fn tags<D: Display>(self, tags: D) -> Self {
+        self._tags(tags.to_string())
+    }
+
+    fn _tags(mut self, tags: String) -> Self {
+        self.0.insert("tags", Value::String(tags));
+
+        self
+    }
+}
@@ -1,5 +1,6 @@
 use serde_json::Value;
 use std::default::Default;
+use std::fmt::Display;
 use utils::VecMap;
 
 /// A builder to create the inner content of a [`Webhook`]'s execution.
@@ -50,6 +51,31 @@ use utils::VecMap;
 pub struct ExecuteWebhook(pub VecMap<&'static str, Value>);
 
 impl ExecuteWebhook {
+    /// Set the description of the file at the given index, which is the alt
+    /// text shown by screen readers.
+    ///
+    /// The index refers to the order the files are given in, starting from
+    /// `0`. Descriptions only apply when sending files, such as through
+    /// [`Webhook::execute_with_files`].
+    ///
+    /// **Note**: Descriptions must be at most 1024 unicode code points.
+    ///
+    /// [`Webhook::execute_with_files`]: ../model/webhook/struct.Webhook.html#method.execute_with_files
+    pub fn attachment_description<D: Display>(mut self, index: u64, description: D) -> Self {
+        let attachments = self.0
+            .entry("attachments")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut attachments) = *attachments {
+            attachments.push(json!({
+                "id": index,
+                "description": description.to_string(),
+            }));
+        }
+
+        self
+    }
+
     /// Override the default avatar of the webhook with an image URL.
     ///
     /// # Examples
@@ -185,3 +211,17 @@ impl Default for ExecuteWebhook {
         ExecuteWebhook(map)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ExecuteWebhook;
+
+    #[test]
+    fn attachment_descriptions() {
+        let execute = ExecuteWebhook::default().attachment_description(1, "Sales chart");
+
+        assert_eq!(execute.0.get(&"attachments"), Some(&json!([
+            {"id": 1, "description": "Sales chart"},
+        ])));
+    }
+}
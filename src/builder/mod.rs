@@ -5,28 +5,53 @@
 //! optional, and/or sane default values for required parameters can be applied
 //! by a builder.
 
+mod create_application_command;
+mod create_components;
 mod create_embed;
+mod create_interaction_response;
 mod create_invite;
 mod create_message;
+mod create_modal;
+mod create_poll;
+mod create_stage_instance;
+mod create_thread;
+mod edit_automod_rule;
 mod edit_channel;
+mod edit_discovery_metadata;
 mod edit_guild;
 mod edit_member;
 mod edit_message;
+mod edit_onboarding;
 mod edit_profile;
 mod edit_role;
+mod edit_sticker;
 mod execute_webhook;
 mod get_messages;
 
 pub use self::{
-    create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter},
+    create_application_command::{CreateApplicationCommand, CreateApplicationCommandOption},
+    create_components::{CreateActionRow, CreateButton, CreateSelectMenu, CreateSelectMenuOption},
+    create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter, EmbedValidationError},
+    create_interaction_response::CreateInteractionResponse,
     create_invite::CreateInvite,
     create_message::CreateMessage,
+    create_modal::{CreateInputText, CreateModal},
+    create_poll::CreatePoll,
+    create_stage_instance::CreateStageInstance,
+    create_thread::CreateThread,
+    edit_automod_rule::EditAutoModRule,
     edit_channel::EditChannel,
+    edit_discovery_metadata::EditDiscoveryMetadata,
     edit_guild::EditGuild,
     edit_member::EditMember,
     edit_message::EditMessage,
+    edit_onboarding::EditOnboarding,
     edit_profile::EditProfile,
     edit_role::EditRole,
+    edit_sticker::EditSticker,
     execute_webhook::ExecuteWebhook,
     get_messages::GetMessages
 };
+
+#[cfg(feature = "model")]
+pub(crate) use self::create_embed::{embed_length, validate_embed};
@@ -115,6 +115,11 @@ pub struct Cache {
     ///
     /// This will always be empty for bot users.
     pub notes: HashMap<UserId, String>,
+    /// A map of channels to their pinned messages, if enabled through
+    /// [`Settings::cache_pins`].
+    ///
+    /// [`Settings::cache_pins`]: struct.Settings.html#structfield.cache_pins
+    pub pins: HashMap<ChannelId, Vec<Message>>,
     /// A map of users' presences. This is updated in real-time. Note that
     /// status updates are often "eaten" by the gateway, and this should not
     /// be treated as being entirely 100% accurate.
@@ -662,6 +667,28 @@ impl Cache {
         self.private_channels.get(&channel_id).cloned()
     }
 
+    /// Retrieves the [`PrivateChannel`] between the current user and the given
+    /// user from the cache's [`private_channels`] map, if it exists.
+    ///
+    /// This is used by [`UserId::create_dm_channel`] to avoid creating the
+    /// channel over REST again.
+    ///
+    /// [`PrivateChannel`]: ../model/channel/struct.PrivateChannel.html
+    /// [`UserId::create_dm_channel`]: ../model/id/struct.UserId.html#method.create_dm_channel
+    /// [`private_channels`]: #structfield.private_channels
+    #[inline]
+    pub fn private_channel_with<U: Into<UserId>>(&self, user_id: U)
+        -> Option<Arc<RwLock<PrivateChannel>>> {
+        self._private_channel_with(user_id.into())
+    }
+
+    fn _private_channel_with(&self, user_id: UserId) -> Option<Arc<RwLock<PrivateChannel>>> {
+        self.private_channels
+            .values()
+            .find(|channel| channel.read().recipient.read().id == user_id)
+            .cloned()
+    }
+
     /// Retrieves a [`Guild`]'s role by their Ids.
     ///
     /// **Note**: This will clone the entire role. Instead, retrieve the guild
@@ -803,7 +830,20 @@ impl Cache {
                 e.insert(Arc::new(RwLock::new(user.clone())));
             },
             Entry::Occupied(mut e) => {
-                e.get_mut().write().clone_from(user);
+                let mut cached = e.get_mut().write();
+
+                // Users are updated by many events, but rarely change, so only
+                // allocate for the fields that did.
+                if cached.avatar != user.avatar {
+                    cached.avatar = user.avatar.clone();
+                }
+
+                if cached.name != user.name {
+                    cached.name.clone_from(&user.name);
+                }
+
+                cached.bot = user.bot;
+                cached.discriminator = user.discriminator;
             },
         }
     }
@@ -818,6 +858,7 @@ impl Default for Cache {
             guilds: HashMap::default(),
             messages: HashMap::default(),
             notes: HashMap::default(),
+            pins: HashMap::default(),
             presences: HashMap::default(),
             private_channels: HashMap::with_capacity(128),
             settings: Settings::default(),
@@ -833,7 +874,6 @@ impl Default for Cache {
 
 #[cfg(test)]
 mod test {
-    use chrono::DateTime;
     use serde_json::{Number, Value};
     use std::{
         collections::HashMap,
@@ -852,10 +892,7 @@ mod test {
         let mut cache = Cache::new_with_settings(settings);
 
         // Test inserting one message into a channel's message cache.
-        let datetime = DateTime::parse_from_str(
-            "1983 Apr 13 12:09:14.274 +0000",
-            "%Y %b %d %H:%M:%S%.3f %z",
-        ).unwrap();
+        let datetime = Timestamp::parse("1983-04-13T12:09:14.274+00:00").unwrap();
         let mut event = MessageCreateEvent {
             message: Message {
                 id: MessageId(3),
@@ -869,9 +906,11 @@ mod test {
                 },
                 channel_id: ChannelId(2),
                 guild_id: Some(GuildId(1)),
+                components: vec![],
                 content: String::new(),
                 edited_timestamp: None,
                 embeds: vec![],
+                interaction: None,
                 kind: MessageType::Regular,
                 member: None,
                 mention_everyone: false,
@@ -879,7 +918,9 @@ mod test {
                 mentions: vec![],
                 nonce: Value::Number(Number::from(1)),
                 pinned: false,
+                poll: None,
                 reactions: vec![],
+                sticker_items: vec![],
                 timestamp: datetime.clone(),
                 tts: false,
                 webhook_id: None,
@@ -922,9 +963,14 @@ mod test {
             name: String::new(),
             permission_overwrites: vec![],
             position: 0,
+            rtc_region: None,
             topic: None,
             user_limit: None,
             nsfw: false,
+            thread_metadata: None,
+            owner_id: None,
+            message_count: None,
+            member_count: None,
         };
 
         // Add a channel delete event to the cache, the cached messages for that
@@ -960,6 +1006,8 @@ mod test {
                     name: String::new(),
                     owner_id: UserId(3),
                     presences: HashMap::new(),
+                    premium_subscription_count: 0,
+                    premium_tier: PremiumTier::Tier0,
                     region: String::new(),
                     roles: HashMap::new(),
                     splash: None,
@@ -987,6 +1035,8 @@ mod test {
                 mfa_level: MfaLevel::None,
                 name: String::new(),
                 owner_id: UserId(3),
+                premium_subscription_count: 0,
+                premium_tier: PremiumTier::Tier0,
                 region: String::new(),
                 roles: HashMap::new(),
                 splash: None,
@@ -1001,4 +1051,53 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.contains_key(&ChannelId(2)));
     }
+
+    #[test]
+    fn test_cache_private_channel_with() {
+        let mut cache = Cache::default();
+
+        let user = User {
+            id: UserId(2),
+            avatar: None,
+            bot: false,
+            discriminator: 1,
+            name: "user 1".to_owned(),
+        };
+        let mut event = ChannelCreateEvent {
+            channel: Channel::Private(Arc::new(RwLock::new(PrivateChannel {
+                id: ChannelId(3),
+                last_message_id: None,
+                last_pin_timestamp: None,
+                kind: ChannelType::Private,
+                recipient: Arc::new(RwLock::new(user)),
+            }))),
+        };
+
+        assert!(cache.private_channel_with(UserId(2)).is_none());
+        cache.update(&mut event);
+
+        let channel = cache.private_channel_with(UserId(2)).unwrap();
+        assert_eq!(channel.read().id, ChannelId(3));
+        assert!(cache.private_channel_with(UserId(4)).is_none());
+    }
+
+    #[test]
+    fn test_cache_pins_removed_on_update() {
+        let mut settings = Settings::new();
+        settings.cache_pins(true);
+        let mut cache = Cache::new_with_settings(settings);
+
+        cache.pins.insert(ChannelId(2), vec![]);
+        cache.pins.insert(ChannelId(3), vec![]);
+
+        let mut event = ChannelPinsUpdateEvent {
+            channel_id: ChannelId(2),
+            guild_id: Some(GuildId(1)),
+            last_pin_timestamp: None,
+        };
+        cache.update(&mut event);
+
+        assert!(!cache.pins.contains_key(&ChannelId(2)));
+        assert!(cache.pins.contains_key(&ChannelId(3)));
+    }
 }
@@ -1,50 +1,160 @@
-/// Settings for the cache.
-///
-/// # Examples
-///
-/// Create new settings, specifying the maximum number of messages:
-///
-/// ```rust
-/// use serenity::cache::Settings as CacheSettings;
-///
-/// let mut settings = CacheSettings::new();
-/// settings.max_messages(10);
-/// ```
-#[derive(Clone, Debug, Default)]
-pub struct Settings {
-    /// The maximum number of messages to store in a channel's message cache.
-    ///
-    /// Defaults to 0.
-    pub max_messages: usize,
-    __nonexhaustive: (),
-}
-
-impl Settings {
-    /// Creates new settings to be used with a cache.
-    #[inline]
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Sets the maximum number of messages to cache in a channel.
-    ///
-    /// Refer to [`max_messages`] for more information.
-    ///
-    /// # Examples
-    ///
-    /// Set the maximum number of messages to cache:
-    ///
-    /// ```rust
-    /// use serenity::cache::Settings;
-    ///
-    /// let mut settings = Settings::new();
-    /// settings.max_messages(10);
-    /// ```
-    ///
-    /// [`max_messages`]: #structfield.max_messages
-    pub fn max_messages(&mut self, max: usize) -> &mut Self {
-        self.max_messages = max;
-
-        self
-    }
-}
+/// Settings for the cache.
+///
+/// # Examples
+///
+/// Create new settings, specifying the maximum number of messages:
+///
+/// ```rust
+/// use serenity::cache::Settings as CacheSettings;
+///
+/// let mut settings = CacheSettings::new();
+/// settings.max_messages(10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The maximum number of messages to store in a channel's message cache.
+    ///
+    /// Defaults to 0.
+    pub max_messages: usize,
+    /// Whether to store the members sent along with a guild when it becomes
+    /// available.
+    ///
+    /// When disabled, the members are not deserialized at all, which saves
+    /// time and memory when connecting to many large guilds. Members can still
+    /// be cached through other events, such as a [`GuildMemberAddEvent`].
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`GuildMemberAddEvent`]: ../model/event/struct.GuildMemberAddEvent.html
+    pub cache_members: bool,
+    /// Whether to store the presences sent along with a guild when it becomes
+    /// available.
+    ///
+    /// Refer to [`cache_members`] for more information. If disabled, presence
+    /// updates of an ignored type are dropped without being deserialized, as
+    /// with other ignored events not updating the cache, refer to
+    /// [`Client::ignore_events`].
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`Client::ignore_events`]: ../client/struct.Client.html#method.ignore_events
+    /// [`cache_members`]: #structfield.cache_members
+    pub cache_presences: bool,
+    /// Whether to store the voice states sent along with a guild when it
+    /// becomes available.
+    ///
+    /// Refer to [`cache_members`] for more information.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`cache_members`]: #structfield.cache_members
+    pub cache_voice_states: bool,
+    /// Whether to store the pinned messages of channels once retrieved
+    /// through [`ChannelId::pins`], so that later calls need no request.
+    ///
+    /// A channel's pins are removed from the cache when they change.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`ChannelId::pins`]: ../model/id/struct.ChannelId.html#method.pins
+    pub cache_pins: bool,
+    __nonexhaustive: (),
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            max_messages: 0,
+            cache_members: true,
+            cache_presences: true,
+            cache_voice_states: true,
+            cache_pins: false,
+            __nonexhaustive: (),
+        }
+    }
+}
+
+impl Settings {
+    /// Creates new settings to be used with a cache.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of messages to cache in a channel.
+    ///
+    /// Refer to [`max_messages`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Set the maximum number of messages to cache:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.max_messages(10);
+    /// ```
+    ///
+    /// [`max_messages`]: #structfield.max_messages
+    pub fn max_messages(&mut self, max: usize) -> &mut Self {
+        self.max_messages = max;
+
+        self
+    }
+
+    /// Sets whether to store the members sent along with guilds.
+    ///
+    /// Refer to [`cache_members`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Skip the members of guilds, as the bot does not need them:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.cache_members(false);
+    /// ```
+    ///
+    /// [`cache_members`]: #structfield.cache_members
+    pub fn cache_members(&mut self, enabled: bool) -> &mut Self {
+        self.cache_members = enabled;
+
+        self
+    }
+
+    /// Sets whether to store the presences sent along with guilds.
+    ///
+    /// Refer to [`cache_presences`] for more information.
+    ///
+    /// [`cache_presences`]: #structfield.cache_presences
+    pub fn cache_presences(&mut self, enabled: bool) -> &mut Self {
+        self.cache_presences = enabled;
+
+        self
+    }
+
+    /// Sets whether to store the voice states sent along with guilds.
+    ///
+    /// Refer to [`cache_voice_states`] for more information.
+    ///
+    /// [`cache_voice_states`]: #structfield.cache_voice_states
+    pub fn cache_voice_states(&mut self, enabled: bool) -> &mut Self {
+        self.cache_voice_states = enabled;
+
+        self
+    }
+
+    /// Sets whether to store the pinned messages of channels.
+    ///
+    /// Refer to [`cache_pins`] for more information.
+    ///
+    /// [`cache_pins`]: #structfield.cache_pins
+    pub fn cache_pins(&mut self, enabled: bool) -> &mut Self {
+        self.cache_pins = enabled;
+
+        self
+    }
+}
@@ -0,0 +1,279 @@
+use model::event::Event;
+use std::collections::VecDeque;
+use super::super::super::dispatch::DispatchEvent;
+
+/// The limit on how many events a [`ShardRunner`] keeps waiting while the
+/// threadpool is busy, as set through [`Client::set_dispatch_queue`].
+///
+/// Without a limit, every event received is queued in the threadpool, so
+/// that an event storm, such as a raid, grows the queue without bounds if
+/// the event handler can not keep up.
+///
+/// [`Client::set_dispatch_queue`]: ../../struct.Client.html#method.set_dispatch_queue
+/// [`ShardRunner`]: struct.ShardRunner.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DispatchQueue {
+    /// The number of events that may wait for the threadpool, at least 1.
+    ///
+    /// Events are only handed to the threadpool while it has fewer jobs
+    /// queued than this. Otherwise they wait in the shard runner, of which a
+    /// shard keeps up to this many, or up to the [`limit`] while applying
+    /// backpressure.
+    ///
+    /// [`limit`]: #method.limit
+    pub capacity: usize,
+    /// What to do once a shard has `capacity` events waiting.
+    pub overflow: QueueOverflow,
+}
+
+/// What a [`ShardRunner`] does with an event received while its
+/// [`DispatchQueue`] is full.
+///
+/// **Note**: Events that are dropped are neither dispatched to the event
+/// handler nor used to update the cache, as with ignored events.
+///
+/// [`DispatchQueue`]: struct.DispatchQueue.html
+/// [`ShardRunner`]: struct.ShardRunner.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum QueueOverflow {
+    /// Slow down reading from the gateway until an event was handed to the
+    /// threadpool, leaving further events buffered by the connection.
+    ///
+    /// The gateway is still read from at a slower pace, so that the shard
+    /// keeps its session alive. Events received meanwhile keep waiting, up to
+    /// [`BACKPRESSURE_LIMIT`] times the `capacity`. Beyond that, events are
+    /// dropped as with [`DropNoisiest`] and a warning is logged.
+    ///
+    /// Up to this limit no events are lost, but a shard lags behind for as
+    /// long as the event handler can not keep up.
+    ///
+    /// [`BACKPRESSURE_LIMIT`]: constant.BACKPRESSURE_LIMIT.html
+    /// [`DropNoisiest`]: #variant.DropNoisiest
+    Backpressure,
+    /// Drop the event that has been waiting the longest.
+    DropOldest,
+    /// Drop the oldest event of the noisiest type waiting, which in order are
+    /// typing indicators, presence updates, and reaction and poll vote
+    /// updates. If none of these are waiting, the oldest event is dropped.
+    DropNoisiest,
+}
+
+/// How many times its `capacity` a [`DispatchQueue`] applying
+/// [`QueueOverflow::Backpressure`] keeps waiting at most, before dropping
+/// events regardless.
+///
+/// [`DispatchQueue`]: struct.DispatchQueue.html
+/// [`QueueOverflow::Backpressure`]: enum.QueueOverflow.html#variant.Backpressure
+pub const BACKPRESSURE_LIMIT: usize = 4;
+
+impl DispatchQueue {
+    /// The number of events a shard keeps waiting at most, beyond which it
+    /// drops events.
+    pub fn limit(&self) -> usize {
+        match self.overflow {
+            QueueOverflow::Backpressure => self.capacity.saturating_mul(BACKPRESSURE_LIMIT),
+            QueueOverflow::DropOldest | QueueOverflow::DropNoisiest => self.capacity,
+        }
+    }
+
+    /// Drops waiting events until no more than the [`limit`] are left,
+    /// returning how many were dropped.
+    ///
+    /// [`limit`]: #method.limit
+    pub(crate) fn drop_overflow(&self, pending: &mut VecDeque<DispatchEvent>) -> usize {
+        let limit = self.limit();
+        let mut dropped = 0;
+
+        while pending.len() > limit {
+            match self.overflow {
+                QueueOverflow::DropOldest => {
+                    pending.pop_front();
+                },
+                QueueOverflow::Backpressure | QueueOverflow::DropNoisiest => {
+                    drop_noisiest(pending);
+                },
+            }
+
+            dropped += 1;
+        }
+
+        dropped
+    }
+}
+
+/// Drops a waiting event according to [`QueueOverflow::DropNoisiest`].
+///
+/// [`QueueOverflow::DropNoisiest`]: enum.QueueOverflow.html#variant.DropNoisiest
+fn drop_noisiest(pending: &mut VecDeque<DispatchEvent>) {
+    let noisiest = pending
+        .iter()
+        .enumerate()
+        .max_by_key(|&(index, event)| (noise(event), -(index as isize)))
+        .map(|(index, _)| index);
+
+    if let Some(index) = noisiest {
+        pending.remove(index);
+    }
+}
+
+fn noise(event: &DispatchEvent) -> u8 {
+    match *event {
+        DispatchEvent::Model(Event::TypingStart(_)) => 3,
+        DispatchEvent::Model(Event::PresenceUpdate(_))
+        | DispatchEvent::Model(Event::PresencesReplace(_))
+        | DispatchEvent::Presences(_) => 2,
+        DispatchEvent::Model(Event::PollVoteAdd(_))
+        | DispatchEvent::Model(Event::PollVoteRemove(_))
+        | DispatchEvent::Model(Event::ReactionAdd(_))
+        | DispatchEvent::Model(Event::ReactionRemove(_))
+        | DispatchEvent::Model(Event::ReactionRemoveAll(_)) => 1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use client::dispatch::DispatchEvent;
+    use model::event::*;
+    use std::collections::VecDeque;
+    use super::*;
+
+    fn typing() -> DispatchEvent {
+        DispatchEvent::Model(Event::TypingStart(TypingStartEvent {
+            channel_id: 1.into(),
+            timestamp: 0,
+            user_id: 2.into(),
+        }))
+    }
+
+    fn reactions() -> DispatchEvent {
+        DispatchEvent::Model(Event::ReactionRemoveAll(ReactionRemoveAllEvent {
+            channel_id: 1.into(),
+            message_id: 3.into(),
+        }))
+    }
+
+    fn resumed(mark: &str) -> DispatchEvent {
+        DispatchEvent::Model(Event::Resumed(ResumedEvent {
+            trace: vec![Some(mark.to_string())],
+        }))
+    }
+
+    fn presences() -> DispatchEvent {
+        DispatchEvent::Presences(vec![])
+    }
+
+    fn names(pending: &VecDeque<DispatchEvent>) -> Vec<String> {
+        pending.iter().map(|event| match *event {
+            DispatchEvent::Model(Event::TypingStart(_)) => "typing".to_string(),
+            DispatchEvent::Model(Event::ReactionRemoveAll(_)) => "reactions".to_string(),
+            DispatchEvent::Model(Event::Resumed(ref event)) => {
+                event.trace[0].clone().unwrap()
+            },
+            DispatchEvent::Presences(_) => "presences".to_string(),
+            _ => "other".to_string(),
+        }).collect()
+    }
+
+    #[test]
+    fn drop_noisiest_order() {
+        let mut pending = vec![
+            resumed("a"),
+            reactions(),
+            presences(),
+            typing(),
+            typing(),
+        ].into_iter().collect::<VecDeque<_>>();
+
+        drop_noisiest(&mut pending);
+        assert_eq!(names(&pending), ["a", "reactions", "presences", "typing"]);
+
+        drop_noisiest(&mut pending);
+        assert_eq!(names(&pending), ["a", "reactions", "presences"]);
+
+        drop_noisiest(&mut pending);
+        assert_eq!(names(&pending), ["a", "reactions"]);
+
+        drop_noisiest(&mut pending);
+        assert_eq!(names(&pending), ["a"]);
+    }
+
+    #[test]
+    fn drop_noisiest_falls_back_to_oldest() {
+        let mut pending = vec![resumed("a"), resumed("b")].into_iter().collect();
+
+        drop_noisiest(&mut pending);
+        assert_eq!(names(&pending), ["b"]);
+
+        drop_noisiest(&mut pending);
+        drop_noisiest(&mut pending);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn limit() {
+        let mut queue = DispatchQueue {
+            capacity: 10,
+            overflow: QueueOverflow::DropOldest,
+        };
+        assert_eq!(queue.limit(), 10);
+
+        queue.overflow = QueueOverflow::DropNoisiest;
+        assert_eq!(queue.limit(), 10);
+
+        queue.overflow = QueueOverflow::Backpressure;
+        assert_eq!(queue.limit(), 10 * BACKPRESSURE_LIMIT);
+
+        queue.capacity = usize::max_value();
+        assert_eq!(queue.limit(), usize::max_value());
+    }
+
+    #[test]
+    fn drop_overflow_oldest() {
+        let queue = DispatchQueue {
+            capacity: 2,
+            overflow: QueueOverflow::DropOldest,
+        };
+        let mut pending = vec![typing(), resumed("a"), resumed("b")].into_iter().collect();
+
+        assert_eq!(queue.drop_overflow(&mut pending), 1);
+        assert_eq!(names(&pending), ["a", "b"]);
+
+        assert_eq!(queue.drop_overflow(&mut pending), 0);
+        assert_eq!(names(&pending), ["a", "b"]);
+    }
+
+    #[test]
+    fn drop_overflow_noisiest() {
+        let queue = DispatchQueue {
+            capacity: 2,
+            overflow: QueueOverflow::DropNoisiest,
+        };
+        let mut pending = vec![resumed("a"), typing(), resumed("b"), reactions()]
+            .into_iter()
+            .collect();
+
+        assert_eq!(queue.drop_overflow(&mut pending), 2);
+        assert_eq!(names(&pending), ["a", "b"]);
+    }
+
+    #[test]
+    fn drop_overflow_backpressure() {
+        let queue = DispatchQueue {
+            capacity: 1,
+            overflow: QueueOverflow::Backpressure,
+        };
+        let mut pending = (0..BACKPRESSURE_LIMIT)
+            .map(|i| resumed(&i.to_string()))
+            .collect::<VecDeque<_>>();
+
+        // Up to the limit, nothing is dropped.
+        assert_eq!(queue.drop_overflow(&mut pending), 0);
+        assert_eq!(pending.len(), BACKPRESSURE_LIMIT);
+
+        pending.push_back(typing());
+        assert_eq!(queue.drop_overflow(&mut pending), 1);
+        assert_eq!(pending.len(), BACKPRESSURE_LIMIT);
+        assert!(!names(&pending).contains(&"typing".to_string()));
+    }
+}
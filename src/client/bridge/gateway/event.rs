@@ -3,9 +3,13 @@
 
 use super::ShardId;
 use ::gateway::ConnectionStage;
+#[cfg(feature = "cache")]
+use model::id::GuildId;
 
 #[derive(Clone, Debug)]
 pub(crate) enum ClientEvent {
+    #[cfg(feature = "cache")]
+    CacheReady(Vec<GuildId>),
     ShardStageUpdate(ShardStageUpdateEvent),
 }
 
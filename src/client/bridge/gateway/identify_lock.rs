@@ -0,0 +1,37 @@
+/// A lock coordinating the starts of shards, so that shards run by multiple
+/// processes or machines do not identify with the gateway at the same time.
+///
+/// Discord only allows a bot to identify once every 5 seconds, or as often as
+/// its `max_concurrency` allows. Without a lock, the [`ShardQueuer`] only
+/// waits between the starts of the shards it is responsible for itself. The
+/// lock to use is set through [`Client::set_identify_lock`].
+///
+/// **Note**: Shards that re-identify after losing their session do so
+/// without acquiring the lock.
+///
+/// # Examples
+///
+/// A lock backed by a service shared by all processes:
+///
+/// ```rust,ignore
+/// use serenity::client::bridge::gateway::IdentifyLock;
+///
+/// struct ClusterLock {
+///     service: LockService,
+/// }
+///
+/// impl IdentifyLock for ClusterLock {
+///     fn acquire(&mut self, shard_info: [u64; 2]) {
+///         // Blocks until no other shard started in the last 5 seconds.
+///         self.service.lock_for("identify", 5);
+///     }
+/// }
+/// ```
+///
+/// [`Client::set_identify_lock`]: ../../struct.Client.html#method.set_identify_lock
+/// [`ShardQueuer`]: struct.ShardQueuer.html
+pub trait IdentifyLock: Send {
+    /// Called before a shard is started, with the shard's Id and the total
+    /// number of shards. This should block until the shard may identify.
+    fn acquire(&mut self, shard_info: [u64; 2]);
+}
@@ -50,6 +50,8 @@
 
 pub mod event;
 
+mod dispatch_queue;
+mod identify_lock;
 mod shard_manager;
 mod shard_manager_monitor;
 mod shard_messenger;
@@ -57,6 +59,8 @@ mod shard_queuer;
 mod shard_runner;
 mod shard_runner_message;
 
+pub use self::dispatch_queue::{BACKPRESSURE_LIMIT, DispatchQueue, QueueOverflow};
+pub use self::identify_lock::IdentifyLock;
 pub use self::shard_manager::{ShardManager, ShardManagerOptions};
 pub use self::shard_manager_monitor::ShardManagerMonitor;
 pub use self::shard_messenger::ShardMessenger;
@@ -1,16 +1,20 @@
-use gateway::InterMessage;
+use gateway::{CurrentPresence, InterMessage};
 use internal::prelude::*;
+use model::event::EventType;
 use parking_lot::Mutex;
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     sync::{
         mpsc::{self, Sender},
         Arc
     },
-    thread
+    thread,
+    time::Duration
 };
 use super::super::super::EventHandler;
 use super::{
+    DispatchQueue,
+    IdentifyLock,
     ShardClientMessage,
     ShardId,
     ShardManagerMessage,
@@ -24,6 +28,7 @@ use typemap::ShareMap;
 
 #[cfg(feature = "framework")]
 use framework::Framework;
+use client::bridge::voice::VoiceGatewayManager;
 #[cfg(feature = "voice")]
 use client::bridge::voice::ClientVoiceManager;
 
@@ -58,6 +63,8 @@ use client::bridge::voice::ClientVoiceManager;
 /// use serenity::client::bridge::gateway::{ShardManager, ShardManagerOptions};
 /// use serenity::client::EventHandler;
 /// use serenity::http;
+/// use serenity::model::user::OnlineStatus;
+/// use std::collections::HashSet;
 /// use std::sync::Arc;
 /// use std::env;
 /// use threadpool::ThreadPool;
@@ -76,11 +83,22 @@ use client::bridge::voice::ClientVoiceManager;
 /// let event_handler = Arc::new(Handler);
 /// let framework = Arc::new(Mutex::new(None));
 /// let threadpool = ThreadPool::with_name("my threadpool".to_owned(), 5);
+/// let presence = Arc::new(Mutex::new((Vec::new(), OnlineStatus::Online)));
 ///
 /// ShardManager::new(ShardManagerOptions {
 ///     data: &data,
+///     // the limit on events waiting for the threadpool, if any
+///     dispatch_queue: &Arc::new(Mutex::new(None)),
 ///     event_handler: &event_handler,
 ///     framework: &framework,
+///     // the lock shards acquire before starting, if any
+///     identify_lock: &Arc::new(Mutex::new(None)),
+///     // the dispatch types to not deserialize
+///     ignored_events: &Arc::new(Mutex::new(HashSet::new())),
+///     // the presence shards identify with
+///     presence: &presence,
+///     // the window to coalesce presence updates of a user in, if any
+///     presence_coalescing: &Arc::new(Mutex::new(None)),
 ///     // the shard index to start initiating from
 ///     shard_index: 0,
 ///     // the number of shards to initiate (this initiates 0, 1, and 2)
@@ -89,6 +107,8 @@ use client::bridge::voice::ClientVoiceManager;
 ///     shard_total: 5,
 ///     token: &token,
 ///     threadpool,
+///     // the manager receiving voice events, if any
+///     voice_gateway: &Arc::new(Mutex::new(None)),
 ///     # #[cfg(feature = "voice")]
 ///     # voice_manager: &Arc::new(Mutex::new(ClientVoiceManager::new(0, UserId(0)))),
 ///     ws_url: &gateway_url,
@@ -116,10 +136,8 @@ pub struct ShardManager {
     /// need to. Instead prefer to use methods on this struct that are provided
     /// where possible.
     pub runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
-    /// The index of the first shard to initialize, 0-indexed.
-    shard_index: u64,
-    /// The number of shards to initialize.
-    shard_init: u64,
+    /// The Ids of the shards to initialize, 0-indexed.
+    shard_ids: Vec<u64>,
     /// The total shards in use, 1-indexed.
     shard_total: u64,
     shard_queuer: Sender<ShardQueuerMessage>,
@@ -138,16 +156,22 @@ impl ShardManager {
 
         let mut shard_queuer = ShardQueuer {
             data: Arc::clone(opt.data),
+            dispatch_queue: Arc::clone(opt.dispatch_queue),
             event_handler: Arc::clone(opt.event_handler),
             #[cfg(feature = "framework")]
             framework: Arc::clone(opt.framework),
+            identify_lock: Arc::clone(opt.identify_lock),
+            ignored_events: Arc::clone(opt.ignored_events),
             last_start: None,
             manager_tx: thread_tx.clone(),
+            presence: Arc::clone(opt.presence),
+            presence_coalescing: Arc::clone(opt.presence_coalescing),
             queue: VecDeque::new(),
             runners: Arc::clone(&runners),
             rx: shard_queue_rx,
             threadpool: opt.threadpool,
             token: Arc::clone(opt.token),
+            voice_gateway: Arc::clone(opt.voice_gateway),
             #[cfg(feature = "voice")]
             voice_manager: Arc::clone(opt.voice_manager),
             ws_url: Arc::clone(opt.ws_url),
@@ -159,8 +183,7 @@ impl ShardManager {
 
         let manager = Arc::new(Mutex::new(Self {
             monitor_tx: thread_tx,
-            shard_index: opt.shard_index,
-            shard_init: opt.shard_init,
+            shard_ids: (opt.shard_index..opt.shard_index + opt.shard_init).collect(),
             shard_queuer: shard_queue_tx,
             shard_total: opt.shard_total,
             runners,
@@ -191,27 +214,46 @@ impl ShardManager {
     ///
     /// [`ShardQueuer`]: struct.ShardQueuer.html
     pub fn initialize(&mut self) -> Result<()> {
-        let shard_to = self.shard_index + self.shard_init;
-
-        for shard_id in self.shard_index..shard_to {
-            let shard_total = self.shard_total;
+        let shard_total = self.shard_total;
 
+        for shard_id in self.shard_ids.clone() {
             self.boot([ShardId(shard_id), ShardId(shard_total)]);
         }
 
         Ok(())
     }
 
+    /// Returns whether the shard manager is responsible for the given shard,
+    /// regardless of whether it has been started yet.
+    pub fn is_responsible_for(&self, shard_id: ShardId) -> bool {
+        self.shard_ids.contains(&shard_id.0)
+    }
+
     /// Sets the new sharding information for the manager.
     ///
     /// This will shutdown all existing shards.
     ///
     /// This will _not_ instantiate the new shards.
     pub fn set_shards(&mut self, index: u64, init: u64, total: u64) {
+        self.set_shard_ids(index..index + init, total);
+    }
+
+    /// Sets the Ids of the shards the manager is responsible for, out of the
+    /// given total number of shards.
+    ///
+    /// Unlike [`set_shards`], the shards need not be consecutive, allowing the
+    /// shards of a bot to be distributed across processes or machines as
+    /// needed.
+    ///
+    /// This will shutdown all existing shards.
+    ///
+    /// This will _not_ instantiate the new shards.
+    ///
+    /// [`set_shards`]: #method.set_shards
+    pub fn set_shard_ids<I: IntoIterator<Item = u64>>(&mut self, shard_ids: I, total: u64) {
         self.shutdown_all();
 
-        self.shard_index = index;
-        self.shard_init = init;
+        self.shard_ids = shard_ids.into_iter().collect();
         self.shard_total = total;
     }
 
@@ -351,14 +393,20 @@ impl Drop for ShardManager {
 
 pub struct ShardManagerOptions<'a, H: EventHandler + Send + Sync + 'static> {
     pub data: &'a Arc<Mutex<ShareMap>>,
+    pub dispatch_queue: &'a Arc<Mutex<Option<DispatchQueue>>>,
     pub event_handler: &'a Arc<H>,
     #[cfg(feature = "framework")]
     pub framework: &'a Arc<Mutex<Option<Box<Framework + Send>>>>,
+    pub identify_lock: &'a Arc<Mutex<Option<Box<IdentifyLock>>>>,
+    pub ignored_events: &'a Arc<Mutex<HashSet<EventType>>>,
+    pub presence: &'a Arc<Mutex<CurrentPresence>>,
+    pub presence_coalescing: &'a Arc<Mutex<Option<Duration>>>,
     pub shard_index: u64,
     pub shard_init: u64,
     pub shard_total: u64,
     pub threadpool: ThreadPool,
     pub token: &'a Arc<Mutex<String>>,
+    pub voice_gateway: &'a Arc<Mutex<Option<Box<VoiceGatewayManager>>>>,
     #[cfg(feature = "voice")]
     pub voice_manager: &'a Arc<Mutex<ClientVoiceManager>>,
     pub ws_url: &'a Arc<Mutex<String>>,
@@ -1,3 +1,4 @@
+use constants::OpCode;
 use gateway::InterMessage;
 use model::prelude::*;
 use super::{ShardClientMessage, ShardRunnerMessage};
@@ -154,11 +155,7 @@ impl ShardMessenger {
     /// # #[cfg(feature = "model")]
     /// shard.set_game(Some(Game::playing("Heroes of the Storm")));
     /// # #[cfg(not(feature = "model"))]
-    /// shard.set_game(Some(Game {
-    ///     kind: GameType::Playing,
-    ///     name: "Heroes of the Storm".to_owned(),
-    ///     url: None,
-    /// }));
+    /// shard.set_game(Some(Game::from(("Heroes of the Storm".to_owned(), GameType::Playing))));
     /// #     Ok(())
     /// # }
     /// #
@@ -224,6 +221,21 @@ impl ShardMessenger {
         let _ = self.send(ShardRunnerMessage::SetPresence(status, game));
     }
 
+    /// Sets the user's full presence information, displaying all of the given
+    /// activities. Pass an empty `Vec` to clear the activities.
+    ///
+    /// Refer to [`ActivityData`] for the kinds of activities, such as custom
+    /// statuses.
+    ///
+    /// [`ActivityData`]: ../../../model/gateway/struct.ActivityData.html
+    pub fn set_activities(&self, activities: Vec<ActivityData>, mut status: OnlineStatus) {
+        if status == OnlineStatus::Offline {
+            status = OnlineStatus::Invisible;
+        }
+
+        let _ = self.send(ShardRunnerMessage::SetActivities(status, activities));
+    }
+
     /// Sets the user's current online status.
     ///
     /// Note that [`Offline`] is not a valid online status, so it is
@@ -277,6 +289,34 @@ impl ShardMessenger {
         let _ = self.send(ShardRunnerMessage::Close(1000, None));
     }
 
+    /// Updates the current user's voice state in a guild, joining, moving to,
+    /// or leaving its voice channels.
+    ///
+    /// Pass `None` as the `channel_id` to leave the voice channel the user is
+    /// connected to.
+    ///
+    /// **Note**: This only updates the voice state. Connecting to the voice
+    /// server is left to the [`VoiceGatewayManager`] in use.
+    ///
+    /// [`VoiceGatewayManager`]: ../voice/trait.VoiceGatewayManager.html
+    pub fn update_voice_state(
+        &self,
+        guild_id: GuildId,
+        channel_id: Option<ChannelId>,
+        self_mute: bool,
+        self_deaf: bool,
+    ) {
+        let _ = self.tx.send(InterMessage::Json(json!({
+            "op": OpCode::VoiceStateUpdate.num(),
+            "d": {
+                "channel_id": channel_id.map(|id| id.0),
+                "guild_id": guild_id.0,
+                "self_deaf": self_deaf,
+                "self_mute": self_mute,
+            }
+        })));
+    }
+
     /// Sends a raw message over the WebSocket.
     ///
     /// The given message is not mutated in any way, and is sent as-is.
@@ -296,3 +336,38 @@ impl ShardMessenger {
         self.tx.send(InterMessage::Client(ShardClientMessage::Runner(msg)))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use gateway::InterMessage;
+    use model::id::{ChannelId, GuildId};
+    use std::sync::mpsc;
+    use super::ShardMessenger;
+
+    #[test]
+    fn update_voice_state() {
+        let (tx, rx) = mpsc::channel();
+        let messenger = ShardMessenger::new(tx);
+
+        messenger.update_voice_state(GuildId(1), Some(ChannelId(2)), true, false);
+        messenger.update_voice_state(GuildId(1), None, false, false);
+
+        match rx.try_recv() {
+            Ok(InterMessage::Json(value)) => assert_eq!(value, json!({
+                "op": 4,
+                "d": {
+                    "channel_id": 2,
+                    "guild_id": 1,
+                    "self_deaf": false,
+                    "self_mute": true,
+                }
+            })),
+            _ => panic!("no voice state update was sent"),
+        }
+
+        match rx.try_recv() {
+            Ok(InterMessage::Json(value)) => assert!(value["d"]["channel_id"].is_null()),
+            _ => panic!("no voice state update was sent"),
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use gateway::Shard;
+use gateway::{CurrentPresence, Shard};
 use internal::prelude::*;
+use model::event::EventType;
 use parking_lot::Mutex;
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     sync::{
         mpsc::{
             Receiver,
@@ -15,6 +16,8 @@ use std::{
 };
 use super::super::super::EventHandler;
 use super::{
+    DispatchQueue,
+    IdentifyLock,
     ShardId,
     ShardManagerMessage,
     ShardQueuerMessage,
@@ -26,6 +29,7 @@ use threadpool::ThreadPool;
 use typemap::ShareMap;
 use ::gateway::ConnectionStage;
 
+use client::bridge::voice::VoiceGatewayManager;
 #[cfg(feature = "voice")]
 use client::bridge::voice::ClientVoiceManager;
 #[cfg(feature = "framework")]
@@ -45,6 +49,8 @@ pub struct ShardQueuer<H: EventHandler + Send + Sync + 'static> {
     ///
     /// [`Client::data`]: ../../struct.Client.html#structfield.data
     pub data: Arc<Mutex<ShareMap>>,
+    /// A copy of the limit on events waiting for the threadpool, if set.
+    pub dispatch_queue: Arc<Mutex<Option<DispatchQueue>>>,
     /// A reference to an `EventHandler`, such as the one given to the
     /// [`Client`].
     ///
@@ -53,15 +59,27 @@ pub struct ShardQueuer<H: EventHandler + Send + Sync + 'static> {
     /// A copy of the framework
     #[cfg(feature = "framework")]
     pub framework: Arc<Mutex<Option<Box<Framework + Send>>>>,
+    /// A copy of the lock that shards acquire before they are started, if
+    /// set.
+    pub identify_lock: Arc<Mutex<Option<Box<IdentifyLock>>>>,
+    /// A copy of the dispatch types that shards do not deserialize.
+    pub ignored_events: Arc<Mutex<HashSet<EventType>>>,
     /// The instant that a shard was last started.
     ///
-    /// This is used to determine how long to wait between shard IDENTIFYs.
+    /// This is used to determine how long to wait between shard IDENTIFYs,
+    /// unless an [`identify_lock`] is set.
+    ///
+    /// [`identify_lock`]: #structfield.identify_lock
     pub last_start: Option<Instant>,
     /// A copy of the sender channel to communicate with the
     /// [`ShardManagerMonitor`].
     ///
     /// [`ShardManagerMonitor`]: struct.ShardManagerMonitor.html
     pub manager_tx: Sender<ShardManagerMessage>,
+    /// A copy of the presence that shards are to identify with.
+    pub presence: Arc<Mutex<CurrentPresence>>,
+    /// A copy of the window that shards coalesce presence updates in, if set.
+    pub presence_coalescing: Arc<Mutex<Option<Duration>>>,
     /// The shards that are queued for booting.
     ///
     /// This will typically be filled with previously failed boots.
@@ -80,6 +98,9 @@ pub struct ShardQueuer<H: EventHandler + Send + Sync + 'static> {
     pub threadpool: ThreadPool,
     /// A copy of the token to connect with.
     pub token: Arc<Mutex<String>>,
+    /// A copy of the client's voice gateway manager, receiving the voice
+    /// events of the shards.
+    pub voice_gateway: Arc<Mutex<Option<Box<VoiceGatewayManager>>>>,
     /// A copy of the client's voice manager.
     #[cfg(feature = "voice")]
     pub voice_manager: Arc<Mutex<ClientVoiceManager>>,
@@ -97,7 +118,7 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
     ///
     /// 1. Check how much time has passed since the last shard was started
     /// 2. If the amount of time is less than the ratelimit, it will sleep until
-    /// that time has passed
+    /// that time has passed, or acquire the [`identify_lock`] if one is set
     /// 3. Start the shard by ID
     ///
     /// If a [`ShardQueuerMessage::Shutdown`] is received, this will return and
@@ -109,6 +130,7 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
     /// [`ShardQueuerMessage`]: enum.ShardQueuerMessage.html
     /// [`ShardQueuerMessage::Shutdown`]: enum.ShardQueuerMessage.html#variant.Shutdown
     /// [`ShardQueuerMessage::Start`]: enum.ShardQueuerMessage.html#variant.Start
+    /// [`identify_lock`]: #structfield.identify_lock
     /// [`rx`]: #structfield.rx
     pub fn run(&mut self) {
         // The duration to timeout from reads over the Rx channel. This can be
@@ -157,7 +179,12 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
     }
 
     fn checked_start(&mut self, id: u64, total: u64) {
-        self.check_last_start();
+        let identify_lock = Arc::clone(&self.identify_lock);
+
+        match *identify_lock.lock() {
+            Some(ref mut lock) => lock.acquire([id, total]),
+            None => self.check_last_start(),
+        }
 
         if let Err(why) = self.start(id, total) {
             warn!("Err starting shard {}: {:?}", id, why);
@@ -172,19 +199,29 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
     fn start(&mut self, shard_id: u64, shard_total: u64) -> Result<()> {
         let shard_info = [shard_id, shard_total];
 
-        let shard = Shard::new(
+        let mut shard = Shard::new(
             Arc::clone(&self.ws_url),
             Arc::clone(&self.token),
             shard_info,
         )?;
 
+        {
+            let presence = self.presence.lock();
+            shard.set_activities(presence.0.clone());
+            shard.set_status(presence.1);
+        }
+
         let mut runner = ShardRunner::new(ShardRunnerOptions {
             data: Arc::clone(&self.data),
+            dispatch_queue: Arc::clone(&self.dispatch_queue),
             event_handler: Arc::clone(&self.event_handler),
             #[cfg(feature = "framework")]
             framework: Arc::clone(&self.framework),
+            ignored_events: Arc::clone(&self.ignored_events),
             manager_tx: self.manager_tx.clone(),
+            presence_coalescing: Arc::clone(&self.presence_coalescing),
             threadpool: self.threadpool.clone(),
+            voice_gateway: Arc::clone(&self.voice_gateway),
             #[cfg(feature = "voice")]
             voice_manager: Arc::clone(&self.voice_manager),
             shard,
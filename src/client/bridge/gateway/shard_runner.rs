@@ -1,9 +1,18 @@
 use gateway::{InterMessage, ReconnectType, Shard, ShardAction};
+use internal::json;
 use internal::prelude::*;
 use internal::ws_impl::{ReceiverExt, SenderExt};
-use model::event::{Event, GatewayEvent};
+use model::event::{Event, EventType, GatewayEvent, MessageCreateEvent, PresenceUpdateEvent};
 use parking_lot::Mutex;
 use serde::Deserialize;
+use serde_json;
+use model::id::{GuildId, UserId};
+use std::collections::{
+    hash_map::Entry,
+    HashMap,
+    HashSet,
+    VecDeque
+};
 use std::sync::{
     mpsc::{
         self,
@@ -13,10 +22,28 @@ use std::sync::{
     },
     Arc
 };
+use std::{
+    thread,
+    time::{Duration, Instant}
+};
+#[cfg(feature = "cache")]
+use model::guild::GuildStatus;
+#[cfg(feature = "cache")]
+use model::event::{guild_create_event, GuildCreateMap};
 use super::super::super::dispatch::{DispatchEvent, dispatch};
-use super::super::super::EventHandler;
+#[cfg(feature = "cache")]
+use super::super::super::dispatch::updates_cache;
+use super::super::super::{Context, EventHandler};
 use super::event::{ClientEvent, ShardStageUpdateEvent};
-use super::{ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage};
+use super::{
+    DispatchQueue,
+    QueueOverflow,
+    ShardClientMessage,
+    ShardId,
+    ShardManagerMessage,
+    ShardMessenger,
+    ShardRunnerMessage
+};
 use threadpool::ThreadPool;
 use typemap::ShareMap;
 use websocket::{
@@ -26,6 +53,7 @@ use websocket::{
 
 #[cfg(feature = "framework")]
 use framework::Framework;
+use super::super::voice::VoiceGatewayManager;
 #[cfg(feature = "voice")]
 use super::super::voice::ClientVoiceManager;
 
@@ -33,17 +61,29 @@ use super::super::voice::ClientVoiceManager;
 ///
 /// [`Shard`]: ../../../gateway/struct.Shard.html
 pub struct ShardRunner<H: EventHandler + Send + Sync + 'static> {
+    // the guilds of the last READY that have yet to be sent
+    #[cfg(feature = "cache")]
+    awaited_guilds: Option<AwaitedGuilds>,
+    // the latest presence update of each user being coalesced, with the
+    // instant the first of them was received
+    coalesced_presences: CoalescedPresences,
     data: Arc<Mutex<ShareMap>>,
+    dispatch_queue: Arc<Mutex<Option<DispatchQueue>>>,
     event_handler: Arc<H>,
     #[cfg(feature = "framework")]
     framework: Arc<Mutex<Option<Box<Framework + Send>>>>,
+    ignored_events: Arc<Mutex<HashSet<EventType>>>,
     manager_tx: Sender<ShardManagerMessage>,
+    // events waiting for the threadpool to have room for them
+    pending: VecDeque<DispatchEvent>,
+    presence_coalescing: Arc<Mutex<Option<Duration>>>,
     // channel to receive messages from the shard manager and dispatches
     runner_rx: Receiver<InterMessage>,
     // channel to send messages to the shard runner from the shard manager
     runner_tx: Sender<InterMessage>,
     shard: Shard,
     threadpool: ThreadPool,
+    voice_gateway: Arc<Mutex<Option<Box<VoiceGatewayManager>>>>,
     #[cfg(feature = "voice")]
     voice_manager: Arc<Mutex<ClientVoiceManager>>,
 }
@@ -56,13 +96,21 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
         Self {
             runner_rx: rx,
             runner_tx: tx,
+            #[cfg(feature = "cache")]
+            awaited_guilds: None,
+            coalesced_presences: HashMap::new(),
             data: opt.data,
+            dispatch_queue: opt.dispatch_queue,
             event_handler: opt.event_handler,
             #[cfg(feature = "framework")]
             framework: opt.framework,
+            ignored_events: opt.ignored_events,
             manager_tx: opt.manager_tx,
+            pending: VecDeque::new(),
+            presence_coalescing: opt.presence_coalescing,
             shard: opt.shard,
             threadpool: opt.threadpool,
+            voice_gateway: opt.voice_gateway,
             #[cfg(feature = "voice")]
             voice_manager: opt.voice_manager,
         }
@@ -78,18 +126,25 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
     /// 2. checks if a heartbeat should be sent to the discord Gateway, and if
     /// so, sends one.
     ///
-    /// 3. attempts to retrieve a message from the WebSocket, processing it into
+    /// 3. dispatches events waiting for the threadpool, if a
+    /// [`DispatchQueue`] is set. If it is full and applies backpressure, this
+    /// waits for 10ms before reading from the gateway.
+    ///
+    /// 4. attempts to retrieve a message from the WebSocket, processing it into
     /// a [`GatewayEvent`]. This will block for 100ms before assuming there is
     /// no message available.
     ///
-    /// 4. Checks with the [`Shard`] to determine if the gateway event is
+    /// 5. Checks with the [`Shard`] to determine if the gateway event is
     /// specifying an action to take (e.g. resuming, reconnecting, heartbeating)
     /// and then performs that action, if any.
     ///
-    /// 5. Dispatches the event via the Client.
+    /// 6. Dispatches the event via the Client, or keeps it to be coalesced if
+    /// it is a presence update and presence coalescing is enabled. Coalesced
+    /// presence updates that are due are dispatched as well.
     ///
-    /// 6. Go back to 1.
+    /// 7. Go back to 1.
     ///
+    /// [`DispatchQueue`]: struct.DispatchQueue.html
     /// [`GatewayEvent`]: ../../../model/event/enum.GatewayEvent.html
     /// [`Shard`]: ../../../gateway/struct.Shard.html
     /// [`ShardManager`]: struct.ShardManager.html
@@ -112,6 +167,13 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
                 return self.request_restart();
             }
 
+            // While backpressured, the gateway is still read from, so that
+            // heartbeat ACKs, reconnects and invalidated sessions are handled,
+            // but at a slower pace.
+            if self.flush_pending() {
+                thread::sleep(Duration::from_millis(10));
+            }
+
             let pre = self.shard.stage();
             let (event, action, successful) = self.recv_event();
             let post = self.shard.stage();
@@ -137,16 +199,99 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
                 None => {},
             }
 
-            if let Some(event) = event {
-                self.dispatch(DispatchEvent::Model(event));
+            if let Some(received) = event {
+                let event = received.event;
+
+                #[cfg(feature = "cache")]
+                self.await_guilds(&event);
+
+                match event {
+                    #[cfg(feature = "cache")]
+                    event if received.cache_only => self.enqueue(DispatchEvent::CacheUpdate(event)),
+                    Event::PresenceUpdate(event) => self.coalesce_presence(event),
+                    other => self.enqueue(DispatchEvent::Model(other)),
+                }
             }
 
+            self.flush_presences();
+
+            #[cfg(feature = "cache")]
+            self.check_cache_ready();
+
             if !successful && !self.shard.stage().is_connecting() {
                 return self.request_restart();
             }
         }
     }
 
+    /// Keeps track of the guilds sent in a READY, which are received through
+    /// GUILD_CREATEs afterwards.
+    #[cfg(feature = "cache")]
+    fn await_guilds(&mut self, event: &Event) {
+        match *event {
+            Event::Ready(ref event) => {
+                self.awaited_guilds = Some(AwaitedGuilds::new(&event.ready.guilds, Instant::now()));
+            },
+            Event::GuildCreate(ref event) => {
+                if let Some(ref mut awaited) = self.awaited_guilds {
+                    awaited.arrive(event.guild.id, Instant::now());
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Dispatches a `cache_ready` event once all guilds of the last READY have
+    /// been received, or none have been received for a while.
+    ///
+    /// The event is queued behind the GUILD_CREATEs, so that it is only
+    /// dispatched when the cache has been updated with the guilds.
+    #[cfg(feature = "cache")]
+    fn check_cache_ready(&mut self) {
+        let now = Instant::now();
+
+        if !self.awaited_guilds.as_ref().map_or(false, |awaited| awaited.is_done(now)) {
+            return;
+        }
+
+        if let Some(awaited) = self.awaited_guilds.take() {
+            if !awaited.pending.is_empty() {
+                warn!(
+                    "[ShardRunner {:?}] Timed out waiting for {} guilds",
+                    self.shard.shard_info(),
+                    awaited.pending.len(),
+                );
+            }
+
+            self.enqueue(DispatchEvent::Client(ClientEvent::CacheReady(awaited.arrived)));
+        }
+    }
+
+    /// Keeps a presence update to be dispatched once the presence coalescing
+    /// window has passed, replacing an earlier update of the same user.
+    fn coalesce_presence(&mut self, event: PresenceUpdateEvent) {
+        if self.presence_coalescing.lock().is_none() {
+            return self.enqueue(DispatchEvent::Model(Event::PresenceUpdate(event)));
+        }
+
+        coalesce_presence(&mut self.coalesced_presences, event, Instant::now());
+    }
+
+    /// Dispatches the coalesced presence updates whose window has passed, or
+    /// all of them if presence coalescing was disabled.
+    fn flush_presences(&mut self) {
+        if self.coalesced_presences.is_empty() {
+            return;
+        }
+
+        let window = *self.presence_coalescing.lock();
+        let events = take_due_presences(&mut self.coalesced_presences, window, Instant::now());
+
+        if !events.is_empty() {
+            self.enqueue(DispatchEvent::Presences(events));
+        }
+    }
+
     /// Clones the internal copy of the Sender to the shard runner.
     pub(super) fn runner_tx(&self) -> Sender<InterMessage> {
         self.runner_tx.clone()
@@ -203,14 +348,85 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
             event,
             #[cfg(feature = "framework")]
             &self.framework,
-            &self.data,
+            &self.context(),
             &self.event_handler,
-            &self.runner_tx,
             &self.threadpool,
-            self.shard.shard_info()[0],
         );
     }
 
+    // Dispatches an event, or keeps it waiting if the threadpool is busy and
+    // a dispatch queue is set.
+    fn enqueue(&mut self, event: DispatchEvent) {
+        let queue = *self.dispatch_queue.lock();
+        let queue = match queue {
+            Some(queue) => queue,
+            None => return self.dispatch(event),
+        };
+
+        self.pending.push_back(event);
+        self.flush_pending();
+
+        let dropped = queue.drop_overflow(&mut self.pending);
+
+        if dropped == 0 {
+            return;
+        }
+
+        // Backpressure only drops events once the runner fell far behind.
+        if queue.overflow == QueueOverflow::Backpressure {
+            warn!(
+                "[ShardRunner {:?}] Dispatch queue over its limit of {}; dropped {} event(s)",
+                self.shard.shard_info(),
+                queue.limit(),
+                dropped,
+            );
+        } else {
+            debug!(
+                "[ShardRunner {:?}] Dispatch queue full; dropped {} event(s)",
+                self.shard.shard_info(),
+                dropped,
+            );
+        }
+    }
+
+    // Hands waiting events to the threadpool while it has room for them.
+    //
+    // Returns whether the runner should wait before receiving more events.
+    fn flush_pending(&mut self) -> bool {
+        let queue = *self.dispatch_queue.lock();
+        let queue = match queue {
+            Some(queue) => queue,
+            None => {
+                // The queue was removed; dispatch everything still waiting.
+                while let Some(event) = self.pending.pop_front() {
+                    self.dispatch(event);
+                }
+
+                return false;
+            },
+        };
+
+        while self.threadpool.queued_count() < queue.capacity {
+            match self.pending.pop_front() {
+                Some(event) => self.dispatch(event),
+                None => break,
+            }
+        }
+
+        queue.overflow == QueueOverflow::Backpressure && self.pending.len() >= queue.capacity
+    }
+
+    // Creates a context for an event dispatched by this runner.
+    fn context(&self) -> Context {
+        Context {
+            data: Arc::clone(&self.data),
+            shard: ShardMessenger::new(self.runner_tx.clone()),
+            shard_id: self.shard.shard_info()[0],
+            #[cfg(feature = "voice")]
+            voice_manager: Arc::clone(&self.voice_manager),
+        }
+    }
+
     // Handles a received value over the shard runner rx channel.
     //
     // Returns a boolean on whether the shard runner can continue.
@@ -258,6 +474,12 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
                 ShardRunnerMessage::Message(msg) => {
                     self.shard.client.send_message(&msg).is_ok()
                 },
+                ShardRunnerMessage::SetActivities(status, activities) => {
+                    self.shard.set_activities(activities);
+                    self.shard.set_status(status);
+
+                    self.shard.update_presence().is_ok()
+                },
                 ShardRunnerMessage::SetGame(game) => {
                     // To avoid a clone of `game`, we do a little bit of
                     // trickery here:
@@ -294,33 +516,29 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
         }
     }
 
-    #[cfg(feature = "voice")]
     fn handle_voice_event(&self, event: &Event) {
+        let mut voice_gateway = self.voice_gateway.lock();
+
+        let manager = match *voice_gateway {
+            Some(ref mut manager) => manager,
+            None => return,
+        };
+
         match *event {
             Event::Ready(_) => {
-                self.voice_manager.lock().set(
+                manager.register_shard(
                     self.shard.shard_info()[0],
                     self.runner_tx.clone(),
                 );
             },
             Event::VoiceServerUpdate(ref event) => {
                 if let Some(guild_id) = event.guild_id {
-                    let mut manager = self.voice_manager.lock();
-                    let mut search = manager.get_mut(guild_id);
-
-                    if let Some(handler) = search {
-                        handler.update_server(&event.endpoint, &event.token);
-                    }
+                    manager.server_update(guild_id, &event.endpoint, &event.token);
                 }
             },
             Event::VoiceStateUpdate(ref event) => {
                 if let Some(guild_id) = event.guild_id {
-                    let mut manager = self.voice_manager.lock();
-                    let mut search = manager.get_mut(guild_id);
-
-                    if let Some(handler) = search {
-                        handler.update_state(&event.voice_state);
-                    }
+                    manager.state_update(guild_id, &event.voice_state);
                 }
             },
             _ => {},
@@ -365,10 +583,60 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
 
     /// Returns a received event, as well as whether reading the potentially
     /// present event was successful.
-    fn recv_event(&mut self) -> (Option<Event>, Option<ShardAction>, bool) {
-        let gw_event = match self.shard.client.recv_json() {
-            Ok(Some(value)) => {
-                GatewayEvent::deserialize(value).map(Some).map_err(From::from)
+    fn recv_event(&mut self) -> (Option<ReceivedEvent>, Option<ShardAction>, bool) {
+        #[cfg(feature = "cache")]
+        let mut cache_only = false;
+
+        let gw_event = match self.shard.client.recv_raw_json() {
+            Ok(Some(mut json)) => {
+                let kind = {
+                    let ignored = self.ignored_events.lock();
+
+                    // The header is only needed to look up ignored types.
+                    let header = if ignored.is_empty() {
+                        None
+                    } else {
+                        serde_json::from_slice::<DispatchHeader>(&json).ok()
+                    };
+
+                    match header {
+                        Some(header) => {
+                            if let Some(seq) = ignored_dispatch_seq(&header, &ignored) {
+                                self.shard.skip_dispatch(seq);
+
+                                return (None, None, true);
+                            }
+
+                            // The other ignored types update the cache, and
+                            // are only kept from the event handler.
+                            #[cfg(feature = "cache")]
+                            {
+                                cache_only = ignored_cache_update(&header, &ignored);
+                            }
+
+                            header.t
+                        },
+                        None => leading_event_type(&json),
+                    }
+                };
+
+                deserialize_gateway_event(&mut json, kind.clone())
+                    .map(Some)
+                    .map_err(|why| {
+                        // simd-json parses in-place, leaving the payload
+                        // garbled afterwards.
+                        #[cfg(feature = "simd-json")]
+                        warn!("Err deserializing {:?} payload: {:?}", kind, why);
+                        #[cfg(not(feature = "simd-json"))]
+                        warn!(
+                            "Err deserializing {:?} payload: {:?}; payload: {}",
+                            kind,
+                            why,
+                            String::from_utf8_lossy(&json),
+                        );
+
+                        why
+                    })
             },
             Ok(None) => Ok(None),
             Err(Error::WebSocket(WebSocketError::IoError(_))) => {
@@ -437,15 +705,18 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
             self.update_manager();
         }
 
-        #[cfg(feature = "voice")]
-        {
-            if let Ok(GatewayEvent::Dispatch(_, ref event)) = event {
-                self.handle_voice_event(&event);
-            }
+        if let Ok(GatewayEvent::Dispatch(_, ref event)) = event {
+            self.handle_voice_event(&event);
         }
 
         let event = match event {
-            Ok(GatewayEvent::Dispatch(_, event)) => Some(event),
+            Ok(GatewayEvent::Dispatch(_, event)) => {
+                Some(ReceivedEvent {
+                    event,
+                    #[cfg(feature = "cache")]
+                    cache_only,
+                })
+            },
             _ => None,
         };
 
@@ -463,9 +734,8 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
         let msg = ShardManagerMessage::Restart(shard_id);
         let _ = self.manager_tx.send(msg);
 
-        #[cfg(feature = "voice")]
-        {
-            self.voice_manager.lock().manager_remove(&shard_id.0);
+        if let Some(ref mut manager) = *self.voice_gateway.lock() {
+            manager.deregister_shard(shard_id.0);
         }
 
         Ok(())
@@ -485,12 +755,386 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
 /// [`ShardRunner::new`]: struct.ShardRunner.html#method.new
 pub struct ShardRunnerOptions<H: EventHandler + Send + Sync + 'static> {
     pub data: Arc<Mutex<ShareMap>>,
+    pub dispatch_queue: Arc<Mutex<Option<DispatchQueue>>>,
     pub event_handler: Arc<H>,
     #[cfg(feature = "framework")]
     pub framework: Arc<Mutex<Option<Box<Framework + Send>>>>,
+    pub ignored_events: Arc<Mutex<HashSet<EventType>>>,
     pub manager_tx: Sender<ShardManagerMessage>,
+    pub presence_coalescing: Arc<Mutex<Option<Duration>>>,
     pub shard: Shard,
     pub threadpool: ThreadPool,
+    pub voice_gateway: Arc<Mutex<Option<Box<VoiceGatewayManager>>>>,
     #[cfg(feature = "voice")]
     pub voice_manager: Arc<Mutex<ClientVoiceManager>>,
 }
+
+/// The parts of a gateway payload needed to decide how to handle it.
+#[derive(Deserialize)]
+struct DispatchHeader {
+    s: Option<u64>,
+    t: Option<EventType>,
+}
+
+/// The number of seconds to wait for the next guild of a READY before
+/// dispatching `cache_ready` regardless.
+#[cfg(feature = "cache")]
+const CACHE_READY_TIMEOUT: u64 = 10;
+
+/// The guilds of a READY, of which the unavailable ones are expected to be
+/// received through GUILD_CREATEs.
+#[cfg(feature = "cache")]
+struct AwaitedGuilds {
+    arrived: Vec<GuildId>,
+    last_arrival: Instant,
+    pending: HashSet<GuildId>,
+}
+
+#[cfg(feature = "cache")]
+impl AwaitedGuilds {
+    fn new(guilds: &[GuildStatus], now: Instant) -> Self {
+        let mut awaited = AwaitedGuilds {
+            arrived: Vec::new(),
+            last_arrival: now,
+            pending: HashSet::new(),
+        };
+
+        for guild in guilds {
+            match *guild {
+                GuildStatus::OnlineGuild(ref guild) => awaited.arrived.push(guild.id),
+                GuildStatus::OnlinePartialGuild(ref guild) => awaited.arrived.push(guild.id),
+                GuildStatus::Offline(ref guild) => {
+                    awaited.pending.insert(guild.id);
+                },
+            }
+        }
+
+        awaited
+    }
+
+    fn arrive(&mut self, guild_id: GuildId, now: Instant) {
+        if self.pending.remove(&guild_id) {
+            self.arrived.push(guild_id);
+            self.last_arrival = now;
+        }
+    }
+
+    /// Whether all guilds arrived, or no guild arrived for too long.
+    fn is_done(&self, now: Instant) -> bool {
+        self.pending.is_empty()
+            || now >= self.last_arrival + Duration::from_secs(CACHE_READY_TIMEOUT)
+    }
+}
+
+/// An event received by a shard.
+struct ReceivedEvent {
+    event: Event,
+    // whether the event is of an ignored type, and only used to update the
+    // cache
+    #[cfg(feature = "cache")]
+    cache_only: bool,
+}
+
+/// A dispatch of a known event type.
+#[derive(Deserialize)]
+struct Dispatch<T> {
+    s: u64,
+    d: T,
+}
+
+/// The latest presence update of each user in a guild, along with when the
+/// first of them was received.
+type CoalescedPresences = HashMap<(Option<GuildId>, UserId), (Instant, PresenceUpdateEvent)>;
+
+/// Keeps a presence update, replacing an earlier update of the same user in
+/// the same guild while keeping when that was received.
+fn coalesce_presence(
+    coalesced: &mut CoalescedPresences,
+    mut event: PresenceUpdateEvent,
+    now: Instant,
+) {
+    match coalesced.entry((event.guild_id, event.presence.user_id)) {
+        Entry::Occupied(mut entry) => {
+            let latest = &mut entry.get_mut().1;
+
+            // Discord only sends the user when it changed.
+            if event.presence.user.is_none() {
+                event.presence.user = latest.presence.user.take();
+            }
+
+            *latest = event;
+        },
+        Entry::Vacant(entry) => {
+            entry.insert((now, event));
+        },
+    }
+}
+
+/// Removes the coalesced presence updates whose window has passed, or all of
+/// them without a window.
+fn take_due_presences(
+    coalesced: &mut CoalescedPresences,
+    window: Option<Duration>,
+    now: Instant,
+) -> Vec<PresenceUpdateEvent> {
+    let due = coalesced
+        .iter()
+        .filter(|&(_, entry)| window.map_or(true, |window| now >= entry.0 + window))
+        .map(|(key, _)| *key)
+        .collect::<Vec<_>>();
+
+    due.iter()
+        .filter_map(|key| coalesced.remove(key))
+        .map(|(_, event)| event)
+        .collect()
+}
+
+/// Returns the sequence of the payload if it is a dispatch of an ignored event
+/// type that can be dropped.
+fn ignored_dispatch_seq(header: &DispatchHeader, ignored: &HashSet<EventType>) -> Option<u64> {
+    match (header.s, header.t.as_ref()) {
+        // The shard relies on these to maintain its session.
+        (_, Some(&EventType::Ready)) | (_, Some(&EventType::Resumed)) => None,
+        (Some(seq), Some(kind)) if ignored.contains(kind) && !updates_cache(kind) => Some(seq),
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "cache"))]
+fn updates_cache(_: &EventType) -> bool {
+    false
+}
+
+/// Returns whether the payload is a dispatch of an ignored event type that is
+/// only used to update the cache.
+#[cfg(feature = "cache")]
+fn ignored_cache_update(header: &DispatchHeader, ignored: &HashSet<EventType>) -> bool {
+    match header.t {
+        Some(EventType::Ready) | Some(EventType::Resumed) => false,
+        Some(ref kind) => ignored.contains(kind) && updates_cache(kind),
+        None => false,
+    }
+}
+
+/// Reads the type of a dispatch from the start of the payload, where the
+/// gateway puts it.
+///
+/// This is much cheaper than deserializing the header. Payloads ordered
+/// differently are deserialized through an intermediate `Value` instead.
+fn leading_event_type(json: &[u8]) -> Option<EventType> {
+    const PREFIX: &[u8] = b"{\"t\":\"";
+
+    if !json.starts_with(PREFIX) {
+        return None;
+    }
+
+    let len = json[PREFIX.len()..].iter().position(|&byte| byte == b'"')?;
+
+    // The name including its quotes.
+    serde_json::from_slice(&json[PREFIX.len() - 1..PREFIX.len() + len + 1]).ok()
+}
+
+/// Deserializes a gateway payload of the given dispatch type, if it is one.
+///
+/// The most frequent events are deserialized straight from the JSON, rather
+/// than building an intermediate `Value` first. The arrays of a guild that the
+/// cache does not store are passed over while parsing.
+fn deserialize_gateway_event(json: &mut [u8], kind: Option<EventType>) -> Result<GatewayEvent> {
+    match kind {
+        Some(EventType::MessageCreate) => {
+            let dispatch = json::from_slice::<Dispatch<MessageCreateEvent>>(json)?;
+
+            Ok(GatewayEvent::Dispatch(dispatch.s, Event::MessageCreate(dispatch.d)))
+        },
+        #[cfg(feature = "cache")]
+        Some(EventType::GuildCreate) => {
+            let dispatch = json::from_slice::<Dispatch<GuildCreateMap>>(json)?;
+
+            Ok(GatewayEvent::Dispatch(dispatch.s, guild_create_event(dispatch.d.0)?))
+        },
+        Some(EventType::PresenceUpdate) => {
+            let dispatch = json::from_slice::<Dispatch<PresenceUpdateEvent>>(json)?;
+
+            Ok(GatewayEvent::Dispatch(dispatch.s, Event::PresenceUpdate(dispatch.d)))
+        },
+        _ => {
+            let value = json::from_slice::<Value>(json)?;
+
+            GatewayEvent::deserialize(value).map_err(From::from)
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::event::{EventType, PresenceUpdateEvent};
+    use model::user::OnlineStatus;
+    use serde_json;
+    use std::collections::{HashMap, HashSet};
+    use std::time::{Duration, Instant};
+    use super::{
+        DispatchHeader,
+        coalesce_presence,
+        ignored_dispatch_seq,
+        leading_event_type,
+        take_due_presences
+    };
+
+    fn presence(guild_id: u64, user_id: u64, status: &str, with_user: bool) -> PresenceUpdateEvent {
+        let user = if with_user {
+            json!({"id": user_id.to_string(), "username": "fake", "discriminator": "1234", "avatar": null})
+        } else {
+            json!({"id": user_id.to_string()})
+        };
+
+        serde_json::from_value(json!({
+            "guild_id": guild_id.to_string(),
+            "user": user,
+            "status": status,
+            "game": null,
+        })).unwrap()
+    }
+
+    #[test]
+    fn leading_type() {
+        let json = br#"{"t":"MESSAGE_CREATE","s":2,"op":0,"d":{"t":"READY"}}"#;
+        assert_eq!(leading_event_type(json), Some(EventType::MessageCreate));
+
+        let json = br#"{"t":"SOMETHING_NEW","s":2,"op":0,"d":{}}"#;
+        assert_eq!(
+            leading_event_type(json),
+            Some(EventType::Other("SOMETHING_NEW".to_string())),
+        );
+    }
+
+    #[test]
+    fn leading_type_elsewhere() {
+        // The type is only read when it is the first key.
+        let json = br#"{"op":0,"d":{"t":"READY"},"t":"MESSAGE_CREATE"}"#;
+        assert_eq!(leading_event_type(json), None);
+
+        assert_eq!(leading_event_type(br#"{"t":null,"s":null,"op":11}"#), None);
+        assert_eq!(leading_event_type(br#"{"t":"MESSAGE_CRE"#), None);
+        assert_eq!(leading_event_type(b""), None);
+    }
+
+    #[test]
+    fn ignored_seq() {
+        let ignored = vec![EventType::TypingStart, EventType::Ready]
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        let header = serde_json::from_str::<DispatchHeader>(r#"{"t":"TYPING_START","s":5}"#)
+            .unwrap();
+        assert_eq!(ignored_dispatch_seq(&header, &ignored), Some(5));
+
+        // The shard relies on READY to maintain its session.
+        let header = serde_json::from_str::<DispatchHeader>(r#"{"t":"READY","s":1}"#).unwrap();
+        assert_eq!(ignored_dispatch_seq(&header, &ignored), None);
+
+        let header = serde_json::from_str::<DispatchHeader>(r#"{"t":"MESSAGE_CREATE","s":6}"#)
+            .unwrap();
+        assert_eq!(ignored_dispatch_seq(&header, &ignored), None);
+
+        let header = serde_json::from_str::<DispatchHeader>(r#"{"t":null,"s":null}"#).unwrap();
+        assert_eq!(ignored_dispatch_seq(&header, &HashSet::new()), None);
+    }
+
+    #[test]
+    fn coalesces_per_user_and_guild() {
+        let mut coalesced = HashMap::new();
+        let start = Instant::now();
+
+        coalesce_presence(&mut coalesced, presence(1, 2, "online", true), start);
+        coalesce_presence(&mut coalesced, presence(1, 3, "online", false), start);
+        coalesce_presence(&mut coalesced, presence(4, 2, "online", false), start);
+        assert_eq!(coalesced.len(), 3);
+
+        let later = start + Duration::from_secs(1);
+        coalesce_presence(&mut coalesced, presence(1, 2, "idle", false), later);
+        assert_eq!(coalesced.len(), 3);
+
+        let &(received, ref latest) = &coalesced[&(Some(1.into()), 2.into())];
+        // The window starts with the first update of the user.
+        assert_eq!(received, start);
+        assert_eq!(latest.presence.status, OnlineStatus::Idle);
+        // The user is kept when the later update does not contain it.
+        assert_eq!(latest.presence.user.as_ref().unwrap().read().name, "fake");
+    }
+
+    #[test]
+    fn takes_due_presences() {
+        let mut coalesced = HashMap::new();
+        let start = Instant::now();
+        let window = Some(Duration::from_secs(2));
+
+        coalesce_presence(&mut coalesced, presence(1, 2, "online", false), start);
+        coalesce_presence(
+            &mut coalesced,
+            presence(1, 3, "online", false),
+            start + Duration::from_secs(1),
+        );
+
+        assert!(take_due_presences(&mut coalesced, window, start).is_empty());
+        assert_eq!(coalesced.len(), 2);
+
+        let due = take_due_presences(&mut coalesced, window, start + Duration::from_secs(2));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].presence.user_id, 2);
+        assert_eq!(coalesced.len(), 1);
+
+        // Without a window, everything is due.
+        let due = take_due_presences(&mut coalesced, None, start);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].presence.user_id, 3);
+        assert!(coalesced.is_empty());
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn awaits_unavailable_guilds() {
+        use model::guild::{GuildStatus, GuildUnavailable};
+        use model::id::GuildId;
+        use super::AwaitedGuilds;
+
+        let offline = |id| GuildStatus::Offline(GuildUnavailable {
+            id: GuildId(id),
+            unavailable: true,
+        });
+        let start = Instant::now();
+
+        let mut awaited = AwaitedGuilds::new(&[offline(1), offline(2)], start);
+        assert!(!awaited.is_done(start));
+
+        // Guilds not part of the READY are not awaited.
+        awaited.arrive(GuildId(3), start + Duration::from_secs(1));
+        awaited.arrive(GuildId(1), start + Duration::from_secs(1));
+        assert_eq!(awaited.arrived, vec![GuildId(1)]);
+        assert!(!awaited.is_done(start + Duration::from_secs(10)));
+
+        awaited.arrive(GuildId(2), start + Duration::from_secs(2));
+        assert_eq!(awaited.arrived, vec![GuildId(1), GuildId(2)]);
+        assert!(awaited.is_done(start + Duration::from_secs(2)));
+
+        assert!(AwaitedGuilds::new(&[], start).is_done(start));
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn stops_awaiting_guilds_after_timeout() {
+        use model::guild::{GuildStatus, GuildUnavailable};
+        use model::id::GuildId;
+        use super::AwaitedGuilds;
+
+        let start = Instant::now();
+        let guilds = [GuildStatus::Offline(GuildUnavailable {
+            id: GuildId(1),
+            unavailable: true,
+        })];
+        let awaited = AwaitedGuilds::new(&guilds, start);
+
+        assert!(!awaited.is_done(start + Duration::from_secs(9)));
+        assert!(awaited.is_done(start + Duration::from_secs(10)));
+        assert!(awaited.arrived.is_empty());
+    }
+}
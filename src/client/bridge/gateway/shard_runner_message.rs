@@ -1,5 +1,5 @@
 use model::{
-    gateway::Game,
+    gateway::{ActivityData, Game},
     user::OnlineStatus,
     id::GuildId
 };
@@ -38,6 +38,9 @@ pub enum ShardRunnerMessage {
     Close(u16, Option<String>),
     /// Indicates that the client is to send a custom WebSocket message.
     Message(OwnedMessage),
+    /// Indicates that the client is to update the shard's presence in its
+    /// entirity, replacing all current activities.
+    SetActivities(OnlineStatus, Vec<ActivityData>),
     /// Indicates that the client is to update the shard's presence's game.
     SetGame(Option<Game>),
     /// Indicates that the client is to update the shard's presence in its
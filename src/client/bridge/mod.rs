@@ -8,6 +8,4 @@
 //! [`client`]: ../
 
 pub mod gateway;
-
-#[cfg(feature = "voice")]
 pub mod voice;
@@ -1,16 +1,82 @@
 use gateway::InterMessage;
+use parking_lot::Mutex;
+use std::sync::{mpsc::Sender as MpscSender, Arc};
+use ::model::id::{GuildId, UserId};
+use ::model::voice::VoiceState;
+
+#[cfg(feature = "voice")]
 use std::collections::HashMap;
-use std::sync::mpsc::Sender as MpscSender;
-use ::model::id::{ChannelId, GuildId, UserId};
+#[cfg(feature = "voice")]
+use ::model::id::ChannelId;
+#[cfg(feature = "voice")]
 use ::voice::{Handler, Manager};
+#[cfg(feature = "voice")]
 use ::utils;
 
+/// An interface for voice implementations, receiving the voice events of all
+/// shards.
+///
+/// This allows voice to be implemented outside of the library, while the
+/// [`Client`] handles the connections to the gateway. The manager to use is
+/// set through [`Client::with_voice_gateway`]. With the `voice` feature
+/// enabled, the [`ClientVoiceManager`] is used by default.
+///
+/// Voice states are updated by sending them over a shard's sender, e.g.
+/// through [`ShardMessenger::update_voice_state`].
+///
+/// [`Client`]: ../../struct.Client.html
+/// [`Client::with_voice_gateway`]: ../../struct.Client.html#method.with_voice_gateway
+/// [`ClientVoiceManager`]: struct.ClientVoiceManager.html
+/// [`ShardMessenger::update_voice_state`]: ../gateway/struct.ShardMessenger.html#method.update_voice_state
+pub trait VoiceGatewayManager: Send {
+    /// Called before the shards are started, with the total number of shards
+    /// and the ID of the current user.
+    fn initialise(&mut self, shard_count: u64, user_id: UserId);
+
+    /// Called when a shard is ready, with a sender for messages to it.
+    fn register_shard(&mut self, shard_id: u64, sender: MpscSender<InterMessage>);
+
+    /// Called when a shard is restarted, after which its sender is no longer
+    /// valid.
+    fn deregister_shard(&mut self, shard_id: u64);
+
+    /// Called when a voice server was assigned to or changed in a guild.
+    fn server_update(&mut self, guild_id: GuildId, endpoint: &Option<String>, token: &str);
+
+    /// Called when the voice state of a user in a guild was updated.
+    fn state_update(&mut self, guild_id: GuildId, voice_state: &VoiceState);
+}
+
+impl<T: VoiceGatewayManager> VoiceGatewayManager for Arc<Mutex<T>> {
+    fn initialise(&mut self, shard_count: u64, user_id: UserId) {
+        self.lock().initialise(shard_count, user_id);
+    }
+
+    fn register_shard(&mut self, shard_id: u64, sender: MpscSender<InterMessage>) {
+        self.lock().register_shard(shard_id, sender);
+    }
+
+    fn deregister_shard(&mut self, shard_id: u64) {
+        self.lock().deregister_shard(shard_id);
+    }
+
+    fn server_update(&mut self, guild_id: GuildId, endpoint: &Option<String>, token: &str) {
+        self.lock().server_update(guild_id, endpoint, token);
+    }
+
+    fn state_update(&mut self, guild_id: GuildId, voice_state: &VoiceState) {
+        self.lock().state_update(guild_id, voice_state);
+    }
+}
+
+#[cfg(feature = "voice")]
 pub struct ClientVoiceManager {
     managers: HashMap<u64, Manager>,
     shard_count: u64,
     user_id: UserId,
 }
 
+#[cfg(feature = "voice")]
 impl ClientVoiceManager {
     pub fn new(shard_count: u64, user_id: UserId) -> Self {
         Self {
@@ -115,3 +181,101 @@ impl ClientVoiceManager {
         (guild_id, shard_id)
     }
 }
+
+#[cfg(feature = "voice")]
+impl VoiceGatewayManager for ClientVoiceManager {
+    fn initialise(&mut self, shard_count: u64, user_id: UserId) {
+        self.set_shard_count(shard_count);
+        self.set_user_id(user_id);
+    }
+
+    fn register_shard(&mut self, shard_id: u64, sender: MpscSender<InterMessage>) {
+        self.set(shard_id, sender);
+    }
+
+    fn deregister_shard(&mut self, shard_id: u64) {
+        self.manager_remove(&shard_id);
+    }
+
+    fn server_update(&mut self, guild_id: GuildId, endpoint: &Option<String>, token: &str) {
+        if let Some(handler) = self.get_mut(guild_id) {
+            handler.update_server(endpoint, token);
+        }
+    }
+
+    fn state_update(&mut self, guild_id: GuildId, voice_state: &VoiceState) {
+        if let Some(handler) = self.get_mut(guild_id) {
+            handler.update_state(voice_state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use gateway::InterMessage;
+    use model::id::{GuildId, UserId};
+    use model::voice::VoiceState;
+    use parking_lot::Mutex;
+    use std::sync::{mpsc::{self, Sender}, Arc};
+    use super::VoiceGatewayManager;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+    }
+
+    impl VoiceGatewayManager for Recorder {
+        fn initialise(&mut self, shard_count: u64, user_id: UserId) {
+            self.events.push(format!("initialise {} {}", shard_count, user_id));
+        }
+
+        fn register_shard(&mut self, shard_id: u64, _: Sender<InterMessage>) {
+            self.events.push(format!("register {}", shard_id));
+        }
+
+        fn deregister_shard(&mut self, shard_id: u64) {
+            self.events.push(format!("deregister {}", shard_id));
+        }
+
+        fn server_update(&mut self, guild_id: GuildId, endpoint: &Option<String>, token: &str) {
+            self.events.push(format!("server {} {:?} {}", guild_id, endpoint, token));
+        }
+
+        fn state_update(&mut self, guild_id: GuildId, _: &VoiceState) {
+            self.events.push(format!("state {}", guild_id));
+        }
+    }
+
+    #[test]
+    fn shared_manager_receives_events() {
+        let recorder = Arc::new(Mutex::new(Recorder::default()));
+        let mut manager: Box<VoiceGatewayManager> = Box::new(Arc::clone(&recorder));
+        let (tx, _rx) = mpsc::channel();
+
+        manager.initialise(2, UserId(1));
+        manager.register_shard(0, tx);
+        manager.server_update(GuildId(3), &Some("rotterdam.discord.media".to_string()), "token");
+        manager.deregister_shard(0);
+
+        assert_eq!(recorder.lock().events, vec![
+            "initialise 2 1".to_string(),
+            "register 0".to_string(),
+            "server 3 Some(\"rotterdam.discord.media\") token".to_string(),
+            "deregister 0".to_string(),
+        ]);
+    }
+
+    #[cfg(feature = "voice")]
+    #[test]
+    fn guilds_are_assigned_to_their_shards() {
+        use super::ClientVoiceManager;
+
+        let guild_id = GuildId(81384788765712384);
+
+        let mut manager = ClientVoiceManager::new(17, UserId(1));
+        assert_eq!(manager.manager_info(guild_id), (guild_id, 7));
+
+        manager.initialise(1, UserId(1));
+        assert_eq!(manager.manager_info(guild_id).1, 0);
+    }
+}
@@ -1,11 +1,8 @@
 use client::bridge::gateway::ShardMessenger;
-use gateway::InterMessage;
 use model::prelude::*;
 use parking_lot::Mutex;
-use std::sync::{
-    mpsc::Sender,
-    Arc
-};
+use std::sync::Arc;
+use super::{data, TypeMapKey};
 use typemap::ShareMap;
 
 #[cfg(feature = "builder")]
@@ -18,6 +15,14 @@ use super::CACHE;
 use {Result, http};
 #[cfg(feature = "builder")]
 use utils::{self, VecMap};
+#[cfg(feature = "voice")]
+use super::bridge::voice::ClientVoiceManager;
+#[cfg(feature = "collector")]
+use collector;
+#[cfg(feature = "collector")]
+use model::event::Event;
+#[cfg(feature = "collector")]
+use std::time::Duration;
 
 /// The context is a general utility struct provided on event dispatches, which
 /// helps with dealing with the current "context" of the event dispatch.
@@ -45,20 +50,164 @@ pub struct Context {
     pub shard: ShardMessenger,
     /// The ID of the shard this context is related to.
     pub shard_id: u64,
+    /// A clone of [`Client::voice_manager`], used to join, leave, and play
+    /// audio in the voice channels of a guild.
+    ///
+    /// # Examples
+    ///
+    /// Playing audio with the voice handler of the guild a message was sent
+    /// in:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// use serenity::voice;
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {
+    ///     fn message(&self, ctx: Context, msg: Message) {
+    ///         let guild_id = match msg.guild_id {
+    ///             Some(guild_id) => guild_id,
+    ///             None => return,
+    ///         };
+    ///
+    ///         if let Some(handler) = ctx.voice_manager.lock().get_mut(guild_id) {
+    ///             if let Ok(source) = voice::ytdl("https://www.youtube.com/watch?v=dQw4w9WgXcQ") {
+    ///                 handler.play(source);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Client::voice_manager`]: struct.Client.html#structfield.voice_manager
+    #[cfg(feature = "voice")]
+    pub voice_manager: Arc<Mutex<ClientVoiceManager>>,
 }
 
 impl Context {
-    /// Create a new Context to be passed to an event handler.
-    pub(crate) fn new(
-        data: Arc<Mutex<ShareMap>>,
-        runner_tx: Sender<InterMessage>,
-        shard_id: u64,
-    ) -> Context {
-        Context {
-            shard: ShardMessenger::new(runner_tx),
-            shard_id,
-            data,
-        }
+    /// Inserts a value into the [`data`] under the given key, returning the
+    /// value previously stored under it, if any.
+    ///
+    /// Keys can be declared with the [`type_map_key`] macro.
+    ///
+    /// [`data`]: #structfield.data
+    /// [`type_map_key`]: ../macro.type_map_key.html
+    pub fn data_insert<K>(&self, value: K::Value) -> Option<K::Value>
+        where K: TypeMapKey, K::Value: Send + Sync {
+        data::insert::<K>(&self.data, value)
+    }
+
+    /// Retrieves a clone of the value stored in the [`data`] under the given
+    /// key, if there is one.
+    ///
+    /// The data is only locked while the value is cloned. Values declared as
+    /// `locked` through the [`type_map_key`] macro are shared, so that they
+    /// can be used without keeping the whole of the data locked.
+    ///
+    /// # Examples
+    ///
+    /// Count the messages received per channel:
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate serenity;
+    /// #
+    /// use serenity::model::prelude::*;
+    /// use serenity::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// type_map_key!(MessageCount: locked HashMap<ChannelId, u64>);
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {
+    ///     fn message(&self, ctx: Context, msg: Message) {
+    ///         if let Some(counts) = ctx.data_get::<MessageCount>() {
+    ///             *counts.write().entry(msg.channel_id).or_insert(0) += 1;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// let mut client = Client::new("token", Handler).unwrap();
+    /// client.data_insert::<MessageCount>(Default::default());
+    ///
+    /// client.start().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`data`]: #structfield.data
+    /// [`type_map_key`]: ../macro.type_map_key.html
+    pub fn data_get<K>(&self) -> Option<K::Value>
+        where K: TypeMapKey, K::Value: Clone + Send + Sync {
+        data::get::<K>(&self.data)
+    }
+
+    /// Updates the value stored in the [`data`] under the given key, if there
+    /// is one, returning the result of the closure.
+    ///
+    /// The whole of the data is locked while the closure runs.
+    ///
+    /// [`data`]: #structfield.data
+    pub fn data_update<K, F, R>(&self, f: F) -> Option<R>
+        where K: TypeMapKey, K::Value: Send + Sync, F: FnOnce(&mut K::Value) -> R {
+        data::update::<K, F, R>(&self.data, f)
+    }
+
+    /// Blocks the current thread until an event for which the `filter` returns
+    /// `true` was received, or the `timeout` elapsed.
+    ///
+    /// This complements the [collectors] for kinds of events that they do not
+    /// cover. The event is offered before the cache was updated with it.
+    ///
+    /// **Note**: Requires the `collector` feature.
+    ///
+    /// As the event handler runs on a threadpool, waiting blocks one
+    /// of its threads. Waiting for an event that needs another event handler
+    /// call to occur, while all other threads of the threadpool wait as well,
+    /// ends with the timeout.
+    ///
+    /// # Examples
+    ///
+    /// Wait for the author of a message to join a voice channel:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::prelude::*;
+    /// # use serenity::prelude::*;
+    /// #
+    /// use std::time::Duration;
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {
+    ///     fn message(&self, ctx: Context, msg: Message) {
+    ///         if msg.content != "!follow" {
+    ///             return;
+    ///         }
+    ///
+    ///         let author_id = msg.author.id;
+    ///         let joined = ctx.wait_for(Duration::from_secs(60), move |event| match *event {
+    ///             Event::VoiceStateUpdate(ref event) => {
+    ///                 event.voice_state.user_id == author_id
+    ///                     && event.voice_state.channel_id.is_some()
+    ///             },
+    ///             _ => false,
+    ///         });
+    ///
+    ///         if joined.is_none() {
+    ///             let _ = msg.channel_id.say("You did not join a voice channel.");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [collectors]: ../collector/index.html
+    #[cfg(feature = "collector")]
+    pub fn wait_for<F>(&self, timeout: Duration, filter: F) -> Option<Event>
+        where F: Fn(&Event) -> bool + Send + Sync + 'static {
+        collector::wait_for(timeout, filter)
     }
 
     /// Edits the current user's profile settings.
@@ -424,6 +573,47 @@ impl Context {
         self.shard.set_presence(game, status);
     }
 
+    /// Sets the current user's presence to display all of the given
+    /// activities, such as a custom status next to a game being played.
+    ///
+    /// Pass an empty `Vec` to clear the activities. Refer to [`ActivityData`]
+    /// for the kinds of activities.
+    ///
+    /// # Examples
+    ///
+    /// Setting a custom status and watching a stream:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {
+    ///     fn message(&self, ctx: Context, msg: Message) {
+    ///         use serenity::model::gateway::ActivityData;
+    ///         use serenity::model::user::OnlineStatus;
+    ///
+    ///         if msg.content == "!watch" {
+    ///             ctx.set_activities(vec![
+    ///                 ActivityData::custom("Movie night").emoji('🍿'),
+    ///                 ActivityData::watching("a movie"),
+    ///             ], OnlineStatus::Online);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut client = Client::new("token", Handler).unwrap();
+    ///
+    /// client.start().unwrap();
+    /// ```
+    ///
+    /// [`ActivityData`]: ../model/gateway/struct.ActivityData.html
+    #[inline]
+    pub fn set_activities(&self, activities: Vec<ActivityData>, status: OnlineStatus) {
+        self.shard.set_activities(activities, status);
+    }
+
     /// Disconnects the shard from the websocket, essentially "quiting" it.
     /// Note however that this will only exit the one which the `Context` was given.
     /// If it's just one shard that's on, then serenity will stop any further actions
@@ -435,3 +625,33 @@ impl Context {
         self.shard.shutdown_clean();
     }
 }
+
+#[cfg(all(test, feature = "voice"))]
+mod test {
+    use client::bridge::gateway::ShardMessenger;
+    use client::bridge::voice::ClientVoiceManager;
+    use model::id::{ChannelId, GuildId, UserId};
+    use parking_lot::Mutex;
+    use std::sync::{mpsc, Arc};
+    use super::Context;
+    use typemap::ShareMap;
+
+    #[test]
+    fn clones_share_the_voice_manager() {
+        let (tx, _rx) = mpsc::channel();
+
+        let mut voice_manager = ClientVoiceManager::new(1, UserId(1));
+        voice_manager.set(0, tx.clone());
+
+        let context = Context {
+            data: Arc::new(Mutex::new(ShareMap::custom())),
+            shard: ShardMessenger::new(tx),
+            shard_id: 0,
+            voice_manager: Arc::new(Mutex::new(voice_manager)),
+        };
+        let clone = context.clone();
+
+        assert!(context.voice_manager.lock().join(GuildId(2), ChannelId(3)).is_some());
+        assert!(clone.voice_manager.lock().get(GuildId(2)).is_some());
+    }
+}
@@ -0,0 +1,126 @@
+//! Shortcuts for working with the data shared between a [`Client`] and its
+//! [`Context`]s.
+//!
+//! [`Client`]: struct.Client.html
+//! [`Context`]: struct.Context.html
+
+use parking_lot::Mutex;
+use typemap::{Key, ShareMap};
+
+/// Declares a key to store a value in [`Client::data`] under.
+///
+/// A value declared as `locked` is stored in an `Arc<RwLock<T>>`, so that it
+/// can be read or written without keeping the whole of the data locked, by
+/// retrieving it through [`Context::data_get`].
+///
+/// # Examples
+///
+/// ```rust
+/// #[macro_use] extern crate serenity;
+///
+/// use std::collections::HashMap;
+///
+/// type_map_key!(pub CommandCounter: locked HashMap<String, u64>);
+/// type_map_key!(Prefix: String);
+///
+/// # fn main() {}
+/// ```
+///
+/// [`Client::data`]: client/struct.Client.html#structfield.data
+/// [`Context::data_get`]: client/struct.Context.html#method.data_get
+#[macro_export]
+macro_rules! type_map_key {
+    ($(#[$attr:meta])* pub $name:ident: locked $value:ty) => {
+        $(#[$attr])*
+        pub struct $name;
+
+        impl $crate::client::TypeMapKey for $name {
+            type Value = ::std::sync::Arc<$crate::prelude::RwLock<$value>>;
+        }
+    };
+    ($(#[$attr:meta])* pub $name:ident: $value:ty) => {
+        $(#[$attr])*
+        pub struct $name;
+
+        impl $crate::client::TypeMapKey for $name {
+            type Value = $value;
+        }
+    };
+    ($(#[$attr:meta])* $name:ident: locked $value:ty) => {
+        $(#[$attr])*
+        struct $name;
+
+        impl $crate::client::TypeMapKey for $name {
+            type Value = ::std::sync::Arc<$crate::prelude::RwLock<$value>>;
+        }
+    };
+    ($(#[$attr:meta])* $name:ident: $value:ty) => {
+        $(#[$attr])*
+        struct $name;
+
+        impl $crate::client::TypeMapKey for $name {
+            type Value = $value;
+        }
+    };
+}
+
+pub(crate) fn insert<K>(data: &Mutex<ShareMap>, value: K::Value) -> Option<K::Value>
+    where K: Key, K::Value: Send + Sync {
+    data.lock().insert::<K>(value)
+}
+
+pub(crate) fn get<K>(data: &Mutex<ShareMap>) -> Option<K::Value>
+    where K: Key, K::Value: Clone + Send + Sync {
+    data.lock().get::<K>().cloned()
+}
+
+pub(crate) fn update<K, F, R>(data: &Mutex<ShareMap>, f: F) -> Option<R>
+    where K: Key, K::Value: Send + Sync, F: FnOnce(&mut K::Value) -> R {
+    data.lock().get_mut::<K>().map(f)
+}
+
+#[cfg(test)]
+mod test {
+    use parking_lot::Mutex;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use typemap::ShareMap;
+    use super::{get, insert, update};
+
+    type_map_key!(Prefix: String);
+    type_map_key!(Counts: locked HashMap<u64, u64>);
+
+    #[test]
+    fn insert_get_and_update() {
+        let data = Mutex::new(ShareMap::custom());
+
+        assert!(get::<Prefix>(&data).is_none());
+        assert!(update::<Prefix, _, _>(&data, |prefix| prefix.push('~')).is_none());
+
+        assert!(insert::<Prefix>(&data, "~".to_string()).is_none());
+        assert_eq!(get::<Prefix>(&data).unwrap(), "~");
+
+        let len = update::<Prefix, _, _>(&data, |prefix| {
+            prefix.push('~');
+
+            prefix.len()
+        });
+        assert_eq!(len, Some(2));
+
+        assert_eq!(insert::<Prefix>(&data, "!".to_string()).unwrap(), "~~");
+        assert_eq!(get::<Prefix>(&data).unwrap(), "!");
+    }
+
+    #[test]
+    fn locked_values_are_shared() {
+        let data = Mutex::new(ShareMap::custom());
+        insert::<Counts>(&data, Default::default());
+
+        let counts = get::<Counts>(&data).unwrap();
+        *counts.write().entry(1).or_insert(0) += 1;
+
+        let stored = get::<Counts>(&data).unwrap();
+        assert!(Arc::ptr_eq(&counts, &stored));
+        assert_eq!(stored.read()[&1], 1);
+    }
+}
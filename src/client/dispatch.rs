@@ -1,29 +1,29 @@
-use gateway::InterMessage;
 use model::{
     channel::{Channel, Message},
-    event::Event,
+    event::{Event, PresenceUpdateEvent},
     guild::Member,
 };
 use std::sync::Arc;
-use parking_lot::Mutex;
 use super::{
     bridge::gateway::event::ClientEvent,
     event_handler::EventHandler,
     Context
 };
-use std::sync::mpsc::Sender;
 use threadpool::ThreadPool;
-use typemap::ShareMap;
 
+#[cfg(feature = "collector")]
+use collector;
 #[cfg(feature = "framework")]
 use framework::Framework;
-#[cfg(feature = "cache")]
-use model::id::GuildId;
+#[cfg(feature = "framework")]
+use parking_lot::Mutex;
 #[cfg(feature = "cache")]
 use std::time::Duration;
 
 #[cfg(feature = "cache")]
 use super::CACHE;
+#[cfg(feature = "cache")]
+use model::event::EventType;
 
 macro_rules! update {
     ($event:expr) => {
@@ -45,35 +45,129 @@ macro_rules! update {
     };
 }
 
-fn context(
-    data: &Arc<Mutex<ShareMap>>,
-    runner_tx: &Sender<InterMessage>,
-    shard_id: u64,
-) -> Context {
-    Context::new(Arc::clone(data), runner_tx.clone(), shard_id)
-}
-
 pub(crate) enum DispatchEvent {
     Client(ClientEvent),
     Model(Event),
+    // presence updates that were coalesced by a shard runner, to be written to
+    // the cache at once
+    Presences(Vec<PresenceUpdateEvent>),
+    // an event of an ignored type, which is only written to the cache
+    #[cfg(feature = "cache")]
+    CacheUpdate(Event),
+}
+
+/// Whether events of the type update the cache, and are therefore not dropped
+/// when ignored.
+///
+/// Presence updates are only kept if the cache stores presences.
+#[cfg(feature = "cache")]
+pub(crate) fn updates_cache(kind: &EventType) -> bool {
+    match *kind {
+        EventType::PresenceUpdate |
+        EventType::PresencesReplace => CACHE.read().settings().cache_presences,
+        EventType::ChannelCreate |
+        EventType::ChannelDelete |
+        EventType::ChannelPinsUpdate |
+        EventType::ChannelRecipientAdd |
+        EventType::ChannelRecipientRemove |
+        EventType::ChannelUpdate |
+        EventType::GuildCreate |
+        EventType::GuildDelete |
+        EventType::GuildEmojisUpdate |
+        EventType::GuildMemberAdd |
+        EventType::GuildMemberRemove |
+        EventType::GuildMemberUpdate |
+        EventType::GuildMembersChunk |
+        EventType::GuildRoleCreate |
+        EventType::GuildRoleDelete |
+        EventType::GuildRoleUpdate |
+        EventType::GuildUnavailable |
+        EventType::GuildUpdate |
+        EventType::MessageCreate |
+        EventType::MessageUpdate |
+        EventType::Ready |
+        EventType::UserUpdate |
+        EventType::VoiceStateUpdate => true,
+        _ => false,
+    }
+}
+
+/// Writes an event to the cache, without dispatching it.
+#[cfg(feature = "cache")]
+fn update_cache(event: &mut Event) {
+    let mut cache = match CACHE.try_write_for(Duration::from_millis(10)) {
+        Some(cache) => cache,
+        None => {
+            warn!(
+                "[dispatch] Possible deadlock: couldn't unlock cache to update with event: {:?}",
+                event,
+            );
+
+            return;
+        },
+    };
+
+    match *event {
+        Event::ChannelCreate(ref mut event) => { cache.update(event); },
+        Event::ChannelDelete(ref mut event) => { cache.update(event); },
+        Event::ChannelPinsUpdate(ref mut event) => { cache.update(event); },
+        Event::ChannelRecipientAdd(ref mut event) => { cache.update(event); },
+        Event::ChannelRecipientRemove(ref mut event) => { cache.update(event); },
+        Event::ChannelUpdate(ref mut event) => { cache.update(event); },
+        Event::GuildCreate(ref mut event) => { cache.update(event); },
+        Event::GuildDelete(ref mut event) => { cache.update(event); },
+        Event::GuildEmojisUpdate(ref mut event) => { cache.update(event); },
+        Event::GuildMemberAdd(ref mut event) => { cache.update(event); },
+        Event::GuildMemberRemove(ref mut event) => { cache.update(event); },
+        Event::GuildMemberUpdate(ref mut event) => { cache.update(event); },
+        Event::GuildMembersChunk(ref mut event) => { cache.update(event); },
+        Event::GuildRoleCreate(ref mut event) => { cache.update(event); },
+        Event::GuildRoleDelete(ref mut event) => { cache.update(event); },
+        Event::GuildRoleUpdate(ref mut event) => { cache.update(event); },
+        Event::GuildUnavailable(ref mut event) => { cache.update(event); },
+        Event::GuildUpdate(ref mut event) => { cache.update(event); },
+        Event::MessageCreate(ref mut event) => { cache.update(event); },
+        Event::MessageUpdate(ref mut event) => { cache.update(event); },
+        Event::PresenceUpdate(ref mut event) => { cache.update(event); },
+        Event::PresencesReplace(ref mut event) => { cache.update(event); },
+        Event::Ready(ref mut event) => { cache.update(event); },
+        Event::UserUpdate(ref mut event) => { cache.update(event); },
+        Event::VoiceStateUpdate(ref mut event) => { cache.update(event); },
+        _ => {},
+    }
 }
 
 #[cfg(feature = "framework")]
-#[cfg_attr(feature = "cargo-clippy", allow(too_many_arguments))]
 pub(crate) fn dispatch<H: EventHandler + Send + Sync + 'static>(
     event: DispatchEvent,
     framework: &Arc<Mutex<Option<Box<Framework + Send>>>>,
-    data: &Arc<Mutex<ShareMap>>,
+    context: &Context,
     event_handler: &Arc<H>,
-    runner_tx: &Sender<InterMessage>,
     threadpool: &ThreadPool,
-    shard_id: u64,
 ) {
+    #[cfg(feature = "collector")]
+    {
+        match event {
+            DispatchEvent::Model(ref event) => collector::dispatch_event(event),
+            DispatchEvent::Presences(ref events) => if collector::has_event_collectors() {
+                for event in events {
+                    collector::dispatch_event(&Event::PresenceUpdate(event.clone()));
+                }
+            },
+            DispatchEvent::Client(_) => {},
+            #[cfg(feature = "cache")]
+            DispatchEvent::CacheUpdate(_) => {},
+        }
+    }
+
     match event {
         DispatchEvent::Model(Event::MessageCreate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            #[cfg(feature = "collector")]
+            collector::dispatch_message(&event.message);
+
+            let context = context.clone();
             dispatch_message(
                 context.clone(),
                 event.message.clone(),
@@ -85,14 +179,7 @@ pub(crate) fn dispatch<H: EventHandler + Send + Sync + 'static>(
                 framework.dispatch(context, event.message, threadpool);
             }
         },
-        other => handle_event(
-            other,
-            data,
-            event_handler,
-            runner_tx,
-            threadpool,
-            shard_id,
-        ),
+        other => handle_event(other, context, event_handler, threadpool),
     }
 }
 
@@ -100,27 +187,36 @@ pub(crate) fn dispatch<H: EventHandler + Send + Sync + 'static>(
 #[allow(unused_mut)]
 pub(crate) fn dispatch<H: EventHandler + Send + Sync + 'static>(
     event: DispatchEvent,
-    data: &Arc<Mutex<ShareMap>>,
+    context: &Context,
     event_handler: &Arc<H>,
-    runner_tx: &Sender<InterMessage>,
     threadpool: &ThreadPool,
-    shard_id: u64,
 ) {
+    #[cfg(feature = "collector")]
+    {
+        match event {
+            DispatchEvent::Model(ref event) => collector::dispatch_event(event),
+            DispatchEvent::Presences(ref events) => if collector::has_event_collectors() {
+                for event in events {
+                    collector::dispatch_event(&Event::PresenceUpdate(event.clone()));
+                }
+            },
+            DispatchEvent::Client(_) => {},
+            #[cfg(feature = "cache")]
+            DispatchEvent::CacheUpdate(_) => {},
+        }
+    }
+
     match event {
         DispatchEvent::Model(Event::MessageCreate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            #[cfg(feature = "collector")]
+            collector::dispatch_message(&event.message);
+
+            let context = context.clone();
             dispatch_message(context, event.message, event_handler, threadpool);
         },
-        other => handle_event(
-            other,
-            data,
-            event_handler,
-            runner_tx,
-            threadpool,
-            shard_id,
-        ),
+        other => handle_event(other, context, event_handler, threadpool),
     }
 }
 
@@ -146,25 +242,69 @@ fn dispatch_message<H>(
 #[allow(cyclomatic_complexity, unused_assignments, unused_mut)]
 fn handle_event<H: EventHandler + Send + Sync + 'static>(
     event: DispatchEvent,
-    data: &Arc<Mutex<ShareMap>>,
+    context: &Context,
     event_handler: &Arc<H>,
-    runner_tx: &Sender<InterMessage>,
     threadpool: &ThreadPool,
-    shard_id: u64,
 ) {
     match event {
+        #[cfg(feature = "cache")]
+        DispatchEvent::CacheUpdate(mut event) => update_cache(&mut event),
+        #[cfg(feature = "cache")]
+        DispatchEvent::Client(ClientEvent::CacheReady(guilds)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.cache_ready(context.clone(), guilds.clone());
+
+                #[allow(deprecated)]
+                event_handler.cached(context, guilds);
+            });
+        },
         DispatchEvent::Client(ClientEvent::ShardStageUpdate(event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
                 event_handler.shard_stage_update(context, event);
             });
         }
+        DispatchEvent::Model(Event::AutoModActionExecution(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.auto_moderation_action_execution(context, event);
+            });
+        },
+        DispatchEvent::Model(Event::AutoModRuleCreate(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.auto_moderation_rule_create(context, event.rule);
+            });
+        },
+        DispatchEvent::Model(Event::AutoModRuleDelete(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.auto_moderation_rule_delete(context, event.rule);
+            });
+        },
+        DispatchEvent::Model(Event::AutoModRuleUpdate(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.auto_moderation_rule_update(context, event.rule);
+            });
+        },
         DispatchEvent::Model(Event::ChannelCreate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
 
             // Discord sends both a MessageCreate and a ChannelCreate upon a new message in a private channel.
             // This could potentionally be annoying to handle when otherwise wanting to normally take care of a new channel.
@@ -197,7 +337,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelDelete(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
 
             match event.channel {
                 Channel::Private(_) | Channel::Group(_) => {},
@@ -218,7 +358,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             }
         },
         DispatchEvent::Model(Event::ChannelPinsUpdate(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -228,7 +368,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelRecipientAdd(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
 
             let event_handler = Arc::clone(event_handler);
 
@@ -243,7 +383,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelRecipientRemove(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -257,7 +397,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -271,7 +411,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::GuildBanAdd(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -279,7 +419,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::GuildBanRemove(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -296,27 +436,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
 
             update!(event);
 
-            #[cfg(feature = "cache")]
-            {
-                let cache = CACHE.read();
-
-                if cache.unavailable_guilds.is_empty() {
-                    let context = context(data, runner_tx, shard_id);
-
-                    let guild_amount = cache
-                        .guilds
-                        .iter()
-                        .map(|(&id, _)| id)
-                        .collect::<Vec<GuildId>>();
-                    let event_handler = Arc::clone(event_handler);
-
-                    threadpool.execute(move || {
-                        event_handler.cached(context, guild_amount);
-                    });
-                }
-            }
-
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -329,7 +449,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildDelete(mut event)) => {
             let _full = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -343,7 +463,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildEmojisUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -351,7 +471,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::GuildIntegrationsUpdate(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -361,7 +481,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildMemberAdd(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -370,7 +490,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildMemberRemove(mut event)) => {
             let _member = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -390,7 +510,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
                 None
             }};
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -406,7 +526,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildMembersChunk(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -416,7 +536,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildRoleCreate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -425,7 +545,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildRoleDelete(mut event)) => {
             let _role = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -438,7 +558,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildRoleUpdate(mut event)) => {
             let _before = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -452,7 +572,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildUnavailable(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -462,7 +582,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -478,10 +598,18 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
                 }}
             });
         },
+        DispatchEvent::Model(Event::InteractionCreate(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.interaction_create(context, event.interaction);
+            });
+        },
         // Already handled by the framework check macro
         DispatchEvent::Model(Event::MessageCreate(_)) => {},
         DispatchEvent::Model(Event::MessageDeleteBulk(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -489,7 +617,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::MessageDelete(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -499,7 +627,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::MessageUpdate(mut event)) => {
             let _before = update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -513,10 +641,26 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
                 }}
             });
         },
+        DispatchEvent::Model(Event::PollVoteAdd(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.poll_vote_add(context, event);
+            });
+        },
+        DispatchEvent::Model(Event::PollVoteRemove(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.poll_vote_remove(context, event);
+            });
+        },
         DispatchEvent::Model(Event::PresencesReplace(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -526,15 +670,42 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::PresenceUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
                 event_handler.presence_update(context, event);
             });
         },
+        DispatchEvent::Presences(mut events) => {
+            #[cfg(feature = "cache")]
+            {
+                if let Some(mut lock) = CACHE.try_write_for(Duration::from_millis(10)) {
+                    for event in &mut events {
+                        lock.update(event);
+                    }
+                } else {
+                    warn!(
+                        "[dispatch] Possible deadlock: couldn't unlock cache to update with {} presences",
+                        events.len(),
+                    );
+                }
+            }
+
+            for event in events {
+                let context = context.clone();
+                let event_handler = Arc::clone(event_handler);
+
+                threadpool.execute(move || {
+                    event_handler.presence_update(context, event);
+                });
+            }
+        },
         DispatchEvent::Model(Event::ReactionAdd(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            #[cfg(feature = "collector")]
+            collector::dispatch_reaction_add(&event.reaction);
+
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -542,7 +713,10 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::ReactionRemove(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            #[cfg(feature = "collector")]
+            collector::dispatch_reaction_remove(&event.reaction);
+
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -550,7 +724,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::ReactionRemoveAll(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -560,7 +734,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::Ready(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(&event_handler);
 
             threadpool.execute(move || {
@@ -568,12 +742,60 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::Resumed(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
 
             event_handler.resume(context, event);
         },
+        DispatchEvent::Model(Event::StageInstanceCreate(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.stage_instance_create(context, event.stage_instance);
+            });
+        },
+        DispatchEvent::Model(Event::StageInstanceDelete(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.stage_instance_delete(context, event.stage_instance);
+            });
+        },
+        DispatchEvent::Model(Event::StageInstanceUpdate(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.stage_instance_update(context, event.stage_instance);
+            });
+        },
+        DispatchEvent::Model(Event::ThreadCreate(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.thread_create(context, event.thread);
+            });
+        },
+        DispatchEvent::Model(Event::ThreadDelete(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.thread_delete(context, event);
+            });
+        },
+        DispatchEvent::Model(Event::ThreadUpdate(mut event)) => {
+            let context = context.clone();
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.thread_update(context, event.thread);
+            });
+        },
         DispatchEvent::Model(Event::TypingStart(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -581,7 +803,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::Unknown(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -590,7 +812,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::UserUpdate(mut event)) => {
             let _before = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -602,7 +824,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::VoiceServerUpdate(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -612,7 +834,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::VoiceStateUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -620,7 +842,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::WebhookUpdate(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context.clone();
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
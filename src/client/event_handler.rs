@@ -10,12 +10,49 @@ use ::client::bridge::gateway::event::*;
 
 /// The core trait for handling events by serenity.
 pub trait EventHandler {
-    /// Dispatched when the cache gets full.
+    /// Dispatched once a shard received all guilds sent in its [`ready`]
+    /// event, so that the cache holds them.
     ///
-    /// Provides the cached guilds' ids.
+    /// Provides the Ids of the guilds received. Guilds that did not arrive
+    /// within 10 seconds of the previous one are left out, as they may be
+    /// unavailable due to an outage.
+    ///
+    /// This is dispatched per shard, with each shard's own guilds, and again
+    /// after every new session. Unlike [`ready`], this can be used for
+    /// initialization that relies on the cached guilds.
+    ///
+    /// [`ready`]: #method.ready
+    #[cfg(feature = "cache")]
+    fn cache_ready(&self, _ctx: Context, _guilds: Vec<GuildId>) {}
+
+    /// Dispatched together with [`cache_ready`], providing the same Ids.
+    ///
+    /// [`cache_ready`]: #method.cache_ready
     #[cfg(feature = "cache")]
+    #[deprecated(since = "0.5.9", note = "Use the `cache_ready`-method instead.")]
     fn cached(&self, _ctx: Context, _guilds: Vec<GuildId>) {}
 
+    /// Dispatched when an action of an auto moderation rule is performed on a
+    /// message, once for each action.
+    ///
+    /// Provides the action, the rule that was triggered and the message.
+    fn auto_moderation_action_execution(&self, _ctx: Context, _execution: AutoModActionExecutionEvent) {}
+
+    /// Dispatched when an auto moderation rule is created.
+    ///
+    /// Provides the created rule.
+    fn auto_moderation_rule_create(&self, _ctx: Context, _rule: AutoModRule) {}
+
+    /// Dispatched when an auto moderation rule is deleted.
+    ///
+    /// Provides the rule as it was before it was deleted.
+    fn auto_moderation_rule_delete(&self, _ctx: Context, _rule: AutoModRule) {}
+
+    /// Dispatched when an auto moderation rule is updated.
+    ///
+    /// Provides the updated rule.
+    fn auto_moderation_rule_update(&self, _ctx: Context, _rule: AutoModRule) {}
+
     /// Dispatched when a channel is created.
     ///
     /// Provides said channel's data.
@@ -197,6 +234,14 @@ pub trait EventHandler {
     #[cfg(not(feature = "cache"))]
     fn guild_update(&self, _ctx: Context, _new_but_incomplete_data: PartialGuild) {}
 
+    /// Dispatched when a user interacts with the application, such as by
+    /// invoking one of its commands, clicking a button of its messages or
+    /// submitting one of its modals.
+    ///
+    /// Provides the interaction, which has to be responded to within 3
+    /// seconds.
+    fn interaction_create(&self, _ctx: Context, _interaction: Interaction) {}
+
     /// Dispatched when a message is created.
     ///
     /// Provides the message's data.
@@ -239,6 +284,16 @@ pub trait EventHandler {
     /// Provides the channel's id and the message's id.
     fn reaction_remove_all(&self, _ctx: Context, _channel_id: ChannelId, _removed_from_message_id: MessageId) {}
 
+    /// Dispatched when a user votes for an answer of a poll.
+    ///
+    /// Provides the user's id, the message's id, and the answer's id.
+    fn poll_vote_add(&self, _ctx: Context, _: PollVoteAddEvent) {}
+
+    /// Dispatched when a user removes their vote for an answer of a poll.
+    ///
+    /// Provides the user's id, the message's id, and the answer's id.
+    fn poll_vote_remove(&self, _ctx: Context, _: PollVoteRemoveEvent) {}
+
     fn presence_replace(&self, _ctx: Context, _: Vec<Presence>) {}
 
     /// Dispatched when a user's presence is updated (e.g off -> on).
@@ -259,6 +314,37 @@ pub trait EventHandler {
     /// Provides the context of the shard and the event information about the update.
     fn shard_stage_update(&self, _ctx: Context, _: ShardStageUpdateEvent) {}
 
+    /// Dispatched when a stage is started in a stage channel.
+    ///
+    /// Provides the stage instance, which is not added to the cache.
+    fn stage_instance_create(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
+    /// Dispatched when a stage is ended in a stage channel.
+    ///
+    /// Provides the stage instance as it was before it ended.
+    fn stage_instance_delete(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
+    /// Dispatched when the topic or privacy level of a stage is updated.
+    ///
+    /// Provides the updated stage instance.
+    fn stage_instance_update(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
+    /// Dispatched when a thread is created, or when the current user is added
+    /// to a private thread.
+    ///
+    /// Provides the thread, which is not added to the cache.
+    fn thread_create(&self, _ctx: Context, _thread: GuildChannel) {}
+
+    /// Dispatched when a thread is deleted.
+    ///
+    /// Provides the thread's id, guild id, parent channel id and type.
+    fn thread_delete(&self, _ctx: Context, _thread: ThreadDeleteEvent) {}
+
+    /// Dispatched when a thread is updated, such as when it is archived.
+    ///
+    /// Provides the updated thread.
+    fn thread_update(&self, _ctx: Context, _thread: GuildChannel) {}
+
     /// Dispatched when a user starts typing.
     fn typing_start(&self, _ctx: Context, _: TypingStartEvent) {}
 
@@ -22,6 +22,7 @@
 pub mod bridge;
 
 mod context;
+mod data;
 mod dispatch;
 mod error;
 mod event_handler;
@@ -31,6 +32,7 @@ pub use self::{
     error::Error as ClientError,
     event_handler::EventHandler
 };
+pub use typemap::Key as TypeMapKey;
 
 // Note: the following re-exports are here for backwards compatibility
 pub use gateway;
@@ -39,11 +41,26 @@ pub use http as rest;
 #[cfg(feature = "cache")]
 pub use CACHE;
 
+use gateway::CurrentPresence;
 use http;
 use internal::prelude::*;
+use model::event::EventType;
+use model::gateway::ActivityData;
+use model::user::OnlineStatus;
 use parking_lot::Mutex;
-use self::bridge::gateway::{ShardManager, ShardManagerMonitor, ShardManagerOptions};
+use self::bridge::gateway::{
+    DispatchQueue,
+    IdentifyLock,
+    ShardManager,
+    ShardManagerMonitor,
+    ShardManagerOptions,
+    QueueOverflow,
+};
+use self::bridge::voice::VoiceGatewayManager;
+use std::cmp;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 use threadpool::ThreadPool;
 use typemap::ShareMap;
 
@@ -274,6 +291,32 @@ pub struct Client {
     /// [`Client::start_shards`]: #method.start_shards
     pub shard_manager: Arc<Mutex<ShardManager>>,
     shard_manager_worker: ShardManagerMonitor,
+    /// The limit on events waiting for the [`threadpool`], as set through
+    /// [`set_dispatch_queue`].
+    ///
+    /// [`set_dispatch_queue`]: #method.set_dispatch_queue
+    /// [`threadpool`]: #structfield.threadpool
+    pub dispatch_queue: Arc<Mutex<Option<DispatchQueue>>>,
+    /// The lock that shards acquire before they are started, as set through
+    /// [`set_identify_lock`].
+    ///
+    /// [`set_identify_lock`]: #method.set_identify_lock
+    pub identify_lock: Arc<Mutex<Option<Box<IdentifyLock>>>>,
+    /// The dispatch types that shards do not dispatch to the event handler,
+    /// as set through [`ignore_events`].
+    ///
+    /// [`ignore_events`]: #method.ignore_events
+    pub ignored_events: Arc<Mutex<HashSet<EventType>>>,
+    /// The presence that shards identify with when connecting, as set through
+    /// [`set_initial_presence`].
+    ///
+    /// [`set_initial_presence`]: #method.set_initial_presence
+    pub initial_presence: Arc<Mutex<CurrentPresence>>,
+    /// The window in which shards coalesce presence updates of a user, as set
+    /// through [`set_presence_coalescing`].
+    ///
+    /// [`set_presence_coalescing`]: #method.set_presence_coalescing
+    pub presence_coalescing: Arc<Mutex<Option<Duration>>>,
     /// The threadpool shared by all shards.
     ///
     /// Defaults to 5 threads, which should suffice small bots. Consider
@@ -281,6 +324,15 @@ pub struct Client {
     pub threadpool: ThreadPool,
     /// The token in use by the client.
     pub token: Arc<Mutex<String>>,
+    /// The manager receiving the voice events of all shards, as set through
+    /// [`with_voice_gateway`].
+    ///
+    /// With the `voice` feature enabled, this defaults to the
+    /// [`voice_manager`].
+    ///
+    /// [`voice_manager`]: #structfield.voice_manager
+    /// [`with_voice_gateway`]: #method.with_voice_gateway
+    pub voice_gateway: Arc<Mutex<Option<Box<VoiceGatewayManager>>>>,
     /// The voice manager for the client.
     ///
     /// This is an ergonomic structure for interfacing over shards' voice
@@ -345,6 +397,11 @@ impl Client {
         let url = Arc::new(Mutex::new(http::get_gateway()?.url));
         let data = Arc::new(Mutex::new(ShareMap::custom()));
         let event_handler = Arc::new(handler);
+        let initial_presence = Arc::new(Mutex::new((Vec::new(), OnlineStatus::Online)));
+        let dispatch_queue = Arc::new(Mutex::new(None));
+        let identify_lock = Arc::new(Mutex::new(None));
+        let ignored_events = Arc::new(Mutex::new(HashSet::new()));
+        let presence_coalescing = Arc::new(Mutex::new(None));
 
         #[cfg(feature = "framework")]
         let framework = Arc::new(Mutex::new(None));
@@ -353,18 +410,30 @@ impl Client {
             0,
             UserId(0),
         )));
+        #[cfg(feature = "voice")]
+        let voice_gateway = Arc::new(Mutex::new(Some(
+            Box::new(Arc::clone(&voice_manager)) as Box<VoiceGatewayManager>
+        )));
+        #[cfg(not(feature = "voice"))]
+        let voice_gateway = Arc::new(Mutex::new(None));
 
         let (shard_manager, shard_manager_worker) = {
             ShardManager::new(ShardManagerOptions {
                 data: &data,
+                dispatch_queue: &dispatch_queue,
                 event_handler: &event_handler,
                 #[cfg(feature = "framework")]
                 framework: &framework,
+                identify_lock: &identify_lock,
+                ignored_events: &ignored_events,
+                presence: &initial_presence,
+                presence_coalescing: &presence_coalescing,
                 shard_index: 0,
                 shard_init: 0,
                 shard_total: 0,
                 threadpool: threadpool.clone(),
                 token: &locked,
+                voice_gateway: &voice_gateway,
                 #[cfg(feature = "voice")]
                 voice_manager: &voice_manager,
                 ws_url: &url,
@@ -379,7 +448,13 @@ impl Client {
             data,
             shard_manager,
             shard_manager_worker,
+            dispatch_queue,
+            identify_lock,
+            ignored_events,
+            initial_presence,
+            presence_coalescing,
             threadpool,
+            voice_gateway,
             #[cfg(feature = "voice")]
             voice_manager,
         })
@@ -492,6 +567,291 @@ impl Client {
         *self.framework.lock() = Some(Box::new(f));
     }
 
+    /// Inserts a value into the [`data`] under the given key, returning the
+    /// value previously stored under it, if any.
+    ///
+    /// Keys can be declared with the [`type_map_key`] macro.
+    ///
+    /// # Examples
+    ///
+    /// Store the prefix of the bot before starting it:
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate serenity;
+    /// #
+    /// # use serenity::prelude::*;
+    /// #
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// #
+    /// type_map_key!(Prefix: String);
+    ///
+    /// # fn main() {
+    /// let client = Client::new("token", Handler).unwrap();
+    ///
+    /// client.data_insert::<Prefix>("~".to_string());
+    /// # }
+    /// ```
+    ///
+    /// [`data`]: #structfield.data
+    /// [`type_map_key`]: ../macro.type_map_key.html
+    pub fn data_insert<K>(&self, value: K::Value) -> Option<K::Value>
+        where K: TypeMapKey, K::Value: Send + Sync {
+        data::insert::<K>(&self.data, value)
+    }
+
+    /// Retrieves a clone of the value stored in the [`data`] under the given
+    /// key, if there is one.
+    ///
+    /// Refer to [`Context::data_get`] for more information.
+    ///
+    /// [`Context::data_get`]: struct.Context.html#method.data_get
+    /// [`data`]: #structfield.data
+    pub fn data_get<K>(&self) -> Option<K::Value>
+        where K: TypeMapKey, K::Value: Clone + Send + Sync {
+        data::get::<K>(&self.data)
+    }
+
+    /// Updates the value stored in the [`data`] under the given key, if there
+    /// is one, returning the result of the closure.
+    ///
+    /// [`data`]: #structfield.data
+    pub fn data_update<K, F, R>(&self, f: F) -> Option<R>
+        where K: TypeMapKey, K::Value: Send + Sync, F: FnOnce(&mut K::Value) -> R {
+        data::update::<K, F, R>(&self.data, f)
+    }
+
+    /// Sets the manager that receives the voice events of all shards, such as
+    /// an external voice implementation.
+    ///
+    /// This replaces the [`voice_manager`] if the `voice` feature is enabled,
+    /// which then no longer receives any events.
+    ///
+    /// **Note**: This must be called before starting the client.
+    ///
+    /// [`voice_manager`]: #structfield.voice_manager
+    pub fn with_voice_gateway<V: VoiceGatewayManager + 'static>(&mut self, manager: V) {
+        *self.voice_gateway.lock() = Some(Box::new(manager));
+    }
+
+    /// Sets the lock that shards acquire before they are started, to
+    /// coordinate the starts of shards run by multiple processes.
+    ///
+    /// Refer to [`IdentifyLock`] for more information.
+    ///
+    /// **Note**: This must be called before starting the client.
+    ///
+    /// [`IdentifyLock`]: bridge/gateway/trait.IdentifyLock.html
+    pub fn set_identify_lock<L: IdentifyLock + 'static>(&mut self, lock: L) {
+        *self.identify_lock.lock() = Some(Box::new(lock));
+    }
+
+    /// Sets the presence that shards identify with once they are started.
+    ///
+    /// Offline is not a valid status to identify with, so
+    /// [`OnlineStatus::Offline`] is treated as [`OnlineStatus::Invisible`].
+    ///
+    /// # Examples
+    ///
+    /// Starting with a "Watching" activity while idle:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::EventHandler;
+    /// # use std::error::Error;
+    /// #
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {}
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// use serenity::client::Client;
+    /// use serenity::model::gateway::ActivityData;
+    /// use serenity::model::user::OnlineStatus;
+    /// use std::env;
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let mut client = Client::new(&token, Handler)?;
+    ///
+    /// client.set_initial_presence(vec![ActivityData::watching("the stars")], OnlineStatus::Idle);
+    ///
+    /// client.start()?;
+    /// # Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`OnlineStatus::Invisible`]: ../model/user/enum.OnlineStatus.html#variant.Invisible
+    /// [`OnlineStatus::Offline`]: ../model/user/enum.OnlineStatus.html#variant.Offline
+    pub fn set_initial_presence(&self, activities: Vec<ActivityData>, mut status: OnlineStatus) {
+        if status == OnlineStatus::Offline {
+            status = OnlineStatus::Invisible;
+        }
+
+        *self.initial_presence.lock() = (activities, status);
+    }
+
+    /// Sets the dispatch types to not dispatch to the event handler.
+    ///
+    /// Types that do not update the cache are dropped as soon as they are
+    /// received, without deserializing them. This is cheaper than ignoring the
+    /// events in the event handler, for bots that receive many events they do
+    /// not need, such as typing indicators.
+    ///
+    /// With the `cache` feature, types that update the cache, such as
+    /// [`EventType::GuildMemberUpdate`], are still deserialized and written to
+    /// the cache, so that it and [`EventHandler::cache_ready`] stay correct.
+    /// Only their event handler is skipped. Presence updates are dropped
+    /// regardless if the cache is set to not store presences, via
+    /// [`Settings::cache_presences`].
+    ///
+    /// **Note**: [`EventType::Ready`] and [`EventType::Resumed`] are never
+    /// dropped, as the shards rely on them to maintain their sessions.
+    ///
+    /// # Examples
+    ///
+    /// Ignoring typing indicators, as well as presence updates, which are not
+    /// cached either:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::EventHandler;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// use serenity::client::Client;
+    /// use serenity::model::event::EventType;
+    /// use serenity::CACHE;
+    /// use std::env;
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let mut client = Client::new(&token, Handler)?;
+    ///
+    /// CACHE.write().settings_mut().cache_presences(false);
+    /// client.ignore_events(vec![EventType::TypingStart, EventType::PresenceUpdate]);
+    ///
+    /// client.start()?;
+    /// # Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`EventHandler::cache_ready`]: trait.EventHandler.html#method.cache_ready
+    /// [`EventType::GuildMemberUpdate`]: ../model/event/enum.EventType.html#variant.GuildMemberUpdate
+    /// [`EventType::Ready`]: ../model/event/enum.EventType.html#variant.Ready
+    /// [`EventType::Resumed`]: ../model/event/enum.EventType.html#variant.Resumed
+    /// [`Settings::cache_presences`]: ../cache/struct.Settings.html#method.cache_presences
+    pub fn ignore_events<I: IntoIterator<Item = EventType>>(&self, events: I) {
+        *self.ignored_events.lock() = events.into_iter().collect();
+    }
+
+    /// Limits how many events each shard keeps waiting while the
+    /// [`threadpool`] is busy, and sets what happens to events received once
+    /// the limit is reached.
+    ///
+    /// By default, all events are queued in the threadpool, so that memory
+    /// grows without bounds during event storms the event handler can not
+    /// keep up with. Refer to [`QueueOverflow`] for the available policies.
+    ///
+    /// A `capacity` of 0 is treated as 1.
+    ///
+    /// # Examples
+    ///
+    /// Dropping typing indicators and presence updates first once more than
+    /// 1000 events are waiting:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::EventHandler;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// use serenity::client::bridge::gateway::QueueOverflow;
+    /// use serenity::client::Client;
+    /// use std::env;
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let mut client = Client::new(&token, Handler)?;
+    ///
+    /// client.set_dispatch_queue(1000, QueueOverflow::DropNoisiest);
+    ///
+    /// client.start()?;
+    /// # Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`QueueOverflow`]: bridge/gateway/enum.QueueOverflow.html
+    /// [`threadpool`]: #structfield.threadpool
+    pub fn set_dispatch_queue(&self, capacity: usize, overflow: QueueOverflow) {
+        *self.dispatch_queue.lock() = Some(DispatchQueue {
+            capacity: cmp::max(capacity, 1),
+            overflow,
+        });
+    }
+
+    /// Coalesces presence updates of the same user within a guild that are
+    /// received within the given window, so that only the latest of them is
+    /// dispatched.
+    ///
+    /// Bots receiving presences on large guilds get bursts of redundant
+    /// updates, such as when a user's activity changes several times in a row.
+    /// Once the window has passed since the first update of a user was
+    /// received, the latest one is dispatched to the [`presence_update`] event
+    /// handler. All updates that are due at the same time are written to the
+    /// cache at once, rather than locking it for each of them.
+    ///
+    /// **Note**: Coalesced updates are dispatched up to `window` late, and so
+    /// possibly after events that were received after them.
+    ///
+    /// # Examples
+    ///
+    /// Dispatching at most one presence update per user and guild every 5
+    /// seconds:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::EventHandler;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// use serenity::client::Client;
+    /// use std::env;
+    /// use std::time::Duration;
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let mut client = Client::new(&token, Handler)?;
+    ///
+    /// client.set_presence_coalescing(Duration::from_secs(5));
+    ///
+    /// client.start()?;
+    /// # Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`presence_update`]: trait.EventHandler.html#method.presence_update
+    pub fn set_presence_coalescing(&self, window: Duration) {
+        *self.presence_coalescing.lock() = Some(window);
+    }
+
     /// Establish the connection and start listening for events.
     ///
     /// This will start receiving events in a loop and start dispatching the
@@ -535,7 +895,7 @@ impl Client {
     ///
     /// [gateway docs]: ../gateway/index.html#sharding
     pub fn start(&mut self) -> Result<()> {
-        self.start_connection([0, 0, 1])
+        self.start_connection(vec![0], 1)
     }
 
     /// Establish the connection(s) and start listening for events.
@@ -587,13 +947,9 @@ impl Client {
     /// [`ClientError::Shutdown`]: enum.ClientError.html#variant.Shutdown
     /// [gateway docs]: ../gateway/index.html#sharding
     pub fn start_autosharded(&mut self) -> Result<()> {
-        let (x, y) = {
-            let res = http::get_bot_gateway()?;
-
-            (res.shards as u64 - 1, res.shards as u64)
-        };
+        let total_shards = http::get_bot_gateway()?.shards as u64;
 
-        self.start_connection([0, x, y])
+        self.start_connection((0..total_shards).collect(), total_shards)
     }
 
     /// Establish a sharded connection and start listening for events.
@@ -674,7 +1030,7 @@ impl Client {
     /// [`start_autosharded`]: #method.start_autosharded
     /// [gateway docs]: ../gateway/index.html#sharding
     pub fn start_shard(&mut self, shard: u64, shards: u64) -> Result<()> {
-        self.start_connection([shard, shard, shards])
+        self.start_connection(vec![shard], shards)
     }
 
     /// Establish sharded connections and start listening for events.
@@ -728,7 +1084,7 @@ impl Client {
     /// [`start_shard_range`]: #method.start_shard_range
     /// [Gateway docs]: ../gateway/index.html#sharding
     pub fn start_shards(&mut self, total_shards: u64) -> Result<()> {
-        self.start_connection([0, total_shards - 1, total_shards])
+        self.start_connection((0..total_shards).collect(), total_shards)
     }
 
     /// Establish a range of sharded connections and start listening for events.
@@ -798,13 +1154,64 @@ impl Client {
     /// [`start_shards`]: #method.start_shards
     /// [Gateway docs]: ../gateway/index.html#sharding
     pub fn start_shard_range(&mut self, range: [u64; 2], total_shards: u64) -> Result<()> {
-        self.start_connection([range[0], range[1], total_shards])
+        self.start_connection((range[0]..=range[1]).collect(), total_shards)
+    }
+
+    /// Establish sharded connections for the given shards and start listening
+    /// for events.
+    ///
+    /// Unlike [`start_shard_range`], the shards need not be consecutive. This
+    /// allows the shards of a bot to be distributed across processes or
+    /// machines by an external coordinator, which assigns each process the
+    /// shards it is responsible for.
+    ///
+    /// Refer to the [Gateway documentation][gateway docs] for more
+    /// information on effectively using sharding.
+    ///
+    /// # Examples
+    ///
+    /// For a bot using a total of 10 shards, initialize shards 1, 4, and 7:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::EventHandler;
+    /// # use std::error::Error;
+    /// #
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {}
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// use serenity::client::Client;
+    /// use std::env;
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let mut client = Client::new(&token, Handler)?;
+    ///
+    /// if let Err(why) = client.start_shard_ids(vec![1, 4, 7], 10) {
+    ///     println!("Err with client: {:?}", why);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError::Shutdown`] when all shards have shutdown due to
+    /// an error.
+    ///
+    /// [`ClientError::Shutdown`]: enum.ClientError.html#variant.Shutdown
+    /// [`start_shard_range`]: #method.start_shard_range
+    /// [gateway docs]: ../gateway/index.html#sharding
+    pub fn start_shard_ids<I>(&mut self, shard_ids: I, total_shards: u64) -> Result<()>
+        where I: IntoIterator<Item = u64> {
+        self.start_connection(shard_ids.into_iter().collect(), total_shards)
     }
 
-    // Shard data layout is:
-    // 0: first shard number to initialize
-    // 1: shard number to initialize up to and including
-    // 2: total number of shards the bot is sharding for
+    // Starts the given shards, out of the total number of shards the bot is
+    // sharding for.
     //
     // Not all shards need to be initialized in this process.
     //
@@ -814,17 +1221,14 @@ impl Client {
     // an error.
     //
     // [`ClientError::Shutdown`]: enum.ClientError.html#variant.Shutdown
-    fn start_connection(&mut self, shard_data: [u64; 3]) -> Result<()> {
-        #[cfg(feature = "voice")]
-        self.voice_manager.lock().set_shard_count(shard_data[2]);
-
+    fn start_connection(&mut self, shard_ids: Vec<u64>, total_shards: u64) -> Result<()> {
         // This is kind of gross, but oh well.
         //
-        // Both the framework and voice bridge need the user's ID, so we'll only
-        // retrieve it over REST if at least one of those are enabled.
-        #[cfg(any(all(feature = "standard_framework", feature = "framework"),
-                  feature = "voice"))]
-        {
+        // Both the framework and voice gateway manager need the user's ID, so
+        // we'll only retrieve it over REST if at least one of those are in use.
+        let uses_voice = self.voice_gateway.lock().is_some();
+
+        if cfg!(all(feature = "standard_framework", feature = "framework")) || uses_voice {
             let user = http::get_current_user()?;
 
             // Update the framework's current user if the feature is enabled.
@@ -837,25 +1241,17 @@ impl Client {
                 }
             }
 
-            #[cfg(feature = "voice")]
-            {
-                self.voice_manager.lock().set_user_id(user.id);
+            if let Some(ref mut manager) = *self.voice_gateway.lock() {
+                manager.initialise(total_shards, user.id);
             }
         }
 
         {
             let mut manager = self.shard_manager.lock();
 
-            let init = shard_data[1] - shard_data[0] + 1;
-
-            manager.set_shards(shard_data[0], init, shard_data[2]);
+            debug!("Initializing shard info: {:?}/{}", shard_ids, total_shards);
 
-            debug!(
-                "Initializing shard info: {} - {}/{}",
-                shard_data[0],
-                init,
-                shard_data[2],
-            );
+            manager.set_shard_ids(shard_ids, total_shards);
 
             if let Err(why) = manager.initialize() {
                 error!("Failed to boot a shard: {:?}", why);
@@ -875,6 +1271,10 @@ impl Client {
 
 /// Validates that a token is likely in a valid format.
 ///
+/// **Note**: Deprecated in favour of [`utils::validate_token`], which also
+/// decodes the user Id and timestamp of the token, and ignores a leading
+/// `"Bot "` and surrounding whitespace.
+///
 /// This performs the following checks on a given token:
 ///
 /// - At least one character long;
@@ -888,6 +1288,7 @@ impl Client {
 /// actually invalid:
 ///
 /// ```rust,no_run
+/// # #![allow(deprecated)]
 /// use serenity::client::validate_token;
 ///
 /// // ensure a valid token is in fact valid:
@@ -911,6 +1312,8 @@ impl Client {
 /// The type of failure is not specified.
 ///
 /// [`ClientError::InvalidToken`]: enum.ClientError.html#variant.InvalidToken
+/// [`utils::validate_token`]: ../utils/fn.validate_token.html
+#[deprecated(since = "0.5.9", note = "Use `utils::validate_token`, which checks the user Id and timestamp of the token.")]
 pub fn validate_token(token: &str) -> Result<()> {
     if token.is_empty() {
         return Err(Error::Client(ClientError::InvalidToken));
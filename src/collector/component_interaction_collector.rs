@@ -0,0 +1,366 @@
+use model::{
+    channel::InteractionType,
+    id::{ChannelId, GuildId, MessageId, UserId},
+    interaction::{Interaction, InteractionData}
+};
+use std::{
+    sync::Arc,
+    time::Duration
+};
+use super::{Collected, FilterOptions};
+
+type ComponentInteractionFilterFn = Fn(&Interaction) -> bool + Send + Sync + 'static;
+
+/// The conditions a component [`Interaction`] has to meet in order to be
+/// collected.
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+#[derive(Clone, Default)]
+pub(super) struct ComponentInteractionFilterOptions {
+    custom_id: Option<String>,
+    author_id: Option<UserId>,
+    channel_id: Option<ChannelId>,
+    guild_id: Option<GuildId>,
+    message_id: Option<MessageId>,
+    filter: Option<Arc<ComponentInteractionFilterFn>>,
+}
+
+impl FilterOptions<Interaction> for ComponentInteractionFilterOptions {
+    fn is_passing(&self, interaction: &Interaction) -> bool {
+        if interaction.kind != InteractionType::MessageComponent {
+            return false;
+        }
+
+        let custom_id = match interaction.data {
+            Some(InteractionData::MessageComponent(ref data)) => &data.custom_id,
+            _ => return false,
+        };
+
+        let author_id = match interaction.member {
+            Some(ref member) => Some(member.user.read().id),
+            None => interaction.user.as_ref().map(|user| user.id),
+        };
+
+        self.custom_id.as_ref().map_or(true, |id| id == custom_id)
+            && self.author_id.map_or(true, |id| Some(id) == author_id)
+            && self.channel_id.map_or(true, |id| Some(id) == interaction.channel_id)
+            && self.guild_id.map_or(true, |id| Some(id) == interaction.guild_id)
+            && self.message_id.map_or(true, |id| {
+                interaction.message.as_ref().map_or(false, |message| message.id == id)
+            })
+            && self.filter.as_ref().map_or(true, |f| f(interaction))
+    }
+}
+
+/// A builder to wait for a single component [`Interaction`] that fulfills all
+/// configured conditions.
+///
+/// Refer to [`Message::await_component_interaction`] for usage.
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+/// [`Message::await_component_interaction`]: ../model/channel/struct.Message.html#method.await_component_interaction
+#[derive(Clone, Default)]
+pub struct CollectComponentInteraction {
+    options: ComponentInteractionFilterOptions,
+    timeout: Option<Duration>,
+}
+
+impl CollectComponentInteraction {
+    /// Only accepts an interaction with a component of the given custom Id.
+    pub fn custom_id<S: Into<String>>(mut self, custom_id: S) -> Self {
+        self.options.custom_id = Some(custom_id.into());
+
+        self
+    }
+
+    /// Only accepts an interaction by the user with the given Id.
+    pub fn author_id<U: Into<UserId>>(mut self, author_id: U) -> Self {
+        self.options.author_id = Some(author_id.into());
+
+        self
+    }
+
+    /// Only accepts an interaction in the channel with the given Id.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.options.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Only accepts an interaction in the guild with the given Id.
+    pub fn guild_id<G: Into<GuildId>>(mut self, guild_id: G) -> Self {
+        self.options.guild_id = Some(guild_id.into());
+
+        self
+    }
+
+    /// Only accepts an interaction with a component on the message with the
+    /// given Id.
+    pub fn message_id<M: Into<MessageId>>(mut self, message_id: M) -> Self {
+        self.options.message_id = Some(message_id.into());
+
+        self
+    }
+
+    /// Sets a custom condition that an interaction must additionally pass.
+    pub fn filter<F>(mut self, filter: F) -> Self
+        where F: Fn(&Interaction) -> bool + Send + Sync + 'static {
+        self.options.filter = Some(Arc::new(filter));
+
+        self
+    }
+
+    /// Sets the maximum duration to wait for an interaction.
+    ///
+    /// **Note**: Without a timeout, waiting will only stop once a fitting
+    /// interaction was received, which might never happen.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+
+        self
+    }
+
+    /// Blocks the current thread until a fitting interaction was received or
+    /// the timeout elapsed.
+    pub(crate) fn wait(self) -> Option<Interaction> {
+        ComponentInteractionCollectorBuilder {
+            options: self.options,
+            timeout: self.timeout,
+            collect_limit: Some(1),
+        }.build().next()
+    }
+}
+
+/// A builder for a [`ComponentInteractionCollector`], collecting every
+/// interaction with a message component that fulfills all configured
+/// conditions.
+///
+/// **Note**: The interactions still have to be responded to, the collector
+/// only hands them over.
+///
+/// # Examples
+///
+/// Collecting the clicks on a button of a message for a minute:
+///
+/// ```rust,no_run
+/// # use serenity::model::channel::Message;
+/// #
+/// # fn collect(msg: &Message) {
+/// use serenity::collector::ComponentInteractionCollectorBuilder;
+/// use serenity::model::interaction::InteractionResponseType;
+/// use std::time::Duration;
+///
+/// let clicks = ComponentInteractionCollectorBuilder::default()
+///     .message_id(msg.id)
+///     .custom_id("vote")
+///     .timeout(Duration::from_secs(60))
+///     .build();
+///
+/// for interaction in clicks {
+///     let _ = interaction.create_response(InteractionResponseType::DeferredUpdateMessage, |r| r);
+/// }
+/// # }
+/// ```
+///
+/// [`ComponentInteractionCollector`]: struct.ComponentInteractionCollector.html
+#[derive(Clone, Default)]
+pub struct ComponentInteractionCollectorBuilder {
+    options: ComponentInteractionFilterOptions,
+    timeout: Option<Duration>,
+    collect_limit: Option<u32>,
+}
+
+impl ComponentInteractionCollectorBuilder {
+    /// Only collects interactions with a component of the given custom Id.
+    pub fn custom_id<S: Into<String>>(mut self, custom_id: S) -> Self {
+        self.options.custom_id = Some(custom_id.into());
+
+        self
+    }
+
+    /// Only collects interactions by the user with the given Id.
+    pub fn author_id<U: Into<UserId>>(mut self, author_id: U) -> Self {
+        self.options.author_id = Some(author_id.into());
+
+        self
+    }
+
+    /// Only collects interactions in the channel with the given Id.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.options.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Only collects interactions in the guild with the given Id.
+    pub fn guild_id<G: Into<GuildId>>(mut self, guild_id: G) -> Self {
+        self.options.guild_id = Some(guild_id.into());
+
+        self
+    }
+
+    /// Only collects interactions with a component on the message with the
+    /// given Id.
+    pub fn message_id<M: Into<MessageId>>(mut self, message_id: M) -> Self {
+        self.options.message_id = Some(message_id.into());
+
+        self
+    }
+
+    /// Sets a custom condition that interactions must additionally pass.
+    pub fn filter<F>(mut self, filter: F) -> Self
+        where F: Fn(&Interaction) -> bool + Send + Sync + 'static {
+        self.options.filter = Some(Arc::new(filter));
+
+        self
+    }
+
+    /// Sets the maximum amount of interactions to collect.
+    pub fn collect_limit(mut self, limit: u32) -> Self {
+        self.collect_limit = Some(limit);
+
+        self
+    }
+
+    /// Sets the duration after which no more interactions are collected.
+    ///
+    /// **Note**: Without a timeout or collect limit, the collector will only
+    /// stop once it is dropped.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+
+        self
+    }
+
+    /// Registers the collector, collecting interactions from now on.
+    pub fn build(self) -> ComponentInteractionCollector {
+        let collected = Collected::register(
+            self.options,
+            self.timeout,
+            self.collect_limit,
+            |collectors, filter| collectors.component_interactions.push(filter),
+        );
+
+        ComponentInteractionCollector(collected)
+    }
+}
+
+/// An iterator over the collected component interactions, blocking until the
+/// next fitting interaction was received.
+///
+/// The iterator ends once the collect limit was reached or the timeout
+/// elapsed. Dropping it stops collecting.
+///
+/// Refer to [`ComponentInteractionCollectorBuilder`] for creating one.
+///
+/// [`ComponentInteractionCollectorBuilder`]: struct.ComponentInteractionCollectorBuilder.html
+pub struct ComponentInteractionCollector(Collected<Interaction>);
+
+impl Iterator for ComponentInteractionCollector {
+    type Item = Interaction;
+
+    fn next(&mut self) -> Option<Interaction> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::{
+        channel::InteractionType,
+        interaction::Interaction
+    };
+    use serde_json;
+    use super::super::FilterOptions;
+    use super::ComponentInteractionFilterOptions;
+
+    fn interaction() -> Interaction {
+        serde_json::from_str(include_str!("../../tests/resources/interaction_create_2.json"))
+            .unwrap()
+    }
+
+    fn options() -> ComponentInteractionFilterOptions {
+        ComponentInteractionFilterOptions::default()
+    }
+
+    #[test]
+    fn passes_without_conditions() {
+        assert!(options().is_passing(&interaction()));
+    }
+
+    #[test]
+    fn custom_id() {
+        let interaction = interaction();
+
+        let mut options = options();
+        options.custom_id = Some("colour".to_string());
+        assert!(options.is_passing(&interaction));
+
+        options.custom_id = Some("cancel".to_string());
+        assert!(!options.is_passing(&interaction));
+    }
+
+    #[test]
+    fn author_id() {
+        let interaction = interaction();
+
+        let mut options = options();
+        options.author_id = Some(114941315417899012.into());
+        assert!(options.is_passing(&interaction));
+
+        options.author_id = Some(249608457672458240.into());
+        assert!(!options.is_passing(&interaction));
+    }
+
+    #[test]
+    fn message_id() {
+        let mut interaction = interaction();
+
+        let mut options = options();
+        options.message_id = Some(786008729715212340.into());
+        assert!(options.is_passing(&interaction));
+
+        options.message_id = Some(786008729715212339.into());
+        assert!(!options.is_passing(&interaction));
+
+        interaction.message = None;
+        options.message_id = Some(786008729715212340.into());
+        assert!(!options.is_passing(&interaction));
+    }
+
+    #[test]
+    fn channel_and_guild_id() {
+        let interaction = interaction();
+
+        let mut options = options();
+        options.channel_id = Some(302917639565475841.into());
+        assert!(options.is_passing(&interaction));
+
+        // The interaction was sent from a direct message.
+        options.guild_id = Some(302917639565475841.into());
+        assert!(!options.is_passing(&interaction));
+    }
+
+    #[test]
+    fn custom_filter() {
+        let interaction = interaction();
+
+        let mut options = options();
+        options.filter = Some(::std::sync::Arc::new(|i: &Interaction| i.version == 1));
+        assert!(options.is_passing(&interaction));
+
+        options.filter = Some(::std::sync::Arc::new(|i: &Interaction| i.version == 2));
+        assert!(!options.is_passing(&interaction));
+    }
+
+    #[test]
+    fn rejects_other_interactions() {
+        let mut interaction = interaction();
+        interaction.kind = InteractionType::ApplicationCommand;
+        assert!(!options().is_passing(&interaction));
+
+        let mut interaction = self::interaction();
+        interaction.data = None;
+        assert!(!options().is_passing(&interaction));
+    }
+}
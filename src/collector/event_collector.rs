@@ -0,0 +1,85 @@
+use model::event::Event;
+use std::{
+    sync::Arc,
+    time::Duration
+};
+use super::{Collected, FilterOptions};
+
+type EventFilterFn = Fn(&Event) -> bool + Send + Sync + 'static;
+
+/// The condition an [`Event`] has to meet in order to be collected.
+///
+/// [`Event`]: ../model/event/enum.Event.html
+#[derive(Clone)]
+pub(super) struct EventFilterOptions(Arc<EventFilterFn>);
+
+impl FilterOptions<Event> for EventFilterOptions {
+    fn is_passing(&self, event: &Event) -> bool {
+        (self.0)(event)
+    }
+}
+
+/// Blocks the current thread until an event passing the `filter` was received
+/// or the `timeout` elapsed.
+pub(crate) fn wait_for<F>(timeout: Duration, filter: F) -> Option<Event>
+    where F: Fn(&Event) -> bool + Send + Sync + 'static {
+    Collected::register(
+        EventFilterOptions(Arc::new(filter)),
+        Some(timeout),
+        Some(1),
+        |collectors, filter| collectors.events.push(filter),
+    ).next()
+}
+
+#[cfg(test)]
+mod test {
+    use model::event::{Event, UnknownEvent};
+    use std::{
+        sync::Arc,
+        thread,
+        time::Duration
+    };
+    use super::super::{dispatch_event, FilterOptions};
+    use super::{wait_for, EventFilterOptions};
+
+    fn unknown(kind: &str) -> Event {
+        Event::Unknown(UnknownEvent {
+            kind: kind.to_string(),
+            value: json!({}),
+        })
+    }
+
+    fn is_kind(event: &Event, kind: &str) -> bool {
+        match *event {
+            Event::Unknown(ref event) => event.kind == kind,
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn passes_filter() {
+        let options = EventFilterOptions(Arc::new(|event: &Event| is_kind(event, "FOLLOW")));
+
+        assert!(options.is_passing(&unknown("FOLLOW")));
+        assert!(!options.is_passing(&unknown("UNFOLLOW")));
+    }
+
+    #[test]
+    fn waits_for_dispatched_event() {
+        thread::spawn(|| for _ in 0..100 {
+            dispatch_event(&unknown("WAIT_FOR_OTHER"));
+            dispatch_event(&unknown("WAIT_FOR"));
+            thread::sleep(Duration::from_millis(10));
+        });
+
+        let event = wait_for(Duration::from_secs(5), |event| is_kind(event, "WAIT_FOR"));
+        assert!(event.map_or(false, |event| is_kind(&event, "WAIT_FOR")));
+    }
+
+    #[test]
+    fn gives_up_after_timeout() {
+        let event = wait_for(Duration::from_millis(20), |event| is_kind(event, "NEVER_SENT"));
+
+        assert!(event.is_none());
+    }
+}
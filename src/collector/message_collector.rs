@@ -0,0 +1,208 @@
+use model::{
+    channel::Message,
+    id::{ChannelId, GuildId, UserId}
+};
+use std::{
+    sync::Arc,
+    time::Duration
+};
+use super::{Collected, FilterOptions};
+
+type MessageFilterFn = Fn(&Message) -> bool + Send + Sync + 'static;
+
+/// The conditions a [`Message`] has to meet in order to be collected.
+///
+/// [`Message`]: ../model/channel/struct.Message.html
+#[derive(Clone, Default)]
+pub(super) struct MessageFilterOptions {
+    author_id: Option<UserId>,
+    channel_id: Option<ChannelId>,
+    guild_id: Option<GuildId>,
+    filter: Option<Arc<MessageFilterFn>>,
+}
+
+impl FilterOptions<Message> for MessageFilterOptions {
+    fn is_passing(&self, message: &Message) -> bool {
+        self.author_id.map_or(true, |id| id == message.author.id)
+            && self.channel_id.map_or(true, |id| id == message.channel_id)
+            && self.guild_id.map_or(true, |id| Some(id) == message.guild_id)
+            && self.filter.as_ref().map_or(true, |f| f(message))
+    }
+}
+
+/// A builder to wait for a single [`Message`] that fulfills all configured
+/// conditions.
+///
+/// Refer to [`ChannelId::await_reply`] for usage.
+///
+/// [`ChannelId::await_reply`]: ../model/id/struct.ChannelId.html#method.await_reply
+/// [`Message`]: ../model/channel/struct.Message.html
+#[derive(Clone, Default)]
+pub struct CollectReply {
+    options: MessageFilterOptions,
+    timeout: Option<Duration>,
+}
+
+impl CollectReply {
+    /// Only accepts a reply sent by the user with the given Id.
+    pub fn author_id<U: Into<UserId>>(mut self, author_id: U) -> Self {
+        self.options.author_id = Some(author_id.into());
+
+        self
+    }
+
+    /// Only accepts a reply sent in the channel with the given Id.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.options.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Only accepts a reply sent in the guild with the given Id.
+    pub fn guild_id<G: Into<GuildId>>(mut self, guild_id: G) -> Self {
+        self.options.guild_id = Some(guild_id.into());
+
+        self
+    }
+
+    /// Sets a custom condition that a reply must additionally pass.
+    pub fn filter<F>(mut self, filter: F) -> Self
+        where F: Fn(&Message) -> bool + Send + Sync + 'static {
+        self.options.filter = Some(Arc::new(filter));
+
+        self
+    }
+
+    /// Sets the maximum duration to wait for a reply.
+    ///
+    /// **Note**: Without a timeout, waiting will only stop once a fitting reply
+    /// was received, which might never happen.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+
+        self
+    }
+
+    /// Blocks the current thread until a fitting reply was received or the
+    /// timeout elapsed.
+    pub(crate) fn wait(self) -> Option<Message> {
+        MessageCollectorBuilder {
+            options: self.options,
+            timeout: self.timeout,
+            collect_limit: Some(1),
+        }.build().next()
+    }
+}
+
+/// A builder for a [`MessageCollector`], collecting every [`Message`] that
+/// fulfills all configured conditions.
+///
+/// # Examples
+///
+/// Collecting up to five answers by the author within two minutes:
+///
+/// ```rust,no_run
+/// # use serenity::model::channel::Message;
+/// #
+/// # fn collect(msg: &Message) {
+/// use serenity::collector::MessageCollectorBuilder;
+/// use std::time::Duration;
+///
+/// let answers = MessageCollectorBuilder::default()
+///     .author_id(msg.author.id)
+///     .channel_id(msg.channel_id)
+///     .collect_limit(5)
+///     .timeout(Duration::from_secs(120))
+///     .build()
+///     .map(|message| message.content)
+///     .collect::<Vec<_>>();
+/// # }
+/// ```
+///
+/// [`Message`]: ../model/channel/struct.Message.html
+/// [`MessageCollector`]: struct.MessageCollector.html
+#[derive(Clone, Default)]
+pub struct MessageCollectorBuilder {
+    options: MessageFilterOptions,
+    timeout: Option<Duration>,
+    collect_limit: Option<u32>,
+}
+
+impl MessageCollectorBuilder {
+    /// Only collects messages sent by the user with the given Id.
+    pub fn author_id<U: Into<UserId>>(mut self, author_id: U) -> Self {
+        self.options.author_id = Some(author_id.into());
+
+        self
+    }
+
+    /// Only collects messages sent in the channel with the given Id.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.options.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Only collects messages sent in the guild with the given Id.
+    pub fn guild_id<G: Into<GuildId>>(mut self, guild_id: G) -> Self {
+        self.options.guild_id = Some(guild_id.into());
+
+        self
+    }
+
+    /// Sets a custom condition that messages must additionally pass.
+    pub fn filter<F>(mut self, filter: F) -> Self
+        where F: Fn(&Message) -> bool + Send + Sync + 'static {
+        self.options.filter = Some(Arc::new(filter));
+
+        self
+    }
+
+    /// Sets the maximum amount of messages to collect.
+    pub fn collect_limit(mut self, limit: u32) -> Self {
+        self.collect_limit = Some(limit);
+
+        self
+    }
+
+    /// Sets the duration after which no more messages are collected.
+    ///
+    /// **Note**: Without a timeout or collect limit, the collector will only
+    /// stop once it is dropped.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+
+        self
+    }
+
+    /// Registers the collector, collecting messages from now on.
+    pub fn build(self) -> MessageCollector {
+        let collected = Collected::register(
+            self.options,
+            self.timeout,
+            self.collect_limit,
+            |collectors, filter| collectors.messages.push(filter),
+        );
+
+        MessageCollector(collected)
+    }
+}
+
+/// An iterator over the collected messages, blocking until the next fitting
+/// message was received.
+///
+/// The iterator ends once the collect limit was reached or the timeout
+/// elapsed. Dropping it stops collecting.
+///
+/// Refer to [`MessageCollectorBuilder`] for creating one.
+///
+/// [`MessageCollectorBuilder`]: struct.MessageCollectorBuilder.html
+pub struct MessageCollector(Collected<Message>);
+
+impl Iterator for MessageCollector {
+    type Item = Message;
+
+    fn next(&mut self) -> Option<Message> {
+        self.0.next()
+    }
+}
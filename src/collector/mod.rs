@@ -0,0 +1,354 @@
+//! Collectors allow waiting for events that match a set of conditions from
+//! anywhere in your code, e.g. while processing a command, without forwarding
+//! events from an [`EventHandler`] by hand.
+//!
+//! Every event received by the [`Client`] is compared against the registered
+//! collectors before being dispatched to the event handler and framework.
+//! Collectors are automatically dropped once they are satisfied, their
+//! timeout has elapsed, or the collecting end was dropped.
+//!
+//! The [`MessageCollector`], [`ReactionCollector`] and
+//! [`ComponentInteractionCollector`] are iterators yielding each fitting event
+//! as it arrives, until their limit is reached or their
+//! timeout elapses. For any other kind of event, [`Context::wait_for`] waits
+//! for a single event matching a condition.
+//!
+//! # Examples
+//!
+//! Asking the author of a message for a channel name:
+//!
+//! ```rust,no_run
+//! # use serenity::model::channel::Message;
+//! #
+//! # fn ask(msg: &Message) -> serenity::Result<()> {
+//! use std::time::Duration;
+//!
+//! msg.channel_id.say("Which channel should be used?")?;
+//!
+//! let reply = msg.channel_id.await_reply(|r| r
+//!     .author_id(msg.author.id)
+//!     .timeout(Duration::from_secs(30)));
+//!
+//! match reply {
+//!     Some(reply) => msg.channel_id.say(&format!("Using #{}.", reply.content))?,
+//!     None => msg.channel_id.say("No answer within 30 seconds.")?,
+//! };
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! Waiting for a reaction by the author of a message:
+//!
+//! ```rust,no_run
+//! # use serenity::model::channel::Message;
+//! #
+//! # fn confirm(msg: &Message) {
+//! use std::time::Duration;
+//!
+//! let confirmed = msg.await_reaction(|r| r
+//!     .author_id(msg.author.id)
+//!     .emoji('✅')
+//!     .timeout(Duration::from_secs(30)))
+//!     .is_some();
+//! # }
+//! ```
+//!
+//! Counting the reactions added to a message within a minute:
+//!
+//! ```rust,no_run
+//! # use serenity::model::channel::Message;
+//! #
+//! # fn count(msg: &Message) {
+//! use serenity::collector::ReactionCollectorBuilder;
+//! use std::time::Duration;
+//!
+//! let collector = ReactionCollectorBuilder::default()
+//!     .message_id(msg.id)
+//!     .timeout(Duration::from_secs(60))
+//!     .build();
+//!
+//! println!("Collected {} reactions.", collector.count());
+//! # }
+//! ```
+//!
+//! [`Client`]: ../client/struct.Client.html
+//! [`ComponentInteractionCollector`]: struct.ComponentInteractionCollector.html
+//! [`Context::wait_for`]: ../client/struct.Context.html#method.wait_for
+//! [`EventHandler`]: ../client/trait.EventHandler.html
+//! [`MessageCollector`]: struct.MessageCollector.html
+//! [`ReactionCollector`]: struct.ReactionCollector.html
+
+mod component_interaction_collector;
+mod event_collector;
+mod message_collector;
+mod reaction_collector;
+
+pub use self::component_interaction_collector::{
+    CollectComponentInteraction,
+    ComponentInteractionCollector,
+    ComponentInteractionCollectorBuilder
+};
+pub use self::message_collector::{CollectReply, MessageCollector, MessageCollectorBuilder};
+pub use self::reaction_collector::{CollectReaction, ReactionAction, ReactionCollector, ReactionCollectorBuilder};
+
+pub(crate) use self::event_collector::wait_for;
+
+use model::channel::{Message, Reaction};
+use model::event::Event;
+use model::interaction::Interaction;
+use parking_lot::Mutex;
+use self::component_interaction_collector::ComponentInteractionFilterOptions;
+use self::event_collector::EventFilterOptions;
+use self::message_collector::MessageFilterOptions;
+use self::reaction_collector::ReactionFilterOptions;
+use std::{
+    sync::mpsc::{self, Receiver, Sender},
+    time::{Duration, Instant}
+};
+
+lazy_static! {
+    static ref COLLECTORS: Mutex<Collectors> = Mutex::new(Collectors::default());
+}
+
+/// All collectors that are currently waiting for events.
+#[derive(Default)]
+struct Collectors {
+    events: Vec<Filter<EventFilterOptions, Event>>,
+    messages: Vec<Filter<MessageFilterOptions, Message>>,
+    reactions: Vec<Filter<ReactionFilterOptions, ReactionAction>>,
+    component_interactions: Vec<Filter<ComponentInteractionFilterOptions, Interaction>>,
+}
+
+/// The conditions an event has to meet in order to be collected.
+trait FilterOptions<T> {
+    fn is_passing(&self, item: &T) -> bool;
+}
+
+/// A registered collector, forwarding fitting events to the receiving end of
+/// its channel.
+struct Filter<O, T> {
+    options: O,
+    expires_at: Option<Instant>,
+    /// How many more events are collected, if limited.
+    remaining: Option<u32>,
+    sender: Sender<T>,
+}
+
+impl<O: FilterOptions<T>, T: Clone> Filter<O, T> {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.map_or(false, |expires_at| now >= expires_at)
+    }
+
+    /// Sends the `item` if it passes the filter, returning whether the
+    /// collector is done afterwards.
+    fn send(&mut self, item: &T) -> bool {
+        if !self.options.is_passing(item) {
+            return false;
+        }
+
+        // A collector whose receiver has hung up does not require any more.
+        if self.sender.send(item.clone()).is_err() {
+            return true;
+        }
+
+        match self.remaining {
+            Some(ref mut remaining) => {
+                *remaining = remaining.saturating_sub(1);
+
+                *remaining == 0
+            },
+            None => false,
+        }
+    }
+}
+
+/// Offers the `item` to all `filters`, removing those that are done or have
+/// expired.
+fn dispatch<O: FilterOptions<T>, T: Clone>(filters: &mut Vec<Filter<O, T>>, item: &T) {
+    let now = Instant::now();
+
+    filters.retain(|filter| !filter.is_expired(now));
+
+    // `retain` does not allow mutating the retained elements.
+    let mut i = 0;
+
+    while i < filters.len() {
+        if filters[i].send(item) {
+            filters.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Offers a received event to all event collectors.
+pub(crate) fn dispatch_event(event: &Event) {
+    let mut collectors = COLLECTORS.lock();
+
+    if !collectors.events.is_empty() {
+        dispatch(&mut collectors.events, event);
+    }
+
+    if let Event::InteractionCreate(ref event) = *event {
+        if !collectors.component_interactions.is_empty() {
+            dispatch(&mut collectors.component_interactions, &event.interaction);
+        }
+    }
+}
+
+/// Whether any event collectors are waiting, so that events only have to be
+/// built for them if so.
+pub(crate) fn has_event_collectors() -> bool {
+    !COLLECTORS.lock().events.is_empty()
+}
+
+/// Offers a received message to all message collectors.
+pub(crate) fn dispatch_message(message: &Message) {
+    let mut collectors = COLLECTORS.lock();
+
+    if !collectors.messages.is_empty() {
+        dispatch(&mut collectors.messages, message);
+    }
+}
+
+/// Offers an added reaction to all reaction collectors.
+pub(crate) fn dispatch_reaction_add(reaction: &Reaction) {
+    let mut collectors = COLLECTORS.lock();
+
+    if !collectors.reactions.is_empty() {
+        dispatch(&mut collectors.reactions, &ReactionAction::Added(reaction.clone()));
+    }
+}
+
+/// Offers a removed reaction to all reaction collectors.
+pub(crate) fn dispatch_reaction_remove(reaction: &Reaction) {
+    let mut collectors = COLLECTORS.lock();
+
+    if !collectors.reactions.is_empty() {
+        dispatch(&mut collectors.reactions, &ReactionAction::Removed(reaction.clone()));
+    }
+}
+
+/// The receiving end of a registered collector, giving up once the timeout
+/// elapsed.
+struct Collected<T> {
+    receiver: Receiver<T>,
+    expires_at: Option<Instant>,
+}
+
+impl<T> Collected<T> {
+    /// Registers a collector in the list selected by `register`.
+    fn register<O, F>(options: O, timeout: Option<Duration>, limit: Option<u32>, register: F) -> Self
+        where F: FnOnce(&mut Collectors, Filter<O, T>) {
+        let (sender, receiver) = mpsc::channel();
+        let expires_at = timeout.map(|timeout| Instant::now() + timeout);
+
+        register(&mut COLLECTORS.lock(), Filter {
+            options,
+            expires_at,
+            remaining: limit,
+            sender,
+        });
+
+        Collected {
+            receiver,
+            expires_at,
+        }
+    }
+
+    /// Blocks until the next event was collected, the collector is done, or
+    /// the timeout elapsed.
+    fn next(&mut self) -> Option<T> {
+        match self.expires_at {
+            Some(expires_at) => {
+                let now = Instant::now();
+
+                if now >= expires_at {
+                    return None;
+                }
+
+                self.receiver.recv_timeout(expires_at - now).ok()
+            },
+            None => self.receiver.recv().ok(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::mpsc,
+        time::{Duration, Instant}
+    };
+    use super::{dispatch, Collected, Filter, FilterOptions};
+
+    struct Even;
+
+    impl FilterOptions<u32> for Even {
+        fn is_passing(&self, item: &u32) -> bool { item % 2 == 0 }
+    }
+
+    fn filter(remaining: Option<u32>, expires_at: Option<Instant>) -> (Filter<Even, u32>, mpsc::Receiver<u32>) {
+        let (sender, receiver) = mpsc::channel();
+
+        (Filter {
+            options: Even,
+            expires_at,
+            remaining,
+            sender,
+        }, receiver)
+    }
+
+    #[test]
+    fn collects_passing_items_until_limit() {
+        let (filter, receiver) = filter(Some(2), None);
+        let mut filters = vec![filter];
+
+        for item in 1..=6 {
+            dispatch(&mut filters, &item);
+        }
+
+        assert!(filters.is_empty());
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2, 4]);
+    }
+
+    #[test]
+    fn removes_expired_and_dropped_collectors() {
+        let past = Instant::now() - Duration::from_secs(1);
+        let (expired, expired_receiver) = filter(None, Some(past));
+        let (dropped, dropped_receiver) = filter(None, None);
+        let (kept, kept_receiver) = filter(None, None);
+        let mut filters = vec![expired, dropped, kept];
+
+        drop(dropped_receiver);
+        // Items that do not pass leave the hung up collector alone.
+        dispatch(&mut filters, &1);
+        assert_eq!(filters.len(), 2);
+
+        dispatch(&mut filters, &2);
+        assert_eq!(filters.len(), 1);
+
+        assert!(expired_receiver.try_recv().is_err());
+        assert_eq!(kept_receiver.try_recv(), Ok(2));
+    }
+
+    #[test]
+    fn collected_gives_up_after_timeout() {
+        let (sender, receiver) = mpsc::channel::<u32>();
+        let mut collected = Collected {
+            receiver,
+            expires_at: Some(Instant::now() + Duration::from_millis(20)),
+        };
+
+        sender.send(1).unwrap();
+        assert_eq!(collected.next(), Some(1));
+        assert_eq!(collected.next(), None);
+
+        let (_sender, receiver) = mpsc::channel::<u32>();
+        let mut expired = Collected {
+            receiver,
+            expires_at: Some(Instant::now() - Duration::from_secs(1)),
+        };
+        assert_eq!(expired.next(), None);
+    }
+}
@@ -0,0 +1,392 @@
+use model::{
+    channel::{Reaction, ReactionType},
+    id::{ChannelId, MessageId, UserId}
+};
+use std::{
+    sync::Arc,
+    time::Duration
+};
+use super::{Collected, FilterOptions};
+
+type ReactionFilterFn = Fn(&Reaction) -> bool + Send + Sync + 'static;
+
+/// A reaction that was either added to or removed from a message.
+#[derive(Clone, Debug)]
+pub enum ReactionAction {
+    /// The reaction was added.
+    Added(Reaction),
+    /// The reaction was removed.
+    Removed(Reaction),
+}
+
+impl ReactionAction {
+    /// The reaction that was added or removed.
+    pub fn as_inner_ref(&self) -> &Reaction {
+        match *self {
+            ReactionAction::Added(ref reaction) | ReactionAction::Removed(ref reaction) => reaction,
+        }
+    }
+
+    /// Whether the reaction was added.
+    pub fn is_added(&self) -> bool {
+        match *self {
+            ReactionAction::Added(_) => true,
+            ReactionAction::Removed(_) => false,
+        }
+    }
+
+    /// Whether the reaction was removed.
+    pub fn is_removed(&self) -> bool {
+        !self.is_added()
+    }
+}
+
+/// The conditions a [`Reaction`] has to meet in order to be collected.
+///
+/// [`Reaction`]: ../model/channel/struct.Reaction.html
+#[derive(Clone)]
+pub(super) struct ReactionFilterOptions {
+    author_id: Option<UserId>,
+    channel_id: Option<ChannelId>,
+    message_id: Option<MessageId>,
+    emoji: Option<ReactionType>,
+    filter: Option<Arc<ReactionFilterFn>>,
+    added: bool,
+    removed: bool,
+}
+
+impl Default for ReactionFilterOptions {
+    fn default() -> Self {
+        ReactionFilterOptions {
+            author_id: None,
+            channel_id: None,
+            message_id: None,
+            emoji: None,
+            filter: None,
+            added: true,
+            removed: false,
+        }
+    }
+}
+
+impl FilterOptions<ReactionAction> for ReactionFilterOptions {
+    fn is_passing(&self, action: &ReactionAction) -> bool {
+        let reaction = action.as_inner_ref();
+
+        (if action.is_added() { self.added } else { self.removed })
+            && self.author_id.map_or(true, |id| id == reaction.user_id)
+            && self.channel_id.map_or(true, |id| id == reaction.channel_id)
+            && self.message_id.map_or(true, |id| id == reaction.message_id)
+            && self.emoji.as_ref().map_or(true, |emoji| *emoji == reaction.emoji)
+            && self.filter.as_ref().map_or(true, |f| f(reaction))
+    }
+}
+
+/// A builder to wait for a single reaction that fulfills all configured
+/// conditions.
+///
+/// **Note**: Only added reactions are accepted by default, refer to
+/// [`removed`] to accept removed ones as well.
+///
+/// Refer to [`Message::await_reaction`] for usage.
+///
+/// [`Message::await_reaction`]: ../model/channel/struct.Message.html#method.await_reaction
+/// [`removed`]: #method.removed
+#[derive(Clone, Default)]
+pub struct CollectReaction {
+    options: ReactionFilterOptions,
+    timeout: Option<Duration>,
+}
+
+impl CollectReaction {
+    /// Only accepts a reaction by the user with the given Id.
+    pub fn author_id<U: Into<UserId>>(mut self, author_id: U) -> Self {
+        self.options.author_id = Some(author_id.into());
+
+        self
+    }
+
+    /// Only accepts a reaction in the channel with the given Id.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.options.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Only accepts a reaction on the message with the given Id.
+    pub fn message_id<M: Into<MessageId>>(mut self, message_id: M) -> Self {
+        self.options.message_id = Some(message_id.into());
+
+        self
+    }
+
+    /// Only accepts a reaction with the given emoji.
+    pub fn emoji<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.options.emoji = Some(emoji.into());
+
+        self
+    }
+
+    /// Sets a custom condition that a reaction must additionally pass.
+    pub fn filter<F>(mut self, filter: F) -> Self
+        where F: Fn(&Reaction) -> bool + Send + Sync + 'static {
+        self.options.filter = Some(Arc::new(filter));
+
+        self
+    }
+
+    /// Whether an added reaction is accepted.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn added(mut self, added: bool) -> Self {
+        self.options.added = added;
+
+        self
+    }
+
+    /// Whether a removed reaction is accepted.
+    ///
+    /// **Note**: Defaults to `false`.
+    pub fn removed(mut self, removed: bool) -> Self {
+        self.options.removed = removed;
+
+        self
+    }
+
+    /// Sets the maximum duration to wait for a reaction.
+    ///
+    /// **Note**: Without a timeout, waiting will only stop once a fitting
+    /// reaction was received, which might never happen.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+
+        self
+    }
+
+    /// Blocks the current thread until a fitting reaction was received or the
+    /// timeout elapsed.
+    pub(crate) fn wait(self) -> Option<ReactionAction> {
+        ReactionCollectorBuilder {
+            options: self.options,
+            timeout: self.timeout,
+            collect_limit: Some(1),
+        }.build().next()
+    }
+}
+
+/// A builder for a [`ReactionCollector`], collecting every reaction that
+/// fulfills all configured conditions.
+///
+/// **Note**: Only added reactions are collected by default, refer to
+/// [`removed`] to collect removed ones as well.
+///
+/// # Examples
+///
+/// Waiting for the author to react with 👍 or 👎 to a poll:
+///
+/// ```rust,no_run
+/// # use serenity::model::channel::Message;
+/// #
+/// # fn poll(msg: &Message) {
+/// use serenity::collector::ReactionCollectorBuilder;
+/// use serenity::model::channel::ReactionType;
+/// use std::time::Duration;
+///
+/// let vote = ReactionCollectorBuilder::default()
+///     .message_id(msg.id)
+///     .author_id(msg.author.id)
+///     .filter(|reaction| match reaction.emoji {
+///         ReactionType::Unicode(ref emoji) => emoji == "👍" || emoji == "👎",
+///         _ => false,
+///     })
+///     .collect_limit(1)
+///     .timeout(Duration::from_secs(60))
+///     .build()
+///     .next();
+/// # }
+/// ```
+///
+/// [`ReactionCollector`]: struct.ReactionCollector.html
+/// [`removed`]: #method.removed
+#[derive(Clone, Default)]
+pub struct ReactionCollectorBuilder {
+    options: ReactionFilterOptions,
+    timeout: Option<Duration>,
+    collect_limit: Option<u32>,
+}
+
+impl ReactionCollectorBuilder {
+    /// Only collects reactions by the user with the given Id.
+    pub fn author_id<U: Into<UserId>>(mut self, author_id: U) -> Self {
+        self.options.author_id = Some(author_id.into());
+
+        self
+    }
+
+    /// Only collects reactions in the channel with the given Id.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.options.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Only collects reactions on the message with the given Id.
+    pub fn message_id<M: Into<MessageId>>(mut self, message_id: M) -> Self {
+        self.options.message_id = Some(message_id.into());
+
+        self
+    }
+
+    /// Only collects reactions with the given emoji.
+    pub fn emoji<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.options.emoji = Some(emoji.into());
+
+        self
+    }
+
+    /// Sets a custom condition that reactions must additionally pass.
+    pub fn filter<F>(mut self, filter: F) -> Self
+        where F: Fn(&Reaction) -> bool + Send + Sync + 'static {
+        self.options.filter = Some(Arc::new(filter));
+
+        self
+    }
+
+    /// Whether added reactions are collected.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn added(mut self, added: bool) -> Self {
+        self.options.added = added;
+
+        self
+    }
+
+    /// Whether removed reactions are collected.
+    ///
+    /// **Note**: Defaults to `false`.
+    pub fn removed(mut self, removed: bool) -> Self {
+        self.options.removed = removed;
+
+        self
+    }
+
+    /// Sets the maximum amount of reactions to collect.
+    pub fn collect_limit(mut self, limit: u32) -> Self {
+        self.collect_limit = Some(limit);
+
+        self
+    }
+
+    /// Sets the duration after which no more reactions are collected.
+    ///
+    /// **Note**: Without a timeout or collect limit, the collector will only
+    /// stop once it is dropped.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+
+        self
+    }
+
+    /// Registers the collector, collecting reactions from now on.
+    pub fn build(self) -> ReactionCollector {
+        let collected = Collected::register(
+            self.options,
+            self.timeout,
+            self.collect_limit,
+            |collectors, filter| collectors.reactions.push(filter),
+        );
+
+        ReactionCollector(collected)
+    }
+}
+
+/// An iterator over the collected reactions, blocking until the next fitting
+/// reaction was added or removed.
+///
+/// The iterator ends once the collect limit was reached or the timeout
+/// elapsed. Dropping it stops collecting.
+///
+/// Refer to [`ReactionCollectorBuilder`] for creating one.
+///
+/// [`ReactionCollectorBuilder`]: struct.ReactionCollectorBuilder.html
+pub struct ReactionCollector(Collected<ReactionAction>);
+
+impl Iterator for ReactionCollector {
+    type Item = ReactionAction;
+
+    fn next(&mut self) -> Option<ReactionAction> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::channel::Reaction;
+    use serde_json;
+    use super::super::FilterOptions;
+    use super::{ReactionAction, ReactionFilterOptions};
+
+    fn reaction() -> Reaction {
+        serde_json::from_str(include_str!("../../tests/resources/message_reaction_add_1.json"))
+            .unwrap()
+    }
+
+    #[test]
+    fn actions() {
+        assert!(ReactionAction::Added(reaction()).is_added());
+        assert!(ReactionAction::Removed(reaction()).is_removed());
+    }
+
+    #[test]
+    fn only_added_by_default() {
+        let options = ReactionFilterOptions::default();
+        assert!(options.is_passing(&ReactionAction::Added(reaction())));
+        assert!(!options.is_passing(&ReactionAction::Removed(reaction())));
+
+        let mut options = ReactionFilterOptions::default();
+        options.added = false;
+        options.removed = true;
+        assert!(!options.is_passing(&ReactionAction::Added(reaction())));
+        assert!(options.is_passing(&ReactionAction::Removed(reaction())));
+    }
+
+    #[test]
+    fn ids() {
+        let action = ReactionAction::Added(reaction());
+
+        let mut options = ReactionFilterOptions::default();
+        options.author_id = Some(114941315417899012.into());
+        options.channel_id = Some(244567637332328449.into());
+        options.message_id = Some(302928389764022282.into());
+        assert!(options.is_passing(&action));
+
+        options.message_id = Some(302928389764022281.into());
+        assert!(!options.is_passing(&action));
+    }
+
+    #[test]
+    fn emoji() {
+        let mut reaction = reaction();
+        reaction.emoji = '✅'.into();
+        let action = ReactionAction::Added(reaction);
+
+        let mut options = ReactionFilterOptions::default();
+        options.emoji = Some('✅'.into());
+        assert!(options.is_passing(&action));
+
+        options.emoji = Some('❌'.into());
+        assert!(!options.is_passing(&action));
+    }
+
+    #[test]
+    fn custom_filter() {
+        let action = ReactionAction::Added(reaction());
+
+        let mut options = ReactionFilterOptions::default();
+        options.filter = Some(::std::sync::Arc::new(|r: &Reaction| r.user_id == 114941315417899012));
+        assert!(options.is_passing(&action));
+
+        options.filter = Some(::std::sync::Arc::new(|r: &Reaction| r.user_id == 1));
+        assert!(!options.is_passing(&action));
+    }
+}
@@ -2,15 +2,33 @@
 
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: u16 = 6000;
+/// The maximum unicode code points allowed within an embed's title.
+pub const EMBED_TITLE_LIMIT: u16 = 256;
+/// The maximum unicode code points allowed within an embed's description.
+pub const EMBED_DESCRIPTION_LIMIT: u16 = 4096;
+/// The maximum unicode code points allowed within an embed author's name.
+pub const EMBED_AUTHOR_NAME_LIMIT: u16 = 256;
+/// The maximum unicode code points allowed within an embed footer's text.
+pub const EMBED_FOOTER_TEXT_LIMIT: u16 = 2048;
+/// The maximum amount of fields allowed within an embed.
+pub const EMBED_FIELD_LIMIT: u16 = 25;
+/// The maximum unicode code points allowed within an embed field's name.
+pub const EMBED_FIELD_NAME_LIMIT: u16 = 256;
+/// The maximum unicode code points allowed within an embed field's value.
+pub const EMBED_FIELD_VALUE_LIMIT: u16 = 1024;
 /// The gateway version used by the library. The gateway URI is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 6;
 /// The voice gateway version used by the library.
-pub const VOICE_GATEWAY_VERSION: u8 = 3;
+pub const VOICE_GATEWAY_VERSION: u8 = 4;
 /// The large threshold to send on identify.
 pub const LARGE_THRESHOLD: u8 = 250;
 /// The maximum unicode code points allowed within a message by Discord.
 pub const MESSAGE_CODE_LIMIT: u16 = 2000;
+/// The maximum number of messages that can be deleted in one bulk delete.
+pub const BULK_DELETE_LIMIT: usize = 100;
+/// The maximum age in seconds of messages that can be bulk deleted, two weeks.
+pub const BULK_DELETE_MAX_AGE: i64 = 1_209_600;
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ../../hyper/header/struct.UserAgent.html
@@ -144,8 +162,16 @@ pub enum VoiceOpCode {
     SessionDescription = 4,
     /// Used to indicate which users are speaking.
     Speaking = 5,
+    /// Used to acknowledge a received keepalive.
+    HeartbeatAck = 6,
+    /// Used to resume a connection.
+    Resume = 7,
     /// Used to heartbeat.
     Heartbeat = 8,
+    /// Used to acknowledge a resumed connection.
+    Resumed = 9,
+    /// Used to indicate that a user disconnected from the voice channel.
+    ClientDisconnect = 13,
 }
 
 enum_number!(
@@ -156,7 +182,11 @@ enum_number!(
         KeepAlive,
         SessionDescription,
         Speaking,
+        HeartbeatAck,
+        Resume,
         Heartbeat,
+        Resumed,
+        ClientDisconnect,
     }
 );
 
@@ -169,7 +199,11 @@ impl VoiceOpCode {
             VoiceOpCode::KeepAlive => 3,
             VoiceOpCode::SessionDescription => 4,
             VoiceOpCode::Speaking => 5,
+            VoiceOpCode::HeartbeatAck => 6,
+            VoiceOpCode::Resume => 7,
             VoiceOpCode::Heartbeat => 8,
+            VoiceOpCode::Resumed => 9,
+            VoiceOpCode::ClientDisconnect => 13,
         }
     }
 }
@@ -16,6 +16,8 @@ use std::{
 use hyper::Error as HyperError;
 #[cfg(feature = "native-tls")]
 use native_tls::Error as TlsError;
+#[cfg(feature = "simd-json")]
+use simd_json::Error as SimdJsonError;
 #[cfg(feature = "voice")]
 use opus::Error as OpusError;
 #[cfg(feature = "websocket")]
@@ -100,6 +102,9 @@ pub enum Error {
     /// An error from the `native-tls` crate.
     #[cfg(feature = "native-tls")]
     Tls(TlsError),
+    /// An error from the `simd-json` crate.
+    #[cfg(feature = "simd-json")]
+    SimdJson(SimdJsonError),
     /// An error from the `rust-websocket` crate.
     #[cfg(feature = "gateway")]
     WebSocket(WebSocketError),
@@ -111,6 +116,54 @@ pub enum Error {
     /// [voice module]: voice/index.html
     #[cfg(feature = "voice")]
     Voice(VoiceError),
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+impl Error {
+    /// Whether the error is an [`HttpError`] for a resource that does not
+    /// exist.
+    ///
+    /// # Examples
+    ///
+    /// Ignoring that a message was already deleted:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::{ChannelId, MessageId};
+    /// #
+    /// # fn try_main() -> serenity::Result<()> {
+    /// match ChannelId(7).delete_message(MessageId(8)) {
+    ///     Err(ref why) if why.is_not_found() => {},
+    ///     other => other?,
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`HttpError`]: http/enum.HttpError.html
+    pub fn is_not_found(&self) -> bool {
+        match *self {
+            #[cfg(feature = "http")]
+            Error::Http(ref inner) => inner.is_not_found(),
+            _ => false,
+        }
+    }
+
+    /// Whether the error is an [`HttpError`] for a request the current user
+    /// lacks the permissions for.
+    ///
+    /// [`HttpError`]: http/enum.HttpError.html
+    pub fn is_missing_permissions(&self) -> bool {
+        match *self {
+            #[cfg(feature = "http")]
+            Error::Http(ref inner) => inner.is_missing_permissions(),
+            _ => false,
+        }
+    }
 }
 
 impl From<FormatError> for Error {
@@ -148,6 +201,11 @@ impl From<OpusError> for Error {
     fn from(e: OpusError) -> Error { Error::Opus(e) }
 }
 
+#[cfg(feature = "simd-json")]
+impl From<SimdJsonError> for Error {
+    fn from(e: SimdJsonError) -> Error { Error::SimdJson(e) }
+}
+
 #[cfg(feature = "native-tls")]
 impl From<TlsError> for Error {
     fn from(e: TlsError) -> Error { Error::Tls(e) }
@@ -160,7 +218,11 @@ impl From<WebSocketError> for Error {
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(self.description())
+        match *self {
+            #[cfg(feature = "http")]
+            Error::Http(ref inner) => Display::fmt(inner, f),
+            _ => f.write_str(self.description()),
+        }
     }
 }
 
@@ -185,12 +247,15 @@ impl StdError for Error {
             Error::Hyper(ref inner) => inner.description(),
             #[cfg(feature = "voice")]
             Error::Opus(ref inner) => inner.description(),
+            #[cfg(feature = "simd-json")]
+            Error::SimdJson(ref inner) => inner.description(),
             #[cfg(feature = "native-tls")]
             Error::Tls(ref inner) => inner.description(),
             #[cfg(feature = "voice")]
             Error::Voice(_) => "Voice error",
             #[cfg(feature = "gateway")]
             Error::WebSocket(ref inner) => inner.description(),
+            Error::__Nonexhaustive => "An error occurred",
         }
     }
 
@@ -200,6 +265,8 @@ impl StdError for Error {
             Error::Hyper(ref inner) => Some(inner),
             Error::Json(ref inner) => Some(inner),
             Error::Io(ref inner) => Some(inner),
+            #[cfg(feature = "simd-json")]
+            Error::SimdJson(ref inner) => Some(inner),
             #[cfg(feature = "gateway")]
             Error::WebSocket(ref inner) => Some(inner),
             _ => None,
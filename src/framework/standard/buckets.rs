@@ -1,6 +1,6 @@
-use chrono::Utc;
 use client::Context;
 use model::id::{ChannelId, GuildId, UserId};
+use model::Timestamp;
 use std::{
     collections::HashMap,
     default::Default
@@ -12,6 +12,59 @@ type Check = Fn(&mut Context, Option<GuildId>, ChannelId, UserId) -> bool + Send
 #[cfg(not(feature = "cache"))]
 type Check = Fn(&mut Context, ChannelId, UserId) -> bool + Send + Sync + 'static;
 
+/// Whom the ratelimit of a bucket applies to.
+///
+/// **Note**: Defaults to [`User`].
+///
+/// [`User`]: #variant.User
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LimitedFor {
+    /// Each user is limited on their own.
+    User,
+    /// Each channel is limited on its own, shared by all users within it.
+    Channel,
+    /// Each guild is limited on its own, shared by all users within it.
+    /// Private channels are limited on their own instead.
+    Guild,
+}
+
+impl LimitedFor {
+    /// Returns the Id of the user, channel or guild that is limited.
+    pub(crate) fn target_id(&self,
+                            user_id: UserId,
+                            channel_id: ChannelId,
+                            guild_id: Option<GuildId>) -> u64 {
+        match *self {
+            LimitedFor::User => user_id.0,
+            LimitedFor::Channel => channel_id.0,
+            LimitedFor::Guild => guild_id.map_or(channel_id.0, |id| id.0),
+        }
+    }
+}
+
+impl Default for LimitedFor {
+    fn default() -> Self {
+        LimitedFor::User
+    }
+}
+
+/// Information about the ratelimit of a bucket that a command invocation hit,
+/// as given by [`DispatchError::RateLimited`].
+///
+/// [`DispatchError::RateLimited`]: enum.DispatchError.html#variant.RateLimited
+#[derive(Clone, Debug)]
+pub struct RateLimitInfo {
+    /// The name of the bucket.
+    pub bucket: String,
+    /// The number of seconds to wait before the command can be used again.
+    pub remaining: i64,
+    /// Whom the ratelimit applies to.
+    pub limited_for: LimitedFor,
+    /// The number of times the ratelimit was hit in a row, including this
+    /// one, which is reset once the command can be used again.
+    pub hits: u32,
+}
+
 pub(crate) struct Ratelimit {
     pub delay: i64,
     pub limit: Option<(i64, i32)>,
@@ -22,39 +75,128 @@ pub(crate) struct MemberRatelimit {
     pub last_time: i64,
     pub set_time: i64,
     pub tickets: i32,
+    pub hits: u32,
 }
 
 pub(crate) struct Bucket {
     pub ratelimit: Ratelimit,
-    pub users: HashMap<u64, MemberRatelimit>,
+    pub targets: HashMap<u64, MemberRatelimit>,
     pub check: Option<Box<Check>>,
+    pub limited_for: LimitedFor,
 }
 
 impl Bucket {
-    pub fn take(&mut self, user_id: u64) -> i64 {
-        let time = Utc::now().timestamp();
-        let user = self.users
-            .entry(user_id)
+    /// Takes a ticket for the user, channel or guild of the given Id, as
+    /// determined by the bucket's [`LimitedFor`].
+    ///
+    /// Returns the number of seconds left to wait, which is `0` if the ticket
+    /// was taken, and the number of times in a row the ratelimit was hit.
+    ///
+    /// [`LimitedFor`]: enum.LimitedFor.html
+    pub fn take(&mut self, target_id: u64) -> (i64, u32) {
+        self.take_at(target_id, Timestamp::now().unix_timestamp())
+    }
+
+    fn take_at(&mut self, target_id: u64, time: i64) -> (i64, u32) {
+        let target = self.targets
+            .entry(target_id)
             .or_insert_with(MemberRatelimit::default);
 
         if let Some((timespan, limit)) = self.ratelimit.limit {
-            if (user.tickets + 1) > limit {
-                if time < (user.set_time + timespan) {
-                    return (user.set_time + timespan) - time;
+            if (target.tickets + 1) > limit {
+                if time < (target.set_time + timespan) {
+                    target.hits += 1;
+
+                    return ((target.set_time + timespan) - time, target.hits);
                 } else {
-                    user.tickets = 0;
-                    user.set_time = time;
+                    target.tickets = 0;
+                    target.set_time = time;
                 }
             }
         }
 
-        if time < user.last_time + self.ratelimit.delay {
-            (user.last_time + self.ratelimit.delay) - time
+        if time < target.last_time + self.ratelimit.delay {
+            target.hits += 1;
+
+            ((target.last_time + self.ratelimit.delay) - time, target.hits)
         } else {
-            user.tickets += 1;
-            user.last_time = time;
+            target.tickets += 1;
+            target.last_time = time;
+            target.hits = 0;
+
+            (0, 0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::id::{ChannelId, GuildId, UserId};
+    use super::{Bucket, LimitedFor, Ratelimit};
+    use std::collections::HashMap;
 
-            0
+    fn bucket(delay: i64, limit: Option<(i64, i32)>) -> Bucket {
+        Bucket {
+            ratelimit: Ratelimit { delay, limit },
+            targets: HashMap::new(),
+            check: None,
+            limited_for: LimitedFor::default(),
         }
     }
+
+    #[test]
+    fn delay() {
+        let mut bucket = bucket(5, None);
+
+        assert_eq!(bucket.take_at(1, 100), (0, 0));
+        assert_eq!(bucket.take_at(1, 102), (3, 1));
+        assert_eq!(bucket.take_at(1, 105), (0, 0));
+
+        // Other targets are limited on their own.
+        assert_eq!(bucket.take_at(2, 102), (0, 0));
+    }
+
+    #[test]
+    fn limit_window() {
+        let mut bucket = bucket(0, Some((30, 2)));
+
+        // A window starts once the tickets are used up the first time.
+        assert_eq!(bucket.take_at(1, 100), (0, 0));
+        assert_eq!(bucket.take_at(1, 101), (0, 0));
+        assert_eq!(bucket.take_at(1, 110), (0, 0));
+        assert_eq!(bucket.take_at(1, 111), (0, 0));
+        assert_eq!(bucket.take_at(1, 115), (25, 1));
+        assert_eq!(bucket.take_at(1, 116), (24, 2));
+
+        // The tickets are given back once the window is over.
+        assert_eq!(bucket.take_at(1, 140), (0, 0));
+        assert_eq!(bucket.take_at(1, 141), (0, 0));
+        assert_eq!(bucket.take_at(1, 142), (28, 1));
+    }
+
+    #[test]
+    fn hits() {
+        let mut bucket = bucket(10, None);
+
+        assert_eq!(bucket.take_at(1, 100), (0, 0));
+        assert_eq!(bucket.take_at(1, 101), (9, 1));
+        assert_eq!(bucket.take_at(1, 102), (8, 2));
+        assert_eq!(bucket.take_at(1, 103), (7, 3));
+
+        // The hits are reset once a ticket is taken again.
+        assert_eq!(bucket.take_at(1, 110), (0, 0));
+        assert_eq!(bucket.take_at(1, 111), (9, 1));
+    }
+
+    #[test]
+    fn targets() {
+        let (user, channel, guild) = (UserId(1), ChannelId(2), GuildId(3));
+
+        assert_eq!(LimitedFor::User.target_id(user, channel, Some(guild)), 1);
+        assert_eq!(LimitedFor::Channel.target_id(user, channel, Some(guild)), 2);
+        assert_eq!(LimitedFor::Guild.target_id(user, channel, Some(guild)), 3);
+
+        // Private channels are limited on their own.
+        assert_eq!(LimitedFor::Guild.target_id(user, channel, None), 2);
+    }
 }
@@ -1,39 +1,59 @@
 use client::Context;
 use model::{
-    channel::{
-        Message,
-        Channel,
-    },
+    channel::Message,
     Permissions
 };
 use std::{
+    any::Any,
     collections::HashMap,
     fmt,
     fmt::{Debug, Formatter},
-    sync::Arc
+    sync::Arc,
+    time::Duration
 };
 use utils::Colour;
-use super::{Args, Configuration, HelpBehaviour};
+use super::{Args, HelpBehaviour};
 
 type CheckFunction = Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
                      + Send
                      + Sync
                      + 'static;
 
-pub struct Check(pub(crate) Box<CheckFunction>);
+#[derive(Clone)]
+pub struct Check {
+    pub(crate) function: Arc<CheckFunction>,
+    /// Whether the check is bypassed for [owners].
+    ///
+    /// [owners]: struct.Configuration.html#method.owners
+    pub owner_privilege: bool,
+    /// The name of the check, displayed by the help-commands.
+    pub name: Option<String>,
+}
 
 impl Check {
-    pub(crate) fn new<F: Send + Sync + 'static>(f: F) -> Self
+    pub(crate) fn new<F: Send + Sync + 'static>(f: F, owner_privilege: bool) -> Self
         where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
     {
-        Check(Box::new(f))
+        Check {
+            function: Arc::new(f),
+            owner_privilege,
+            name: None,
+        }
+    }
+
+    pub(crate) fn named(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+
+        self
     }
 }
 
 impl Debug for Check {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_tuple("Check")
-            .field(&"<fn>")
+        f.debug_struct("Check")
+            .field("function", &"<fn>")
+            .field("owner_privilege", &self.owner_privilege)
+            .field("name", &self.name)
             .finish()
     }
 }
@@ -59,7 +79,12 @@ impl HelpCommand for Help {
 
 pub type BeforeHook = Fn(&mut Context, &Message, &str) -> bool + Send + Sync + 'static;
 pub type AfterHook = Fn(&mut Context, &Message, &str, Result<(), Error>) + Send + Sync + 'static;
+pub type AfterOutputHook = Fn(&mut Context, &Message, &str, CommandResult<Option<CommandOutput>>)
+                           + Send
+                           + Sync
+                           + 'static;
 pub type UnrecognisedCommandHook = Fn(&mut Context, &Message, &str) + Send + Sync + 'static;
+pub type UnrecognisedCommandSuggestionsHook = Fn(&mut Context, &Message, &str, &[String]) + Send + Sync + 'static;
 pub(crate) type InternalCommand = Arc<Command>;
 pub type PrefixCheck = Fn(&mut Context, &Message) -> Option<String> + Send + Sync + 'static;
 
@@ -81,6 +106,17 @@ impl fmt::Debug for CommandOrAlias {
 #[derive(Clone, Debug)]
 pub struct Error(pub String);
 
+/// The result of a command, optionally carrying a value on success.
+pub type CommandResult<T = ()> = ::std::result::Result<T, Error>;
+
+/// A value produced by a successful command, e.g. a summary of the action
+/// taken.
+///
+/// Refer to [`StandardFramework::after_with_output`] for receiving it.
+///
+/// [`StandardFramework::after_with_output`]: struct.StandardFramework.html#method.after_with_output
+pub type CommandOutput = Box<Any + Send>;
+
 // TODO: Have seperate `From<(&)String>` and `From<&str>` impls via specialization
 impl<D: fmt::Display> From<D> for Error {
     fn from(d: D) -> Self {
@@ -106,6 +142,11 @@ pub struct CommandGroup {
     pub checks: Vec<Check>,
     pub default_command: Option<CommandOrAlias>,
     pub description: Option<String>,
+    /// Groups nested within this group, by name.
+    ///
+    /// Their options already include the ones inherited from this group, and
+    /// their prefixes are preceded by this group's prefixes.
+    pub sub_groups: HashMap<String, Arc<CommandGroup>>,
 }
 
 impl Default for CommandGroup {
@@ -124,6 +165,7 @@ impl Default for CommandGroup {
             checks: Vec::new(),
             default_command: None,
             description: None,
+            sub_groups: HashMap::new(),
         }
     }
 }
@@ -138,19 +180,37 @@ pub struct CommandOptions {
     /// Command description, used by other commands.
     pub desc: Option<String>,
     /// Example arguments, used by other commands.
+    ///
+    /// This is the first of [`examples`], kept for compatibility.
+    ///
+    /// [`examples`]: #structfield.examples
     pub example: Option<String>,
+    /// All example arguments, used by other commands such as help.
+    pub examples: Vec<String>,
     /// Command usage schema, used by other commands.
     pub usage: Option<String>,
+    /// Delimiters splitting the command's arguments, taking precedence over
+    /// the framework's delimiters if not empty.
+    pub delimiters: Vec<String>,
     /// Minumum amount of arguments that should be passed.
     pub min_args: Option<i32>,
     /// Maximum amount of arguments that can be passed.
     pub max_args: Option<i32>,
+    /// Maximum duration a command may take to execute before it is considered
+    /// to have failed.
+    pub timeout: Option<Duration>,
+    /// Template of the reply sent when too few arguments were passed.
+    pub not_enough_args_message: Option<String>,
+    /// Template of the reply sent when too many arguments were passed.
+    pub too_many_args_message: Option<String>,
     /// Permissions required to use this command.
     pub required_permissions: Permissions,
     /// Roles allowed to use this command.
     pub allowed_roles: Vec<String>,
     /// Whether command should be displayed in help list or not, used by other commands.
     pub help_available: bool,
+    /// Whether the typing indicator is shown while the command executes.
+    pub broadcast_typing: bool,
     /// Whether command can be used only privately or not.
     pub dm_only: bool,
     /// Whether command can be used only in guilds or not.
@@ -159,6 +219,12 @@ pub struct CommandOptions {
     pub owners_only: bool,
     /// Other names that can be used to call this command instead.
     pub aliases: Vec<String>,
+    /// Whether the checks of the group this command belongs to must pass
+    /// before the command's own checks are run.
+    pub inherit_group_checks: bool,
+    /// Arbitrary key-value pairs attached to the command, e.g. a category icon
+    /// or a stability tag, for use by custom help-commands and hooks.
+    pub metadata: HashMap<String, String>,
 }
 
 #[derive(Debug)]
@@ -220,6 +286,18 @@ pub struct HelpOptions {
     pub embed_success_colour: Colour,
     /// If not 0, help will check whether a command is similar to searched named.
     pub max_levenshtein_distance: usize,
+    /// Whether a command's requirements, being its required permissions and
+    /// roles, whether it is limited to owners, and the names of its checks,
+    /// are displayed.
+    pub show_requirements: bool,
+    /// Text labeling the permissions required by a command.
+    pub required_permissions_label: String,
+    /// Text labeling the roles a command is limited to.
+    pub allowed_roles_label: String,
+    /// Text labeling the names of a command's checks.
+    pub checks_label: String,
+    /// Text explaining a command can only be used by owners.
+    pub owners_only_text: String,
 }
 
 pub trait HelpCommand: Send + Sync + 'static {
@@ -263,6 +341,11 @@ impl Default for HelpOptions {
             embed_error_colour: Colour::DARK_RED,
             embed_success_colour: Colour::ROSEWATER,
             max_levenshtein_distance: 0,
+            show_requirements: true,
+            required_permissions_label: "Required permissions".to_string(),
+            allowed_roles_label: "Required roles".to_string(),
+            checks_label: "Checks".to_string(),
+            owners_only_text: "Only for owners".to_string(),
         }
     }
 }
@@ -275,6 +358,17 @@ lazy_static! {
 pub trait Command: Send + Sync + 'static {
     fn execute(&self, &mut Context, &Message, Args) -> Result<(), Error>;
 
+    /// Executes the command, additionally producing a value on success that is
+    /// passed to the [`after_with_output`] hook.
+    ///
+    /// Defaults to calling [`execute`] without producing a value.
+    ///
+    /// [`after_with_output`]: struct.StandardFramework.html#method.after_with_output
+    /// [`execute`]: #tymethod.execute
+    fn execute_with_output(&self, c: &mut Context, m: &Message, a: Args) -> CommandResult<Option<CommandOutput>> {
+        self.execute(c, m, a).map(|()| None)
+    }
+
     fn options(&self) -> Arc<CommandOptions> {
         Arc::clone(&DEFAULT_OPTIONS)
     }
@@ -294,6 +388,10 @@ impl Command for Arc<Command> {
         (**self).execute(c, m, a)
     }
 
+    fn execute_with_output(&self, c: &mut Context, m: &Message, a: Args) -> CommandResult<Option<CommandOutput>> {
+        (**self).execute_with_output(c, m, a)
+    }
+
     fn options(&self) -> Arc<CommandOptions> {
         (**self).options()
     }
@@ -328,99 +426,24 @@ impl Default for CommandOptions {
             checks: Vec::default(),
             desc: None,
             usage: None,
+            delimiters: Vec::new(),
             example: None,
+            examples: Vec::new(),
             min_args: None,
             bucket: None,
             max_args: None,
+            timeout: None,
+            not_enough_args_message: None,
+            too_many_args_message: None,
             required_permissions: Permissions::empty(),
             dm_only: false,
             guild_only: false,
             help_available: true,
+            broadcast_typing: false,
             owners_only: false,
             allowed_roles: Vec::new(),
+            inherit_group_checks: true,
+            metadata: HashMap::new(),
         }
     }
 }
-
-pub fn positions(ctx: &mut Context, msg: &Message, conf: &Configuration) -> Option<Vec<usize>> {
-    // Mentions have the highest precedence.
-    if let Some(mention_end) = find_mention_end(&msg.content, conf) {
-        return Some(vec![mention_end]); // This can simply be returned without trying to find the end whitespaces as trim will remove it later
-    }
-
-    if !conf.prefixes.is_empty() || conf.dynamic_prefix.is_some() {
-        // Determine if a prefix was used. Otherwise return None.
-        let mut positions = Vec::new();
-
-        // Dynamic prefixes, if present and suitable, always have a higher priority.
-        if let Some(x) = conf.dynamic_prefix.as_ref().and_then(|f| f(ctx, msg)) {
-            if msg.content.starts_with(&x) {
-                positions.push(x.chars().count());
-            }
-        } else {
-            for n in &conf.prefixes {
-                if msg.content.starts_with(n) {
-                    positions.push(n.chars().count());
-                }
-            }
-        }
-
-        #[cfg(feature = "cache")]
-        {
-            let private = match msg.channel() {
-                Some(Channel::Private(_)) => true,
-                _ => false,
-            };
-
-            // If the above do not fill `positions`, then that means no kind of prefix was present.
-            // Check if a no-prefix-execution is applicable.
-            if conf.no_dm_prefix && private && positions.is_empty() {
-                positions.push(0);
-            }
-        }
-
-        if positions.is_empty() {
-            return None;
-        }
-
-        let pos = *unsafe { positions.get_unchecked(0) };
-
-        let with_whitespace = find_end_of_prefix_with_whitespace(&msg.content, pos);
-
-        if conf.allow_whitespace {
-            positions.insert(0, with_whitespace.unwrap_or(pos));
-        } else if with_whitespace.is_some() {
-            return None;
-        }
-
-        Some(positions)
-    } else {
-        None
-    }
-}
-
-fn find_mention_end(content: &str, conf: &Configuration) -> Option<usize> {
-    conf.on_mention.as_ref().and_then(|mentions| {
-        mentions
-            .iter()
-            .find(|mention| content.starts_with(&mention[..]))
-            .map(|m| m.len())
-    })
-}
-
-// Finds the end of the first continuous block of whitespace after the prefix
-fn find_end_of_prefix_with_whitespace(content: &str, position: usize) -> Option<usize> {
-    let content_len = content.len();
-    if position >= content_len { return None; }
-
-    let mut i = 0;
-    let chars = content.chars().skip(position);
-    for char in chars {
-        match char {
-            // \t \n \r [space]
-            '\t' | '\n' | '\r' | ' ' => i += 1,
-            _ => return if i == 0 { None } else { Some(position + i) }
-        }
-    }
-    Some(content.len())
-}
@@ -6,9 +6,11 @@ use model::{
 };
 use std::{
     collections::HashSet,
-    default::Default
+    default::Default,
+    sync::Arc
 };
 use super::command::PrefixCheck;
+use super::prefix_store::PrefixStore;
 
 /// The configuration to use for a [`StandardFramework`] associated with a [`Client`]
 /// instance.
@@ -45,6 +47,7 @@ use super::command::PrefixCheck;
 pub struct Configuration {
     #[doc(hidden)] pub allow_dm: bool,
     #[doc(hidden)] pub allow_whitespace: bool,
+    #[doc(hidden)] pub broadcast_typing: bool,
     #[doc(hidden)] pub blocked_guilds: HashSet<GuildId>,
     #[doc(hidden)] pub blocked_users: HashSet<UserId>,
     #[doc(hidden)] pub allowed_channels: HashSet<ChannelId>,
@@ -53,12 +56,15 @@ pub struct Configuration {
     #[doc(hidden)] pub dynamic_prefix: Option<Box<PrefixCheck>>,
     #[doc(hidden)] pub ignore_bots: bool,
     #[doc(hidden)] pub ignore_webhooks: bool,
+    #[doc(hidden)] pub max_levenshtein_distance: usize,
     #[doc(hidden)] pub on_mention: Option<Vec<String>>,
     #[doc(hidden)] pub owners: HashSet<UserId>,
     #[doc(hidden)] pub prefixes: Vec<String>,
+    #[doc(hidden)] pub prefix_store: Option<Arc<PrefixStore>>,
     #[doc(hidden)] pub no_dm_prefix: bool,
     #[doc(hidden)] pub delimiters: Vec<String>,
     #[doc(hidden)] pub case_insensitive: bool,
+    #[doc(hidden)] pub case_insensitive_group_prefixes: Option<bool>,
 }
 
 impl Configuration {
@@ -127,6 +133,21 @@ impl Configuration {
         self
     }
 
+    /// Whether to show the typing indicator in the channel while any command
+    /// executes, refreshing it until the command finished.
+    ///
+    /// Individual commands can enable this via
+    /// [`CreateCommand::broadcast_typing`] instead.
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// [`CreateCommand::broadcast_typing`]: struct.CreateCommand.html#method.broadcast_typing
+    pub fn broadcast_typing(mut self, broadcast_typing: bool) -> Self {
+        self.broadcast_typing = broadcast_typing;
+
+        self
+    }
+
     /// HashSet of guild Ids where commands will be ignored.
     ///
     /// **Note**: Defaults to an empty HashSet.
@@ -373,6 +394,40 @@ impl Configuration {
         self
     }
 
+    /// Sets the [`owners`] to the owners of the current application, as
+    /// retrieved from the application info.
+    ///
+    /// If the application belongs to a team, `team_members` decides whether
+    /// all members of the team are owners or only the team's owner is.
+    ///
+    /// **Note**: The owners are left untouched if the application info could
+    /// not be retrieved.
+    ///
+    /// # Examples
+    ///
+    /// Treating all members of the application's team as owners:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new().configure(|c| c
+    ///     .owners_from_application(true)));
+    /// ```
+    ///
+    /// [`owners`]: #method.owners
+    pub fn owners_from_application(mut self, team_members: bool) -> Self {
+        if let Ok(info) = http::get_current_application_info() {
+            self.owners = info.owner_ids(team_members);
+        }
+
+        self
+    }
+
     /// Sets the prefix to respond to. A prefix can be a string slice of any
     /// non-zero length.
     ///
@@ -429,6 +484,58 @@ impl Configuration {
         self
     }
 
+    /// Sets the storage of prefixes per guild to consult when a message is sent
+    /// in a guild. The prefix of the guild, if any, is used instead of the
+    /// configured [`prefixes`].
+    ///
+    /// Keep a clone of the `Arc` to change the prefixes at runtime.
+    ///
+    /// **Note**: Defaults to no prefix store.
+    ///
+    /// # Examples
+    ///
+    /// Using the in-memory store, with a prefix for a guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::standard::{MemoryPrefixStore, PrefixStore};
+    /// use serenity::framework::StandardFramework;
+    /// use serenity::model::id::GuildId;
+    /// use std::sync::Arc;
+    ///
+    /// let store = Arc::new(MemoryPrefixStore::new());
+    /// store.set_prefix(GuildId(7), "!".to_string());
+    ///
+    /// client.with_framework(StandardFramework::new().configure(|c| c
+    ///     .prefix("~")
+    ///     .prefix_store(store)));
+    /// ```
+    ///
+    /// [`prefixes`]: #method.prefixes
+    pub fn prefix_store(mut self, store: Arc<PrefixStore>) -> Self {
+        self.prefix_store = Some(store);
+
+        self
+    }
+
+    /// Sets the maximum Levenshtein distance of the names of commands to
+    /// suggest to an unrecognised one, as passed to the
+    /// [`unrecognised_command_with_suggestions`] hook.
+    ///
+    /// **Note**: Defaults to `2`.
+    ///
+    /// [`unrecognised_command_with_suggestions`]: struct.StandardFramework.html#method.unrecognised_command_with_suggestions
+    pub fn max_levenshtein_distance(mut self, distance: usize) -> Self {
+        self.max_levenshtein_distance = distance;
+
+        self
+    }
+
     /// Sets whether command execution can done without a prefix. Works only in private channels.
     ///
     /// **Note**: Defaults to `false`.
@@ -511,6 +618,42 @@ impl Configuration {
 
         self
     }
+
+    /// Whether group prefixes should be matched regardless of case, e.g. both
+    /// `~Music play` and `~MUSIC play` invoking the `play` command of a group
+    /// with the `music` prefix.
+    ///
+    /// This is independent of [`case_insensitivity`], which then only applies
+    /// to command names, allowing prefixes to be case insensitive while
+    /// commands are not, and vice versa. Prefixes do not need to be registered
+    /// in lowercase for this.
+    ///
+    /// **Note**: Defaults to the value of [`case_insensitivity`].
+    ///
+    /// # Examples
+    ///
+    /// Matching group prefixes regardless of case, while command names are
+    /// case sensitive:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new().configure(|c| c
+    ///     .case_insensitivity(false)
+    ///     .group_prefix_case_insensitivity(true)));
+    /// ```
+    ///
+    /// [`case_insensitivity`]: #method.case_insensitivity
+    pub fn group_prefix_case_insensitivity(mut self, cs: bool) -> Self {
+        self.case_insensitive_group_prefixes = Some(cs);
+
+        self
+    }
 }
 
 impl Default for Configuration {
@@ -521,17 +664,21 @@ impl Default for Configuration {
     /// - **allowed_channels** to an empty HashSet
     /// - **blocked_guilds** to an empty HashSet
     /// - **blocked_users** to an empty HashSet
+    /// - **broadcast_typing** to `false`
     /// - **case_insensitive** to `false`
+    /// - **case_insensitive_group_prefixes** to the value of **case_insensitive**
     /// - **delimiters** to `vec![" "]`
     /// - **depth** to `5`
     /// - **disabled_commands** to an empty HashSet
     /// - **dynamic_prefix** to no dynamic prefix check
     /// - **ignore_bots** to `true`
     /// - **ignore_webhooks** to `true`
+    /// - **max_levenshtein_distance** to `2`
     /// - **no_dm_prefix** to `false`
     /// - **on_mention** to `false` (basically)
     /// - **owners** to an empty HashSet
     /// - **prefix** to an empty vector
+    /// - **prefix_store** to no prefix store
     fn default() -> Configuration {
         Configuration {
             allow_dm: true,
@@ -539,17 +686,21 @@ impl Default for Configuration {
             allowed_channels: HashSet::default(),
             blocked_guilds: HashSet::default(),
             blocked_users: HashSet::default(),
+            broadcast_typing: false,
             case_insensitive: false,
+            case_insensitive_group_prefixes: None,
             delimiters: vec![" ".to_string()],
             depth: 5,
             disabled_commands: HashSet::default(),
             dynamic_prefix: None,
             ignore_bots: true,
             ignore_webhooks: true,
+            max_levenshtein_distance: 2,
             no_dm_prefix: false,
             on_mention: None,
             owners: HashSet::default(),
             prefixes: vec![],
+            prefix_store: None,
         }
     }
 }
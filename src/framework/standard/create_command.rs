@@ -6,13 +6,19 @@ pub use super::{
     CommandError,
     Check,
 };
+use super::command::{CommandOutput, CommandResult};
 
 use client::Context;
 use model::{
     channel::Message,
     Permissions
 };
-use std::sync::Arc;
+use std::{
+    any::Any,
+    marker::PhantomData,
+    sync::Arc,
+    time::Duration
+};
 
 pub enum FnOrCommand {
     Fn(fn(&mut Context, &Message, Args) -> Result<(), CommandError>),
@@ -50,6 +56,19 @@ impl CreateCommand {
         self
     }
 
+    /// Whether the typing indicator should be shown in the channel for as long
+    /// as the command executes.
+    ///
+    /// **Note**: Defaults to `false`. Refer to
+    /// [`Configuration::broadcast_typing`] to enable this for all commands.
+    ///
+    /// [`Configuration::broadcast_typing`]: struct.Configuration.html#method.broadcast_typing
+    pub fn broadcast_typing(mut self, broadcast_typing: bool) -> Self {
+        self.0.broadcast_typing = broadcast_typing;
+
+        self
+    }
+
     /// Adds a ratelimit bucket.
     pub fn bucket(mut self, bucket: &str) -> Self {
         self.0.bucket = Some(bucket.to_string());
@@ -60,7 +79,8 @@ impl CreateCommand {
     /// Adds a "check" to a command, which checks whether or not the command's
     /// function should be called.
     ///
-    /// **Note**: These checks are bypassed for commands sent by the application owner.
+    /// **Note**: These checks are bypassed for commands sent by the application
+    /// owner, use [`check_with_owner_privilege`] to change this per check.
     ///
     /// # Examples
     ///
@@ -104,12 +124,80 @@ impl CreateCommand {
     ///     message.author.id == 7
     /// }
     /// ```
+    ///
+    /// [`check_with_owner_privilege`]: #method.check_with_owner_privilege
     pub fn check<F>(mut self, check: F) -> Self
         where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
                      + Send
                      + Sync
                      + 'static {
-        self.0.checks.push(Check::new(check));
+        self.0.checks.push(Check::new(check, true));
+
+        self
+    }
+
+    /// Like [`check`], but allows declaring whether the check is bypassed for
+    /// commands sent by the application owner.
+    ///
+    /// Passing `false` as `owner_privilege` makes the check apply to owners
+    /// as well, e.g. for a check asserting the guild has a premium plan.
+    ///
+    /// [`check`]: #method.check
+    pub fn check_with_owner_privilege<F>(mut self, check: F, owner_privilege: bool) -> Self
+        where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
+                     + Send
+                     + Sync
+                     + 'static {
+        self.0.checks.push(Check::new(check, owner_privilege));
+
+        self
+    }
+
+    /// Like [`check`], but names the check, so that it is listed among the
+    /// command's requirements by the help-commands.
+    ///
+    /// [`check`]: #method.check
+    pub fn named_check<F>(mut self, name: &str, check: F) -> Self
+        where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
+                     + Send
+                     + Sync
+                     + 'static {
+        self.0.checks.push(Check::new(check, true).named(name));
+
+        self
+    }
+
+    /// Whether the checks of the group this command is part of have to pass
+    /// before this command's own checks are evaluated.
+    ///
+    /// Setting this to `false` lets a command opt out of its group's checks,
+    /// including the ones a sub-group inherited from its parents, e.g. to have
+    /// an `info` command inside of an otherwise moderator-only group.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn inherit_group_checks(mut self, inherit: bool) -> Self {
+        self.0.inherit_group_checks = inherit;
+
+        self
+    }
+
+    /// Sets the delimiters splitting this command's arguments, overriding the
+    /// [framework-wide delimiters] for this command only.
+    ///
+    /// # Examples
+    ///
+    /// Splitting the arguments of a `multiply` command by commas, allowing
+    /// `~multiply 2, 3, 4`:
+    ///
+    /// ```rust,ignore
+    /// framework.command("multiply", |c| c
+    ///     .delimiters(vec![", ", ","])
+    ///     .cmd(multiply))
+    /// ```
+    ///
+    /// [framework-wide delimiters]: struct.Configuration.html#method.delimiters
+    pub fn delimiters<T: ToString, It: IntoIterator<Item=T>>(mut self, delimiters: It) -> Self {
+        self.0.delimiters = delimiters.into_iter().map(|d| d.to_string()).collect();
 
         self
     }
@@ -128,9 +216,27 @@ impl CreateCommand {
         self
     }
 
-    /// Example arguments, used by other commands.
+    /// Adds example arguments, used by other commands.
+    ///
+    /// Can be called multiple times to add several examples, all of which the
+    /// default help-commands display under the [usage sample label].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// framework.command("multiply", |c| c
+    ///     .usage("<number> <number>")
+    ///     .example("2 3")
+    ///     .example("0.5 8"))
+    /// ```
+    ///
+    /// [usage sample label]: struct.CreateHelpCommand.html#method.usage_sample_label
     pub fn example(mut self, example: &str) -> Self {
-        self.0.example = Some(example.to_string());
+        if self.0.example.is_none() {
+            self.0.example = Some(example.to_string());
+        }
+
+        self.0.examples.push(example.to_string());
 
         self
     }
@@ -143,6 +249,45 @@ impl CreateCommand {
         self
     }
 
+    /// Like [`exec`], but the function may return a value on success, which
+    /// is passed to the [`after_with_output`] hook, e.g. to log the actions
+    /// taken by commands in a central place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// framework.command("ban", |c| c
+    ///     .exec_with_output(|_, msg, _| {
+    ///         // Banning the mentioned users...
+    ///
+    ///         Ok(format!("{} banned {} user(s)", msg.author.tag(), msg.mentions.len()))
+    ///     }))
+    /// ```
+    ///
+    /// [`after_with_output`]: struct.StandardFramework.html#method.after_with_output
+    /// [`exec`]: #method.exec
+    pub fn exec_with_output<F, T>(mut self, func: F) -> Self
+        where F: Fn(&mut Context, &Message, Args) -> CommandResult<T> + Send + Sync + 'static,
+              T: Any + Send {
+        struct WithOutput<F, T>(F, PhantomData<fn() -> T>);
+
+        impl<F, T> Command for WithOutput<F, T>
+            where F: Fn(&mut Context, &Message, Args) -> CommandResult<T> + Send + Sync + 'static,
+                  T: Any + Send {
+            fn execute(&self, c: &mut Context, m: &Message, a: Args) -> Result<(), CommandError> {
+                (self.0)(c, m, a).map(|_| ())
+            }
+
+            fn execute_with_output(&self, c: &mut Context, m: &Message, a: Args) -> CommandResult<Option<CommandOutput>> {
+                (self.0)(c, m, a).map(|output| Some(Box::new(output) as CommandOutput))
+            }
+        }
+
+        self.1 = FnOrCommand::Command(Arc::new(WithOutput(func, PhantomData)));
+
+        self
+    }
+
     /// Like [`exec`] but accepts a `Command` directly.
     ///
     /// [`exec`]: #method.exec
@@ -196,6 +341,47 @@ impl CreateCommand {
         self
     }
 
+    /// Sets the reply sent in place of calling the dispatch error handler when
+    /// fewer than [`min_args`] arguments were passed.
+    ///
+    /// The following placeholders will be substituted:
+    ///
+    /// - `{name}`: the name the command was invoked with;
+    /// - `{usage}`: the command's [`usage`], or an empty string;
+    /// - `{min}`: the minimum amount of arguments;
+    /// - `{given}`: the amount of arguments passed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// framework.command("multiply", |c| c
+    ///     .usage("<number> <number>")
+    ///     .min_args(2)
+    ///     .not_enough_args_message("Expected {min} numbers, got {given}. Usage: `{name} {usage}`"))
+    /// ```
+    ///
+    /// [`min_args`]: #method.min_args
+    /// [`usage`]: #method.usage
+    pub fn not_enough_args_message(mut self, template: &str) -> Self {
+        self.0.not_enough_args_message = Some(template.to_string());
+
+        self
+    }
+
+    /// Sets the reply sent in place of calling the dispatch error handler when
+    /// more than [`max_args`] arguments were passed.
+    ///
+    /// Supports the same placeholders as [`not_enough_args_message`], except
+    /// that `{max}` takes the place of `{min}`.
+    ///
+    /// [`max_args`]: #method.max_args
+    /// [`not_enough_args_message`]: #method.not_enough_args_message
+    pub fn too_many_args_message(mut self, template: &str) -> Self {
+        self.0.too_many_args_message = Some(template.to_string());
+
+        self
+    }
+
     /// Exact number of arguments that should be passed.
     pub fn num_args(mut self, num_args: i32) -> Self {
         self.0.min_args = Some(num_args);
@@ -204,6 +390,43 @@ impl CreateCommand {
         self
     }
 
+    /// Attaches a piece of custom metadata to the command, overwriting any
+    /// previous value stored under the same `key`.
+    ///
+    /// The framework itself does not interpret metadata, it is made available
+    /// via [`CommandOptions::metadata`] for custom help-commands and
+    /// middleware.
+    ///
+    /// # Examples
+    ///
+    /// Tagging a command as experimental:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .command("ping", |c| c
+    ///         .metadata("stability", "experimental")
+    ///         .exec(|_, msg, _| {
+    ///             msg.channel_id.say("pong!")?;
+    ///
+    ///             Ok(())
+    ///         })));
+    /// ```
+    ///
+    /// [`CommandOptions::metadata`]: struct.CommandOptions.html#structfield.metadata
+    pub fn metadata<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.0.metadata.insert(key.to_string(), value.to_string());
+
+        self
+    }
+
     /// Whether command can be used only privately or not.
     pub fn owners_only(mut self, owners_only: bool) -> Self {
         self.0.owners_only = owners_only;
@@ -219,6 +442,22 @@ impl CreateCommand {
         self
     }
 
+    /// Sets the maximum duration the command may take to execute.
+    ///
+    /// Once exceeded, the `after` middleware and the framework's [`after`] hook
+    /// are called with an error, without waiting for the command any longer.
+    ///
+    /// **Note**: The command is executed on a separate thread in order to be
+    /// watched. As threads cannot be cancelled, a timed out command will keep
+    /// running in the background until it finishes on its own.
+    ///
+    /// [`after`]: struct.StandardFramework.html#method.after
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.0.timeout = Some(duration);
+
+        self
+    }
+
     /// Command usage schema, used by other commands.
     pub fn usage(mut self, usage: &str) -> Self {
         self.0.usage = Some(usage.to_string());
@@ -271,6 +510,10 @@ impl CreateCommand {
                 self.1.execute(c, m, a)
             }
 
+            fn execute_with_output(&self, c: &mut Context, m: &Message, a: Args) -> CommandResult<Option<CommandOutput>> {
+                self.1.execute_with_output(c, m, a)
+            }
+
             fn options(&self) -> Arc<CommandOptions> { Arc::clone(&self.0) }
 
             fn init(&self) {
@@ -308,3 +551,65 @@ impl CreateCommand {
     }
 }
 
+
+#[cfg(test)]
+mod test {
+    use super::CreateCommand;
+
+    #[test]
+    fn metadata() {
+        let command = CreateCommand::default()
+            .metadata("stability", "experimental")
+            .metadata("icon", ":tools:")
+            .metadata("stability", "stable")
+            .finish();
+
+        let options = command.options();
+
+        assert_eq!(options.metadata.len(), 2);
+        assert_eq!(options.metadata.get("stability").map(String::as_str), Some("stable"));
+        assert_eq!(options.metadata.get("icon").map(String::as_str), Some(":tools:"));
+    }
+
+    #[test]
+    fn args_messages() {
+        let command = CreateCommand::default()
+            .not_enough_args_message("At least {min}")
+            .too_many_args_message("At most {max}")
+            .finish();
+
+        let options = command.options();
+
+        assert_eq!(options.not_enough_args_message.as_ref().map(String::as_str), Some("At least {min}"));
+        assert_eq!(options.too_many_args_message.as_ref().map(String::as_str), Some("At most {max}"));
+    }
+
+    #[test]
+    fn checks_owner_privilege() {
+        let command = CreateCommand::default()
+            .check(|_, _, _, _| true)
+            .check_with_owner_privilege(|_, _, _, _| true, false)
+            .finish();
+
+        let privileges = command.options()
+            .checks
+            .iter()
+            .map(|check| check.owner_privilege)
+            .collect::<Vec<_>>();
+
+        assert_eq!(privileges, vec![true, false]);
+    }
+
+    #[test]
+    fn examples() {
+        let command = CreateCommand::default()
+            .example("2 3")
+            .example("0.5 8")
+            .finish();
+
+        let options = command.options();
+
+        assert_eq!(options.example.as_ref().map(String::as_str), Some("2 3"));
+        assert_eq!(options.examples, vec!["2 3".to_string(), "0.5 8".to_string()]);
+    }
+}
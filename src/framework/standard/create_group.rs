@@ -17,7 +17,7 @@ use model::{
     channel::Message,
     Permissions,
 };
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 /// Used to create command groups
 ///
@@ -34,7 +34,12 @@ use std::sync::Arc;
 ///         .exec_str("Hakase")))
 /// ```
 #[derive(Default)]
-pub struct CreateGroup(pub CommandGroup);
+pub struct CreateGroup(
+    pub CommandGroup,
+    // The sub-groups, which are only nested once the whole tree of groups is
+    // built, as their prefixes depend on the prefixes of their ancestors.
+    HashMap<String, CreateGroup>,
+);
 
 impl CreateGroup {
     fn build_command(&self) -> CreateCommand {
@@ -184,19 +189,75 @@ impl CreateGroup {
     /// Adds a "check" to a group, which checks whether or not the groups's
     /// commands should be called.
     ///
-    /// **Note**: These checks are bypassed for commands sent by the application owner.
+    /// Group checks are run once per invocation, before the checks of the
+    /// invoked command. A [sub-group] copies the checks of its parent when it
+    /// is created, so that the checks of all enclosing groups are run once as
+    /// well, however deeply the command is nested, rather than once per level.
+    /// A command can opt out of all of them via
+    /// [`CreateCommand::inherit_group_checks`].
+    ///
+    /// **Note**: These checks are bypassed for commands sent by the application
+    /// owner, use [`check_with_owner_privilege`] to change this per check.
+    ///
+    /// [`check_with_owner_privilege`]: #method.check_with_owner_privilege
+    /// [`CreateCommand::inherit_group_checks`]: struct.CreateCommand.html#method.inherit_group_checks
+    /// [sub-group]: #method.sub_group
     pub fn check<F>(mut self, check: F) -> Self
         where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
                      + Send
                      + Sync
                      + 'static {
-        self.0.checks.push(Check::new(check));
+        self.0.checks.push(Check::new(check, true));
+
+        self
+    }
+
+    /// Like [`check`], but allows declaring whether the check is bypassed for
+    /// commands sent by the application owner.
+    ///
+    /// [`check`]: #method.check
+    pub fn check_with_owner_privilege<F>(mut self, check: F, owner_privilege: bool) -> Self
+        where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
+                     + Send
+                     + Sync
+                     + 'static {
+        self.0.checks.push(Check::new(check, owner_privilege));
+
+        self
+    }
+
+    /// Like [`check`], but names the check, so that it is listed among the
+    /// requirements of the group's commands by the help-commands.
+    ///
+    /// [`check`]: #method.check
+    pub fn named_check<F>(mut self, name: &str, check: F) -> Self
+        where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
+                     + Send
+                     + Sync
+                     + 'static {
+        self.0.checks.push(Check::new(check, true).named(name));
 
         self
     }
 
     /// Adds a command for a group that will be executed if no command-name
-    /// has been passed.
+    /// has been passed, or if the passed one does not match any of the
+    /// group's commands.
+    ///
+    /// The command receives everything following the group's prefix as its
+    /// arguments, including the word that did not match a command.
+    ///
+    /// # Examples
+    ///
+    /// Showing a tag via `~tag <name>`, while `~tag add <name> <content>`
+    /// invokes the `add` command:
+    ///
+    /// ```rust,ignore
+    /// framework.group("Tags", |g| g
+    ///     .prefix("tag")
+    ///     .cmd("add", tag_add)
+    ///     .default_cmd(tag_show))
+    /// ```
     pub fn default_cmd<C: Command + 'static>(mut self, c: C) -> Self {
         let cmd: Arc<Command> = Arc::new(c);
 
@@ -207,6 +268,83 @@ impl CreateGroup {
         self
     }
 
+    /// Adds a group nested within this group.
+    ///
+    /// The sub-group starts out with this group's options: its bucket,
+    /// required permissions, allowed roles, checks, as well as whether it is
+    /// limited to DMs, guilds or owners, and shown in help. Calling the
+    /// respective methods on the sub-group overrides them, while checks added
+    /// to the sub-group are run in addition to the inherited ones.
+    ///
+    /// The sub-group's prefixes are preceded by this group's prefixes. If the
+    /// sub-group does not set a prefix, it uses this group's prefixes.
+    ///
+    /// Sub-groups are registered with the framework under their path, such as
+    /// `Music::Queue`, so that sub-groups of different groups may share a
+    /// name.
+    ///
+    /// **Note**: Options other than the prefixes set on this group after
+    /// calling this method are not inherited.
+    ///
+    /// # Examples
+    ///
+    /// Create a `music` group, where the `queue` sub-group's commands are
+    /// invoked as `~music queue add` and inherit the guild-only option:
+    ///
+    /// ```rust,ignore
+    /// framework.group("Music", |g| g
+    ///     .prefix("music")
+    ///     .guild_only(true)
+    ///     .cmd("play", play)
+    ///     .sub_group("Queue", |g| g
+    ///         .prefix("queue")
+    ///         .cmd("add", queue_add)
+    ///         .cmd("clear", queue_clear)))
+    /// ```
+    pub fn sub_group<F>(mut self, group_name: &str, f: F) -> Self
+        where F: FnOnce(CreateGroup) -> CreateGroup {
+        let group = f(self.build_sub_group());
+
+        self.1.insert(group_name.to_string(), group);
+
+        self
+    }
+
+    /// Builds the group, nesting the prefixes of its sub-groups.
+    pub(crate) fn build(self) -> CommandGroup {
+        self.build_nested(None)
+    }
+
+    fn build_nested(self, parent_prefixes: Option<&[String]>) -> CommandGroup {
+        let CreateGroup(mut group, sub_groups) = self;
+
+        if let Some(parent_prefixes) = parent_prefixes {
+            nest_prefixes(parent_prefixes, &mut group);
+        }
+
+        for (name, sub_group) in sub_groups {
+            let sub_group = sub_group.build_nested(group.prefixes.as_ref().map(|p| &p[..]));
+
+            group.sub_groups.insert(name, Arc::new(sub_group));
+        }
+
+        group
+    }
+
+    fn build_sub_group(&self) -> CreateGroup {
+        CreateGroup(CommandGroup {
+            bucket: self.0.bucket.clone(),
+            required_permissions: self.0.required_permissions,
+            allowed_roles: self.0.allowed_roles.clone(),
+            help_available: self.0.help_available,
+            dm_only: self.0.dm_only,
+            guild_only: self.0.guild_only,
+            owners_only: self.0.owners_only,
+            checks: self.0.checks.clone(),
+            ..CommandGroup::default()
+        }, HashMap::new())
+    }
+
     /// Sets a description for the group that will be displayed if only
     /// one specific group is requested via help.
     pub fn desc(mut self, text: &str) -> Self {
@@ -215,3 +353,118 @@ impl CreateGroup {
         self
     }
 }
+
+/// Precedes the prefixes of `group` with `parent_prefixes`, re-registering
+/// prefixed aliases accordingly.
+fn nest_prefixes(parent_prefixes: &[String], group: &mut CommandGroup) {
+    let own_prefixes = group.prefixes.take().unwrap_or_default();
+
+    let prefixes = if own_prefixes.is_empty() {
+        parent_prefixes.to_vec()
+    } else {
+        parent_prefixes
+            .iter()
+            .flat_map(|parent| own_prefixes.iter().map(move |own| format!("{} {}", parent, own)))
+            .collect()
+    };
+
+    let aliases = group.commands
+        .iter()
+        .filter_map(|(name, command)| match *command {
+            CommandOrAlias::Alias(ref points_to) => Some((name.clone(), points_to.clone())),
+            CommandOrAlias::Command(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    for (alias, points_to) in aliases {
+        group.commands.remove(&alias);
+
+        let (alias, points_to) = match own_prefixes.iter().find(|own| alias.starts_with(&format!("{} ", own))) {
+            Some(own) => (alias[own.len() + 1..].to_string(), points_to[own.len() + 1..].to_string()),
+            None => (alias, points_to),
+        };
+
+        for prefix in &prefixes {
+            group.commands.insert(
+                format!("{} {}", prefix, alias),
+                CommandOrAlias::Alias(format!("{} {}", prefix, points_to)),
+            );
+        }
+    }
+
+    group.prefixes = Some(prefixes);
+}
+
+#[cfg(test)]
+mod test {
+    use client::Context;
+    use model::channel::Message;
+    use super::{Args, CommandError, CommandOrAlias, CreateGroup};
+
+    fn noop(_: &mut Context, _: &Message, _: Args) -> Result<(), CommandError> {
+        Ok(())
+    }
+
+    #[test]
+    fn nested_prefixes() {
+        let group = CreateGroup::default()
+            .prefixes(vec!["music", "m"])
+            .sub_group("Queue", |g| g
+                .prefix("queue")
+                .cmd("add", noop)
+                .sub_group("History", |g| g
+                    .prefix("history")
+                    .cmd("show", noop)))
+            .sub_group("Player", |g| g.cmd("play", noop))
+            .build();
+
+        let queue = &group.sub_groups["Queue"];
+        assert_eq!(queue.prefixes, Some(vec!["music queue".to_string(), "m queue".to_string()]));
+
+        let history = &queue.sub_groups["History"];
+        assert_eq!(history.prefixes, Some(vec![
+            "music queue history".to_string(),
+            "m queue history".to_string(),
+        ]));
+
+        // Sub-groups without prefixes of their own use their parent's.
+        assert_eq!(group.sub_groups["Player"].prefixes, group.prefixes);
+    }
+
+    #[test]
+    fn prefixes_set_after_sub_groups() {
+        let group = CreateGroup::default()
+            .sub_group("Queue", |g| g.prefix("queue").cmd("add", noop))
+            .prefix("music")
+            .build();
+
+        assert_eq!(group.sub_groups["Queue"].prefixes, Some(vec!["music queue".to_string()]));
+    }
+
+    #[test]
+    fn nested_aliases() {
+        let group = CreateGroup::default()
+            .prefix("music")
+            .sub_group("Queue", |g| g
+                .prefix("queue")
+                .command("add", |c| c.known_as("push").cmd(noop)))
+            .build();
+
+        match group.sub_groups["Queue"].commands.get("music queue push") {
+            Some(&CommandOrAlias::Alias(ref points_to)) => assert_eq!(points_to, "music queue add"),
+            _ => panic!("the alias was not nested"),
+        }
+    }
+
+    #[test]
+    fn checks_owner_privilege() {
+        let group = CreateGroup::default()
+            .check(|_, _, _, _| true)
+            .check_with_owner_privilege(|_, _, _, _| true, false)
+            .build();
+
+        let privileges = group.checks.iter().map(|check| check.owner_privilege).collect::<Vec<_>>();
+
+        assert_eq!(privileges, vec![true, false]);
+    }
+}
@@ -225,6 +225,47 @@ impl CreateHelpCommand {
         self
     }
 
+    /// Whether a command's requirements are displayed when help for it is
+    /// requested, being its required permissions and roles, whether it is
+    /// limited to owners, and the names of its [named checks].
+    ///
+    /// **Note**: Defaults to `true`.
+    ///
+    /// [named checks]: struct.CreateCommand.html#method.named_check
+    pub fn show_requirements(mut self, show_requirements: bool) -> Self {
+        self.0.show_requirements = show_requirements;
+
+        self
+    }
+
+    /// Sets a label for the permissions required by a command.
+    pub fn required_permissions_label(mut self, text: &str) -> Self {
+        self.0.required_permissions_label = text.to_string();
+
+        self
+    }
+
+    /// Sets a label for the roles a command is limited to.
+    pub fn allowed_roles_label(mut self, text: &str) -> Self {
+        self.0.allowed_roles_label = text.to_string();
+
+        self
+    }
+
+    /// Sets a label for the names of a command's checks.
+    pub fn checks_label(mut self, text: &str) -> Self {
+        self.0.checks_label = text.to_string();
+
+        self
+    }
+
+    /// Sets a message explaining a command can only be used by owners.
+    pub fn owners_only_text(mut self, text: &str) -> Self {
+        self.0.owners_only_text = text.to_string();
+
+        self
+    }
+
     fn produce_strike_text(&self, dm_or_guild: &str) -> Option<String> {
         let mut strike_text = String::from("~~`Strikethrough commands`~~ are unavailable because they");
         let mut is_any_option_strike = false;
@@ -29,6 +29,7 @@ use framework::standard::{has_correct_roles, has_correct_permissions};
 use model::{
     channel::Message,
     id::ChannelId,
+    Permissions,
 };
 use Error;
 use std::{
@@ -97,6 +98,11 @@ pub struct Command<'a> {
     availability: &'a str,
     description: Option<String>,
     usage: Option<String>,
+    examples: Vec<String>,
+    required_permissions: Permissions,
+    allowed_roles: Vec<String>,
+    owners_only: bool,
+    checks: Vec<String>,
 }
 
 /// Contains possible suggestions in case a command could not be found
@@ -400,6 +406,18 @@ fn fetch_single_command<'a, H: BuildHasher>(
                 &help_options.dm_and_guild_text
             };
 
+            let group_checks = if command.inherit_group_checks {
+                &group.checks[..]
+            } else {
+                &[]
+            };
+
+            let checks = group_checks
+                .iter()
+                .chain(command.checks.iter())
+                .filter_map(|check| check.name.clone())
+                .collect();
+
             similar_commands.sort_unstable_by(|a, b| a.levenshtein_distance.cmp(&b.levenshtein_distance));
 
             return Ok(CustomisedHelpData::SingleCommand {
@@ -410,6 +428,11 @@ fn fetch_single_command<'a, H: BuildHasher>(
                     aliases: command.aliases.clone(),
                     availability: available_text,
                     usage: command.usage.clone(),
+                    examples: command.examples.clone(),
+                    required_permissions: command.required_permissions,
+                    allowed_roles: command.allowed_roles.clone(),
+                    owners_only: command.owners_only,
+                    checks,
                 },
             });
         }
@@ -653,8 +676,20 @@ fn send_single_command_embed(
                 embed = embed.description(desc);
             }
 
-            if let &Some(ref usage_sample) = &command.usage {
-                embed = embed.field(&help_options.usage_label, usage_sample, true);
+            if let &Some(ref usage) = &command.usage {
+                embed = embed.field(
+                    &help_options.usage_label,
+                    format!("`{} {}`", command.name, usage),
+                    true,
+                );
+            }
+
+            if !command.examples.is_empty() {
+                embed = embed.field(
+                    &help_options.usage_sample_label,
+                    format_examples(command),
+                    true,
+                );
             }
 
             embed = embed.field(&help_options.grouped_label, command.group_name, true);
@@ -667,11 +702,58 @@ fn send_single_command_embed(
                 );
             }
 
-            embed.field(&help_options.available_text, &command.availability, true)
+            embed = embed.field(&help_options.available_text, &command.availability, true);
+
+            if help_options.show_requirements {
+
+                for (label, requirement) in format_requirements(help_options, command) {
+                    embed = embed.field(label, requirement, true);
+                }
+            }
+
+            embed
         })
     })
 }
 
+/// Lists the labeled requirements of a command that are set.
+fn format_requirements<'a>(help_options: &'a HelpOptions, command: &Command) -> Vec<(&'a str, String)> {
+    let mut requirements = Vec::new();
+
+    if command.owners_only {
+        requirements.push((&help_options.available_text[..], help_options.owners_only_text.clone()));
+    }
+
+    if !command.required_permissions.is_empty() {
+        // Turns `BAN_MEMBERS | KICK_MEMBERS` into `ban members, kick members`.
+        let permissions = format!("{:?}", command.required_permissions)
+            .to_lowercase()
+            .replace('_', " ")
+            .replace(" | ", ", ");
+
+        requirements.push((&help_options.required_permissions_label[..], permissions));
+    }
+
+    if !command.allowed_roles.is_empty() {
+        requirements.push((&help_options.allowed_roles_label[..], format!("`{}`", command.allowed_roles.join("`, `"))));
+    }
+
+    if !command.checks.is_empty() {
+        requirements.push((&help_options.checks_label[..], command.checks.join(", ")));
+    }
+
+    requirements
+}
+
+/// Formats each of a command's examples as an invocation on its own line.
+fn format_examples(command: &Command) -> String {
+    command.examples
+        .iter()
+        .map(|example| format!("`{} {}`", command.name, example))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Sends embed listing commands that are similar to the sent one.
 fn send_suggestion_embed(
     channel_id: ChannelId,
@@ -788,9 +870,24 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command)
         let _ = writeln!(result, "**{}**: {}", help_options.description_label, description);
     };
 
+    if let &Some(ref usage) = &command.usage {
+        let _ = writeln!(result, "**{}**: `{} {}`", help_options.usage_label, command.name, usage);
+    }
+
+    if !command.examples.is_empty() {
+        let _ = writeln!(result, "**{}**: {}", help_options.usage_sample_label, format_examples(command).replace("\n", ", "));
+    }
+
     let _ = writeln!(result, "**{}**: {}", help_options.grouped_label, command.group_name);
     let _ = writeln!(result, "**{}**: {}", help_options.available_text, command.availability);
 
+    if help_options.show_requirements {
+
+        for (label, requirement) in format_requirements(help_options, command) {
+            let _ = writeln!(result, "**{}**: {}", label, requirement);
+        }
+    }
+
     result
 }
 
@@ -840,3 +937,96 @@ pub fn plain<H: BuildHasher>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use model::Permissions;
+    use super::{
+        format_examples,
+        format_requirements,
+        single_command_to_plain_string,
+        Command,
+        HelpOptions,
+    };
+
+    fn command<'a>() -> Command<'a> {
+        Command {
+            name: "multiply",
+            group_name: "Maths",
+            aliases: Vec::new(),
+            availability: "In DM and guilds",
+            description: None,
+            usage: Some("<number> <number>".to_string()),
+            examples: vec!["2 3".to_string(), "0.5 8".to_string()],
+            required_permissions: Permissions::empty(),
+            allowed_roles: Vec::new(),
+            owners_only: false,
+            checks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn examples_are_formatted_as_invocations() {
+        assert_eq!(format_examples(&command()), "`multiply 2 3`\n`multiply 0.5 8`");
+    }
+
+    #[test]
+    fn plain_single_command_shows_usage_and_examples() {
+        let mut help_options = HelpOptions::default();
+        help_options.show_requirements = false;
+
+        assert_eq!(single_command_to_plain_string(&help_options, &command()), "**multiply**\n\
+            **Usage**: `multiply <number> <number>`\n\
+            **Sample usage**: `multiply 2 3`, `multiply 0.5 8`\n\
+            **Group**: Maths\n\
+            **Available**: In DM and guilds\n");
+    }
+
+    #[test]
+    fn plain_single_command_without_usage_or_examples() {
+        let mut command = command();
+        command.usage = None;
+        command.examples.clear();
+
+        let mut help_options = HelpOptions::default();
+        help_options.show_requirements = false;
+
+        assert_eq!(single_command_to_plain_string(&help_options, &command), "**multiply**\n\
+            **Group**: Maths\n\
+            **Available**: In DM and guilds\n");
+    }
+
+    #[test]
+    fn requirements() {
+        let help_options = HelpOptions::default();
+        let mut command = command();
+
+        assert!(format_requirements(&help_options, &command).is_empty());
+
+        command.owners_only = true;
+        command.required_permissions = Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS;
+        command.allowed_roles = vec!["Mods".to_string(), "Admins".to_string()];
+        command.checks = vec!["in_guild".to_string(), "premium".to_string()];
+
+        assert_eq!(format_requirements(&help_options, &command), vec![
+            ("Available", "Only for owners".to_string()),
+            ("Required permissions", "kick members, ban members".to_string()),
+            ("Required roles", "`Mods`, `Admins`".to_string()),
+            ("Checks", "in_guild, premium".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn plain_single_command_shows_requirements() {
+        let help_options = HelpOptions::default();
+        let mut command = command();
+        command.usage = None;
+        command.examples.clear();
+        command.checks = vec!["in_guild".to_string()];
+
+        assert_eq!(single_command_to_plain_string(&help_options, &command), "**multiply**\n\
+            **Group**: Maths\n\
+            **Available**: In DM and guilds\n\
+            **Checks**: in_guild\n");
+    }
+}
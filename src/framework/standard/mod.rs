@@ -1,5 +1,6 @@
 
 pub mod help_commands;
+pub mod parse;
 
 mod command;
 mod configuration;
@@ -8,6 +9,7 @@ mod create_help_command;
 mod create_group;
 mod buckets;
 mod args;
+mod prefix_store;
 
 pub use self::args::{
     Args,
@@ -15,6 +17,7 @@ pub use self::args::{
     Error as ArgError
 };
 pub(crate) use self::buckets::{Bucket, Ratelimit};
+pub use self::buckets::{LimitedFor, RateLimitInfo};
 pub(crate) use self::command::Help;
 pub use self::command::{
     Check,
@@ -23,6 +26,8 @@ pub use self::command::{
     Command,
     CommandGroup,
     CommandOptions,
+    CommandOutput,
+    CommandResult,
     Error as CommandError
 };
 pub use self::command::CommandOrAlias;
@@ -30,6 +35,7 @@ pub use self::configuration::Configuration;
 pub use self::create_help_command::CreateHelpCommand;
 pub use self::create_command::{CreateCommand, FnOrCommand};
 pub use self::create_group::CreateGroup;
+pub use self::prefix_store::{MemoryPrefixStore, PrefixStore};
 
 use client::Context;
 use internal::RwLockExt;
@@ -39,12 +45,25 @@ use model::{
     id::{ChannelId, GuildId, UserId},
     Permissions
 };
-use self::command::{AfterHook, BeforeHook, UnrecognisedCommandHook};
+use self::command::{
+    AfterHook,
+    AfterOutputHook,
+    BeforeHook,
+    UnrecognisedCommandHook,
+    UnrecognisedCommandSuggestionsHook
+};
+use self::parse::{Invoke, ParseError};
 use std::{
     collections::HashMap,
     default::Default,
-    sync::Arc
+    error::Error as StdError,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc
+    },
+    thread
 };
+use serde_json::Value;
 use super::Framework;
 use threadpool::ThreadPool;
 
@@ -147,20 +166,73 @@ macro_rules! command {
     };
 }
 
-macro_rules! command_and_help_args {
-    ($message_content:expr, $position:expr, $command_length:expr, $delimiters:expr) => {
-        {
-            let content = $message_content.chars().skip($position).skip_while(|x| x.is_whitespace())
-                .skip($command_length).collect::<String>();
-
-            Args::new(&content.trim(), $delimiters)
+/// A convenience macro for generating a function fulfilling the signature of
+/// a check, as taken by [`CreateCommand::check`] and [`CreateGroup::check`].
+///
+/// The block has to evaluate to a `bool`, stating whether the check passed.
+/// Parameters that are not named are ignored.
+///
+/// # Examples
+///
+/// Create a check that only passes for messages sent in guilds:
+///
+/// ```rust,ignore
+/// check!(in_guild(_ctx, msg) {
+///     msg.guild_id.is_some()
+/// });
+///
+/// framework.command("server", |c| c
+///     .check(in_guild)
+///     .exec(server));
+/// ```
+///
+/// Create a check that inspects the arguments:
+///
+/// ```rust,ignore
+/// check!(few_args(_ctx, _msg, args) {
+///     args.len() <= 3
+/// });
+/// ```
+///
+/// [`CreateCommand::check`]: framework/standard/struct.CreateCommand.html#method.check
+/// [`CreateGroup::check`]: framework/standard/struct.CreateGroup.html#method.check
+#[macro_export]
+macro_rules! check {
+    ($fname:ident($c:ident, $m:ident) $b:block) => {
+        #[allow(unreachable_code, unused_mut)]
+        pub fn $fname(mut $c: &mut $crate::client::Context,
+                      $m: &$crate::model::channel::Message,
+                      _: &mut $crate::framework::standard::Args,
+                      _: &$crate::framework::standard::CommandOptions)
+                      -> bool {
+            $b
+        }
+    };
+    ($fname:ident($c:ident, $m:ident, $a:ident) $b:block) => {
+        #[allow(unreachable_code, unused_mut)]
+        pub fn $fname(mut $c: &mut $crate::client::Context,
+                      $m: &$crate::model::channel::Message,
+                      $a: &mut $crate::framework::standard::Args,
+                      _: &$crate::framework::standard::CommandOptions)
+                      -> bool {
+            $b
+        }
+    };
+    ($fname:ident($c:ident, $m:ident, $a:ident, $o:ident) $b:block) => {
+        #[allow(unreachable_code, unused_mut)]
+        pub fn $fname(mut $c: &mut $crate::client::Context,
+                      $m: &$crate::model::channel::Message,
+                      $a: &mut $crate::framework::standard::Args,
+                      $o: &$crate::framework::standard::CommandOptions)
+                      -> bool {
+            $b
         }
     };
 }
 
 /// An enum representing all possible fail conditions under which a command won't
 /// be executed.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum DispatchError {
     /// When a custom function check has failed.
     //
@@ -177,8 +249,9 @@ pub enum DispatchError {
     /// When the command requester lacks specific required permissions.
     LackOfPermissions(Permissions),
     /// When the command requester has exceeded a ratelimit bucket. The attached
-    /// value is the time a requester has to wait to run the command again.
-    RateLimited(i64),
+    /// value holds the time a requester has to wait to run the command again,
+    /// among other information about the bucket.
+    RateLimited(RateLimitInfo),
     /// When the requested command can only be used in a direct message or group
     /// channel.
     OnlyForDM,
@@ -202,6 +275,72 @@ pub enum DispatchError {
 
 type DispatchErrorHook = Fn(Context, Message, DispatchError) + Send + Sync + 'static;
 
+/// A command that can not be registered as an application command, as found
+/// by [`StandardFramework::application_commands`].
+///
+/// Each variant contains the full name of the command, such as `music play`.
+///
+/// [`StandardFramework::application_commands`]: struct.StandardFramework.html#method.application_commands
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ApplicationCommandError {
+    /// The name is empty, longer than 32 characters, or contains characters
+    /// other than letters, numbers, `-` and `_`.
+    InvalidName(String),
+    /// Another command on the same level has the same name.
+    NameCollision(String),
+}
+
+impl fmt::Display for ApplicationCommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ApplicationCommandError::InvalidName(ref name)
+                | ApplicationCommandError::NameCollision(ref name) => {
+                write!(f, "{}: {}", self.description(), name)
+            },
+        }
+    }
+}
+
+impl StdError for ApplicationCommandError {
+    fn description(&self) -> &str {
+        match *self {
+            ApplicationCommandError::InvalidName(_) => "Invalid application command name",
+            ApplicationCommandError::NameCollision(_) => "Duplicate application command name",
+        }
+    }
+}
+
+/// The reason a message did not invoke a command, passed to the
+/// [`normal_message`] hook.
+///
+/// [`normal_message`]: struct.StandardFramework.html#method.normal_message
+#[derive(Clone, Debug)]
+pub enum NormalMessage {
+    /// The message does not start with a prefix.
+    NoPrefix,
+    /// The message consists of nothing but a prefix.
+    PrefixOnly,
+    /// No command matched the name following the prefix.
+    UnrecognisedCommand {
+        /// The last name that was looked up, if any.
+        name: Option<String>,
+        /// The message's content following the prefix.
+        content: String,
+    },
+    /// A command was recognised, but it was not dispatched, e.g. due to a
+    /// failing check.
+    DispatchFailed {
+        /// The name the command was invoked with.
+        name: String,
+        /// The message's content following the prefix.
+        content: String,
+        /// The reason the command was not dispatched.
+        error: DispatchError,
+    },
+}
+
+type NormalMessageHook = Fn(&mut Context, &Message, &NormalMessage) + Send + Sync + 'static;
+
 /// A utility for easily managing dispatches to commands.
 ///
 /// Refer to the [module-level documentation] for more information.
@@ -216,7 +355,10 @@ pub struct StandardFramework {
     dispatch_error_handler: Option<Arc<DispatchErrorHook>>,
     buckets: HashMap<String, Bucket>,
     after: Option<Arc<AfterHook>>,
+    after_with_output: Option<Arc<AfterOutputHook>>,
     unrecognised_command: Option<Arc<UnrecognisedCommandHook>>,
+    unrecognised_command_with_suggestions: Option<Arc<UnrecognisedCommandSuggestionsHook>>,
+    normal_message: Option<Arc<NormalMessageHook>>,
     /// Whether the framework has been "initialized".
     ///
     /// The framework is initialized once one of the following occurs:
@@ -312,8 +454,9 @@ impl StandardFramework {
                     delay,
                     limit: Some((time_span, limit)),
                 },
-                users: HashMap::new(),
+                targets: HashMap::new(),
                 check: None,
+                limited_for: LimitedFor::default(),
             },
         );
 
@@ -371,8 +514,9 @@ impl StandardFramework {
                     delay,
                     limit: Some((time_span, limit)),
                 },
-                users: HashMap::new(),
+                targets: HashMap::new(),
                 check: Some(Box::new(check)),
+                limited_for: LimitedFor::default(),
             },
         );
 
@@ -425,8 +569,9 @@ impl StandardFramework {
                     delay,
                     limit: Some((time_span, limit)),
                 },
-                users: HashMap::new(),
+                targets: HashMap::new(),
                 check: Some(Box::new(check)),
+                limited_for: LimitedFor::default(),
             },
         );
 
@@ -462,14 +607,51 @@ impl StandardFramework {
                     delay,
                     limit: None,
                 },
-                users: HashMap::new(),
+                targets: HashMap::new(),
                 check: None,
+                limited_for: LimitedFor::default(),
             },
         );
 
         self
     }
 
+    /// Sets whom the ratelimit of a previously defined bucket applies to, such
+    /// as to all users of a channel together.
+    ///
+    /// **Note**: Defaults to [`LimitedFor::User`].
+    ///
+    /// # Examples
+    ///
+    /// Allowing a command to be used once per 30 seconds per channel:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::standard::LimitedFor;
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .simple_bucket("announce", 30)
+    ///     .bucket_limited_for("announce", LimitedFor::Channel)
+    ///     .command("announce", |c| c
+    ///         .bucket("announce")
+    ///         .exec(|_, msg, args| { msg.channel_id.say(args.full())?; Ok(()) })));
+    /// ```
+    ///
+    /// [`LimitedFor::User`]: enum.LimitedFor.html#variant.User
+    pub fn bucket_limited_for(mut self, s: &str, limited_for: LimitedFor) -> Self {
+        if let Some(bucket) = self.buckets.get_mut(s) {
+            bucket.limited_for = limited_for;
+        }
+
+        self
+    }
+
     #[cfg(feature = "cache")]
     fn is_blocked_guild(&self, message: &Message) -> bool {
         if let Some(Channel::Guild(channel)) = CACHE.read().channel(message.channel_id) {
@@ -499,7 +681,7 @@ impl StandardFramework {
     #[allow(too_many_arguments)]
     #[cfg_attr(feature = "cargo-clippy", allow(cyclomatic_complexity))]
     fn should_fail(&mut self,
-                   mut context: &mut Context,
+                   context: &mut Context,
                    message: &Message,
                    command: &Arc<CommandOptions>,
                    group: &Arc<CommandGroup>,
@@ -533,13 +715,15 @@ impl StandardFramework {
             }
 
             if self.configuration.owners.contains(&message.author.id) {
-                return None;
+                return if passes_checks(context, message, args, command, group, true) {
+                    None
+                } else {
+                    Some(DispatchError::CheckFailed)
+                };
             }
 
-            if let Some(ref bucket) = command.bucket {
-                if let Some(ref mut bucket) = self.buckets.get_mut(bucket) {
-                    let rate_limit = bucket.take(message.author.id.0);
-
+            if let Some(ref name) = command.bucket {
+                if let Some(ref mut bucket) = self.buckets.get_mut(name) {
                     // Is there a custom check for when this bucket applies?
                     // If not, assert that it does always.
                     let apply = bucket.check.as_ref().map_or(true, |check| {
@@ -551,8 +735,23 @@ impl StandardFramework {
                         }}
                     });
 
-                    if apply && rate_limit > 0i64 {
-                        return Some(DispatchError::RateLimited(rate_limit));
+                    // Only take a ticket, and count a hit, when it applies.
+                    if apply {
+                        let target_id = bucket.limited_for.target_id(
+                            message.author.id,
+                            message.channel_id,
+                            message.guild_id,
+                        );
+                        let (rate_limit, hits) = bucket.take(target_id);
+
+                        if rate_limit > 0i64 {
+                            return Some(DispatchError::RateLimited(RateLimitInfo {
+                                bucket: name.clone(),
+                                remaining: rate_limit,
+                                limited_for: bucket.limited_for,
+                                hits,
+                            }));
+                        }
                     }
                 }
             }
@@ -613,21 +812,7 @@ impl StandardFramework {
                     }
                 }
 
-                let all_group_checks_passed = group
-                    .checks
-                    .iter()
-                    .all(|check| (check.0)(&mut context, message, args, command));
-
-                if !all_group_checks_passed {
-                    return Some(DispatchError::CheckFailed);
-                }
-
-                let all_command_checks_passed = command
-                    .checks
-                    .iter()
-                    .all(|check| (check.0)(&mut context, message, args, command));
-
-                if all_command_checks_passed {
+                if passes_checks(context, message, args, command, group, false) {
                     None
                 } else {
                     Some(DispatchError::CheckFailed)
@@ -786,14 +971,31 @@ impl StandardFramework {
     /// ```
     pub fn group<F>(mut self, group_name: &str, f: F) -> Self
         where F: FnOnce(CreateGroup) -> CreateGroup {
-        let group = f(CreateGroup(CommandGroup::default())).0;
+        let group = f(CreateGroup::default()).build();
 
-        self.groups.insert(group_name.into(), Arc::new(group));
+        self.insert_group(group_name, Arc::new(group));
         self.initialized = true;
 
         self
     }
 
+    /// Registers the group along with all of its sub-groups, as these are
+    /// resolved like any other group, given that their prefixes already
+    /// include the parent's prefixes.
+    ///
+    /// Sub-groups are registered under their path, e.g. `Music::Queue`, so
+    /// that same-named sub-groups of different groups do not replace each
+    /// other.
+    fn insert_group(&mut self, group_name: &str, group: Arc<CommandGroup>) {
+        for (sub_group_name, sub_group) in &group.sub_groups {
+            let path = format!("{}::{}", group_name, sub_group_name);
+
+            self.insert_group(&path, Arc::clone(sub_group));
+        }
+
+        self.groups.insert(group_name.into(), group);
+    }
+
     /// Specify the function that's called in case a command wasn't executed for one reason or
     /// another.
     ///
@@ -924,6 +1126,51 @@ impl StandardFramework {
         self
     }
 
+    /// Like [`after`], but additionally receives the value a command produced
+    /// on success, if any.
+    ///
+    /// Commands produce values by being registered via
+    /// [`CreateCommand::exec_with_output`] or by implementing
+    /// [`Command::execute_with_output`]. This allows, for example, logging
+    /// the actions taken by all commands in one place.
+    ///
+    /// **Note**: This is called after the [`after`] hook.
+    ///
+    /// # Examples
+    ///
+    /// Logging summaries produced by commands:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .after_with_output(|_, _, cmd_name, result| {
+    ///         let summary = result.ok()
+    ///             .and_then(|output| output)
+    ///             .and_then(|output| output.downcast::<String>().ok());
+    ///
+    ///         if let Some(summary) = summary {
+    ///             println!("{}: {}", cmd_name, summary);
+    ///         }
+    ///     }));
+    /// ```
+    ///
+    /// [`after`]: #method.after
+    /// [`Command::execute_with_output`]: trait.Command.html#method.execute_with_output
+    /// [`CreateCommand::exec_with_output`]: struct.CreateCommand.html#method.exec_with_output
+    pub fn after_with_output<F>(mut self, f: F) -> Self
+        where F: Fn(&mut Context, &Message, &str, CommandResult<Option<CommandOutput>>) + Send + Sync + 'static {
+        self.after_with_output = Some(Arc::new(f));
+
+        self
+    }
+
     /// Specify the function to be called if no command could be dispatched.
     ///
     /// # Examples
@@ -949,6 +1196,194 @@ impl StandardFramework {
         self
     }
 
+    /// Specify the function to be called if no command could be dispatched,
+    /// receiving the names of similar commands to suggest to the user.
+    ///
+    /// The suggestions are sorted by similarity, the most similar first, and
+    /// may be empty. Refer to [`Configuration::max_levenshtein_distance`] for
+    /// how similar they are.
+    ///
+    /// This is called in addition to [`unrecognised_command`].
+    ///
+    /// # Examples
+    ///
+    /// Suggesting the most similar command:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .configure(|c| c.prefix("?"))
+    ///     .unrecognised_command_with_suggestions(|_, msg, _, suggestions| {
+    ///         if let Some(suggestion) = suggestions.first() {
+    ///             let _ = msg.channel_id.say(format!("Did you mean `?{}`?", suggestion));
+    ///         }
+    ///     }));
+    /// ```
+    ///
+    /// [`Configuration::max_levenshtein_distance`]: struct.Configuration.html#method.max_levenshtein_distance
+    /// [`unrecognised_command`]: #method.unrecognised_command
+    pub fn unrecognised_command_with_suggestions<F>(mut self, f: F) -> Self
+        where F: Fn(&mut Context, &Message, &str, &[String]) + Send + Sync + 'static {
+        self.unrecognised_command_with_suggestions = Some(Arc::new(f));
+
+        self
+    }
+
+    /// Specify the function to be called for every message that did not
+    /// invoke a command, receiving the reason why it did not.
+    ///
+    /// Unlike [`unrecognised_command`], this is also called if the message
+    /// has no prefix, consists of only the prefix, or if the command was
+    /// recognised but not dispatched, e.g. due to a failing check. In the
+    /// latter case, the [`on_dispatch_error`] handler is called as well.
+    ///
+    /// **Note**: Messages by bots are not passed to this if
+    /// [`Configuration::ignore_bots`] is enabled.
+    ///
+    /// # Examples
+    ///
+    /// Responding to a message consisting of only the prefix:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::standard::{NormalMessage, StandardFramework};
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .configure(|c| c.prefix("~"))
+    ///     .normal_message(|_, msg, reason| {
+    ///         if let NormalMessage::PrefixOnly = *reason {
+    ///             let _ = msg.channel_id.say("Try `~help` for a list of commands.");
+    ///         }
+    ///     }));
+    /// ```
+    ///
+    /// [`Configuration::ignore_bots`]: struct.Configuration.html#method.ignore_bots
+    /// [`on_dispatch_error`]: #method.on_dispatch_error
+    /// [`unrecognised_command`]: #method.unrecognised_command
+    pub fn normal_message<F>(mut self, f: F) -> Self
+        where F: Fn(&mut Context, &Message, &NormalMessage) + Send + Sync + 'static {
+        self.normal_message = Some(Arc::new(f));
+
+        self
+    }
+
+    /// Passes the reason for the `message` not invoking a command to the
+    /// `normal_message` hook, if set.
+    fn dispatch_normal_message(&self,
+                               mut context: Context,
+                               message: Message,
+                               reason: NormalMessage,
+                               threadpool: &ThreadPool) {
+        if self.configuration.ignore_bots && message.author.bot {
+            return;
+        }
+
+        if let Some(ref normal_message) = self.normal_message {
+            let normal_message = Arc::clone(normal_message);
+
+            threadpool.execute(move || {
+                (normal_message)(&mut context, &message, &reason);
+            });
+        }
+    }
+
+    /// Generates the definitions of the registered commands in the format
+    /// expected by Discord for [application commands], so that the command
+    /// tree can be registered as slash commands.
+    ///
+    /// Groups with a prefix become a single application command, where their
+    /// commands are sub-commands and their nested groups are sub-command
+    /// groups. Commands of groups without a prefix become application commands
+    /// of their own. Descriptions are taken from the command's [`desc`] and the
+    /// group's [`description`], and the arguments are given as string options,
+    /// where the first [`min_args`] are required. As Discord allows at most 25
+    /// options, commands taking more arguments are limited to the first 25.
+    ///
+    /// Descriptions are cut off after 100 characters, and an empty one is
+    /// replaced with the name.
+    ///
+    /// **Note**: Aliases are not included, and as Discord limits nesting to
+    /// sub-command groups, commands of groups nested more deeply are added to
+    /// the sub-command group of their outermost nested ancestor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .group("Music", |g| g
+    ///         .prefix("music")
+    ///         .desc("Plays music.")
+    ///         .on("play", |_, _, _| Ok(())));
+    ///
+    /// for definition in framework.application_commands().expect("Invalid command") {
+    ///     println!("{}", definition);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApplicationCommandError`] naming the first command, or
+    /// group prefix, that Discord would reject: either as its name is not 1
+    /// to 32 letters, numbers, `-` or `_`, or as another command on the same
+    /// level has the same name, e.g. in another group without a prefix.
+    ///
+    /// [application commands]: https://discord.com/developers/docs/interactions/application-commands
+    /// [`ApplicationCommandError`]: enum.ApplicationCommandError.html
+    /// [`desc`]: struct.CreateCommand.html#method.desc
+    /// [`description`]: struct.CreateGroup.html#method.desc
+    /// [`min_args`]: struct.CreateCommand.html#method.min_args
+    pub fn application_commands(&self) -> Result<Vec<Value>, ApplicationCommandError> {
+        // Sub-groups are registered like any other group, but belong to the
+        // definition of their parent, if it has a prefix.
+        let nested = self.groups
+            .values()
+            .filter(|group| group.prefixes.is_some())
+            .flat_map(|group| group.sub_groups.values())
+            .collect::<Vec<_>>();
+
+        let mut definitions = Vec::new();
+
+        for group in self.groups.values() {
+            if nested.iter().any(|sub_group| Arc::ptr_eq(sub_group, group)) {
+                continue;
+            }
+
+            match group.prefixes.as_ref().and_then(|prefixes| prefixes.first()) {
+                Some(prefix) => definitions.push(json!({
+                    "name": prefix.to_lowercase(),
+                    "description": definition_description(group.description.as_ref(), prefix),
+                    "options": group_definition_options(group, true),
+                })),
+                None => definitions.extend(command_definitions(group).into_iter().map(|mut definition| {
+                    // Top-level commands carry no type.
+                    if let Some(map) = definition.as_object_mut() {
+                        map.remove("type");
+                    }
+
+                    definition
+                })),
+            }
+        }
+
+        validate_definitions(&definitions, "")?;
+
+        Ok(definitions)
+    }
+
     /// Sets what code should be executed when a user sends `(prefix)help`.
     ///
     /// If a command named `help` was set with [`command`], then this takes precendence first.
@@ -975,16 +1410,6 @@ impl StandardFramework {
     }
 }
 
-fn skip_chars_and_trim_to_new_string(str_to_transform_to_chars: &str, chars_to_skip: usize) -> String {
-    let mut chars = str_to_transform_to_chars.chars();
-
-    if chars_to_skip > 0 {
-        chars.nth(chars_to_skip - 1);
-    }
-
-    chars.as_str().trim().to_string()
-}
-
 impl Framework for StandardFramework {
     fn dispatch(
         &mut self,
@@ -992,210 +1417,196 @@ impl Framework for StandardFramework {
         message: Message,
         threadpool: &ThreadPool,
     ) {
-        let res = command::positions(&mut context, &message, &self.configuration);
-        let mut unrecognised_command_name = String::from("");
+        let positions = match parse::prefix(&mut context, &message, &self.configuration) {
+            Ok(positions) => positions,
+            Err(error) => {
+                let reason = match error {
+                    ParseError::PrefixOnly => NormalMessage::PrefixOnly,
+                    _ => NormalMessage::NoPrefix,
+                };
 
-        let positions = match res {
-            Some(mut positions) => {
-                // First, take out the prefixes that are as long as _or_ longer
-                // than the message, to avoid character boundary violations.
-                positions.retain(|p| *p < message.content.len());
-
-                // Ensure that there is _at least one_ position remaining. There
-                // is no point in continuing if there is not.
-                if positions.is_empty() {
-                    return;
-                }
+                self.dispatch_normal_message(context, message, reason, threadpool);
 
-                positions
+                return;
             },
-            None => return,
         };
 
-        'outer: for position in positions {
-            let mut built = String::new();
-
-            let orginal_round = skip_chars_and_trim_to_new_string(&message.content, position);
-            let mut round = orginal_round.split_whitespace();
-
-            for i in 0..self.configuration.depth {
-                if i != 0 {
-                    built.push(' ');
-                }
-
-                built.push_str(match round.next() {
-                    Some(piece) => piece,
-                    None => continue 'outer,
-                });
-
-                let groups = self.groups.clone();
+        let mut unrecognised_command_name = String::from("");
+        let mut unrecognised_name = None;
+        let mut content = String::new();
+
+        for position in positions {
+            content = parse::skip_prefix_and_trim_to_new_string(&message.content, position);
+
+            let invoke = match parse::command(
+                &self.configuration,
+                &self.groups,
+                &message.content,
+                position,
+                self.help.is_some(),
+            ) {
+                Ok(invoke) => invoke,
+                Err(ParseError::UnrecognisedCommand(name)) => {
+                    if let Some(ref name) = name {
+                        unrecognised_command_name = name.clone();
+                    }
 
-                for group in groups.values() {
-                    let command_length = built.len();
+                    unrecognised_name = name;
 
-                    built = if self.configuration.case_insensitive {
-                        built.to_lowercase()
-                    } else {
-                        built
+                    continue;
+                },
+                Err(_) => continue,
+            };
+
+            let before = self.before.clone();
+            let after = self.after.clone();
+            let after_with_output = self.after_with_output.clone();
+
+            match invoke {
+                Invoke::Help { name: built, args } => {
+                    let help = match self.help.clone() {
+                        Some(help) => help,
+                        None => return,
                     };
+                    let groups = self.groups.clone();
 
-                    unrecognised_command_name = built.clone();
-                    let cmd = group.commands.get(&built);
+                    threadpool.execute(move || {
 
-                    if let Some(&CommandOrAlias::Alias(ref points_to)) = cmd {
-                        built = points_to.to_string();
-                    }
+                        if let Some(before) = before {
 
-                    let mut check_contains_group_prefix = false;
-                    let mut longest_matching_prefix_len = 0;
-                    let to_check = if let Some(ref prefixes) = group.prefixes {
-                        // Once `built` starts with a set prefix,
-                        // we want to make sure that all following matching prefixes are longer
-                        // than the last matching one, this prevents picking a wrong prefix,
-                        // e.g. "f" instead of "ferris" due to "f" having a lower index in the `Vec`.
-                        longest_matching_prefix_len = prefixes.iter().fold(0, |longest_prefix_len, prefix|
-                            if prefix.len() > longest_prefix_len
-                            && built.starts_with(prefix)
-                            && (orginal_round.len() == prefix.len() || built.get(prefix.len()..prefix.len() + 1) == Some(" ")) {
-                                prefix.len()
-                            } else {
-                                longest_prefix_len
+                            if !(before)(&mut context, &message, &built) {
+                                return;
                             }
-                        );
-
-                        if longest_matching_prefix_len == built.len() {
-                            check_contains_group_prefix = true;
-                            String::new()
-                        } else if longest_matching_prefix_len > 0 {
-                            check_contains_group_prefix = true;
-                            built[longest_matching_prefix_len + 1..].to_string()
-                        } else {
-                            continue;
                         }
-                    } else {
-                        built.clone()
-                    };
-
-                    let before = self.before.clone();
-                    let after = self.after.clone();
 
-                    if to_check == "help" {
-                        let help = self.help.clone();
-
-                        if let Some(help) = help {
-                            let groups = self.groups.clone();
-                            let mut args = command_and_help_args!(&message.content, position, command_length, &self.configuration.delimiters);
-
-                            threadpool.execute(move || {
-
-                                if let Some(before) = before {
-
-                                    if !(before)(&mut context, &message, &built) {
-                                        return;
-                                    }
-                                }
+                        let result = (help.0)(&mut context, &message, &help.1, groups, &args);
 
-                                let result = (help.0)(&mut context, &message, &help.1, groups, &args);
+                        if let Some(after) = after {
+                            (after)(&mut context, &message, &built, result.clone());
+                        }
 
-                                if let Some(after) = after {
-                                    (after)(&mut context, &message, &built, result);
-                                }
-                            });
-                            return;
+                        if let Some(after_with_output) = after_with_output {
+                            (after_with_output)(&mut context, &message, &built, result.map(|()| None));
+                        }
+                    });
+                },
+                Invoke::Command { command, group, name: built, command_name: to_check, mut args } => {
+                    if let Some(error) = self.should_fail(
+                        &mut context,
+                        &message,
+                        &command.options(),
+                        &group,
+                        &mut args,
+                        &to_check,
+                        &built,
+                    ) {
+                        self.dispatch_normal_message(context.clone(), message.clone(), NormalMessage::DispatchFailed {
+                            name: built.clone(),
+                            content,
+                            error: error.clone(),
+                        }, threadpool);
+
+                        if let Some(reply) = args_error_reply(&command.options(), &built, &error) {
+                            if let Err(why) = message.channel_id.say(reply) {
+                                warn!("Failed to send argument error reply: {:?}", why);
+                            }
+                        } else if let Some(ref handler) = self.dispatch_error_handler {
+                            handler(context, message, error);
                         }
+                        return;
                     }
 
-                    if !to_check.is_empty() {
-
-                        if let Some(&CommandOrAlias::Command(ref command)) =
-                            group.commands.get(&to_check) {
-                            let command = Arc::clone(command);
-                            let mut args = command_and_help_args!(&message.content, position, command_length, &self.configuration.delimiters);
-
-                            if let Some(error) = self.should_fail(
-                                &mut context,
-                                &message,
-                                &command.options(),
-                                &group,
-                                &mut args,
-                                &to_check,
-                                &built,
-                            ) {
-                                if let Some(ref handler) = self.dispatch_error_handler {
-                                    handler(context, message, error);
-                                }
+                    let typing = broadcasts_typing(&self.configuration, &command.options());
+
+                    threadpool.execute(move || {
+                        if let Some(before) = before {
+                            if !(before)(&mut context, &message, &built) {
                                 return;
                             }
+                        }
 
-                            threadpool.execute(move || {
-                                if let Some(before) = before {
-                                    if !(before)(&mut context, &message, &built) {
-                                        return;
-                                    }
-                                }
-
-                                if !command.before(&mut context, &message) {
-                                    return;
-                                }
-
-                                let result = command.execute(&mut context, &message, args);
+                        if !command.before(&mut context, &message) {
+                            return;
+                        }
 
-                                command.after(&mut context, &message, &result);
+                        let output = execute_command(&command, &mut context, &message, args, typing);
+                        let result = output.as_ref().map(|_| ()).map_err(Clone::clone);
 
-                                if let Some(after) = after {
-                                    (after)(&mut context, &message, &built, result);
-                                }
-                            });
-
-                            return;
+                        command.after(&mut context, &message, &result);
 
+                        if let Some(after) = after {
+                            (after)(&mut context, &message, &built, result);
                         }
-                    }
 
-                    if check_contains_group_prefix {
-
-                        if let &Some(CommandOrAlias::Command(ref command)) = &group.default_command {
-                            let command = Arc::clone(command);
-                            let mut args = {
-                                Args::new(&orginal_round[longest_matching_prefix_len..], &self.configuration.delimiters)
-                            };
+                        if let Some(after_with_output) = after_with_output {
+                            (after_with_output)(&mut context, &message, &built, output);
+                        }
+                    });
+                },
+                Invoke::DefaultCommand { command, name: built, args, .. } => {
+                    let typing = broadcasts_typing(&self.configuration, &command.options());
 
-                            threadpool.execute(move || {
-                                if let Some(before) = before {
-                                    if !(before)(&mut context, &message, &args.full()) {
-                                        return;
-                                    }
-                                }
+                    threadpool.execute(move || {
+                        if let Some(before) = before {
+                            if !(before)(&mut context, &message, &args.full()) {
+                                return;
+                            }
+                        }
 
-                                if !command.before(&mut context, &message) {
-                                    return;
-                                }
+                        if !command.before(&mut context, &message) {
+                            return;
+                        }
 
-                                let result = command.execute(&mut context, &message, args);
+                        let output = execute_command(&command, &mut context, &message, args, typing);
+                        let result = output.as_ref().map(|_| ()).map_err(Clone::clone);
 
-                                command.after(&mut context, &message, &result);
+                        command.after(&mut context, &message, &result);
 
-                                if let Some(after) = after {
-                                    (after)(&mut context, &message, &built, result);
-                                }
-                            });
+                        if let Some(after) = after {
+                            (after)(&mut context, &message, &built, result);
+                        }
 
-                            return;
+                        if let Some(after_with_output) = after_with_output {
+                            (after_with_output)(&mut context, &message, &built, output);
                         }
-                    }
-                }
+                    });
+                },
             }
+
+            return;
         }
 
         if !(self.configuration.ignore_bots && message.author.bot) {
 
             if let &Some(ref unrecognised_command) = &self.unrecognised_command {
                 let unrecognised_command = unrecognised_command.clone();
+                let mut context = context.clone();
+                let message = message.clone();
                 threadpool.execute(move || {
                     (unrecognised_command)(&mut context, &message, &unrecognised_command_name);
                 });
             }
+
+            if let (&Some(ref hook), &Some(ref name)) = (&self.unrecognised_command_with_suggestions, &unrecognised_name) {
+                let suggestions = parse::similar_commands(
+                    &self.groups,
+                    name,
+                    self.configuration.max_levenshtein_distance,
+                );
+                let hook = Arc::clone(hook);
+                let name = name.clone();
+                let mut context = context.clone();
+                let message = message.clone();
+                threadpool.execute(move || {
+                    (hook)(&mut context, &message, &name, &suggestions);
+                });
+            }
         }
+
+        self.dispatch_normal_message(context, message, NormalMessage::UnrecognisedCommand {
+            name: unrecognised_name,
+            content,
+        }, threadpool);
     }
 
     fn update_current_user(&mut self, user_id: UserId) {
@@ -1203,6 +1614,227 @@ impl Framework for StandardFramework {
     }
 }
 
+/// Runs the group's and the command's checks, short-circuiting on the first
+/// failing one.
+///
+/// Group checks are evaluated exactly once per invocation, prior to the
+/// command's own checks, unless the command opted out of them. The checks of a
+/// sub-group already contain the ones of its parents, so only the innermost
+/// group's are run. Checks granting owner privilege are skipped if the author
+/// is an owner.
+fn passes_checks(context: &mut Context,
+                 message: &Message,
+                 args: &mut Args,
+                 command: &CommandOptions,
+                 group: &CommandGroup,
+                 is_owner: bool)
+                 -> bool {
+    let group_checks: &[Check] = if command.inherit_group_checks {
+        &group.checks
+    } else {
+        &[]
+    };
+
+    group_checks
+        .iter()
+        .chain(command.checks.iter())
+        .filter(|check| !(is_owner && check.owner_privilege))
+        .all(|check| (check.function)(context, message, args, command))
+}
+
+/// Whether typing is broadcast while the command executes, as enabled for all
+/// commands or for this one.
+fn broadcasts_typing(configuration: &Configuration, command: &CommandOptions) -> bool {
+    configuration.broadcast_typing || command.broadcast_typing
+}
+
+/// Executes a command, giving up on it once its timeout, if any, elapsed.
+fn execute_command(command: &Arc<Command>,
+                   context: &mut Context,
+                   message: &Message,
+                   args: Args,
+                   typing: bool)
+                   -> CommandResult<Option<CommandOutput>> {
+    let _typing = if typing {
+        Some(message.channel_id.start_typing())
+    } else {
+        None
+    };
+
+    let timeout = match command.options().timeout {
+        Some(timeout) => timeout,
+        None => return command.execute_with_output(context, message, args),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let command = Arc::clone(command);
+    let mut context = context.clone();
+    let message = message.clone();
+
+    thread::spawn(move || {
+        let _ = tx.send(command.execute_with_output(&mut context, &message, args));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(CommandError(format!(
+            "Command timed out after {}.{:03} seconds",
+            timeout.as_secs(),
+            timeout.subsec_millis(),
+        ))),
+        Err(RecvTimeoutError::Disconnected) => Err(CommandError("Command panicked".to_string())),
+    }
+}
+
+/// Renders a command's template for argument count errors, if it set one for
+/// the given `error`.
+fn args_error_reply(command: &CommandOptions, name: &str, error: &DispatchError) -> Option<String> {
+    let (template, bound, given) = match *error {
+        DispatchError::NotEnoughArguments { min, given } =>
+            (command.not_enough_args_message.as_ref()?, ("{min}", min), given),
+        DispatchError::TooManyArguments { max, given } =>
+            (command.too_many_args_message.as_ref()?, ("{max}", max), given),
+        _ => return None,
+    };
+
+    Some(template
+        .replace("{name}", name)
+        .replace("{usage}", command.usage.as_ref().map_or("", |usage| usage))
+        .replace(bound.0, &bound.1.to_string())
+        .replace("{given}", &given.to_string()))
+}
+
+/// Application command option type of a sub-command.
+const SUB_COMMAND: u8 = 1;
+/// Application command option type of a sub-command group.
+const SUB_COMMAND_GROUP: u8 = 2;
+/// Application command option type of a string.
+const STRING: u8 = 3;
+
+/// Discord's limit on the length of a name.
+const NAME_LIMIT: usize = 32;
+/// Discord's limit on the length of a description.
+const DESCRIPTION_LIMIT: usize = 100;
+/// Discord's limit on the number of options of a command.
+const OPTIONS_LIMIT: usize = 25;
+
+fn definition_description(description: Option<&String>, name: &str) -> String {
+    // Discord requires a non-empty description.
+    description
+        .filter(|description| !description.is_empty())
+        .map_or(name, |description| &description[..])
+        .chars()
+        .take(DESCRIPTION_LIMIT)
+        .collect()
+}
+
+fn command_definitions(group: &CommandGroup) -> Vec<Value> {
+    group.commands
+        .iter()
+        .filter_map(|(name, command)| match *command {
+            CommandOrAlias::Command(ref command) => Some((name, command.options())),
+            CommandOrAlias::Alias(_) => None,
+        })
+        .map(|(name, options)| {
+            let min_args = options.min_args.unwrap_or(0).max(0) as usize;
+            let args = options.max_args
+                .map_or(min_args, |max| max.max(0) as usize)
+                .min(OPTIONS_LIMIT);
+
+            let args = (1..=args)
+                .map(|n| json!({
+                    "type": STRING,
+                    "name": format!("argument-{}", n),
+                    "description": format!("Argument {}", n),
+                    "required": n <= min_args,
+                }))
+                .collect::<Vec<_>>();
+
+            json!({
+                "type": SUB_COMMAND,
+                "name": name.to_lowercase(),
+                "description": definition_description(options.desc.as_ref(), name),
+                "options": args,
+            })
+        })
+        .collect()
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let length = name.chars().count();
+
+    length >= 1
+        && length <= NAME_LIMIT
+        && name.chars().all(|c| c == '-' || c == '_' || c.is_alphanumeric())
+}
+
+/// Checks the names of the definitions and their sub-commands, where `path`
+/// is the full name of their parent.
+fn validate_definitions(definitions: &[Value], path: &str) -> Result<(), ApplicationCommandError> {
+    let mut names = Vec::with_capacity(definitions.len());
+
+    for definition in definitions {
+        // Argument options have generated names, while top-level commands
+        // carry no type.
+        match definition.get("type").and_then(Value::as_u64) {
+            None => {},
+            Some(kind) if kind == u64::from(SUB_COMMAND) || kind == u64::from(SUB_COMMAND_GROUP) => {},
+            Some(_) => continue,
+        }
+
+        let name = definition.get("name").and_then(Value::as_str).unwrap_or_default();
+        let full_name = if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{} {}", path, name)
+        };
+
+        if !is_valid_name(name) {
+            return Err(ApplicationCommandError::InvalidName(full_name));
+        }
+
+        if names.contains(&name) {
+            return Err(ApplicationCommandError::NameCollision(full_name));
+        }
+
+        names.push(name);
+
+        if let Some(&Value::Array(ref options)) = definition.get("options") {
+            validate_definitions(options, &full_name)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the commands of `group` and, if `top_level`, its sub-groups.
+fn group_definition_options(group: &CommandGroup, top_level: bool) -> Vec<Value> {
+    let mut options = command_definitions(group);
+
+    for sub_group in group.sub_groups.values() {
+        // Sub-groups without a prefix of their own share their parent's prefix.
+        if !top_level || sub_group.prefixes == group.prefixes {
+            options.extend(group_definition_options(sub_group, top_level));
+
+            continue;
+        }
+
+        let name = sub_group.prefixes.as_ref()
+            .and_then(|prefixes| prefixes.first())
+            .and_then(|prefix| prefix.split_whitespace().last())
+            .unwrap_or_default();
+
+        options.push(json!({
+            "type": SUB_COMMAND_GROUP,
+            "name": name.to_lowercase(),
+            "description": definition_description(sub_group.description.as_ref(), name),
+            "options": group_definition_options(sub_group, false),
+        }));
+    }
+
+    options
+}
+
 #[cfg(feature = "cache")]
 pub fn has_correct_permissions(command: &Arc<CommandOptions>, message: &Message) -> bool {
     if !command.required_permissions.is_empty() {
@@ -1253,3 +1885,421 @@ impl fmt::Display for HelpBehaviour {
        fmt::Debug::fmt(self, f)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use client::Context;
+    use model::channel::Message;
+    use std::sync::Arc;
+    use super::{
+        args_error_reply,
+        broadcasts_typing,
+        command_definitions,
+        execute_command,
+        passes_checks,
+        ApplicationCommandError,
+        Args,
+        Check,
+        Command,
+        CommandError,
+        CommandGroup,
+        CommandOptions,
+        CommandOrAlias,
+        Configuration,
+        CreateCommand,
+        CreateGroup,
+        DispatchError,
+        StandardFramework,
+        OPTIONS_LIMIT
+    };
+
+    struct Ban(Arc<CommandOptions>);
+
+    impl Command for Ban {
+        fn execute(&self, _: &mut Context, _: &Message, _: Args) -> Result<(), CommandError> {
+            Ok(())
+        }
+
+        fn options(&self) -> Arc<CommandOptions> {
+            Arc::clone(&self.0)
+        }
+    }
+
+    fn group_with_ban(min_args: i32, max_args: Option<i32>) -> CommandGroup {
+        let mut options = CommandOptions::default();
+        options.desc = Some("Bans a user.".to_string());
+        options.min_args = Some(min_args);
+        options.max_args = max_args;
+
+        let mut group = CommandGroup::default();
+        group.commands.insert("Ban".to_string(), CommandOrAlias::Command(Arc::new(Ban(Arc::new(options)))));
+
+        group
+    }
+
+    fn options() -> CommandOptions {
+        let mut options = CommandOptions::default();
+        options.usage = Some("<user> [reason]".to_string());
+        options.not_enough_args_message = Some("`{name}` takes at least {min} arguments, got {given}: {usage}".to_string());
+        options.too_many_args_message = Some("`{name}` takes at most {max} arguments, got {given}".to_string());
+
+        options
+    }
+
+    #[test]
+    fn args_error_reply_not_enough_args() {
+        let error = DispatchError::NotEnoughArguments { min: 1, given: 0 };
+
+        assert_eq!(
+            args_error_reply(&options(), "ban", &error),
+            Some("`ban` takes at least 1 arguments, got 0: <user> [reason]".to_string()),
+        );
+    }
+
+    #[test]
+    fn args_error_reply_too_many_args() {
+        let error = DispatchError::TooManyArguments { max: 2, given: 3 };
+
+        assert_eq!(
+            args_error_reply(&options(), "ban", &error),
+            Some("`ban` takes at most 2 arguments, got 3".to_string()),
+        );
+    }
+
+    #[test]
+    fn args_error_reply_without_template() {
+        let error = DispatchError::NotEnoughArguments { min: 1, given: 0 };
+        assert_eq!(args_error_reply(&CommandOptions::default(), "ban", &error), None);
+
+        // Other errors are never replied to.
+        assert_eq!(args_error_reply(&options(), "ban", &DispatchError::OnlyForDM), None);
+    }
+
+    #[test]
+    fn args_error_reply_without_usage() {
+        let mut options = options();
+        options.usage = None;
+        let error = DispatchError::NotEnoughArguments { min: 1, given: 0 };
+
+        assert_eq!(
+            args_error_reply(&options, "ban", &error),
+            Some("`ban` takes at least 1 arguments, got 0: ".to_string()),
+        );
+    }
+
+    #[test]
+    fn command_definition() {
+        assert_eq!(command_definitions(&group_with_ban(1, Some(2))), vec![json!({
+            "type": 1,
+            "name": "ban",
+            "description": "Bans a user.",
+            "options": [
+                {
+                    "type": 3,
+                    "name": "argument-1",
+                    "description": "Argument 1",
+                    "required": true,
+                },
+                {
+                    "type": 3,
+                    "name": "argument-2",
+                    "description": "Argument 2",
+                    "required": false,
+                },
+            ],
+        })]);
+    }
+
+    #[test]
+    fn command_definition_options_are_limited() {
+        for &(min_args, max_args) in &[(0, Some(40)), (30, None)] {
+            let definitions = command_definitions(&group_with_ban(min_args, max_args));
+            let options = definitions[0]["options"].as_array().unwrap();
+
+            assert_eq!(options.len(), OPTIONS_LIMIT);
+            assert_eq!(options[OPTIONS_LIMIT - 1]["name"], "argument-25");
+        }
+    }
+
+    #[test]
+    fn sub_groups_are_registered_by_path() {
+        let framework = StandardFramework::new()
+            .group("Admin", |g| g
+                .prefix("admin")
+                .sub_group("Settings", |g| g.prefix("settings").cmd("show", noop)))
+            .group("Music", |g| g
+                .prefix("music")
+                .sub_group("Settings", |g| g.prefix("settings").cmd("show", noop)));
+
+        let prefixes = |path: &str| framework.groups[path].prefixes.clone();
+
+        assert_eq!(prefixes("Admin::Settings"), Some(vec!["admin settings".to_string()]));
+        assert_eq!(prefixes("Music::Settings"), Some(vec!["music settings".to_string()]));
+        assert!(!framework.groups.contains_key("Settings"));
+    }
+
+    fn noop(_: &mut Context, _: &Message, _: Args) -> Result<(), CommandError> {
+        Ok(())
+    }
+
+    #[test]
+    fn application_command_names_are_validated() {
+        let framework = StandardFramework::new()
+            .group("Music", |g| g.prefix("music").cmd("play", noop).cmd("Skip-2_ä", noop));
+        assert!(framework.application_commands().is_ok());
+
+        let framework = StandardFramework::new()
+            .group("Music", |g| g.prefix("music").cmd("play!", noop));
+        assert_eq!(
+            framework.application_commands(),
+            Err(ApplicationCommandError::InvalidName("music play!".to_string())),
+        );
+
+        let long = "a".repeat(33);
+        let framework = StandardFramework::new().group("Misc", |g| g.cmd(&long, noop));
+        assert_eq!(
+            framework.application_commands(),
+            Err(ApplicationCommandError::InvalidName(long.clone())),
+        );
+    }
+
+    #[test]
+    fn application_command_collisions() {
+        let framework = StandardFramework::new()
+            .group("Fun", |g| g.cmd("ping", noop))
+            .group("Misc", |g| g.cmd("Ping", noop));
+        assert_eq!(
+            framework.application_commands(),
+            Err(ApplicationCommandError::NameCollision("ping".to_string())),
+        );
+
+        // Commands of groups with a prefix do not collide with top-level ones.
+        let framework = StandardFramework::new()
+            .group("Fun", |g| g.cmd("ping", noop))
+            .group("Music", |g| g.prefix("music").cmd("ping", noop));
+        assert!(framework.application_commands().is_ok());
+    }
+
+    // A context that is not connected to a shard, for calling checks.
+    fn context() -> Context {
+        use client::bridge::gateway::ShardMessenger;
+        use parking_lot::Mutex;
+        use std::sync::mpsc;
+        use typemap::ShareMap;
+
+        let (tx, _) = mpsc::channel();
+
+        Context {
+            data: Arc::new(Mutex::new(ShareMap::custom())),
+            shard: ShardMessenger::new(tx),
+            shard_id: 0,
+            #[cfg(feature = "voice")]
+            voice_manager: Arc::new(Mutex::new(::client::bridge::voice::ClientVoiceManager::new(
+                1,
+                1.into(),
+            ))),
+        }
+    }
+
+    fn message() -> Message {
+        ::serde_json::from_str(include_str!("../../../tests/resources/message_create_1.json"))
+            .unwrap()
+    }
+
+    fn run_checks(command: &CommandOptions, group: &CommandGroup, is_owner: bool) -> bool {
+        let mut args = Args::new("", &[]);
+
+        passes_checks(&mut context(), &message(), &mut args, command, group, is_owner)
+    }
+
+    fn command_with_check(passes: bool, owner_privilege: bool) -> CommandOptions {
+        let mut options = CommandOptions::default();
+        options.checks.push(Check::new(move |_, _, _, _| passes, owner_privilege));
+
+        options
+    }
+
+    #[test]
+    fn passes_checks_without_inheriting() {
+        let group = CreateGroup::default().check(|_, _, _, _| false).build();
+
+        let mut command = command_with_check(true, true);
+        assert!(!run_checks(&command, &group, false));
+
+        command.inherit_group_checks = false;
+        assert!(run_checks(&command, &group, false));
+
+        // The command's own checks are still run.
+        let mut command = command_with_check(false, true);
+        command.inherit_group_checks = false;
+        assert!(!run_checks(&command, &group, false));
+    }
+
+    #[test]
+    fn passes_checks_owner_privilege() {
+        let group = CreateGroup::default().check(|_, _, _, _| false).build();
+        let strict_group = CreateGroup::default()
+            .check_with_owner_privilege(|_, _, _, _| false, false)
+            .build();
+
+        // Checks granting owner privilege are skipped for owners.
+        assert!(run_checks(&command_with_check(false, true), &group, true));
+        assert!(!run_checks(&command_with_check(true, true), &strict_group, true));
+        assert!(!run_checks(&command_with_check(false, false), &group, true));
+
+        // Without inheriting, even strict group checks are not run.
+        let mut command = command_with_check(true, false);
+        command.inherit_group_checks = false;
+        assert!(run_checks(&command, &strict_group, true));
+        assert!(run_checks(&command, &strict_group, false));
+
+        let mut command = command_with_check(false, false);
+        command.inherit_group_checks = false;
+        assert!(!run_checks(&command, &strict_group, true));
+    }
+
+    #[test]
+    fn passes_checks_once_per_invocation() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let outer = Arc::new(AtomicUsize::new(0));
+        let inner = Arc::new(AtomicUsize::new(0));
+        let (outer_check, inner_check) = (Arc::clone(&outer), Arc::clone(&inner));
+
+        let group = CreateGroup::default()
+            .check(move |_, _, _, _| {
+                outer_check.fetch_add(1, Ordering::SeqCst);
+
+                true
+            })
+            .sub_group("Inner", |g| g.sub_group("Innermost", |g| g.check(move |_, _, _, _| {
+                inner_check.fetch_add(1, Ordering::SeqCst);
+
+                true
+            })))
+            .build();
+        let innermost = &group.sub_groups["Inner"].sub_groups["Innermost"];
+
+        assert!(run_checks(&CommandOptions::default(), innermost, false));
+        assert_eq!(outer.load(Ordering::SeqCst), 1);
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
+
+        let mut command = CommandOptions::default();
+        command.inherit_group_checks = false;
+
+        assert!(run_checks(&command, innermost, false));
+        assert_eq!(outer.load(Ordering::SeqCst), 1);
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
+    }
+
+    fn sleep(_: &mut Context, _: &Message, _: Args) -> Result<(), CommandError> {
+        ::std::thread::sleep(::std::time::Duration::from_millis(500));
+
+        Ok(())
+    }
+
+    fn execute(command: CreateCommand) -> Result<(), CommandError> {
+        let command = command.finish();
+
+        execute_command(&command, &mut context(), &message(), Args::new("", &[]), false).map(|_| ())
+    }
+
+    #[test]
+    fn execute_command_times_out() {
+        use std::time::Duration;
+
+        let result = execute(CreateCommand::default().exec(sleep).timeout(Duration::from_millis(20)));
+        assert_eq!(result.unwrap_err().0, "Command timed out after 0.020 seconds");
+
+        let result = execute(CreateCommand::default().exec(noop).timeout(Duration::from_secs(5)));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn execute_command_reports_panics() {
+        use std::time::Duration;
+
+        fn panics(_: &mut Context, _: &Message, _: Args) -> Result<(), CommandError> {
+            panic!("command");
+        }
+
+        let result = execute(CreateCommand::default().exec(panics).timeout(Duration::from_secs(5)));
+        assert_eq!(result.unwrap_err().0, "Command panicked");
+    }
+
+    #[test]
+    fn execute_command_passes_output() {
+        let command = CreateCommand::default()
+            .exec_with_output(|_, msg, _| Ok(msg.content.len()))
+            .finish();
+
+        let output = execute_command(&command, &mut context(), &message(), Args::new("", &[]), false)
+            .ok()
+            .and_then(|output| output)
+            .and_then(|output| output.downcast::<usize>().ok());
+        assert_eq!(output.map(|len| *len), Some(1));
+
+        let command = CreateCommand::default().exec(noop).finish();
+
+        let output = execute_command(&command, &mut context(), &message(), Args::new("", &[]), false);
+        assert!(output.ok().map_or(false, |output| output.is_none()));
+    }
+
+    #[test]
+    fn typing_is_broadcast_if_enabled_anywhere() {
+        let quiet = Configuration::default();
+        let typing = Configuration::default().broadcast_typing(true);
+
+        let command = CreateCommand::default().finish().options();
+        let typing_command = CreateCommand::default().broadcast_typing(true).finish().options();
+
+        assert!(!broadcasts_typing(&quiet, &command));
+        assert!(broadcasts_typing(&quiet, &typing_command));
+        assert!(broadcasts_typing(&typing, &command));
+        assert!(broadcasts_typing(&typing, &typing_command));
+    }
+
+    check!(in_guild(_ctx, msg) {
+        msg.guild_id.is_some()
+    });
+
+    check!(few_args(_ctx, _msg, args) {
+        args.len() <= 1
+    });
+
+    check!(owners_only(_ctx, _msg, _args, options) {
+        options.owners_only
+    });
+
+    #[test]
+    fn check_macro() {
+        let message = message();
+        let mut options = CommandOptions::default();
+
+        assert!(!in_guild(&mut context(), &message, &mut Args::new("", &[]), &options));
+
+        let delimiters = [" ".to_string()];
+        assert!(few_args(&mut context(), &message, &mut Args::new("a", &delimiters), &options));
+        assert!(!few_args(&mut context(), &message, &mut Args::new("a b", &delimiters), &options));
+
+        assert!(!owners_only(&mut context(), &message, &mut Args::new("", &[]), &options));
+        options.owners_only = true;
+        assert!(owners_only(&mut context(), &message, &mut Args::new("", &[]), &options));
+    }
+
+    #[test]
+    fn prefix_reports_normal_messages() {
+        use super::parse::{self, ParseError};
+
+        let conf = Configuration::default().prefix("~");
+        let mut message = message();
+
+        assert_eq!(parse::prefix(&mut context(), &message, &conf), Err(ParseError::NoPrefix));
+
+        message.content = "~".to_string();
+        assert_eq!(parse::prefix(&mut context(), &message, &conf), Err(ParseError::PrefixOnly));
+
+        message.content = "~ping".to_string();
+        assert_eq!(parse::prefix(&mut context(), &message, &conf), Ok(vec![1]));
+    }
+}
@@ -0,0 +1,734 @@
+//! The parsing machinery the [`StandardFramework`] uses to turn a message into
+//! a command invocation.
+//!
+//! The functions in here are exposed so that custom [`Framework`]s can reuse
+//! the prefix and command resolution of the standard framework, while
+//! implementing their own dispatching.
+//!
+//! # Examples
+//!
+//! Resolving the command a message invokes:
+//!
+//! ```rust,ignore
+//! use serenity::framework::standard::parse::{self, Invoke};
+//!
+//! if let Ok(positions) = parse::prefix(&mut ctx, &msg, &config) {
+//!     for position in positions {
+//!         if let Ok(Invoke::Command { name, args, .. }) =
+//!             parse::command(&config, &groups, &msg.content, position, false) {
+//!             println!("{} was invoked with {:?}", name, args.full());
+//!
+//!             break;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`Framework`]: ../../trait.Framework.html
+//! [`StandardFramework`]: ../struct.StandardFramework.html
+
+use client::Context;
+use model::{channel::Message, id::GuildId};
+use std::{
+    collections::HashMap,
+    hash::BuildHasher,
+    sync::Arc
+};
+use super::{
+    help_commands::levenshtein_distance,
+    Args,
+    Command,
+    CommandGroup,
+    CommandOrAlias,
+    Configuration
+};
+
+#[cfg(feature = "cache")]
+use model::channel::Channel;
+
+macro_rules! command_and_help_args {
+    ($message_content:expr, $position:expr, $command_length:expr, $delimiters:expr) => {
+        {
+            let content = $message_content.get($position..).unwrap_or("").trim_left();
+            let content = content.get($command_length..).unwrap_or("");
+
+            Args::new(content.trim(), $delimiters)
+        }
+    };
+}
+
+/// A command invocation resolved by [`command`].
+///
+/// [`command`]: fn.command.html
+pub enum Invoke {
+    /// The help-command was invoked.
+    Help {
+        /// The name the help-command was invoked with.
+        name: String,
+        /// The arguments passed to the help-command.
+        args: Args,
+    },
+    /// A command of a group was invoked.
+    Command {
+        /// The invoked command.
+        command: Arc<Command>,
+        /// The group the command belongs to.
+        group: Arc<CommandGroup>,
+        /// The full name of the command, including the group's prefix and
+        /// with aliases being resolved.
+        name: String,
+        /// The name of the command within its group, excluding the prefix.
+        command_name: String,
+        /// The arguments passed to the command.
+        args: Args,
+    },
+    /// Only a group's prefix was given, or it was followed by a name not
+    /// matching any of the group's commands, invoking the group's default
+    /// command.
+    DefaultCommand {
+        /// The group's default command.
+        command: Arc<Command>,
+        /// The group the prefix belongs to.
+        group: Arc<CommandGroup>,
+        /// The name the default command was invoked with.
+        name: String,
+        /// The arguments passed to the default command, being everything
+        /// following the group's prefix.
+        args: Args,
+    },
+}
+
+/// The reasons [`command`] may fail to resolve an invocation.
+///
+/// [`command`]: fn.command.html
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+    /// The message does not start with a prefix.
+    NoPrefix,
+    /// The message consists of nothing but a prefix.
+    PrefixOnly,
+    /// No command matched. Contains the last name that was looked up, if any.
+    UnrecognisedCommand(Option<String>),
+}
+
+/// Determines the positions at which a prefix of a message ends, and therefore
+/// attempting to parse a command from makes sense. The positions are given in
+/// bytes.
+///
+/// Mentions of the current user take precedence over dynamic prefixes, which
+/// in turn take precedence over the prefix of the guild in the [`PrefixStore`]
+/// and then the configured ones.
+///
+/// Returns [`ParseError::NoPrefix`] if the message does not start with a
+/// prefix, and [`ParseError::PrefixOnly`] if it consists of nothing but the
+/// prefix.
+///
+/// [`ParseError::NoPrefix`]: enum.ParseError.html#variant.NoPrefix
+/// [`ParseError::PrefixOnly`]: enum.ParseError.html#variant.PrefixOnly
+/// [`PrefixStore`]: ../trait.PrefixStore.html
+pub fn prefix(ctx: &mut Context, msg: &Message, conf: &Configuration) -> Result<Vec<usize>, ParseError> {
+    let mut positions = find_prefix_positions(ctx, msg, conf).ok_or(ParseError::NoPrefix)?;
+
+    // Take out the prefixes that are as long as _or_ longer than the message,
+    // to avoid character boundary violations.
+    positions.retain(|p| *p < msg.content.len());
+
+    if positions.is_empty() {
+        Err(ParseError::PrefixOnly)
+    } else {
+        Ok(positions)
+    }
+}
+
+fn find_prefix_positions(ctx: &mut Context, msg: &Message, conf: &Configuration) -> Option<Vec<usize>> {
+    // Mentions have the highest precedence.
+    if let Some(mention_end) = find_mention_end(&msg.content, conf) {
+        return Some(vec![mention_end]); // This can simply be returned without trying to find the end whitespaces as trim will remove it later
+    }
+
+    if !conf.prefixes.is_empty() || conf.dynamic_prefix.is_some() || conf.prefix_store.is_some() {
+        // Determine if a prefix was used. Otherwise return None.
+        let dynamic_prefix = conf.dynamic_prefix.as_ref().and_then(|f| f(ctx, msg));
+        let mut positions = prefix_positions(&msg.content, dynamic_prefix, msg.guild_id, conf);
+
+        #[cfg(feature = "cache")]
+        {
+            let private = match msg.channel() {
+                Some(Channel::Private(_)) => true,
+                _ => false,
+            };
+
+            // If the above do not fill `positions`, then that means no kind of prefix was present.
+            // Check if a no-prefix-execution is applicable.
+            if conf.no_dm_prefix && private && positions.is_empty() {
+                positions.push(0);
+            }
+        }
+
+        if positions.is_empty() {
+            return None;
+        }
+
+        let pos = *unsafe { positions.get_unchecked(0) };
+
+        let with_whitespace = find_end_of_prefix_with_whitespace(&msg.content, pos);
+
+        if conf.allow_whitespace {
+            positions.insert(0, with_whitespace.unwrap_or(pos));
+        } else if with_whitespace.is_some() {
+            return None;
+        }
+
+        Some(positions)
+    } else {
+        None
+    }
+}
+
+// Finds the ends of the dynamic prefix, the guild's prefix or the configured
+// prefixes the content starts with.
+fn prefix_positions(content: &str,
+                    dynamic_prefix: Option<String>,
+                    guild_id: Option<GuildId>,
+                    conf: &Configuration) -> Vec<usize> {
+    let mut positions = Vec::new();
+
+    // Dynamic prefixes, if present and suitable, always have a higher priority,
+    // followed by the prefix of the guild.
+    if let Some(x) = dynamic_prefix {
+        if content.starts_with(&x) {
+            positions.push(x.len());
+        }
+    } else if let Some(x) = guild_prefix(guild_id, conf) {
+        if content.starts_with(&x) {
+            positions.push(x.len());
+        }
+    } else {
+        for n in &conf.prefixes {
+            if content.starts_with(n) {
+                positions.push(n.len());
+            }
+        }
+    }
+
+    positions
+}
+
+fn guild_prefix(guild_id: Option<GuildId>, conf: &Configuration) -> Option<String> {
+    let guild_id = guild_id?;
+
+    conf.prefix_store.as_ref().and_then(|store| store.prefix(guild_id))
+}
+
+fn find_mention_end(content: &str, conf: &Configuration) -> Option<usize> {
+    conf.on_mention.as_ref().and_then(|mentions| {
+        mentions
+            .iter()
+            .find(|mention| content.starts_with(&mention[..]))
+            .map(|m| m.len())
+    })
+}
+
+// Finds the end of the first continuous block of whitespace after the prefix
+fn find_end_of_prefix_with_whitespace(content: &str, position: usize) -> Option<usize> {
+    let content_len = content.len();
+    if position >= content_len { return None; }
+
+    let mut i = 0;
+    let chars = content.get(position..)?.chars();
+    for char in chars {
+        match char {
+            // \t \n \r [space]
+            '\t' | '\n' | '\r' | ' ' => i += 1,
+            _ => return if i == 0 { None } else { Some(position + i) }
+        }
+    }
+    Some(content.len())
+}
+
+pub(crate) fn skip_prefix_and_trim_to_new_string(content: &str, position: usize) -> String {
+    content.get(position..).unwrap_or("").trim().to_string()
+}
+
+/// Resolves the command invoked by the `content` of a message, starting after
+/// the prefix ending at `position`, as returned by [`prefix`].
+///
+/// Up to [`Configuration::depth`] words are taken into account for the
+/// command's name. If `help` is `true`, a command named `help` resolves to
+/// [`Invoke::Help`].
+///
+/// [`Configuration::depth`]: ../struct.Configuration.html#method.depth
+/// [`Invoke::Help`]: enum.Invoke.html#variant.Help
+/// [`prefix`]: fn.prefix.html
+pub fn command<H: BuildHasher>(
+    conf: &Configuration,
+    groups: &HashMap<String, Arc<CommandGroup>, H>,
+    content: &str,
+    position: usize,
+    help: bool,
+) -> Result<Invoke, ParseError> {
+    let mut built = String::new();
+    let mut unrecognised_command_name = None;
+    let mut default_command = None;
+    let prefixes_case_insensitive = conf.case_insensitive_group_prefixes
+        .unwrap_or(conf.case_insensitive);
+
+    let orginal_round = skip_prefix_and_trim_to_new_string(content, position);
+    let mut round = orginal_round.split_whitespace();
+
+    for i in 0..conf.depth {
+        if i != 0 {
+            built.push(' ');
+        }
+
+        built.push_str(match round.next() {
+            Some(piece) => piece,
+            None => break,
+        });
+
+        unrecognised_command_name = Some(if conf.case_insensitive {
+            built.to_lowercase()
+        } else {
+            built.clone()
+        });
+
+        for group in groups.values() {
+            let command_length = built.len();
+
+            let prefix = match group.prefixes {
+                // Once `built` starts with a set prefix,
+                // we want to make sure that all following matching prefixes are longer
+                // than the last matching one, this prevents picking a wrong prefix,
+                // e.g. "f" instead of "ferris" due to "f" having a lower index in the `Vec`.
+                Some(ref prefixes) => match prefixes.iter().fold(None, |longest_prefix: Option<&String>, prefix|
+                    if prefix.len() > longest_prefix.map_or(0, |p| p.len())
+                    && starts_with_prefix(&built, prefix, prefixes_case_insensitive)
+                    && (orginal_round.len() == prefix.len() || built.get(prefix.len()..prefix.len() + 1) == Some(" ")) {
+                        Some(prefix)
+                    } else {
+                        longest_prefix
+                    }
+                ) {
+                    Some(prefix) => Some(prefix),
+                    None => continue,
+                },
+                None => None,
+            };
+
+            // Bring the name into the form commands and aliases are registered in.
+            let rest = match prefix {
+                Some(prefix) => built.get(prefix.len() + 1..).unwrap_or(""),
+                None => &built[..],
+            };
+
+            let rest = if conf.case_insensitive {
+                rest.to_lowercase()
+            } else {
+                rest.to_string()
+            };
+
+            let mut name = match prefix {
+                Some(prefix) if rest.is_empty() => prefix.clone(),
+                Some(prefix) => format!("{} {}", prefix, rest),
+                None => rest,
+            };
+
+            if let Some(&CommandOrAlias::Alias(ref points_to)) = group.commands.get(&name) {
+                name = points_to.to_string();
+            }
+
+            let check_contains_group_prefix = prefix.is_some();
+            let longest_matching_prefix_len = prefix.map_or(0, |prefix| prefix.len());
+            let to_check = match prefix {
+                Some(prefix) => name.get(prefix.len() + 1..).unwrap_or("").to_string(),
+                None => name.clone(),
+            };
+
+            if help && to_check == "help" {
+                return Ok(Invoke::Help {
+                    args: command_and_help_args!(content, position, command_length, &conf.delimiters),
+                    name,
+                });
+            }
+
+            if !to_check.is_empty() {
+
+                if let Some(&CommandOrAlias::Command(ref command)) = group.commands.get(&to_check) {
+                    let delimiters = command_delimiters(command, conf);
+
+                    return Ok(Invoke::Command {
+                        command: Arc::clone(command),
+                        group: Arc::clone(group),
+                        args: command_and_help_args!(content, position, command_length, &delimiters),
+                        name,
+                        command_name: to_check,
+                    });
+                }
+            }
+
+            if check_contains_group_prefix {
+                let is_longer = default_command.as_ref()
+                    .map_or(true, |&(prefix_len, _)| longest_matching_prefix_len > prefix_len);
+
+                if is_longer {
+
+                    if let Some(CommandOrAlias::Command(ref command)) = group.default_command {
+                        let delimiters = command_delimiters(command, conf);
+
+                        // Nested groups share the start of their prefix with their parent,
+                        // hence keep looking for a command of a more deeply nested group.
+                        default_command = Some((longest_matching_prefix_len, Invoke::DefaultCommand {
+                            command: Arc::clone(command),
+                            group: Arc::clone(group),
+                            args: Args::new(orginal_round[longest_matching_prefix_len..].trim(), &delimiters),
+                            name,
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    match default_command {
+        Some((_, invoke)) => Ok(invoke),
+        None => Err(ParseError::UnrecognisedCommand(unrecognised_command_name)),
+    }
+}
+
+/// Finds the names of the commands similar to a `name` that no command
+/// matched, as given by [`ParseError::UnrecognisedCommand`], to suggest them
+/// instead.
+///
+/// A name is similar if its Levenshtein distance to as many leading words of
+/// `name` as it consists of, but at least the first, is at most
+/// `max_distance`, so that arguments following the name are not taken into
+/// account. Commands of groups with prefixes are named with the first prefix
+/// of their group, and commands hidden from the help are skipped. The names
+/// are sorted by their distance, closest first.
+///
+/// [`ParseError::UnrecognisedCommand`]: enum.ParseError.html#variant.UnrecognisedCommand
+pub fn similar_commands<H: BuildHasher>(
+    groups: &HashMap<String, Arc<CommandGroup>, H>,
+    name: &str,
+    max_distance: usize,
+) -> Vec<String> {
+    let mut similar = groups
+        .values()
+        .filter(|group| group.help_available)
+        .flat_map(|group| {
+            let prefix = group.prefixes.as_ref().and_then(|prefixes| prefixes.first());
+
+            group.commands.iter().filter_map(move |(command_name, command)| match *command {
+                CommandOrAlias::Command(ref command) if command.options().help_available => {
+                    Some(match prefix {
+                        Some(prefix) => format!("{} {}", prefix, command_name),
+                        None => command_name.clone(),
+                    })
+                },
+                _ => None,
+            })
+        })
+        .filter_map(|candidate| {
+            let words = candidate.split_whitespace().count().max(1);
+            let leading = name.split_whitespace().take(words).collect::<Vec<_>>().join(" ");
+            let distance = levenshtein_distance(&candidate, &leading);
+
+            if distance <= max_distance {
+                Some((distance, candidate))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    similar.sort();
+    similar.dedup();
+
+    similar.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+/// The delimiters the command's arguments are split by, preferring the
+/// command's own over the configured ones.
+fn command_delimiters(command: &Arc<Command>, conf: &Configuration) -> Vec<String> {
+    let options = command.options();
+
+    if options.delimiters.is_empty() {
+        conf.delimiters.clone()
+    } else {
+        options.delimiters.clone()
+    }
+}
+
+fn starts_with_prefix(name: &str, prefix: &str, case_insensitive: bool) -> bool {
+    name.get(..prefix.len()).map_or(false, |start| if case_insensitive {
+        start.to_lowercase() == prefix.to_lowercase()
+    } else {
+        start == prefix
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use client::Context;
+    use framework::standard::{
+        Args,
+        CommandError,
+        CommandGroup,
+        CommandOrAlias,
+        Configuration,
+        CreateCommand,
+        MemoryPrefixStore,
+        PrefixStore
+    };
+    use model::{channel::Message, id::GuildId};
+    use std::{collections::HashMap, sync::Arc};
+    use super::super::help_commands::levenshtein_distance;
+    use super::{
+        command,
+        find_end_of_prefix_with_whitespace,
+        prefix_positions,
+        similar_commands,
+        skip_prefix_and_trim_to_new_string,
+        Invoke,
+        ParseError
+    };
+
+    fn noop(_: &mut Context, _: &Message, _: Args) -> Result<(), CommandError> {
+        Ok(())
+    }
+
+    fn group(prefixes: Option<Vec<&str>>, commands: &[&str]) -> Arc<CommandGroup> {
+        let mut group = CommandGroup::default();
+        group.prefixes = prefixes.map(|prefixes| prefixes.iter().map(|p| p.to_string()).collect());
+
+        for name in commands {
+            group.commands.insert(name.to_string(), CommandOrAlias::Command(Arc::new(noop)));
+        }
+
+        Arc::new(group)
+    }
+
+    fn groups(groups: Vec<(&str, Arc<CommandGroup>)>) -> HashMap<String, Arc<CommandGroup>> {
+        groups.into_iter().map(|(name, group)| (name.to_string(), group)).collect()
+    }
+
+    // Returns the full name, the name without the group's prefix and the
+    // arguments of an invoked command.
+    fn invoked(invoke: Result<Invoke, ParseError>) -> (String, String, String) {
+        match invoke {
+            Ok(Invoke::Command { name, command_name, args, .. }) => {
+                (name, command_name, args.full().to_string())
+            },
+            Ok(_) => panic!("not a command invocation"),
+            Err(why) => panic!("no command invoked: {:?}", why),
+        }
+    }
+
+    #[test]
+    fn command_without_group_prefix() {
+        let conf = Configuration::default().prefix("~");
+        let groups = groups(vec![("General", group(None, &["ping"]))]);
+
+        let (name, command_name, args) = invoked(command(&conf, &groups, "~ping a b", 1, false));
+        assert_eq!(name, "ping");
+        assert_eq!(command_name, "ping");
+        assert_eq!(args, "a b");
+    }
+
+    #[test]
+    fn command_with_group_prefix() {
+        let conf = Configuration::default().prefix("~");
+        let groups = groups(vec![
+            ("General", group(None, &["ping"])),
+            ("Admin", group(Some(vec!["admin"]), &["ban"])),
+        ]);
+
+        let (name, command_name, args) = invoked(command(&conf, &groups, "~admin ban spam", 1, false));
+        assert_eq!(name, "admin ban");
+        assert_eq!(command_name, "ban");
+        assert_eq!(args, "spam");
+
+        // The command is only reachable through the group's prefix.
+        match command(&conf, &groups, "~ban spam", 1, false) {
+            Err(why) => assert_eq!(why, ParseError::UnrecognisedCommand(Some("ban spam".to_string()))),
+            Ok(_) => panic!("ban was invoked without the group's prefix"),
+        }
+    }
+
+    #[test]
+    fn case_insensitive_group_prefixes() {
+        let groups = groups(vec![("Music", group(Some(vec!["music"]), &["play"]))]);
+
+        let conf = Configuration::default()
+            .prefix("~")
+            .group_prefix_case_insensitivity(true);
+
+        let (name, command_name, _) = invoked(command(&conf, &groups, "~MUSIC play", 1, false));
+        assert_eq!(name, "music play");
+        assert_eq!(command_name, "play");
+
+        // Command names stay case sensitive.
+        match command(&conf, &groups, "~MUSIC Play", 1, false) {
+            Err(why) => assert_eq!(why, ParseError::UnrecognisedCommand(Some("MUSIC Play".to_string()))),
+            Ok(_) => panic!("Play was invoked despite case sensitive command names"),
+        }
+
+        // Prefixes are case sensitive by default.
+        let conf = Configuration::default().prefix("~");
+
+        match command(&conf, &groups, "~MUSIC play", 1, false) {
+            Err(why) => assert_eq!(why, ParseError::UnrecognisedCommand(Some("MUSIC play".to_string()))),
+            Ok(_) => panic!("the prefix matched despite being case sensitive"),
+        }
+
+        // Unless all names are case insensitive.
+        let conf = Configuration::default().prefix("~").case_insensitivity(true);
+
+        let (name, _, _) = invoked(command(&conf, &groups, "~Music PLAY", 1, false));
+        assert_eq!(name, "music play");
+    }
+
+    #[test]
+    fn default_command_remainder() {
+        let conf = Configuration::default().prefix("~");
+        let mut music = CommandGroup::default();
+        music.prefixes = Some(vec!["music".to_string()]);
+        music.commands.insert("play".to_string(), CommandOrAlias::Command(Arc::new(noop)));
+        music.default_command = Some(CommandOrAlias::Command(Arc::new(noop)));
+        let groups = groups(vec![("Music", Arc::new(music))]);
+
+        match command(&conf, &groups, "~music  some song", 1, false) {
+            Ok(Invoke::DefaultCommand { args, .. }) => assert_eq!(args.full(), "some song"),
+            _ => panic!("the default command was not invoked"),
+        }
+
+        match command(&conf, &groups, "~music", 1, false) {
+            Ok(Invoke::DefaultCommand { args, .. }) => assert_eq!(args.full(), ""),
+            _ => panic!("the default command was not invoked"),
+        }
+
+        // Commands of the group take precedence.
+        let (name, _, args) = invoked(command(&conf, &groups, "~music play song", 1, false));
+        assert_eq!(name, "music play");
+        assert_eq!(args, "song");
+    }
+
+    #[test]
+    fn help_command() {
+        let conf = Configuration::default().prefix("~");
+        let groups = groups(vec![("General", group(None, &["ping"]))]);
+
+        match command(&conf, &groups, "~help ping", 1, true) {
+            Ok(Invoke::Help { name, args }) => {
+                assert_eq!(name, "help");
+                assert_eq!(args.full(), "ping");
+            },
+            _ => panic!("help was not invoked"),
+        }
+
+        // Without a help-command, `help` is looked up like any other name.
+        match command(&conf, &groups, "~help", 1, false) {
+            Err(why) => assert_eq!(why, ParseError::UnrecognisedCommand(Some("help".to_string()))),
+            Ok(_) => panic!("help was invoked"),
+        }
+    }
+
+    fn store_with(guild_id: GuildId, prefix: &str) -> Arc<MemoryPrefixStore> {
+        let store = Arc::new(MemoryPrefixStore::new());
+        store.set_prefix(guild_id, prefix.to_string());
+
+        store
+    }
+
+    #[test]
+    fn configured_prefixes() {
+        let conf = Configuration::default().prefixes(vec!["~", "!!"]);
+
+        assert_eq!(prefix_positions("~ping", None, None, &conf), vec![1]);
+        assert_eq!(prefix_positions("!!ping", None, None, &conf), vec![2]);
+        assert!(prefix_positions("?ping", None, None, &conf).is_empty());
+    }
+
+    #[test]
+    fn guild_prefix_replaces_configured_prefixes() {
+        let conf = Configuration::default()
+            .prefix("~")
+            .prefix_store(store_with(GuildId(1), "?"));
+
+        assert_eq!(prefix_positions("?ping", None, Some(GuildId(1)), &conf), vec![1]);
+        assert!(prefix_positions("~ping", None, Some(GuildId(1)), &conf).is_empty());
+
+        // Other guilds and private channels keep the configured prefixes.
+        assert_eq!(prefix_positions("~ping", None, Some(GuildId(2)), &conf), vec![1]);
+        assert_eq!(prefix_positions("~ping", None, None, &conf), vec![1]);
+    }
+
+    #[test]
+    fn dynamic_prefix_precedes_guild_prefix() {
+        let conf = Configuration::default()
+            .prefix("~")
+            .prefix_store(store_with(GuildId(1), "?"));
+        let dynamic = || Some("$".to_string());
+
+        assert_eq!(prefix_positions("$ping", dynamic(), Some(GuildId(1)), &conf), vec![1]);
+        assert!(prefix_positions("?ping", dynamic(), Some(GuildId(1)), &conf).is_empty());
+    }
+
+    #[test]
+    fn non_ascii_prefix() {
+        let conf = Configuration::default().prefix_store(store_with(GuildId(1), "λ→"));
+        let content = "λ→ping pong";
+
+        let positions = prefix_positions(content, None, Some(GuildId(1)), &conf);
+        assert_eq!(positions, vec!["λ→".len()]);
+        assert_eq!(skip_prefix_and_trim_to_new_string(content, positions[0]), "ping pong");
+
+        let positions = prefix_positions("ü ping", Some("ü".to_string()), None, &conf);
+        assert_eq!(positions, vec![2]);
+        assert_eq!(find_end_of_prefix_with_whitespace("ü ping", positions[0]), Some(3));
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(levenshtein_distance("ban", "ban"), 0);
+        assert_eq!(levenshtein_distance("ban", "bna"), 2);
+        assert_eq!(levenshtein_distance("ban", "bans"), 1);
+        assert_eq!(levenshtein_distance("kick", "ban"), 4);
+        assert_eq!(levenshtein_distance("", "ban"), 3);
+        assert_eq!(levenshtein_distance("ü", "u"), 1);
+    }
+
+    #[test]
+    fn similar_commands_ignore_arguments() {
+        let groups = groups(vec![
+            ("General", group(None, &["ban", "kick"])),
+            ("Music", group(Some(vec!["music"]), &["play"])),
+        ]);
+
+        assert_eq!(similar_commands(&groups, "bna", 2), vec!["ban"]);
+        assert_eq!(similar_commands(&groups, "bna @user spam", 2), vec!["ban"]);
+        assert_eq!(similar_commands(&groups, "musik plya some song", 3), vec!["music play"]);
+        assert!(similar_commands(&groups, "mute @user", 2).is_empty());
+    }
+
+    #[test]
+    fn command_delimiters_override_configured_ones() {
+        let conf = Configuration::default().prefix("~");
+
+        let mut maths = CommandGroup::default();
+        let multiply = CreateCommand::default().delimiters(vec![","]).exec(noop).finish();
+        maths.commands.insert("multiply".to_string(), CommandOrAlias::Command(multiply));
+        maths.commands.insert("add".to_string(), CommandOrAlias::Command(Arc::new(noop)));
+        let groups = groups(vec![("Maths", Arc::new(maths))]);
+
+        match command(&conf, &groups, "~multiply 2,3,4 5", 1, false) {
+            Ok(Invoke::Command { args, .. }) => assert_eq!(args.len(), 3),
+            _ => panic!("multiply was not invoked"),
+        }
+
+        match command(&conf, &groups, "~add 2,3,4 5", 1, false) {
+            Ok(Invoke::Command { args, .. }) => assert_eq!(args.len(), 2),
+            _ => panic!("add was not invoked"),
+        }
+    }
+}
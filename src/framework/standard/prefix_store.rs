@@ -0,0 +1,92 @@
+use model::id::GuildId;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+
+/// A storage of prefixes per guild, which the [`StandardFramework`] consults
+/// when a message is sent in a guild.
+///
+/// The prefix of the guild, if any, takes the place of the configured ones.
+/// Mentions and [dynamic prefixes] still take precedence over it.
+///
+/// As the store is given to the [`Configuration`] behind an [`Arc`], a clone
+/// of the `Arc` can be kept, such as in the [data] of the client, to change
+/// the prefixes at runtime. Implement this to keep the prefixes in a
+/// database, or use the in-memory [`MemoryPrefixStore`].
+///
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Configuration`]: struct.Configuration.html
+/// [`MemoryPrefixStore`]: struct.MemoryPrefixStore.html
+/// [`StandardFramework`]: struct.StandardFramework.html
+/// [data]: ../../client/struct.Client.html#structfield.data
+/// [dynamic prefixes]: struct.Configuration.html#method.dynamic_prefix
+pub trait PrefixStore: Send + Sync {
+    /// Retrieves the prefix of a guild, if one was set.
+    fn prefix(&self, guild_id: GuildId) -> Option<String>;
+
+    /// Sets the prefix of a guild, replacing the previous one.
+    fn set_prefix(&self, guild_id: GuildId, prefix: String);
+
+    /// Removes the prefix of a guild, returning it if one was set.
+    ///
+    /// The guild falls back to the configured prefixes afterwards.
+    fn remove_prefix(&self, guild_id: GuildId) -> Option<String>;
+}
+
+/// A [`PrefixStore`] keeping the prefixes in memory, which are lost when the
+/// bot is restarted.
+///
+/// # Examples
+///
+/// Letting a guild change its prefix through a command:
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # struct Handler;
+/// #
+/// # impl EventHandler for Handler {}
+/// # let mut client = Client::new("token", Handler).unwrap();
+/// #
+/// use serenity::framework::standard::{MemoryPrefixStore, PrefixStore};
+/// use serenity::framework::StandardFramework;
+/// use std::sync::Arc;
+///
+/// let store = Arc::new(MemoryPrefixStore::new());
+/// let commands_store = Arc::clone(&store);
+///
+/// client.with_framework(StandardFramework::new()
+///     .configure(|c| c.prefix("~").prefix_store(store))
+///     .on("prefix", move |_, msg, mut args| {
+///         if let Some(guild_id) = msg.guild_id {
+///             commands_store.set_prefix(guild_id, args.single::<String>()?);
+///         }
+///
+///         Ok(())
+///     }));
+/// ```
+///
+/// [`PrefixStore`]: trait.PrefixStore.html
+#[derive(Debug, Default)]
+pub struct MemoryPrefixStore {
+    prefixes: RwLock<HashMap<GuildId, String>>,
+}
+
+impl MemoryPrefixStore {
+    /// Creates a store without any prefixes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PrefixStore for MemoryPrefixStore {
+    fn prefix(&self, guild_id: GuildId) -> Option<String> {
+        self.prefixes.read().get(&guild_id).cloned()
+    }
+
+    fn set_prefix(&self, guild_id: GuildId, prefix: String) {
+        self.prefixes.write().insert(guild_id, prefix);
+    }
+
+    fn remove_prefix(&self, guild_id: GuildId) -> Option<String> {
+        self.prefixes.write().remove(&guild_id)
+    }
+}
@@ -57,7 +57,7 @@ pub use self::{
 };
 
 use model::{
-    gateway::Game,
+    gateway::ActivityData,
     user::OnlineStatus
 };
 use serde_json::Value;
@@ -70,7 +70,7 @@ use websocket::sync::{
 #[cfg(feature = "client")]
 use client::bridge::gateway::ShardClientMessage;
 
-pub type CurrentPresence = (Option<Game>, OnlineStatus);
+pub type CurrentPresence = (Vec<ActivityData>, OnlineStatus);
 pub type WsClient = Client<TlsStream<TcpStream>>;
 
 /// Indicates the current connection stage of a [`Shard`].
@@ -195,3 +195,48 @@ pub enum ReconnectType {
     /// Indicator that a new connection should be made by sending a RESUME.
     Resume,
 }
+
+/// The information needed to resume the session of a [`Shard`], such as from
+/// another process after a restart.
+///
+/// A session is retrieved through [`Shard::session`], and can be restored
+/// onto a newly created shard through [`Shard::restore_session`] before it
+/// identifies. The gateway only allows resuming for a short while after the
+/// shard disconnected.
+///
+/// [`Shard`]: struct.Shard.html
+/// [`Shard::restore_session`]: struct.Shard.html#method.restore_session
+/// [`Shard::session`]: struct.Shard.html#method.session
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ShardSession {
+    /// The Id of the session, as received in the READY.
+    pub session_id: String,
+    /// The sequence of the last dispatch received.
+    pub seq: u64,
+    /// The shard's Id and the total number of shards.
+    pub shard_info: [u64; 2],
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+    use super::ShardSession;
+
+    #[test]
+    fn shard_session_round_trip() {
+        let session = ShardSession {
+            session_id: "a5b4c3d2".to_string(),
+            seq: 512,
+            shard_info: [3, 8],
+        };
+
+        let value = serde_json::to_value(&session).unwrap();
+        assert_eq!(value, json!({
+            "session_id": "a5b4c3d2",
+            "seq": 512,
+            "shard_info": [3, 8],
+        }));
+
+        assert_eq!(serde_json::from_value::<ShardSession>(value).unwrap(), session);
+    }
+}
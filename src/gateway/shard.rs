@@ -2,7 +2,7 @@ use constants::{self, close_codes};
 use internal::prelude::*;
 use model::{
     event::{Event, GatewayEvent},
-    gateway::Game,
+    gateway::{ActivityData, Game},
     id::GuildId,
     user::OnlineStatus
 };
@@ -17,6 +17,7 @@ use super::{
     ShardAction,
     GatewayError,
     ReconnectType,
+    ShardSession,
     WsClient,
     WebSocketGatewayClientExt,
 };
@@ -101,15 +102,18 @@ impl Shard {
     /// # Examples
     ///
     /// Instantiating a new Shard manually for a bot with no shards, and
-    /// then listening for events:
+    /// then listening for events, retrieving the gateway URL through the
+    /// `http` feature:
     ///
     /// ```rust,no_run
     /// extern crate parking_lot;
     /// extern crate serenity;
     /// #
+    /// # #[cfg(feature = "http")]
+    /// # mod inner {
     /// # use std::error::Error;
     /// #
-    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// # pub fn try_main() -> Result<(), Box<Error>> {
     /// #
     /// use parking_lot::Mutex;
     /// use serenity::gateway::Shard;
@@ -126,10 +130,15 @@ impl Shard {
     /// // their variants
     /// #     Ok(())
     /// # }
+    /// # }
     /// #
+    /// # #[cfg(feature = "http")]
     /// # fn main() {
-    /// #     try_main().unwrap();
+    /// #     inner::try_main().unwrap();
     /// # }
+    /// #
+    /// # #[cfg(not(feature = "http"))]
+    /// # fn main() {}
     /// ```
     pub fn new(
         ws_url: Arc<Mutex<String>>,
@@ -140,7 +149,7 @@ impl Shard {
 
         let _ = set_client_timeout(&mut client);
 
-        let current_presence = (None, OnlineStatus::Online);
+        let current_presence = (Vec::new(), OnlineStatus::Online);
         let heartbeat_instants = (None, None);
         let heartbeat_interval = None;
         let last_heartbeat_acknowledged = true;
@@ -256,11 +265,57 @@ impl Shard {
         self.seq
     }
 
+    /// Records the sequence of a dispatch that was received, but deliberately
+    /// not deserialized, so that heartbeats and resumes stay in sync.
+    pub(crate) fn skip_dispatch(&mut self, seq: u64) {
+        if seq > self.seq {
+            self.seq = seq;
+        }
+    }
+
     #[inline]
     pub fn session_id(&self) -> Option<&String> {
         self.session_id.as_ref()
     }
 
+    /// Retrieves the information needed to resume the shard's session
+    /// elsewhere, if a session has been started.
+    ///
+    /// Refer to [`ShardSession`] for more information.
+    ///
+    /// [`ShardSession`]: struct.ShardSession.html
+    pub fn session(&self) -> Option<ShardSession> {
+        self.session_id.as_ref().map(|session_id| ShardSession {
+            session_id: session_id.clone(),
+            seq: self.seq,
+            shard_info: self.shard_info,
+        })
+    }
+
+    /// Restores a session retrieved through [`session`], so that the shard
+    /// resumes it instead of identifying once the gateway greets it.
+    ///
+    /// If the session can no longer be resumed, the shard identifies anew.
+    ///
+    /// **Note**: This has no effect once the shard identified, and if the
+    /// session belongs to a different shard.
+    ///
+    /// [`session`]: #method.session
+    pub fn restore_session(&mut self, session: ShardSession) {
+        if session.shard_info != self.shard_info || self.stage != ConnectionStage::Handshake {
+            warn!(
+                "[Shard {:?}] Not restoring session of shard {:?}",
+                self.shard_info,
+                session.shard_info,
+            );
+
+            return;
+        }
+
+        self.session_id = Some(session.session_id);
+        self.seq = session.seq;
+    }
+
     /// ```rust,no_run
     /// # #[cfg(feature = "model")]
     /// # fn main() {
@@ -282,7 +337,18 @@ impl Shard {
     /// ```
     #[inline]
     pub fn set_game(&mut self, game: Option<Game>) {
-        self.current_presence.0 = game;
+        self.set_activities(game.map(ActivityData::from).into_iter().collect());
+    }
+
+    /// Sets the activities displayed in the presence, replacing all current
+    /// ones. Pass an empty `Vec` to clear them.
+    ///
+    /// Refer to [`ActivityData`] for the kinds of activities.
+    ///
+    /// [`ActivityData`]: ../../model/gateway/struct.ActivityData.html
+    #[inline]
+    pub fn set_activities(&mut self, activities: Vec<ActivityData>) {
+        self.current_presence.0 = activities;
     }
 
     #[inline]
@@ -441,7 +507,13 @@ impl Shard {
                 }
 
                 Ok(Some(if self.stage == ConnectionStage::Handshake {
-                    ShardAction::Identify
+                    // A session restored from elsewhere is resumed rather
+                    // than identifying anew.
+                    if self.session_id.is_some() {
+                        ShardAction::Reconnect(ReconnectType::Resume)
+                    } else {
+                        ShardAction::Identify
+                    }
                 } else {
                     debug!("[Shard {:?}] Received late Hello; autoreconnecting",
                            self.shard_info);
@@ -757,7 +829,11 @@ impl Shard {
     // - the time that the last heartbeat sent as being now
     // - the `stage` to `Identifying`
     pub fn identify(&mut self) -> Result<()> {
-        self.client.send_identify(&self.shard_info, &self.token.lock())?;
+        self.client.send_identify(
+            &self.shard_info,
+            &self.token.lock(),
+            &self.current_presence,
+        )?;
 
         self.heartbeat_instants.0 = Some(Instant::now());
         self.stage = ConnectionStage::Identifying;
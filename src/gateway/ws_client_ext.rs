@@ -1,9 +1,9 @@
-use chrono::Utc;
 use constants::{self, OpCode};
 use gateway::{CurrentPresence, WsClient};
 use internal::prelude::*;
 use internal::ws_impl::SenderExt;
 use model::id::GuildId;
+use model::Timestamp;
 use std::env::consts;
 
 pub trait WebSocketGatewayClientExt {
@@ -18,8 +18,12 @@ pub trait WebSocketGatewayClientExt {
     fn send_heartbeat(&mut self, shard_info: &[u64; 2], seq: Option<u64>)
         -> Result<()>;
 
-    fn send_identify(&mut self, shard_info: &[u64; 2], token: &str)
-        -> Result<()>;
+    fn send_identify(
+        &mut self,
+        shard_info: &[u64; 2],
+        token: &str,
+        current_presence: &CurrentPresence,
+    ) -> Result<()>;
 
     fn send_presence_update(
         &mut self,
@@ -66,8 +70,12 @@ impl WebSocketGatewayClientExt for WsClient {
         })).map_err(From::from)
     }
 
-    fn send_identify(&mut self, shard_info: &[u64; 2], token: &str)
-        -> Result<()> {
+    fn send_identify(
+        &mut self,
+        shard_info: &[u64; 2],
+        token: &str,
+        current_presence: &CurrentPresence,
+    ) -> Result<()> {
         debug!("[Shard {:?}] Identifying", shard_info);
 
         self.send_json(&json!({
@@ -75,6 +83,7 @@ impl WebSocketGatewayClientExt for WsClient {
             "d": {
                 "compression": true,
                 "large_threshold": constants::LARGE_THRESHOLD,
+                "presence": presence_json(current_presence),
                 "shard": shard_info,
                 "token": token,
                 "v": constants::GATEWAY_VERSION,
@@ -92,23 +101,11 @@ impl WebSocketGatewayClientExt for WsClient {
         shard_info: &[u64; 2],
         current_presence: &CurrentPresence,
     ) -> Result<()> {
-        let &(ref game, ref status) = current_presence;
-        let now = Utc::now().timestamp() as u64;
-
         debug!("[Shard {:?}] Sending presence update", shard_info);
 
         self.send_json(&json!({
             "op": OpCode::StatusUpdate.num(),
-            "d": {
-                "afk": false,
-                "since": now,
-                "status": status.name(),
-                "game": game.as_ref().map(|x| json!({
-                    "name": x.name,
-                    "type": x.kind,
-                    "url": x.url,
-                })),
-            },
+            "d": presence_json(current_presence),
         }))
     }
 
@@ -131,3 +128,46 @@ impl WebSocketGatewayClientExt for WsClient {
         })).map_err(From::from)
     }
 }
+
+/// Creates the data of a presence update, also sent when identifying.
+///
+/// The first activity is sent as the `game` as well, for it is the only one
+/// that older gateway versions display.
+fn presence_json(current_presence: &CurrentPresence) -> Value {
+    let &(ref activities, ref status) = current_presence;
+    let now = Timestamp::now().unix_timestamp() as u64;
+
+    json!({
+        "afk": false,
+        "since": now,
+        "status": status.name(),
+        "game": activities.first(),
+        "activities": activities,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use model::gateway::ActivityData;
+    use model::user::OnlineStatus;
+    use super::presence_json;
+
+    #[test]
+    fn presence_with_activities() {
+        let activities = vec![ActivityData::watching("a movie"), ActivityData::custom("Movie night")];
+        let presence = presence_json(&(activities, OnlineStatus::Idle));
+
+        assert_eq!(presence["status"], "idle");
+        assert_eq!(presence["game"]["name"], "a movie");
+        assert_eq!(presence["activities"].as_array().map(Vec::len), Some(2));
+        assert_eq!(presence["activities"][1]["state"], "Movie night");
+    }
+
+    #[test]
+    fn presence_without_activities() {
+        let presence = presence_json(&(Vec::new(), OnlineStatus::Online));
+
+        assert!(presence["game"].is_null());
+        assert_eq!(presence["activities"], json!([]));
+    }
+}
@@ -0,0 +1,172 @@
+//! Downloading of assets from Discord's CDN, such as avatars, emojis, and
+//! attachments.
+//!
+//! Downloads share the client the library makes requests to the API with,
+//! which gives up once the CDN stopped sending data for 30 seconds.
+//!
+//! # Examples
+//!
+//! Saving a user's avatar to a file:
+//!
+//! ```rust,no_run
+//! # use serenity::model::user::User;
+//! #
+//! # fn save(user: &User) -> serenity::Result<()> {
+//! use serenity::http::cdn;
+//! use std::fs::File;
+//!
+//! if let Some(url) = user.avatar_url() {
+//!     let mut file = File::create("avatar")?;
+//!
+//!     cdn::download_to(&url, &mut file)?;
+//! }
+//! #     Ok(())
+//! # }
+//! ```
+
+use hyper::{client::Response, header::ContentLength, Url};
+use internal::prelude::*;
+use std::io::{self, Read, Write};
+use super::{
+    raw::unsuccessful_request,
+    routing::Route,
+    HttpError,
+    StatusClass,
+    CLIENT
+};
+
+/// Downloads the asset at the given URL, returning its contents.
+///
+/// # Errors
+///
+/// Returns an [`Error::Url`] if the URL is invalid or not an HTTP(S) URL, and
+/// an [`HttpError::UnsuccessfulRequest`] if the CDN responded with an error,
+/// such as when the asset does not exist.
+///
+/// [`Error::Url`]: ../../enum.Error.html#variant.Url
+/// [`HttpError::UnsuccessfulRequest`]: ../enum.HttpError.html#variant.UnsuccessfulRequest
+pub fn download(url: &str) -> Result<Vec<u8>> {
+    let mut response = get(url)?;
+
+    let mut bytes = Vec::new();
+    response.read_to_end(&mut bytes)?;
+
+    Ok(bytes)
+}
+
+/// Downloads the asset at the given URL, like [`download`], but fails instead
+/// of reading more than `max_size` bytes.
+///
+/// This is useful for assets given by users, such as attachments.
+///
+/// # Errors
+///
+/// Returns the same errors as [`download`], as well as an
+/// [`HttpError::TooLarge`] if the asset is larger than `max_size` bytes.
+///
+/// [`download`]: fn.download.html
+/// [`HttpError::TooLarge`]: ../enum.HttpError.html#variant.TooLarge
+pub fn download_limited(url: &str, max_size: u64) -> Result<Vec<u8>> {
+    let response = get(url)?;
+    let content_length = response.headers.get::<ContentLength>().map(|length| length.0);
+
+    read_limited(response, content_length, max_size)
+}
+
+/// Downloads the asset at the given URL, streaming its contents into the
+/// `writer` rather than keeping them in memory.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// Returns the same errors as [`download`], as well as an [`Error::Io`] if
+/// writing failed.
+///
+/// [`download`]: fn.download.html
+/// [`Error::Io`]: ../../enum.Error.html#variant.Io
+pub fn download_to<W: Write + ?Sized>(url: &str, writer: &mut W) -> Result<u64> {
+    let mut response = get(url)?;
+
+    io::copy(&mut response, writer).map_err(From::from)
+}
+
+fn get(url: &str) -> Result<Response> {
+    let response = CLIENT.get(parse_url(url)?).send()?;
+
+    if response.status.class() != StatusClass::Success {
+        return Err(unsuccessful_request(response, Route::None, url.to_string()));
+    }
+
+    Ok(response)
+}
+
+/// Parses the URL of an asset, which has to be an HTTP(S) URL.
+fn parse_url(url: &str) -> Result<Url> {
+    match Url::parse(url) {
+        Ok(ref parsed) if parsed.scheme() != "https" && parsed.scheme() != "http" => {
+            Err(Error::Url(url.to_string()))
+        },
+        Ok(parsed) => Ok(parsed),
+        Err(_) => Err(Error::Url(url.to_string())),
+    }
+}
+
+/// Reads at most `max_size` bytes, failing early if the `content_length`
+/// already exceeds it.
+fn read_limited<R: Read>(reader: R, content_length: Option<u64>, max_size: u64) -> Result<Vec<u8>> {
+    if let Some(length) = content_length.filter(|length| *length > max_size) {
+        return Err(Error::Http(HttpError::TooLarge(length)));
+    }
+
+    // Read one byte more than allowed to tell whether there would be more.
+    let mut bytes = Vec::new();
+    let read = reader.take(max_size + 1).read_to_end(&mut bytes)? as u64;
+
+    if read > max_size {
+        return Err(Error::Http(HttpError::TooLarge(max_size)));
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use super::{parse_url, read_limited, Error, HttpError};
+
+    #[test]
+    fn urls() {
+        assert!(parse_url("https://cdn.discordapp.com/avatars/1/a.png").is_ok());
+        assert!(parse_url("http://media.discordapp.net/attachments/1/2/a.png").is_ok());
+
+        for url in &["", "avatar.png", "cdn.discordapp.com/a.png", "file:///etc/passwd", "ftp://cdn.discordapp.com/a.png"] {
+            match parse_url(url) {
+                Err(Error::Url(ref given)) => assert_eq!(given, url),
+                _ => panic!("{} was accepted", url),
+            }
+        }
+    }
+
+    #[test]
+    fn size_limit() {
+        let data = vec![7; 100];
+
+        assert_eq!(read_limited(Cursor::new(&data), None, 100).unwrap(), data);
+        assert_eq!(read_limited(Cursor::new(&data), Some(100), 200).unwrap(), data);
+
+        match read_limited(Cursor::new(&data), None, 99) {
+            Err(Error::Http(HttpError::TooLarge(99))) => {},
+            other => panic!("read {:?}", other.map(|bytes| bytes.len())),
+        }
+    }
+
+    #[test]
+    fn size_limit_by_content_length() {
+        // The declared length is rejected before anything is read.
+        match read_limited(Cursor::new(&[][..]), Some(1_000), 100) {
+            Err(Error::Http(HttpError::TooLarge(1_000))) => {},
+            other => panic!("read {:?}", other.map(|bytes| bytes.len())),
+        }
+    }
+}
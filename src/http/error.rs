@@ -1,27 +1,162 @@
-use hyper::client::Response;
+use hyper::{
+    client::Response,
+    status::StatusCode
+};
+use serde_json::{self, Value};
 use std::{
     error::Error as StdError,
     fmt::{
         Display,
         Formatter,
         Result as FmtResult
-    }
+    },
+    io::Read,
+    str,
+    time::Duration
 };
+use super::routing::Route;
+
+/// The error code Discord returns when the current user lacks a permission
+/// required by a request.
+const MISSING_PERMISSIONS: isize = 50_013;
+
+/// The error code Discord returns when pinning a message in a channel that
+/// has the maximum number of pinned messages.
+const MAXIMUM_PINS: isize = 30_003;
+
+/// The error Discord describes an unsuccessful request with in the body of
+/// the response.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct DiscordJsonError {
+    /// The [error code] of the error, or `0` if the response did not contain
+    /// one.
+    ///
+    /// [error code]: https://discordapp.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+    #[serde(default)]
+    pub code: isize,
+    /// A description of the error, such as `Missing Permissions`.
+    #[serde(default)]
+    pub message: String,
+    /// The errors of the individual fields of the request body, as nested by
+    /// Discord, if the body was invalid.
+    #[serde(default)]
+    pub errors: Option<Value>,
+}
+
+impl DiscordJsonError {
+    /// Parses the body of an unsuccessful response, which is not necessarily
+    /// JSON, e.g. when a proxy responded in place of Discord.
+    fn from_body(body: &str) -> Self { serde_json::from_str(body).unwrap_or_default() }
+}
+
+/// Information about a response with a non-successful status code.
+#[derive(Clone, Debug)]
+pub struct ErrorResponse {
+    /// The status code of the response.
+    pub status_code: StatusCode,
+    /// The route of the request, for ratelimiting purposes.
+    pub route: Route,
+    /// The URL the request was made to.
+    pub url: String,
+    /// The error given by Discord in the body of the response.
+    pub error: DiscordJsonError,
+    /// How long to wait before retrying the request, if it was ratelimited.
+    pub retry_after: Option<Duration>,
+}
+
+impl ErrorResponse {
+    /// Reads the error from an unsuccessful response to a request made to
+    /// `url`.
+    pub(crate) fn from_response(mut response: Response, route: Route, url: String) -> Self {
+        let mut body = String::new();
+        let _ = response.read_to_string(&mut body);
+
+        let retry_after = response.headers
+            .get_raw("retry-after")
+            .and_then(|header| parse_retry_after(&header[0]));
+
+        ErrorResponse {
+            status_code: response.status,
+            route,
+            url,
+            error: DiscordJsonError::from_body(&body),
+            retry_after,
+        }
+    }
+}
+
+/// Parses the `Retry-After` header, given in milliseconds.
+fn parse_retry_after(header: &[u8]) -> Option<Duration> {
+    str::from_utf8(header)
+        .ok()
+        .and_then(|header| header.parse::<u64>().ok())
+        .map(Duration::from_millis)
+}
 
 #[derive(Debug)]
 pub enum Error {
     /// When a non-successful status code was received for a request.
-    UnsuccessfulRequest(Response),
+    UnsuccessfulRequest(ErrorResponse),
     /// When the decoding of a ratelimit header could not be properly decoded
     /// into an `i64`.
     RateLimitI64,
     /// When the decoding of a ratelimit header could not be properly decoded
     /// from UTF-8.
     RateLimitUtf8,
+    /// When a download was larger than the maximum size allowed, which is
+    /// given.
+    TooLarge(u64),
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+impl Error {
+    /// Retrieves the status code of the response, if the request was
+    /// unsuccessful.
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match *self {
+            Error::UnsuccessfulRequest(ref response) => Some(response.status_code),
+            _ => None,
+        }
+    }
+
+    /// Whether the requested resource does not exist, such as when retrieving
+    /// a deleted message.
+    pub fn is_not_found(&self) -> bool { self.status_code() == Some(StatusCode::NotFound) }
+
+    /// Whether the request failed because the current user lacks a required
+    /// permission.
+    pub fn is_missing_permissions(&self) -> bool {
+        match *self {
+            Error::UnsuccessfulRequest(ref response) => {
+                response.error.code == MISSING_PERMISSIONS
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether the request failed because the channel already has the maximum
+    /// number of pinned messages.
+    pub fn is_pin_limit_reached(&self) -> bool {
+        match *self {
+            Error::UnsuccessfulRequest(ref response) => response.error.code == MAXIMUM_PINS,
+            _ => false,
+        }
+    }
 }
 
 impl Display for Error {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            Error::UnsuccessfulRequest(ref response) if !response.error.message.is_empty() => {
+                write!(f, "{} ({}): {}", self.description(), response.status_code, response.error.message)
+            },
+            Error::UnsuccessfulRequest(ref response) => {
+                write!(f, "{} ({})", self.description(), response.status_code)
+            },
+            _ => f.write_str(self.description()),
+        }
+    }
 }
 
 impl StdError for Error {
@@ -32,6 +167,80 @@ impl StdError for Error {
             },
             Error::RateLimitI64 => "Error decoding a header into an i64",
             Error::RateLimitUtf8 => "Error decoding a header from UTF-8",
+            Error::TooLarge(_) => "The download exceeded the maximum size",
+            Error::__Nonexhaustive => "An HTTP error occurred",
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hyper::status::StatusCode;
+    use std::time::Duration;
+    use super::super::routing::Route;
+    use super::{parse_retry_after, DiscordJsonError, Error, ErrorResponse};
+
+    fn unsuccessful(status_code: StatusCode, code: isize) -> Error {
+        Error::UnsuccessfulRequest(ErrorResponse {
+            status_code,
+            route: Route::ChannelsIdPinsMessageId(1),
+            url: "https://discordapp.com/api/v6/channels/1/pins/2".to_string(),
+            error: DiscordJsonError {
+                code,
+                ..DiscordJsonError::default()
+            },
+            retry_after: None,
+        })
+    }
+
+    #[test]
+    fn code_and_message() {
+        let error = DiscordJsonError::from_body(include_str!("../../tests/resources/http_error_1.json"));
+
+        assert_eq!(error.code, 50_013);
+        assert_eq!(error.message, "Missing Permissions");
+        assert!(error.errors.is_none());
+    }
+
+    #[test]
+    fn message_without_code() {
+        let error = DiscordJsonError::from_body(include_str!("../../tests/resources/http_error_2.json"));
+
+        assert_eq!(error.code, 0);
+        assert_eq!(error.message, "You are being rate limited.");
+    }
+
+    #[test]
+    fn nested_errors() {
+        let error = DiscordJsonError::from_body(include_str!("../../tests/resources/http_error_3.json"));
+
+        assert_eq!(error.code, 50_035);
+        assert_eq!(error.message, "Invalid Form Body");
+
+        let errors = error.errors.unwrap();
+        let nested = &errors["embed"]["fields"]["0"]["name"]["_errors"][0];
+        assert_eq!(nested["code"], "BASE_TYPE_REQUIRED");
+    }
+
+    #[test]
+    fn non_json_body() {
+        let error = DiscordJsonError::from_body("<html><body>502 Bad Gateway</body></html>");
+
+        assert_eq!(error, DiscordJsonError::default());
+        assert_eq!(DiscordJsonError::from_body(""), DiscordJsonError::default());
+    }
+
+    #[test]
+    fn retry_after() {
+        assert_eq!(parse_retry_after(b"1500"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_retry_after(b"soon"), None);
+        assert_eq!(parse_retry_after(&[0xff]), None);
+    }
+
+    #[test]
+    fn pin_limit_reached() {
+        assert!(unsuccessful(StatusCode::BadRequest, 30_003).is_pin_limit_reached());
+        assert!(!unsuccessful(StatusCode::Forbidden, 50_013).is_pin_limit_reached());
+        assert!(!Error::RateLimitI64.is_pin_limit_reached());
+    }
+}
@@ -23,6 +23,7 @@
 //! [`Client`]: ../client/struct.Client.html
 //! [model]: ../model/index.html
 
+pub mod cdn;
 pub mod ratelimiting;
 pub mod raw;
 pub mod request;
@@ -31,7 +32,7 @@ pub mod routing;
 mod error;
 
 pub use hyper::status::{StatusClass, StatusCode};
-pub use self::error::Error as HttpError;
+pub use self::error::{DiscordJsonError, Error as HttpError, ErrorResponse};
 pub use self::raw::*;
 
 use hyper::{
@@ -46,16 +47,25 @@ use self::{request::Request};
 use std::{
     default::Default,
     fs::File,
+    io::Read,
     path::{Path, PathBuf},
-    sync::Arc
+    sync::Arc,
+    time::Duration
 };
 
+/// How many seconds to wait for Discord to respond or receive more data.
+const TIMEOUT_SECS: u64 = 30;
+
 lazy_static! {
     static ref CLIENT: HyperClient = {
         let tc = NativeTlsClient::new().expect("Unable to make http client");
         let connector = HttpsConnector::new(tc);
 
-        HyperClient::with_connector(connector)
+        let mut client = HyperClient::with_connector(connector);
+        client.set_read_timeout(Some(Duration::from_secs(TIMEOUT_SECS)));
+        client.set_write_timeout(Some(Duration::from_secs(TIMEOUT_SECS)));
+
+        client
     };
 }
 
@@ -92,20 +102,37 @@ lazy_static! {
     static ref TOKEN: Arc<Mutex<String>> = Arc::new(Mutex::new(String::default()));
 }
 
-/// Enum that allows a user to pass a `Path` or a `File` type to `send_files`
+/// Enum that allows a user to pass a `Path`, a `File`, bytes, a reader, or a
+/// URL to `send_files`
 pub enum AttachmentType<'a> {
     /// Indicates that the `AttachmentType` is a byte slice with a filename.
     Bytes((&'a [u8], &'a str)),
     /// Indicates that the `AttachmentType` is a `File`
     File((&'a File, &'a str)),
+    /// Indicates that the `AttachmentType` is an owned byte buffer with a
+    /// filename, such as a generated image.
+    OwnedBytes((Vec<u8>, String)),
     /// Indicates that the `AttachmentType` is a `Path`
     Path(&'a Path),
+    /// Indicates that the `AttachmentType` is read from a reader, with a
+    /// filename.
+    Reader((Box<Read + 'a>, &'a str)),
+    /// Indicates that the `AttachmentType` is the file at a URL, which is
+    /// downloaded before sending. The file is named after the last segment of
+    /// the URL's path.
+    Url(&'a str),
 }
 
 impl<'a> From<(&'a [u8], &'a str)> for AttachmentType<'a> {
     fn from(params: (&'a [u8], &'a str)) -> AttachmentType { AttachmentType::Bytes(params) }
 }
 
+impl<'a> From<(Vec<u8>, &'a str)> for AttachmentType<'a> {
+    fn from(params: (Vec<u8>, &'a str)) -> AttachmentType<'a> {
+        AttachmentType::OwnedBytes((params.0, params.1.to_string()))
+    }
+}
+
 impl<'a> From<&'a str> for AttachmentType<'a> {
     fn from(s: &'a str) -> AttachmentType { AttachmentType::Path(Path::new(s)) }
 }
@@ -150,5 +177,11 @@ mod test {
             AttachmentType::Path(_) => true,
             _ => false,
         });
+        assert!(match AttachmentType::from((vec![1, 2, 3], "bytes.bin")) {
+            AttachmentType::OwnedBytes((ref bytes, ref filename)) => {
+                bytes == &[1, 2, 3] && filename == "bytes.bin"
+            },
+            _ => false,
+        });
     }
 }
@@ -42,16 +42,16 @@
 
 pub use super::routing::Route;
 
-use chrono::{DateTime, Utc};
 use hyper::client::Response;
-use hyper::header::Headers;
+use hyper::header::{Headers, HttpDate};
 use hyper::status::StatusCode;
 use internal::prelude::*;
+use model::Timestamp;
 use parking_lot::Mutex;
 use std::{
     collections::HashMap,
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
     str,
     thread,
     i64
@@ -102,6 +102,8 @@ lazy_static! {
     pub static ref ROUTES: Arc<Mutex<HashMap<Route, Arc<Mutex<RateLimit>>>>> = {
         Arc::new(Mutex::new(HashMap::default()))
     };
+    /// When the global ratelimit that was hit last resets.
+    static ref GLOBAL_RESET: Mutex<Option<Instant>> = Mutex::new(None);
 }
 
 pub(super) fn perform(req: Request) -> Result<Response> {
@@ -172,12 +174,16 @@ pub(super) fn perform(req: Request) -> Result<Response> {
             return Ok(response);
         } else {
             let redo = if response.headers.get_raw("x-ratelimit-global").is_some() {
-                let _ = GLOBAL.lock();
+                // Hold the lock while waiting, blocking all other requests.
+                let _global = GLOBAL.lock();
 
                 Ok(
                     if let Some(retry_after) = parse_header(&response.headers, "retry-after")? {
                         debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
-                        thread::sleep(Duration::from_millis(retry_after as u64));
+
+                        let retry_after = Duration::from_millis(retry_after as u64);
+                        *GLOBAL_RESET.lock() = Some(Instant::now() + retry_after);
+                        thread::sleep(retry_after);
 
                         true
                     } else {
@@ -221,13 +227,35 @@ pub struct RateLimit {
 }
 
 impl RateLimit {
+    /// Returns the number of requests that can currently be made, taking into
+    /// account whether the period of time has reset since the last request.
+    pub fn current_remaining(&self) -> i64 {
+        match self.reset_after() {
+            Some(_) => self.remaining,
+            None => self.limit,
+        }
+    }
+
+    /// Returns the time left until the period of time resets, or `None` if it
+    /// already has.
+    pub fn reset_after(&self) -> Option<Duration> {
+        let offset = unsafe { OFFSET }.unwrap_or(0);
+        let current_time = Timestamp::now().unix_timestamp() - offset;
+
+        if current_time >= self.reset {
+            None
+        } else {
+            Some(Duration::from_secs((self.reset - current_time) as u64))
+        }
+    }
+
     pub(crate) fn pre_hook(&mut self, route: &Route) {
         if self.limit == 0 {
             return;
         }
 
         let offset = unsafe { OFFSET }.unwrap_or(0);
-        let now = Utc::now().timestamp();
+        let now = Timestamp::now().unix_timestamp();
         let current_time = now - offset;
 
         // The reset was in the past, so we're probably good.
@@ -281,6 +309,97 @@ impl RateLimit {
     }
 }
 
+/// Returns a copy of the ratelimit information of a [`Route`], if a request
+/// has been made to it.
+///
+/// This allows checking whether a number of requests can be made without
+/// waiting, such as before assigning a role to many members.
+///
+/// Until Discord responded to the first request, the values are `i64::MAX`.
+///
+/// **Note**: This blocks while a request to the route is made, including while
+/// it waits for the route's ratelimit to reset.
+///
+/// # Examples
+///
+/// Estimate how long sending many messages to a channel takes:
+///
+/// ```rust,no_run
+/// use serenity::http::ratelimiting::{self, Route};
+///
+/// if let Some(ratelimit) = ratelimiting::route_ratelimit(&Route::ChannelsIdMessages(7)) {
+///     if ratelimit.current_remaining() < 50 {
+///         println!("Waiting for the ratelimit to reset in {:?}", ratelimit.reset_after());
+///     }
+/// }
+/// ```
+///
+/// [`Route`]: ../routing/enum.Route.html
+pub fn route_ratelimit(route: &Route) -> Option<RateLimit> {
+    let bucket = ROUTES.lock().get(route).map(Arc::clone)?;
+    let ratelimit = bucket.lock().clone();
+
+    Some(ratelimit)
+}
+
+/// Returns a copy of the ratelimit information of a [`Route`], like
+/// [`route_ratelimit`], but without waiting for a request to the route that is
+/// in progress.
+///
+/// Returns `None` if the ratelimit is unknown, as no request has been made to
+/// the route yet, or one is in progress and may be waiting for the ratelimit
+/// to reset.
+///
+/// [`Route`]: ../routing/enum.Route.html
+/// [`route_ratelimit`]: fn.route_ratelimit.html
+pub fn try_route_ratelimit(route: &Route) -> Option<RateLimit> {
+    let bucket = ROUTES.lock().get(route).map(Arc::clone)?;
+    let ratelimit = bucket.try_lock()?.clone();
+
+    Some(ratelimit)
+}
+
+/// Returns a copy of the ratelimit information of every [`Route`] that a
+/// request has been made to.
+///
+/// Refer to [`route_ratelimit`] for more information.
+///
+/// [`Route`]: ../routing/enum.Route.html
+/// [`route_ratelimit`]: fn.route_ratelimit.html
+pub fn route_ratelimits() -> HashMap<Route, RateLimit> {
+    let buckets = ROUTES
+        .lock()
+        .iter()
+        .map(|(route, bucket)| (*route, Arc::clone(bucket)))
+        .collect::<Vec<_>>();
+
+    buckets
+        .into_iter()
+        .map(|(route, bucket)| (route, bucket.lock().clone()))
+        .collect()
+}
+
+/// Returns whether requests are currently blocked by the [`GLOBAL`]
+/// ratelimit.
+///
+/// [`GLOBAL`]: struct.GLOBAL.html
+pub fn is_global_ratelimited() -> bool { global_reset_after().is_some() }
+
+/// Returns the time left until the [`GLOBAL`] ratelimit resets, or `None` if
+/// requests are not blocked by it.
+///
+/// [`GLOBAL`]: struct.GLOBAL.html
+pub fn global_reset_after() -> Option<Duration> {
+    reset_after(*GLOBAL_RESET.lock(), Instant::now())
+}
+
+fn reset_after(reset: Option<Instant>, now: Instant) -> Option<Duration> {
+    match reset {
+        Some(reset) if reset > now => Some(reset - now),
+        _ => None,
+    }
+}
+
 /// The calculated offset of the time difference between Discord and the client
 /// in seconds.
 ///
@@ -304,7 +423,7 @@ pub fn offset() -> Option<i64> {
 
 fn calculate_offset(header: Option<&[Vec<u8>]>) {
     // Get the current time as soon as possible.
-    let now = Utc::now().timestamp();
+    let now = Timestamp::now().unix_timestamp();
 
     // First get the `Date` header's value and parse it as UTF8.
     let header = header
@@ -312,14 +431,10 @@ fn calculate_offset(header: Option<&[Vec<u8>]>) {
         .and_then(|x| str::from_utf8(x).ok());
 
     if let Some(date) = header {
-        // Replace the `GMT` timezone with an offset, and then parse it
-        // into a chrono DateTime. If it parses correctly, calculate the
+        // Parse it as an HTTP date. If it parses correctly, calculate the
         // diff and then set it as the offset.
-        let s = date.replace("GMT", "+0000");
-        let parsed = DateTime::parse_from_str(&s, "%a, %d %b %Y %T %z");
-
-        if let Ok(parsed) = parsed {
-            let offset = parsed.timestamp();
+        if let Ok(parsed) = date.parse::<HttpDate>() {
+            let offset = parsed.0.to_timespec().sec;
 
             let diff = offset - now;
 
@@ -343,3 +458,20 @@ fn parse_header(headers: &Headers, header: &str) -> Result<Option<i64>> {
             })
     })
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+    use super::reset_after;
+
+    #[test]
+    fn global_reset_after() {
+        let now = Instant::now();
+        let reset = now + Duration::from_millis(1500);
+
+        assert_eq!(reset_after(Some(reset), now), Some(Duration::from_millis(1500)));
+        assert_eq!(reset_after(Some(reset), reset), None);
+        assert_eq!(reset_after(Some(now), reset), None);
+        assert_eq!(reset_after(None, now), None);
+    }
+}
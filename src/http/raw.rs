@@ -7,22 +7,25 @@ use hyper::{
     header::{ContentType, Headers},
     method::Method,
     mime::{Mime, SubLevel, TopLevel},
-    net::HttpsConnector,
+    net::{HttpsConnector, Streaming},
     header,
     Error as HyperError,
     Result as HyperResult,
     Url
 };
 use hyper_native_tls::NativeTlsClient;
+use internal::json;
 use internal::prelude::*;
 use model::prelude::*;
 use multipart::client::Multipart;
 use super::{
+    CLIENT,
     TOKEN,
     ratelimiting,
     request::Request,
-    routing::RouteInfo,
+    routing::{Route, RouteInfo},
     AttachmentType,
+    ErrorResponse,
     GuildPagination,
     HttpError,
     StatusClass,
@@ -76,6 +79,20 @@ pub fn add_group_recipient(group_id: u64, user_id: u64) -> Result<()> {
     })
 }
 
+/// Lists a guild under a subcategory of the guild discovery.
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn add_guild_discovery_category(guild_id: u64, category_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::AddGuildDiscoveryCategory { category_id, guild_id },
+    })
+}
+
+
 /// Adds a single [`Role`] to a [`Member`] in a [`Guild`].
 ///
 /// **Note**: Requires the [Manage Roles] permission and respect of role
@@ -93,6 +110,18 @@ pub fn add_member_role(guild_id: u64, user_id: u64, role_id: u64) -> Result<()>
     })
 }
 
+/// Adds a member to a thread.
+///
+/// **Note**: Requires the ability to send messages in the thread, and the
+/// thread must not be archived.
+pub fn add_thread_member(channel_id: u64, user_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::AddThreadMember { channel_id, user_id },
+    })
+}
+
 /// Bans a [`User`] from a [`Guild`], removing their messages sent in the last
 /// X number of days.
 ///
@@ -177,6 +206,25 @@ pub fn broadcast_typing(channel_id: u64) -> Result<()> {
     })
 }
 
+/// Creates an auto moderation rule in a [`Guild`].
+///
+/// Refer to Discord's [docs] for the fields the `map` can contain.
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [docs]: https://discord.com/developers/docs/resources/auto-moderation#create-auto-moderation-rule
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn create_automod_rule(guild_id: u64, map: &JsonMap) -> Result<AutoModRule> {
+    let body = serde_json::to_vec(map)?;
+
+    fire(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::CreateAutoModRule { guild_id },
+    })
+}
+
 /// Creates a [`GuildChannel`] in the [`Guild`] given its Id.
 ///
 /// Refer to the Discord's [docs] for information on what fields this requires.
@@ -213,6 +261,29 @@ pub fn create_emoji(guild_id: u64, map: &Value) -> Result<Emoji> {
     })
 }
 
+/// Sends a follow-up message to an [`Interaction`], after it has been
+/// responded to.
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+pub fn create_followup_message(application_id: u64, token: &str, map: &Value) -> Result<Message> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::CreateFollowupMessage { application_id, token },
+    })
+}
+
+/// Creates a global application command, or overwrites the one of the same
+/// name.
+pub fn create_global_application_command(application_id: u64, map: &Value)
+    -> Result<ApplicationCommand> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::CreateGlobalApplicationCommand { application_id },
+    })
+}
+
 /// Creates a guild with the data provided.
 ///
 /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`]
@@ -257,6 +328,17 @@ pub fn create_guild(map: &Value) -> Result<PartialGuild> {
     })
 }
 
+/// Creates an application command in a guild, or overwrites the one of the
+/// same name.
+pub fn create_guild_application_command(application_id: u64, guild_id: u64, map: &Value)
+    -> Result<ApplicationCommand> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::CreateGuildApplicationCommand { application_id, guild_id },
+    })
+}
+
 /// Creates an [`Integration`] for a [`Guild`].
 ///
 /// Refer to Discord's [docs] for field information.
@@ -275,6 +357,18 @@ pub fn create_guild_integration(guild_id: u64, integration_id: u64, map: &Value)
     })
 }
 
+/// Responds to an [`Interaction`], which has to be done within 3 seconds of
+/// receiving it.
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+pub fn create_interaction_response(interaction_id: u64, token: &str, map: &Value) -> Result<()> {
+    wind(204, Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::CreateInteractionResponse { interaction_id, token },
+    })
+}
+
 /// Creates a [`RichInvite`] for the given [channel][`GuildChannel`].
 ///
 /// Refer to Discord's [docs] for field information.
@@ -346,6 +440,48 @@ pub fn create_role(guild_id: u64, map: &JsonMap) -> Result<Role> {
     })
 }
 
+/// Starts a stage in a stage channel.
+///
+/// Refer to Discord's [docs] for the fields the `map` can contain.
+///
+/// [docs]: https://discord.com/developers/docs/resources/stage-instance#create-stage-instance
+pub fn create_stage_instance(map: &Value) -> Result<StageInstance> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::CreateStageInstance,
+    })
+}
+
+/// Uploads a sticker to a [`Guild`], with the image of the sticker as the
+/// `file`.
+///
+/// The `map` is sent as the text fields of the request. Refer to Discord's
+/// [docs] for the fields it can contain.
+///
+/// **Note**: Requires the [Manage Emojis] permission.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [docs]: https://discord.com/developers/docs/resources/sticker#create-guild-sticker
+/// [Manage Emojis]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+pub fn create_sticker<'a, T>(guild_id: u64, map: &JsonMap, file: T) -> Result<Sticker>
+    where T: Into<AttachmentType<'a>> {
+    let uri = api!("/guilds/{}/stickers", guild_id);
+    let mut request = multipart_request(&uri)?;
+
+    for (key, value) in map {
+        if let Value::String(ref value) = *value {
+            request.write_text(key, value)?;
+        }
+    }
+
+    write_attachment(&mut request, "file", file.into())?;
+
+    let response = finish_multipart(request, Route::GuildsIdStickers(guild_id), uri)?;
+
+    json::from_reader(response)
+}
+
 /// Creates a webhook for the given [channel][`GuildChannel`]'s Id, passing in
 /// the given data.
 ///
@@ -386,6 +522,20 @@ pub fn create_webhook(channel_id: u64, map: &Value) -> Result<Webhook> {
     })
 }
 
+/// Deletes an auto moderation rule from a [`Guild`].
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn delete_automod_rule(guild_id: u64, rule_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteAutoModRule { guild_id, rule_id },
+    })
+}
+
 /// Deletes a private channel or a channel in a guild.
 pub fn delete_channel(channel_id: u64) -> Result<Channel> {
     fire(Request {
@@ -404,6 +554,26 @@ pub fn delete_emoji(guild_id: u64, emoji_id: u64) -> Result<()> {
     })
 }
 
+/// Deletes a follow-up message of an [`Interaction`].
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+pub fn delete_followup_message(application_id: u64, token: &str, message_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteFollowupMessage { application_id, message_id, token },
+    })
+}
+
+/// Deletes a global application command.
+pub fn delete_global_application_command(application_id: u64, command_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteGlobalApplicationCommand { application_id, command_id },
+    })
+}
+
 /// Deletes a guild, only if connected account owns it.
 pub fn delete_guild(guild_id: u64) -> Result<PartialGuild> {
     fire(Request {
@@ -413,6 +583,33 @@ pub fn delete_guild(guild_id: u64) -> Result<PartialGuild> {
     })
 }
 
+/// Deletes an application command in a guild.
+pub fn delete_guild_application_command(application_id: u64, guild_id: u64, command_id: u64)
+    -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteGuildApplicationCommand {
+            application_id,
+            command_id,
+            guild_id,
+        },
+    })
+}
+
+/// Removes a guild from a subcategory of the guild discovery.
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn delete_guild_discovery_category(guild_id: u64, category_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteGuildDiscoveryCategory { category_id, guild_id },
+    })
+}
+
 /// Remvoes an integration from a guild.
 pub fn delete_guild_integration(guild_id: u64, integration_id: u64) -> Result<()> {
     wind(204, Request {
@@ -475,6 +672,17 @@ pub fn delete_message_reactions(channel_id: u64, message_id: u64) -> Result<()>
     })
 }
 
+/// Deletes the message responding to an [`Interaction`].
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+pub fn delete_original_interaction_response(application_id: u64, token: &str) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteOriginalInteractionResponse { application_id, token },
+    })
+}
+
 /// Deletes a permission override from a role or a member in a channel.
 pub fn delete_permission(channel_id: u64, target_id: u64) -> Result<()> {
     wind(204, Request {
@@ -516,6 +724,29 @@ pub fn delete_role(guild_id: u64, role_id: u64) -> Result<()> {
     })
 }
 
+/// Deletes the stage instance of a stage channel, ending the stage.
+pub fn delete_stage_instance(channel_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteStageInstance { channel_id },
+    })
+}
+
+/// Deletes a sticker from a [`Guild`].
+///
+/// **Note**: Requires the [Manage Emojis] permission.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [Manage Emojis]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+pub fn delete_sticker(guild_id: u64, sticker_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteSticker { guild_id, sticker_id },
+    })
+}
+
 /// Deletes a [`Webhook`] given its Id.
 ///
 /// This method requires authentication, whereas [`delete_webhook_with_token`]
@@ -572,6 +803,22 @@ pub fn delete_webhook_with_token(webhook_id: u64, token: &str) -> Result<()> {
     })
 }
 
+/// Changes an auto moderation rule of a [`Guild`].
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn edit_automod_rule(guild_id: u64, rule_id: u64, map: &JsonMap) -> Result<AutoModRule> {
+    let body = serde_json::to_vec(map)?;
+
+    fire(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::EditAutoModRule { guild_id, rule_id },
+    })
+}
+
 /// Changes channel information.
 pub fn edit_channel(channel_id: u64, map: &JsonMap) -> Result<GuildChannel> {
     let body = serde_json::to_vec(map)?;
@@ -594,6 +841,28 @@ pub fn edit_emoji(guild_id: u64, emoji_id: u64, map: &Value) -> Result<Emoji> {
     })
 }
 
+/// Edits a follow-up message of an [`Interaction`].
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+pub fn edit_followup_message(application_id: u64, token: &str, message_id: u64, map: &Value)
+    -> Result<Message> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::EditFollowupMessage { application_id, message_id, token },
+    })
+}
+
+/// Edits a global application command.
+pub fn edit_global_application_command(application_id: u64, command_id: u64, map: &Value)
+    -> Result<ApplicationCommand> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::EditGlobalApplicationCommand { application_id, command_id },
+    })
+}
+
 /// Changes guild information.
 pub fn edit_guild(guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
     let body = serde_json::to_vec(map)?;
@@ -605,6 +874,39 @@ pub fn edit_guild(guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
     })
 }
 
+/// Edits an application command in a guild.
+pub fn edit_guild_application_command(
+    application_id: u64,
+    guild_id: u64,
+    command_id: u64,
+    map: &Value,
+) -> Result<ApplicationCommand> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::EditGuildApplicationCommand {
+            application_id,
+            command_id,
+            guild_id,
+        },
+    })
+}
+
+/// Edits the settings of a guild's listing in the guild discovery.
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn edit_guild_discovery_metadata(guild_id: u64, map: &JsonMap) -> Result<DiscoveryMetadata> {
+    let body = serde_json::to_vec(map)?;
+
+    fire(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::EditGuildDiscoveryMetadata { guild_id },
+    })
+}
+
 /// Edits the positions of a guild's channels.
 pub fn edit_guild_channel_positions(guild_id: u64, value: &Value)
                                     -> Result<()> {
@@ -630,13 +932,59 @@ pub fn edit_guild_embed(guild_id: u64, map: &Value) -> Result<GuildEmbed> {
     })
 }
 
+/// Changes the multi-factor authentication level required for moderators of
+/// a guild.
+///
+/// **Note**: Requires that the current user be the owner of the guild, with
+/// multi-factor authentication enabled on their account.
+pub fn edit_guild_mfa_level(guild_id: u64, map: &Value) -> Result<MfaLevel> {
+    #[derive(Deserialize)]
+    struct GuildMfaLevel {
+        level: MfaLevel,
+    }
+
+    let body = serde_json::to_vec(map)?;
+
+    let response = request(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::EditGuildMfaLevel { guild_id },
+    })?;
+
+    json::from_reader::<HyperResponse, GuildMfaLevel>(response).map(|x| x.level)
+}
+
+/// Edits the onboarding of a guild, replacing its prompts and default
+/// channels.
+///
+/// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+///
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+/// [Manage Roles]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+pub fn edit_guild_onboarding(guild_id: u64, map: &JsonMap) -> Result<Onboarding> {
+    let body = serde_json::to_vec(map)?;
+
+    fire(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::EditGuildOnboarding { guild_id },
+    })
+}
+
 /// Does specific actions to a member.
 pub fn edit_member(guild_id: u64, user_id: u64, map: &JsonMap) -> Result<()> {
+    edit_member_with_reason(guild_id, user_id, map, None)
+}
+
+/// Does specific actions to a member, displaying the given reason in the
+/// guild's audit log.
+pub fn edit_member_with_reason(guild_id: u64, user_id: u64, map: &JsonMap, reason: Option<&str>)
+    -> Result<()> {
     let body = serde_json::to_vec(map)?;
 
     wind(204, Request {
         body: Some(&body),
-        headers: None,
+        headers: audit_log_reason_headers(reason),
         route: RouteInfo::EditMember { guild_id, user_id },
     })
 }
@@ -660,16 +1008,40 @@ pub fn edit_message(channel_id: u64, message_id: u64, map: &Value) -> Result<Mes
 ///
 /// [`Guild`]: ../model/guild/struct.Guild.html
 pub fn edit_nickname(guild_id: u64, new_nickname: Option<&str>) -> Result<()> {
+    edit_nickname_with_reason(guild_id, new_nickname, None)
+}
+
+/// Edits the current user's nickname for the provided [`Guild`] via its Id,
+/// displaying the given reason in the guild's audit log.
+///
+/// Pass `None` to reset the nickname.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+pub fn edit_nickname_with_reason(guild_id: u64, new_nickname: Option<&str>, reason: Option<&str>)
+    -> Result<()> {
     let map = json!({ "nick": new_nickname });
     let body = serde_json::to_vec(&map)?;
 
     wind(200, Request {
         body: Some(&body),
-        headers: None,
+        headers: audit_log_reason_headers(reason),
         route: RouteInfo::EditNickname { guild_id },
     })
 }
 
+/// Edits the message responding to an [`Interaction`], such as to send the
+/// message of a deferred response.
+///
+/// [`Interaction`]: ../model/interaction/struct.Interaction.html
+pub fn edit_original_interaction_response(application_id: u64, token: &str, map: &Value)
+    -> Result<Message> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::EditOriginalInteractionResponse { application_id, token },
+    })
+}
+
 /// Edits the current user's profile settings.
 ///
 /// For bot users, the password is optional.
@@ -691,7 +1063,7 @@ pub fn edit_profile(map: &JsonMap) -> Result<CurrentUser> {
         route: RouteInfo::EditProfile,
     })?;
 
-    let mut value = serde_json::from_reader::<HyperResponse, Value>(response)?;
+    let mut value = json::from_reader::<HyperResponse, Value>(response)?;
 
     if let Some(map) = value.as_object_mut() {
         if !TOKEN.lock().starts_with("Bot ") {
@@ -729,6 +1101,35 @@ pub fn edit_role_position(guild_id: u64, role_id: u64, position: u64) -> Result<
     })
 }
 
+/// Edits the stage instance of a stage channel.
+///
+/// Refer to Discord's [docs] for the fields the `map` can contain.
+///
+/// [docs]: https://discord.com/developers/docs/resources/stage-instance#modify-stage-instance
+pub fn edit_stage_instance(channel_id: u64, map: &Value) -> Result<StageInstance> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::EditStageInstance { channel_id },
+    })
+}
+
+/// Changes a sticker of a [`Guild`].
+///
+/// **Note**: Requires the [Manage Emojis] permission.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [Manage Emojis]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+pub fn edit_sticker(guild_id: u64, sticker_id: u64, map: &JsonMap) -> Result<Sticker> {
+    let body = serde_json::to_vec(map)?;
+
+    fire(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::EditSticker { guild_id, sticker_id },
+    })
+}
+
 /// Edits a the webhook with the given data.
 ///
 /// The Value is a map with optional values of:
@@ -812,6 +1213,17 @@ pub fn edit_webhook_with_token(webhook_id: u64, token: &str, map: &JsonMap) -> R
     })
 }
 
+/// Ends the poll of a message immediately, returning the message.
+///
+/// **Note**: Requires that the current user be the author of the message.
+pub fn end_poll(channel_id: u64, message_id: u64) -> Result<Message> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::EndPoll { channel_id, message_id },
+    })
+}
+
 /// Executes a webhook, posting a [`Message`] in the webhook's associated
 /// [`Channel`].
 ///
@@ -894,9 +1306,42 @@ pub fn execute_webhook(webhook_id: u64,
         return Ok(None);
     }
 
-    serde_json::from_reader::<HyperResponse, Message>(response)
-        .map(Some)
-        .map_err(From::from)
+    json::from_reader::<HyperResponse, Message>(response).map(Some)
+}
+
+/// Executes a webhook with file(s) attached, posting a [`Message`] in the
+/// webhook's associated [`Channel`].
+///
+/// Refer to [`execute_webhook`] for more information on the map and `wait`,
+/// and to [`send_files`] for how the files are numbered.
+///
+/// This method does _not_ require authentication.
+///
+/// # Errors
+///
+/// Returns an [`HttpError::UnsuccessfulRequest`] with a `PayloadTooLarge`
+/// status code if the file is too large to send.
+///
+/// [`Channel`]: ../model/channel/enum.Channel.html
+/// [`HttpError::UnsuccessfulRequest`]: enum.HttpError.html#variant.UnsuccessfulRequest
+/// [`Message`]: ../model/channel/struct.Message.html
+/// [`execute_webhook`]: fn.execute_webhook.html
+/// [`send_files`]: fn.send_files.html
+pub fn execute_webhook_with_files<'a, T, It>(webhook_id: u64,
+                                             token: &str,
+                                             wait: bool,
+                                             files: It,
+                                             map: JsonMap)
+                                             -> Result<Option<Message>>
+    where T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T> {
+    let uri = Route::webhook_with_token_optioned(webhook_id, token, wait);
+    let response = send_multipart(uri, Route::WebhooksId(webhook_id), files, map)?;
+
+    if response.status == StatusCode::NoContent {
+        return Ok(None);
+    }
+
+    json::from_reader::<HyperResponse, Message>(response).map(Some)
 }
 
 /// Gets the active maintenances from Discord's Status API.
@@ -909,7 +1354,7 @@ pub fn get_active_maintenances() -> Result<Vec<Maintenance>> {
         route: RouteInfo::GetActiveMaintenance,
     })?;
 
-    let mut map: BTreeMap<String, Value> = serde_json::from_reader(response)?;
+    let mut map: BTreeMap<String, Value> = json::from_reader(response)?;
 
     match map.remove("scheduled_maintenances") {
         Some(v) => serde_json::from_value::<Vec<Maintenance>>(v)
@@ -918,6 +1363,77 @@ pub fn get_active_maintenances() -> Result<Vec<Maintenance>> {
     }
 }
 
+/// Gets all active threads of a guild, including private threads.
+pub fn get_active_threads(guild_id: u64) -> Result<ThreadsData> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetActiveThreads { guild_id },
+    })
+}
+
+/// Gets the archived private threads of a channel, most recently archived
+/// first.
+///
+/// The `before` timestamp has to be formatted as ISO 8601.
+///
+/// **Note**: Requires the [Manage Messages] permission.
+///
+/// [Manage Messages]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+pub fn get_archived_private_threads(channel_id: u64, before: Option<&str>, limit: Option<u64>)
+    -> Result<ThreadsData> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetArchivedPrivateThreads { before, channel_id, limit },
+    })
+}
+
+/// Gets the archived public threads of a channel, most recently archived
+/// first.
+///
+/// The `before` timestamp has to be formatted as ISO 8601.
+///
+/// **Note**: Requires the [Read Message History] permission.
+///
+/// [Read Message History]: ../model/permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+pub fn get_archived_public_threads(channel_id: u64, before: Option<&str>, limit: Option<u64>)
+    -> Result<ThreadsData> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetArchivedPublicThreads { before, channel_id, limit },
+    })
+}
+
+/// Gets an auto moderation rule of a [`Guild`].
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn get_automod_rule(guild_id: u64, rule_id: u64) -> Result<AutoModRule> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetAutoModRule { guild_id, rule_id },
+    })
+}
+
+/// Gets all auto moderation rules of a [`Guild`].
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn get_automod_rules(guild_id: u64) -> Result<Vec<AutoModRule>> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetAutoModRules { guild_id },
+    })
+}
+
 /// Gets all the users that are banned in specific guild.
 pub fn get_bans(guild_id: u64) -> Result<Vec<Ban>> {
     fire(Request {
@@ -1028,6 +1544,16 @@ pub fn get_current_user() -> Result<CurrentUser> {
     })
 }
 
+/// Gets the categories that guilds can be listed under in the guild
+/// discovery.
+pub fn get_discovery_categories() -> Result<Vec<DiscoveryCategory>> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetDiscoveryCategories,
+    })
+}
+
 /// Gets current gateway.
 pub fn get_gateway() -> Result<Gateway> {
     fire(Request {
@@ -1037,6 +1563,15 @@ pub fn get_gateway() -> Result<Gateway> {
     })
 }
 
+/// Gets the global application commands of an application.
+pub fn get_global_application_commands(application_id: u64) -> Result<Vec<ApplicationCommand>> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetGlobalApplicationCommands { application_id },
+    })
+}
+
 /// Gets guild information.
 pub fn get_guild(guild_id: u64) -> Result<PartialGuild> {
     fire(Request {
@@ -1046,6 +1581,29 @@ pub fn get_guild(guild_id: u64) -> Result<PartialGuild> {
     })
 }
 
+/// Gets the application commands of an application in a guild.
+pub fn get_guild_application_commands(application_id: u64, guild_id: u64)
+    -> Result<Vec<ApplicationCommand>> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetGuildApplicationCommands { application_id, guild_id },
+    })
+}
+
+/// Gets the settings of a guild's listing in the guild discovery.
+///
+/// **Note**: Requires the [Manage Guild] permission.
+///
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub fn get_guild_discovery_metadata(guild_id: u64) -> Result<DiscoveryMetadata> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetGuildDiscoveryMetadata { guild_id },
+    })
+}
+
 /// Gets a guild embed information.
 pub fn get_guild_embed(guild_id: u64) -> Result<GuildEmbed> {
     fire(Request {
@@ -1086,9 +1644,7 @@ pub fn get_guild_vanity_url(guild_id: u64) -> Result<String> {
         route: RouteInfo::GetGuildVanityUrl { guild_id },
     })?;
 
-    serde_json::from_reader::<HyperResponse, GuildVanityUrl>(response)
-        .map(|x| x.code)
-        .map_err(From::from)
+    json::from_reader::<HyperResponse, GuildVanityUrl>(response).map(|x| x.code)
 }
 
 /// Gets the members of a guild. Optionally pass a `limit` and the Id of the
@@ -1103,7 +1659,7 @@ pub fn get_guild_members(guild_id: u64,
         route: RouteInfo::GetGuildMembers { after, guild_id, limit },
     })?;
 
-    let mut v = serde_json::from_reader::<HyperResponse, Value>(response)?;
+    let mut v = json::from_reader::<HyperResponse, Value>(response)?;
 
     if let Some(values) = v.as_array_mut() {
         let num = Value::Number(Number::from(guild_id));
@@ -1118,6 +1674,15 @@ pub fn get_guild_members(guild_id: u64,
     serde_json::from_value::<Vec<Member>>(v).map_err(From::from)
 }
 
+/// Gets the onboarding of a guild, which is shown to new members.
+pub fn get_guild_onboarding(guild_id: u64) -> Result<Onboarding> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetGuildOnboarding { guild_id },
+    })
+}
+
 /// Gets the amount of users that can be pruned.
 pub fn get_guild_prune_count(guild_id: u64, map: &Value) -> Result<GuildPrune> {
     // Note for 0.6.x: turn this into a function parameter.
@@ -1159,6 +1724,28 @@ pub fn get_guild_roles(guild_id: u64) -> Result<Vec<Role>> {
     })
 }
 
+/// Retrieves a sticker of a [`Guild`].
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+pub fn get_guild_sticker(guild_id: u64, sticker_id: u64) -> Result<Sticker> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetGuildSticker { guild_id, sticker_id },
+    })
+}
+
+/// Retrieves a list of stickers in a [`Guild`].
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+pub fn get_guild_stickers(guild_id: u64) -> Result<Vec<Sticker>> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetGuildStickers { guild_id },
+    })
+}
+
 /// Retrieves the webhooks for the given [guild][`Guild`]'s Id.
 ///
 /// This method requires authentication.
@@ -1241,7 +1828,7 @@ pub fn get_member(guild_id: u64, user_id: u64) -> Result<Member> {
         route: RouteInfo::GetMember { guild_id, user_id },
     })?;
 
-    let mut v = serde_json::from_reader::<HyperResponse, Value>(response)?;
+    let mut v = json::from_reader::<HyperResponse, Value>(response)?;
 
     if let Some(map) = v.as_object_mut() {
         map.insert("guild_id".to_string(), Value::Number(Number::from(guild_id)));
@@ -1280,6 +1867,33 @@ pub fn get_pins(channel_id: u64) -> Result<Vec<Message>> {
     })
 }
 
+/// Gets the users who voted for an answer of the poll of a message.
+pub fn get_poll_answer_voters(channel_id: u64,
+                              message_id: u64,
+                              answer_id: u64,
+                              limit: u8,
+                              after: Option<u64>)
+                              -> Result<Vec<User>> {
+    #[derive(Deserialize)]
+    struct PollAnswerVoters {
+        users: Vec<User>,
+    }
+
+    let response = request(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetPollAnswerVoters {
+            after,
+            answer_id,
+            channel_id,
+            limit,
+            message_id,
+        },
+    })?;
+
+    json::from_reader::<HyperResponse, PollAnswerVoters>(response).map(|x| x.users)
+}
+
 /// Gets user Ids based on their reaction to a message. This endpoint is dumb.
 pub fn get_reaction_users(channel_id: u64,
                           message_id: u64,
@@ -1302,6 +1916,33 @@ pub fn get_reaction_users(channel_id: u64,
     })
 }
 
+/// Gets the stage instance of a stage channel.
+pub fn get_stage_instance(channel_id: u64) -> Result<StageInstance> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetStageInstance { channel_id },
+    })
+}
+
+/// Gets a sticker, which is either standard or of a guild.
+pub fn get_sticker(sticker_id: u64) -> Result<Sticker> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetSticker { sticker_id },
+    })
+}
+
+/// Gets the members of a thread.
+pub fn get_thread_members(channel_id: u64) -> Result<Vec<ThreadMember>> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetThreadMembers { channel_id },
+    })
+}
+
 /// Gets the current unresolved incidents from Discord's Status API.
 ///
 /// Does not require authentication.
@@ -1312,7 +1953,7 @@ pub fn get_unresolved_incidents() -> Result<Vec<Incident>> {
         route: RouteInfo::GetUnresolvedIncidents,
     })?;
 
-    let mut map: BTreeMap<String, Value> = serde_json::from_reader(response)?;
+    let mut map: BTreeMap<String, Value> = json::from_reader(response)?;
 
     match map.remove("incidents") {
         Some(v) => serde_json::from_value::<Vec<Incident>>(v)
@@ -1331,7 +1972,7 @@ pub fn get_upcoming_maintenances() -> Result<Vec<Maintenance>> {
         route: RouteInfo::GetUpcomingMaintenances,
     })?;
 
-    let mut map: BTreeMap<String, Value> = serde_json::from_reader(response)?;
+    let mut map: BTreeMap<String, Value> = json::from_reader(response)?;
 
     match map.remove("scheduled_maintenances") {
         Some(v) => serde_json::from_value::<Vec<Maintenance>>(v)
@@ -1417,6 +2058,15 @@ pub fn get_webhook_with_token(webhook_id: u64, token: &str) -> Result<Webhook> {
     })
 }
 
+/// Adds the current user to a thread.
+pub fn join_thread(channel_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::JoinThread { channel_id },
+    })
+}
+
 /// Kicks a member from a guild.
 pub fn kick_member(guild_id: u64, user_id: u64) -> Result<()> {
     wind(204, Request {
@@ -1444,6 +2094,15 @@ pub fn leave_guild(guild_id: u64) -> Result<()> {
     })
 }
 
+/// Removes the current user from a thread.
+pub fn leave_thread(channel_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::LeaveThread { channel_id },
+    })
+}
+
 /// Deletes a user from group DM.
 pub fn remove_group_recipient(group_id: u64, user_id: u64) -> Result<()> {
     wind(204, Request {
@@ -1455,19 +2114,46 @@ pub fn remove_group_recipient(group_id: u64, user_id: u64) -> Result<()> {
 
 /// Sends file(s) to a channel.
 ///
+/// The files are numbered from `0` in the order given, which is what the Ids
+/// in an `attachments` array of the map refer to, such as when setting the
+/// descriptions of the files through
+/// [`CreateMessage::attachment_description`].
+///
 /// # Errors
 ///
-/// Returns an
-/// [`HttpError::InvalidRequest(PayloadTooLarge)`][`HttpError::InvalidRequest`]
-/// if the file is too large to send.
+/// Returns an [`HttpError::UnsuccessfulRequest`] with a `PayloadTooLarge`
+/// status code if the file is too large to send.
 ///
-/// [`HttpError::InvalidRequest`]: enum.HttpError.html#variant.InvalidRequest
+/// [`CreateMessage::attachment_description`]: ../builder/struct.CreateMessage.html#method.attachment_description
+/// [`HttpError::UnsuccessfulRequest`]: enum.HttpError.html#variant.UnsuccessfulRequest
 pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, map: JsonMap) -> Result<Message>
     where T: Into<AttachmentType<'a>> {
     let uri = api!("/channels/{}/messages", channel_id);
-    let url = match Url::parse(&uri) {
+    let response = send_multipart(uri, Route::ChannelsIdMessages(channel_id), files, map)?;
+
+    json::from_reader(response)
+}
+
+/// Sends the files along with the map as the `payload_json` of a multipart
+/// request.
+fn send_multipart<'a, T, It>(uri: String, route: Route, files: It, map: JsonMap)
+    -> Result<HyperResponse> where T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T> {
+    let mut request = multipart_request(&uri)?;
+
+    for (index, file) in files.into_iter().enumerate() {
+        write_attachment(&mut request, &format!("files[{}]", index), file.into())?;
+    }
+
+    request.write_text("payload_json", serde_json::to_string(&map)?)?;
+
+    finish_multipart(request, route, uri)
+}
+
+/// Starts an authenticated multipart `POST` request to the URI.
+fn multipart_request(uri: &str) -> Result<Multipart<HyperRequest<Streaming>>> {
+    let url = match Url::parse(uri) {
         Ok(url) => url,
-        Err(_) => return Err(Error::Url(uri)),
+        Err(_) => return Err(Error::Url(uri.to_string())),
     };
 
     let tc = NativeTlsClient::new()?;
@@ -1480,48 +2166,113 @@ pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, m
         .headers_mut()
         .set(header::UserAgent(constants::USER_AGENT.to_string()));
 
-    let mut request = Multipart::from_request(request)?;
-    let mut file_num = "0".to_string();
-
-    for file in files {
-        match file.into() {
-            AttachmentType::Bytes((mut bytes, filename)) => {
-                request
-                    .write_stream(&file_num, &mut bytes, Some(filename), None)?;
-            },
-            AttachmentType::File((mut f, filename)) => {
-                request
-                    .write_stream(&file_num, &mut f, Some(filename), None)?;
-            },
-            AttachmentType::Path(p) => {
-                request.write_file(&file_num, &p)?;
-            },
-        }
+    Ok(Multipart::from_request(request)?)
+}
 
-        unsafe {
-            let vec = file_num.as_mut_vec();
-            vec[0] += 1;
-        }
-    }
+/// Writes the file as the field of the given name to a multipart request.
+fn write_attachment(request: &mut Multipart<HyperRequest<Streaming>>, name: &str, file: AttachmentType)
+    -> Result<()> {
+    match file {
+        AttachmentType::Bytes((mut bytes, filename)) => {
+            request
+                .write_stream(name, &mut bytes, Some(filename), None)?;
+        },
+        AttachmentType::File((mut f, filename)) => {
+            request
+                .write_stream(name, &mut f, Some(filename), None)?;
+        },
+        AttachmentType::OwnedBytes((bytes, filename)) => {
+            request
+                .write_stream(name, &mut bytes.as_slice(), Some(&filename), None)?;
+        },
+        AttachmentType::Path(p) => {
+            request.write_file(name, &p)?;
+        },
+        AttachmentType::Reader((mut reader, filename)) => {
+            request
+                .write_stream(name, &mut reader, Some(filename), None)?;
+        },
+        AttachmentType::Url(url) => {
+            let (bytes, filename) = download_attachment(url)?;
 
-    for (k, v) in map {
-        match v {
-            Value::Bool(false) => request.write_text(&k, "false")?,
-            Value::Bool(true) => request.write_text(&k, "true")?,
-            Value::Number(inner) => request.write_text(&k, inner.to_string())?,
-            Value::String(inner) => request.write_text(&k, inner)?,
-            Value::Object(inner) => request.write_text(&k, serde_json::to_string(&inner)?)?,
-            _ => continue,
-        };
+            request
+                .write_stream(name, &mut bytes.as_slice(), Some(&filename), None)?;
+        },
     }
 
+    Ok(())
+}
+
+/// Sends a multipart request, returning an error if it was unsuccessful.
+fn finish_multipart(request: Multipart<HyperRequest<Streaming>>, route: Route, uri: String)
+    -> Result<HyperResponse> {
     let response = request.send()?;
 
     if response.status.class() != StatusClass::Success {
-        return Err(Error::Http(HttpError::UnsuccessfulRequest(response)));
+        return Err(unsuccessful_request(response, route, uri));
+    }
+
+    Ok(response)
+}
+
+/// Downloads the file of an [`AttachmentType::Url`], returning its contents
+/// and the last segment of the URL's path as its filename.
+///
+/// [`AttachmentType::Url`]: enum.AttachmentType.html#variant.Url
+fn download_attachment(url: &str) -> Result<(Vec<u8>, String)> {
+    let parsed = match Url::parse(url) {
+        Ok(url) => url,
+        Err(_) => return Err(Error::Url(url.to_string())),
+    };
+
+    let filename = attachment_filename(&parsed);
+    let bytes = super::cdn::download(url)?;
+
+    Ok((bytes, filename))
+}
+
+/// The decoded last segment of the URL's path, or `attachment` if it is empty.
+fn attachment_filename(url: &Url) -> String {
+    url.path_segments()
+        .and_then(|segments| segments.last())
+        .map(percent_decode)
+        .filter(|segment| !segment.is_empty())
+        .unwrap_or_else(|| "attachment".to_string())
+}
+
+/// Decodes the percent-encoded bytes of a segment of a URL's path, such as
+/// `%20` for a space.
+///
+/// Invalid escapes are kept as they are.
+fn percent_decode(segment: &str) -> String {
+    fn hex(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(high), Some(low)) = (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                decoded.push(high << 4 | low);
+                i += 3;
+
+                continue;
+            }
+        }
+
+        decoded.push(bytes[i]);
+        i += 1;
     }
 
-    serde_json::from_reader(response).map_err(From::from)
+    String::from_utf8_lossy(&decoded).into_owned()
 }
 
 /// Sends a message to a channel.
@@ -1570,6 +2321,42 @@ pub fn remove_member_role(guild_id: u64, user_id: u64, role_id: u64) -> Result<(
     })
 }
 
+/// Removes a member from a thread.
+///
+/// **Note**: Requires the [Manage Messages] permission, unless the current
+/// user created the private thread.
+///
+/// [Manage Messages]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+pub fn remove_thread_member(channel_id: u64, user_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::RemoveThreadMember { channel_id, user_id },
+    })
+}
+
+/// Replaces all global application commands of an application with the given
+/// array of commands.
+pub fn set_global_application_commands(application_id: u64, commands: &Value)
+    -> Result<Vec<ApplicationCommand>> {
+    fire(Request {
+        body: Some(commands.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::SetGlobalApplicationCommands { application_id },
+    })
+}
+
+/// Replaces all application commands of an application in a guild with the
+/// given array of commands.
+pub fn set_guild_application_commands(application_id: u64, guild_id: u64, commands: &Value)
+    -> Result<Vec<ApplicationCommand>> {
+    fire(Request {
+        body: Some(commands.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::SetGuildApplicationCommands { application_id, guild_id },
+    })
+}
+
 /// Starts removing some members from a guild based on the last time they've been online.
 pub fn start_guild_prune(guild_id: u64, map: &Value) -> Result<GuildPrune> {
     // Note for 0.6.x: turn this into a function parameter.
@@ -1599,6 +2386,34 @@ pub fn start_integration_sync(guild_id: u64, integration_id: u64) -> Result<()>
     })
 }
 
+/// Starts a public thread from a message in a channel.
+///
+/// Refer to Discord's [docs] for the fields the `map` can contain.
+///
+/// [docs]: https://discord.com/developers/docs/resources/channel#start-thread-from-message
+pub fn start_thread_with_message(channel_id: u64, message_id: u64, map: &Value)
+    -> Result<GuildChannel> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::StartThreadWithMessage { channel_id, message_id },
+    })
+}
+
+/// Starts a thread in a channel that is not attached to a message, which is
+/// private unless the `type` is set otherwise.
+///
+/// Refer to Discord's [docs] for the fields the `map` can contain.
+///
+/// [docs]: https://discord.com/developers/docs/resources/channel#start-thread-without-message
+pub fn start_thread_without_message(channel_id: u64, map: &Value) -> Result<GuildChannel> {
+    fire(Request {
+        body: Some(map.to_string().as_bytes()),
+        headers: None,
+        route: RouteInfo::StartThreadWithoutMessage { channel_id },
+    })
+}
+
 /// Unpins a message from a channel.
 pub fn unpin_message(channel_id: u64, message_id: u64) -> Result<()> {
     wind(204, Request {
@@ -1660,7 +2475,7 @@ pub fn unpin_message(channel_id: u64, message_id: u64) -> Result<()> {
 pub fn fire<T: DeserializeOwned>(req: Request) -> Result<T> {
     let response = request(req)?;
 
-    serde_json::from_reader(response).map_err(From::from)
+    json::from_reader(response)
 }
 
 /// Performs a request, ratelimiting it if necessary.
@@ -1708,15 +2523,46 @@ pub fn fire<T: DeserializeOwned>(req: Request) -> Result<T> {
 ///
 /// [`fire`]: fn.fire.html
 pub fn request(req: Request) -> Result<HyperResponse> {
+    let (_, route, url) = req.route.deconstruct();
+    let url = url.into_owned();
+
     let response = ratelimiting::perform(req)?;
 
     if response.status.class() == StatusClass::Success {
         Ok(response)
     } else {
-        Err(Error::Http(HttpError::UnsuccessfulRequest(response)))
+        Err(unsuccessful_request(response, route, url))
     }
 }
 
+/// Creates the error for an unsuccessful response to a request made to `url`.
+pub(super) fn unsuccessful_request(response: HyperResponse, route: Route, url: String) -> Error {
+    Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse::from_response(response, route, url)))
+}
+
+/// Creates the headers carrying a reason for the audit log, if one is given.
+///
+/// Discord expects the reason to be percent-encoded.
+fn audit_log_reason_headers(reason: Option<&str>) -> Option<Headers> {
+    reason.map(|reason| {
+        let mut encoded = String::with_capacity(reason.len());
+
+        for byte in reason.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    encoded.push(byte as char);
+                },
+                _ => encoded.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+
+        let mut headers = Headers::new();
+        headers.set_raw("X-Audit-Log-Reason", vec![encoded.into_bytes()]);
+
+        headers
+    })
+}
+
 pub(super) fn retry(request: &Request) -> HyperResult<HyperResponse> {
     // Retry the request twice in a loop until it succeeds.
     //
@@ -1738,6 +2584,9 @@ pub(super) fn retry(request: &Request) -> HyperResult<HyperResponse> {
 /// This is a function that performs a light amount of work and returns an
 /// empty tuple, so it's called "wind" to denote that it's lightweight.
 pub(super) fn wind(expected: u16, req: Request) -> Result<()> {
+    let (_, route, url) = req.route.deconstruct();
+    let url = url.into_owned();
+
     let resp = request(req)?;
 
     if resp.status.to_u16() == expected {
@@ -1747,5 +2596,45 @@ pub(super) fn wind(expected: u16, req: Request) -> Result<()> {
     debug!("Expected {}, got {}", expected, resp.status);
     trace!("Unsuccessful response: {:?}", resp);
 
-    Err(Error::Http(HttpError::UnsuccessfulRequest(resp)))
+    Err(unsuccessful_request(resp, route, url))
+}
+
+#[cfg(test)]
+mod test {
+    use hyper::Url;
+    use super::{attachment_filename, audit_log_reason_headers, percent_decode};
+
+    #[test]
+    fn percent_decodes_segments() {
+        assert_eq!(percent_decode("cat.png"), "cat.png");
+        assert_eq!(percent_decode("my%20cat.png"), "my cat.png");
+        assert_eq!(percent_decode("%C3%BCber%2a"), "über*");
+    }
+
+    #[test]
+    fn percent_decode_keeps_invalid_escapes() {
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%2"), "%2");
+        assert_eq!(percent_decode("%zz.png"), "%zz.png");
+        assert_eq!(percent_decode("%FF"), "\u{FFFD}");
+    }
+
+    #[test]
+    fn attachment_filenames() {
+        let filename = |url| attachment_filename(&Url::parse(url).unwrap());
+
+        assert_eq!(filename("https://example.com/images/my%20cat.png?size=1"), "my cat.png");
+        assert_eq!(filename("https://example.com/images/"), "attachment");
+        assert_eq!(filename("https://example.com"), "attachment");
+    }
+
+    #[test]
+    fn audit_log_reasons_are_percent_encoded() {
+        assert!(audit_log_reason_headers(None).is_none());
+
+        let headers = audit_log_reason_headers(Some("Spam: 3 links ü")).unwrap();
+        let raw = headers.get_raw("X-Audit-Log-Reason").unwrap();
+
+        assert_eq!(raw, &[b"Spam%3A%203%20links%20%C3%BC".to_vec()][..]);
+    }
 }
@@ -11,6 +11,33 @@ use super::LightMethod;
 /// [`http`]: ../index.html
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Route {
+    /// Route for the `/applications/:application_id/commands` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    ApplicationsIdCommands(u64),
+    /// Route for the `/applications/:application_id/commands/:command_id` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    ApplicationsIdCommandsId(u64),
+    /// Route for the `/applications/:application_id/guilds/:guild_id/commands`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    ApplicationsIdGuildsIdCommands(u64),
+    /// Route for the
+    /// `/applications/:application_id/guilds/:guild_id/commands/:command_id`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    ApplicationsIdGuildsIdCommandsId(u64),
     /// Route for the `/channels/:channel_id` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -71,6 +98,13 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdMessagesIdReactionsUserIdType(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/threads`
+    /// path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdMessagesIdThreads(u64),
     /// Route for the `/channels/:channel_id/permissions/:target_id` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -89,6 +123,49 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdPinsMessageId(u64),
+    /// Route for the
+    /// `/channels/:channel_id/polls/:message_id/answers/:answer_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdPollsIdAnswersId(u64),
+    /// Route for the `/channels/:channel_id/polls/:message_id/expire` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdPollsIdExpire(u64),
+    /// Route for the `/channels/:channel_id/thread-members` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadMembers(u64),
+    /// Route for the `/channels/:channel_id/thread-members/:user_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadMembersUserId(u64),
+    /// Route for the `/channels/:channel_id/threads` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreads(u64),
+    /// Route for the `/channels/:channel_id/threads/archived/private` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadsArchivedPrivate(u64),
+    /// Route for the `/channels/:channel_id/threads/archived/public` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadsArchivedPublic(u64),
     /// Route for the `/channels/:channel_id/typing` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -101,6 +178,8 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdWebhooks(u64),
+    /// Route for the `/discovery/categories` path.
+    DiscoveryCategories,
     /// Route for the `/gateway` path.
     Gateway,
     /// Route for the `/gateway/bot` path.
@@ -124,6 +203,18 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdAuditLogs(u64),
+    /// Route for the `/guilds/:guild_id/auto-moderation/rules` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdAutoModerationRules(u64),
+    /// Route for the `/guilds/:guild_id/auto-moderation/rules/:rule_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdAutoModerationRulesId(u64),
     /// Route for the `/guilds/:guild_id/bans/:user_id` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -136,6 +227,19 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdChannels(u64),
+    /// Route for the `/guilds/:guild_id/discovery-categories/:category_id`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdDiscoveryCategoriesId(u64),
+    /// Route for the `/guilds/:guild_id/discovery-metadata` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdDiscoveryMetadata(u64),
     /// Route for the `/guilds/:guild_id/embed` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -203,6 +307,18 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdMembersMeNick(u64),
+    /// Route for the `/guilds/:guild_id/mfa` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdMfa(u64),
+    /// Route for the `/guilds/:guild_id/onboarding` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdOnboarding(u64),
     /// Route for the `/guilds/:guild_id/prune` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -227,6 +343,24 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdRolesId(u64),
+    /// Route for the `/guilds/:guild_id/stickers` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdStickers(u64),
+    /// Route for the `/guilds/:guild_id/stickers/:sticker_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdStickersId(u64),
+    /// Route for the `/guilds/:guild_id/threads/active` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdThreadsActive(u64),
     /// Route for the `/guilds/:guild_id/vanity-url` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -239,8 +373,24 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdWebhooks(u64),
+    /// Route for the `/interactions/:interaction_id/:token/callback` path.
+    ///
+    /// The data is the relevant [`InteractionId`].
+    ///
+    /// [`InteractionId`]: ../../model/id/struct.InteractionId.html
+    InteractionsIdTokenCallback(u64),
     /// Route for the `/invites/:code` path.
     InvitesCode,
+    /// Route for the `/stage-instances` path.
+    StageInstances,
+    /// Route for the `/stage-instances/:channel_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    StageInstancesId(u64),
+    /// Route for the `/stickers/:sticker_id` path.
+    StickersId,
     /// Route for the `/users/:user_id` path.
     UsersId,
     /// Route for the `/users/@me` path.
@@ -255,6 +405,12 @@ pub enum Route {
     VoiceRegions,
     /// Route for the `/webhooks/:webhook_id` path.
     WebhooksId(u64),
+    /// Route for the `/webhooks/:webhook_id/:token/messages/:message_id` path.
+    ///
+    /// The data is the relevant [`WebhookId`].
+    ///
+    /// [`WebhookId`]: ../../model/id/struct.WebhookId.html
+    WebhooksIdMessagesId(u64),
     /// Route where no ratelimit headers are in place (i.e. user account-only
     /// routes).
     ///
@@ -264,6 +420,31 @@ pub enum Route {
 }
 
 impl Route {
+    pub fn application_command(application_id: u64, command_id: u64) -> String {
+        format!(api!("/applications/{}/commands/{}"), application_id, command_id)
+    }
+
+    pub fn application_commands(application_id: u64) -> String {
+        format!(api!("/applications/{}/commands"), application_id)
+    }
+
+    pub fn application_guild_command(
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    ) -> String {
+        format!(
+            api!("/applications/{}/guilds/{}/commands/{}"),
+            application_id,
+            guild_id,
+            command_id,
+        )
+    }
+
+    pub fn application_guild_commands(application_id: u64, guild_id: u64) -> String {
+        format!(api!("/applications/{}/guilds/{}/commands"), application_id, guild_id)
+    }
+
     pub fn channel(channel_id: u64) -> String {
         format!(api!("/channels/{}"), channel_id)
     }
@@ -320,6 +501,10 @@ impl Route {
         uri
     }
 
+    pub fn channel_message_threads(channel_id: u64, message_id: u64) -> String {
+        format!(api!("/channels/{}/messages/{}/threads"), channel_id, message_id)
+    }
+
     pub fn channel_messages(channel_id: u64, query: Option<&str>) -> String {
         format!(
             api!("/channels/{}/messages{}"),
@@ -344,6 +529,63 @@ impl Route {
         format!(api!("/channels/{}/pins"), channel_id)
     }
 
+    pub fn channel_poll_answer_voters(
+        channel_id: u64,
+        message_id: u64,
+        answer_id: u64,
+        limit: u8,
+        after: Option<u64>,
+    ) -> String {
+        let mut uri = format!(
+            api!("/channels/{}/polls/{}/answers/{}?limit={}"),
+            channel_id,
+            message_id,
+            answer_id,
+            limit,
+        );
+
+        if let Some(after) = after {
+            let _ = write!(uri, "&after={}", after);
+        }
+
+        uri
+    }
+
+    pub fn channel_poll_expire(channel_id: u64, message_id: u64) -> String {
+        format!(api!("/channels/{}/polls/{}/expire"), channel_id, message_id)
+    }
+
+    pub fn channel_thread_member<D: Display>(channel_id: u64, target: D) -> String {
+        format!(api!("/channels/{}/thread-members/{}"), channel_id, target)
+    }
+
+    pub fn channel_thread_members(channel_id: u64) -> String {
+        format!(api!("/channels/{}/thread-members"), channel_id)
+    }
+
+    pub fn channel_threads(channel_id: u64) -> String {
+        format!(api!("/channels/{}/threads"), channel_id)
+    }
+
+    pub fn channel_threads_archived(
+        channel_id: u64,
+        kind: &str,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut uri = format!(api!("/channels/{}/threads/archived/{}?"), channel_id, kind);
+
+        if let Some(before) = before {
+            let _ = write!(uri, "&before={}", before);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(uri, "&limit={}", limit);
+        }
+
+        uri
+    }
+
     pub fn channel_typing(channel_id: u64) -> String {
         format!(api!("/channels/{}/typing"), channel_id)
     }
@@ -352,6 +594,10 @@ impl Route {
         format!(api!("/channels/{}/webhooks"), channel_id)
     }
 
+    pub fn discovery_categories() -> &'static str {
+        api!("/discovery/categories")
+    }
+
     pub fn gateway() -> &'static str {
         api!("/gateway")
     }
@@ -399,6 +645,14 @@ impl Route {
         s
     }
 
+    pub fn guild_auto_moderation_rule(guild_id: u64, rule_id: u64) -> String {
+        format!(api!("/guilds/{}/auto-moderation/rules/{}"), guild_id, rule_id)
+    }
+
+    pub fn guild_auto_moderation_rules(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/auto-moderation/rules"), guild_id)
+    }
+
     pub fn guild_ban(guild_id: u64, user_id: u64) -> String {
         format!(api!("/guilds/{}/bans/{}"), guild_id, user_id)
     }
@@ -426,6 +680,14 @@ impl Route {
         format!(api!("/guilds/{}/channels"), guild_id)
     }
 
+    pub fn guild_discovery_category(guild_id: u64, category_id: u64) -> String {
+        format!(api!("/guilds/{}/discovery-categories/{}"), guild_id, category_id)
+    }
+
+    pub fn guild_discovery_metadata(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/discovery-metadata"), guild_id)
+    }
+
     pub fn guild_embed(guild_id: u64) -> String {
         format!(api!("/guilds/{}/embed"), guild_id)
     }
@@ -507,6 +769,14 @@ impl Route {
         format!(api!("/guilds/{}/members/@me/nick"), guild_id)
     }
 
+    pub fn guild_mfa(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/mfa"), guild_id)
+    }
+
+    pub fn guild_onboarding(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/onboarding"), guild_id)
+    }
+
     pub fn guild_prune(guild_id: u64, days: u64) -> String {
         format!(api!("/guilds/{}/prune?days={}"), guild_id, days)
     }
@@ -523,6 +793,18 @@ impl Route {
         format!(api!("/guilds/{}/roles"), guild_id)
     }
 
+    pub fn guild_sticker(guild_id: u64, sticker_id: u64) -> String {
+        format!(api!("/guilds/{}/stickers/{}"), guild_id, sticker_id)
+    }
+
+    pub fn guild_stickers(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/stickers"), guild_id)
+    }
+
+    pub fn guild_threads_active(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/threads/active"), guild_id)
+    }
+
     pub fn guild_vanity_url(guild_id: u64) -> String {
         format!(api!("/guilds/{}/vanity-url"), guild_id)
     }
@@ -535,6 +817,10 @@ impl Route {
         api!("/guilds")
     }
 
+    pub fn interaction_callback<D: Display>(interaction_id: u64, token: D) -> String {
+        format!(api!("/interactions/{}/{}/callback"), interaction_id, token)
+    }
+
     pub fn invite(code: &str) -> String {
         format!(api!("/invites/{}"), code)
     }
@@ -551,6 +837,18 @@ impl Route {
         api!("/users/@me/channels")
     }
 
+    pub fn stage_instance(channel_id: u64) -> String {
+        format!(api!("/stage-instances/{}"), channel_id)
+    }
+
+    pub fn stage_instances() -> &'static str {
+        api!("/stage-instances")
+    }
+
+    pub fn sticker(sticker_id: u64) -> String {
+        format!(api!("/stickers/{}"), sticker_id)
+    }
+
     pub fn status_incidents_unresolved() -> &'static str {
         status!("/incidents/unresolved.json")
     }
@@ -606,6 +904,11 @@ impl Route {
         format!(api!("/webhooks/{}"), webhook_id)
     }
 
+    pub fn webhook_message<D, M>(webhook_id: u64, token: D, message_id: M) -> String
+        where D: Display, M: Display {
+        format!(api!("/webhooks/{}/{}/messages/{}"), webhook_id, token, message_id)
+    }
+
     pub fn webhook_with_token<D>(webhook_id: u64, token: D) -> String
         where D: Display {
         format!(api!("/webhooks/{}/{}"), webhook_id, token)
@@ -623,11 +926,19 @@ pub enum RouteInfo<'a> {
         group_id: u64,
         user_id: u64,
     },
+    AddGuildDiscoveryCategory {
+        category_id: u64,
+        guild_id: u64,
+    },
     AddMemberRole {
         guild_id: u64,
         role_id: u64,
         user_id: u64,
     },
+    AddThreadMember {
+        channel_id: u64,
+        user_id: u64,
+    },
     GuildBanUser {
         guild_id: u64,
         user_id: u64,
@@ -637,17 +948,35 @@ pub enum RouteInfo<'a> {
     BroadcastTyping {
         channel_id: u64,
     },
+    CreateAutoModRule {
+        guild_id: u64,
+    },
     CreateChannel {
         guild_id: u64,
     },
     CreateEmoji {
         guild_id: u64,
     },
+    CreateFollowupMessage {
+        application_id: u64,
+        token: &'a str,
+    },
+    CreateGlobalApplicationCommand {
+        application_id: u64,
+    },
     CreateGuild,
+    CreateGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+    },
     CreateGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    CreateInteractionResponse {
+        interaction_id: u64,
+        token: &'a str,
+    },
     CreateInvite {
         channel_id: u64,
     },
@@ -667,9 +996,14 @@ pub enum RouteInfo<'a> {
     CreateRole {
         guild_id: u64,
     },
+    CreateStageInstance,
     CreateWebhook {
         channel_id: u64,
     },
+    DeleteAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
     DeleteChannel {
         channel_id: u64,
     },
@@ -677,9 +1011,27 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    DeleteFollowupMessage {
+        application_id: u64,
+        message_id: u64,
+        token: &'a str,
+    },
+    DeleteGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
     DeleteGuild {
         guild_id: u64,
     },
+    DeleteGuildApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+        guild_id: u64,
+    },
+    DeleteGuildDiscoveryCategory {
+        category_id: u64,
+        guild_id: u64,
+    },
     DeleteGuildIntegration {
         guild_id: u64,
         integration_id: u64,
@@ -698,6 +1050,10 @@ pub enum RouteInfo<'a> {
         channel_id: u64,
         message_id: u64,
     },
+    DeleteOriginalInteractionResponse {
+        application_id: u64,
+        token: &'a str,
+    },
     DeletePermission {
         channel_id: u64,
         target_id: u64,
@@ -712,6 +1068,13 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         role_id: u64,
     },
+    DeleteStageInstance {
+        channel_id: u64,
+    },
+    DeleteSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
     DeleteWebhook {
         webhook_id: u64,
     },
@@ -719,6 +1082,10 @@ pub enum RouteInfo<'a> {
         token: &'a str,
         webhook_id: u64,
     },
+    EditAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
     EditChannel {
         channel_id: u64,
     },
@@ -726,15 +1093,38 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    EditFollowupMessage {
+        application_id: u64,
+        message_id: u64,
+        token: &'a str,
+    },
+    EditGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
     EditGuild {
         guild_id: u64,
     },
+    EditGuildApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+        guild_id: u64,
+    },
     EditGuildChannels {
         guild_id: u64,
     },
+    EditGuildDiscoveryMetadata {
+        guild_id: u64,
+    },
     EditGuildEmbed {
         guild_id: u64,
     },
+    EditGuildMfaLevel {
+        guild_id: u64,
+    },
+    EditGuildOnboarding {
+        guild_id: u64,
+    },
     EditMember {
         guild_id: u64,
         user_id: u64,
@@ -746,11 +1136,22 @@ pub enum RouteInfo<'a> {
     EditNickname {
         guild_id: u64,
     },
+    EditOriginalInteractionResponse {
+        application_id: u64,
+        token: &'a str,
+    },
     EditProfile,
     EditRole {
         guild_id: u64,
         role_id: u64,
     },
+    EditStageInstance {
+        channel_id: u64,
+    },
+    EditSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
     EditWebhook {
         webhook_id: u64,
     },
@@ -758,12 +1159,29 @@ pub enum RouteInfo<'a> {
         token: &'a str,
         webhook_id: u64,
     },
+    EndPoll {
+        channel_id: u64,
+        message_id: u64,
+    },
     ExecuteWebhook {
         token: &'a str,
         wait: bool,
         webhook_id: u64,
     },
     GetActiveMaintenance,
+    GetActiveThreads {
+        guild_id: u64,
+    },
+    GetArchivedPrivateThreads {
+        before: Option<&'a str>,
+        channel_id: u64,
+        limit: Option<u64>,
+    },
+    GetArchivedPublicThreads {
+        before: Option<&'a str>,
+        channel_id: u64,
+        limit: Option<u64>,
+    },
     GetAuditLogs {
         action_type: Option<u8>,
         before: Option<u64>,
@@ -771,6 +1189,13 @@ pub enum RouteInfo<'a> {
         limit: Option<u8>,
         user_id: Option<u64>,
     },
+    GetAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
+    GetAutoModRules {
+        guild_id: u64,
+    },
     GetBans {
         guild_id: u64,
     },
@@ -789,10 +1214,21 @@ pub enum RouteInfo<'a> {
     },
     GetCurrentApplicationInfo,
     GetCurrentUser,
+    GetDiscoveryCategories,
     GetGateway,
+    GetGlobalApplicationCommands {
+        application_id: u64,
+    },
     GetGuild {
         guild_id: u64,
     },
+    GetGuildApplicationCommands {
+        application_id: u64,
+        guild_id: u64,
+    },
+    GetGuildDiscoveryMetadata {
+        guild_id: u64,
+    },
     GetGuildEmbed {
         guild_id: u64,
     },
@@ -807,6 +1243,9 @@ pub enum RouteInfo<'a> {
         limit: Option<u64>,
         guild_id: u64,
     },
+    GetGuildOnboarding {
+        guild_id: u64,
+    },
     GetGuildPruneCount {
         days: u64,
         guild_id: u64,
@@ -817,6 +1256,13 @@ pub enum RouteInfo<'a> {
     GetGuildRoles {
         guild_id: u64,
     },
+    GetGuildSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
+    GetGuildStickers {
+        guild_id: u64,
+    },
     GetGuildVanityUrl {
         guild_id: u64,
     },
@@ -847,6 +1293,13 @@ pub enum RouteInfo<'a> {
     GetPins {
         channel_id: u64,
     },
+    GetPollAnswerVoters {
+        after: Option<u64>,
+        answer_id: u64,
+        channel_id: u64,
+        limit: u8,
+        message_id: u64,
+    },
     GetReactionUsers {
         after: Option<u64>,
         channel_id: u64,
@@ -854,6 +1307,15 @@ pub enum RouteInfo<'a> {
         message_id: u64,
         reaction: String,
     },
+    GetStageInstance {
+        channel_id: u64,
+    },
+    GetSticker {
+        sticker_id: u64,
+    },
+    GetThreadMembers {
+        channel_id: u64,
+    },
     GetUnresolvedIncidents,
     GetUpcomingMaintenances,
     GetUser {
@@ -868,6 +1330,9 @@ pub enum RouteInfo<'a> {
         token: &'a str,
         webhook_id: u64,
     },
+    JoinThread {
+        channel_id: u64,
+    },
     KickMember {
         guild_id: u64,
         user_id: u64,
@@ -878,6 +1343,9 @@ pub enum RouteInfo<'a> {
     LeaveGuild {
         guild_id: u64,
     },
+    LeaveThread {
+        channel_id: u64,
+    },
     RemoveGroupRecipient {
         group_id: u64,
         user_id: u64,
@@ -895,6 +1363,17 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    RemoveThreadMember {
+        channel_id: u64,
+        user_id: u64,
+    },
+    SetGlobalApplicationCommands {
+        application_id: u64,
+    },
+    SetGuildApplicationCommands {
+        application_id: u64,
+        guild_id: u64,
+    },
     StartGuildPrune {
         days: u64,
         guild_id: u64,
@@ -903,6 +1382,13 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         integration_id: u64,
     },
+    StartThreadWithMessage {
+        channel_id: u64,
+        message_id: u64,
+    },
+    StartThreadWithoutMessage {
+        channel_id: u64,
+    },
     StatusIncidentsUnresolved,
     StatusMaintenancesActive,
     StatusMaintenancesUpcoming,
@@ -920,11 +1406,21 @@ impl<'a> RouteInfo<'a> {
                 Route::None,
                 Cow::from(Route::group_recipient(group_id, user_id)),
             ),
+            RouteInfo::AddGuildDiscoveryCategory { category_id, guild_id } => (
+                LightMethod::Put,
+                Route::GuildsIdDiscoveryCategoriesId(guild_id),
+                Cow::from(Route::guild_discovery_category(guild_id, category_id)),
+            ),
             RouteInfo::AddMemberRole { guild_id, role_id, user_id } => (
                 LightMethod::Put,
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            RouteInfo::AddThreadMember { channel_id, user_id } => (
+                LightMethod::Put,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+            ),
             RouteInfo::GuildBanUser {
                 guild_id,
                 delete_message_days,
@@ -946,6 +1442,11 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdTyping(channel_id),
                 Cow::from(Route::channel_typing(channel_id)),
             ),
+            RouteInfo::CreateAutoModRule { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdAutoModerationRules(guild_id),
+                Cow::from(Route::guild_auto_moderation_rules(guild_id)),
+            ),
             RouteInfo::CreateChannel { guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdChannels(guild_id),
@@ -956,16 +1457,36 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojis(guild_id),
                 Cow::from(Route::guild_emojis(guild_id)),
             ),
+            RouteInfo::CreateFollowupMessage { application_id, token } => (
+                LightMethod::Post,
+                Route::WebhooksId(application_id),
+                Cow::from(Route::webhook_with_token_optioned(application_id, token, true)),
+            ),
+            RouteInfo::CreateGlobalApplicationCommand { application_id } => (
+                LightMethod::Post,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
             RouteInfo::CreateGuild => (
                 LightMethod::Post,
                 Route::Guilds,
                 Cow::from(Route::guilds()),
             ),
+            RouteInfo::CreateGuildApplicationCommand { application_id, guild_id } => (
+                LightMethod::Post,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
             RouteInfo::CreateGuildIntegration { guild_id, integration_id } => (
                 LightMethod::Post,
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::CreateInteractionResponse { interaction_id, token } => (
+                LightMethod::Post,
+                Route::InteractionsIdTokenCallback(interaction_id),
+                Cow::from(Route::interaction_callback(interaction_id, token)),
+            ),
             RouteInfo::CreateInvite { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdInvites(channel_id),
@@ -1001,11 +1522,21 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::CreateStageInstance => (
+                LightMethod::Post,
+                Route::StageInstances,
+                Cow::from(Route::stage_instances()),
+            ),
             RouteInfo::CreateWebhook { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdWebhooks(channel_id),
                 Cow::from(Route::channel_webhooks(channel_id)),
             ),
+            RouteInfo::DeleteAutoModRule { guild_id, rule_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_auto_moderation_rule(guild_id, rule_id)),
+            ),
             RouteInfo::DeleteChannel { channel_id } => (
                 LightMethod::Delete,
                 Route::ChannelsId(channel_id),
@@ -1016,11 +1547,35 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            RouteInfo::DeleteFollowupMessage { application_id, message_id, token } => (
+                LightMethod::Delete,
+                Route::WebhooksIdMessagesId(application_id),
+                Cow::from(Route::webhook_message(application_id, token, message_id)),
+            ),
+            RouteInfo::DeleteGlobalApplicationCommand { application_id, command_id } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
             RouteInfo::DeleteGuild { guild_id } => (
                 LightMethod::Delete,
                 Route::GuildsId(guild_id),
                 Cow::from(Route::guild(guild_id)),
             ),
+            RouteInfo::DeleteGuildApplicationCommand {
+                application_id,
+                command_id,
+                guild_id,
+            } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdGuildsIdCommandsId(guild_id),
+                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+            ),
+            RouteInfo::DeleteGuildDiscoveryCategory { category_id, guild_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdDiscoveryCategoriesId(guild_id),
+                Cow::from(Route::guild_discovery_category(guild_id, category_id)),
+            ),
             RouteInfo::DeleteGuildIntegration { guild_id, integration_id } => (
                 LightMethod::Delete,
                 Route::GuildsIdIntegrationsId(guild_id),
@@ -1049,6 +1604,11 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdMessagesBulkDelete(channel_id),
                 Cow::from(Route::channel_messages_bulk_delete(channel_id)),
             ),
+            RouteInfo::DeleteOriginalInteractionResponse { application_id, token } => (
+                LightMethod::Delete,
+                Route::WebhooksIdMessagesId(application_id),
+                Cow::from(Route::webhook_message(application_id, token, "@original")),
+            ),
             RouteInfo::DeletePermission { channel_id, target_id } => (
                 LightMethod::Delete,
                 Route::ChannelsIdPermissionsOverwriteId(channel_id),
@@ -1074,6 +1634,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_role(guild_id, role_id)),
             ),
+            RouteInfo::DeleteStageInstance { channel_id } => (
+                LightMethod::Delete,
+                Route::StageInstancesId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::DeleteSticker { guild_id, sticker_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
             RouteInfo::DeleteWebhook { webhook_id } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
@@ -1084,6 +1654,11 @@ impl<'a> RouteInfo<'a> {
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
+            RouteInfo::EditAutoModRule { guild_id, rule_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_auto_moderation_rule(guild_id, rule_id)),
+            ),
             RouteInfo::EditChannel { channel_id } => (
                 LightMethod::Patch,
                 Route::ChannelsId(channel_id),
@@ -1094,21 +1669,55 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            RouteInfo::EditFollowupMessage { application_id, message_id, token } => (
+                LightMethod::Patch,
+                Route::WebhooksIdMessagesId(application_id),
+                Cow::from(Route::webhook_message(application_id, token, message_id)),
+            ),
+            RouteInfo::EditGlobalApplicationCommand { application_id, command_id } => (
+                LightMethod::Patch,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
             RouteInfo::EditGuild { guild_id } => (
                 LightMethod::Patch,
                 Route::GuildsId(guild_id),
                 Cow::from(Route::guild(guild_id)),
             ),
+            RouteInfo::EditGuildApplicationCommand {
+                application_id,
+                command_id,
+                guild_id,
+            } => (
+                LightMethod::Patch,
+                Route::ApplicationsIdGuildsIdCommandsId(guild_id),
+                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+            ),
             RouteInfo::EditGuildChannels { guild_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdChannels(guild_id),
                 Cow::from(Route::guild_channels(guild_id)),
             ),
+            RouteInfo::EditGuildDiscoveryMetadata { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdDiscoveryMetadata(guild_id),
+                Cow::from(Route::guild_discovery_metadata(guild_id)),
+            ),
             RouteInfo::EditGuildEmbed { guild_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdEmbed(guild_id),
                 Cow::from(Route::guild_embed(guild_id)),
             ),
+            RouteInfo::EditGuildMfaLevel { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdMfa(guild_id),
+                Cow::from(Route::guild_mfa(guild_id)),
+            ),
+            RouteInfo::EditGuildOnboarding { guild_id } => (
+                LightMethod::Put,
+                Route::GuildsIdOnboarding(guild_id),
+                Cow::from(Route::guild_onboarding(guild_id)),
+            ),
             RouteInfo::EditMember { guild_id, user_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersId(guild_id),
@@ -1124,6 +1733,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersMeNick(guild_id),
                 Cow::from(Route::guild_nickname(guild_id)),
             ),
+            RouteInfo::EditOriginalInteractionResponse { application_id, token } => (
+                LightMethod::Patch,
+                Route::WebhooksIdMessagesId(application_id),
+                Cow::from(Route::webhook_message(application_id, token, "@original")),
+            ),
             RouteInfo::EditProfile => (
                 LightMethod::Patch,
                 Route::UsersMe,
@@ -1134,6 +1748,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_role(guild_id, role_id)),
             ),
+            RouteInfo::EditStageInstance { channel_id } => (
+                LightMethod::Patch,
+                Route::StageInstancesId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::EditSticker { guild_id, sticker_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
             RouteInfo::EditWebhook { webhook_id } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
@@ -1144,6 +1768,11 @@ impl<'a> RouteInfo<'a> {
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
+            RouteInfo::EndPoll { channel_id, message_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdPollsIdExpire(channel_id),
+                Cow::from(Route::channel_poll_expire(channel_id, message_id)),
+            ),
             RouteInfo::ExecuteWebhook { token, wait, webhook_id } => (
                 LightMethod::Post,
                 Route::WebhooksId(webhook_id),
@@ -1158,6 +1787,31 @@ impl<'a> RouteInfo<'a> {
                 Route::None,
                 Cow::from(Route::status_maintenances_active()),
             ),
+            RouteInfo::GetActiveThreads { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdThreadsActive(guild_id),
+                Cow::from(Route::guild_threads_active(guild_id)),
+            ),
+            RouteInfo::GetArchivedPrivateThreads { before, channel_id, limit } => (
+                LightMethod::Get,
+                Route::ChannelsIdThreadsArchivedPrivate(channel_id),
+                Cow::from(Route::channel_threads_archived(
+                    channel_id,
+                    "private",
+                    before,
+                    limit,
+                )),
+            ),
+            RouteInfo::GetArchivedPublicThreads { before, channel_id, limit } => (
+                LightMethod::Get,
+                Route::ChannelsIdThreadsArchivedPublic(channel_id),
+                Cow::from(Route::channel_threads_archived(
+                    channel_id,
+                    "public",
+                    before,
+                    limit,
+                )),
+            ),
             RouteInfo::GetAuditLogs {
                 action_type,
                 before,
@@ -1175,6 +1829,16 @@ impl<'a> RouteInfo<'a> {
                     limit,
                 )),
             ),
+            RouteInfo::GetAutoModRule { guild_id, rule_id } => (
+                LightMethod::Get,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_auto_moderation_rule(guild_id, rule_id)),
+            ),
+            RouteInfo::GetAutoModRules { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdAutoModerationRules(guild_id),
+                Cow::from(Route::guild_auto_moderation_rules(guild_id)),
+            ),
             RouteInfo::GetBans { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdBans(guild_id),
@@ -1215,16 +1879,36 @@ impl<'a> RouteInfo<'a> {
                 Route::UsersMe,
                 Cow::from(Route::user("@me")),
             ),
+            RouteInfo::GetDiscoveryCategories => (
+                LightMethod::Get,
+                Route::DiscoveryCategories,
+                Cow::from(Route::discovery_categories()),
+            ),
             RouteInfo::GetGateway => (
                 LightMethod::Get,
                 Route::Gateway,
                 Cow::from(Route::gateway()),
             ),
+            RouteInfo::GetGlobalApplicationCommands { application_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
             RouteInfo::GetGuild { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsId(guild_id),
                 Cow::from(Route::guild(guild_id)),
             ),
+            RouteInfo::GetGuildApplicationCommands { application_id, guild_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
+            RouteInfo::GetGuildDiscoveryMetadata { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdDiscoveryMetadata(guild_id),
+                Cow::from(Route::guild_discovery_metadata(guild_id)),
+            ),
             RouteInfo::GetGuildEmbed { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdEmbed(guild_id),
@@ -1245,6 +1929,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembers(guild_id),
                 Cow::from(Route::guild_members_optioned(guild_id, after, limit)),
             ),
+            RouteInfo::GetGuildOnboarding { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdOnboarding(guild_id),
+                Cow::from(Route::guild_onboarding(guild_id)),
+            ),
             RouteInfo::GetGuildPruneCount { days, guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdPrune(guild_id),
@@ -1260,6 +1949,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::GetGuildSticker { guild_id, sticker_id } => (
+                LightMethod::Get,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
+            RouteInfo::GetGuildStickers { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdStickers(guild_id),
+                Cow::from(Route::guild_stickers(guild_id)),
+            ),
             RouteInfo::GetGuildVanityUrl { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdVanityUrl(guild_id),
@@ -1308,6 +2007,23 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdPins(channel_id),
                 Cow::from(Route::channel_pins(channel_id)),
             ),
+            RouteInfo::GetPollAnswerVoters {
+                after,
+                answer_id,
+                channel_id,
+                limit,
+                message_id,
+            } => (
+                LightMethod::Get,
+                Route::ChannelsIdPollsIdAnswersId(channel_id),
+                Cow::from(Route::channel_poll_answer_voters(
+                    channel_id,
+                    message_id,
+                    answer_id,
+                    limit,
+                    after,
+                )),
+            ),
             RouteInfo::GetReactionUsers {
                 after,
                 channel_id,
@@ -1325,6 +2041,21 @@ impl<'a> RouteInfo<'a> {
                     after,
                 )),
             ),
+            RouteInfo::GetStageInstance { channel_id } => (
+                LightMethod::Get,
+                Route::StageInstancesId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::GetSticker { sticker_id } => (
+                LightMethod::Get,
+                Route::StickersId,
+                Cow::from(Route::sticker(sticker_id)),
+            ),
+            RouteInfo::GetThreadMembers { channel_id } => (
+                LightMethod::Get,
+                Route::ChannelsIdThreadMembers(channel_id),
+                Cow::from(Route::channel_thread_members(channel_id)),
+            ),
             RouteInfo::GetUnresolvedIncidents => (
                 LightMethod::Get,
                 Route::None,
@@ -1360,6 +2091,11 @@ impl<'a> RouteInfo<'a> {
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
+            RouteInfo::JoinThread { channel_id } => (
+                LightMethod::Put,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, "@me")),
+            ),
             RouteInfo::KickMember { guild_id, user_id } => (
                 LightMethod::Delete,
                 Route::GuildsIdMembersId(guild_id),
@@ -1375,6 +2111,11 @@ impl<'a> RouteInfo<'a> {
                 Route::UsersMeGuildsId,
                 Cow::from(Route::user_guild("@me", guild_id)),
             ),
+            RouteInfo::LeaveThread { channel_id } => (
+                LightMethod::Delete,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, "@me")),
+            ),
             RouteInfo::RemoveGroupRecipient { group_id, user_id } => (
                 LightMethod::Delete,
                 Route::None,
@@ -1395,6 +2136,21 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            RouteInfo::RemoveThreadMember { channel_id, user_id } => (
+                LightMethod::Delete,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+            ),
+            RouteInfo::SetGlobalApplicationCommands { application_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
+            RouteInfo::SetGuildApplicationCommands { application_id, guild_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
             RouteInfo::StartGuildPrune { days, guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdPrune(guild_id),
@@ -1408,6 +2164,16 @@ impl<'a> RouteInfo<'a> {
                     integration_id,
                 )),
             ),
+            RouteInfo::StartThreadWithMessage { channel_id, message_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdMessagesIdThreads(channel_id),
+                Cow::from(Route::channel_message_threads(channel_id, message_id)),
+            ),
+            RouteInfo::StartThreadWithoutMessage { channel_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdThreads(channel_id),
+                Cow::from(Route::channel_threads(channel_id)),
+            ),
             RouteInfo::StatusIncidentsUnresolved => (
                 LightMethod::Get,
                 Route::None,
@@ -1431,3 +2197,86 @@ impl<'a> RouteInfo<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::LightMethod;
+    use super::{Route, RouteInfo};
+
+    #[test]
+    fn edit_guild_mfa_level() {
+        let (method, route, url) = RouteInfo::EditGuildMfaLevel { guild_id: 81384788765712384 }
+            .deconstruct();
+
+        assert_eq!(method, LightMethod::Post);
+        assert_eq!(route, Route::GuildsIdMfa(81384788765712384));
+        assert_eq!(url, "https://discordapp.com/api/v6/guilds/81384788765712384/mfa");
+    }
+
+    #[test]
+    fn nickname_routes() {
+        let (method, route, url) = RouteInfo::EditNickname { guild_id: 1 }.deconstruct();
+
+        assert_eq!(method, LightMethod::Patch);
+        assert_eq!(route, Route::GuildsIdMembersMeNick(1));
+        assert_eq!(url, "https://discordapp.com/api/v6/guilds/1/members/@me/nick");
+
+        // Other members' nicknames are changed by editing the member.
+        let (method, route, url) = RouteInfo::EditMember { guild_id: 1, user_id: 2 }.deconstruct();
+
+        assert_eq!(method, LightMethod::Patch);
+        assert_eq!(route, Route::GuildsIdMembersId(1));
+        assert_eq!(url, "https://discordapp.com/api/v6/guilds/1/members/2");
+    }
+
+    #[test]
+    fn interaction_response_routes() {
+        let (method, route, url) = RouteInfo::EditOriginalInteractionResponse {
+            application_id: 1,
+            token: "abc",
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Patch);
+        assert_eq!(route, Route::WebhooksIdMessagesId(1));
+        assert_eq!(url, "https://discordapp.com/api/v6/webhooks/1/abc/messages/@original");
+
+        let (method, _, url) = RouteInfo::DeleteOriginalInteractionResponse {
+            application_id: 1,
+            token: "abc",
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Delete);
+        assert_eq!(url, "https://discordapp.com/api/v6/webhooks/1/abc/messages/@original");
+    }
+
+    #[test]
+    fn followup_message_routes() {
+        let (method, route, url) = RouteInfo::CreateFollowupMessage {
+            application_id: 1,
+            token: "abc",
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Post);
+        assert_eq!(route, Route::WebhooksId(1));
+        assert_eq!(url, "https://discordapp.com/api/v6/webhooks/1/abc?wait=true");
+
+        let (method, route, url) = RouteInfo::EditFollowupMessage {
+            application_id: 1,
+            message_id: 2,
+            token: "abc",
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Patch);
+        assert_eq!(route, Route::WebhooksIdMessagesId(1));
+        assert_eq!(url, "https://discordapp.com/api/v6/webhooks/1/abc/messages/2");
+
+        let (method, _, url) = RouteInfo::DeleteFollowupMessage {
+            application_id: 1,
+            message_id: 2,
+            token: "abc",
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Delete);
+        assert_eq!(url, "https://discordapp.com/api/v6/webhooks/1/abc/messages/2");
+    }
+}
@@ -0,0 +1,78 @@
+//! Deserialization of JSON payloads received from Discord.
+//!
+//! With the `simd-json` feature enabled, payloads are parsed by `simd-json`,
+//! which is considerably faster for large payloads, such as those of guilds
+//! sent with `GUILD_CREATE`s. Otherwise `serde_json` is used.
+
+use internal::prelude::*;
+use serde::de::DeserializeOwned;
+use std::io::Read;
+
+#[cfg(not(feature = "simd-json"))]
+use serde_json;
+#[cfg(feature = "simd-json")]
+use simd_json;
+
+/// Deserializes a value from JSON bytes.
+///
+/// The bytes are mutable as `simd-json` parses them in-place, leaving them in
+/// an unspecified state.
+#[cfg(feature = "simd-json")]
+pub fn from_slice<T: DeserializeOwned>(bytes: &mut [u8]) -> Result<T> {
+    simd_json::serde::from_slice(bytes).map_err(From::from)
+}
+
+/// Deserializes a value from JSON bytes.
+///
+/// The bytes are mutable as `simd-json` parses them in-place, leaving them in
+/// an unspecified state.
+#[cfg(not(feature = "simd-json"))]
+pub fn from_slice<T: DeserializeOwned>(bytes: &mut [u8]) -> Result<T> {
+    serde_json::from_slice(bytes).map_err(From::from)
+}
+
+/// Deserializes a value from a reader of JSON, such as an HTTP response.
+#[cfg(feature = "simd-json")]
+pub fn from_reader<R: Read, T: DeserializeOwned>(mut reader: R) -> Result<T> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    from_slice(&mut bytes)
+}
+
+/// Deserializes a value from a reader of JSON, such as an HTTP response.
+#[cfg(not(feature = "simd-json"))]
+pub fn from_reader<R: Read, T: DeserializeOwned>(reader: R) -> Result<T> {
+    serde_json::from_reader(reader).map_err(From::from)
+}
+
+#[cfg(test)]
+mod test {
+    use internal::prelude::*;
+    use std::collections::HashMap;
+    use super::{from_reader, from_slice};
+
+    #[test]
+    fn from_slice_parses_payloads() {
+        let mut bytes = br#"{"op": 11, "d": null}"#.to_vec();
+        let value: Value = from_slice(&mut bytes).unwrap();
+
+        assert_eq!(value, json!({"op": 11, "d": null}));
+    }
+
+    #[test]
+    fn from_reader_parses_payloads() {
+        let reader: &[u8] = br#"{"code": "serenity"}"#;
+        let map: HashMap<String, String> = from_reader(reader).unwrap();
+
+        assert_eq!(map.get("code").map(|c| &c[..]), Some("serenity"));
+    }
+
+    #[test]
+    fn invalid_payloads_error() {
+        let mut bytes = b"{\"op\": ".to_vec();
+
+        assert!(from_slice::<Value>(&mut bytes).is_err());
+        assert!(from_reader::<_, Value>(&b"[1, 2"[..]).is_err());
+    }
+}
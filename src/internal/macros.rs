@@ -1,18 +1,5 @@
 //! A set of macros for easily working with internals.
 
-#[cfg(feature = "model")]
-macro_rules! request_client {
-    () => {{
-        use hyper::net::HttpsConnector;
-        use hyper_native_tls::NativeTlsClient;
-
-        let tc = NativeTlsClient::new()?;
-        let connector = HttpsConnector::new(tc);
-
-        HyperClient::with_connector(connector)
-    }}
-}
-
 #[cfg(any(feature = "model", feature = "utils"))]
 macro_rules! cdn {
     ($e:expr) => {
@@ -3,6 +3,9 @@ pub mod macros;
 
 pub mod prelude;
 
+#[cfg(any(feature = "gateway", feature = "http"))]
+pub mod json;
+
 mod rwlock_ext;
 
 pub use self::rwlock_ext::RwLockExt;
@@ -1,7 +1,9 @@
 use flate2::read::ZlibDecoder;
 use gateway::GatewayError;
+use internal::json;
 use internal::prelude::*;
 use serde_json;
+use std::io::Read;
 use websocket::{
     message::OwnedMessage,
     sync::stream::{TcpStream, TlsStream},
@@ -10,6 +12,9 @@ use websocket::{
 
 pub trait ReceiverExt {
     fn recv_json(&mut self) -> Result<Option<Value>>;
+
+    /// Receives the raw JSON of a payload, decompressing it if necessary.
+    fn recv_raw_json(&mut self) -> Result<Option<Vec<u8>>>;
 }
 
 pub trait SenderExt {
@@ -18,28 +23,32 @@ pub trait SenderExt {
 
 impl ReceiverExt for WsClient<TlsStream<TcpStream>> {
     fn recv_json(&mut self) -> Result<Option<Value>> {
+        let mut payload = match self.recv_raw_json()? {
+            Some(payload) => payload,
+            None => return Ok(None),
+        };
+
+        json::from_slice(&mut payload).map(Some).map_err(|why| {
+            warn!(
+                "Err deserializing payload: {:?}; payload: {}",
+                why,
+                String::from_utf8_lossy(&payload),
+            );
+
+            why
+        })
+    }
+
+    fn recv_raw_json(&mut self) -> Result<Option<Vec<u8>>> {
         Ok(match self.recv_message()? {
             OwnedMessage::Binary(bytes) => {
-                serde_json::from_reader(ZlibDecoder::new(&bytes[..]))
-                    .map(Some)
-                    .map_err(|why| {
-                        warn!("Err deserializing bytes: {:?}; bytes: {:?}", why, bytes);
+                let mut decompressed = Vec::new();
+                ZlibDecoder::new(&bytes[..]).read_to_end(&mut decompressed)?;
 
-                        why
-                    })?
+                Some(decompressed)
             },
             OwnedMessage::Close(data) => return Err(Error::Gateway(GatewayError::Closed(data))),
-            OwnedMessage::Text(payload) => {
-                serde_json::from_str(&payload).map(Some).map_err(|why| {
-                    warn!(
-                        "Err deserializing text: {:?}; text: {}",
-                        why,
-                        payload,
-                    );
-
-                    why
-                })?
-            },
+            OwnedMessage::Text(payload) => Some(payload.into_bytes()),
             OwnedMessage::Ping(x) => {
                 self.send_message(&OwnedMessage::Pong(x))
                     .map_err(Error::from)?;
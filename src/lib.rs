@@ -123,7 +123,6 @@ extern crate serde_json;
 #[macro_use]
 extern crate lazy_static;
 
-extern crate chrono;
 extern crate parking_lot;
 extern crate serde;
 
@@ -131,6 +130,8 @@ extern crate serde;
 extern crate base64;
 #[cfg(feature = "byteorder")]
 extern crate byteorder;
+#[cfg(feature = "chrono")]
+extern crate chrono;
 #[cfg(feature = "flate2")]
 extern crate flate2;
 #[cfg(feature = "hyper")]
@@ -143,10 +144,14 @@ extern crate multipart;
 extern crate native_tls;
 #[cfg(feature = "opus")]
 extern crate opus;
+#[cfg(feature = "simd-json")]
+extern crate simd_json;
 #[cfg(feature = "sodiumoxide")]
 extern crate sodiumoxide;
 #[cfg(feature = "threadpool")]
 extern crate threadpool;
+#[cfg(feature = "time")]
+extern crate time;
 #[cfg(feature = "typemap")]
 extern crate typemap;
 #[cfg(feature = "evzht9h3nznqzwl")]
@@ -170,6 +175,8 @@ pub mod builder;
 pub mod cache;
 #[cfg(feature = "client")]
 pub mod client;
+#[cfg(feature = "collector")]
+pub mod collector;
 #[cfg(feature = "framework")]
 pub mod framework;
 #[cfg(feature = "gateway")]
@@ -1,11 +1,15 @@
 //! Models about OAuth2 applications.
 
+use std::collections::HashSet;
 use super::{
-    id::UserId,
+    id::{TeamId, UserId},
     user::User,
     utils::default_true
 };
 
+#[cfg(feature = "model")]
+use super::misc::{cdn_image_url, ImageFormat};
+
 /// Information about a user's application. An application does not necessarily
 /// have an associated bot user.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -48,6 +52,29 @@ pub struct ApplicationInfo {
     pub secret: String,
 }
 
+#[cfg(feature = "model")]
+impl ApplicationInfo {
+    /// Returns the formatted URL of the application's icon, if one exists.
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| format!(cdn!("/app-icons/{}/{}.webp"), self.id, icon))
+    }
+
+    /// Returns the URL of the application's icon in the given format and
+    /// size, if one exists.
+    ///
+    /// Without a `format`, the icon is retrieved as WebP. Refer to
+    /// [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn icon_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| cdn_image_url(&format!("/app-icons/{}", self.id), icon, format, size))
+    }
+}
+
 /// Information about an application with an application's bot user.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BotApplication {
@@ -83,4 +110,147 @@ pub struct CurrentApplicationInfo {
     pub name: String,
     pub owner: User,
     #[serde(default)] pub rpc_origins: Vec<String>,
+    /// The team owning the application, if it belongs to one.
+    ///
+    /// The [`owner`] of a team-owned application is a placeholder user.
+    ///
+    /// [`owner`]: #structfield.owner
+    #[serde(default)] pub team: Option<Team>,
+}
+
+impl CurrentApplicationInfo {
+    /// Returns the formatted URL of the application's icon, if one exists.
+    #[cfg(feature = "model")]
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| format!(cdn!("/app-icons/{}/{}.webp"), self.id, icon))
+    }
+
+    /// Returns the URL of the application's icon in the given format and
+    /// size, if one exists.
+    ///
+    /// Without a `format`, the icon is retrieved as WebP. Refer to
+    /// [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    #[cfg(feature = "model")]
+    pub fn icon_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| cdn_image_url(&format!("/app-icons/{}", self.id), icon, format, size))
+    }
+
+    /// Retrieves the Ids of the users owning the application.
+    ///
+    /// If the application belongs to a [`Team`], this is the team's owner, as
+    /// well as all members that have accepted their invitation if
+    /// `team_members` is `true`. Otherwise, this is the [`owner`].
+    ///
+    /// [`Team`]: struct.Team.html
+    /// [`owner`]: #structfield.owner
+    pub fn owner_ids(&self, team_members: bool) -> HashSet<UserId> {
+        let mut owners = HashSet::new();
+
+        match self.team {
+            Some(ref team) => {
+                owners.insert(team.owner_user_id);
+
+                if team_members {
+                    owners.extend(team.members
+                        .iter()
+                        .filter(|member| member.membership_state == MembershipState::Accepted)
+                        .map(|member| member.user.id));
+                }
+            },
+            None => {
+                owners.insert(self.owner.id);
+            },
+        }
+
+        owners
+    }
+}
+
+/// A team of users owning an application.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Team {
+    /// A hash of the team's icon.
+    pub icon: Option<String>,
+    /// The unique Id of the team.
+    pub id: TeamId,
+    /// The members of the team, including invited users.
+    pub members: Vec<TeamMember>,
+    /// The name of the team.
+    #[serde(default)]
+    pub name: String,
+    /// The Id of the user owning the team.
+    pub owner_user_id: UserId,
+}
+
+#[cfg(feature = "model")]
+impl Team {
+    /// Returns the formatted URL of the team's icon, if one exists.
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| format!(cdn!("/team-icons/{}/{}.webp"), self.id, icon))
+    }
+
+    /// Returns the URL of the team's icon in the given format and size, if one
+    /// exists.
+    ///
+    /// Without a `format`, the icon is retrieved as WebP. Refer to
+    /// [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn icon_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| cdn_image_url(&format!("/team-icons/{}", self.id), icon, format, size))
+    }
+}
+
+/// A member of an application's [`Team`].
+///
+/// [`Team`]: struct.Team.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamMember {
+    /// Whether the user has accepted the invitation to the team.
+    pub membership_state: MembershipState,
+    /// The permissions of the member within the team.
+    ///
+    /// This is always `["*"]`.
+    pub permissions: Vec<String>,
+    /// The Id of the team the user is a member of.
+    pub team_id: TeamId,
+    /// The user that is a member of the team.
+    pub user: User,
+}
+
+/// The state of a user's invitation to a [`Team`].
+///
+/// [`Team`]: struct.Team.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum MembershipState {
+    /// The user was invited, but has not accepted yet.
+    Invited = 1,
+    /// The user has accepted the invitation.
+    Accepted = 2,
+}
+
+enum_number!(
+    MembershipState {
+        Invited,
+        Accepted,
+    }
+);
+
+impl MembershipState {
+    pub fn num(&self) -> u64 {
+        match *self {
+            MembershipState::Invited => 1,
+            MembershipState::Accepted => 2,
+        }
+    }
 }
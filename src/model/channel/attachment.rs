@@ -1,9 +1,7 @@
 #[cfg(feature = "model")]
-use hyper::Client as HyperClient;
+use http::cdn;
 #[cfg(feature = "model")]
 use internal::prelude::*;
-#[cfg(feature = "model")]
-use std::io::Read;
 
 /// A file uploaded with a message. Not to be confused with [`Embed`]s.
 ///
@@ -12,6 +10,8 @@ use std::io::Read;
 pub struct Attachment {
     /// The unique ID given to this attachment.
     pub id: String,
+    /// The description of the file, which is its alt text, if one was set.
+    pub description: Option<String>,
     /// The filename of the file that was uploaded. This is equivalent to what
     /// the uploader had their file named.
     pub filename: String,
@@ -101,18 +101,12 @@ impl Attachment {
     /// of the HTTP response.
     ///
     /// Returns an [`Error::Hyper`] when there is a problem retrieving the
-    /// attachment.
+    /// attachment, and an [`HttpError::UnsuccessfulRequest`] if the CDN
+    /// responded with an error.
     ///
     /// [`Error::Hyper`]: ../../enum.Error.html#variant.Hyper
     /// [`Error::Io`]: ../../enum.Error.html#variant.Io
+    /// [`HttpError::UnsuccessfulRequest`]: ../../http/enum.HttpError.html#variant.UnsuccessfulRequest
     /// [`Message`]: struct.Message.html
-    pub fn download(&self) -> Result<Vec<u8>> {
-        let hyper = request_client!();
-        let mut response = hyper.get(&self.url).send()?;
-
-        let mut bytes = vec![];
-        response.read_to_end(&mut bytes)?;
-
-        Ok(bytes)
-    }
+    pub fn download(&self) -> Result<Vec<u8>> { cdn::download(&self.url) }
 }
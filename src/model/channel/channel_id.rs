@@ -8,12 +8,18 @@ use std::fmt::Write as FmtWrite;
 #[cfg(feature = "model")]
 use builder::{
     CreateMessage,
+    CreateStageInstance,
+    CreateThread,
     EditChannel,
     EditMessage,
     GetMessages
 };
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
+#[cfg(feature = "collector")]
+use collector::{CollectReaction, CollectReply, ReactionAction};
+#[cfg(feature = "model")]
+use constants;
 #[cfg(feature = "model")]
 use http::{self, AttachmentType};
 #[cfg(feature = "model")]
@@ -21,11 +27,108 @@ use utils;
 
 #[cfg(feature = "model")]
 impl ChannelId {
+    /// Adds a member to the thread.
+    ///
+    /// **Note**: Requires the ability to send messages in the thread, and the
+    /// thread must not be archived.
+    #[inline]
+    pub fn add_thread_member<U: Into<UserId>>(&self, user_id: U) -> Result<()> {
+        http::add_thread_member(self.0, user_id.into().0)
+    }
+
+    /// Gets the archived private threads of the channel, most recently
+    /// archived first.
+    ///
+    /// Pass the [`archive_timestamp`] of the last thread retrieved as `before`
+    /// to retrieve the next page.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// [`archive_timestamp`]: ../channel/struct.ThreadMetadata.html#structfield.archive_timestamp
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    pub fn archived_private_threads(&self, before: Option<Timestamp>, limit: Option<u64>)
+        -> Result<ThreadsData> {
+        let before = before.map(|x| threads_query_timestamp(&x));
+
+        http::get_archived_private_threads(self.0, before.as_ref().map(|x| &x[..]), limit)
+    }
+
+    /// Gets the archived public threads of the channel, most recently archived
+    /// first.
+    ///
+    /// Pass the [`archive_timestamp`] of the last thread retrieved as `before`
+    /// to retrieve the next page.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [`archive_timestamp`]: ../channel/struct.ThreadMetadata.html#structfield.archive_timestamp
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub fn archived_public_threads(&self, before: Option<Timestamp>, limit: Option<u64>)
+        -> Result<ThreadsData> {
+        let before = before.map(|x| threads_query_timestamp(&x));
+
+        http::get_archived_public_threads(self.0, before.as_ref().map(|x| &x[..]), limit)
+    }
+
+    /// Blocks the current thread until a reaction is added to any message in
+    /// the channel, returning it.
+    ///
+    /// Refer to [`CollectReaction`] for the conditions the reaction can be
+    /// restricted by, and to [`Message::await_reaction`] for waiting on a
+    /// single message. Returns `None` if no fitting reaction was received
+    /// before the timeout elapsed.
+    ///
+    /// **Note**: Requires the `collector` feature.
+    ///
+    /// [`CollectReaction`]: ../../collector/struct.CollectReaction.html
+    /// [`Message::await_reaction`]: ../channel/struct.Message.html#method.await_reaction
+    #[cfg(feature = "collector")]
+    pub fn await_reaction<F>(&self, f: F) -> Option<ReactionAction>
+        where F: FnOnce(CollectReaction) -> CollectReaction {
+        f(CollectReaction::default().channel_id(*self)).wait()
+    }
+
+    /// Blocks the current thread until a [`Message`] is sent in the channel,
+    /// returning it.
+    ///
+    /// Refer to [`CollectReply`] for the conditions the reply can be restricted
+    /// by. Returns `None` if no fitting message was sent before the timeout
+    /// elapsed.
+    ///
+    /// **Note**: Requires the `collector` feature.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 30 seconds for the author of a message to answer:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// # fn prompt(msg: &Message) {
+    /// use std::time::Duration;
+    ///
+    /// if let Some(answer) = msg.channel_id.await_reply(|r| r
+    ///     .author_id(msg.author.id)
+    ///     .timeout(Duration::from_secs(30))) {
+    ///     println!("{} answered {}", msg.author.name, answer.content);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`CollectReply`]: ../../collector/struct.CollectReply.html
+    /// [`Message`]: ../channel/struct.Message.html
+    #[cfg(feature = "collector")]
+    pub fn await_reply<F>(&self, f: F) -> Option<Message>
+        where F: FnOnce(CollectReply) -> CollectReply {
+        f(CollectReply::default().channel_id(*self)).wait()
+    }
+
     /// Broadcasts that the current user is typing to a channel for the next 5
     /// seconds.
     ///
     /// After 5 seconds, another request must be made to continue broadcasting
-    /// that the current user is typing.
+    /// that the current user is typing. Use [`start_typing`] to keep
+    /// broadcasting until the returned guard is dropped.
     ///
     /// This should rarely be used for bots, and should likely only be used for
     /// signifying that a long-running command is still being executed.
@@ -41,6 +144,7 @@ impl ChannelId {
     /// ```
     ///
     /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    /// [`start_typing`]: #method.start_typing
     #[inline]
     pub fn broadcast_typing(&self) -> Result<()> { http::broadcast_typing(self.0) }
 
@@ -99,6 +203,58 @@ impl ChannelId {
         http::create_reaction(self.0, message_id.0, reaction_type)
     }
 
+    /// Starts a stage in the stage channel.
+    ///
+    /// Refer to [`CreateStageInstance`] for more information.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage
+    /// channel.
+    ///
+    /// [`CreateStageInstance`]: ../../builder/struct.CreateStageInstance.html
+    #[cfg(feature = "utils")]
+    pub fn create_stage_instance<F>(&self, f: F) -> Result<StageInstance>
+        where F: FnOnce(CreateStageInstance) -> CreateStageInstance {
+        let mut map = utils::vecmap_to_json_map(f(CreateStageInstance::default()).0);
+        map.insert("channel_id".to_string(), Value::Number(Number::from(self.0)));
+
+        http::create_stage_instance(&Value::Object(map))
+    }
+
+    /// Starts a thread in the channel that is not attached to a message.
+    ///
+    /// The thread is private unless set otherwise through
+    /// [`CreateThread::kind`]. Refer to [`CreateThread`] for more information.
+    ///
+    /// [`CreateThread`]: ../../builder/struct.CreateThread.html
+    /// [`CreateThread::kind`]: ../../builder/struct.CreateThread.html#method.kind
+    #[cfg(feature = "utils")]
+    pub fn create_thread<F>(&self, f: F) -> Result<GuildChannel>
+        where F: FnOnce(CreateThread) -> CreateThread {
+        let map = utils::vecmap_to_json_map(f(CreateThread::default()).0);
+
+        http::start_thread_without_message(self.0, &Value::Object(map))
+    }
+
+    /// Starts a public thread from a [`Message`] in the channel, given its Id.
+    ///
+    /// Refer to [`CreateThread`] for more information.
+    ///
+    /// [`CreateThread`]: ../../builder/struct.CreateThread.html
+    /// [`Message`]: ../channel/struct.Message.html
+    #[cfg(feature = "utils")]
+    #[inline]
+    pub fn create_thread_from_message<F, M>(&self, message_id: M, f: F) -> Result<GuildChannel>
+        where F: FnOnce(CreateThread) -> CreateThread, M: Into<MessageId> {
+        self._create_thread_from_message(message_id.into(), f)
+    }
+
+    fn _create_thread_from_message<F>(self, message_id: MessageId, f: F) -> Result<GuildChannel>
+        where F: FnOnce(CreateThread) -> CreateThread {
+        let map = utils::vecmap_to_json_map(f(CreateThread::default()).0);
+
+        http::start_thread_with_message(self.0, message_id.0, &Value::Object(map))
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     #[inline]
     pub fn delete(&self) -> Result<Channel> { http::delete_channel(self.0) }
@@ -149,6 +305,100 @@ impl ChannelId {
         self._delete_messages(&ids)
     }
 
+    /// Deletes any number of messages by their Ids, splitting them into as few
+    /// bulk deletes as possible.
+    ///
+    /// Messages older than two weeks can not be bulk deleted, so they are
+    /// either deleted one by one or skipped, as determined by `old`. This
+    /// includes messages less than a minute away from that age. Duplicate
+    /// Ids are removed, and a single remaining message is deleted on its own,
+    /// as a bulk delete requires at least two.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// # Examples
+    ///
+    /// Deleting the last 250 messages of a channel, skipping old ones:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// #
+    /// # fn run(channel_id: ChannelId) -> serenity::Result<()> {
+    /// use serenity::model::channel::OldMessages;
+    ///
+    /// let mut ids = Vec::new();
+    ///
+    /// for _ in 0..3 {
+    ///     let messages = channel_id.messages(|g| match ids.last() {
+    ///         Some(&id) => g.before(id).limit(100),
+    ///         None => g.limit(100),
+    ///     })?;
+    ///
+    ///     ids.extend(messages.iter().map(|message| message.id));
+    /// }
+    ///
+    /// ids.truncate(250);
+    ///
+    /// let summary = channel_id.delete_messages_chunked(ids, OldMessages::Skip)?;
+    ///
+    /// println!("Deleted {}, skipped {}", summary.deleted.len(), summary.skipped.len());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered, after which no further messages
+    /// are deleted. Messages deleted until then stay deleted.
+    ///
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    pub fn delete_messages_chunked<T, It>(&self, message_ids: It, old: OldMessages)
+        -> Result<DeletedMessages> where T: AsRef<MessageId>, It: IntoIterator<Item=T> {
+        let mut ids = message_ids
+            .into_iter()
+            .map(|message_id| *message_id.as_ref())
+            .collect::<Vec<MessageId>>();
+        ids.sort();
+        ids.dedup();
+
+        self._delete_messages_chunked(ids, old)
+    }
+
+    fn _delete_messages_chunked(self, ids: Vec<MessageId>, old: OldMessages)
+        -> Result<DeletedMessages> {
+        let (recent, expired) = split_by_age(ids, Timestamp::now().unix_timestamp());
+
+        let mut summary = DeletedMessages::default();
+
+        match old {
+            OldMessages::Delete => {
+                for id in expired {
+                    self.delete_message(id)?;
+                    summary.deleted.push(id);
+                }
+            },
+            OldMessages::Skip => summary.skipped = expired,
+        }
+
+        for request in delete_requests(&recent) {
+            match request {
+                DeleteRequest::Single(id) => {
+                    self.delete_message(id)?;
+                    summary.deleted.push(id);
+                },
+                DeleteRequest::Bulk(chunk) => {
+                    let ids = chunk.iter().map(|id| id.0).collect::<Vec<u64>>();
+                    let map = json!({ "messages": ids });
+
+                    http::delete_messages(self.0, &map)?;
+                    summary.deleted.extend_from_slice(chunk);
+                },
+            }
+        }
+
+        Ok(summary)
+    }
+
     fn _delete_messages(self, ids: &[u64]) -> Result<()> {
         let len = ids.len();
 
@@ -214,6 +464,13 @@ impl ChannelId {
     }
 
 
+    /// Deletes the stage instance of the stage channel, ending the stage.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage
+    /// channel.
+    #[inline]
+    pub fn delete_stage_instance(&self) -> Result<()> { http::delete_stage_instance(self.0) }
+
     /// Edits the settings of a [`Channel`], optionally setting new values.
     ///
     /// Refer to `EditChannel`'s documentation for its methods.
@@ -283,6 +540,34 @@ impl ChannelId {
         http::edit_message(self.0, message_id.0, &Value::Object(map))
     }
 
+    /// Edits the topic or privacy level of the stage instance of the stage
+    /// channel.
+    ///
+    /// Refer to [`CreateStageInstance`] for more information.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage
+    /// channel.
+    ///
+    /// [`CreateStageInstance`]: ../../builder/struct.CreateStageInstance.html
+    #[cfg(feature = "utils")]
+    pub fn edit_stage_instance<F>(&self, f: F) -> Result<StageInstance>
+        where F: FnOnce(CreateStageInstance) -> CreateStageInstance {
+        let map = utils::vecmap_to_json_map(f(CreateStageInstance::default()).0);
+
+        http::edit_stage_instance(self.0, &Value::Object(map))
+    }
+
+    /// Ends the poll of a [`Message`] given its Id immediately, returning
+    /// the message with the final results of the poll.
+    ///
+    /// **Note**: Requires that the current user be the author of the message.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    #[inline]
+    pub fn end_poll<M: Into<MessageId>>(&self, message_id: M) -> Result<Message> {
+        http::end_poll(self.0, message_id.into().0)
+    }
+
     /// Search the cache for the channel with the Id.
     #[cfg(feature = "cache")]
     #[deprecated(since = "0.5.8", note = "Use the `to_channel_cached`-method instead.")]
@@ -329,6 +614,14 @@ impl ChannelId {
     #[inline]
     pub fn invites(&self) -> Result<Vec<RichInvite>> { http::get_channel_invites(self.0) }
 
+    /// Adds the current user to the thread.
+    #[inline]
+    pub fn join_thread(&self) -> Result<()> { http::join_thread(self.0) }
+
+    /// Removes the current user from the thread.
+    #[inline]
+    pub fn leave_thread(&self) -> Result<()> { http::leave_thread(self.0) }
+
     /// Gets a message from the channel.
     ///
     /// Requires the [Read Message History] permission.
@@ -409,21 +702,98 @@ impl ChannelId {
 
     /// Pins a [`Message`] to the channel.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::PinLimitReached`] if the channel already has
+    /// the maximum of 50 pinned messages.
+    ///
     /// [`Message`]: ../channel/struct.Message.html
+    /// [`ModelError::PinLimitReached`]: ../error/enum.Error.html#variant.PinLimitReached
     #[inline]
     pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
         self._pin(message_id.into())
     }
 
     fn _pin(self, message_id: MessageId) -> Result<()> {
-        http::pin_message(self.0, message_id.0)
+        match http::pin_message(self.0, message_id.0) {
+            Err(Error::Http(ref why)) if why.is_pin_limit_reached() => {
+                Err(Error::Model(ModelError::PinLimitReached))
+            },
+            other => other,
+        }
     }
 
     /// Gets the list of [`Message`]s which are pinned to the channel.
     ///
+    /// If the `cache` feature is enabled and [`Settings::cache_pins`] is set,
+    /// the pins are kept in the cache until they change.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [`Settings::cache_pins`]: ../../cache/struct.Settings.html#structfield.cache_pins
+    pub fn pins(&self) -> Result<Vec<Message>> {
+        #[cfg(feature = "cache")]
+        {
+            let cache = CACHE.read();
+
+            if cache.settings().cache_pins {
+                if let Some(pins) = cache.pins.get(self) {
+                    return Ok(pins.clone());
+                }
+            }
+        }
+
+        let pins = http::get_pins(self.0)?;
+
+        #[cfg(feature = "cache")]
+        {
+            let mut cache = CACHE.write();
+
+            if cache.settings().cache_pins {
+                cache.pins.insert(*self, pins.clone());
+            }
+        }
+
+        Ok(pins)
+    }
+
+    /// Gets the list of [`User`]s who have voted for an answer of the poll of
+    /// a [`Message`].
+    ///
+    /// Refer to [`Message::poll_answer_voters`] for more information.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
     /// [`Message`]: ../channel/struct.Message.html
+    /// [`Message::poll_answer_voters`]: ../channel/struct.Message.html#method.poll_answer_voters
+    /// [`User`]: ../user/struct.User.html
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
     #[inline]
-    pub fn pins(&self) -> Result<Vec<Message>> { http::get_pins(self.0) }
+    pub fn poll_answer_voters<M, U>(&self,
+        message_id: M,
+        answer_id: u64,
+        limit: Option<u8>,
+        after: U,
+    ) -> Result<Vec<User>> where M: Into<MessageId>, U: Into<Option<UserId>> {
+        self._poll_answer_voters(message_id.into(), answer_id, limit, after.into())
+    }
+
+    fn _poll_answer_voters(
+        self,
+        message_id: MessageId,
+        answer_id: u64,
+        limit: Option<u8>,
+        after: Option<UserId>,
+    ) -> Result<Vec<User>> {
+        let limit = limit.map_or(25, |x| if x > 100 { 100 } else { x });
+
+        http::get_poll_answer_voters(
+            self.0,
+            message_id.0,
+            answer_id,
+            limit,
+            after.map(|x| x.0),
+        )
+    }
 
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
     /// certain [`Emoji`].
@@ -471,6 +841,17 @@ impl ChannelId {
         )
     }
 
+    /// Removes a member from the thread.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission, unless the current
+    /// user created the private thread.
+    ///
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    #[inline]
+    pub fn remove_thread_member<U: Into<UserId>>(&self, user_id: U) -> Result<()> {
+        http::remove_thread_member(self.0, user_id.into().0)
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// # Errors
@@ -545,7 +926,7 @@ impl ChannelId {
     #[cfg(feature = "utils")]
     pub fn send_files<'a, F, T, It: IntoIterator<Item=T>>(&self, files: It, f: F) -> Result<Message>
         where F: FnOnce(CreateMessage) -> CreateMessage, T: Into<AttachmentType<'a>> {
-        let mut msg = f(CreateMessage::default());
+        let msg = f(CreateMessage::default());
 
         if let Some(content) = msg.0.get(&"content") {
             if let Value::String(ref content) = *content {
@@ -555,12 +936,17 @@ impl ChannelId {
             }
         }
 
-        if let Some(e) = msg.0.remove(&"embed") {
-            msg.0.insert("payload_json", json!({ "embed": e }));
+        let map = utils::vecmap_to_json_map(msg.0);
+        let message = http::send_files(self.0, files, map)?;
+
+        #[cfg(feature = "scheduler")]
+        {
+            if let Some(delay) = msg.2 {
+                message.delete_after(delay);
+            }
         }
 
-        let map = utils::vecmap_to_json_map(msg.0);
-        http::send_files(self.0, files, map)
+        Ok(message)
     }
 
     /// Sends a message to the channel.
@@ -583,13 +969,58 @@ impl ChannelId {
     /// [`CreateMessage`]: ../../builder/struct.CreateMessage.html
     /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
     #[cfg(feature = "utils")]
+    #[inline]
     pub fn send_message<F>(&self, f: F) -> Result<Message>
         where F: FnOnce(CreateMessage) -> CreateMessage {
-        let msg = f(CreateMessage::default());
+        self._send_message(f(CreateMessage::default()), false)
+    }
+
+    /// Sends a message to the channel like [`send_message`], but first checks
+    /// each text of its embed against Discord's limits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// #
+    /// use serenity::builder::EmbedValidationError;
+    /// use serenity::model::ModelError;
+    /// use serenity::Error;
+    ///
+    /// let sent = ChannelId(7).send_message_validated(|m| m
+    ///     .embed(|e| e.title("a".repeat(300))));
+    ///
+    /// if let Err(Error::Model(ModelError::InvalidEmbed(why))) = sent {
+    ///     assert_eq!(why, EmbedValidationError::Title(44));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidEmbed`] with the first part of the embed
+    /// that exceeds its limit, without making a request. Otherwise, the same
+    /// errors as [`send_message`] are returned.
+    ///
+    /// [`ModelError::InvalidEmbed`]: ../error/enum.Error.html#variant.InvalidEmbed
+    /// [`send_message`]: #method.send_message
+    #[cfg(feature = "utils")]
+    #[inline]
+    pub fn send_message_validated<F>(&self, f: F) -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage {
+        self._send_message(f(CreateMessage::default()), true)
+    }
+
+    #[cfg(feature = "utils")]
+    fn _send_message(&self, msg: CreateMessage, validate_embed: bool) -> Result<Message> {
         let map = utils::vecmap_to_json_map(msg.0);
 
         Message::check_content_length(&map)?;
-        Message::check_embed_length(&map)?;
+
+        if validate_embed {
+            Message::check_embed_limits(&map)?;
+        } else {
+            Message::check_embed_length(&map)?;
+        }
 
         let message = http::send_message(self.0, &Value::Object(map))?;
 
@@ -599,9 +1030,48 @@ impl ChannelId {
             }
         }
 
+        #[cfg(feature = "scheduler")]
+        {
+            if let Some(delay) = msg.2 {
+                message.delete_after(delay);
+            }
+        }
+
         Ok(message)
     }
 
+    /// Gets the stage instance of the stage channel, if a stage is live.
+    #[inline]
+    pub fn stage_instance(&self) -> Result<StageInstance> { http::get_stage_instance(self.0) }
+
+    /// Starts broadcasting that the current user is typing in the channel,
+    /// until the returned [`Typing`] guard is stopped or dropped.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Examples
+    ///
+    /// Keeping the indicator up while processing a long-running command:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let typing = ChannelId(7).start_typing();
+    ///
+    /// // Do something that takes a while.
+    ///
+    /// typing.stop();
+    /// ```
+    ///
+    /// [`Typing`]: struct.Typing.html
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
+    pub fn start_typing(&self) -> Typing { Typing::start(*self) }
+
+    /// Gets the members of the thread.
+    #[inline]
+    pub fn thread_members(&self) -> Result<Vec<ThreadMember>> { http::get_thread_members(self.0) }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
@@ -617,6 +1087,28 @@ impl ChannelId {
         http::unpin_message(self.0, message_id.0)
     }
 
+    /// Unpins all [`Message`]s in the channel, returning the messages that
+    /// were pinned, e.g. to archive them elsewhere.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered, after which no further messages
+    /// are unpinned.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    pub fn unpin_all(&self) -> Result<Vec<Message>> {
+        let pins = http::get_pins(self.0)?;
+
+        for message in &pins {
+            self.unpin(message.id)?;
+        }
+
+        Ok(pins)
+    }
+
     /// Retrieves the channel's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -626,6 +1118,73 @@ impl ChannelId {
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { http::get_channel_webhooks(self.0) }
 }
 
+/// Formats a timestamp for the query of the archived threads routes, escaping
+/// the `+` of its offset so that it is not read as a space.
+#[cfg(feature = "model")]
+fn threads_query_timestamp(timestamp: &Timestamp) -> String {
+    timestamp.to_rfc3339().replace('+', "%2B")
+}
+
+/// What [`ChannelId::delete_messages_chunked`] does with messages that are too
+/// old to be bulk deleted.
+///
+/// [`ChannelId::delete_messages_chunked`]: ../id/struct.ChannelId.html#method.delete_messages_chunked
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OldMessages {
+    /// Delete the messages one by one, each with a request of its own.
+    Delete,
+    /// Leave the messages alone.
+    Skip,
+}
+
+/// How many seconds before the bulk delete age limit messages are already
+/// considered too old, as the request might only arrive a while later.
+#[cfg(feature = "model")]
+const BULK_DELETE_AGE_MARGIN: i64 = 60;
+
+/// Splits the Ids into the messages that can be bulk deleted at the given
+/// Unix timestamp, and those that are too old for that.
+#[cfg(feature = "model")]
+fn split_by_age(ids: Vec<MessageId>, now: i64) -> (Vec<MessageId>, Vec<MessageId>) {
+    let cutoff = now - constants::BULK_DELETE_MAX_AGE + BULK_DELETE_AGE_MARGIN;
+
+    ids.into_iter().partition(|id| id.created_at().unix_timestamp() > cutoff)
+}
+
+/// A request deleting some of the messages handled by
+/// [`ChannelId::delete_messages_chunked`].
+///
+/// [`ChannelId::delete_messages_chunked`]: ../id/struct.ChannelId.html#method.delete_messages_chunked
+#[cfg(feature = "model")]
+#[derive(Debug, Eq, PartialEq)]
+enum DeleteRequest<'a> {
+    /// A single message, which the bulk delete route does not accept.
+    Single(MessageId),
+    Bulk(&'a [MessageId]),
+}
+
+#[cfg(feature = "model")]
+fn delete_requests(ids: &[MessageId]) -> Vec<DeleteRequest> {
+    ids.chunks(constants::BULK_DELETE_LIMIT)
+        .map(|chunk| if chunk.len() == 1 {
+            DeleteRequest::Single(chunk[0])
+        } else {
+            DeleteRequest::Bulk(chunk)
+        })
+        .collect()
+}
+
+/// The messages handled by [`ChannelId::delete_messages_chunked`].
+///
+/// [`ChannelId::delete_messages_chunked`]: ../id/struct.ChannelId.html#method.delete_messages_chunked
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DeletedMessages {
+    /// The Ids of the messages that were deleted.
+    pub deleted: Vec<MessageId>,
+    /// The Ids of the messages that were skipped as they were too old.
+    pub skipped: Vec<MessageId>,
+}
+
 impl From<Channel> for ChannelId {
     /// Gets the Id of a `Channel`.
     fn from(channel: Channel) -> ChannelId {
@@ -668,3 +1227,59 @@ impl<'a> From<&'a GuildChannel> for ChannelId {
     /// Gets the Id of a guild channel.
     fn from(public_channel: &GuildChannel) -> ChannelId { public_channel.id }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use model::id::MessageId;
+    use super::{delete_requests, split_by_age, DeleteRequest};
+
+    const NOW: i64 = 1_600_000_000;
+    const DAY: i64 = 86_400;
+
+    /// Creates a message Id as if the message was sent at the Unix timestamp.
+    fn sent_at(secs: i64) -> MessageId {
+        MessageId(((secs * 1000) as u64 - 1_420_070_400_000) << 22)
+    }
+
+    fn ids(amount: u64) -> Vec<MessageId> {
+        (1..=amount).map(|id| MessageId(sent_at(NOW).0 + id)).collect()
+    }
+
+    #[test]
+    fn chunks_by_limit() {
+        let ids = ids(250);
+        let requests = delete_requests(&ids);
+
+        assert_eq!(requests, vec![
+            DeleteRequest::Bulk(&ids[..100]),
+            DeleteRequest::Bulk(&ids[100..200]),
+            DeleteRequest::Bulk(&ids[200..]),
+        ]);
+    }
+
+    #[test]
+    fn single_message_uses_single_route() {
+        let ids = ids(101);
+
+        assert_eq!(delete_requests(&ids[..1]), vec![DeleteRequest::Single(ids[0])]);
+        assert_eq!(delete_requests(&ids), vec![
+            DeleteRequest::Bulk(&ids[..100]),
+            DeleteRequest::Single(ids[100]),
+        ]);
+        assert!(delete_requests(&[]).is_empty());
+    }
+
+    #[test]
+    fn splits_by_age() {
+        let recent = sent_at(NOW - DAY);
+        let borderline = sent_at(NOW - 14 * DAY + 30);
+        let expired = sent_at(NOW - 15 * DAY);
+
+        let (bulk, old) = split_by_age(vec![expired, borderline, recent], NOW);
+
+        assert_eq!(bulk, vec![recent]);
+        // Close to the age limit, the message might be too old once the
+        // request arrives.
+        assert_eq!(old, vec![expired, borderline]);
+    }
+}
@@ -0,0 +1,436 @@
+use model::prelude::*;
+use serde::de::Error as DeError;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use serde_json;
+
+/// A row of interactive components attached to a [`Message`], of which a
+/// message can have up to 5.
+///
+/// [`Message`]: struct.Message.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ActionRow {
+    /// The type of the component, which is always [`ActionRow`].
+    ///
+    /// [`ActionRow`]: enum.ComponentType.html#variant.ActionRow
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The components of the row, which are either up to 5 buttons, a single
+    /// select menu, or a single input text of a modal.
+    pub components: Vec<Component>,
+}
+
+/// An interactive component within an [`ActionRow`].
+///
+/// [`ActionRow`]: struct.ActionRow.html
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum Component {
+    /// A button.
+    Button(Button),
+    /// A dropdown menu of options, or of users, roles or channels.
+    SelectMenu(SelectMenu),
+    /// A field of a modal to enter text into.
+    InputText(InputText),
+    /// A component of a type the library does not know yet, carrying the
+    /// component as it was received.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let v = JsonMap::deserialize(deserializer)?;
+        let kind = v
+            .get("type")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| DeError::missing_field("type"))?;
+
+        match kind {
+            2 => serde_json::from_value::<Button>(Value::Object(v))
+                .map(Component::Button)
+                .map_err(DeError::custom),
+            3 | 5 | 6 | 7 | 8 => serde_json::from_value::<SelectMenu>(Value::Object(v))
+                .map(Component::SelectMenu)
+                .map_err(DeError::custom),
+            4 => serde_json::from_value::<InputText>(Value::Object(v))
+                .map(Component::InputText)
+                .map_err(DeError::custom),
+            _ => Ok(Component::Unknown(Value::Object(v))),
+        }
+    }
+}
+
+/// A button, which sends an [`Interaction`] when clicked, unless it is a
+/// [`Link`] button.
+///
+/// [`Interaction`]: ../interaction/struct.Interaction.html
+/// [`Link`]: enum.ButtonStyle.html#variant.Link
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Button {
+    /// The type of the component, which is always [`Button`].
+    ///
+    /// [`Button`]: enum.ComponentType.html#variant.Button
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The style of the button.
+    pub style: ButtonStyle,
+    /// The text on the button, if any.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// The emoji on the button, if any.
+    #[serde(default)]
+    pub emoji: Option<ReactionType>,
+    /// The Id that the interaction of a click on the button is sent with,
+    /// unless it is a [`Link`] button.
+    ///
+    /// [`Link`]: enum.ButtonStyle.html#variant.Link
+    #[serde(default)]
+    pub custom_id: Option<String>,
+    /// The URL opened by a [`Link`] button.
+    ///
+    /// [`Link`]: enum.ButtonStyle.html#variant.Link
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Whether the button can not be clicked.
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+/// A dropdown menu, which sends an [`Interaction`] when options were
+/// selected.
+///
+/// The options are either given by the bot, or filled in by Discord with
+/// users, roles or channels, depending on the [`kind`] of the menu.
+///
+/// [`Interaction`]: ../interaction/struct.Interaction.html
+/// [`kind`]: #structfield.kind
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SelectMenu {
+    /// The type of the component, which is [`SelectMenu`] for a menu of
+    /// options given by the bot, or one of [`UserSelect`], [`RoleSelect`],
+    /// [`MentionableSelect`] and [`ChannelSelect`].
+    ///
+    /// [`ChannelSelect`]: enum.ComponentType.html#variant.ChannelSelect
+    /// [`MentionableSelect`]: enum.ComponentType.html#variant.MentionableSelect
+    /// [`RoleSelect`]: enum.ComponentType.html#variant.RoleSelect
+    /// [`SelectMenu`]: enum.ComponentType.html#variant.SelectMenu
+    /// [`UserSelect`]: enum.ComponentType.html#variant.UserSelect
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The Id that the interaction of a selection is sent with.
+    pub custom_id: String,
+    /// The options that can be selected, which are empty unless the menu is
+    /// a [`SelectMenu`].
+    ///
+    /// [`SelectMenu`]: enum.ComponentType.html#variant.SelectMenu
+    #[serde(default)]
+    pub options: Vec<SelectMenuOption>,
+    /// The types of the channels that can be selected in a
+    /// [`ChannelSelect`], which are all types if empty.
+    ///
+    /// [`ChannelSelect`]: enum.ComponentType.html#variant.ChannelSelect
+    #[serde(default)]
+    pub channel_types: Vec<ChannelType>,
+    /// The users, roles or channels selected by default, unless the menu is
+    /// a [`SelectMenu`].
+    ///
+    /// [`SelectMenu`]: enum.ComponentType.html#variant.SelectMenu
+    #[serde(default)]
+    pub default_values: Vec<SelectMenuDefaultValue>,
+    /// The text shown while no option is selected, if any.
+    #[serde(default)]
+    pub placeholder: Option<String>,
+    /// The minimum number of options that have to be selected.
+    #[serde(default)]
+    pub min_values: Option<u64>,
+    /// The maximum number of options that can be selected.
+    #[serde(default)]
+    pub max_values: Option<u64>,
+    /// Whether the menu can not be used.
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+/// An option of a [`SelectMenu`].
+///
+/// [`SelectMenu`]: struct.SelectMenu.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SelectMenuOption {
+    /// The text of the option.
+    pub label: String,
+    /// The value that the interaction of a selection of the option is sent
+    /// with.
+    pub value: String,
+    /// The description shown below the label, if any.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The emoji shown next to the label, if any.
+    #[serde(default)]
+    pub emoji: Option<ReactionType>,
+    /// Whether the option is selected by default.
+    #[serde(default)]
+    pub default: bool,
+}
+
+/// A user, role or channel selected by default in a [`SelectMenu`] filled in
+/// by Discord.
+///
+/// [`SelectMenu`]: struct.SelectMenu.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SelectMenuDefaultValue {
+    /// A user, for a [`UserSelect`] or [`MentionableSelect`].
+    ///
+    /// [`MentionableSelect`]: enum.ComponentType.html#variant.MentionableSelect
+    /// [`UserSelect`]: enum.ComponentType.html#variant.UserSelect
+    User(UserId),
+    /// A role, for a [`RoleSelect`] or [`MentionableSelect`].
+    ///
+    /// [`MentionableSelect`]: enum.ComponentType.html#variant.MentionableSelect
+    /// [`RoleSelect`]: enum.ComponentType.html#variant.RoleSelect
+    Role(RoleId),
+    /// A channel, for a [`ChannelSelect`].
+    ///
+    /// [`ChannelSelect`]: enum.ComponentType.html#variant.ChannelSelect
+    Channel(ChannelId),
+}
+
+impl<'de> Deserialize<'de> for SelectMenuDefaultValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut v = JsonMap::deserialize(deserializer)?;
+        let id = v.remove("id").ok_or_else(|| DeError::missing_field("id"))?;
+        let kind = v
+            .remove("type")
+            .ok_or_else(|| DeError::missing_field("type"))?;
+
+        match kind.as_str() {
+            Some("user") => serde_json::from_value::<UserId>(id)
+                .map(SelectMenuDefaultValue::User)
+                .map_err(DeError::custom),
+            Some("role") => serde_json::from_value::<RoleId>(id)
+                .map(SelectMenuDefaultValue::Role)
+                .map_err(DeError::custom),
+            Some("channel") => serde_json::from_value::<ChannelId>(id)
+                .map(SelectMenuDefaultValue::Channel)
+                .map_err(DeError::custom),
+            _ => Err(DeError::custom("Unknown default value type")),
+        }
+    }
+}
+
+impl Serialize for SelectMenuDefaultValue {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        let (id, kind) = match *self {
+            SelectMenuDefaultValue::User(id) => (id.0, "user"),
+            SelectMenuDefaultValue::Role(id) => (id.0, "role"),
+            SelectMenuDefaultValue::Channel(id) => (id.0, "channel"),
+        };
+
+        let mut state = serializer.serialize_struct("SelectMenuDefaultValue", 2)?;
+        state.serialize_field("id", &id)?;
+        state.serialize_field("type", kind)?;
+
+        state.end()
+    }
+}
+
+/// A field of a modal to enter text into, which is also sent back with the
+/// text that was entered when the modal is submitted.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InputText {
+    /// The type of the component, which is always [`InputText`].
+    ///
+    /// [`InputText`]: enum.ComponentType.html#variant.InputText
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The Id that the entered text is sent with when the modal is submitted.
+    pub custom_id: String,
+    /// Whether the field is a single line or a paragraph, which is absent
+    /// when the modal is submitted.
+    #[serde(default)]
+    pub style: Option<InputTextStyle>,
+    /// The text above the field, which is absent when the modal is submitted.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// The minimum number of characters that have to be entered.
+    #[serde(default)]
+    pub min_length: Option<u64>,
+    /// The maximum number of characters that can be entered.
+    #[serde(default)]
+    pub max_length: Option<u64>,
+    /// Whether text has to be entered to submit the modal.
+    #[serde(default)]
+    pub required: Option<bool>,
+    /// The text that was entered when the modal is submitted, or the text
+    /// that is filled in beforehand otherwise.
+    #[serde(default)]
+    pub value: Option<String>,
+    /// The text shown while the field is empty, if any.
+    #[serde(default)]
+    pub placeholder: Option<String>,
+}
+
+/// The type of a component.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ComponentType {
+    /// A row of other components.
+    ActionRow,
+    /// A button.
+    Button,
+    /// A dropdown menu of options given by the bot.
+    SelectMenu,
+    /// A field of a modal to enter text into.
+    InputText,
+    /// A dropdown menu of users.
+    UserSelect,
+    /// A dropdown menu of roles.
+    RoleSelect,
+    /// A dropdown menu of users and roles.
+    MentionableSelect,
+    /// A dropdown menu of channels.
+    ChannelSelect,
+    /// A component of a type the library does not know yet, carrying the
+    /// number Discord identifies the type with.
+    Unknown(u8),
+}
+
+impl ComponentType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            ComponentType::ActionRow => 1,
+            ComponentType::Button => 2,
+            ComponentType::SelectMenu => 3,
+            ComponentType::InputText => 4,
+            ComponentType::UserSelect => 5,
+            ComponentType::RoleSelect => 6,
+            ComponentType::MentionableSelect => 7,
+            ComponentType::ChannelSelect => 8,
+            ComponentType::Unknown(num) => u64::from(num),
+        }
+    }
+}
+
+impl From<u8> for ComponentType {
+    fn from(num: u8) -> ComponentType {
+        match num {
+            1 => ComponentType::ActionRow,
+            2 => ComponentType::Button,
+            3 => ComponentType::SelectMenu,
+            4 => ComponentType::InputText,
+            5 => ComponentType::UserSelect,
+            6 => ComponentType::RoleSelect,
+            7 => ComponentType::MentionableSelect,
+            8 => ComponentType::ChannelSelect,
+            num => ComponentType::Unknown(num),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ComponentType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        u8::deserialize(deserializer).map(ComponentType::from)
+    }
+}
+
+impl Serialize for ComponentType {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        serializer.serialize_u64(self.num())
+    }
+}
+
+/// The style of a [`Button`].
+///
+/// [`Button`]: struct.Button.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ButtonStyle {
+    /// A blurple button.
+    Primary = 1,
+    /// A grey button.
+    Secondary = 2,
+    /// A green button.
+    Success = 3,
+    /// A red button.
+    Danger = 4,
+    /// A grey button opening a URL, rather than sending an interaction.
+    Link = 5,
+}
+
+enum_number!(
+    ButtonStyle {
+        Primary,
+        Secondary,
+        Success,
+        Danger,
+        Link,
+    }
+);
+
+impl ButtonStyle {
+    pub fn num(&self) -> u64 {
+        match *self {
+            ButtonStyle::Primary => 1,
+            ButtonStyle::Secondary => 2,
+            ButtonStyle::Success => 3,
+            ButtonStyle::Danger => 4,
+            ButtonStyle::Link => 5,
+        }
+    }
+}
+
+/// The style of an [`InputText`].
+///
+/// [`InputText`]: struct.InputText.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum InputTextStyle {
+    /// A field for a single line of text.
+    Short = 1,
+    /// A field for multiple lines of text.
+    Paragraph = 2,
+}
+
+enum_number!(
+    InputTextStyle {
+        Short,
+        Paragraph,
+    }
+);
+
+impl InputTextStyle {
+    pub fn num(&self) -> u64 {
+        match *self {
+            InputTextStyle::Short => 1,
+            InputTextStyle::Paragraph => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn deserializes_by_type() {
+        let button = json!({"type": 2, "style": 1, "custom_id": "vote"});
+        match serde_json::from_value::<Component>(button).unwrap() {
+            Component::Button(button) => assert_eq!(button.custom_id.unwrap(), "vote"),
+            _ => panic!("expected a button"),
+        }
+
+        let input = json!({"type": 4, "custom_id": "title", "style": 1, "label": "Title"});
+        match serde_json::from_value::<Component>(input).unwrap() {
+            Component::InputText(input) => {
+                assert_eq!(input.style, Some(InputTextStyle::Short));
+                assert_eq!(input.label.unwrap(), "Title");
+                assert!(input.value.is_none());
+            },
+            _ => panic!("expected an input text"),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_types() {
+        assert!(serde_json::from_value::<Component>(json!({"type": 99})).is_err());
+        assert!(serde_json::from_value::<Component>(json!({"custom_id": "vote"})).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(feature = "model")]
 use builder::CreateEmbed;
+use model::Timestamp;
 #[cfg(feature = "model")]
 use internal::prelude::*;
 #[cfg(feature = "utils")]
@@ -56,7 +57,7 @@ pub struct Embed {
     /// Thumbnail information of the embed.
     pub thumbnail: Option<EmbedThumbnail>,
     /// Timestamp information.
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
     /// The title of the embed.
     pub title: Option<String>,
     /// The URL of the embed.
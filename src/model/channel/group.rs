@@ -1,6 +1,7 @@
-use chrono::{DateTime, FixedOffset};
 use model::prelude::*;
 
+#[cfg(feature = "model")]
+use model::misc::cdn_image_url;
 #[cfg(feature = "model")]
 use builder::{
     CreateMessage,
@@ -31,7 +32,7 @@ pub struct Group {
     /// The Id of the last message sent.
     pub last_message_id: Option<MessageId>,
     /// Timestamp of the latest pinned message.
-    pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    pub last_pin_timestamp: Option<Timestamp>,
     /// The name of the group channel.
     pub name: Option<String>,
     /// The Id of the group owner.
@@ -172,6 +173,19 @@ impl Group {
         })
     }
 
+    /// Returns the URL of the group's icon in the given format and size, if
+    /// one exists.
+    ///
+    /// Without a `format`, animated icons are retrieved as GIF and others as
+    /// WebP. Refer to [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn icon_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon.as_ref().map(|icon| {
+            cdn_image_url(&format!("/channel-icons/{}", self.channel_id), icon, format, size)
+        })
+    }
+
     /// Determines if the channel is NSFW.
     ///
     /// Refer to [`utils::is_nsfw`] for more details.
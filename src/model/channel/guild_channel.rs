@@ -1,4 +1,3 @@
-use chrono::{DateTime, FixedOffset};
 use model::prelude::*;
 
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -58,19 +57,31 @@ pub struct GuildChannel {
     /// The timestamp of the time a pin was most recently made.
     ///
     /// **Note**: This is only available for text channels.
-    pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    pub last_pin_timestamp: Option<Timestamp>,
     /// The name of the channel.
     pub name: String,
     /// Permission overwrites for [`Member`]s and for [`Role`]s.
     ///
     /// [`Member`]: ../guild/struct.Member.html
     /// [`Role`]: ../guild/struct.Role.html
+    #[serde(default)]
     pub permission_overwrites: Vec<PermissionOverwrite>,
     /// The position of the channel.
     ///
     /// The default text channel will _almost always_ have a position of `-1` or
     /// `0`.
+    ///
+    /// **Note**: Threads do not have a position.
+    #[serde(default)]
     pub position: i64,
+    /// The Id of the [`VoiceRegion`] used for the channel, or `None` if the
+    /// region is chosen automatically.
+    ///
+    /// **Note**: This is only available for voice channels.
+    ///
+    /// [`VoiceRegion`]: ../voice/struct.VoiceRegion.html
+    #[serde(default)]
+    pub rtc_region: Option<String>,
     /// The topic of the channel.
     ///
     /// **Note**: This is only available for text channels.
@@ -87,6 +98,28 @@ pub struct GuildChannel {
     // default to `false`.
     #[serde(default)]
     pub nsfw: bool,
+    /// The archival state of the thread.
+    ///
+    /// **Note**: This is only available for threads.
+    #[serde(default)]
+    pub thread_metadata: Option<ThreadMetadata>,
+    /// The Id of the user that started the thread.
+    ///
+    /// **Note**: This is only available for threads.
+    #[serde(default)]
+    pub owner_id: Option<UserId>,
+    /// The approximate number of messages in the thread, which stops counting
+    /// at 50.
+    ///
+    /// **Note**: This is only available for threads.
+    #[serde(default)]
+    pub message_count: Option<u64>,
+    /// The approximate number of members in the thread, which stops counting
+    /// at 50.
+    ///
+    /// **Note**: This is only available for threads.
+    #[serde(default)]
+    pub member_count: Option<u64>,
 }
 
 #[cfg(feature = "model")]
@@ -1,11 +1,12 @@
 //! Models relating to Discord channels.
 
-use chrono::{DateTime, FixedOffset};
 use model::prelude::*;
 use serde_json::Value;
 
 #[cfg(feature = "model")]
-use builder::{CreateEmbed, EditMessage};
+use builder::{self, CreateEmbed, EditMessage};
+#[cfg(feature = "collector")]
+use collector::{CollectComponentInteraction, CollectReaction, ReactionAction};
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -14,6 +15,10 @@ use std::fmt::Write;
 use std::mem;
 #[cfg(feature = "model")]
 use {constants, http, utils as serenity_utils};
+#[cfg(feature = "scheduler")]
+use std::time::Duration;
+#[cfg(feature = "scheduler")]
+use utils::scheduler::{self, ScheduledTask};
 
 /// A representation of a message over a guild's text channel, a group, or a
 /// private channel.
@@ -30,10 +35,14 @@ pub struct Message {
     ///
     /// [`Channel`]: enum.Channel.html
     pub channel_id: ChannelId,
+    /// The rows of interactive components attached to the message, such as
+    /// buttons.
+    #[serde(default)]
+    pub components: Vec<ActionRow>,
     /// The content of the message.
     pub content: String,
     /// The timestamp of the last time the message was updated, if it was.
-    pub edited_timestamp: Option<DateTime<FixedOffset>>,
+    pub edited_timestamp: Option<Timestamp>,
     /// Array of embeds sent with the message.
     pub embeds: Vec<Embed>,
     /// The Id of the [`Guild`] that the message was sent in. This value will
@@ -41,6 +50,9 @@ pub struct Message {
     ///
     /// [`Guild`]: ../guild/struct.Guild.html
     pub guild_id: Option<GuildId>,
+    /// The interaction this message is a response to, such as the invocation
+    /// of an application command.
+    pub interaction: Option<MessageInteraction>,
     /// Indicator of the type of message this is, i.e. whether it is a regular
     /// message or a system message.
     #[serde(rename = "type")]
@@ -61,11 +73,17 @@ pub struct Message {
     pub nonce: Value,
     /// Indicator of whether the message is pinned.
     pub pinned: bool,
+    /// The poll attached to the message, if any.
+    #[serde(default)]
+    pub poll: Option<Poll>,
     /// Array of reactions performed on the message.
     #[serde(default)]
     pub reactions: Vec<MessageReaction>,
+    /// The stickers sent with the message.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
     /// Initial message creation timestamp, calculated from its Id.
-    pub timestamp: DateTime<FixedOffset>,
+    pub timestamp: Timestamp,
     /// Indicator of whether the command is to be played back via
     /// text-to-speech.
     ///
@@ -77,6 +95,78 @@ pub struct Message {
 
 #[cfg(feature = "model")]
 impl Message {
+    /// Blocks the current thread until a reaction is added to the message,
+    /// returning it.
+    ///
+    /// Refer to [`CollectReaction`] for the conditions the reaction can be
+    /// restricted by. Returns `None` if no fitting reaction was received
+    /// before the timeout elapsed.
+    ///
+    /// **Note**: Requires the `collector` feature.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 30 seconds for the author to confirm with a reaction:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// # fn confirm(msg: &Message) {
+    /// use std::time::Duration;
+    ///
+    /// if let Some(reaction) = msg.await_reaction(|r| r
+    ///     .author_id(msg.author.id)
+    ///     .timeout(Duration::from_secs(30))) {
+    ///     println!("Reacted with {}", reaction.as_inner_ref().emoji);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`CollectReaction`]: ../../collector/struct.CollectReaction.html
+    #[cfg(feature = "collector")]
+    pub fn await_reaction<F>(&self, f: F) -> Option<ReactionAction>
+        where F: FnOnce(CollectReaction) -> CollectReaction {
+        f(CollectReaction::default().channel_id(self.channel_id).message_id(self.id)).wait()
+    }
+
+    /// Blocks the current thread until a component of the message is used,
+    /// returning the interaction.
+    ///
+    /// Refer to [`CollectComponentInteraction`] for the conditions the
+    /// interaction can be restricted by. Returns `None` if no fitting
+    /// interaction was received before the timeout elapsed.
+    ///
+    /// **Note**: Requires the `collector` feature.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 30 seconds for the author to click the confirm button:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// # fn confirm(msg: &Message) {
+    /// use std::time::Duration;
+    ///
+    /// let confirmed = msg.await_component_interaction(|i| i
+    ///     .author_id(msg.author.id)
+    ///     .custom_id("confirm")
+    ///     .timeout(Duration::from_secs(30)))
+    ///     .is_some();
+    /// # }
+    /// ```
+    ///
+    /// [`CollectComponentInteraction`]: ../../collector/struct.CollectComponentInteraction.html
+    #[cfg(feature = "collector")]
+    pub fn await_component_interaction<F>(&self, f: F) -> Option<Interaction>
+        where F: FnOnce(CollectComponentInteraction) -> CollectComponentInteraction {
+        let collect = CollectComponentInteraction::default()
+            .channel_id(self.channel_id)
+            .message_id(self.id);
+
+        f(collect).wait()
+    }
+
     /// Retrieves the related channel located in the cache.
     ///
     /// Returns `None` if the channel is not in the cache.
@@ -156,6 +246,36 @@ impl Message {
         self.channel_id.delete_message(self.id)
     }
 
+    /// Deletes the message on a background thread once the delay elapsed,
+    /// e.g. to clean up a response after it was read.
+    ///
+    /// Errors are logged, as there is nothing to return them to. To delete a
+    /// message right after sending it, refer to [`CreateMessage::delete_after`].
+    ///
+    /// **Note**: Requires the `scheduler` feature.
+    ///
+    /// # Examples
+    ///
+    /// Removing a reply after 10 seconds:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// # fn reply(msg: &Message) -> serenity::Result<()> {
+    /// use std::time::Duration;
+    ///
+    /// msg.reply("This message deletes itself.")?
+    ///     .delete_after(Duration::from_secs(10));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`CreateMessage::delete_after`]: ../../builder/struct.CreateMessage.html#method.delete_after
+    #[cfg(feature = "scheduler")]
+    pub fn delete_after(&self, delay: Duration) -> ScheduledTask {
+        scheduler::delete_message_after(self.channel_id, self.id, delay)
+    }
+
     /// Deletes all of the [`Reaction`]s associated with the message.
     ///
     /// **Note**: Requires the [Manage Messages] permission.
@@ -245,6 +365,30 @@ impl Message {
         }
     }
 
+    /// Ends the message's [`Poll`] immediately, updating the message with the
+    /// final results of the poll.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidUser`] if the
+    /// current user is not the author.
+    ///
+    /// [`ModelError::InvalidUser`]: ../error/enum.Error.html#variant.InvalidUser
+    /// [`Poll`]: struct.Poll.html
+    pub fn end_poll(&mut self) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            if self.author.id != CACHE.read().user.id {
+                return Err(Error::Model(ModelError::InvalidUser));
+            }
+        }
+
+        let ended = self.channel_id.end_poll(self.id)?;
+        mem::replace(self, ended);
+
+        Ok(())
+    }
+
     pub(crate) fn transform_content(&mut self) {
         match self.kind {
             MessageType::PinsAdd => {
@@ -254,11 +398,11 @@ impl Message {
                 );
             },
             MessageType::MemberJoin => {
-                let sec = self.timestamp.timestamp() as usize;
+                let sec = self.timestamp.unix_timestamp() as usize;
                 let chosen = constants::JOIN_MESSAGES[sec % constants::JOIN_MESSAGES.len()];
 
                 self.content = if chosen.contains("$user") {
-                    chosen.replace("$user", &self.author.mention())
+                    chosen.replace("$user", &self.author.mention().to_string())
                 } else {
                     chosen.to_string()
                 };
@@ -280,12 +424,12 @@ impl Message {
             at_distinct.push_str(&u.name);
             at_distinct.push('#');
             let _ = write!(at_distinct, "{:04}", u.discriminator);
-            result = result.replace(&u.mention(), &at_distinct);
+            result = result.replace(&u.mention().to_string(), &at_distinct);
         }
 
         // Then replace all role mentions.
         for id in &self.mention_roles {
-            let mention = id.mention();
+            let mention = id.mention().to_string();
 
             if let Some(role) = id.to_role_cached() {
                 result = result.replace(&mention, &format!("@{}", role.name));
@@ -300,6 +444,58 @@ impl Message {
             .replace("@here", "@\u{200B}here")
     }
 
+    /// Iterates over the user, role, and channel mentions in the content, in
+    /// the order they appear in.
+    ///
+    /// Unlike [`mentions`] and [`mention_roles`], this includes channel
+    /// mentions and mentions of users that are repeated.
+    ///
+    /// [`mention_roles`]: #structfield.mention_roles
+    /// [`mentions`]: #structfield.mentions
+    #[inline]
+    pub fn content_mentions(&self) -> serenity_utils::Mentions {
+        serenity_utils::parse_mentions(&self.content)
+    }
+
+    /// Iterates over the custom emojis used in the content.
+    #[inline]
+    pub fn content_emojis(&self) -> serenity_utils::Emojis {
+        serenity_utils::parse_emojis(&self.content)
+    }
+
+    /// Iterates over the URLs in the content.
+    #[inline]
+    pub fn content_urls(&self) -> serenity_utils::Urls {
+        serenity_utils::parse_urls(&self.content)
+    }
+
+    /// Iterates over the code blocks in the content.
+    #[inline]
+    pub fn content_code_blocks(&self) -> serenity_utils::CodeBlocks {
+        serenity_utils::parse_code_blocks(&self.content)
+    }
+
+    /// Gets the list of [`User`]s who have voted for an answer of the
+    /// message's [`Poll`].
+    ///
+    /// The default `limit` is `25` - specify otherwise to receive a different
+    /// maximum number of users. The maximum that may be retrieve at a time is
+    /// `100`, if a greater number is provided then it is automatically reduced.
+    ///
+    /// The optional `after` attribute is to retrieve the users after a certain
+    /// user. This is useful for pagination.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [`Poll`]: struct.Poll.html
+    /// [`User`]: ../user/struct.User.html
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    #[inline]
+    pub fn poll_answer_voters<U>(&self, answer_id: u64, limit: Option<u8>, after: U)
+        -> Result<Vec<User>> where U: Into<Option<UserId>> {
+        self.channel_id.poll_answer_voters(self.id, answer_id, limit, after)
+    }
+
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
     /// certain [`Emoji`].
     ///
@@ -486,7 +682,7 @@ impl Message {
             }
         }
 
-        let mut gen = self.author.mention();
+        let mut gen = self.author.mention().to_string();
         gen.push_str(": ");
         gen.push_str(content);
 
@@ -554,47 +750,21 @@ impl Message {
         Ok(())
     }
 
+    pub(crate) fn check_embed_limits(map: &JsonMap) -> Result<()> {
+        match map.get("embed") {
+            Some(&Value::Object(ref embed)) => builder::validate_embed(|key| embed.get(key))
+                .map_err(|why| Error::Model(ModelError::InvalidEmbed(why))),
+            _ => Ok(()),
+        }
+    }
+
     pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
         let embed = match map.get("embed") {
             Some(&Value::Object(ref value)) => value,
             _ => return Ok(()),
         };
 
-        let mut total: usize = 0;
-
-        if let Some(&Value::Object(ref author)) = embed.get("author") {
-            if let Some(&Value::Object(ref name)) = author.get("name") {
-                total += name.len();
-            }
-        }
-
-        if let Some(&Value::String(ref description)) = embed.get("description") {
-            total += description.len();
-        }
-
-        if let Some(&Value::Array(ref fields)) = embed.get("fields") {
-            for field_as_value in fields {
-                if let Value::Object(ref field) = *field_as_value {
-                    if let Some(&Value::String(ref field_name)) = field.get("name") {
-                        total += field_name.len();
-                    }
-
-                    if let Some(&Value::String(ref field_value)) = field.get("value") {
-                        total += field_value.len();
-                    }
-                }
-            }
-        }
-
-        if let Some(&Value::Object(ref footer)) = embed.get("footer") {
-            if let Some(&Value::String(ref text)) = footer.get("text") {
-                total += text.len();
-            }
-        }
-
-        if let Some(&Value::String(ref title)) = embed.get("title") {
-            total += title.len();
-        }
+        let total = builder::embed_length(|key| embed.get(key));
 
         if total <= constants::EMBED_MAX_LENGTH as usize {
             Ok(())
@@ -622,6 +792,55 @@ impl<'a> From<&'a Message> for MessageId {
     fn from(message: &Message) -> MessageId { message.id }
 }
 
+/// Information about the interaction a [`Message`] is a response to.
+///
+/// [`Message`]: struct.Message.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageInteraction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The name of the invoked application command.
+    pub name: String,
+    /// The user that invoked the interaction.
+    pub user: User,
+}
+
+/// The type of an interaction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum InteractionType {
+    /// A ping sent by Discord to verify an interactions endpoint.
+    Ping = 1,
+    /// The invocation of an application command.
+    ApplicationCommand = 2,
+    /// The use of a message component, such as a button.
+    MessageComponent = 3,
+    /// The submission of a modal.
+    ModalSubmit = 5,
+}
+
+enum_number!(
+    InteractionType {
+        Ping,
+        ApplicationCommand,
+        MessageComponent,
+        ModalSubmit,
+    }
+);
+
+impl InteractionType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            InteractionType::Ping => 1,
+            InteractionType::ApplicationCommand => 2,
+            InteractionType::MessageComponent => 3,
+            InteractionType::ModalSubmit => 5,
+        }
+    }
+}
+
 /// A representation of a reaction to a message.
 ///
 /// Multiple of the same [reaction type] are sent into one `MessageReaction`,
@@ -2,23 +2,35 @@
 
 mod attachment;
 mod channel_id;
+mod component;
 mod embed;
 mod group;
 mod guild_channel;
 mod message;
+mod poll;
 mod private_channel;
 mod reaction;
 mod channel_category;
+mod stage_instance;
+mod thread;
+#[cfg(feature = "model")]
+mod typing;
 
 pub use self::attachment::*;
 pub use self::channel_id::*;
+pub use self::component::*;
 pub use self::embed::*;
 pub use self::group::*;
 pub use self::guild_channel::*;
 pub use self::message::*;
+pub use self::poll::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
 pub use self::channel_category::*;
+pub use self::stage_instance::*;
+pub use self::thread::*;
+#[cfg(feature = "model")]
+pub use self::typing::*;
 
 use internal::RwLockExt;
 use model::prelude::*;
@@ -521,7 +533,7 @@ impl<'de> Deserialize<'de> for Channel {
         };
 
         match kind {
-            0 | 2 => serde_json::from_value::<GuildChannel>(Value::Object(v))
+            0 | 2 | 5 | 6 | 10 | 11 | 12 | 13 => serde_json::from_value::<GuildChannel>(Value::Object(v))
                 .map(|x| Channel::Guild(Arc::new(RwLock::new(x))))
                 .map_err(DeError::custom),
             1 => serde_json::from_value::<PrivateChannel>(Value::Object(v))
@@ -533,6 +545,11 @@ impl<'de> Deserialize<'de> for Channel {
             4 => serde_json::from_value::<ChannelCategory>(Value::Object(v))
                 .map(|x| Channel::Category(Arc::new(RwLock::new(x))))
                 .map_err(DeError::custom),
+            // Channel types unknown to the library are most likely new kinds
+            // of guild channels.
+            _ if v.contains_key("guild_id") => serde_json::from_value::<GuildChannel>(Value::Object(v))
+                .map(|x| Channel::Guild(Arc::new(RwLock::new(x))))
+                .map_err(DeError::custom),
             _ => Err(DeError::custom("Unknown channel type")),
         }
     }
@@ -594,35 +611,51 @@ pub enum ChannelType {
     /// An indicator that the channel is a text [`GuildChannel`].
     ///
     /// [`GuildChannel`]: struct.GuildChannel.html
-    Text = 0,
+    Text,
     /// An indicator that the channel is a [`PrivateChannel`].
     ///
     /// [`PrivateChannel`]: struct.PrivateChannel.html
-    Private = 1,
+    Private,
     /// An indicator that the channel is a voice [`GuildChannel`].
     ///
     /// [`GuildChannel`]: struct.GuildChannel.html
-    Voice = 2,
+    Voice,
     /// An indicator that the channel is the channel of a [`Group`].
     ///
     /// [`Group`]: struct.Group.html
-    Group = 3,
+    Group,
     /// An indicator that the channel is the channel of a [`ChannelCategory`].
     ///
     /// [`ChannelCategory`]: struct.ChannelCategory.html
-    Category = 4,
+    Category,
+    /// An indicator that the channel is a news [`GuildChannel`], whose
+    /// messages other guilds can follow.
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    News,
+    /// An indicator that the channel is a store [`GuildChannel`], used to sell
+    /// games.
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    Store,
+    /// An indicator that the channel is a thread within a news channel.
+    NewsThread,
+    /// An indicator that the channel is a thread within a text channel that
+    /// everyone can see.
+    PublicThread,
+    /// An indicator that the channel is a thread within a text channel that
+    /// only invited members can see.
+    PrivateThread,
+    /// An indicator that the channel is a stage voice [`GuildChannel`], for
+    /// hosting events with an audience.
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    Stage,
+    /// An indicator that the channel is of a type the library does not know
+    /// yet, carrying the number Discord identifies the type with.
+    Unknown(u8),
 }
 
-enum_number!(
-    ChannelType {
-        Text,
-        Private,
-        Voice,
-        Group,
-        Category,
-    }
-);
-
 impl ChannelType {
     pub fn name(&self) -> &str {
         match *self {
@@ -631,6 +664,13 @@ impl ChannelType {
             ChannelType::Text => "text",
             ChannelType::Voice => "voice",
             ChannelType::Category => "category",
+            ChannelType::News => "news",
+            ChannelType::Store => "store",
+            ChannelType::NewsThread => "news thread",
+            ChannelType::PublicThread => "public thread",
+            ChannelType::PrivateThread => "private thread",
+            ChannelType::Stage => "stage",
+            ChannelType::Unknown(_) => "unknown",
         }
     }
 
@@ -641,10 +681,79 @@ impl ChannelType {
             ChannelType::Voice => 2,
             ChannelType::Group => 3,
             ChannelType::Category => 4,
+            ChannelType::News => 5,
+            ChannelType::Store => 6,
+            ChannelType::NewsThread => 10,
+            ChannelType::PublicThread => 11,
+            ChannelType::PrivateThread => 12,
+            ChannelType::Stage => 13,
+            ChannelType::Unknown(num) => u64::from(num),
+        }
+    }
+
+    /// Whether the channel is a thread.
+    pub fn is_thread(&self) -> bool {
+        match *self {
+            ChannelType::NewsThread
+            | ChannelType::PublicThread
+            | ChannelType::PrivateThread => true,
+            _ => false,
         }
     }
 }
 
+impl From<u8> for ChannelType {
+    fn from(num: u8) -> ChannelType {
+        match num {
+            0 => ChannelType::Text,
+            1 => ChannelType::Private,
+            2 => ChannelType::Voice,
+            3 => ChannelType::Group,
+            4 => ChannelType::Category,
+            5 => ChannelType::News,
+            6 => ChannelType::Store,
+            10 => ChannelType::NewsThread,
+            11 => ChannelType::PublicThread,
+            12 => ChannelType::PrivateThread,
+            13 => ChannelType::Stage,
+            num => ChannelType::Unknown(num),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ChannelType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        u8::deserialize(deserializer).map(ChannelType::from)
+    }
+}
+
+impl Serialize for ChannelType {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        serializer.serialize_u64(self.num())
+    }
+}
+
+/// A partial amount of data for a channel.
+///
+/// This is used for the channels selected through an [`Interaction`].
+///
+/// [`Interaction`]: ../interaction/struct.Interaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PartialChannel {
+    /// The Id of the channel.
+    pub id: ChannelId,
+    /// The name of the channel, if it has one.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The type of the channel.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The permissions of the invoking user in the channel.
+    #[serde(default)]
+    pub permissions: Option<Permissions>,
+}
+
 #[derive(Deserialize, Serialize)]
 struct PermissionOverwriteData {
     allow: Permissions,
@@ -713,6 +822,42 @@ pub enum PermissionOverwriteType {
 
 #[cfg(test)]
 mod test {
+    use model::channel::ChannelType;
+    use serde_json;
+
+    #[test]
+    fn channel_type_numbers() {
+        for num in 0u16..256 {
+            assert_eq!(ChannelType::from(num as u8).num(), u64::from(num));
+        }
+
+        let kind: ChannelType = serde_json::from_str("13").unwrap();
+        assert_eq!(kind, ChannelType::Stage);
+
+        let kind: ChannelType = serde_json::from_str("99").unwrap();
+        assert_eq!(kind, ChannelType::Unknown(99));
+        assert_eq!(serde_json::to_string(&kind).unwrap(), "99");
+    }
+
+    #[test]
+    fn reaction_type_from_str() {
+        use model::channel::ReactionType;
+        use model::id::EmojiId;
+
+        let custom = |animated, name: &str| ReactionType::Custom {
+            animated,
+            id: EmojiId(400000000000000000),
+            name: Some(name.to_string()),
+        };
+
+        assert_eq!("<:ferris:400000000000000000>".parse::<ReactionType>().unwrap(), custom(false, "ferris"));
+        assert_eq!("<a:ferris:400000000000000000>".parse::<ReactionType>().unwrap(), custom(true, "ferris"));
+        assert_eq!("ferris:400000000000000000".parse::<ReactionType>().unwrap(), custom(false, "ferris"));
+        assert_eq!("🦀".parse::<ReactionType>().unwrap(), ReactionType::Unicode("🦀".to_string()));
+        assert_eq!("<:ferris:crab>".parse::<ReactionType>().unwrap(),
+                   ReactionType::Unicode("<:ferris:crab>".to_string()));
+    }
+
     #[cfg(all(feature = "model", feature = "utils"))]
     mod model_utils {
         use model::prelude::*;
@@ -744,9 +889,14 @@ mod test {
                 name: "nsfw-stuff".to_string(),
                 permission_overwrites: vec![],
                 position: 0,
+                rtc_region: None,
                 topic: None,
                 user_limit: None,
                 nsfw: false,
+                thread_metadata: None,
+                owner_id: None,
+                message_count: None,
+                member_count: None,
             }
         }
 
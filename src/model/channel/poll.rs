@@ -0,0 +1,138 @@
+use model::prelude::*;
+
+/// A poll attached to a [`Message`].
+///
+/// [`Message`]: struct.Message.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Poll {
+    /// The question of the poll.
+    pub question: PollMedia,
+    /// The answers that can be voted for.
+    pub answers: Vec<PollAnswer>,
+    /// When the poll ends, if it does.
+    pub expiry: Option<Timestamp>,
+    /// Whether users can vote for more than one answer.
+    pub allow_multiselect: bool,
+    /// The way the poll is displayed.
+    pub layout_type: PollLayoutType,
+    /// The number of votes for each answer.
+    ///
+    /// This may be absent if Discord has not counted the votes yet, and is
+    /// only exact once [`PollResults::is_finalized`] is set.
+    ///
+    /// [`PollResults::is_finalized`]: struct.PollResults.html#structfield.is_finalized
+    #[serde(default)]
+    pub results: Option<PollResults>,
+}
+
+#[cfg(feature = "model")]
+impl Poll {
+    /// Returns the number of votes for the answer with the given Id, if the
+    /// votes have been counted.
+    pub fn answer_count(&self, answer_id: u64) -> Option<u64> {
+        let results = self.results.as_ref()?;
+
+        Some(results
+            .answer_counts
+            .iter()
+            .find(|count| count.id == answer_id)
+            .map_or(0, |count| count.count))
+    }
+
+    /// Returns the answers with the most votes, of which there are several in
+    /// the case of a tie.
+    ///
+    /// Returns an empty vector if the votes have not been counted or if no
+    /// answer received a vote.
+    pub fn winning_answers(&self) -> Vec<&PollAnswer> {
+        let counts = match self.results {
+            Some(ref results) => &results.answer_counts,
+            None => return vec![],
+        };
+
+        let highest = match counts.iter().map(|count| count.count).max() {
+            Some(highest) if highest > 0 => highest,
+            _ => return vec![],
+        };
+
+        self.answers
+            .iter()
+            .filter(|answer| counts
+                .iter()
+                .any(|count| count.id == answer.answer_id && count.count == highest))
+            .collect()
+    }
+}
+
+/// The text and emoji of a poll's question or answer.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PollMedia {
+    /// The text, which is always present for the question of a poll.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// The emoji, which can only be set for answers.
+    #[serde(default)]
+    pub emoji: Option<ReactionType>,
+}
+
+/// An answer that can be voted for in a [`Poll`].
+///
+/// [`Poll`]: struct.Poll.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PollAnswer {
+    /// The Id of the answer, which is counted up from `1` for each answer of
+    /// a poll.
+    pub answer_id: u64,
+    /// The text and emoji of the answer.
+    pub poll_media: PollMedia,
+}
+
+/// The number of votes for the answers of a [`Poll`].
+///
+/// [`Poll`]: struct.Poll.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PollResults {
+    /// Whether the votes have been counted exactly, which is only the case
+    /// once the poll ended.
+    pub is_finalized: bool,
+    /// The number of votes for each answer that received any.
+    pub answer_counts: Vec<PollAnswerCount>,
+}
+
+/// The number of votes for an answer of a [`Poll`].
+///
+/// [`Poll`]: struct.Poll.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PollAnswerCount {
+    /// The [`PollAnswer::answer_id`] of the answer.
+    ///
+    /// [`PollAnswer::answer_id`]: struct.PollAnswer.html#structfield.answer_id
+    pub id: u64,
+    /// The number of votes for the answer.
+    pub count: u64,
+    /// Whether the current user voted for the answer.
+    pub me_voted: bool,
+}
+
+/// The way a [`Poll`] is displayed.
+///
+/// [`Poll`]: struct.Poll.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum PollLayoutType {
+    /// The default layout, which is currently the only one.
+    Default = 1,
+}
+
+enum_number!(
+    PollLayoutType {
+        Default,
+    }
+);
+
+impl PollLayoutType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            PollLayoutType::Default => 1,
+        }
+    }
+}
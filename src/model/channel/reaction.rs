@@ -417,11 +417,53 @@ impl StdError for NeverFails {
 impl FromStr for ReactionType {
     type Err = NeverFails;
 
+    /// Parses a reaction type from user input, such as a command argument.
+    ///
+    /// Custom emojis are accepted in their mention form of `<:name:id>`, or
+    /// `<a:name:id>` for animated emojis, as well as in the form of `name:id`.
+    /// Anything else is taken as a unicode emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::channel::ReactionType;
+    /// use serenity::model::id::EmojiId;
+    ///
+    /// let reaction = "<a:ferris:400000000000000000>".parse::<ReactionType>().unwrap();
+    ///
+    /// assert_eq!(reaction, ReactionType::Custom {
+    ///     animated: true,
+    ///     id: EmojiId(400000000000000000),
+    ///     name: Some("ferris".to_string()),
+    /// });
+    /// ```
     fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
-        Ok(ReactionType::from(s))
+        Ok(parse_custom_reaction(s).unwrap_or_else(|| ReactionType::from(s)))
     }
 }
 
+/// Parses a custom emoji in the form of `<:name:id>`, `<a:name:id>`, or
+/// `name:id`.
+fn parse_custom_reaction(s: &str) -> Option<ReactionType> {
+    let (animated, inner) = if s.starts_with("<a:") && s.ends_with('>') {
+        (true, &s[3..s.len() - 1])
+    } else if s.starts_with("<:") && s.ends_with('>') {
+        (false, &s[2..s.len() - 1])
+    } else {
+        (false, s)
+    };
+
+    let mut parts = inner.splitn(2, ':');
+    let name = parts.next()?;
+    let id = parts.next()?.parse::<u64>().ok()?;
+
+    Some(ReactionType::Custom {
+        animated,
+        id: EmojiId(id),
+        name: if name.is_empty() { None } else { Some(name.to_string()) },
+    })
+}
+
 impl Display for ReactionType {
     /// Formats the reaction type, displaying the associated emoji in a
     /// way that clients can understand.
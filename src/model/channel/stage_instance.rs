@@ -0,0 +1,81 @@
+use model::prelude::*;
+
+#[cfg(feature = "model")]
+use builder::CreateStageInstance;
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use utils;
+
+/// A live stage in a [`Stage`] channel, which holds the topic the speakers of
+/// the channel are talking about.
+///
+/// [`Stage`]: enum.ChannelType.html#variant.Stage
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StageInstance {
+    /// The Id of the stage instance.
+    pub id: StageInstanceId,
+    /// The Id of the guild the stage channel is in.
+    pub guild_id: GuildId,
+    /// The Id of the stage channel.
+    pub channel_id: ChannelId,
+    /// The topic of the stage.
+    pub topic: String,
+    /// Who can see the stage.
+    pub privacy_level: StagePrivacyLevel,
+}
+
+#[cfg(feature = "model")]
+impl StageInstance {
+    /// Deletes the stage instance, ending the stage.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage
+    /// channel.
+    #[inline]
+    pub fn delete(&self) -> Result<()> { self.channel_id.delete_stage_instance() }
+
+    /// Edits the topic or privacy level of the stage instance.
+    ///
+    /// Refer to [`CreateStageInstance`] for more information.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage
+    /// channel.
+    ///
+    /// [`CreateStageInstance`]: ../../builder/struct.CreateStageInstance.html
+    #[cfg(feature = "utils")]
+    pub fn edit<F>(&mut self, f: F) -> Result<()>
+        where F: FnOnce(CreateStageInstance) -> CreateStageInstance {
+        let map = utils::vecmap_to_json_map(f(CreateStageInstance::default()).0);
+
+        *self = http::edit_stage_instance(self.channel_id.0, &Value::Object(map))?;
+
+        Ok(())
+    }
+}
+
+/// Who can see a [`StageInstance`].
+///
+/// [`StageInstance`]: struct.StageInstance.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum StagePrivacyLevel {
+    /// The stage is visible to everyone.
+    Public = 1,
+    /// The stage is only visible to members of the guild.
+    GuildOnly = 2,
+}
+
+enum_number!(
+    StagePrivacyLevel {
+        Public,
+        GuildOnly,
+    }
+);
+
+impl StagePrivacyLevel {
+    pub fn num(&self) -> u64 {
+        match *self {
+            StagePrivacyLevel::Public => 1,
+            StagePrivacyLevel::GuildOnly => 2,
+        }
+    }
+}
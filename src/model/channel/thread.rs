@@ -0,0 +1,59 @@
+use model::prelude::*;
+
+/// The metadata of a thread, which is a [`GuildChannel`] of the
+/// [`PublicThread`], [`PrivateThread`] or [`NewsThread`] kind.
+///
+/// [`GuildChannel`]: struct.GuildChannel.html
+/// [`NewsThread`]: enum.ChannelType.html#variant.NewsThread
+/// [`PrivateThread`]: enum.ChannelType.html#variant.PrivateThread
+/// [`PublicThread`]: enum.ChannelType.html#variant.PublicThread
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived, hiding it from the channel list.
+    pub archived: bool,
+    /// The number of minutes without activity after which the thread is
+    /// archived, which is either `60`, `1440`, `4320` or `10080`.
+    pub auto_archive_duration: u64,
+    /// When the thread was last archived or unarchived.
+    pub archive_timestamp: Timestamp,
+    /// Whether only moderators can unarchive the thread.
+    #[serde(default)]
+    pub locked: bool,
+    /// Whether members that are not moderators can add other members to the
+    /// thread, which is only present for private threads.
+    #[serde(default)]
+    pub invitable: Option<bool>,
+}
+
+/// A member of a thread.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMember {
+    /// The Id of the thread, which is absent in some gateway events.
+    #[serde(default)]
+    pub id: Option<ChannelId>,
+    /// The Id of the user, which is absent in some gateway events.
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+    /// When the user last joined the thread.
+    pub join_timestamp: Timestamp,
+    /// The notification settings of the user for the thread.
+    pub flags: u64,
+}
+
+/// A list of threads, retrieved through [`GuildId::active_threads`] or the
+/// archived threads of a channel, such as through
+/// [`ChannelId::archived_public_threads`].
+///
+/// [`ChannelId::archived_public_threads`]: struct.ChannelId.html#method.archived_public_threads
+/// [`GuildId::active_threads`]: ../guild/struct.GuildId.html#method.active_threads
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadsData {
+    /// The threads.
+    pub threads: Vec<GuildChannel>,
+    /// The membership of the current user for each of the threads it joined.
+    pub members: Vec<ThreadMember>,
+    /// Whether there are more archived threads than were retrieved, which is
+    /// always `false` for active threads.
+    #[serde(default)]
+    pub has_more: bool,
+}
@@ -0,0 +1,100 @@
+use model::id::ChannelId;
+use std::{
+    sync::mpsc::{self, RecvTimeoutError, Sender},
+    thread,
+    time::Duration
+};
+
+/// A guard broadcasting that the current user is typing in a channel, until
+/// it is [`stop`]ped or dropped.
+///
+/// The indicator is triggered again every 8 seconds on a background thread,
+/// as a single trigger lasts for up to 10 seconds.
+///
+/// Retrieved through [`ChannelId::start_typing`].
+///
+/// **Note**: The indicator can not be removed early, so it remains visible for
+/// up to 10 seconds after stopping, unless a message was sent in the meantime.
+///
+/// [`ChannelId::start_typing`]: struct.ChannelId.html#method.start_typing
+/// [`stop`]: #method.stop
+#[derive(Debug)]
+pub struct Typing(Sender<()>);
+
+impl Typing {
+    pub(crate) fn start(channel_id: ChannelId) -> Self {
+        Typing::repeat(Duration::from_secs(8), move || {
+            if let Err(why) = channel_id.broadcast_typing() {
+                warn!("Failed to broadcast typing in {}: {:?}", channel_id, why);
+            }
+        })
+    }
+
+    // Calls `trigger` right away and after every `interval`, until the guard
+    // is stopped or dropped.
+    fn repeat<F: FnMut() + Send + 'static>(interval: Duration, mut trigger: F) -> Self {
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || loop {
+            trigger();
+
+            if rx.recv_timeout(interval) != Err(RecvTimeoutError::Timeout) {
+                break;
+            }
+        });
+
+        Typing(tx)
+    }
+
+    /// Stops broadcasting typing.
+    ///
+    /// This is equivalent to dropping the guard.
+    pub fn stop(self) {
+        let _ = self.0.send(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+    use super::Typing;
+
+    #[test]
+    fn triggers_until_stopped() {
+        let (tx, rx) = mpsc::channel();
+
+        let typing = Typing::repeat(Duration::from_millis(10), move || {
+            let _ = tx.send(());
+        });
+
+        for _ in 0..3 {
+            assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok());
+        }
+
+        typing.stop();
+
+        // Wait for a trigger running concurrently to stopping.
+        thread::sleep(Duration::from_millis(50));
+        while rx.try_recv().is_ok() {}
+
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn dropping_stops_triggering() {
+        let (tx, rx) = mpsc::channel();
+
+        drop(Typing::repeat(Duration::from_millis(10), move || {
+            let _ = tx.send(());
+        }));
+
+        // The thread ends, dropping the trigger and with it the sender.
+        let start = ::std::time::Instant::now();
+
+        while rx.recv_timeout(Duration::from_secs(5)).is_ok() {
+            assert!(start.elapsed() < Duration::from_secs(5));
+        }
+    }
+}
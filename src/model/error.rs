@@ -6,9 +6,12 @@ use std::{
         Display,
         Formatter,
         Result as FmtResult
-    }
+    },
+    time::Duration
 };
 use super::Permissions;
+#[cfg(feature = "builder")]
+use builder::EmbedValidationError;
 
 /// An error returned from the [`model`] module.
 ///
@@ -117,6 +120,12 @@ pub enum Error {
     ///
     /// [`Cache`]: ../../cache/struct.Cache.html
     ItemMissing,
+    /// Indicates that a part of an embed exceeds one of Discord's limits, as
+    /// found when sending through [`ChannelId::send_message_validated`].
+    ///
+    /// [`ChannelId::send_message_validated`]: ../id/struct.ChannelId.html#method.send_message_validated
+    #[cfg(feature = "builder")]
+    InvalidEmbed(EmbedValidationError),
     /// Indicates that a [`Message`]s content was too long and will not
     /// successfully send, as the length is over 2000 codepoints, or 4000 bytes.
     ///
@@ -127,6 +136,14 @@ pub enum Error {
     /// Indicates that the current user is attempting to Direct Message another
     /// bot user, which is disallowed by the API.
     MessagingBot,
+    /// Indicates that a message could not be pinned, as the channel already
+    /// has the maximum of 50 pinned messages.
+    PinLimitReached,
+    /// Indicates that a request was not made, as the route is ratelimited and
+    /// making it would have blocked the thread until the ratelimit reset.
+    ///
+    /// The time left until the ratelimit resets is provided.
+    Ratelimited(Duration),
 }
 
 impl Display for Error {
@@ -141,11 +158,15 @@ impl StdError for Error {
             Error::EmbedTooLarge(_) => "Embed too large",
             Error::GuildNotFound => "Guild not found in the cache",
             Error::Hierarchy => "Role hierarchy prevents this action",
+            #[cfg(feature = "builder")]
+            Error::InvalidEmbed(_) => "Invalid embed",
             Error::InvalidPermissions(_) => "Invalid permissions",
             Error::InvalidUser => "The current user can not perform the action",
             Error::ItemMissing => "The required item is missing from the cache",
             Error::MessageTooLong(_) => "Message too large",
             Error::MessagingBot => "Attempted to message another bot user",
+            Error::PinLimitReached => "The channel has the maximum number of pins",
+            Error::Ratelimited(_) => "The route is ratelimited",
         }
     }
 }
@@ -1,6 +1,5 @@
 //! All the events this library handles.
 
-use chrono::{DateTime, FixedOffset};
 use serde::de::Error as DeError;
 use serde::ser::{
     Serialize,
@@ -9,19 +8,147 @@ use serde::ser::{
 };
 use serde_json;
 use std::collections::HashMap;
+use super::gateway::PresenceData;
 use super::utils::deserialize_emojis;
 use super::prelude::*;
 use constants::{OpCode, VoiceOpCode};
 use internal::prelude::*;
 
 #[cfg(feature = "cache")]
-use cache::{Cache, CacheUpdate};
+use CACHE;
+#[cfg(feature = "cache")]
+use cache::{Cache, CacheUpdate, Settings as CacheSettings};
 #[cfg(feature = "cache")]
 use internal::RwLockExt;
 #[cfg(feature = "cache")]
 use std::collections::hash_map::Entry;
 #[cfg(feature = "cache")]
 use std::mem;
+#[cfg(feature = "cache")]
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+#[cfg(feature = "cache")]
+use std::fmt;
+#[cfg(feature = "model")]
+use std::time::Duration;
+
+/// Event data for the execution of an action of an [`AutoModRule`], which is
+/// fired for each action that was performed on a message.
+///
+/// **Note**: The [`content`] and [`matched_content`] are only present for bots
+/// that may read the content of messages.
+///
+/// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+/// [`content`]: #structfield.content
+/// [`matched_content`]: #structfield.matched_content
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AutoModActionExecutionEvent {
+    /// The Id of the guild the action was performed in.
+    pub guild_id: GuildId,
+    /// The action that was performed.
+    pub action: AutoModAction,
+    /// The Id of the rule that was triggered.
+    pub rule_id: AutoModRuleId,
+    /// The kind of content that triggered the rule.
+    pub rule_trigger_type: AutoModTriggerType,
+    /// The Id of the user whose message triggered the rule.
+    pub user_id: UserId,
+    /// The Id of the channel the message was sent in, if any.
+    #[serde(default)]
+    pub channel_id: Option<ChannelId>,
+    /// The Id of the message, which is absent if the message was blocked.
+    #[serde(default)]
+    pub message_id: Option<MessageId>,
+    /// The Id of the message that was logged by a [`SendAlertMessage`]
+    /// action.
+    ///
+    /// [`SendAlertMessage`]: ../guild/enum.AutoModActionType.html#variant.SendAlertMessage
+    #[serde(default)]
+    pub alert_system_message_id: Option<MessageId>,
+    /// The content of the message.
+    #[serde(default)]
+    pub content: String,
+    /// The word or phrase of the rule that triggered it.
+    #[serde(default)]
+    pub matched_keyword: Option<String>,
+    /// The part of the content that triggered the rule.
+    #[serde(default)]
+    pub matched_content: Option<String>,
+}
+
+/// Event data for the auto moderation rule create event, fired when an
+/// [`AutoModRule`] was created.
+///
+/// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+#[derive(Clone, Debug)]
+pub struct AutoModRuleCreateEvent {
+    /// The rule that was created.
+    pub rule: AutoModRule,
+}
+
+impl<'de> Deserialize<'de> for AutoModRuleCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            rule: AutoModRule::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for AutoModRuleCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        AutoModRule::serialize(&self.rule, serializer)
+    }
+}
+
+/// Event data for the auto moderation rule delete event, fired when an
+/// [`AutoModRule`] was deleted.
+///
+/// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+#[derive(Clone, Debug)]
+pub struct AutoModRuleDeleteEvent {
+    /// The rule as it was before it was deleted.
+    pub rule: AutoModRule,
+}
+
+impl<'de> Deserialize<'de> for AutoModRuleDeleteEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            rule: AutoModRule::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for AutoModRuleDeleteEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        AutoModRule::serialize(&self.rule, serializer)
+    }
+}
+
+/// Event data for the auto moderation rule update event, fired when an
+/// [`AutoModRule`] was updated.
+///
+/// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+#[derive(Clone, Debug)]
+pub struct AutoModRuleUpdateEvent {
+    /// The rule as it is after the update.
+    pub rule: AutoModRule,
+}
+
+impl<'de> Deserialize<'de> for AutoModRuleUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            rule: AutoModRule::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for AutoModRuleUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        AutoModRule::serialize(&self.rule, serializer)
+    }
+}
 
 /// Event data for the channel creation event.
 ///
@@ -154,6 +281,7 @@ impl CacheUpdate for ChannelDeleteEvent {
 
         // Remove the cached messages for the channel.
         cache.messages.remove(&self.channel.id());
+        cache.pins.remove(&self.channel.id());
 
         None
     }
@@ -177,7 +305,12 @@ impl Serialize for ChannelDeleteEvent {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelPinsUpdateEvent {
     pub channel_id: ChannelId,
-    pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    /// The Id of the guild the channel is in, if it is a guild channel.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// When the most recent message still pinned was pinned, if any message
+    /// is.
+    pub last_pin_timestamp: Option<Timestamp>,
 }
 
 #[cfg(feature = "cache")]
@@ -185,9 +318,12 @@ impl CacheUpdate for ChannelPinsUpdateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &mut Cache) -> Option<()> {
+        // The event does not tell which message was (un)pinned.
+        cache.pins.remove(&self.channel_id);
+
         if let Some(channel) = cache.channels.get(&self.channel_id) {
             channel.with_mut(|c| {
-                c.last_pin_timestamp = self.last_pin_timestamp;
+                c.last_pin_timestamp = self.last_pin_timestamp.clone();
             });
 
             return None;
@@ -195,7 +331,7 @@ impl CacheUpdate for ChannelPinsUpdateEvent {
 
         if let Some(channel) = cache.private_channels.get_mut(&self.channel_id) {
             channel.with_mut(|c| {
-                c.last_pin_timestamp = self.last_pin_timestamp;
+                c.last_pin_timestamp = self.last_pin_timestamp.clone();
             });
 
             return None;
@@ -203,7 +339,7 @@ impl CacheUpdate for ChannelPinsUpdateEvent {
 
         if let Some(group) = cache.groups.get_mut(&self.channel_id) {
             group.with_mut(|c| {
-                c.last_pin_timestamp = self.last_pin_timestamp;
+                c.last_pin_timestamp = self.last_pin_timestamp.clone();
             });
 
             return None;
@@ -338,6 +474,26 @@ pub struct GuildBanAddEvent {
     pub user: User,
 }
 
+#[cfg(feature = "model")]
+impl GuildBanAddEvent {
+    /// Searches the guild's audit log for the entry of the ban, which holds
+    /// who banned the user and their reason, if it was created within the
+    /// `window` of time before now.
+    ///
+    /// Refer to [`GuildId::find_audit_log_entry`] for more information.
+    ///
+    /// [`GuildId::find_audit_log_entry`]: ../id/struct.GuildId.html#method.find_audit_log_entry
+    pub fn audit_log_entry(&self, window: Duration) -> Result<Option<AuditLogEntry>> {
+        let user_id = self.user.id.0;
+
+        self.guild_id.find_audit_log_entry(
+            Action::Member(ActionMember::BanAdd),
+            window,
+            |entry| entry.target_id == user_id,
+        )
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildBanRemoveEvent {
     pub guild_id: GuildId,
@@ -506,6 +662,32 @@ pub struct GuildMemberRemoveEvent {
     pub user: User,
 }
 
+#[cfg(feature = "model")]
+impl GuildMemberRemoveEvent {
+    /// Searches the guild's audit log for the entry of a kick of the member,
+    /// which holds who kicked them and their reason, if it was created within
+    /// the `window` of time before now.
+    ///
+    /// If there is none, the member most likely left on their own. Bans are
+    /// sent as a [`GuildBanAddEvent`] as well, which can be attributed
+    /// through [`GuildBanAddEvent::audit_log_entry`].
+    ///
+    /// Refer to [`GuildId::find_audit_log_entry`] for more information.
+    ///
+    /// [`GuildBanAddEvent`]: struct.GuildBanAddEvent.html
+    /// [`GuildBanAddEvent::audit_log_entry`]: struct.GuildBanAddEvent.html#method.audit_log_entry
+    /// [`GuildId::find_audit_log_entry`]: ../id/struct.GuildId.html#method.find_audit_log_entry
+    pub fn kick_audit_log_entry(&self, window: Duration) -> Result<Option<AuditLogEntry>> {
+        let user_id = self.user.id.0;
+
+        self.guild_id.find_audit_log_entry(
+            Action::Member(ActionMember::Kick),
+            window,
+            |entry| entry.target_id == user_id,
+        )
+    }
+}
+
 #[cfg(feature = "cache")]
 impl CacheUpdate for GuildMemberRemoveEvent {
     type Output = Member;
@@ -736,6 +918,8 @@ impl CacheUpdate for GuildUpdateEvent {
             guild.icon.clone_from(&self.guild.icon);
             guild.name.clone_from(&self.guild.name);
             guild.owner_id.clone_from(&self.guild.owner_id);
+            guild.premium_subscription_count = self.guild.premium_subscription_count;
+            guild.premium_tier = self.guild.premium_tier;
             guild.region.clone_from(&self.guild.region);
             guild.roles.clone_from(&self.guild.roles);
             guild.verification_level = self.guild.verification_level;
@@ -760,6 +944,36 @@ impl Serialize for GuildUpdateEvent {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct InteractionCreateEvent {
+    pub interaction: Interaction,
+}
+
+impl<'de> Deserialize<'de> for InteractionCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        // The member of an interaction is sent without the Id of its guild.
+        if let Some(guild_id) = map.get("guild_id").cloned() {
+            if let Some(&mut Value::Object(ref mut member)) = map.get_mut("member") {
+                member.insert("guild_id".to_string(), guild_id);
+            }
+        }
+
+        Ok(Self {
+            interaction: Interaction::deserialize(Value::Object(map))
+                .map_err(DeError::custom)?,
+        })
+    }
+}
+
+impl Serialize for InteractionCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Interaction::serialize(&self.interaction, serializer)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct MessageCreateEvent {
     pub message: Message,
@@ -826,6 +1040,38 @@ pub struct MessageDeleteEvent {
     #[serde(rename = "id")] pub message_id: MessageId,
 }
 
+#[cfg(feature = "model")]
+impl MessageDeleteEvent {
+    /// Searches the guild's audit log for the entry of the deletion, which
+    /// holds who deleted the message, if it was created within the `window`
+    /// of time before now.
+    ///
+    /// As the event does not contain the message, the Id of its author has to
+    /// be known, such as from a cached copy of the message. There is no entry
+    /// if the author deleted the message themselves.
+    ///
+    /// **Note**: Discord merges deletions of messages by the same author in
+    /// the same channel into one entry for a few minutes, and does not update
+    /// its creation time. A `window` of at least 5 minutes is needed for
+    /// those deletions to be attributed, which may attribute the deletion of
+    /// a message deleted by its author to a recent moderator instead.
+    ///
+    /// Refer to [`GuildId::find_audit_log_entry`] for more information.
+    ///
+    /// [`GuildId::find_audit_log_entry`]: ../id/struct.GuildId.html#method.find_audit_log_entry
+    pub fn audit_log_entry(&self, guild_id: GuildId, author_id: UserId, window: Duration)
+        -> Result<Option<AuditLogEntry>> {
+        let channel_id = self.channel_id;
+
+        guild_id.find_audit_log_entry(Action::MessageDelete, window, |entry| {
+            entry.target_id == author_id.0 && entry
+                .options
+                .as_ref()
+                .map_or(false, |options| options.channel_id == Some(channel_id))
+        })
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUpdateEvent {
     pub id: MessageId,
@@ -835,14 +1081,16 @@ pub struct MessageUpdateEvent {
     pub nonce: Option<String>,
     pub tts: Option<bool>,
     pub pinned: Option<bool>,
-    pub timestamp: Option<DateTime<FixedOffset>>,
-    pub edited_timestamp: Option<DateTime<FixedOffset>>,
+    pub timestamp: Option<Timestamp>,
+    pub edited_timestamp: Option<Timestamp>,
     pub author: Option<User>,
     pub mention_everyone: Option<bool>,
     pub mentions: Option<Vec<User>>,
     pub mention_roles: Option<Vec<RoleId>>,
     pub attachments: Option<Vec<Attachment>>,
     pub embeds: Option<Vec<Value>>,
+    pub poll: Option<Poll>,
+    pub components: Option<Vec<ActionRow>>,
 }
 
 #[cfg(feature = "cache")]
@@ -858,11 +1106,15 @@ impl CacheUpdate for MessageUpdateEvent {
                     message.attachments = attachments;
                 }
 
+                if let Some(components) = self.components.clone() {
+                    message.components = components;
+                }
+
                 if let Some(content) = self.content.clone() {
                     message.content = content;
                 }
 
-                if let Some(edited_timestamp) = self.edited_timestamp {
+                if let Some(edited_timestamp) = self.edited_timestamp.clone() {
                     message.edited_timestamp = Some(edited_timestamp);
                 }
 
@@ -882,6 +1134,10 @@ impl CacheUpdate for MessageUpdateEvent {
                     message.pinned = pinned;
                 }
 
+                if let Some(poll) = self.poll.clone() {
+                    message.poll = Some(poll);
+                }
+
                 Some(item)
             } else {
                 None
@@ -892,6 +1148,24 @@ impl CacheUpdate for MessageUpdateEvent {
     }
 }
 
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct PollVoteAddEvent {
+    pub user_id: UserId,
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    pub guild_id: Option<GuildId>,
+    pub answer_id: u64,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct PollVoteRemoveEvent {
+    pub user_id: UserId,
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    pub guild_id: Option<GuildId>,
+    pub answer_id: u64,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct PresenceUpdateEvent {
     pub guild_id: Option<GuildId>,
@@ -957,20 +1231,7 @@ impl CacheUpdate for PresenceUpdateEvent {
 
 impl<'de> Deserialize<'de> for PresenceUpdateEvent {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
-        let mut map = JsonMap::deserialize(deserializer)?;
-
-        let guild_id = match map.remove("guild_id") {
-            Some(v) => serde_json::from_value::<Option<GuildId>>(v)
-                .map_err(DeError::custom)?,
-            None => None,
-        };
-        let roles = match map.remove("roles") {
-            Some(v) => serde_json::from_value::<Option<Vec<RoleId>>>(v)
-                .map_err(DeError::custom)?,
-            None => None,
-        };
-        let presence = Presence::deserialize(Value::Object(map))
-            .map_err(DeError::custom)?;
+        let (presence, guild_id, roles) = PresenceData::deserialize(deserializer)?.into_presence();
 
         Ok(Self {
             guild_id,
@@ -1139,6 +1400,133 @@ pub struct ResumedEvent {
     #[serde(rename = "_trace")] pub trace: Vec<Option<String>>,
 }
 
+/// Event data for the stage instance creation event.
+#[derive(Clone, Debug)]
+pub struct StageInstanceCreateEvent {
+    /// The stage instance that was created.
+    pub stage_instance: StageInstance,
+}
+
+impl<'de> Deserialize<'de> for StageInstanceCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for StageInstanceCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
+/// Event data for the stage instance deletion event.
+#[derive(Clone, Debug)]
+pub struct StageInstanceDeleteEvent {
+    /// The stage instance that was deleted.
+    pub stage_instance: StageInstance,
+}
+
+impl<'de> Deserialize<'de> for StageInstanceDeleteEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for StageInstanceDeleteEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
+/// Event data for the stage instance update event.
+#[derive(Clone, Debug)]
+pub struct StageInstanceUpdateEvent {
+    /// The stage instance that was updated.
+    pub stage_instance: StageInstance,
+}
+
+impl<'de> Deserialize<'de> for StageInstanceUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for StageInstanceUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
+/// Event data for the thread creation event, which is also fired when the
+/// current user is added to a private thread.
+#[derive(Clone, Debug)]
+pub struct ThreadCreateEvent {
+    /// The thread that was created.
+    pub thread: GuildChannel,
+}
+
+impl<'de> Deserialize<'de> for ThreadCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            thread: GuildChannel::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for ThreadCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        GuildChannel::serialize(&self.thread, serializer)
+    }
+}
+
+/// Event data for the thread deletion event, carrying only the identifying
+/// fields of the thread.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadDeleteEvent {
+    /// The Id of the thread.
+    pub id: ChannelId,
+    /// The Id of the guild the thread was in.
+    pub guild_id: GuildId,
+    /// The Id of the channel the thread was started in.
+    pub parent_id: Option<ChannelId>,
+    /// The type of the thread.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+}
+
+/// Event data for the thread update event, which is also fired when a thread
+/// is archived or unarchived.
+#[derive(Clone, Debug)]
+pub struct ThreadUpdateEvent {
+    /// The updated thread.
+    pub thread: GuildChannel,
+}
+
+impl<'de> Deserialize<'de> for ThreadUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            thread: GuildChannel::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for ThreadUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        GuildChannel::serialize(&self.thread, serializer)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TypingStartEvent {
     pub channel_id: ChannelId,
@@ -1337,6 +1725,30 @@ impl<'de> Deserialize<'de> for GatewayEvent {
 #[allow(large_enum_variant)]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Event {
+    /// An action of an auto moderation rule was performed.
+    ///
+    /// Fires the [`auto_moderation_action_execution`] event handler.
+    ///
+    /// [`auto_moderation_action_execution`]: ../../prelude/trait.EventHandler.html#method.auto_moderation_action_execution
+    AutoModActionExecution(AutoModActionExecutionEvent),
+    /// An auto moderation rule was created.
+    ///
+    /// Fires the [`auto_moderation_rule_create`] event handler.
+    ///
+    /// [`auto_moderation_rule_create`]: ../../prelude/trait.EventHandler.html#method.auto_moderation_rule_create
+    AutoModRuleCreate(AutoModRuleCreateEvent),
+    /// An auto moderation rule was deleted.
+    ///
+    /// Fires the [`auto_moderation_rule_delete`] event handler.
+    ///
+    /// [`auto_moderation_rule_delete`]: ../../prelude/trait.EventHandler.html#method.auto_moderation_rule_delete
+    AutoModRuleDelete(AutoModRuleDeleteEvent),
+    /// An auto moderation rule was updated.
+    ///
+    /// Fires the [`auto_moderation_rule_update`] event handler.
+    ///
+    /// [`auto_moderation_rule_update`]: ../../prelude/trait.EventHandler.html#method.auto_moderation_rule_update
+    AutoModRuleUpdate(AutoModRuleUpdateEvent),
     /// A [`Channel`] was created.
     ///
     /// Fires the [`Client::channel_create`] event.
@@ -1400,11 +1812,30 @@ pub enum Event {
     /// When a guild is unavailable, such as due to a Discord server outage.
     GuildUnavailable(GuildUnavailableEvent),
     GuildUpdate(GuildUpdateEvent),
+    /// A user interacted with the application, such as by invoking one of its
+    /// commands.
+    ///
+    /// Fires the [`interaction_create`] event handler.
+    ///
+    /// [`interaction_create`]: ../../prelude/trait.EventHandler.html#method.interaction_create
+    InteractionCreate(InteractionCreateEvent),
     MessageCreate(MessageCreateEvent),
     MessageDelete(MessageDeleteEvent),
     MessageDeleteBulk(MessageDeleteBulkEvent),
     /// A message has been edited, either by the user or the system
     MessageUpdate(MessageUpdateEvent),
+    /// A user voted for an answer of a poll.
+    ///
+    /// Fires the [`poll_vote_add`] event handler.
+    ///
+    /// [`poll_vote_add`]: ../../prelude/trait.EventHandler.html#method.poll_vote_add
+    PollVoteAdd(PollVoteAddEvent),
+    /// A user removed their vote for an answer of a poll.
+    ///
+    /// Fires the [`poll_vote_remove`] event handler.
+    ///
+    /// [`poll_vote_remove`]: ../../prelude/trait.EventHandler.html#method.poll_vote_remove
+    PollVoteRemove(PollVoteRemoveEvent),
     /// A member's presence state (or username or avatar) has changed
     PresenceUpdate(PresenceUpdateEvent),
     /// The precense list of the user's friends should be replaced entirely
@@ -1435,6 +1866,43 @@ pub enum Event {
     Ready(ReadyEvent),
     /// The connection has successfully resumed after a disconnect.
     Resumed(ResumedEvent),
+    /// A stage was started in a stage channel.
+    ///
+    /// Fires the [`stage_instance_create`] event handler.
+    ///
+    /// [`stage_instance_create`]: ../../prelude/trait.EventHandler.html#method.stage_instance_create
+    StageInstanceCreate(StageInstanceCreateEvent),
+    /// A stage was ended in a stage channel.
+    ///
+    /// Fires the [`stage_instance_delete`] event handler.
+    ///
+    /// [`stage_instance_delete`]: ../../prelude/trait.EventHandler.html#method.stage_instance_delete
+    StageInstanceDelete(StageInstanceDeleteEvent),
+    /// The topic or privacy level of a stage was updated.
+    ///
+    /// Fires the [`stage_instance_update`] event handler.
+    ///
+    /// [`stage_instance_update`]: ../../prelude/trait.EventHandler.html#method.stage_instance_update
+    StageInstanceUpdate(StageInstanceUpdateEvent),
+    /// A thread was created, or the current user was added to a private
+    /// thread.
+    ///
+    /// Fires the [`thread_create`] event handler.
+    ///
+    /// [`thread_create`]: ../../prelude/trait.EventHandler.html#method.thread_create
+    ThreadCreate(ThreadCreateEvent),
+    /// A thread was deleted.
+    ///
+    /// Fires the [`thread_delete`] event handler.
+    ///
+    /// [`thread_delete`]: ../../prelude/trait.EventHandler.html#method.thread_delete
+    ThreadDelete(ThreadDeleteEvent),
+    /// A thread was updated, such as by being archived.
+    ///
+    /// Fires the [`thread_update`] event handler.
+    ///
+    /// [`thread_update`]: ../../prelude/trait.EventHandler.html#method.thread_update
+    ThreadUpdate(ThreadUpdateEvent),
     /// A user is typing; considered to last 5 seconds
     TypingStart(TypingStartEvent),
     /// Update to the logged-in user's information
@@ -1453,6 +1921,99 @@ pub enum Event {
     Unknown(UnknownEvent),
 }
 
+/// The keys of the arrays of a guild's data that the cache is set to not store.
+#[cfg(feature = "cache")]
+fn uncached_guild_keys() -> Vec<&'static str> {
+    skipped_guild_keys(CACHE.read().settings())
+}
+
+#[cfg(feature = "cache")]
+fn skipped_guild_keys(settings: &CacheSettings) -> Vec<&'static str> {
+    let keys = [
+        ("members", settings.cache_members),
+        ("presences", settings.cache_presences),
+        ("voice_states", settings.cache_voice_states),
+    ];
+
+    keys.iter().filter(|&&(_, enabled)| !enabled).map(|&(key, _)| key).collect()
+}
+
+/// Empties the arrays of a guild's data that the cache is set to not store,
+/// so that they are not deserialized into their models.
+#[cfg(feature = "cache")]
+fn remove_uncached_guild_data(map: &mut JsonMap) {
+    for key in uncached_guild_keys() {
+        map.insert(key.to_string(), Value::Array(vec![]));
+    }
+}
+
+#[cfg(not(feature = "cache"))]
+fn remove_uncached_guild_data(_: &mut JsonMap) {}
+
+/// The data of a guild becoming available, with the arrays that the cache is
+/// set to not store left empty.
+///
+/// The entries of those arrays are passed over while parsing, so that no
+/// values are built for them at all.
+#[cfg(feature = "cache")]
+pub(crate) struct GuildCreateMap(pub JsonMap);
+
+#[cfg(feature = "cache")]
+impl<'de> Deserialize<'de> for GuildCreateMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        deserializer.deserialize_map(GuildCreateVisitor(uncached_guild_keys()))
+    }
+}
+
+/// Parses the data of a guild, passing over the arrays of the given keys.
+#[cfg(feature = "cache")]
+struct GuildCreateVisitor(Vec<&'static str>);
+
+#[cfg(feature = "cache")]
+impl<'de> Visitor<'de> for GuildCreateVisitor {
+    type Value = GuildCreateMap;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the data of a guild")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> StdResult<GuildCreateMap, A::Error> {
+        let mut map = JsonMap::new();
+
+        while let Some(key) = access.next_key::<String>()? {
+            let value = if self.0.contains(&key.as_str()) {
+                access.next_value::<IgnoredAny>()?;
+
+                Value::Array(vec![])
+            } else {
+                access.next_value::<Value>()?
+            };
+
+            map.insert(key, value);
+        }
+
+        Ok(GuildCreateMap(map))
+    }
+}
+
+/// Builds the event of a GUILD_CREATE, which is a [`GuildUnavailableEvent`]
+/// if the guild is marked as unavailable.
+///
+/// [`GuildUnavailableEvent`]: struct.GuildUnavailableEvent.html
+pub(crate) fn guild_create_event(mut map: JsonMap) -> Result<Event> {
+    if map.remove("unavailable")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false) {
+        let guild_data = serde_json::from_value(Value::Object(map))?;
+
+        Ok(Event::GuildUnavailable(guild_data))
+    } else {
+        remove_uncached_guild_data(&mut map);
+
+        Ok(Event::GuildCreate(serde_json::from_value(Value::Object(map))?))
+    }
+}
+
 /// Deserializes a `serde_json::Value` into an `Event`.
 ///
 /// The given `EventType` is used to determine what event to deserialize into.
@@ -1465,13 +2026,24 @@ pub enum Event {
 /// [`GuildUnavailableEvent`] to be returned. Otherwise, all other event types
 /// correlate to the deserialization of their appropriate event.
 ///
+/// The members, presences, and voice states of a guild are not deserialized
+/// into their models if the cache is [set][`Settings`] to not store them. The
+/// shards go further and do not parse them from the payload at all.
+///
 /// [`EventType::ChannelCreate`]: enum.EventType.html#variant.ChannelCreate
 /// [`EventType::GuildCreate`]: enum.EventType.html#variant.GuildCreate
 /// [`EventType::GuildDelete`]: enum.EventType.html#variant.GuildDelete
 /// [`ChannelCreateEvent`]: struct.ChannelCreateEvent.html
 /// [`GuildUnavailableEvent`]: struct.GuildUnavailableEvent.html
+/// [`Settings`]: ../../cache/struct.Settings.html
 pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
     Ok(match kind {
+        EventType::AutoModActionExecution => {
+            Event::AutoModActionExecution(serde_json::from_value(v)?)
+        },
+        EventType::AutoModRuleCreate => Event::AutoModRuleCreate(serde_json::from_value(v)?),
+        EventType::AutoModRuleDelete => Event::AutoModRuleDelete(serde_json::from_value(v)?),
+        EventType::AutoModRuleUpdate => Event::AutoModRuleUpdate(serde_json::from_value(v)?),
         EventType::ChannelCreate => Event::ChannelCreate(serde_json::from_value(v)?),
         EventType::ChannelDelete => Event::ChannelDelete(serde_json::from_value(v)?),
         EventType::ChannelPinsUpdate => {
@@ -1490,17 +2062,7 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
             // GuildUnavailable isn't actually received from the gateway, so it
             // can be lumped in with GuildCreate's arm.
 
-            let mut map = JsonMap::deserialize(v)?;
-
-            if map.remove("unavailable")
-                    .and_then(|v| v.as_bool())
-                    .unwrap_or(false) {
-                let guild_data = serde_json::from_value(Value::Object(map))?;
-
-                Event::GuildUnavailable(guild_data)
-            } else {
-                Event::GuildCreate(serde_json::from_value(Value::Object(map))?)
-            }
+            guild_create_event(JsonMap::deserialize(v)?)?
         },
         EventType::GuildDelete => {
             let mut map = JsonMap::deserialize(v)?;
@@ -1541,6 +2103,9 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
             Event::GuildRoleUpdate(serde_json::from_value(v)?)
         },
         EventType::GuildUpdate => Event::GuildUpdate(serde_json::from_value(v)?),
+        EventType::InteractionCreate => {
+            Event::InteractionCreate(serde_json::from_value(v)?)
+        },
         EventType::MessageCreate => Event::MessageCreate(serde_json::from_value(v)?),
         EventType::MessageDelete => Event::MessageDelete(serde_json::from_value(v)?),
         EventType::MessageDeleteBulk => {
@@ -1556,12 +2121,20 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
             Event::ReactionRemoveAll(serde_json::from_value(v)?)
         },
         EventType::MessageUpdate => Event::MessageUpdate(serde_json::from_value(v)?),
+        EventType::PollVoteAdd => Event::PollVoteAdd(serde_json::from_value(v)?),
+        EventType::PollVoteRemove => Event::PollVoteRemove(serde_json::from_value(v)?),
         EventType::PresenceUpdate => Event::PresenceUpdate(serde_json::from_value(v)?),
         EventType::PresencesReplace => {
             Event::PresencesReplace(serde_json::from_value(v)?)
         },
         EventType::Ready => Event::Ready(serde_json::from_value(v)?),
         EventType::Resumed => Event::Resumed(serde_json::from_value(v)?),
+        EventType::StageInstanceCreate => Event::StageInstanceCreate(serde_json::from_value(v)?),
+        EventType::StageInstanceDelete => Event::StageInstanceDelete(serde_json::from_value(v)?),
+        EventType::StageInstanceUpdate => Event::StageInstanceUpdate(serde_json::from_value(v)?),
+        EventType::ThreadCreate => Event::ThreadCreate(serde_json::from_value(v)?),
+        EventType::ThreadDelete => Event::ThreadDelete(serde_json::from_value(v)?),
+        EventType::ThreadUpdate => Event::ThreadUpdate(serde_json::from_value(v)?),
         EventType::TypingStart => Event::TypingStart(serde_json::from_value(v)?),
         EventType::UserUpdate => Event::UserUpdate(serde_json::from_value(v)?),
         EventType::VoiceServerUpdate => {
@@ -1587,8 +2160,33 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
 /// [`EventType::ChannelCreate`].
 ///
 /// [`EventType::ChannelCreate`]: enum.EventType.html#variant.ChannelCreate
-#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum EventType {
+    /// Indicator that an auto moderation action execution payload was
+    /// received.
+    ///
+    /// This maps to [`AutoModActionExecutionEvent`].
+    ///
+    /// [`AutoModActionExecutionEvent`]: struct.AutoModActionExecutionEvent.html
+    AutoModActionExecution,
+    /// Indicator that an auto moderation rule create payload was received.
+    ///
+    /// This maps to [`AutoModRuleCreateEvent`].
+    ///
+    /// [`AutoModRuleCreateEvent`]: struct.AutoModRuleCreateEvent.html
+    AutoModRuleCreate,
+    /// Indicator that an auto moderation rule delete payload was received.
+    ///
+    /// This maps to [`AutoModRuleDeleteEvent`].
+    ///
+    /// [`AutoModRuleDeleteEvent`]: struct.AutoModRuleDeleteEvent.html
+    AutoModRuleDelete,
+    /// Indicator that an auto moderation rule update payload was received.
+    ///
+    /// This maps to [`AutoModRuleUpdateEvent`].
+    ///
+    /// [`AutoModRuleUpdateEvent`]: struct.AutoModRuleUpdateEvent.html
+    AutoModRuleUpdate,
     /// Indicator that a channel create payload was received.
     ///
     /// This maps to [`ChannelCreateEvent`].
@@ -1715,6 +2313,12 @@ pub enum EventType {
     ///
     /// [`GuildUpdateEvent`]: struct.GuildUpdateEvent.html
     GuildUpdate,
+    /// Indicator that an interaction create payload was received.
+    ///
+    /// This maps to [`InteractionCreateEvent`].
+    ///
+    /// [`InteractionCreateEvent`]: struct.InteractionCreateEvent.html
+    InteractionCreate,
     /// Indicator that a message create payload was received.
     ///
     /// This maps to [`MessageCreateEvent`].
@@ -1739,6 +2343,18 @@ pub enum EventType {
     ///
     /// [`MessageUpdateEvent`]: struct.MessageUpdateEvent.html
     MessageUpdate,
+    /// Indicator that a poll vote add payload was received.
+    ///
+    /// This maps to [`PollVoteAddEvent`].
+    ///
+    /// [`PollVoteAddEvent`]: struct.PollVoteAddEvent.html
+    PollVoteAdd,
+    /// Indicator that a poll vote remove payload was received.
+    ///
+    /// This maps to [`PollVoteRemoveEvent`].
+    ///
+    /// [`PollVoteRemoveEvent`]: struct.PollVoteRemoveEvent.html
+    PollVoteRemove,
     /// Indicator that a presence update payload was received.
     ///
     /// This maps to [`PresenceUpdateEvent`].
@@ -1781,6 +2397,42 @@ pub enum EventType {
     ///
     /// [`ResumedEvent`]: struct.ResumedEvent.html
     Resumed,
+    /// Indicator that a stage instance create payload was received.
+    ///
+    /// This maps to [`StageInstanceCreateEvent`].
+    ///
+    /// [`StageInstanceCreateEvent`]: struct.StageInstanceCreateEvent.html
+    StageInstanceCreate,
+    /// Indicator that a stage instance delete payload was received.
+    ///
+    /// This maps to [`StageInstanceDeleteEvent`].
+    ///
+    /// [`StageInstanceDeleteEvent`]: struct.StageInstanceDeleteEvent.html
+    StageInstanceDelete,
+    /// Indicator that a stage instance update payload was received.
+    ///
+    /// This maps to [`StageInstanceUpdateEvent`].
+    ///
+    /// [`StageInstanceUpdateEvent`]: struct.StageInstanceUpdateEvent.html
+    StageInstanceUpdate,
+    /// Indicator that a thread create payload was received.
+    ///
+    /// This maps to [`ThreadCreateEvent`].
+    ///
+    /// [`ThreadCreateEvent`]: struct.ThreadCreateEvent.html
+    ThreadCreate,
+    /// Indicator that a thread delete payload was received.
+    ///
+    /// This maps to [`ThreadDeleteEvent`].
+    ///
+    /// [`ThreadDeleteEvent`]: struct.ThreadDeleteEvent.html
+    ThreadDelete,
+    /// Indicator that a thread update payload was received.
+    ///
+    /// This maps to [`ThreadUpdateEvent`].
+    ///
+    /// [`ThreadUpdateEvent`]: struct.ThreadUpdateEvent.html
+    ThreadUpdate,
     /// Indicator that a typing start payload was received.
     ///
     /// This maps to [`TypingStartEvent`].
@@ -1833,6 +2485,12 @@ impl<'de> Deserialize<'de> for EventType {
             fn visit_str<E>(self, v: &str) -> StdResult<Self::Value, E>
                 where E: DeError {
                 Ok(match v {
+                    "AUTO_MODERATION_ACTION_EXECUTION" => {
+                        EventType::AutoModActionExecution
+                    },
+                    "AUTO_MODERATION_RULE_CREATE" => EventType::AutoModRuleCreate,
+                    "AUTO_MODERATION_RULE_DELETE" => EventType::AutoModRuleDelete,
+                    "AUTO_MODERATION_RULE_UPDATE" => EventType::AutoModRuleUpdate,
                     "CHANNEL_CREATE" => EventType::ChannelCreate,
                     "CHANNEL_DELETE" => EventType::ChannelDelete,
                     "CHANNEL_PINS_UPDATE" => EventType::ChannelPinsUpdate,
@@ -1853,9 +2511,12 @@ impl<'de> Deserialize<'de> for EventType {
                     "GUILD_ROLE_DELETE" => EventType::GuildRoleDelete,
                     "GUILD_ROLE_UPDATE" => EventType::GuildRoleUpdate,
                     "GUILD_UPDATE" => EventType::GuildUpdate,
+                    "INTERACTION_CREATE" => EventType::InteractionCreate,
                     "MESSAGE_CREATE" => EventType::MessageCreate,
                     "MESSAGE_DELETE" => EventType::MessageDelete,
                     "MESSAGE_DELETE_BULK" => EventType::MessageDeleteBulk,
+                    "MESSAGE_POLL_VOTE_ADD" => EventType::PollVoteAdd,
+                    "MESSAGE_POLL_VOTE_REMOVE" => EventType::PollVoteRemove,
                     "MESSAGE_REACTION_ADD" => EventType::ReactionAdd,
                     "MESSAGE_REACTION_REMOVE" => EventType::ReactionRemove,
                     "MESSAGE_REACTION_REMOVE_ALL" => EventType::ReactionRemoveAll,
@@ -1864,6 +2525,12 @@ impl<'de> Deserialize<'de> for EventType {
                     "PRESENCES_REPLACE" => EventType::PresencesReplace,
                     "READY" => EventType::Ready,
                     "RESUMED" => EventType::Resumed,
+                    "STAGE_INSTANCE_CREATE" => EventType::StageInstanceCreate,
+                    "STAGE_INSTANCE_DELETE" => EventType::StageInstanceDelete,
+                    "STAGE_INSTANCE_UPDATE" => EventType::StageInstanceUpdate,
+                    "THREAD_CREATE" => EventType::ThreadCreate,
+                    "THREAD_DELETE" => EventType::ThreadDelete,
+                    "THREAD_UPDATE" => EventType::ThreadUpdate,
                     "TYPING_START" => EventType::TypingStart,
                     "USER_UPDATE" => EventType::UserUpdate,
                     "VOICE_SERVER_UPDATE" => EventType::VoiceServerUpdate,
@@ -1887,6 +2554,13 @@ pub struct VoiceHeartbeat {
 #[allow(missing_docs)]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct VoiceHello {
+    /// The interval at which keepalives are sent.
+    ///
+    /// **Note**: Since version 4 of the voice gateway, this is only given
+    /// through a separate [`Heartbeat`] event.
+    ///
+    /// [`Heartbeat`]: enum.VoiceEvent.html#variant.Heartbeat
+    #[serde(default)]
     pub heartbeat_interval: u64,
     pub ip: String,
     pub modes: Vec<String>,
@@ -1904,19 +2578,43 @@ pub struct VoiceSessionDescription {
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct VoiceSpeaking {
+    /// Whether the user is speaking.
+    ///
+    /// Discord sends this either as a boolean or as a bitfield of speaking
+    /// modes, which is `true` if any mode is set.
+    #[serde(deserialize_with = "deserialize_speaking")]
     pub speaking: bool,
     pub ssrc: u32,
     pub user_id: UserId,
 }
 
+fn deserialize_speaking<'de, D: Deserializer<'de>>(deserializer: D) -> StdResult<bool, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Bool(speaking) => Ok(speaking),
+        Value::Number(ref modes) if modes.is_u64() => Ok(modes.as_u64() != Some(0)),
+        other => Err(DeError::custom(format!("expected speaking state, found {}", other))),
+    }
+}
+
+/// A user disconnected from the voice channel of the current connection.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct VoiceClientDisconnect {
+    /// The Id of the user that disconnected.
+    pub user_id: UserId,
+}
+
 /// A representation of data received for [`voice`] events.
 ///
 /// [`voice`]: ../../voice/index.html
 #[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum VoiceEvent {
+    /// A user disconnected from the voice channel.
+    ClientDisconnect(VoiceClientDisconnect),
     /// A voice heartbeat.
     Heartbeat(VoiceHeartbeat),
+    /// An acknowledgement of a sent keepalive.
+    HeartbeatAck,
     /// A "hello" was received with initial voice data, such as the
     /// [`heartbeat_interval`].
     ///
@@ -1926,6 +2624,8 @@ pub enum VoiceEvent {
     KeepAlive,
     /// A voice event describing the current session.
     Ready(VoiceSessionDescription),
+    /// An acknowledgement that the connection was resumed.
+    Resumed,
     /// A voice event denoting that someone is speaking.
     Speaking(VoiceSpeaking),
     /// An unknown voice event not registered.
@@ -1948,17 +2648,25 @@ impl<'de> Deserialize<'de> for VoiceEvent {
             .map_err(DeError::custom)?;
 
         Ok(match op {
+            VoiceOpCode::ClientDisconnect => {
+                let v = VoiceClientDisconnect::deserialize(v)
+                    .map_err(DeError::custom)?;
+
+                VoiceEvent::ClientDisconnect(v)
+            },
             VoiceOpCode::Heartbeat => {
                 let v = serde_json::from_value(v).map_err(DeError::custom)?;
 
                 VoiceEvent::Heartbeat(v)
             },
+            VoiceOpCode::HeartbeatAck => VoiceEvent::HeartbeatAck,
             VoiceOpCode::Hello => {
                 let v = VoiceHello::deserialize(v).map_err(DeError::custom)?;
 
                 VoiceEvent::Hello(v)
             },
             VoiceOpCode::KeepAlive => VoiceEvent::KeepAlive,
+            VoiceOpCode::Resumed => VoiceEvent::Resumed,
             VoiceOpCode::SessionDescription => {
                 let v = VoiceSessionDescription::deserialize(v)
                     .map_err(DeError::custom)?;
@@ -1974,3 +2682,39 @@ impl<'de> Deserialize<'de> for VoiceEvent {
         })
     }
 }
+
+#[cfg(all(test, feature = "cache"))]
+mod test {
+    use cache::Settings;
+    use serde::Deserializer;
+    use serde_json;
+    use super::{skipped_guild_keys, GuildCreateVisitor};
+
+    #[test]
+    fn skipped_guild_keys_follow_settings() {
+        let mut settings = Settings::new();
+        assert!(skipped_guild_keys(&settings).is_empty());
+
+        settings.cache_members(false).cache_voice_states(false);
+        assert_eq!(skipped_guild_keys(&settings), vec!["members", "voice_states"]);
+
+        settings.cache_members(true).cache_presences(false);
+        assert_eq!(skipped_guild_keys(&settings), vec!["presences", "voice_states"]);
+    }
+
+    #[test]
+    fn guild_create_map_passes_over_skipped_arrays() {
+        let payload = r#"{
+            "id": "1",
+            "members": [{"user": {"id": "2"}}],
+            "presences": [{"user": {"id": "2"}}]
+        }"#;
+
+        let mut deserializer = serde_json::Deserializer::from_str(payload);
+        let map = deserializer.deserialize_map(GuildCreateVisitor(vec!["members"])).unwrap().0;
+
+        assert_eq!(map["id"], json!("1"));
+        assert_eq!(map["members"], json!([]));
+        assert_eq!(map["presences"], json!([{"user": {"id": "2"}}]));
+    }
+}
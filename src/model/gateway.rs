@@ -1,7 +1,6 @@
 //! Models pertaining to the gateway.
 
 use parking_lot::RwLock;
-use serde::de::Error as DeError;
 use serde::ser::{SerializeStruct, Serialize, Serializer};
 use serde_json;
 use std::sync::Arc;
@@ -42,6 +41,56 @@ pub struct Game {
     /// [`GameType::Streaming`]: enum.GameType.html#variant.Streaming
     /// [`kind`]: #structfield.kind
     pub url: Option<String>,
+    /// The Id of the application the activity belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<ApplicationId>,
+    /// Images of the activity and their hover texts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assets: Option<ActivityAssets>,
+    /// The labels of the buttons shown with the activity.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub buttons: Vec<String>,
+    /// What the user is currently doing in the activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The emoji of a custom status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ActivityEmoji>,
+    /// Information about the party the user is in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub party: Option<ActivityParty>,
+    /// Secrets used for joining and spectating the activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<ActivitySecrets>,
+    /// The user's current party status, or the text of a custom status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// The Id of the song being listened to on Spotify.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_id: Option<String>,
+    /// When the activity started and ends.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamps: Option<ActivityTimestamps>,
+}
+
+impl Game {
+    fn new(kind: GameType, name: String, url: Option<String>) -> Game {
+        Game {
+            application_id: None,
+            assets: None,
+            buttons: Vec::new(),
+            details: None,
+            emoji: None,
+            party: None,
+            secrets: None,
+            state: None,
+            sync_id: None,
+            timestamps: None,
+            kind,
+            name,
+            url,
+        }
+    }
 }
 
 #[cfg(feature = "model")]
@@ -68,11 +117,7 @@ impl Game {
     /// # fn main() {}
     /// ```
     pub fn playing(name: &str) -> Game {
-        Game {
-            kind: GameType::Playing,
-            name: name.to_string(),
-            url: None,
-        }
+        Game::new(GameType::Playing, name.to_string(), None)
     }
 
     /// Creates a `Game` struct that appears as a `Streaming <name>` status.
@@ -99,11 +144,7 @@ impl Game {
     /// # fn main() {}
     /// ```
     pub fn streaming(name: &str, url: &str) -> Game {
-        Game {
-            kind: GameType::Streaming,
-            name: name.to_string(),
-            url: Some(url.to_string()),
-        }
+        Game::new(GameType::Streaming, name.to_string(), Some(url.to_string()))
     }
 
     /// Creates a `Game` struct that appears as a `Listening to <name>` status.
@@ -128,71 +169,57 @@ impl Game {
     /// # fn main() {}
     /// ```
     pub fn listening(name: &str) -> Game {
-        Game {
-            kind: GameType::Listening,
-            name: name.to_string(),
-            url: None,
-        }
+        Game::new(GameType::Listening, name.to_string(), None)
+    }
+
+    /// Creates a `Game` struct that appears as a `Watching <name>` status.
+    ///
+    /// **Note**: Maximum `name` length is 128.
+    pub fn watching(name: &str) -> Game {
+        Game::new(GameType::Watching, name.to_string(), None)
+    }
+
+    /// Creates a `Game` struct that appears as a `Competing in <name>` status.
+    ///
+    /// **Note**: Maximum `name` length is 128.
+    pub fn competing(name: &str) -> Game {
+        Game::new(GameType::Competing, name.to_string(), None)
     }
 }
 
 impl<'a> From<&'a str> for Game {
     fn from(name: &'a str) -> Self {
-        Game {
-            kind: GameType::Playing,
-            name: name.to_owned(),
-            url: None,
-        }
+        Game::new(GameType::Playing, name.to_owned(), None)
     }
 }
 
 impl From<String> for Game {
     fn from(name: String) -> Self {
-        Game {
-            kind: GameType::Playing,
-            url: None,
-            name,
-        }
+        Game::new(GameType::Playing, name, None)
     }
 }
 
 impl<'a> From<(String, GameType)> for Game {
     fn from((name, kind): (String, GameType)) -> Self {
-        Self {
-            url: None,
-            kind,
-            name,
-        }
+        Game::new(kind, name, None)
     }
 }
 
 impl<'a> From<(&'a str, &'a str)> for Game {
     fn from((name, url): (&'a str, &'a str)) -> Self {
-        Self {
-            kind: GameType::Streaming,
-            name: name.to_owned(),
-            url: Some(url.to_owned()),
-        }
+        Game::new(GameType::Streaming, name.to_owned(), Some(url.to_owned()))
     }
 }
 
 impl From<(String, String)> for Game {
     fn from((name, url): (String, String)) -> Self {
-        Self {
-            kind: GameType::Streaming,
-            url: Some(url),
-            name,
-        }
+        Game::new(GameType::Streaming, name, Some(url))
     }
 }
 
 impl From<(String, GameType, String)> for Game {
     fn from((name, kind, url): (String, GameType, String)) -> Self {
-        Self {
-            url: Some(url),
-            kind,
-            name,
-        }
+        Game::new(kind, name, Some(url))
     }
 }
 
@@ -208,15 +235,241 @@ impl<'de> Deserialize<'de> for Game {
         let url = map.remove("url")
             .and_then(|v| serde_json::from_value::<String>(v).ok());
 
-        Ok(Game {
+        let mut game = Game::new(kind, name, url);
+
+        game.application_id = map.remove("application_id")
+            .and_then(|v| serde_json::from_value::<ApplicationId>(v).ok());
+        game.assets = map.remove("assets")
+            .and_then(|v| serde_json::from_value::<ActivityAssets>(v).ok());
+        game.buttons = map.remove("buttons")
+            .and_then(|v| serde_json::from_value::<Vec<String>>(v).ok())
+            .unwrap_or_else(Vec::new);
+        game.details = map.remove("details")
+            .and_then(|v| serde_json::from_value::<String>(v).ok());
+        game.emoji = map.remove("emoji")
+            .and_then(|v| serde_json::from_value::<ActivityEmoji>(v).ok());
+        game.party = map.remove("party")
+            .and_then(|v| serde_json::from_value::<ActivityParty>(v).ok());
+        game.secrets = map.remove("secrets")
+            .and_then(|v| serde_json::from_value::<ActivitySecrets>(v).ok());
+        game.state = map.remove("state")
+            .and_then(|v| serde_json::from_value::<String>(v).ok());
+        game.sync_id = map.remove("sync_id")
+            .and_then(|v| serde_json::from_value::<String>(v).ok());
+        game.timestamps = map.remove("timestamps")
+            .and_then(|v| serde_json::from_value::<ActivityTimestamps>(v).ok());
+
+        Ok(game)
+    }
+}
+
+/// The images of an activity and their hover texts.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ActivityAssets {
+    /// The Id of the large image.
+    pub large_image: Option<String>,
+    /// The text shown when hovering over the large image.
+    pub large_text: Option<String>,
+    /// The Id of the small image.
+    pub small_image: Option<String>,
+    /// The text shown when hovering over the small image.
+    pub small_text: Option<String>,
+}
+
+/// Information about the party of an activity.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ActivityParty {
+    /// The Id of the party.
+    pub id: Option<String>,
+    /// The current and the maximum size of the party.
+    pub size: Option<[u64; 2]>,
+}
+
+/// The secrets used for joining and spectating an activity.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ActivitySecrets {
+    /// The secret for joining the party.
+    pub join: Option<String>,
+    /// The secret for a specific instanced match.
+    #[serde(rename = "match")]
+    pub match_: Option<String>,
+    /// The secret for spectating the game.
+    pub spectate: Option<String>,
+}
+
+/// The start and end of an activity, as unix timestamps in milliseconds.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ActivityTimestamps {
+    /// When the activity ends.
+    pub end: Option<u64>,
+    /// When the activity started.
+    pub start: Option<u64>,
+}
+
+
+
+/// An activity to display in the current user's presence, as set through
+/// [`Context::set_activities`] or [`Client::set_initial_presence`].
+///
+/// Unlike a received [`Game`], this supports custom statuses and can be
+/// combined with other activities.
+///
+/// # Examples
+///
+/// Setting a custom status next to a game being played:
+///
+/// ```rust,no_run
+/// # #[macro_use] extern crate serenity;
+/// #
+/// use serenity::model::gateway::ActivityData;
+/// use serenity::model::user::OnlineStatus;
+///
+/// command!(relax(ctx, _msg) {
+///     ctx.set_activities(vec![
+///         ActivityData::custom("Taking a break").emoji('☕'),
+///         ActivityData::playing("Solitaire"),
+///     ], OnlineStatus::Idle);
+/// });
+/// #
+/// # fn main() {}
+/// ```
+///
+/// [`Client::set_initial_presence`]: ../../client/struct.Client.html#method.set_initial_presence
+/// [`Context::set_activities`]: ../../client/struct.Context.html#method.set_activities
+/// [`Game`]: struct.Game.html
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ActivityData {
+    /// The type of the activity.
+    #[serde(rename = "type")]
+    pub kind: GameType,
+    /// The name of the activity being performed.
+    pub name: String,
+    /// The text of a custom status, or what the user is doing in the activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// The Stream URL if [`kind`] is [`GameType::Streaming`].
+    ///
+    /// [`GameType::Streaming`]: enum.GameType.html#variant.Streaming
+    /// [`kind`]: #structfield.kind
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The emoji displayed next to a custom status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ActivityEmoji>,
+}
+
+impl ActivityData {
+    fn new(kind: GameType, name: &str) -> Self {
+        ActivityData {
             kind,
-            name,
-            url,
-        })
+            name: name.to_string(),
+            state: None,
+            url: None,
+            emoji: None,
+        }
+    }
+
+    /// Creates an activity that appears as a `Playing <name>` status.
+    pub fn playing(name: &str) -> Self { ActivityData::new(GameType::Playing, name) }
+
+    /// Creates an activity that appears as a `Streaming <name>` status,
+    /// linking to the given stream.
+    pub fn streaming(name: &str, url: &str) -> Self {
+        let mut activity = ActivityData::new(GameType::Streaming, name);
+        activity.url = Some(url.to_string());
+
+        activity
+    }
+
+    /// Creates an activity that appears as a `Listening to <name>` status.
+    pub fn listening(name: &str) -> Self { ActivityData::new(GameType::Listening, name) }
+
+    /// Creates an activity that appears as a `Watching <name>` status.
+    pub fn watching(name: &str) -> Self { ActivityData::new(GameType::Watching, name) }
+
+    /// Creates an activity that appears as a `Competing in <name>` status.
+    pub fn competing(name: &str) -> Self { ActivityData::new(GameType::Competing, name) }
+
+    /// Creates a custom status, displaying the given text as is.
+    ///
+    /// Use [`emoji`] to display an emoji in front of the text.
+    ///
+    /// [`emoji`]: #method.emoji
+    pub fn custom(state: &str) -> Self {
+        let mut activity = ActivityData::new(GameType::Custom, "Custom Status");
+        activity.state = Some(state.to_string());
+
+        activity
+    }
+
+    /// Sets the emoji displayed in front of a custom status.
+    pub fn emoji<E: Into<ActivityEmoji>>(mut self, emoji: E) -> Self {
+        self.emoji = Some(emoji.into());
+
+        self
+    }
+
+    /// Sets what the user is doing in the activity, or the text of a custom
+    /// status.
+    pub fn state(mut self, state: &str) -> Self {
+        self.state = Some(state.to_string());
+
+        self
     }
 }
 
+impl From<Game> for ActivityData {
+    fn from(game: Game) -> Self {
+        ActivityData {
+            kind: game.kind,
+            name: game.name,
+            state: game.state,
+            url: game.url,
+            emoji: game.emoji,
+        }
+    }
+}
 
+impl<'a> From<&'a str> for ActivityData {
+    fn from(name: &'a str) -> Self { ActivityData::playing(name) }
+}
+
+impl From<String> for ActivityData {
+    fn from(name: String) -> Self { ActivityData::playing(&name) }
+}
+
+/// An emoji displayed in an activity, such as next to a custom status.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ActivityEmoji {
+    /// The name of the emoji, or the emoji itself if it is a unicode emoji.
+    pub name: String,
+    /// The Id of the emoji, if it is a custom emoji.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<EmojiId>,
+    /// Whether the emoji is animated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animated: Option<bool>,
+}
+
+impl From<char> for ActivityEmoji {
+    fn from(ch: char) -> Self {
+        ActivityEmoji {
+            name: ch.to_string(),
+            id: None,
+            animated: None,
+        }
+    }
+}
+
+impl From<EmojiIdentifier> for ActivityEmoji {
+    fn from(emoji: EmojiIdentifier) -> Self {
+        ActivityEmoji {
+            name: emoji.name,
+            id: Some(emoji.id),
+            animated: None,
+        }
+    }
+}
 
 /// The type of activity that is being performed when playing a game.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -227,6 +480,12 @@ pub enum GameType {
     Streaming = 1,
     /// An indicator that the user is listening to something.
     Listening = 2,
+    /// An indicator that the user is watching something.
+    Watching = 3,
+    /// An indicator that the user has set a custom status.
+    Custom = 4,
+    /// An indicator that the user is competing in something.
+    Competing = 5,
 }
 
 enum_number!(
@@ -234,6 +493,9 @@ enum_number!(
         Playing,
         Streaming,
         Listening,
+        Watching,
+        Custom,
+        Competing,
     }
 );
 
@@ -245,6 +507,9 @@ impl GameType {
             Playing => 0,
             Streaming => 1,
             Listening => 2,
+            Watching => 3,
+            Custom => 4,
+            Competing => 5,
         }
     }
 }
@@ -286,56 +551,87 @@ pub struct Presence {
     pub user: Option<Arc<RwLock<User>>>,
 }
 
-impl<'de> Deserialize<'de> for Presence {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Presence, D::Error> {
-        let mut map = JsonMap::deserialize(deserializer)?;
-        let mut user_map = map.remove("user")
-            .ok_or_else(|| DeError::custom("expected presence user"))
-            .and_then(JsonMap::deserialize)
-            .map_err(DeError::custom)?;
-
-        let (user_id, user) = if user_map.len() > 1 {
-            let user = User::deserialize(Value::Object(user_map))
-                .map_err(DeError::custom)?;
+/// The fields of a presence, as sent by Discord.
+///
+/// This is deserialized directly, rather than through an intermediate map, as
+/// presences are among the most frequently received data.
+#[derive(Deserialize)]
+pub(crate) struct PresenceData {
+    #[serde(default)]
+    game: Option<Game>,
+    #[serde(default)]
+    last_modified: Option<u64>,
+    #[serde(default)]
+    nick: Option<String>,
+    status: OnlineStatus,
+    user: PresenceUser,
+    /// The Id of the guild, if the presence was sent in a
+    /// [`PresenceUpdateEvent`].
+    ///
+    /// [`PresenceUpdateEvent`]: ../event/struct.PresenceUpdateEvent.html
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// The roles of the member, if the presence was sent in a
+    /// [`PresenceUpdateEvent`].
+    ///
+    /// [`PresenceUpdateEvent`]: ../event/struct.PresenceUpdateEvent.html
+    #[serde(default)]
+    pub roles: Option<Vec<RoleId>>,
+}
 
-            (user.id, Some(Arc::new(RwLock::new(user))))
-        } else {
-            let user_id = user_map
-                .remove("id")
-                .ok_or_else(|| DeError::custom("Missing presence user id"))
-                .and_then(UserId::deserialize)
-                .map_err(DeError::custom)?;
+/// The user of a presence, which is often only partially given.
+#[derive(Deserialize)]
+struct PresenceUser {
+    id: UserId,
+    #[serde(default)]
+    avatar: Option<String>,
+    #[serde(default)]
+    bot: bool,
+    #[serde(default)]
+    discriminator: Option<Discriminator>,
+    #[serde(default, rename = "username")]
+    name: Option<String>,
+}
 
-            (user_id, None)
+#[derive(Deserialize)]
+struct Discriminator(#[serde(deserialize_with = "deserialize_u16")] u16);
+
+impl PresenceData {
+    /// Splits off the presence, leaving the fields specific to presence
+    /// updates.
+    pub(crate) fn into_presence(self) -> (Presence, Option<GuildId>, Option<Vec<RoleId>>) {
+        let user_id = self.user.id;
+
+        // Only the Id of the user is guaranteed to be present.
+        let user = match (self.user.name, self.user.discriminator) {
+            (Some(name), Some(Discriminator(discriminator))) => {
+                Some(Arc::new(RwLock::new(User {
+                    id: user_id,
+                    avatar: self.user.avatar,
+                    bot: self.user.bot,
+                    discriminator,
+                    name,
+                })))
+            },
+            _ => None,
         };
 
-        let game = match map.remove("game") {
-            Some(v) => serde_json::from_value::<Option<Game>>(v)
-                .map_err(DeError::custom)?,
-            None => None,
-        };
-        let last_modified = match map.remove("last_modified") {
-            Some(v) => Some(u64::deserialize(v).map_err(DeError::custom)?),
-            None => None,
-        };
-        let nick = match map.remove("nick") {
-            Some(v) => serde_json::from_value::<Option<String>>(v)
-                .map_err(DeError::custom)?,
-            None => None,
-        };
-        let status = map.remove("status")
-            .ok_or_else(|| DeError::custom("expected presence status"))
-            .and_then(OnlineStatus::deserialize)
-            .map_err(DeError::custom)?;
-
-        Ok(Presence {
-            game,
-            last_modified,
-            nick,
-            status,
+        let presence = Presence {
+            game: self.game,
+            last_modified: self.last_modified,
+            nick: self.nick,
+            status: self.status,
             user,
             user_id,
-        })
+        };
+
+        (presence, self.guild_id, self.roles)
+    }
+}
+
+impl<'de> Deserialize<'de> for Presence {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Presence, D::Error> {
+        PresenceData::deserialize(deserializer).map(|data| data.into_presence().0)
     }
 }
 
@@ -393,4 +689,52 @@ pub struct SessionStartLimit {
     pub reset_after: u64,
     /// The total number of session starts within the ratelimit period allowed.
     pub total: u64,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod test {
+    use model::id::EmojiId;
+    use model::misc::EmojiIdentifier;
+    use serde_json;
+    use super::{ActivityData, ActivityEmoji, Game, GameType};
+
+    #[test]
+    fn activity_kinds() {
+        assert_eq!(ActivityData::watching("a movie").kind, GameType::Watching);
+        assert_eq!(ActivityData::competing("a race").kind, GameType::Competing);
+        assert_eq!(ActivityData::from("a game").kind, GameType::Playing);
+
+        let streaming = ActivityData::streaming("a stream", "https://twitch.tv/a");
+        assert_eq!(streaming.kind, GameType::Streaming);
+        assert_eq!(streaming.url, Some("https://twitch.tv/a".to_string()));
+    }
+
+    #[test]
+    fn custom_status_serialization() {
+        let activity = ActivityData::custom("Movie night").emoji('🍿');
+
+        assert_eq!(serde_json::to_value(&activity).unwrap(), json!({
+            "type": 4,
+            "name": "Custom Status",
+            "state": "Movie night",
+            "emoji": {"name": "🍿"},
+        }));
+    }
+
+    #[test]
+    fn custom_emoji() {
+        let emoji = ActivityEmoji::from(EmojiIdentifier {
+            id: EmojiId(5),
+            name: "ferris".to_string(),
+        });
+
+        assert_eq!(serde_json::to_value(&emoji).unwrap(), json!({"name": "ferris", "id": "5"}));
+    }
+
+    #[test]
+    fn from_game() {
+        let activity = ActivityData::from(Game::listening("a song"));
+
+        assert_eq!(activity, ActivityData::listening("a song"));
+        assert_eq!(serde_json::to_value(&activity).unwrap(), json!({"type": 2, "name": "a song"}));
+    }
+}
@@ -0,0 +1,269 @@
+use model::prelude::*;
+
+#[cfg(feature = "model")]
+use builder::EditAutoModRule;
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use utils;
+
+/// A rule of the auto moderation of a guild, which performs its [`actions`]
+/// when a message of a member triggers it.
+///
+/// [`actions`]: #structfield.actions
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AutoModRule {
+    /// The Id of the rule.
+    pub id: AutoModRuleId,
+    /// The Id of the guild of the rule.
+    pub guild_id: GuildId,
+    /// The name of the rule.
+    pub name: String,
+    /// The Id of the user that created the rule.
+    pub creator_id: UserId,
+    /// The event that the rule is checked on.
+    pub event_type: AutoModEventType,
+    /// The kind of content that triggers the rule.
+    pub trigger_type: AutoModTriggerType,
+    /// The settings of the trigger of the rule.
+    #[serde(default)]
+    pub trigger_metadata: AutoModTriggerMetadata,
+    /// The actions that are performed when the rule is triggered.
+    pub actions: Vec<AutoModAction>,
+    /// Whether the rule is enabled.
+    pub enabled: bool,
+    /// The roles of which the members are not affected by the rule.
+    #[serde(default)]
+    pub exempt_roles: Vec<RoleId>,
+    /// The channels in which the rule is not checked.
+    #[serde(default)]
+    pub exempt_channels: Vec<ChannelId>,
+}
+
+#[cfg(feature = "model")]
+impl AutoModRule {
+    /// Deletes the rule.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn delete(&self) -> Result<()> { self.guild_id.delete_automod_rule(self.id) }
+
+    /// Edits the rule, optionally setting its new fields.
+    ///
+    /// Refer to [`EditAutoModRule`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditAutoModRule`]: ../../builder/struct.EditAutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub fn edit<F>(&mut self, f: F) -> Result<()>
+        where F: FnOnce(EditAutoModRule) -> EditAutoModRule {
+        let map = utils::vecmap_to_json_map(f(EditAutoModRule::default()).0);
+
+        *self = http::edit_automod_rule(self.guild_id.0, self.id.0, &map)?;
+
+        Ok(())
+    }
+}
+
+/// The settings of the trigger of an [`AutoModRule`], of which the fields that
+/// apply depend on its [`trigger_type`].
+///
+/// [`AutoModRule`]: struct.AutoModRule.html
+/// [`trigger_type`]: struct.AutoModRule.html#structfield.trigger_type
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AutoModTriggerMetadata {
+    /// The words that trigger a [`Keyword`] rule.
+    ///
+    /// [`Keyword`]: enum.AutoModTriggerType.html#variant.Keyword
+    #[serde(default)]
+    pub keyword_filter: Vec<String>,
+    /// The regular expressions that trigger a [`Keyword`] rule.
+    ///
+    /// [`Keyword`]: enum.AutoModTriggerType.html#variant.Keyword
+    #[serde(default)]
+    pub regex_patterns: Vec<String>,
+    /// The lists of words that trigger a [`KeywordPreset`] rule.
+    ///
+    /// [`KeywordPreset`]: enum.AutoModTriggerType.html#variant.KeywordPreset
+    #[serde(default)]
+    pub presets: Vec<AutoModKeywordPresetType>,
+    /// The words that do not trigger a [`Keyword`] or [`KeywordPreset`] rule.
+    ///
+    /// [`Keyword`]: enum.AutoModTriggerType.html#variant.Keyword
+    /// [`KeywordPreset`]: enum.AutoModTriggerType.html#variant.KeywordPreset
+    #[serde(default)]
+    pub allow_list: Vec<String>,
+    /// The number of unique mentions of roles and users in a message that
+    /// trigger a [`MentionSpam`] rule.
+    ///
+    /// [`MentionSpam`]: enum.AutoModTriggerType.html#variant.MentionSpam
+    #[serde(default)]
+    pub mention_total_limit: Option<u64>,
+    /// Whether a [`MentionSpam`] rule automatically detects mention raids.
+    ///
+    /// [`MentionSpam`]: enum.AutoModTriggerType.html#variant.MentionSpam
+    #[serde(default)]
+    pub mention_raid_protection_enabled: bool,
+}
+
+/// An action that is performed when an [`AutoModRule`] is triggered.
+///
+/// [`AutoModRule`]: struct.AutoModRule.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AutoModAction {
+    /// The type of the action.
+    #[serde(rename = "type")]
+    pub kind: AutoModActionType,
+    /// The settings of the action, of which the fields that apply depend on
+    /// its [`kind`].
+    ///
+    /// [`kind`]: #structfield.kind
+    #[serde(default)]
+    pub metadata: AutoModActionMetadata,
+}
+
+/// The settings of an [`AutoModAction`].
+///
+/// [`AutoModAction`]: struct.AutoModAction.html
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AutoModActionMetadata {
+    /// The channel that a [`SendAlertMessage`] action logs the message to.
+    ///
+    /// [`SendAlertMessage`]: enum.AutoModActionType.html#variant.SendAlertMessage
+    #[serde(default)]
+    pub channel_id: Option<ChannelId>,
+    /// The number of seconds that a [`Timeout`] action times the member out
+    /// for, which is at most 4 weeks.
+    ///
+    /// [`Timeout`]: enum.AutoModActionType.html#variant.Timeout
+    #[serde(default)]
+    pub duration_seconds: Option<u64>,
+    /// The message that a [`BlockMessage`] action shows to the member.
+    ///
+    /// [`BlockMessage`]: enum.AutoModActionType.html#variant.BlockMessage
+    #[serde(default)]
+    pub custom_message: Option<String>,
+}
+
+/// The event that an [`AutoModRule`] is checked on.
+///
+/// [`AutoModRule`]: struct.AutoModRule.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum AutoModEventType {
+    /// A member sends or edits a message.
+    MessageSend = 1,
+}
+
+enum_number!(
+    AutoModEventType {
+        MessageSend,
+    }
+);
+
+impl AutoModEventType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            AutoModEventType::MessageSend => 1,
+        }
+    }
+}
+
+/// The kind of content that triggers an [`AutoModRule`].
+///
+/// [`AutoModRule`]: struct.AutoModRule.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum AutoModTriggerType {
+    /// Words of a custom list.
+    Keyword = 1,
+    /// Content that is generally considered spam.
+    Spam = 3,
+    /// Words of the lists of Discord.
+    KeywordPreset = 4,
+    /// Too many mentions of roles and users in a message.
+    MentionSpam = 5,
+}
+
+enum_number!(
+    AutoModTriggerType {
+        Keyword,
+        Spam,
+        KeywordPreset,
+        MentionSpam,
+    }
+);
+
+impl AutoModTriggerType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            AutoModTriggerType::Keyword => 1,
+            AutoModTriggerType::Spam => 3,
+            AutoModTriggerType::KeywordPreset => 4,
+            AutoModTriggerType::MentionSpam => 5,
+        }
+    }
+}
+
+/// A list of words of Discord that triggers a [`KeywordPreset`] rule.
+///
+/// [`KeywordPreset`]: enum.AutoModTriggerType.html#variant.KeywordPreset
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum AutoModKeywordPresetType {
+    /// Swearing and cursing.
+    Profanity = 1,
+    /// Sexually explicit words.
+    SexualContent = 2,
+    /// Personal insults and hate speech.
+    Slurs = 3,
+}
+
+enum_number!(
+    AutoModKeywordPresetType {
+        Profanity,
+        SexualContent,
+        Slurs,
+    }
+);
+
+impl AutoModKeywordPresetType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            AutoModKeywordPresetType::Profanity => 1,
+            AutoModKeywordPresetType::SexualContent => 2,
+            AutoModKeywordPresetType::Slurs => 3,
+        }
+    }
+}
+
+/// The type of an [`AutoModAction`].
+///
+/// [`AutoModAction`]: struct.AutoModAction.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum AutoModActionType {
+    /// Prevents the message from being sent.
+    BlockMessage = 1,
+    /// Logs the message to a channel.
+    SendAlertMessage = 2,
+    /// Times the member out.
+    Timeout = 3,
+}
+
+enum_number!(
+    AutoModActionType {
+        BlockMessage,
+        SendAlertMessage,
+        Timeout,
+    }
+);
+
+impl AutoModActionType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            AutoModActionType::BlockMessage => 1,
+            AutoModActionType::SendAlertMessage => 2,
+            AutoModActionType::Timeout => 3,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use model::prelude::*;
+
+/// The settings of a guild's listing in the guild discovery, retrieved
+/// through [`GuildId::discovery_metadata`].
+///
+/// [`GuildId::discovery_metadata`]: struct.GuildId.html#method.discovery_metadata
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiscoveryMetadata {
+    /// The Id of the guild.
+    pub guild_id: GuildId,
+    /// The Id of the guild's primary [`DiscoveryCategory`].
+    ///
+    /// [`DiscoveryCategory`]: struct.DiscoveryCategory.html
+    pub primary_category_id: u64,
+    /// The search keywords of the guild, if any are set.
+    pub keywords: Option<Vec<String>>,
+    /// Whether the guild's custom emojis are shown to users who are not in
+    /// the guild.
+    pub emoji_discoverability_enabled: bool,
+    /// When the guild's partner application was last acted upon.
+    pub partner_actioned_timestamp: Option<Timestamp>,
+    /// When the guild last applied for partnership.
+    pub partner_application_timestamp: Option<Timestamp>,
+    /// The Ids of the guild's subcategories, which the primary category is
+    /// not part of.
+    pub category_ids: Vec<u64>,
+}
+
+/// A category that guilds can be listed under in the guild discovery, as
+/// retrieved through [`http::get_discovery_categories`].
+///
+/// [`http::get_discovery_categories`]: ../../http/fn.get_discovery_categories.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiscoveryCategory {
+    /// The Id of the category.
+    pub id: u64,
+    /// The name of the category.
+    pub name: DiscoveryCategoryName,
+    /// Whether the category can be used as a primary category.
+    pub is_primary: bool,
+}
+
+/// The name of a [`DiscoveryCategory`].
+///
+/// [`DiscoveryCategory`]: struct.DiscoveryCategory.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiscoveryCategoryName {
+    /// The name in English.
+    pub default: String,
+    /// The name in other locales, keyed by the locale.
+    #[serde(default)]
+    pub localizations: Option<HashMap<String, String>>,
+}
@@ -6,6 +6,9 @@ use std::fmt::{
 };
 use super::super::id::{EmojiId, RoleId};
 
+#[cfg(feature = "model")]
+use super::super::misc::{cdn_url, ImageFormat};
+
 #[cfg(all(feature = "cache", feature = "model"))]
 use internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -199,6 +202,16 @@ impl Emoji {
     /// ```
     #[inline]
     pub fn url(&self) -> String { format!(cdn!("/emojis/{}.png"), self.id) }
+
+    /// Generates a URL to the emoji's image in the given format and size.
+    ///
+    /// Without a `format`, animated emojis are retrieved as GIF and others as
+    /// WebP. Refer to [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn url_with(&self, format: Option<ImageFormat>, size: u16) -> String {
+        cdn_url("/emojis", &self.id.to_string(), self.animated, format, size)
+    }
 }
 
 impl Display for Emoji {
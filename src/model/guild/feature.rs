@@ -0,0 +1,157 @@
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+
+/// A feature enabled for a [`Guild`].
+///
+/// Discord adds new features over time, which are available as
+/// [`GuildFeature::Unknown`] until this enum is updated. More variants may be
+/// added in future releases, so matching on it should include a wildcard arm.
+///
+/// [`Guild`]: struct.Guild.html
+/// [`GuildFeature::Unknown`]: #variant.Unknown
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum GuildFeature {
+    /// The guild has access to set an animated banner.
+    AnimatedBanner,
+    /// The guild has access to set an animated icon.
+    AnimatedIcon,
+    /// The guild has access to set a banner.
+    Banner,
+    /// The guild has access to use commerce features.
+    Commerce,
+    /// The guild can enable the welcome screen, membership screening, stage
+    /// channels, discovery, and receives community updates.
+    Community,
+    /// The guild is able to be discovered in the directory.
+    Discoverable,
+    /// The guild is able to be featured in the directory.
+    Featurable,
+    /// The guild has access to set an invite splash background.
+    InviteSplash,
+    /// The guild has enabled membership screening.
+    MemberVerificationGateEnabled,
+    /// The guild has enabled monetization.
+    MonetizationEnabled,
+    /// The guild has increased custom sticker slots.
+    MoreStickers,
+    /// The guild has access to create news channels.
+    News,
+    /// The guild is partnered.
+    Partnered,
+    /// The guild can be previewed before joining via membership screening or
+    /// the directory.
+    PreviewEnabled,
+    /// The guild has access to create private threads.
+    PrivateThreads,
+    /// The guild is able to set role icons.
+    RoleIcons,
+    /// The guild has enabled ticketed events.
+    TicketedEventsEnabled,
+    /// The guild has access to set a vanity URL.
+    VanityUrl,
+    /// The guild is verified.
+    Verified,
+    /// The guild has access to set 384kbps bitrate in voice channels.
+    VipRegions,
+    /// The guild has enabled the welcome screen.
+    WelcomeScreenEnabled,
+    /// A feature not known to this version of the library.
+    Unknown(String),
+}
+
+impl GuildFeature {
+    /// Returns the name of the feature as used by Discord.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GuildFeature::AnimatedBanner => "ANIMATED_BANNER",
+            GuildFeature::AnimatedIcon => "ANIMATED_ICON",
+            GuildFeature::Banner => "BANNER",
+            GuildFeature::Commerce => "COMMERCE",
+            GuildFeature::Community => "COMMUNITY",
+            GuildFeature::Discoverable => "DISCOVERABLE",
+            GuildFeature::Featurable => "FEATURABLE",
+            GuildFeature::InviteSplash => "INVITE_SPLASH",
+            GuildFeature::MemberVerificationGateEnabled => "MEMBER_VERIFICATION_GATE_ENABLED",
+            GuildFeature::MonetizationEnabled => "MONETIZATION_ENABLED",
+            GuildFeature::MoreStickers => "MORE_STICKERS",
+            GuildFeature::News => "NEWS",
+            GuildFeature::Partnered => "PARTNERED",
+            GuildFeature::PreviewEnabled => "PREVIEW_ENABLED",
+            GuildFeature::PrivateThreads => "PRIVATE_THREADS",
+            GuildFeature::RoleIcons => "ROLE_ICONS",
+            GuildFeature::TicketedEventsEnabled => "TICKETED_EVENTS_ENABLED",
+            GuildFeature::VanityUrl => "VANITY_URL",
+            GuildFeature::Verified => "VERIFIED",
+            GuildFeature::VipRegions => "VIP_REGIONS",
+            GuildFeature::WelcomeScreenEnabled => "WELCOME_SCREEN_ENABLED",
+            GuildFeature::Unknown(ref name) => name,
+        }
+    }
+}
+
+impl Display for GuildFeature {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.as_str()) }
+}
+
+impl From<String> for GuildFeature {
+    fn from(name: String) -> Self {
+        match &name[..] {
+            "ANIMATED_BANNER" => GuildFeature::AnimatedBanner,
+            "ANIMATED_ICON" => GuildFeature::AnimatedIcon,
+            "BANNER" => GuildFeature::Banner,
+            "COMMERCE" => GuildFeature::Commerce,
+            "COMMUNITY" => GuildFeature::Community,
+            "DISCOVERABLE" => GuildFeature::Discoverable,
+            "FEATURABLE" => GuildFeature::Featurable,
+            "INVITE_SPLASH" => GuildFeature::InviteSplash,
+            "MEMBER_VERIFICATION_GATE_ENABLED" => GuildFeature::MemberVerificationGateEnabled,
+            "MONETIZATION_ENABLED" => GuildFeature::MonetizationEnabled,
+            "MORE_STICKERS" => GuildFeature::MoreStickers,
+            "NEWS" => GuildFeature::News,
+            "PARTNERED" => GuildFeature::Partnered,
+            "PREVIEW_ENABLED" => GuildFeature::PreviewEnabled,
+            "PRIVATE_THREADS" => GuildFeature::PrivateThreads,
+            "ROLE_ICONS" => GuildFeature::RoleIcons,
+            "TICKETED_EVENTS_ENABLED" => GuildFeature::TicketedEventsEnabled,
+            "VANITY_URL" => GuildFeature::VanityUrl,
+            "VERIFIED" => GuildFeature::Verified,
+            "VIP_REGIONS" => GuildFeature::VipRegions,
+            "WELCOME_SCREEN_ENABLED" => GuildFeature::WelcomeScreenEnabled,
+            _ => GuildFeature::Unknown(name),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GuildFeature {
+    fn from(name: &'a str) -> Self { GuildFeature::from(name.to_owned()) }
+}
+
+impl<'de> Deserialize<'de> for GuildFeature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        String::deserialize(deserializer).map(GuildFeature::from)
+    }
+}
+
+impl Serialize for GuildFeature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GuildFeature;
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(GuildFeature::from("COMMUNITY"), GuildFeature::Community);
+        assert_eq!(GuildFeature::from("VANITY_URL").as_str(), "VANITY_URL");
+
+        let unknown = GuildFeature::from("SOME_NEW_FEATURE");
+
+        assert_eq!(unknown, GuildFeature::Unknown("SOME_NEW_FEATURE".to_owned()));
+        assert_eq!(unknown.to_string(), "SOME_NEW_FEATURE");
+    }
+}
@@ -3,12 +3,26 @@ use model::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
 #[cfg(feature = "model")]
-use builder::{EditGuild, EditMember, EditRole};
+use builder::{
+    EditAutoModRule,
+    EditDiscoveryMetadata,
+    EditGuild,
+    EditMember,
+    EditOnboarding,
+    EditRole,
+    EditSticker
+};
 #[cfg(feature = "model")]
 use internal::prelude::*;
 #[cfg(feature = "model")]
 use model::guild::BanOptions;
 #[cfg(feature = "model")]
+use http::AttachmentType;
+#[cfg(feature = "model")]
+use http::ratelimiting::{self, Route};
+#[cfg(feature = "model")]
+use std::time::Duration;
+#[cfg(feature = "model")]
 use {http, utils};
 
 #[cfg(feature = "model")]
@@ -20,6 +34,26 @@ impl GuildId {
                          concept.")]
     pub fn as_channel_id(&self) -> ChannelId { ChannelId(self.0) }
 
+    /// Gets all active threads of the guild, including private threads, and
+    /// the membership of the current user in each of the threads it joined.
+    #[inline]
+    pub fn active_threads(&self) -> Result<ThreadsData> { http::get_active_threads(self.0) }
+
+    /// Lists the guild under a subcategory of the guild discovery, of which a
+    /// guild can have up to 5.
+    ///
+    /// Refer to [`http::get_discovery_categories`] for the available
+    /// categories.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`http::get_discovery_categories`]: ../../http/fn.get_discovery_categories.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn add_discovery_category(&self, category_id: u64) -> Result<()> {
+        http::add_guild_discovery_category(self.0, category_id)
+    }
+
     /// Ban a [`User`] from the guild. All messages by the
     /// user within the last given number of days given will be deleted.
     ///
@@ -84,6 +118,78 @@ impl GuildId {
         http::get_audit_logs(self.0, action_type, user_id.map(|u| u.0), before.map(|a| a.0), limit)
     }
 
+    /// Searches the most recent audit log entries of the given `action` for
+    /// the newest one that passes the `filter` and was created within the
+    /// `window` of time before now.
+    ///
+    /// This is used to attribute an action seen through an event, such as a
+    /// [`GuildBanAddEvent`], to the user who did it and their reason. Refer
+    /// to [`GuildBanAddEvent::audit_log_entry`],
+    /// [`GuildMemberRemoveEvent::kick_audit_log_entry`], and
+    /// [`MessageDeleteEvent::audit_log_entry`] for shortcuts.
+    ///
+    /// Only the 10 most recent entries of the action are searched, as
+    /// Discord may take a moment to create the entry and other actions may
+    /// be logged in the meantime.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::Ratelimited`] instead of waiting if the audit
+    /// log route or all requests are currently ratelimited, so that event
+    /// handlers are not blocked by a burst of moderation actions. If another
+    /// request to the route is in progress, its ratelimit is unknown and the
+    /// request is made regardless.
+    ///
+    /// [`GuildBanAddEvent`]: ../event/struct.GuildBanAddEvent.html
+    /// [`GuildBanAddEvent::audit_log_entry`]: ../event/struct.GuildBanAddEvent.html#method.audit_log_entry
+    /// [`GuildMemberRemoveEvent::kick_audit_log_entry`]: ../event/struct.GuildMemberRemoveEvent.html#method.kick_audit_log_entry
+    /// [`MessageDeleteEvent::audit_log_entry`]: ../event/struct.MessageDeleteEvent.html#method.audit_log_entry
+    /// [`ModelError::Ratelimited`]: ../error/enum.Error.html#variant.Ratelimited
+    /// [View Audit Log]: ../permissions/struct.Permissions.html#associatedconstant.VIEW_AUDIT_LOG
+    pub fn find_audit_log_entry<F>(&self, action: Action, window: Duration, filter: F)
+        -> Result<Option<AuditLogEntry>> where F: Fn(&AuditLogEntry) -> bool {
+        if let Some(reset_after) = ratelimiting::global_reset_after() {
+            return Err(Error::Model(ModelError::Ratelimited(reset_after)));
+        }
+
+        if let Some(ratelimit) = ratelimiting::try_route_ratelimit(&Route::GuildsIdAuditLogs(self.0)) {
+            if let Some(reset_after) = ratelimit.reset_after() {
+                if ratelimit.remaining <= 0 {
+                    return Err(Error::Model(ModelError::Ratelimited(reset_after)));
+                }
+            }
+        }
+
+        let logs = http::get_audit_logs(self.0, Some(action.num()), None, None, Some(10))?;
+
+        let now = Timestamp::now().timestamp_millis();
+        let window = window.as_secs() as i64 * 1000 + i64::from(window.subsec_millis());
+
+        Ok(newest_audit_log_entry(logs.entries, now, window, filter))
+    }
+
+    /// Gets an [`AutoModRule`] of the guild by its Id.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn automod_rule<R: Into<AutoModRuleId>>(&self, rule_id: R) -> Result<AutoModRule> {
+        http::get_automod_rule(self.0, rule_id.into().0)
+    }
+
+    /// Gets all [`AutoModRule`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn automod_rules(&self) -> Result<Vec<AutoModRule>> { http::get_automod_rules(self.0) }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// [`Guild`]: ../guild/struct.Guild.html
@@ -97,6 +203,22 @@ impl GuildId {
         Ok(channels)
     }
 
+    /// Creates an [`AutoModRule`] in the guild.
+    ///
+    /// Refer to [`EditAutoModRule`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [`EditAutoModRule`]: ../../builder/struct.EditAutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub fn create_automod_rule<F>(&self, f: F) -> Result<AutoModRule>
+        where F: FnOnce(EditAutoModRule) -> EditAutoModRule {
+        let map = utils::vecmap_to_json_map(f(EditAutoModRule::default()).0);
+
+        http::create_automod_rule(self.0, &map)
+    }
+
     /// Creates a [`GuildChannel`] in the the guild.
     ///
     /// Refer to [`http::create_channel`] for more information.
@@ -130,7 +252,7 @@ impl GuildId {
     ) -> Result<GuildChannel> {
         let map = json!({
             "name": name,
-            "type": kind as u8,
+            "type": kind.num(),
             "parent_id": category.map(|c| c.0)
         });
 
@@ -209,6 +331,22 @@ impl GuildId {
         Ok(role)
     }
 
+    /// Uploads a sticker to the guild, with the image of the sticker as the
+    /// `file`.
+    ///
+    /// Refer to [`EditSticker`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`EditSticker`]: ../../builder/struct.EditSticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub fn create_sticker<'a, F, T>(&self, file: T, f: F) -> Result<Sticker>
+        where F: FnOnce(EditSticker) -> EditSticker, T: Into<AttachmentType<'a>> {
+        let map = utils::vecmap_to_json_map(f(EditSticker::default()).0);
+
+        http::create_sticker(self.0, &map, file)
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -220,6 +358,21 @@ impl GuildId {
     #[inline]
     pub fn delete(&self) -> Result<PartialGuild> { http::delete_guild(self.0) }
 
+    /// Deletes an [`AutoModRule`] from the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn delete_automod_rule<R: Into<AutoModRuleId>>(&self, rule_id: R) -> Result<()> {
+        self._delete_automod_rule(rule_id.into())
+    }
+
+    fn _delete_automod_rule(self, rule_id: AutoModRuleId) -> Result<()> {
+        http::delete_automod_rule(self.0, rule_id.0)
+    }
+
     /// Deletes an [`Emoji`] from the guild.
     ///
     /// Requires the [Manage Emojis] permission.
@@ -268,6 +421,31 @@ impl GuildId {
         http::delete_role(self.0, role_id.0)
     }
 
+    /// Deletes a [`Sticker`] from the guild.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`Sticker`]: ../guild/struct.Sticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub fn delete_sticker<S: Into<StickerId>>(&self, sticker_id: S) -> Result<()> {
+        self._delete_sticker(sticker_id.into())
+    }
+
+    fn _delete_sticker(self, sticker_id: StickerId) -> Result<()> {
+        http::delete_sticker(self.0, sticker_id.0)
+    }
+
+    /// Retrieves the settings of the guild's listing in the guild discovery.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn discovery_metadata(&self) -> Result<DiscoveryMetadata> {
+        http::get_guild_discovery_metadata(self.0)
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// Refer to [`Guild::edit`] for more information.
@@ -284,6 +462,53 @@ impl GuildId {
         http::edit_guild(self.0, &map)
     }
 
+    /// Edits an [`AutoModRule`] of the guild, optionally setting its new
+    /// fields.
+    ///
+    /// Refer to [`EditAutoModRule`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [`EditAutoModRule`]: ../../builder/struct.EditAutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn edit_automod_rule<F, R>(&self, rule_id: R, f: F) -> Result<AutoModRule>
+        where F: FnOnce(EditAutoModRule) -> EditAutoModRule, R: Into<AutoModRuleId> {
+        self._edit_automod_rule(rule_id.into(), f)
+    }
+
+    fn _edit_automod_rule<F>(self, rule_id: AutoModRuleId, f: F) -> Result<AutoModRule>
+        where F: FnOnce(EditAutoModRule) -> EditAutoModRule {
+        let map = utils::vecmap_to_json_map(f(EditAutoModRule::default()).0);
+
+        http::edit_automod_rule(self.0, rule_id.0, &map)
+    }
+
+    /// Edits the settings of the guild's listing in the guild discovery.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Examples
+    ///
+    /// Set the search keywords of a guild:
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::id::GuildId;
+    ///
+    /// let _ = GuildId(7).edit_discovery_metadata(|m| m
+    ///     .keywords(Some(vec!["rust", "programming"])));
+    /// ```
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn edit_discovery_metadata<F>(&self, f: F) -> Result<DiscoveryMetadata>
+        where F: FnOnce(EditDiscoveryMetadata) -> EditDiscoveryMetadata {
+        let map = utils::vecmap_to_json_map(f(EditDiscoveryMetadata::default()).0);
+
+        http::edit_guild_discovery_metadata(self.0, &map)
+    }
+
     /// Edits an [`Emoji`]'s name in the guild.
     ///
     /// Also see [`Emoji::edit`] if you have the `cache` and `methods` features
@@ -328,9 +553,29 @@ impl GuildId {
 
     fn _edit_member<F>(self, user_id: UserId, f: F) -> Result<()>
         where F: FnOnce(EditMember) -> EditMember {
-        let map = utils::vecmap_to_json_map(f(EditMember::default()).0);
+        let (map, reason) = f(EditMember::default()).into_request();
 
-        http::edit_member(self.0, user_id.0, &map)
+        http::edit_member_with_reason(self.0, user_id.0, &map, reason.as_ref().map(String::as_str))
+    }
+
+    /// Changes the multi-factor authentication level required for moderators
+    /// of the guild, returning the new level.
+    ///
+    /// With [`MfaLevel::Elevated`], moderation actions such as kicking,
+    /// banning, or deleting messages can only be taken by users with
+    /// multi-factor authentication enabled on their account.
+    ///
+    /// **Note**: Requires the current user to be the owner of the guild, with
+    /// multi-factor authentication enabled on their account.
+    ///
+    /// [`MfaLevel::Elevated`]: ../guild/enum.MfaLevel.html#variant.Elevated
+    #[inline]
+    pub fn edit_mfa_level(&self, level: MfaLevel) -> Result<MfaLevel> {
+        let map = json!({
+            "level": level.num(),
+        });
+
+        http::edit_guild_mfa_level(self.0, &map)
     }
 
     /// Edits the current user's nickname for the guild.
@@ -345,6 +590,46 @@ impl GuildId {
         http::edit_nickname(self.0, new_nickname)
     }
 
+    /// Edits the current user's nickname for the guild, displaying the given
+    /// reason in the guild's audit log.
+    ///
+    /// Pass `None` to reset the nickname.
+    ///
+    /// Requires the [Change Nickname] permission.
+    ///
+    /// [Change Nickname]: ../permissions/struct.Permissions.html#associatedconstant.CHANGE_NICKNAME
+    #[inline]
+    pub fn edit_nickname_with_reason(&self, new_nickname: Option<&str>, reason: Option<&str>)
+        -> Result<()> {
+        http::edit_nickname_with_reason(self.0, new_nickname, reason)
+    }
+
+    /// Edits the onboarding of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Examples
+    ///
+    /// Enable the onboarding, opting new members into a channel by default:
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::id::{ChannelId, GuildId};
+    ///
+    /// let _ = GuildId(7).edit_onboarding(|o| o
+    ///     .default_channels(vec![ChannelId(8)])
+    ///     .enabled(true));
+    /// ```
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn edit_onboarding<F>(&self, f: F) -> Result<Onboarding>
+        where F: FnOnce(EditOnboarding) -> EditOnboarding {
+        let map = utils::vecmap_to_json_map(f(EditOnboarding::default()).0);
+
+        http::edit_guild_onboarding(self.0, &map)
+    }
+
     /// Edits a [`Role`], optionally setting its new fields.
     ///
     /// Requires the [Manage Roles] permission.
@@ -402,6 +687,28 @@ impl GuildId {
         http::edit_role_position(self.0, role_id.0, position)
     }
 
+    /// Edits a [`Sticker`] of the guild, optionally setting its new fields.
+    ///
+    /// Refer to [`EditSticker`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`EditSticker`]: ../../builder/struct.EditSticker.html
+    /// [`Sticker`]: ../guild/struct.Sticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub fn edit_sticker<F, S>(&self, sticker_id: S, f: F) -> Result<Sticker>
+        where F: FnOnce(EditSticker) -> EditSticker, S: Into<StickerId> {
+        self._edit_sticker(sticker_id.into(), f)
+    }
+
+    fn _edit_sticker<F>(self, sticker_id: StickerId, f: F) -> Result<Sticker>
+        where F: FnOnce(EditSticker) -> EditSticker {
+        let map = utils::vecmap_to_json_map(f(EditSticker::default()).0);
+
+        http::edit_sticker(self.0, sticker_id.0, &map)
+    }
+
     /// Search the cache for the guild.
     #[cfg(feature = "cache")]
     #[deprecated(since = "0.5.8", note = "Use the `to_guild_cached`-method instead.")]
@@ -526,6 +833,10 @@ impl GuildId {
         http::edit_member(self.0, user_id.0, &map)
     }
 
+    /// Gets the onboarding of the guild, which is shown to new members.
+    #[inline]
+    pub fn onboarding(&self) -> Result<Onboarding> { http::get_guild_onboarding(self.0) }
+
     /// Gets the number of [`Member`]s that would be pruned with the given
     /// number of days.
     ///
@@ -541,6 +852,16 @@ impl GuildId {
         http::get_guild_prune_count(self.0, &map)
     }
 
+    /// Removes the guild from a subcategory of the guild discovery.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub fn remove_discovery_category(&self, category_id: u64) -> Result<()> {
+        http::delete_guild_discovery_category(self.0, category_id)
+    }
+
     /// Re-orders the channels of the guild.
     ///
     /// Accepts an iterator of a tuple of the channel ID to modify and its new
@@ -638,6 +959,20 @@ impl GuildId {
         http::start_guild_prune(self.0, &map)
     }
 
+    /// Gets a [`Sticker`] of the guild by its Id.
+    ///
+    /// [`Sticker`]: ../guild/struct.Sticker.html
+    #[inline]
+    pub fn sticker<S: Into<StickerId>>(&self, sticker_id: S) -> Result<Sticker> {
+        http::get_guild_sticker(self.0, sticker_id.into().0)
+    }
+
+    /// Gets all [`Sticker`]s of the guild.
+    ///
+    /// [`Sticker`]: ../guild/struct.Sticker.html
+    #[inline]
+    pub fn stickers(&self) -> Result<Vec<Sticker>> { http::get_guild_stickers(self.0) }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// Requires the [Ban Members] permission.
@@ -672,6 +1007,27 @@ impl GuildId {
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { http::get_guild_webhooks(self.0) }
 }
 
+/// Finds the newest of the audit log `entries` that passes the `filter` and was
+/// created at most `window` milliseconds before `now`.
+#[cfg(feature = "model")]
+fn newest_audit_log_entry<F>(entries: HashMap<AuditLogEntryId, AuditLogEntry>,
+                             now: i64,
+                             window: i64,
+                             filter: F) -> Option<AuditLogEntry>
+    where F: Fn(&AuditLogEntry) -> bool {
+    entries
+        .into_iter()
+        .map(|(_, entry)| entry)
+        .filter(|entry| {
+            // The clocks of Discord and the client may differ, in which
+            // case entries might appear to be from the future.
+            let age = now - entry.id.created_at().timestamp_millis();
+
+            age <= window && filter(entry)
+        })
+        .max_by_key(|entry| entry.id)
+}
+
 impl From<PartialGuild> for GuildId {
     /// Gets the Id of a partial guild.
     fn from(guild: PartialGuild) -> GuildId { guild.id }
@@ -711,3 +1067,58 @@ impl<'a> From<&'a Guild> for GuildId {
     /// Gets the Id of Guild.
     fn from(live_guild: &Guild) -> GuildId { live_guild.id }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use model::guild::{Action, ActionMember, AuditLogEntry};
+    use model::id::{AuditLogEntryId, UserId};
+    use std::collections::HashMap;
+    use super::newest_audit_log_entry;
+
+    const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+    const NOW: i64 = 1_600_000_000_000;
+
+    fn entry(millis_ago: i64, target_id: u64) -> (AuditLogEntryId, AuditLogEntry) {
+        let id = AuditLogEntryId(((NOW - millis_ago - DISCORD_EPOCH) as u64) << 22);
+
+        (id, AuditLogEntry {
+            target_id,
+            action: Action::Member(ActionMember::BanAdd),
+            reason: None,
+            user_id: UserId(1),
+            changes: None,
+            id,
+            options: None,
+        })
+    }
+
+    fn entries() -> HashMap<AuditLogEntryId, AuditLogEntry> {
+        vec![entry(20_000, 2), entry(4_000, 2), entry(3_000, 3), entry(-1_000, 4)]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn newest_entry_within_window() {
+        let newest = newest_audit_log_entry(entries(), NOW, 5_000, |_| true).unwrap();
+
+        // Entries from the future are within the window.
+        assert_eq!(newest.target_id, 4);
+    }
+
+    #[test]
+    fn entries_are_filtered() {
+        let newest = newest_audit_log_entry(entries(), NOW, 5_000, |e| e.target_id == 2).unwrap();
+        assert_eq!(newest.id, entry(4_000, 2).0);
+
+        assert!(newest_audit_log_entry(entries(), NOW, 5_000, |e| e.target_id == 5).is_none());
+    }
+
+    #[test]
+    fn entries_outside_window_are_skipped() {
+        assert!(newest_audit_log_entry(entries(), NOW, 1_000, |e| e.target_id == 2).is_none());
+
+        let newest = newest_audit_log_entry(entries(), NOW, 30_000, |e| e.target_id == 2).unwrap();
+        assert_eq!(newest.id, entry(4_000, 2).0);
+    }
+}
@@ -0,0 +1,201 @@
+use model::prelude::*;
+
+#[cfg(any(feature = "model", feature = "utils"))]
+use model::misc::cdn_image_url;
+
+/// The information shared by [`Guild`]s and [`PartialGuild`]s, allowing to
+/// write functions that accept either.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::guild::GuildLike;
+///
+/// fn describe<G: GuildLike>(guild: &G) -> String {
+///     format!("{} ({} roles)", guild.name(), guild.roles().len())
+/// }
+/// ```
+///
+/// [`Guild`]: struct.Guild.html
+/// [`PartialGuild`]: struct.PartialGuild.html
+pub trait GuildLike {
+    /// The Id of the guild.
+    fn id(&self) -> GuildId;
+
+    /// The Id of the voice channel that is considered the AFK channel, if
+    /// there is one.
+    fn afk_channel_id(&self) -> Option<ChannelId>;
+
+    /// The amount of seconds a user can be inactive in a voice channel before
+    /// being moved to the AFK channel.
+    fn afk_timeout(&self) -> u64;
+
+    /// Whether notifications for all messages are enabled by default.
+    fn default_message_notifications(&self) -> DefaultMessageNotificationLevel;
+
+    /// The custom emojis of the guild.
+    fn emojis(&self) -> &HashMap<EmojiId, Emoji>;
+
+    /// The features enabled for the guild.
+    fn features(&self) -> &[GuildFeature];
+
+    /// The hash of the guild's icon, if it has one.
+    fn icon(&self) -> Option<&str>;
+
+    /// The multi-factor authentication level required for moderators.
+    fn mfa_level(&self) -> MfaLevel;
+
+    /// The name of the guild.
+    fn name(&self) -> &str;
+
+    /// The Id of the user owning the guild.
+    fn owner_id(&self) -> UserId;
+
+    /// The premium tier the guild reached through boosts.
+    fn premium_tier(&self) -> PremiumTier;
+
+    /// The voice region of the guild.
+    fn region(&self) -> &str;
+
+    /// The roles of the guild.
+    fn roles(&self) -> &HashMap<RoleId, Role>;
+
+    /// The hash of the guild's splash image, if it has one.
+    fn splash(&self) -> Option<&str>;
+
+    /// The verification level required to participate in the guild.
+    fn verification_level(&self) -> VerificationLevel;
+
+    /// Returns the formatted URL of the guild's icon, if one exists.
+    #[cfg(any(feature = "model", feature = "utils"))]
+    fn icon_url(&self) -> Option<String> {
+        self.icon()
+            .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id(), icon))
+    }
+
+    /// Returns the URL of the guild's icon in the given format and size, if
+    /// one exists.
+    ///
+    /// Refer to [`Guild::icon_url_with`] for more information.
+    ///
+    /// [`Guild::icon_url_with`]: struct.Guild.html#method.icon_url_with
+    #[cfg(any(feature = "model", feature = "utils"))]
+    fn icon_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon()
+            .map(|icon| cdn_image_url(&format!("/icons/{}", self.id()), icon, format, size))
+    }
+
+    /// Whether the given user owns the guild.
+    fn is_owner<U: Into<UserId>>(&self, user_id: U) -> bool where Self: Sized {
+        self.owner_id() == user_id.into()
+    }
+
+    /// Obtain a reference to a role by its name.
+    ///
+    /// **Note**: If two or more roles have the same name, the first one
+    /// found is returned.
+    fn role_by_name(&self, role_name: &str) -> Option<&Role> {
+        self.roles().values().find(|role| role_name == role.name)
+    }
+}
+
+macro_rules! impl_guild_like {
+    ($($guild:ident),*) => {
+        $(
+            impl GuildLike for $guild {
+                fn id(&self) -> GuildId { self.id }
+
+                fn afk_channel_id(&self) -> Option<ChannelId> { self.afk_channel_id }
+
+                fn afk_timeout(&self) -> u64 { self.afk_timeout }
+
+                fn default_message_notifications(&self) -> DefaultMessageNotificationLevel {
+                    self.default_message_notifications
+                }
+
+                fn emojis(&self) -> &HashMap<EmojiId, Emoji> { &self.emojis }
+
+                fn features(&self) -> &[GuildFeature] { &self.features }
+
+                fn icon(&self) -> Option<&str> { self.icon.as_ref().map(String::as_str) }
+
+                fn mfa_level(&self) -> MfaLevel { self.mfa_level }
+
+                fn name(&self) -> &str { &self.name }
+
+                fn owner_id(&self) -> UserId { self.owner_id }
+
+                fn premium_tier(&self) -> PremiumTier { self.premium_tier }
+
+                fn region(&self) -> &str { &self.region }
+
+                fn roles(&self) -> &HashMap<RoleId, Role> { &self.roles }
+
+                fn splash(&self) -> Option<&str> { self.splash.as_ref().map(String::as_str) }
+
+                fn verification_level(&self) -> VerificationLevel { self.verification_level }
+            }
+        )*
+    }
+}
+
+impl_guild_like!(Guild, PartialGuild);
+
+impl From<Guild> for PartialGuild {
+    /// Converts a guild into its partial form, dropping the information that
+    /// only full guilds carry, such as channels and members.
+    ///
+    /// As guilds do not carry the widget settings, the partial guild's
+    /// `embed_channel_id` and `embed_enabled` are not set.
+    fn from(guild: Guild) -> PartialGuild {
+        PartialGuild {
+            id: guild.id,
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            default_message_notifications: guild.default_message_notifications,
+            embed_channel_id: None,
+            embed_enabled: false,
+            emojis: guild.emojis,
+            features: guild.features,
+            icon: guild.icon,
+            mfa_level: guild.mfa_level,
+            name: guild.name,
+            owner_id: guild.owner_id,
+            premium_subscription_count: guild.premium_subscription_count,
+            premium_tier: guild.premium_tier,
+            region: guild.region,
+            roles: guild.roles,
+            splash: guild.splash,
+            verification_level: guild.verification_level,
+        }
+    }
+}
+
+impl<'a> From<&'a Guild> for PartialGuild {
+    fn from(guild: &'a Guild) -> PartialGuild {
+        PartialGuild {
+            id: guild.id,
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            default_message_notifications: guild.default_message_notifications,
+            embed_channel_id: None,
+            embed_enabled: false,
+            emojis: guild.emojis.clone(),
+            features: guild.features.clone(),
+            icon: guild.icon.clone(),
+            mfa_level: guild.mfa_level,
+            name: guild.name.clone(),
+            owner_id: guild.owner_id,
+            premium_subscription_count: guild.premium_subscription_count,
+            premium_tier: guild.premium_tier,
+            region: guild.region.clone(),
+            roles: guild.roles.clone(),
+            splash: guild.splash.clone(),
+            verification_level: guild.verification_level,
+        }
+    }
+}
+
+impl From<Guild> for GuildContainer {
+    fn from(guild: Guild) -> GuildContainer { GuildContainer::Guild(guild.into()) }
+}
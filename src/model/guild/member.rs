@@ -1,5 +1,4 @@
 use model::prelude::*;
-use chrono::{DateTime, FixedOffset};
 use std::fmt::{
     Display,
     Formatter,
@@ -60,7 +59,7 @@ pub struct Member {
     /// The unique Id of the guild that the member is a part of.
     pub guild_id: GuildId,
     /// Timestamp representing the date when the member joined.
-    pub joined_at: Option<DateTime<FixedOffset>>,
+    pub joined_at: Option<Timestamp>,
     /// Indicator of whether the member can speak in voice channels.
     pub mute: bool,
     /// The member's nickname, if present.
@@ -238,9 +237,44 @@ impl Member {
     /// [`EditMember`]: ../../builder/struct.EditMember.html
     #[cfg(feature = "cache")]
     pub fn edit<F: FnOnce(EditMember) -> EditMember>(&self, f: F) -> Result<()> {
-        let map = utils::vecmap_to_json_map(f(EditMember::default()).0);
+        let (map, reason) = f(EditMember::default()).into_request();
+        let reason = reason.as_ref().map(String::as_str);
 
-        http::edit_member(self.guild_id.0, self.user.read().id.0, &map)
+        http::edit_member_with_reason(self.guild_id.0, self.user.read().id.0, &map, reason)
+    }
+
+    /// Changes the member's nickname, displaying the given reason in the
+    /// guild's audit log. Pass `None` as the nickname to reset it.
+    ///
+    /// If the member is the current user, their nickname is changed through
+    /// [`GuildId::edit_nickname_with_reason`], which only requires the
+    /// [Change Nickname] permission. Otherwise the [Manage Nicknames]
+    /// permission is required.
+    ///
+    /// # Examples
+    ///
+    /// Reset the nickname of a member:
+    ///
+    /// ```rust,ignore
+    /// // assuming a `member` has already been bound
+    /// member.edit_nick(None, Some("Inappropriate nickname"))?;
+    /// ```
+    ///
+    /// [`GuildId::edit_nickname_with_reason`]: ../id/struct.GuildId.html#method.edit_nickname_with_reason
+    /// [Change Nickname]: ../permissions/struct.Permissions.html#associatedconstant.CHANGE_NICKNAME
+    /// [Manage Nicknames]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_NICKNAMES
+    #[cfg(feature = "cache")]
+    pub fn edit_nick(&self, nickname: Option<&str>, reason: Option<&str>) -> Result<()> {
+        let user_id = self.user.read().id;
+
+        if user_id == CACHE.read().user.id {
+            return self.guild_id.edit_nickname_with_reason(nickname, reason);
+        }
+
+        let mut map = JsonMap::new();
+        map.insert("nick".to_string(), json!(nickname));
+
+        http::edit_member_with_reason(self.guild_id.0, user_id.0, &map, reason)
     }
 
     /// Retrieves the ID and position of the member's highest role in the
@@ -487,18 +521,31 @@ impl Display for Member {
 
 /// A partial amount of data for a member.
 ///
-/// This is used in [`Message`]s from [`Guild`]s.
+/// This is used in [`Message`]s from [`Guild`]s, and for the members
+/// selected through an [`Interaction`].
 ///
 /// [`Guild`]: struct.Guild.html
+/// [`Interaction`]: ../interaction/struct.Interaction.html
 /// [`Message`]: ../channel/struct.Message.html
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PartialMember {
     /// Indicator of whether the member can hear in voice channels.
+    ///
+    /// This is always `false` for the members selected through an
+    /// interaction, as Discord does not send it.
+    #[serde(default)]
     pub deaf: bool,
     /// Timestamp representing the date when the member joined.
-    pub joined_at: Option<DateTime<FixedOffset>>,
+    pub joined_at: Option<Timestamp>,
     /// Indicator of whether the member can speak in voice channels.
+    ///
+    /// This is always `false` for the members selected through an
+    /// interaction, as Discord does not send it.
+    #[serde(default)]
     pub mute: bool,
+    /// The nickname of the member, if one was set.
+    #[serde(default)]
+    pub nick: Option<String>,
     /// Vector of Ids of [`Role`]s given to the member.
     pub roles: Vec<RoleId>,
 }
@@ -1,24 +1,38 @@
 //! Models relating to guilds and types that it owns.
 
+mod automod;
+mod discovery;
 mod emoji;
+mod feature;
 mod guild_id;
+mod guild_like;
 mod integration;
 mod member;
+mod onboarding;
 mod partial_guild;
 mod role;
+mod sticker;
 mod audit_log;
 
+pub use self::automod::*;
+pub use self::discovery::*;
 pub use self::emoji::*;
+pub use self::feature::*;
 pub use self::guild_id::*;
+pub use self::guild_like::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::onboarding::*;
 pub use self::partial_guild::*;
 pub use self::role::*;
+pub use self::sticker::*;
 pub use self::audit_log::*;
 
-use chrono::{DateTime, FixedOffset};
 use model::prelude::*;
+#[cfg(any(feature = "model", feature = "utils"))]
+use model::misc::cdn_image_url;
 use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
 use serde_json;
 use super::utils::*;
 
@@ -68,18 +82,14 @@ pub struct Guild {
     pub emojis: HashMap<EmojiId, Emoji>,
     /// Default explicit content filter level.
     pub explicit_content_filter: ExplicitContentFilter,
-    /// VIP features enabled for the guild. Can be obtained through the
+    /// Features enabled for the guild, such as those obtained through the
     /// [Discord Partnership] website.
     ///
-    /// The following is a list of known features:
-    ///
-    /// - `INVITE_SPLASH`
-    /// - `VANITY_URL`
-    /// - `VERIFIED`
-    /// - `VIP_REGIONS`
+    /// Refer to [`GuildFeature`] for a list of known features.
     ///
+    /// [`GuildFeature`]: enum.GuildFeature.html
     /// [Discord Partnership]: https://discordapp.com/partners
-    pub features: Vec<String>,
+    pub features: Vec<GuildFeature>,
     /// The hash of the icon used by the guild.
     ///
     /// In the client, this appears on the guild list on the left-hand side.
@@ -90,7 +100,7 @@ pub struct Guild {
     /// that of the default channel (typically `#general`).
     pub id: GuildId,
     /// The date that the current user joined the guild.
-    pub joined_at: DateTime<FixedOffset>,
+    pub joined_at: Timestamp,
     /// Indicator of whether the guild is considered "large" by Discord.
     pub large: bool,
     /// The number of members in the guild.
@@ -122,6 +132,10 @@ pub struct Guild {
     /// [`User`]: ../user/struct.User.html
     #[serde(serialize_with = "serialize_gen_map")]
     pub presences: HashMap<UserId, Presence>,
+    /// The number of boosts the guild currently has.
+    pub premium_subscription_count: u64,
+    /// The premium tier the guild reached through boosts.
+    pub premium_tier: PremiumTier,
     /// The region that the voice servers that the guild uses are located in.
     pub region: String,
     /// A mapping of the guild's roles.
@@ -541,6 +555,8 @@ impl Guild {
                 self.mfa_level = guild.mfa_level;
                 self.name = guild.name;
                 self.owner_id = guild.owner_id;
+                self.premium_subscription_count = guild.premium_subscription_count;
+                self.premium_tier = guild.premium_tier;
                 self.region = guild.region;
                 self.roles = guild.roles;
                 self.splash = guild.splash;
@@ -586,6 +602,34 @@ impl Guild {
         self.id.edit_member(user_id, f)
     }
 
+    /// Changes the multi-factor authentication level required for moderators
+    /// of the guild, returning the new level.
+    ///
+    /// With [`MfaLevel::Elevated`], moderation actions such as kicking,
+    /// banning, or deleting messages can only be taken by users with
+    /// multi-factor authentication enabled on their account.
+    ///
+    /// **Note**: Requires the current user to be the owner of the guild, with
+    /// multi-factor authentication enabled on their account.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidUser`] if the
+    /// current user is not the guild owner.
+    ///
+    /// [`MfaLevel::Elevated`]: enum.MfaLevel.html#variant.Elevated
+    /// [`ModelError::InvalidUser`]: ../error/enum.Error.html#variant.InvalidUser
+    pub fn edit_mfa_level(&self, level: MfaLevel) -> Result<MfaLevel> {
+        #[cfg(feature = "cache")]
+        {
+            if self.owner_id != CACHE.read().user.id {
+                return Err(Error::Model(ModelError::InvalidUser));
+            }
+        }
+
+        self.id.edit_mfa_level(level)
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -737,6 +781,19 @@ impl Guild {
             .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
     }
 
+    /// Returns the URL of the guild's icon in the given format and size, if
+    /// one exists.
+    ///
+    /// Without a `format`, animated icons are retrieved as GIF and others as
+    /// WebP. Refer to [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn icon_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| cdn_image_url(&format!("/icons/{}", self.id), icon, format, size))
+    }
+
     /// Gets all integration of the guild.
     ///
     /// This performs a request over the REST API.
@@ -767,11 +824,54 @@ impl Guild {
         self.id.invites()
     }
 
+    /// Checks whether the guild has the given [`feature`] enabled.
+    ///
+    /// [`feature`]: #structfield.features
+    #[inline]
+    pub fn has_feature(&self, feature: &GuildFeature) -> bool { self.features.contains(feature) }
+
+    /// Checks if the guild is a community guild.
+    #[inline]
+    pub fn is_community(&self) -> bool { self.has_feature(&GuildFeature::Community) }
+
     /// Checks if the guild is 'large'. A guild is considered large if it has
     /// more than 250 members.
     #[inline]
     pub fn is_large(&self) -> bool { self.members.len() > LARGE_THRESHOLD as usize }
 
+    /// Checks if the guild is partnered.
+    #[inline]
+    pub fn is_partnered(&self) -> bool { self.has_feature(&GuildFeature::Partnered) }
+
+    /// Returns the highest bitrate voice channels of the guild can be set to,
+    /// in bits per second.
+    ///
+    /// Guilds with the [`VipRegions`] feature can always use the highest
+    /// bitrate.
+    ///
+    /// [`VipRegions`]: enum.GuildFeature.html#variant.VipRegions
+    pub fn max_bitrate(&self) -> u64 {
+        if self.has_feature(&GuildFeature::VipRegions) {
+            PremiumTier::Tier3.max_bitrate()
+        } else {
+            self.premium_tier.max_bitrate()
+        }
+    }
+
+    /// Returns the number of custom emojis the guild can have, per kind of
+    /// static and animated emojis.
+    #[inline]
+    pub fn max_emojis(&self) -> u64 { self.premium_tier.max_emojis() }
+
+    /// Returns the largest size of a file that can be uploaded to the guild,
+    /// in bytes.
+    #[inline]
+    pub fn max_upload_size(&self) -> u64 { self.premium_tier.max_upload_size() }
+
+    /// Checks if the guild is verified.
+    #[inline]
+    pub fn is_verified(&self) -> bool { self.has_feature(&GuildFeature::Verified) }
+
     /// Kicks a [`Member`] from the guild.
     ///
     /// Requires the [Kick Members] permission.
@@ -1413,9 +1513,22 @@ impl Guild {
 
     /// Returns the formatted URL of the guild's splash image, if one exists.
     pub fn splash_url(&self) -> Option<String> {
-        self.icon
+        self.splash
+            .as_ref()
+            .map(|splash| format!(cdn!("/splashes/{}/{}.webp"), self.id, splash))
+    }
+
+    /// Returns the URL of the guild's splash image in the given format and
+    /// size, if one exists.
+    ///
+    /// Without a `format`, the splash is retrieved as WebP. Refer to
+    /// [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn splash_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.splash
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|splash| cdn_image_url(&format!("/splashes/{}", self.id), splash, format, size))
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -1599,7 +1712,7 @@ impl<'de> Deserialize<'de> for Guild {
             .map_err(DeError::custom)?;
         let features = map.remove("features")
             .ok_or_else(|| DeError::custom("expected guild features"))
-            .and_then(serde_json::from_value::<Vec<String>>)
+            .and_then(serde_json::from_value::<Vec<GuildFeature>>)
             .map_err(DeError::custom)?;
         let icon = match map.remove("icon") {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
@@ -1611,7 +1724,7 @@ impl<'de> Deserialize<'de> for Guild {
             .map_err(DeError::custom)?;
         let joined_at = map.remove("joined_at")
             .ok_or_else(|| DeError::custom("expected guild joined_at"))
-            .and_then(DateTime::deserialize)
+            .and_then(Timestamp::deserialize)
             .map_err(DeError::custom)?;
         let large = map.remove("large")
             .ok_or_else(|| DeError::custom("expected guild large"))
@@ -1641,6 +1754,14 @@ impl<'de> Deserialize<'de> for Guild {
             .ok_or_else(|| DeError::custom("expected guild presences"))
             .and_then(deserialize_presences)
             .map_err(DeError::custom)?;
+        let premium_subscription_count = match map.remove("premium_subscription_count") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?.unwrap_or(0),
+            None => 0,
+        };
+        let premium_tier = match map.remove("premium_tier") {
+            Some(v) => PremiumTier::deserialize(v).map_err(DeError::custom)?,
+            None => PremiumTier::default(),
+        };
         let region = map.remove("region")
             .ok_or_else(|| DeError::custom("expected guild region"))
             .and_then(String::deserialize)
@@ -1685,6 +1806,8 @@ impl<'de> Deserialize<'de> for Guild {
             name,
             owner_id,
             presences,
+            premium_subscription_count,
+            premium_tier,
             region,
             roles,
             splash,
@@ -1786,6 +1909,19 @@ impl GuildInfo {
             .as_ref()
             .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
     }
+
+    /// Returns the URL of the guild's icon in the given format and size, if
+    /// one exists.
+    ///
+    /// Without a `format`, animated icons are retrieved as GIF and others as
+    /// WebP. Refer to [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn icon_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| cdn_image_url(&format!("/icons/{}", self.id), icon, format, size))
+    }
 }
 
 impl From<PartialGuild> for GuildContainer {
@@ -1804,9 +1940,22 @@ impl From<u64> for GuildContainer {
 impl InviteGuild {
     /// Returns the formatted URL of the guild's splash image, if one exists.
     pub fn splash_url(&self) -> Option<String> {
-        self.icon
+        self.splash_hash
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|splash| format!(cdn!("/splashes/{}/{}.webp"), self.id, splash))
+    }
+
+    /// Returns the URL of the guild's splash image in the given format and
+    /// size, if one exists.
+    ///
+    /// Without a `format`, the splash is retrieved as WebP. Refer to
+    /// [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn splash_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.splash_hash
+            .as_ref()
+            .map(|splash| cdn_image_url(&format!("/splashes/{}", self.id), splash, format, size))
     }
 }
 
@@ -1900,12 +2049,51 @@ impl ExplicitContentFilter {
     }
 }
 
+/// The kinds of system messages that are suppressed in a guild's system
+/// channel.
+#[derive(Copy, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+pub struct SystemChannelFlags {
+    bits: u64
+}
+
+__impl_bitflags! {
+    SystemChannelFlags: u64 {
+        /// Suppresses the messages sent when a member joins.
+        SUPPRESS_JOIN_NOTIFICATIONS = 0b0000_0001;
+        /// Suppresses the messages sent when a member boosts the guild.
+        SUPPRESS_PREMIUM_SUBSCRIPTIONS = 0b0000_0010;
+        /// Suppresses the tips on setting up the guild.
+        SUPPRESS_GUILD_REMINDER_NOTIFICATIONS = 0b0000_0100;
+    }
+}
+
+impl<'de> Deserialize<'de> for SystemChannelFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(SystemChannelFlags::from_bits_truncate(
+            deserializer.deserialize_u64(U64Visitor)?,
+        ))
+    }
+}
+
+impl Serialize for SystemChannelFlags {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
 /// Multi-Factor Authentication level for guild moderators.
+///
+/// The level can be changed by the guild's owner through
+/// [`GuildId::edit_mfa_level`].
+///
+/// [`GuildId::edit_mfa_level`]: ../id/struct.GuildId.html#method.edit_mfa_level
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum MfaLevel {
-    /// MFA is disabled.
+    /// Moderators are not required to have MFA enabled.
     None = 0,
-    /// MFA is enabled.
+    /// Moderators are required to have MFA enabled to take moderation
+    /// actions.
     Elevated = 1,
 }
 
@@ -1925,6 +2113,74 @@ impl MfaLevel {
     }
 }
 
+/// The premium tier a guild reached through the boosts of its members.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum PremiumTier {
+    /// The guild has not unlocked any perks.
+    Tier0 = 0,
+    /// The guild has unlocked the perks of level 1.
+    Tier1 = 1,
+    /// The guild has unlocked the perks of level 2.
+    Tier2 = 2,
+    /// The guild has unlocked the perks of level 3.
+    Tier3 = 3,
+}
+
+enum_number!(
+    PremiumTier {
+        Tier0,
+        Tier1,
+        Tier2,
+        Tier3,
+    }
+);
+
+impl PremiumTier {
+    pub fn num(&self) -> u64 {
+        match *self {
+            PremiumTier::Tier0 => 0,
+            PremiumTier::Tier1 => 1,
+            PremiumTier::Tier2 => 2,
+            PremiumTier::Tier3 => 3,
+        }
+    }
+
+    /// Returns the highest bitrate voice channels can be set to at this
+    /// tier, in bits per second.
+    pub fn max_bitrate(&self) -> u64 {
+        match *self {
+            PremiumTier::Tier0 => 96_000,
+            PremiumTier::Tier1 => 128_000,
+            PremiumTier::Tier2 => 256_000,
+            PremiumTier::Tier3 => 384_000,
+        }
+    }
+
+    /// Returns the number of custom emojis available at this tier, per kind
+    /// of static and animated emojis.
+    pub fn max_emojis(&self) -> u64 {
+        match *self {
+            PremiumTier::Tier0 => 50,
+            PremiumTier::Tier1 => 100,
+            PremiumTier::Tier2 => 150,
+            PremiumTier::Tier3 => 250,
+        }
+    }
+
+    /// Returns the largest size of an uploaded file at this tier, in bytes.
+    pub fn max_upload_size(&self) -> u64 {
+        match *self {
+            PremiumTier::Tier0 | PremiumTier::Tier1 => 8 * 1024 * 1024,
+            PremiumTier::Tier2 => 50 * 1024 * 1024,
+            PremiumTier::Tier3 => 100 * 1024 * 1024,
+        }
+    }
+}
+
+impl Default for PremiumTier {
+    fn default() -> Self { PremiumTier::Tier0 }
+}
+
 /// The name of a region that a voice server can be located in.
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub enum Region {
@@ -2017,7 +2273,6 @@ impl VerificationLevel {
 mod test {
     #[cfg(feature = "model")]
     mod model {
-        use chrono::prelude::*;
         use model::prelude::*;
         use std::collections::*;
         use std::sync::Arc;
@@ -2033,9 +2288,7 @@ mod test {
         }
 
         fn gen_member() -> Member {
-            let dt: DateTime<FixedOffset> = FixedOffset::east(5 * 3600)
-                .ymd(2016, 11, 08)
-                .and_hms(0, 0, 0);
+            let dt = Timestamp::parse("2016-11-08T00:00:00+05:00").unwrap();
             let vec1 = Vec::new();
             let u = Arc::new(RwLock::new(gen_user()));
 
@@ -2057,9 +2310,7 @@ mod test {
             let hm1 = HashMap::new();
             let hm2 = HashMap::new();
             let vec1 = Vec::new();
-            let dt: DateTime<FixedOffset> = FixedOffset::east(5 * 3600)
-                .ymd(2016, 11, 08)
-                .and_hms(0, 0, 0);
+            let dt = Timestamp::parse("2016-11-08T00:00:00+05:00").unwrap();
             let mut hm3 = HashMap::new();
             let hm4 = HashMap::new();
             let hm5 = HashMap::new();
@@ -2085,6 +2336,8 @@ mod test {
                 mfa_level: MfaLevel::Elevated,
                 name: "Spaghetti".to_string(),
                 owner_id: UserId(210),
+                premium_subscription_count: 0,
+                premium_tier: PremiumTier::Tier0,
                 presences: hm4,
                 region: "NA".to_string(),
                 roles: hm5,
@@ -2116,5 +2369,47 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        #[test]
+        fn premium_limits() {
+            let mut guild = gen();
+
+            assert_eq!(guild.max_emojis(), 50);
+            assert_eq!(guild.max_bitrate(), 96_000);
+
+            guild.premium_tier = PremiumTier::Tier2;
+
+            assert_eq!(guild.max_upload_size(), 50 * 1024 * 1024);
+            assert_eq!(guild.max_bitrate(), 256_000);
+
+            guild.features.push(GuildFeature::VipRegions);
+
+            assert_eq!(guild.max_bitrate(), 384_000);
+        }
+
+        #[test]
+        fn guild_like() {
+            fn owner_and_name<G: GuildLike>(guild: &G) -> (UserId, String) {
+                (guild.owner_id(), guild.name().to_string())
+            }
+
+            let guild = gen();
+            let partial = PartialGuild::from(&guild);
+
+            assert_eq!(owner_and_name(&guild), owner_and_name(&partial));
+            assert_eq!(GuildLike::icon_url(&guild), GuildLike::icon_url(&partial));
+            assert!(partial.is_owner(UserId(210)));
+            assert!(!partial.embed_enabled);
+        }
+    }
+
+    #[test]
+    fn system_channel_flags_serde() {
+        use serde_json;
+        use super::SystemChannelFlags;
+
+        let flags: SystemChannelFlags = serde_json::from_str("11").unwrap();
+        assert_eq!(flags, SystemChannelFlags::all());
+        assert_eq!(serde_json::to_string(&flags).unwrap(), "7");
     }
 }
@@ -0,0 +1,186 @@
+use model::prelude::*;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::result::Result as StdResult;
+use super::super::utils::deserialize_u64;
+
+/// The onboarding of a guild, which new members go through before being able
+/// to see the guild's channels, retrieved through [`GuildId::onboarding`].
+///
+/// [`GuildId::onboarding`]: struct.GuildId.html#method.onboarding
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Onboarding {
+    /// The Id of the guild.
+    pub guild_id: GuildId,
+    /// The prompts shown during the onboarding, and in the guild's
+    /// "Channels & Roles" tab.
+    pub prompts: Vec<OnboardingPrompt>,
+    /// The channels that members are opted into by default.
+    pub default_channel_ids: Vec<ChannelId>,
+    /// Whether the onboarding is shown to new members.
+    pub enabled: bool,
+    /// The criteria the default channels and prompts have to meet for the
+    /// onboarding to be enabled.
+    pub mode: OnboardingMode,
+}
+
+/// A prompt shown during the onboarding of a guild.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OnboardingPrompt {
+    /// The Id of the prompt.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub id: u64,
+    /// The way the options of the prompt are displayed.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// The options that can be chosen.
+    pub options: Vec<OnboardingPromptOption>,
+    /// The title of the prompt.
+    pub title: String,
+    /// Whether at most one of the options can be chosen.
+    pub single_select: bool,
+    /// Whether an option has to be chosen to finish the onboarding.
+    pub required: bool,
+    /// Whether the prompt is shown during the onboarding, rather than only in
+    /// the guild's "Channels & Roles" tab.
+    pub in_onboarding: bool,
+}
+
+/// An option of an [`OnboardingPrompt`].
+///
+/// [`OnboardingPrompt`]: struct.OnboardingPrompt.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct OnboardingPromptOption {
+    /// The Id of the option.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub id: u64,
+    /// The channels that members are opted into when choosing the option.
+    pub channel_ids: Vec<ChannelId>,
+    /// The roles that members are given when choosing the option.
+    pub role_ids: Vec<RoleId>,
+    /// The emoji displayed next to the option, if any.
+    #[serde(default, deserialize_with = "deserialize_emoji")]
+    pub emoji: Option<ReactionType>,
+    /// The title of the option.
+    pub title: String,
+    /// The description of the option, if any.
+    pub description: Option<String>,
+}
+
+impl Serialize for OnboardingPromptOption {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        let mut state = serializer.serialize_struct("OnboardingPromptOption", 8)?;
+
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("channel_ids", &self.channel_ids)?;
+        state.serialize_field("role_ids", &self.role_ids)?;
+
+        // Discord does not accept the `emoji` object when editing the
+        // onboarding, but only its separate fields.
+        match self.emoji {
+            Some(ReactionType::Custom { animated, id, ref name }) => {
+                state.serialize_field("emoji_animated", &animated)?;
+                state.serialize_field("emoji_id", &Some(id))?;
+                state.serialize_field("emoji_name", name)?;
+            },
+            Some(ReactionType::Unicode(ref name)) => {
+                state.serialize_field("emoji_animated", &false)?;
+                state.serialize_field("emoji_id", &None::<EmojiId>)?;
+                state.serialize_field("emoji_name", &Some(name))?;
+            },
+            None => {
+                state.serialize_field("emoji_animated", &false)?;
+                state.serialize_field("emoji_id", &None::<EmojiId>)?;
+                state.serialize_field("emoji_name", &None::<String>)?;
+            },
+        }
+
+        state.serialize_field("title", &self.title)?;
+        state.serialize_field("description", &self.description)?;
+
+        state.end()
+    }
+}
+
+/// Deserializes the emoji of an option, which Discord sends without a name
+/// rather than omitting it if the option has none.
+fn deserialize_emoji<'de, D>(deserializer: D) -> StdResult<Option<ReactionType>, D::Error>
+    where D: Deserializer<'de> {
+    #[derive(Deserialize)]
+    struct RawEmoji {
+        #[serde(default)]
+        animated: bool,
+        id: Option<EmojiId>,
+        name: Option<String>,
+    }
+
+    let emoji = match Option::<RawEmoji>::deserialize(deserializer)? {
+        Some(emoji) => emoji,
+        None => return Ok(None),
+    };
+
+    Ok(match (emoji.id, emoji.name) {
+        (Some(id), name) => Some(ReactionType::Custom {
+            animated: emoji.animated,
+            id,
+            name,
+        }),
+        (None, Some(name)) => Some(ReactionType::Unicode(name)),
+        (None, None) => None,
+    })
+}
+
+/// The criteria that have to be met for the onboarding of a guild to be
+/// enabled.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum OnboardingMode {
+    /// Only the default channels count towards the constraints.
+    Default = 0,
+    /// The default channels and the channels of the prompts count towards the
+    /// constraints.
+    Advanced = 1,
+}
+
+enum_number!(
+    OnboardingMode {
+        Default,
+        Advanced,
+    }
+);
+
+impl OnboardingMode {
+    pub fn num(&self) -> u64 {
+        match *self {
+            OnboardingMode::Default => 0,
+            OnboardingMode::Advanced => 1,
+        }
+    }
+}
+
+/// The way the options of an [`OnboardingPrompt`] are displayed.
+///
+/// [`OnboardingPrompt`]: struct.OnboardingPrompt.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum OnboardingPromptType {
+    /// The options are displayed as a list of buttons.
+    MultipleChoice = 0,
+    /// The options are displayed in a dropdown menu.
+    Dropdown = 1,
+}
+
+enum_number!(
+    OnboardingPromptType {
+        MultipleChoice,
+        Dropdown,
+    }
+);
+
+impl OnboardingPromptType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            OnboardingPromptType::MultipleChoice => 0,
+            OnboardingPromptType::Dropdown => 1,
+        }
+    }
+}
@@ -3,6 +3,8 @@ use super::super::utils::{deserialize_emojis, deserialize_roles};
 
 #[cfg(feature = "model")]
 use builder::{EditGuild, EditMember, EditRole};
+#[cfg(feature = "model")]
+use model::misc::cdn_image_url;
 
 /// Partial information about a [`Guild`]. This does not include information
 /// like member data.
@@ -22,11 +24,17 @@ pub struct PartialGuild {
     /// Refer to [`Guild::features`] for more information.
     ///
     /// [`Guild::features`]: struct.Guild.html#structfield.features
-    pub features: Vec<String>,
+    pub features: Vec<GuildFeature>,
     pub icon: Option<String>,
     pub mfa_level: MfaLevel,
     pub name: String,
     pub owner_id: UserId,
+    /// The number of boosts the guild currently has.
+    #[serde(default)]
+    pub premium_subscription_count: u64,
+    /// The premium tier the guild reached through boosts.
+    #[serde(default)]
+    pub premium_tier: PremiumTier,
     pub region: String,
     #[serde(deserialize_with = "deserialize_roles")] pub roles: HashMap<RoleId, Role>,
     pub splash: Option<String>,
@@ -221,6 +229,8 @@ impl PartialGuild {
                 self.mfa_level = guild.mfa_level;
                 self.name = guild.name;
                 self.owner_id = guild.owner_id;
+                self.premium_subscription_count = guild.premium_subscription_count;
+                self.premium_tier = guild.premium_tier;
                 self.region = guild.region;
                 self.roles = guild.roles;
                 self.splash = guild.splash;
@@ -269,6 +279,22 @@ impl PartialGuild {
         self.id.edit_member(user_id, f)
     }
 
+    /// Changes the multi-factor authentication level required for moderators
+    /// of the guild, returning the new level.
+    ///
+    /// With [`MfaLevel::Elevated`], moderation actions such as kicking,
+    /// banning, or deleting messages can only be taken by users with
+    /// multi-factor authentication enabled on their account.
+    ///
+    /// **Note**: Requires the current user to be the owner of the guild, with
+    /// multi-factor authentication enabled on their account.
+    ///
+    /// [`MfaLevel::Elevated`]: enum.MfaLevel.html#variant.Elevated
+    #[inline]
+    pub fn edit_mfa_level(&self, level: MfaLevel) -> Result<MfaLevel> {
+        self.id.edit_mfa_level(level)
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -294,6 +320,40 @@ impl PartialGuild {
     #[inline]
     pub fn get<G: Into<GuildId>>(guild_id: G) -> Result<PartialGuild> { guild_id.into().to_partial_guild() }
 
+    /// Checks whether the guild has the given [`feature`] enabled.
+    ///
+    /// [`feature`]: #structfield.features
+    #[inline]
+    pub fn has_feature(&self, feature: &GuildFeature) -> bool { self.features.contains(feature) }
+
+    /// Checks if the guild is a community guild.
+    #[inline]
+    pub fn is_community(&self) -> bool { self.has_feature(&GuildFeature::Community) }
+
+    /// Returns the highest bitrate voice channels of the guild can be set to,
+    /// in bits per second.
+    ///
+    /// Refer to [`Guild::max_bitrate`] for more information.
+    ///
+    /// [`Guild::max_bitrate`]: struct.Guild.html#method.max_bitrate
+    pub fn max_bitrate(&self) -> u64 {
+        if self.has_feature(&GuildFeature::VipRegions) {
+            PremiumTier::Tier3.max_bitrate()
+        } else {
+            self.premium_tier.max_bitrate()
+        }
+    }
+
+    /// Returns the number of custom emojis the guild can have, per kind of
+    /// static and animated emojis.
+    #[inline]
+    pub fn max_emojis(&self) -> u64 { self.premium_tier.max_emojis() }
+
+    /// Returns the largest size of a file that can be uploaded to the guild,
+    /// in bytes.
+    #[inline]
+    pub fn max_upload_size(&self) -> u64 { self.premium_tier.max_upload_size() }
+
     /// Kicks a [`Member`] from the guild.
     ///
     /// Requires the [Kick Members] permission.
@@ -310,6 +370,19 @@ impl PartialGuild {
             .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
     }
 
+    /// Returns the URL of the guild's icon in the given format and size, if
+    /// one exists.
+    ///
+    /// Without a `format`, animated icons are retrieved as GIF and others as
+    /// WebP. Refer to [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn icon_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| cdn_image_url(&format!("/icons/{}", self.id), icon, format, size))
+    }
+
     /// Gets all integration of the guild.
     ///
     /// This performs a request over the REST API.
@@ -407,9 +480,22 @@ impl PartialGuild {
 
     /// Returns the formatted URL of the guild's splash image, if one exists.
     pub fn splash_url(&self) -> Option<String> {
-        self.icon
+        self.splash
+            .as_ref()
+            .map(|splash| format!(cdn!("/splashes/{}/{}.webp"), self.id, splash))
+    }
+
+    /// Returns the URL of the guild's splash image in the given format and
+    /// size, if one exists.
+    ///
+    /// Without a `format`, the splash is retrieved as WebP. Refer to
+    /// [`ImageFormat`] for details on the `size`.
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    pub fn splash_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.splash
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|splash| cdn_image_url(&format!("/splashes/{}", self.id), splash, format, size))
     }
 
     /// Starts an integration sync for the given integration Id.
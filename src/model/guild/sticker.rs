@@ -0,0 +1,188 @@
+use model::prelude::*;
+
+/// A sticker, which is either a standard sticker of a pack, or a custom
+/// sticker of a guild.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Sticker {
+    /// The Id of the sticker.
+    pub id: StickerId,
+    /// The Id of the pack the sticker is in, if it is a standard sticker.
+    #[serde(default)]
+    pub pack_id: Option<u64>,
+    /// The name of the sticker.
+    pub name: String,
+    /// The description of the sticker, if any.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The comma separated names of the emojis that autocomplete to the
+    /// sticker.
+    pub tags: String,
+    /// Whether the sticker is standard or of a guild.
+    #[serde(rename = "type")]
+    pub kind: StickerType,
+    /// The format of the image of the sticker.
+    pub format_type: StickerFormatType,
+    /// Whether the sticker of a guild can be used, which may be `false` after
+    /// the guild lost boosts.
+    #[serde(default)]
+    pub available: bool,
+    /// The Id of the guild of the sticker, if it is not standard.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// The user that uploaded the sticker of the guild, which is only present
+    /// when retrieved with the [Manage Emojis] permission.
+    ///
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[serde(default)]
+    pub user: Option<User>,
+}
+
+#[cfg(feature = "model")]
+impl Sticker {
+    /// Generates a URL to the image of the sticker.
+    #[inline]
+    pub fn image_url(&self) -> String {
+        format!(cdn!("/stickers/{}.{}"), self.id, self.format_type.extension())
+    }
+}
+
+/// The smallest amount of data of a [`Sticker`] that is sent with a
+/// [`Message`].
+///
+/// [`Message`]: ../channel/struct.Message.html
+/// [`Sticker`]: struct.Sticker.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StickerItem {
+    /// The Id of the sticker.
+    pub id: StickerId,
+    /// The name of the sticker.
+    pub name: String,
+    /// The format of the image of the sticker.
+    pub format_type: StickerFormatType,
+}
+
+#[cfg(feature = "model")]
+impl StickerItem {
+    /// Generates a URL to the image of the sticker.
+    #[inline]
+    pub fn image_url(&self) -> String {
+        format!(cdn!("/stickers/{}.{}"), self.id, self.format_type.extension())
+    }
+}
+
+/// Whether a [`Sticker`] is standard or of a guild.
+///
+/// [`Sticker`]: struct.Sticker.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum StickerType {
+    /// A sticker of an official pack.
+    Standard = 1,
+    /// A sticker uploaded to a guild.
+    Guild = 2,
+}
+
+enum_number!(
+    StickerType {
+        Standard,
+        Guild,
+    }
+);
+
+impl StickerType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            StickerType::Standard => 1,
+            StickerType::Guild => 2,
+        }
+    }
+}
+
+/// The format of the image of a [`Sticker`].
+///
+/// [`Sticker`]: struct.Sticker.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum StickerFormatType {
+    /// A PNG image.
+    Png = 1,
+    /// An animated PNG image.
+    Apng = 2,
+    /// A Lottie animation, in JSON.
+    Lottie = 3,
+    /// An animated GIF.
+    Gif = 4,
+}
+
+enum_number!(
+    StickerFormatType {
+        Png,
+        Apng,
+        Lottie,
+        Gif,
+    }
+);
+
+impl StickerFormatType {
+    /// The file extension of the format, as used in CDN URLs.
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            StickerFormatType::Png | StickerFormatType::Apng => "png",
+            StickerFormatType::Lottie => "json",
+            StickerFormatType::Gif => "gif",
+        }
+    }
+
+    pub fn num(&self) -> u64 {
+        match *self {
+            StickerFormatType::Png => 1,
+            StickerFormatType::Apng => 2,
+            StickerFormatType::Lottie => 3,
+            StickerFormatType::Gif => 4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+    use super::*;
+
+    fn sticker() -> Sticker {
+        serde_json::from_value(json!({
+            "id": "749054660769218631",
+            "name": "Wave",
+            "tags": "wave",
+            "type": 2,
+            "format_type": 3,
+            "available": true,
+            "guild_id": "81384788765712384",
+        })).unwrap()
+    }
+
+    #[test]
+    fn deserializes_guild_sticker() {
+        let sticker = sticker();
+
+        assert_eq!(sticker.kind, StickerType::Guild);
+        assert_eq!(sticker.format_type, StickerFormatType::Lottie);
+        assert_eq!(sticker.guild_id, Some(GuildId(81384788765712384)));
+        assert!(sticker.pack_id.is_none());
+        assert!(sticker.description.is_none());
+        assert!(sticker.user.is_none());
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn image_urls() {
+        assert_eq!(
+            sticker().image_url(),
+            "https://cdn.discordapp.com/stickers/749054660769218631.json",
+        );
+
+        let item = StickerItem {
+            id: StickerId(749054660769218631),
+            name: "Wave".to_string(),
+            format_type: StickerFormatType::Apng,
+        };
+        assert_eq!(item.image_url(), "https://cdn.discordapp.com/stickers/749054660769218631.png");
+    }
+}
@@ -1,20 +1,29 @@
 //! A collection of newtypes defining type-strong IDs.
 
-use chrono::NaiveDateTime;
 use internal::prelude::*;
 use serde::de::{Deserialize, Deserializer};
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use serde::ser::{Serialize, Serializer};
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr
+};
 use super::utils::U64Visitor;
+use super::Timestamp;
+
+/// The first second of 2015, from which the timestamps of Ids are counted in
+/// milliseconds.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
 
 macro_rules! id_u64 {
-    ($($name:ident;)*) => {
+    ($($name:ident $(: $($prefix:expr),+)*;)*) => {
         $(
             impl $name {
                 /// Retrieves the time that the Id was created at.
-                pub fn created_at(&self) -> NaiveDateTime {
-                    let offset = (self.0 >> 22) / 1000;
+                pub fn created_at(&self) -> Timestamp {
+                    let millis = (self.0 >> 22) + DISCORD_EPOCH;
 
-                    NaiveDateTime::from_timestamp(1_420_070_400 + offset as i64, 0)
+                    Timestamp::from_millis_unchecked(millis as i64)
                 }
 
                 /// Immutably borrow inner Id.
@@ -69,74 +78,159 @@ macro_rules! id_u64 {
                 }
             }
 
+            impl FromStr for $name {
+                type Err = IdParseError;
+
+                fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+                    let mention_prefixes: &[&str] = &[$($($prefix),+)*];
+
+                    parse_id(s, mention_prefixes).map($name)
+                }
+            }
+
             impl<'de> Deserialize<'de> for $name {
                 fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
                     deserializer.deserialize_any(U64Visitor).map($name)
                 }
             }
+
+            // Ids are serialized as strings, as not every consumer of JSON can
+            // represent all 64-bit integers.
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+                    serializer.collect_str(&self.0)
+                }
+            }
         )*
     }
 }
 
 /// An identifier for an Application.
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct ApplicationId(pub u64);
 
+/// An identifier for an [`AutoModRule`](../guild/struct.AutoModRule.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
+#[allow(derive_hash_xor_eq)]
+pub struct AutoModRuleId(pub u64);
+
 /// An identifier for a Channel
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct ChannelId(pub u64);
 
+/// An identifier for an application
+/// [`ApplicationCommand`](../interaction/struct.ApplicationCommand.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
+#[allow(derive_hash_xor_eq)]
+pub struct CommandId(pub u64);
+
 /// An identifier for an Emoji
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct EmojiId(pub u64);
 
 /// An identifier for a Guild
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct GuildId(pub u64);
 
 /// An identifier for an Integration
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct IntegrationId(pub u64);
 
+/// An identifier for an [`Interaction`](../interaction/struct.Interaction.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
+#[allow(derive_hash_xor_eq)]
+pub struct InteractionId(pub u64);
+
 /// An identifier for a Message
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct MessageId(pub u64);
 
 /// An identifier for a Role
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct RoleId(pub u64);
 
+/// An identifier for a [`StageInstance`](../channel/struct.StageInstance.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
+#[allow(derive_hash_xor_eq)]
+pub struct StageInstanceId(pub u64);
+
+/// An identifier for a [`Sticker`](../guild/struct.Sticker.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
+#[allow(derive_hash_xor_eq)]
+pub struct StickerId(pub u64);
+
+/// An identifier for an application [`Team`](../application/struct.Team.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
+#[allow(derive_hash_xor_eq)]
+pub struct TeamId(pub u64);
+
 /// An identifier for a User
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct UserId(pub u64);
 
 /// An identifier for a [`Webhook`](../webhook/struct.Webhook.html).
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct WebhookId(pub u64);
 
 /// An identifier for an audit log entry.
-#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord)]
 #[allow(derive_hash_xor_eq)]
 pub struct AuditLogEntryId(pub u64);
 
 id_u64! {
     ApplicationId;
-    ChannelId;
+    AutoModRuleId;
+    ChannelId: "<#";
+    CommandId;
     EmojiId;
     GuildId;
     IntegrationId;
+    InteractionId;
     MessageId;
-    RoleId;
-    UserId;
+    RoleId: "<@&";
+    StageInstanceId;
+    StickerId;
+    TeamId;
+    UserId: "<@!", "<@";
     WebhookId;
     AuditLogEntryId;
 }
+
+/// An error returned when an Id could not be parsed from a string.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum IdParseError {
+    /// The string is neither an integer nor a mention of the Id.
+    InvalidFormat,
+}
+
+impl Display for IdParseError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for IdParseError {
+    fn description(&self) -> &str {
+        match *self {
+            IdParseError::InvalidFormat => "invalid id format",
+        }
+    }
+}
+
+/// Parses an Id from either an integer or a mention starting with one of
+/// `mention_prefixes`, such as `<@&` for roles.
+fn parse_id(s: &str, mention_prefixes: &[&str]) -> StdResult<u64, IdParseError> {
+    let id = mention_prefixes
+        .iter()
+        .find(|prefix| s.len() > prefix.len() && s.starts_with(*prefix) && s.ends_with('>'))
+        .map_or(s, |prefix| &s[prefix.len()..s.len() - 1]);
+
+    id.parse::<u64>().map_err(|_| IdParseError::InvalidFormat)
+}
@@ -0,0 +1,647 @@
+//! Models about application commands, such as slash commands, and the
+//! interactions invoking them.
+
+use internal::prelude::*;
+use model::prelude::*;
+use std::collections::HashMap;
+use super::utils::deserialize_u64;
+
+#[cfg(feature = "model")]
+use builder::{CreateApplicationCommand, CreateInteractionResponse, CreateModal};
+#[cfg(feature = "model")]
+use http;
+#[cfg(feature = "model")]
+use utils;
+
+/// A command registered by an application, which users invoke through the
+/// interface of their client, such as a slash command.
+///
+/// Commands are either global, being available in all guilds of the
+/// application and in direct messages, or registered for a single guild.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommand {
+    /// The Id of the command.
+    pub id: CommandId,
+    /// The Id of the application the command belongs to.
+    pub application_id: ApplicationId,
+    /// The Id of the guild the command is registered for, if it is not global.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// The name of the command, which users type to invoke it.
+    pub name: String,
+    /// The description of the command.
+    pub description: String,
+    /// The options of the command, which are either its arguments or its
+    /// sub-commands.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    /// The version of the command, which changes whenever it is edited.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub version: u64,
+}
+
+/// An option of an [`ApplicationCommand`], which is either an argument or a
+/// sub-command.
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandOption {
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// The name of the option.
+    pub name: String,
+    /// The description of the option.
+    pub description: String,
+    /// Whether the option has to be given to invoke the command.
+    #[serde(default)]
+    pub required: bool,
+    /// The values that can be given for the option, if it is restricted to
+    /// these.
+    #[serde(default)]
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+    /// The options of a sub-command or sub-command group.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+}
+
+/// A value that can be given for an [`ApplicationCommandOption`].
+///
+/// [`ApplicationCommandOption`]: struct.ApplicationCommandOption.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandOptionChoice {
+    /// The name of the choice, as displayed to users.
+    pub name: String,
+    /// The value of the choice, which is either a string or a number.
+    pub value: Value,
+}
+
+/// The type of an [`ApplicationCommandOption`].
+///
+/// [`ApplicationCommandOption`]: struct.ApplicationCommandOption.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ApplicationCommandOptionType {
+    /// A sub-command, whose arguments are its options.
+    SubCommand = 1,
+    /// A group of sub-commands, which are its options.
+    SubCommandGroup = 2,
+    /// A string.
+    String = 3,
+    /// An integer.
+    Integer = 4,
+    /// A boolean.
+    Boolean = 5,
+    /// A user, given as their Id.
+    User = 6,
+    /// A channel, given as its Id.
+    Channel = 7,
+    /// A role, given as its Id.
+    Role = 8,
+    /// Either a user or a role.
+    Mentionable = 9,
+    /// A floating point number.
+    Number = 10,
+}
+
+enum_number!(
+    ApplicationCommandOptionType {
+        SubCommand,
+        SubCommandGroup,
+        String,
+        Integer,
+        Boolean,
+        User,
+        Channel,
+        Role,
+        Mentionable,
+        Number,
+    }
+);
+
+impl ApplicationCommandOptionType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            ApplicationCommandOptionType::SubCommand => 1,
+            ApplicationCommandOptionType::SubCommandGroup => 2,
+            ApplicationCommandOptionType::String => 3,
+            ApplicationCommandOptionType::Integer => 4,
+            ApplicationCommandOptionType::Boolean => 5,
+            ApplicationCommandOptionType::User => 6,
+            ApplicationCommandOptionType::Channel => 7,
+            ApplicationCommandOptionType::Role => 8,
+            ApplicationCommandOptionType::Mentionable => 9,
+            ApplicationCommandOptionType::Number => 10,
+        }
+    }
+}
+
+/// An interaction of a user with the application, such as the invocation of
+/// an [`ApplicationCommand`], received through the [`interaction_create`]
+/// event handler.
+///
+/// An interaction has to be responded to within 3 seconds through
+/// [`create_response`], or it is displayed as failed to the user.
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+/// [`create_response`]: #method.create_response
+/// [`interaction_create`]: ../../prelude/trait.EventHandler.html#method.interaction_create
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Interaction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The Id of the application the interaction is meant for.
+    pub application_id: ApplicationId,
+    /// The type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The invoked command and the options given, the used component, or the
+    /// submitted modal, unless the interaction is a [`Ping`].
+    ///
+    /// [`Ping`]: ../channel/enum.InteractionType.html#variant.Ping
+    #[serde(default)]
+    pub data: Option<InteractionData>,
+    /// The Id of the guild the interaction was sent from, if any.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// The Id of the channel the interaction was sent from, if any.
+    #[serde(default)]
+    pub channel_id: Option<ChannelId>,
+    /// The message the used component is attached to, if the interaction is
+    /// of the [`MessageComponent`] type.
+    ///
+    /// [`MessageComponent`]: ../channel/enum.InteractionType.html#variant.MessageComponent
+    #[serde(default)]
+    pub message: Option<Message>,
+    /// The member that invoked the interaction, if it was sent from a guild.
+    #[serde(default)]
+    pub member: Option<Member>,
+    /// The user that invoked the interaction, if it was sent from a direct
+    /// message.
+    ///
+    /// Use [`invoker`] to retrieve the user in either case.
+    ///
+    /// [`invoker`]: #method.invoker
+    #[serde(default)]
+    pub user: Option<User>,
+    /// The token to respond to the interaction with, which is valid for 15
+    /// minutes.
+    pub token: String,
+    /// The version of the interaction, which is always `1`.
+    pub version: u64,
+}
+
+impl Interaction {
+    /// Returns the user that invoked the interaction, whether it was sent from
+    /// a guild or a direct message.
+    pub fn invoker(&self) -> Option<User> {
+        match self.member {
+            Some(ref member) => Some(member.user.read().clone()),
+            None => self.user.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "model")]
+impl Interaction {
+    /// Responds to the interaction, such as with a message.
+    ///
+    /// Refer to [`CreateInteractionResponse`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Respond to a command with a message only the invoking user can see:
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::interaction::InteractionResponseType;
+    ///
+    /// interaction.create_response(InteractionResponseType::ChannelMessageWithSource, |r| r
+    ///     .content("Pong!")
+    ///     .ephemeral(true))?;
+    /// ```
+    ///
+    /// [`CreateInteractionResponse`]: ../../builder/struct.CreateInteractionResponse.html
+    pub fn create_response<F>(&self, kind: InteractionResponseType, f: F) -> Result<()>
+        where F: FnOnce(CreateInteractionResponse) -> CreateInteractionResponse {
+        let map = utils::vecmap_to_json_map(f(CreateInteractionResponse::default()).0);
+
+        let mut response = json!({
+            "type": kind.num(),
+        });
+
+        if !map.is_empty() {
+            response["data"] = Value::Object(map);
+        }
+
+        http::create_interaction_response(self.id.0, &self.token, &response)
+    }
+
+    /// Responds to the interaction with a modal for the user to fill in, of
+    /// which the submission is received as another interaction of the
+    /// [`ModalSubmit`] type.
+    ///
+    /// Refer to [`CreateModal`] for more information.
+    ///
+    /// **Note**: Can not be used to respond to a [`ModalSubmit`] interaction.
+    ///
+    /// [`CreateModal`]: ../../builder/struct.CreateModal.html
+    /// [`ModalSubmit`]: ../channel/enum.InteractionType.html#variant.ModalSubmit
+    pub fn create_modal<F>(&self, f: F) -> Result<()>
+        where F: FnOnce(CreateModal) -> CreateModal {
+        let map = utils::vecmap_to_json_map(f(CreateModal::default()).0);
+
+        let response = json!({
+            "type": InteractionResponseType::Modal.num(),
+            "data": map,
+        });
+
+        http::create_interaction_response(self.id.0, &self.token, &response)
+    }
+
+    /// Edits the message responding to the interaction, such as to send the
+    /// message of a [`DeferredChannelMessageWithSource`] response.
+    ///
+    /// **Note**: Whether the message is [`ephemeral`] or [`tts`] can not be
+    /// changed.
+    ///
+    /// [`DeferredChannelMessageWithSource`]: enum.InteractionResponseType.html#variant.DeferredChannelMessageWithSource
+    /// [`ephemeral`]: ../../builder/struct.CreateInteractionResponse.html#method.ephemeral
+    /// [`tts`]: ../../builder/struct.CreateInteractionResponse.html#method.tts
+    pub fn edit_original_response<F>(&self, f: F) -> Result<Message>
+        where F: FnOnce(CreateInteractionResponse) -> CreateInteractionResponse {
+        let map = utils::vecmap_to_json_map(f(CreateInteractionResponse::default()).0);
+
+        http::edit_original_interaction_response(
+            self.application_id.0,
+            &self.token,
+            &Value::Object(map),
+        )
+    }
+
+    /// Deletes the message responding to the interaction.
+    #[inline]
+    pub fn delete_original_response(&self) -> Result<()> {
+        http::delete_original_interaction_response(self.application_id.0, &self.token)
+    }
+
+    /// Sends a follow-up message to the interaction, which can only be done
+    /// after it was responded to.
+    ///
+    /// Follow-up messages can be sent for as long as the [`token`] is valid.
+    ///
+    /// [`token`]: #structfield.token
+    pub fn create_followup_message<F>(&self, f: F) -> Result<Message>
+        where F: FnOnce(CreateInteractionResponse) -> CreateInteractionResponse {
+        let map = utils::vecmap_to_json_map(f(CreateInteractionResponse::default()).0);
+
+        http::create_followup_message(self.application_id.0, &self.token, &Value::Object(map))
+    }
+
+    /// Edits a follow-up message of the interaction.
+    ///
+    /// Refer to [`edit_original_response`] for more information.
+    ///
+    /// [`edit_original_response`]: #method.edit_original_response
+    pub fn edit_followup_message<F, M>(&self, message_id: M, f: F) -> Result<Message>
+        where F: FnOnce(CreateInteractionResponse) -> CreateInteractionResponse,
+              M: Into<MessageId> {
+        let map = utils::vecmap_to_json_map(f(CreateInteractionResponse::default()).0);
+
+        http::edit_followup_message(
+            self.application_id.0,
+            &self.token,
+            message_id.into().0,
+            &Value::Object(map),
+        )
+    }
+
+    /// Deletes a follow-up message of the interaction.
+    #[inline]
+    pub fn delete_followup_message<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        http::delete_followup_message(self.application_id.0, &self.token, message_id.into().0)
+    }
+}
+
+/// The data of an [`Interaction`], depending on its type.
+///
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum InteractionData {
+    /// The invoked command and the options given.
+    ApplicationCommand(ApplicationCommandInteractionData),
+    /// The used component and the options selected.
+    MessageComponent(MessageComponentInteractionData),
+    /// The submitted modal and the text entered.
+    ModalSubmit(ModalSubmitInteractionData),
+}
+
+/// The command invoked through an [`Interaction`], and the options given.
+///
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandInteractionData {
+    /// The Id of the invoked command.
+    pub id: CommandId,
+    /// The name of the invoked command.
+    pub name: String,
+    /// The options given, or the invoked sub-command.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+}
+
+/// An option given when invoking an [`ApplicationCommand`].
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandInteractionDataOption {
+    /// The name of the option.
+    pub name: String,
+    /// The value given, unless the option is a sub-command or sub-command
+    /// group.
+    ///
+    /// Users, channels and roles are given as their Id in a string.
+    #[serde(default)]
+    pub value: Option<Value>,
+    /// The options given to a sub-command, or the invoked sub-command of a
+    /// sub-command group.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+}
+
+/// The component used through an [`Interaction`], such as a clicked
+/// [`Button`].
+///
+/// [`Button`]: ../channel/struct.Button.html
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageComponentInteractionData {
+    /// The custom Id of the used component.
+    pub custom_id: String,
+    /// The type of the used component.
+    pub component_type: ComponentType,
+    /// The values of the options selected, if the component is a
+    /// [`SelectMenu`].
+    ///
+    /// The users, roles and channels selected are given as their Id in a
+    /// string, and can be looked up in [`resolved`].
+    ///
+    /// [`SelectMenu`]: ../channel/struct.SelectMenu.html
+    /// [`resolved`]: #structfield.resolved
+    #[serde(default)]
+    pub values: Vec<String>,
+    /// The users, members, roles and channels selected, if the component is
+    /// a select menu filled in by Discord.
+    #[serde(default)]
+    pub resolved: Option<MessageComponentResolvedData>,
+}
+
+/// The users, members, roles and channels selected through a select menu
+/// filled in by Discord, keyed by their Id.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MessageComponentResolvedData {
+    /// The users selected, including those selected as a member.
+    #[serde(default)]
+    pub users: HashMap<UserId, User>,
+    /// The data of the selected users as a member of the guild, if the
+    /// interaction was sent from a guild.
+    #[serde(default)]
+    pub members: HashMap<UserId, PartialMember>,
+    /// The roles selected.
+    #[serde(default)]
+    pub roles: HashMap<RoleId, Role>,
+    /// The channels selected.
+    #[serde(default)]
+    pub channels: HashMap<ChannelId, PartialChannel>,
+}
+
+/// A modal submitted through an [`Interaction`], and the text entered into
+/// its fields.
+///
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModalSubmitInteractionData {
+    /// The custom Id of the submitted modal.
+    pub custom_id: String,
+    /// The rows of the modal, each holding an [`InputText`] with the text
+    /// entered.
+    ///
+    /// [`InputText`]: ../channel/struct.InputText.html
+    pub components: Vec<ActionRow>,
+}
+
+impl ModalSubmitInteractionData {
+    /// Returns the text entered into the field of the given custom Id, if the
+    /// modal has such a field.
+    ///
+    /// # Examples
+    ///
+    /// Reading the fields of a submitted feedback form:
+    ///
+    /// ```rust,ignore
+    /// if let Some(InteractionData::ModalSubmit(ref data)) = interaction.data {
+    ///     let title = data.value("title").unwrap_or_default();
+    ///     let body = data.value("body").unwrap_or_default();
+    /// }
+    /// ```
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.values().find(|&(id, _)| id == custom_id).map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the custom Ids of the fields of the modal and
+    /// the text entered into them.
+    pub fn values<'a>(&'a self) -> Box<Iterator<Item=(&'a str, &'a str)> + 'a> {
+        Box::new(self.components
+            .iter()
+            .flat_map(|row| row.components.iter())
+            .filter_map(|component| match *component {
+                Component::InputText(ref input) => {
+                    let value = input.value.as_ref().map_or("", String::as_str);
+
+                    Some((input.custom_id.as_str(), value))
+                },
+                _ => None,
+            }))
+    }
+}
+
+/// The type of a response to an [`Interaction`].
+///
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum InteractionResponseType {
+    /// Acknowledges a [`Ping`].
+    ///
+    /// [`Ping`]: ../channel/enum.InteractionType.html#variant.Ping
+    Pong = 1,
+    /// Responds with a message.
+    ChannelMessageWithSource = 4,
+    /// Acknowledges the interaction, displaying a loading state until a
+    /// message is sent as a follow-up.
+    DeferredChannelMessageWithSource = 5,
+    /// Acknowledges the use of a component, without editing the message it
+    /// is attached to.
+    DeferredUpdateMessage = 6,
+    /// Responds to the use of a component by editing the message it is
+    /// attached to.
+    UpdateMessage = 7,
+    /// Responds with a modal for the user to fill in.
+    ///
+    /// Use [`Interaction::create_modal`] to send such a response.
+    ///
+    /// [`Interaction::create_modal`]: struct.Interaction.html#method.create_modal
+    Modal = 9,
+}
+
+enum_number!(
+    InteractionResponseType {
+        Pong,
+        ChannelMessageWithSource,
+        DeferredChannelMessageWithSource,
+        DeferredUpdateMessage,
+        UpdateMessage,
+        Modal,
+    }
+);
+
+impl InteractionResponseType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            InteractionResponseType::Pong => 1,
+            InteractionResponseType::ChannelMessageWithSource => 4,
+            InteractionResponseType::DeferredChannelMessageWithSource => 5,
+            InteractionResponseType::DeferredUpdateMessage => 6,
+            InteractionResponseType::UpdateMessage => 7,
+            InteractionResponseType::Modal => 9,
+        }
+    }
+}
+
+#[cfg(feature = "model")]
+impl ApplicationId {
+    /// Creates a global command of the application, or overwrites the one of
+    /// the same name.
+    ///
+    /// **Note**: Global commands can take up to an hour to be available in all
+    /// guilds. Use [`create_guild_command`] while developing a command.
+    ///
+    /// # Examples
+    ///
+    /// Create a command greeting a user:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::ApplicationId;
+    /// use serenity::model::interaction::ApplicationCommandOptionType;
+    ///
+    /// let _ = ApplicationId(7).create_command(|c| c
+    ///     .name("greet")
+    ///     .description("Greets a user.")
+    ///     .option(|o| o
+    ///         .kind(ApplicationCommandOptionType::User)
+    ///         .name("user")
+    ///         .description("The user to greet.")
+    ///         .required(true)));
+    /// ```
+    ///
+    /// [`create_guild_command`]: #method.create_guild_command
+    pub fn create_command<F>(&self, f: F) -> Result<ApplicationCommand>
+        where F: FnOnce(CreateApplicationCommand) -> CreateApplicationCommand {
+        let map = utils::vecmap_to_json_map(f(CreateApplicationCommand::default()).0);
+
+        http::create_global_application_command(self.0, &Value::Object(map))
+    }
+
+    /// Creates a command of the application in the given guild, or overwrites
+    /// the one of the same name.
+    ///
+    /// Refer to [`create_command`] for an example.
+    ///
+    /// [`create_command`]: #method.create_command
+    pub fn create_guild_command<F, G>(&self, guild_id: G, f: F) -> Result<ApplicationCommand>
+        where F: FnOnce(CreateApplicationCommand) -> CreateApplicationCommand,
+              G: Into<GuildId> {
+        let map = utils::vecmap_to_json_map(f(CreateApplicationCommand::default()).0);
+
+        http::create_guild_application_command(self.0, guild_id.into().0, &Value::Object(map))
+    }
+
+    /// Gets the global commands of the application.
+    #[inline]
+    pub fn commands(&self) -> Result<Vec<ApplicationCommand>> {
+        http::get_global_application_commands(self.0)
+    }
+
+    /// Gets the commands of the application in the given guild.
+    #[inline]
+    pub fn guild_commands<G: Into<GuildId>>(&self, guild_id: G) -> Result<Vec<ApplicationCommand>> {
+        http::get_guild_application_commands(self.0, guild_id.into().0)
+    }
+
+    /// Edits a global command of the application.
+    ///
+    /// Options that are not set are left unchanged, except that setting any
+    /// option replaces all of them.
+    pub fn edit_command<C, F>(&self, command_id: C, f: F) -> Result<ApplicationCommand>
+        where C: Into<CommandId>,
+              F: FnOnce(CreateApplicationCommand) -> CreateApplicationCommand {
+        let map = utils::vecmap_to_json_map(f(CreateApplicationCommand::default()).0);
+
+        http::edit_global_application_command(self.0, command_id.into().0, &Value::Object(map))
+    }
+
+    /// Edits a command of the application in the given guild.
+    ///
+    /// Refer to [`edit_command`] for more information.
+    ///
+    /// [`edit_command`]: #method.edit_command
+    pub fn edit_guild_command<C, F, G>(&self, guild_id: G, command_id: C, f: F)
+        -> Result<ApplicationCommand>
+        where C: Into<CommandId>,
+              F: FnOnce(CreateApplicationCommand) -> CreateApplicationCommand,
+              G: Into<GuildId> {
+        let map = utils::vecmap_to_json_map(f(CreateApplicationCommand::default()).0);
+
+        http::edit_guild_application_command(
+            self.0,
+            guild_id.into().0,
+            command_id.into().0,
+            &Value::Object(map),
+        )
+    }
+
+    /// Deletes a global command of the application.
+    #[inline]
+    pub fn delete_command<C: Into<CommandId>>(&self, command_id: C) -> Result<()> {
+        http::delete_global_application_command(self.0, command_id.into().0)
+    }
+
+    /// Deletes a command of the application in the given guild.
+    #[inline]
+    pub fn delete_guild_command<C, G>(&self, guild_id: G, command_id: C) -> Result<()>
+        where C: Into<CommandId>, G: Into<GuildId> {
+        http::delete_guild_application_command(self.0, guild_id.into().0, command_id.into().0)
+    }
+
+    /// Replaces all global commands of the application with the given
+    /// definitions, such as those generated by
+    /// [`StandardFramework::application_commands`].
+    ///
+    /// Commands that are not among the definitions are deleted.
+    ///
+    /// [`StandardFramework::application_commands`]: ../../framework/standard/struct.StandardFramework.html#method.application_commands
+    #[inline]
+    pub fn set_commands(&self, commands: Vec<Value>) -> Result<Vec<ApplicationCommand>> {
+        http::set_global_application_commands(self.0, &Value::Array(commands))
+    }
+
+    /// Replaces all commands of the application in the given guild with the
+    /// given definitions.
+    ///
+    /// Refer to [`set_commands`] for more information.
+    ///
+    /// [`set_commands`]: #method.set_commands
+    #[inline]
+    pub fn set_guild_commands<G: Into<GuildId>>(&self, guild_id: G, commands: Vec<Value>)
+        -> Result<Vec<ApplicationCommand>> {
+        http::set_guild_application_commands(self.0, guild_id.into().0, &Value::Array(commands))
+    }
+}
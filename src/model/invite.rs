@@ -1,6 +1,5 @@
 //! Models for server and channel invites.
 
-use chrono::{DateTime, FixedOffset};
 use super::prelude::*;
 
 #[cfg(feature = "model")]
@@ -237,7 +236,7 @@ pub struct RichInvite {
     /// The unique code for the invite.
     pub code: String,
     /// When the invite was created.
-    pub created_at: DateTime<FixedOffset>,
+    pub created_at: Timestamp,
     /// A representation of the minimal amount of information needed about the
     /// [`Guild`] being invited to.
     ///
@@ -9,24 +9,89 @@ use std::error::Error as StdError;
 use std::result::Result as StdResult;
 #[cfg(all(feature = "model", feature = "utils"))]
 use std::str::FromStr;
-#[cfg(all(feature = "model", feature = "utils"))]
 use std::fmt;
 #[cfg(all(feature = "model", any(feature = "cache", feature = "utils")))]
 use utils;
 
 /// Allows something - such as a channel or role - to be mentioned in a message.
 pub trait Mentionable {
-    /// Creates a mentionable string, that will be able to notify and/or create
-    /// a link to the item.
-    fn mention(&self) -> String;
+    /// Creates a [`Mention`] that will be able to notify and/or create a link
+    /// to the item, once displayed in a message.
+    ///
+    /// [`Mention`]: enum.Mention.html
+    fn mention(&self) -> Mention;
+}
+
+/// A mention of a channel, emoji, role, or user, as created by
+/// [`Mentionable::mention`].
+///
+/// Its `Display` implementation formats the mention the way Discord expects
+/// it in a message's content.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::id::{ChannelId, UserId};
+/// use serenity::model::misc::Mentionable;
+///
+/// let content = format!("{} please read {}", UserId(1).mention(), ChannelId(2).mention());
+///
+/// assert_eq!(content, "<@1> please read <#2>");
+/// ```
+///
+/// [`Mentionable::mention`]: trait.Mentionable.html#tymethod.mention
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Mention {
+    /// A mention of a channel, linking to it.
+    Channel(ChannelId),
+    /// A custom emoji, displaying it inline.
+    Emoji {
+        /// The Id of the emoji.
+        id: EmojiId,
+        /// The name of the emoji.
+        name: String,
+        /// Whether the emoji is animated.
+        animated: bool,
+    },
+    /// A mention of a role, notifying its members if it is mentionable.
+    Role(RoleId),
+    /// A mention of a user, notifying them.
+    User(UserId),
+}
+
+impl fmt::Display for Mention {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Mention::Channel(id) => write!(f, "<#{}>", id.0),
+            Mention::Emoji { id, ref name, animated } => {
+                let prefix = if animated { "a" } else { "" };
+
+                write!(f, "<{}:{}:{}>", prefix, name, id.0)
+            },
+            Mention::Role(id) => write!(f, "<@&{}>", id.0),
+            Mention::User(id) => write!(f, "<@{}>", id.0),
+        }
+    }
+}
+
+impl From<ChannelId> for Mention {
+    fn from(id: ChannelId) -> Mention { Mention::Channel(id) }
+}
+
+impl From<RoleId> for Mention {
+    fn from(id: RoleId) -> Mention { Mention::Role(id) }
+}
+
+impl From<UserId> for Mention {
+    fn from(id: UserId) -> Mention { Mention::User(id) }
 }
 
 impl Mentionable for ChannelId {
-    fn mention(&self) -> String { format!("<#{}>", self.0) }
+    fn mention(&self) -> Mention { Mention::Channel(*self) }
 }
 
 impl Mentionable for Channel {
-    fn mention(&self) -> String {
+    fn mention(&self) -> Mention {
         match *self {
             Channel::Guild(ref x) => x.with(Mentionable::mention),
             Channel::Private(ref x) => x.with(Mentionable::mention),
@@ -37,55 +102,63 @@ impl Mentionable for Channel {
 }
 
 impl Mentionable for ChannelCategory {
-    fn mention(&self) -> String {
-        format!("<#{}>", self.name)
-    }
+    fn mention(&self) -> Mention { Mention::Channel(self.id) }
 }
 
 impl Mentionable for CurrentUser {
-    fn mention(&self) -> String {
-        format!("<@{}>", self.id.0)
-    }
+    fn mention(&self) -> Mention { Mention::User(self.id) }
 }
 
 impl Mentionable for Emoji {
-    fn mention(&self) -> String { format!("<:{}:{}>", self.name, self.id.0) }
+    fn mention(&self) -> Mention {
+        Mention::Emoji {
+            id: self.id,
+            name: self.name.clone(),
+            animated: self.animated,
+        }
+    }
 }
 
-impl Mentionable for Group {
-    fn mention(&self) -> String {
-        format!("<#{}>", self.channel_id.0)
+impl Mentionable for EmojiIdentifier {
+    fn mention(&self) -> Mention {
+        Mention::Emoji {
+            id: self.id,
+            name: self.name.clone(),
+            animated: false,
+        }
     }
 }
 
+impl Mentionable for Group {
+    fn mention(&self) -> Mention { Mention::Channel(self.channel_id) }
+}
+
 impl Mentionable for Member {
-    fn mention(&self) -> String { format!("<@{}>", self.user.with(|u| u.id.0)) }
+    fn mention(&self) -> Mention { Mention::User(self.user.with(|u| u.id)) }
 }
 
 impl Mentionable for PrivateChannel {
-    fn mention(&self) -> String {
-        format!("<#{}>", self.id.0)
-    }
+    fn mention(&self) -> Mention { Mention::Channel(self.id) }
 }
 
 impl Mentionable for RoleId {
-    fn mention(&self) -> String { format!("<@&{}>", self.0) }
+    fn mention(&self) -> Mention { Mention::Role(*self) }
 }
 
 impl Mentionable for Role {
-    fn mention(&self) -> String { format!("<@&{}>", self.id.0) }
+    fn mention(&self) -> Mention { Mention::Role(self.id) }
 }
 
 impl Mentionable for UserId {
-    fn mention(&self) -> String { format!("<@{}>", self.0) }
+    fn mention(&self) -> Mention { Mention::User(*self) }
 }
 
 impl Mentionable for User {
-    fn mention(&self) -> String { format!("<@{}>", self.id.0) }
+    fn mention(&self) -> Mention { Mention::User(self.id) }
 }
 
 impl Mentionable for GuildChannel {
-    fn mention(&self) -> String { format!("<#{}>", self.id.0) }
+    fn mention(&self) -> Mention { Mention::Channel(self.id) }
 }
 
 #[cfg(all(feature = "model", feature = "utils"))]
@@ -127,44 +200,6 @@ impl FromStr for User {
 }
 
 macro_rules! impl_from_str {
-    (id: $($id:tt, $err:ident;)*) => {
-        $(
-            #[cfg(all(feature = "model", feature = "utils"))]
-            #[derive(Debug)]
-            pub enum $err {
-                InvalidFormat,
-            }
-
-            #[cfg(all(feature = "model", feature = "utils"))]
-            impl fmt::Display for $err {
-                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.description()) }
-            }
-
-            #[cfg(all(feature = "model", feature = "utils"))]
-            impl StdError for $err {
-                fn description(&self) -> &str {
-                    use self::$err::*;
-
-                    match *self {
-                        InvalidFormat => "invalid id format",
-                    }
-                }
-            }
-
-            #[cfg(all(feature = "model", feature = "utils"))]
-            impl FromStr for $id {
-                type Err = $err;
-
-                fn from_str(s: &str) -> StdResult<Self, Self::Err> {
-                    Ok(match utils::parse_username(s) {
-                        Some(id) => $id(id),
-                        None => s.parse::<u64>().map($id).map_err(|_| $err::InvalidFormat)?,
-                    })
-                }
-            }
-        )*
-    };
-
     (struct: $($struct:ty, $id:tt, $err:ident, $invalid_variant:tt, $parse_fn:ident, $desc:expr;)*) => {
         $(
             #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
@@ -194,17 +229,90 @@ macro_rules! impl_from_str {
     };
 }
 
-impl_from_str! { id:
-    UserId, UserIdParseError;
-    RoleId, RoleIdParseError;
-    ChannelId, ChannelIdParseError;
-}
-
 impl_from_str! { struct:
     Channel, ChannelId, ChannelParseError, InvalidChannel, parse_channel, "invalid channel";
     Role, RoleId, RoleParseError, InvalidRole, parse_role, "invalid role";
 }
 
+/// The error of parsing a [`UserId`], which all Ids share now.
+///
+/// [`UserId`]: ../id/struct.UserId.html
+#[cfg(all(feature = "model", feature = "utils"))]
+#[deprecated(since = "0.5.9", note = "Use `IdParseError` instead.")]
+pub type UserIdParseError = IdParseError;
+
+/// The error of parsing a [`RoleId`], which all Ids share now.
+///
+/// [`RoleId`]: ../id/struct.RoleId.html
+#[cfg(all(feature = "model", feature = "utils"))]
+#[deprecated(since = "0.5.9", note = "Use `IdParseError` instead.")]
+pub type RoleIdParseError = IdParseError;
+
+/// The error of parsing a [`ChannelId`], which all Ids share now.
+///
+/// [`ChannelId`]: ../id/struct.ChannelId.html
+#[cfg(all(feature = "model", feature = "utils"))]
+#[deprecated(since = "0.5.9", note = "Use `IdParseError` instead.")]
+pub type ChannelIdParseError = IdParseError;
+
+/// The format of an image retrieved from Discord's CDN, for use with methods
+/// such as [`User::avatar_url_with`].
+///
+/// [`User::avatar_url_with`]: ../user/struct.User.html#method.avatar_url_with
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ImageFormat {
+    /// An animated GIF, only available for animated images.
+    Gif,
+    /// A JPEG image.
+    Jpeg,
+    /// A PNG image.
+    Png,
+    /// A WebP image.
+    WebP,
+}
+
+impl ImageFormat {
+    /// The file extension of the format, as used in CDN URLs.
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            ImageFormat::Gif => "gif",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::WebP => "webp",
+        }
+    }
+}
+
+/// Builds the CDN URL of the image with the given `hash` under `path`.
+///
+/// Without a `format`, animated images are retrieved as GIF and others as
+/// WebP. As GIF is only available for animated images, it is replaced by PNG
+/// for others. The `size` is rounded up to a power of two between 16 and
+/// 4096, as required by Discord.
+#[cfg(any(feature = "model", feature = "utils"))]
+pub(crate) fn cdn_image_url(path: &str, hash: &str, format: Option<ImageFormat>, size: u16) -> String {
+    cdn_url(path, hash, is_animated_hash(hash), format, size)
+}
+
+/// Builds the CDN URL of an image, given whether it is animated.
+#[cfg(any(feature = "model", feature = "utils"))]
+pub(crate) fn cdn_url(path: &str, name: &str, animated: bool, format: Option<ImageFormat>, size: u16) -> String {
+    let format = match format {
+        Some(ImageFormat::Gif) if !animated => ImageFormat::Png,
+        Some(format) => format,
+        None if animated => ImageFormat::Gif,
+        None => ImageFormat::WebP,
+    };
+
+    let size = size.max(16).min(4096).next_power_of_two();
+
+    cdn!("{}/{}.{}?size={}", path, name, format.extension(), size)
+}
+
+/// Whether an image hash belongs to an animated image.
+#[cfg(any(feature = "model", feature = "utils"))]
+pub(crate) fn is_animated_hash(hash: &str) -> bool { hash.starts_with("a_") }
+
 /// A version of an emoji used only when solely the Id and name are known.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct EmojiIdentifier {
@@ -220,6 +328,16 @@ impl EmojiIdentifier {
     /// Generates a URL to the emoji's image.
     #[inline]
     pub fn url(&self) -> String { format!(cdn!("/emojis/{}.png"), self.id) }
+
+    /// Generates a URL to the emoji's image in the given format and size.
+    ///
+    /// As it is not known whether the emoji is animated, it is retrieved as
+    /// WebP without a `format`.
+    pub fn url_with(&self, format: Option<ImageFormat>, size: u16) -> String {
+        let animated = format == Some(ImageFormat::Gif);
+
+        cdn_url("/emojis", &self.id.to_string(), animated, format, size)
+    }
 }
 
 #[cfg(all(feature = "model", feature = "utils"))]
@@ -297,6 +415,7 @@ pub struct Maintenance {
 #[cfg(test)]
 mod test {
     use model::prelude::*;
+    use serde_json;
 
     #[test]
     fn test_formatters() {
@@ -307,6 +426,33 @@ mod test {
         assert_eq!(UserId(5).to_string(), "5");
     }
 
+    #[test]
+    fn test_id_from_str() {
+        assert_eq!("1".parse::<GuildId>(), Ok(GuildId(1)));
+        assert_eq!("<#2>".parse::<ChannelId>(), Ok(ChannelId(2)));
+        assert_eq!("<@&3>".parse::<RoleId>(), Ok(RoleId(3)));
+        assert_eq!("<@4>".parse::<UserId>(), Ok(UserId(4)));
+        assert_eq!("<@!5>".parse::<UserId>(), Ok(UserId(5)));
+        assert_eq!("<@&6>".parse::<UserId>(), Err(IdParseError::InvalidFormat));
+        assert_eq!("<#7>".parse::<MessageId>(), Err(IdParseError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_id_created_at() {
+        let created_at = UserId(81_384_788_765_712_384).created_at();
+
+        assert_eq!(created_at.timestamp_millis(), 1_439_474_045_698);
+    }
+
+    #[test]
+    fn test_id_serde() {
+        let id = UserId(81_384_788_765_712_384);
+
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""81384788765712384""#);
+        assert_eq!(serde_json::from_str::<UserId>(r#""81384788765712384""#).unwrap(), id);
+        assert_eq!(serde_json::from_str::<UserId>("81384788765712384").unwrap(), id);
+    }
+
     #[cfg(feature = "utils")]
     mod utils {
         use model::prelude::*;
@@ -314,6 +460,16 @@ mod test {
         use std::sync::Arc;
         use utils::Colour;
 
+        #[test]
+        fn test_cdn_image_url() {
+            use model::misc::cdn_image_url;
+
+            assert_eq!(cdn_image_url("/icons/1", "a_b", None, 128), "https://cdn.discordapp.com/icons/1/a_b.gif?size=128");
+            assert_eq!(cdn_image_url("/icons/1", "b", None, 100), "https://cdn.discordapp.com/icons/1/b.webp?size=128");
+            assert_eq!(cdn_image_url("/icons/1", "b", Some(ImageFormat::Gif), 0), "https://cdn.discordapp.com/icons/1/b.png?size=16");
+            assert_eq!(cdn_image_url("/icons/1", "a_b", Some(ImageFormat::Jpeg), 9000), "https://cdn.discordapp.com/icons/1/a_b.jpg?size=4096");
+        }
+
         #[test]
         fn test_mention() {
             let channel = Channel::Guild(Arc::new(RwLock::new(GuildChannel {
@@ -327,9 +483,14 @@ mod test {
                 name: "a".to_string(),
                 permission_overwrites: vec![],
                 position: 1,
+                rtc_region: None,
                 topic: None,
                 user_limit: None,
                 nsfw: false,
+                thread_metadata: None,
+                owner_id: None,
+                message_count: None,
+                member_count: None,
             })));
             let emoji = Emoji {
                 animated: false,
@@ -366,14 +527,50 @@ mod test {
                 user: Arc::new(RwLock::new(user.clone())),
             };
 
-            assert_eq!(ChannelId(1).mention(), "<#1>");
-            assert_eq!(channel.mention(), "<#4>");
-            assert_eq!(emoji.mention(), "<:a:5>");
-            assert_eq!(member.mention(), "<@6>");
-            assert_eq!(role.mention(), "<@&2>");
-            assert_eq!(role.id.mention(), "<@&2>");
-            assert_eq!(user.mention(), "<@6>");
-            assert_eq!(user.id.mention(), "<@6>");
+            assert_eq!(ChannelId(1).mention().to_string(), "<#1>");
+            assert_eq!(channel.mention().to_string(), "<#4>");
+            assert_eq!(emoji.mention().to_string(), "<:a:5>");
+            assert_eq!(member.mention().to_string(), "<@6>");
+            assert_eq!(role.mention().to_string(), "<@&2>");
+            assert_eq!(role.id.mention().to_string(), "<@&2>");
+            assert_eq!(user.mention().to_string(), "<@6>");
+            assert_eq!(user.id.mention().to_string(), "<@6>");
+
+            let animated = Mention::Emoji {
+                id: EmojiId(5),
+                name: "a".to_string(),
+                animated: true,
+            };
+            assert_eq!(animated.to_string(), "<a:a:5>");
+        }
+
+        #[test]
+        fn test_mention_of_category_uses_id() {
+            let category = ChannelCategory {
+                id: ChannelId(7),
+                category_id: None,
+                position: 0,
+                kind: ChannelType::Category,
+                name: "general".to_string(),
+                nsfw: false,
+                permission_overwrites: vec![],
+            };
+
+            assert_eq!(category.mention(), Mention::Channel(ChannelId(7)));
+            assert_eq!(category.mention().to_string(), "<#7>");
+        }
+
+        #[test]
+        fn test_mention_conversions() {
+            let identifier = EmojiIdentifier {
+                id: EmojiId(5),
+                name: "a".to_string(),
+            };
+
+            assert_eq!(identifier.mention().to_string(), "<:a:5>");
+            assert_eq!(Mention::from(ChannelId(1)), ChannelId(1).mention());
+            assert_eq!(Mention::from(RoleId(2)), RoleId(2).mention());
+            assert_eq!(Mention::from(UserId(3)), UserId(3).mention());
         }
     }
 }
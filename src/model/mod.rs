@@ -29,16 +29,19 @@ pub mod event;
 pub mod gateway;
 pub mod guild;
 pub mod id;
+pub mod interaction;
 pub mod invite;
 pub mod misc;
 pub mod permissions;
 pub mod prelude;
+pub mod timestamp;
 pub mod user;
 pub mod voice;
 pub mod webhook;
 
 pub use self::error::Error as ModelError;
 pub use self::permissions::Permissions;
+pub use self::timestamp::Timestamp;
 
 use internal::prelude::*;
 use parking_lot::RwLock;
@@ -16,6 +16,7 @@ pub use super::event::*;
 pub use super::guild::*;
 pub use super::gateway::*;
 pub use super::id::*;
+pub use super::interaction::*;
 pub use super::invite::*;
 pub use super::misc::*;
 pub use super::permissions::*;
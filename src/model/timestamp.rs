@@ -0,0 +1,607 @@
+//! A timestamp type for the times given by and sent to the API.
+//!
+//! [`Timestamp`]s convert to and from the standard library's `SystemTime`.
+//! Enabling the `chrono` feature also provides conversions for `chrono`'s
+//! `DateTime`, and enabling the `time` feature for the `time` crate's
+//! `Timespec` and `Tm`.
+//!
+//! [`Timestamp`]: struct.Timestamp.html
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+use serde::de::{Deserialize, Deserializer, Error as DeError, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::{
+    cmp::Ordering,
+    error::Error as StdError,
+    fmt::{self, Display, Formatter, Result as FmtResult, Write},
+    hash::{Hash, Hasher},
+    result::Result as StdResult,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH}
+};
+#[cfg(feature = "time")]
+use time::{Timespec, Tm};
+
+/// The milliseconds from the Unix epoch to the start of the year 0, the
+/// earliest time an ISO 8601 timestamp with a four digit year can give.
+const MIN_MILLIS: i64 = -62_167_219_200_000;
+
+/// The milliseconds from the Unix epoch to the end of the year 9999, the
+/// latest time an ISO 8601 timestamp with a four digit year can give.
+const MAX_MILLIS: i64 = 253_402_300_799_999;
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// An error returned when a [`Timestamp`] could not be created.
+///
+/// [`Timestamp`]: struct.Timestamp.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidTimestamp {
+    /// The string is not an ISO 8601 timestamp.
+    Format,
+    /// The time is outside of the range of representable timestamps.
+    OutOfRange,
+}
+
+impl Display for InvalidTimestamp {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(self.description()) }
+}
+
+impl StdError for InvalidTimestamp {
+    fn description(&self) -> &str {
+        match *self {
+            InvalidTimestamp::Format => "The timestamp is not in ISO 8601 format",
+            InvalidTimestamp::OutOfRange => "The timestamp is out of range",
+        }
+    }
+}
+
+/// A point in time in UTC, as used by the API.
+///
+/// Timestamps are parsed from and serialized to ISO 8601 strings, such as
+/// `2016-11-08T00:00:00.000+00:00`, and can represent the years 0 to 9999 in
+/// millisecond precision.
+///
+/// The string a timestamp was parsed from is kept, so that it is displayed
+/// and sent back to the API as it was received. Timestamps are compared by
+/// the point in time alone.
+///
+/// # Examples
+///
+/// Parsing a timestamp and converting it to a Unix timestamp:
+///
+/// ```rust
+/// use serenity::model::Timestamp;
+///
+/// let timestamp: Timestamp = "2016-04-30T11:18:25.796+00:00".parse().unwrap();
+///
+/// assert_eq!(timestamp.unix_timestamp(), 1_462_015_105);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Timestamp {
+    millis: i64,
+    original: String,
+}
+
+impl Timestamp {
+    /// Creates a timestamp of the current time.
+    pub fn now() -> Self {
+        // The system clock only exceeds the milliseconds an `i64` holds when
+        // it is set millions of years off.
+        let millis = system_time_millis(SystemTime::now()).unwrap_or(0);
+
+        Timestamp::from_millis_unchecked(millis)
+    }
+
+    /// Parses an ISO 8601 timestamp.
+    ///
+    /// Timestamps without an offset are treated as being in UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTimestamp::Format`] if the string is not an ISO 8601
+    /// timestamp, or [`InvalidTimestamp::OutOfRange`] if its offset moves it
+    /// out of the representable years.
+    ///
+    /// [`InvalidTimestamp::Format`]: enum.InvalidTimestamp.html#variant.Format
+    /// [`InvalidTimestamp::OutOfRange`]: enum.InvalidTimestamp.html#variant.OutOfRange
+    pub fn parse(input: &str) -> StdResult<Self, InvalidTimestamp> {
+        let millis = parse_millis(input.as_bytes()).ok_or(InvalidTimestamp::Format)?;
+
+        if millis < MIN_MILLIS || millis > MAX_MILLIS {
+            return Err(InvalidTimestamp::OutOfRange);
+        }
+
+        Ok(Timestamp {
+            millis,
+            original: input.to_string(),
+        })
+    }
+
+    /// Creates a timestamp from the number of seconds since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTimestamp::OutOfRange`] if the time can not be
+    /// represented.
+    ///
+    /// [`InvalidTimestamp::OutOfRange`]: enum.InvalidTimestamp.html#variant.OutOfRange
+    pub fn from_unix_timestamp(secs: i64) -> StdResult<Self, InvalidTimestamp> {
+        secs.checked_mul(1000)
+            .ok_or(InvalidTimestamp::OutOfRange)
+            .and_then(Self::from_millis)
+    }
+
+    /// Creates a timestamp from the number of milliseconds since the Unix
+    /// epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTimestamp::OutOfRange`] if the time can not be
+    /// represented.
+    ///
+    /// [`InvalidTimestamp::OutOfRange`]: enum.InvalidTimestamp.html#variant.OutOfRange
+    pub fn from_millis(millis: i64) -> StdResult<Self, InvalidTimestamp> {
+        if millis < MIN_MILLIS || millis > MAX_MILLIS {
+            return Err(InvalidTimestamp::OutOfRange);
+        }
+
+        Ok(Timestamp::from_millis_unchecked(millis))
+    }
+
+    /// Creates a timestamp from a `SystemTime`, dropping the precision below
+    /// milliseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTimestamp::OutOfRange`] if the time can not be
+    /// represented.
+    ///
+    /// [`InvalidTimestamp::OutOfRange`]: enum.InvalidTimestamp.html#variant.OutOfRange
+    pub fn from_system_time(time: SystemTime) -> StdResult<Self, InvalidTimestamp> {
+        system_time_millis(time)
+            .ok_or(InvalidTimestamp::OutOfRange)
+            .and_then(Self::from_millis)
+    }
+
+    /// Creates a timestamp from a `time::Timespec`, dropping the precision
+    /// below milliseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTimestamp::OutOfRange`] if the time can not be
+    /// represented.
+    ///
+    /// [`InvalidTimestamp::OutOfRange`]: enum.InvalidTimestamp.html#variant.OutOfRange
+    #[cfg(feature = "time")]
+    pub fn from_timespec(timespec: Timespec) -> StdResult<Self, InvalidTimestamp> {
+        if timespec.nsec < 0 {
+            return Err(InvalidTimestamp::OutOfRange);
+        }
+
+        timespec.sec
+            .checked_mul(1000)
+            .and_then(|millis| millis.checked_add(i64::from(timespec.nsec / 1_000_000)))
+            .ok_or(InvalidTimestamp::OutOfRange)
+            .and_then(Self::from_millis)
+    }
+
+    /// The number of seconds since the Unix epoch.
+    pub fn unix_timestamp(&self) -> i64 { self.millis.div_euclid_compat(1000) }
+
+    /// The number of milliseconds since the Unix epoch.
+    pub fn timestamp_millis(&self) -> i64 { self.millis }
+
+    /// Formats the timestamp as ISO 8601 string in UTC, such as
+    /// `2016-04-30T11:18:25.796+00:00`.
+    ///
+    /// Unlike the `Display` implementation, this does not give the string the
+    /// timestamp was parsed from.
+    pub fn to_rfc3339(&self) -> String { format_millis(self.millis) }
+
+    /// Creates a timestamp from milliseconds known to be within the range of
+    /// representable timestamps, such as those of Ids.
+    pub(crate) fn from_millis_unchecked(millis: i64) -> Self {
+        Timestamp {
+            millis,
+            original: format_millis(millis),
+        }
+    }
+}
+
+/// Floored division for the Rust versions lacking `i64::div_euclid`.
+trait DivEuclidCompat {
+    fn div_euclid_compat(self, rhs: Self) -> Self;
+}
+
+impl DivEuclidCompat for i64 {
+    fn div_euclid_compat(self, rhs: i64) -> i64 {
+        let quotient = self / rhs;
+
+        if self % rhs < 0 { quotient - 1 } else { quotient }
+    }
+}
+
+/// The milliseconds since the Unix epoch of a `SystemTime`, rounded down.
+fn system_time_millis(time: SystemTime) -> Option<i64> {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => duration_millis(since, false),
+        Err(why) => duration_millis(why.duration(), true).map(|millis| -millis),
+    }
+}
+
+fn duration_millis(duration: Duration, round_up: bool) -> Option<i64> {
+    let mut millis = u64::from(duration.subsec_nanos() / 1_000_000);
+
+    if round_up && duration.subsec_nanos() % 1_000_000 != 0 {
+        millis += 1;
+    }
+
+    duration.as_secs()
+        .checked_mul(1000)
+        .and_then(|secs| secs.checked_add(millis))
+        .and_then(|millis| if millis > i64::max_value() as u64 {
+            None
+        } else {
+            Some(millis as i64)
+        })
+}
+
+/// Parses the milliseconds since the Unix epoch out of a timestamp such as
+/// `2016-04-30T11:18:25.796+00:00`.
+///
+/// The fraction of the seconds and the offset are optional, and the digits of
+/// the fraction beyond milliseconds are ignored.
+fn parse_millis(input: &[u8]) -> Option<i64> {
+    if input.len() < 19
+        || input[4] != b'-'
+        || input[7] != b'-'
+        || !(input[10] == b'T' || input[10] == b't' || input[10] == b' ')
+        || input[13] != b':'
+        || input[16] != b':' {
+        return None;
+    }
+
+    let year = parse_digits(&input[0..4])?;
+    let month = parse_digits(&input[5..7])?;
+    let day = parse_digits(&input[8..10])?;
+    let hour = parse_digits(&input[11..13])?;
+    let minute = parse_digits(&input[14..16])?;
+    let second = parse_digits(&input[17..19])?;
+
+    if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month)
+        || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let mut rest = &input[19..];
+    let mut millis = 0;
+
+    if rest.first() == Some(&b'.') {
+        let digits = rest[1..].iter().take_while(|b| b.is_ascii_digit()).count();
+
+        if digits == 0 {
+            return None;
+        }
+
+        for (index, &digit) in rest[1..digits + 1].iter().take(3).enumerate() {
+            millis += i64::from(digit - b'0') * [100, 10, 1][index];
+        }
+
+        rest = &rest[digits + 1..];
+    }
+
+    let offset = match rest.len() {
+        0 => 0,
+        1 if rest[0] == b'Z' || rest[0] == b'z' => 0,
+        6 if rest[3] == b':' => {
+            let hours = parse_digits(&rest[1..3])?;
+            let minutes = parse_digits(&rest[4..6])?;
+
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+
+            match rest[0] {
+                b'+' => hours * 3600 + minutes * 60,
+                b'-' => -(hours * 3600 + minutes * 60),
+                _ => return None,
+            }
+        },
+        _ => return None,
+    };
+
+    let secs = days_from_civil(year, month, day) * 86_400
+        + hour * 3600
+        + minute * 60
+        + second
+        - offset;
+
+    Some(secs * 1000 + millis)
+}
+
+fn parse_digits(digits: &[u8]) -> Option<i64> {
+    digits.iter().fold(Some(0), |number, &digit| if digit.is_ascii_digit() {
+        number.map(|number| number * 10 + i64::from(digit - b'0'))
+    } else {
+        None
+    })
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The days since the Unix epoch of a date in the proleptic Gregorian
+/// calendar, counting March as the first month so that leap days come last.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = (if year >= 0 { year } else { year - 399 }) / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The date of the days since the Unix epoch, as the inverse of
+/// `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = (if days >= 0 { days } else { days - 146_096 }) / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+        - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400;
+
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+fn format_millis(millis: i64) -> String {
+    let days = millis.div_euclid_compat(MILLIS_PER_DAY);
+    let millis_of_day = millis - days * MILLIS_PER_DAY;
+    let (year, month, day) = civil_from_days(days);
+    let secs = millis_of_day / 1000;
+
+    let mut formatted = String::with_capacity(29);
+    let _ = write!(
+        formatted,
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    );
+
+    if millis_of_day % 1000 != 0 {
+        let _ = write!(formatted, ".{:03}", millis_of_day % 1000);
+    }
+
+    formatted.push_str("+00:00");
+
+    formatted
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_str(&self.original) }
+}
+
+impl FromStr for Timestamp {
+    type Err = InvalidTimestamp;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> { Timestamp::parse(s) }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Timestamp) -> bool { self.millis == other.millis }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Timestamp) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Timestamp) -> Ordering { self.millis.cmp(&other.millis) }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.millis.hash(state) }
+}
+
+impl<'a> From<&'a Timestamp> for Timestamp {
+    fn from(timestamp: &'a Timestamp) -> Self { timestamp.clone() }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+    fn from(datetime: DateTime<Tz>) -> Self { Timestamp::from(&datetime) }
+}
+
+#[cfg(feature = "chrono")]
+impl<'a, Tz: TimeZone> From<&'a DateTime<Tz>> for Timestamp {
+    fn from(datetime: &'a DateTime<Tz>) -> Self {
+        let datetime = datetime.with_timezone(&Utc);
+
+        Timestamp {
+            millis: datetime.timestamp() * 1000 + i64::from(datetime.timestamp_subsec_millis()),
+            original: datetime.to_rfc3339(),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for DateTime<Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        let secs = timestamp.unix_timestamp();
+        let nanos = (timestamp.millis - secs * 1000) as u32 * 1_000_000;
+
+        Utc.timestamp(secs, nanos)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        if timestamp.millis >= 0 {
+            UNIX_EPOCH + Duration::from_millis(timestamp.millis as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_millis(timestamp.millis.wrapping_neg() as u64)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for Timespec {
+    fn from(timestamp: Timestamp) -> Self {
+        let secs = timestamp.unix_timestamp();
+
+        Timespec::new(secs, (timestamp.millis - secs * 1000) as i32 * 1_000_000)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for Tm {
+    fn from(timestamp: Timestamp) -> Self { ::time::at_utc(Timespec::from(timestamp)) }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        deserializer.deserialize_str(TimestampVisitor)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(&self.original)
+    }
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an ISO 8601 timestamp")
+    }
+
+    fn visit_str<E: DeError>(self, v: &str) -> StdResult<Self::Value, E> {
+        Timestamp::parse(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{InvalidTimestamp, Timestamp};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn parse() {
+        let timestamp = Timestamp::parse("2016-04-30T11:18:25.796+00:00").unwrap();
+        assert_eq!(timestamp.timestamp_millis(), 1_462_015_105_796);
+
+        let offset = Timestamp::parse("2016-04-30T13:18:25.796+02:00").unwrap();
+        assert_eq!(timestamp, offset);
+
+        let naive = Timestamp::parse("2016-04-30T11:18:25.796").unwrap();
+        assert_eq!(timestamp, naive);
+
+        let zulu = Timestamp::parse("2016-04-30T11:18:25.796Z").unwrap();
+        assert_eq!(timestamp, zulu);
+
+        let micros = Timestamp::parse("2016-04-30T11:18:25.796563+00:00").unwrap();
+        assert_eq!(timestamp, micros);
+
+        let seconds = Timestamp::parse("2016-04-30T11:18:25+00:00").unwrap();
+        assert_eq!(seconds.timestamp_millis(), 1_462_015_105_000);
+
+        assert_eq!(Timestamp::parse("yesterday"), Err(InvalidTimestamp::Format));
+        assert_eq!(Timestamp::parse("2016-02-30T00:00:00+00:00"), Err(InvalidTimestamp::Format));
+        assert_eq!(Timestamp::parse("2016-04-30T24:00:00+00:00"), Err(InvalidTimestamp::Format));
+        assert_eq!(Timestamp::parse("2016-04-30T11:18:25.+00:00"), Err(InvalidTimestamp::Format));
+        assert_eq!(Timestamp::parse("2016-04-30T11:18:25+0000"), Err(InvalidTimestamp::Format));
+        assert!(Timestamp::parse("2016-02-29T00:00:00+00:00").is_ok());
+    }
+
+    #[test]
+    fn keeps_original() {
+        let original = "2017-04-15T22:09:16.110563+00:00";
+        let timestamp = Timestamp::parse(original).unwrap();
+
+        assert_eq!(timestamp.to_string(), original);
+        assert_eq!(timestamp.to_rfc3339(), "2017-04-15T22:09:16.110+00:00");
+    }
+
+    #[test]
+    fn round_trip() {
+        let timestamp = Timestamp::from_millis(1_462_015_105_796).unwrap();
+
+        assert_eq!(timestamp.to_string(), "2016-04-30T11:18:25.796+00:00");
+        assert_eq!(timestamp.to_string().parse::<Timestamp>(), Ok(timestamp));
+        assert_eq!(Timestamp::from_millis(-1_500).unwrap().timestamp_millis(), -1_500);
+        assert_eq!(Timestamp::from_millis(-1_500).unwrap().unix_timestamp(), -2);
+        assert_eq!(Timestamp::from_millis(-1_500).unwrap().to_rfc3339(), "1969-12-31T23:59:58.500+00:00");
+        assert_eq!(Timestamp::from_unix_timestamp(951_782_400).unwrap().to_rfc3339(), "2000-02-29T00:00:00+00:00");
+    }
+
+    #[test]
+    fn order() {
+        let earlier = Timestamp::parse("2016-04-30T11:18:25.796+00:00").unwrap();
+        let later = Timestamp::parse("2016-04-30T12:18:25.796+02:00").unwrap();
+
+        assert!(later < earlier);
+        assert!(Timestamp::from_millis(0).unwrap() < Timestamp::now());
+    }
+
+    #[test]
+    fn system_time() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_462_015_105_796);
+        let timestamp = Timestamp::from_system_time(time).unwrap();
+
+        assert_eq!(timestamp.timestamp_millis(), 1_462_015_105_796);
+        assert_eq!(SystemTime::from(timestamp), time);
+
+        let before = UNIX_EPOCH - Duration::from_millis(1_500);
+        let timestamp = Timestamp::from_system_time(before).unwrap();
+
+        assert_eq!(timestamp.timestamp_millis(), -1_500);
+        assert_eq!(SystemTime::from(timestamp), before);
+
+        let between = UNIX_EPOCH - Duration::new(1, 500_000);
+        assert_eq!(Timestamp::from_system_time(between).unwrap().timestamp_millis(), -1_001);
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(Timestamp::from_unix_timestamp(i64::max_value()), Err(InvalidTimestamp::OutOfRange));
+        assert_eq!(Timestamp::from_millis(super::MAX_MILLIS + 1), Err(InvalidTimestamp::OutOfRange));
+        assert_eq!(Timestamp::parse("0000-01-01T00:00:00+01:00"), Err(InvalidTimestamp::OutOfRange));
+        assert_eq!(Timestamp::from_millis(super::MIN_MILLIS).unwrap().to_rfc3339(), "0000-01-01T00:00:00+00:00");
+        assert_eq!(Timestamp::from_millis(super::MAX_MILLIS).unwrap().to_rfc3339(), "9999-12-31T23:59:59.999+00:00");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_conversions() {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        let datetime = Utc.timestamp(1_462_015_105, 796_000_000);
+        let timestamp = Timestamp::from(&datetime);
+
+        assert_eq!(timestamp.timestamp_millis(), 1_462_015_105_796);
+        assert_eq!(DateTime::<Utc>::from(timestamp), datetime);
+    }
+}
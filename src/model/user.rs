@@ -12,9 +12,9 @@ use CACHE;
 #[cfg(feature = "model")]
 use builder::{CreateMessage, EditProfile};
 #[cfg(feature = "model")]
-use chrono::NaiveDateTime;
-#[cfg(feature = "model")]
 use http::{self, GuildPagination};
+#[cfg(feature = "model")]
+use model::misc::cdn_image_url;
 #[cfg(all(feature = "cache", feature = "model"))]
 use parking_lot::RwLock;
 #[cfg(feature = "model")]
@@ -65,6 +65,30 @@ impl CurrentUser {
     #[inline]
     pub fn avatar_url(&self) -> Option<String> { avatar_url(self.id, self.avatar.as_ref()) }
 
+    /// Returns the URL of the user's avatar in the given format and size, if
+    /// one exists.
+    ///
+    /// Without a `format`, animated avatars are retrieved as GIF and others as
+    /// WebP. Refer to [`ImageFormat`] for details on the `size`.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve a small, static version of the avatar:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::prelude::*;
+    /// #
+    /// # fn run(user: &CurrentUser) {
+    /// let url = user.avatar_url_with(Some(ImageFormat::Png), 64);
+    /// # }
+    /// ```
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    #[inline]
+    pub fn avatar_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        avatar_url_with(self.id, self.avatar.as_ref(), format, size)
+    }
+
     /// Returns the formatted URL to the user's default avatar URL.
     ///
     /// This will produce a PNG URL.
@@ -205,9 +229,8 @@ impl CurrentUser {
     ///
     /// # Errors
     ///
-    /// Returns an
-    /// [`HttpError::UnsuccessfulRequest(Unauthorized)`][`HttpError::UnsuccessfulRequest`]
-    /// If the user is not authorized for this end point.
+    /// Returns an [`HttpError::UnsuccessfulRequest`] with an `Unauthorized`
+    /// status code if the user is not authorized for this end point.
     ///
     /// May return [`Error::Format`] while writing url to the buffer.
     ///
@@ -385,6 +408,30 @@ impl User {
     #[inline]
     pub fn avatar_url(&self) -> Option<String> { avatar_url(self.id, self.avatar.as_ref()) }
 
+    /// Returns the URL of the user's avatar in the given format and size, if
+    /// one exists.
+    ///
+    /// Without a `format`, animated avatars are retrieved as GIF and others as
+    /// WebP. Refer to [`ImageFormat`] for details on the `size`.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve a small, static version of the avatar:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::prelude::*;
+    /// #
+    /// # fn run(user: &User) {
+    /// let url = user.avatar_url_with(Some(ImageFormat::Png), 64);
+    /// # }
+    /// ```
+    ///
+    /// [`ImageFormat`]: ../misc/enum.ImageFormat.html
+    #[inline]
+    pub fn avatar_url_with(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        avatar_url_with(self.id, self.avatar.as_ref(), format, size)
+    }
+
     /// Creates a direct message channel between the [current user] and the
     /// user. This can also retrieve the channel if one already exists.
     ///
@@ -394,7 +441,7 @@ impl User {
 
     /// Retrieves the time that this user was created at.
     #[inline]
-    pub fn created_at(&self) -> NaiveDateTime { self.id.created_at() }
+    pub fn created_at(&self) -> Timestamp { self.id.created_at() }
 
     /// Returns the formatted URL to the user's default avatar URL.
     ///
@@ -463,21 +510,6 @@ impl User {
     /// [`ModelError::MessagingBot`]: ../error/enum.Error.html#variant.MessagingBot
     /// [`PrivateChannel`]: struct.PrivateChannel.html
     /// [`User::dm`]: struct.User.html#method.dm
-    // A tale with Clippy:
-    //
-    // A person named Clippy once asked you to unlock a box and take something
-    // from it, but you never re-locked it, so you'll die and the universe will
-    // implode because the box must remain locked unless you're there, and you
-    // can't just borrow that item from it and take it with you forever.
-    //
-    // Instead what you do is unlock the box, take the item out of it, make a
-    // copy of said item, and then re-lock the box, and take your copy of the
-    // item with you.
-    //
-    // The universe is still fine, and nothing implodes.
-    //
-    // (AKA: Clippy is wrong and so we have to mark as allowing this lint.)
-    #[allow(let_and_return)]
     #[cfg(feature = "builder")]
     pub fn direct_message<F>(&self, f: F) -> Result<Message>
         where F: FnOnce(CreateMessage) -> CreateMessage {
@@ -485,39 +517,7 @@ impl User {
             return Err(Error::Model(ModelError::MessagingBot));
         }
 
-        let private_channel_id = feature_cache! {
-            {
-                let finding = {
-                    let cache = CACHE.read();
-
-                    let finding = cache.private_channels
-                        .values()
-                        .map(|ch| ch.read())
-                        .find(|ch| ch.recipient.read().id == self.id)
-                        .map(|ch| ch.id);
-
-                    finding
-                };
-
-                if let Some(finding) = finding {
-                    finding
-                } else {
-                    let map = json!({
-                        "recipient_id": self.id.0,
-                    });
-
-                    http::create_private_channel(&map)?.id
-                }
-            } else {
-                let map = json!({
-                    "recipient_id": self.id.0,
-                });
-
-                http::create_private_channel(&map)?.id
-            }
-        };
-
-        private_channel_id.send_message(f)
+        self.id.create_dm_channel()?.id.send_message(f)
     }
 
     /// This is an alias of [direct_message].
@@ -728,12 +728,34 @@ impl UserId {
     /// user. This can also retrieve the channel if one already exists.
     ///
     /// [current user]: ../user/struct.CurrentUser.html
+    ///
+    /// If the `cache` feature is enabled, the channel is looked up in the
+    /// cache first, and stored in it once created, so that repeated calls do
+    /// not each perform a request.
     pub fn create_dm_channel(&self) -> Result<PrivateChannel> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(channel) = CACHE.read().private_channel_with(*self) {
+                return Ok(channel.read().clone());
+            }
+        }
+
         let map = json!({
             "recipient_id": self.0,
         });
 
-        http::create_private_channel(&map)
+        let channel = http::create_private_channel(&map)?;
+
+        #[cfg(feature = "cache")]
+        {
+            let mut event = ChannelCreateEvent {
+                channel: Channel::Private(Arc::new(RwLock::new(channel.clone()))),
+            };
+
+            CACHE.write().update(&mut event);
+        }
+
+        Ok(channel)
     }
 
     /// Search the cache for the user with the Id.
@@ -845,6 +867,11 @@ fn avatar_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
     })
 }
 
+#[cfg(feature = "model")]
+fn avatar_url_with(user_id: UserId, hash: Option<&String>, format: Option<ImageFormat>, size: u16) -> Option<String> {
+    hash.map(|hash| cdn_image_url(&format!("/avatars/{}", user_id.0), hash, format, size))
+}
+
 #[cfg(feature = "model")]
 fn default_avatar_url(discriminator: u16) -> String {
     cdn!("/embed/avatars/{}.png", discriminator % 5u16)
@@ -14,12 +14,20 @@ pub struct VoiceRegion {
     /// A recognizable name of the location of the voice region.
     pub name: String,
     /// Whether the voice region is optimal for use by the current user.
-    pub optional: bool,
-    /// an example hostname.
+    pub optimal: bool,
+    /// An example hostname.
+    ///
+    /// **Note**: This is no longer sent by Discord and defaults to an empty
+    /// string.
+    #[serde(default)]
     pub sample_hostname: String,
     /// An example port.
+    ///
+    /// **Note**: This is no longer sent by Discord and defaults to `0`.
+    #[serde(default)]
     pub sample_port: u64,
     /// Indicator of whether the voice region is only for VIP guilds.
+    #[serde(default)]
     pub vip: bool,
 }
 
@@ -12,6 +12,8 @@ use super::{
 #[cfg(feature = "model")]
 use builder::ExecuteWebhook;
 #[cfg(feature = "model")]
+use http::AttachmentType;
+#[cfg(feature = "model")]
 use internal::prelude::*;
 #[cfg(feature = "model")]
 use std::mem;
@@ -199,6 +201,42 @@ impl Webhook {
         http::execute_webhook(self.id.0, &self.token, wait, &map)
     }
 
+    /// Executes a webhook with file(s) attached.
+    ///
+    /// Refer to [`execute`] for more information, and to
+    /// [`ExecuteWebhook::attachment_description`] for how to describe the
+    /// files.
+    ///
+    /// # Examples
+    ///
+    /// Send an image with alt text:
+    ///
+    /// ```rust,no_run
+    /// use serenity::http;
+    ///
+    /// let id = 245037420704169985;
+    /// let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+    ///
+    /// let webhook = http::get_webhook_with_token(id, token)
+    ///     .expect("valid webhook");
+    ///
+    /// let _ = webhook.execute_with_files(false, vec!["./ferris.png"], |w| w
+    ///     .attachment_description(0, "A crab waving its claws"))
+    ///     .expect("Error executing");
+    /// ```
+    ///
+    /// [`ExecuteWebhook::attachment_description`]: ../../builder/struct.ExecuteWebhook.html#method.attachment_description
+    /// [`execute`]: #method.execute
+    pub fn execute_with_files<'a, F, T, It>(&self, wait: bool, files: It, f: F)
+        -> Result<Option<Message>>
+        where F: FnOnce(ExecuteWebhook) -> ExecuteWebhook,
+              T: Into<AttachmentType<'a>>,
+              It: IntoIterator<Item = T> {
+        let map = utils::vecmap_to_json_map(f(ExecuteWebhook::default()).0);
+
+        http::execute_webhook_with_files(self.id.0, &self.token, wait, files, map)
+    }
+
     /// Retrieves the latest information about the webhook, editing the
     /// webhook in-place.
     ///
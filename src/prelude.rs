@@ -19,7 +19,7 @@ pub use model::misc::Mentionable;
 pub use parking_lot::{Mutex, RwLock};
 
 #[cfg(feature = "client")]
-pub use client::{Client, ClientError as ClientError, Context, EventHandler};
+pub use client::{Client, ClientError as ClientError, Context, EventHandler, TypeMapKey};
 #[cfg(feature = "gateway")]
 pub use gateway::GatewayError;
 #[cfg(feature = "http")]
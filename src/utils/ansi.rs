@@ -0,0 +1,219 @@
+use std::fmt::Write;
+
+const ESCAPE: char = '\u{1b}';
+
+/// The colours of text that Discord renders in `ansi` code blocks.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AnsiColour {
+    Gray,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Pink,
+    Cyan,
+    White,
+}
+
+impl AnsiColour {
+    fn code(&self) -> u8 {
+        match *self {
+            AnsiColour::Gray => 30,
+            AnsiColour::Red => 31,
+            AnsiColour::Green => 32,
+            AnsiColour::Yellow => 33,
+            AnsiColour::Blue => 34,
+            AnsiColour::Pink => 35,
+            AnsiColour::Cyan => 36,
+            AnsiColour::White => 37,
+        }
+    }
+}
+
+/// The background colours that Discord renders in `ansi` code blocks.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AnsiBackground {
+    FireflyDarkBlue,
+    Orange,
+    MarbleBlue,
+    GreyishTurquoise,
+    Gray,
+    Indigo,
+    LightGray,
+    White,
+}
+
+impl AnsiBackground {
+    fn code(&self) -> u8 {
+        match *self {
+            AnsiBackground::FireflyDarkBlue => 40,
+            AnsiBackground::Orange => 41,
+            AnsiBackground::MarbleBlue => 42,
+            AnsiBackground::GreyishTurquoise => 43,
+            AnsiBackground::Gray => 44,
+            AnsiBackground::Indigo => 45,
+            AnsiBackground::LightGray => 46,
+            AnsiBackground::White => 47,
+        }
+    }
+}
+
+/// The style of a piece of text within an `ansi` code block.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::{AnsiColour, AnsiStyle};
+///
+/// let style = AnsiStyle::default().bold().colour(AnsiColour::Yellow);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AnsiStyle {
+    pub background: Option<AnsiBackground>,
+    pub bold: bool,
+    pub colour: Option<AnsiColour>,
+    pub underline: bool,
+}
+
+impl AnsiStyle {
+    /// Sets the background colour of the text.
+    pub fn background(mut self, background: AnsiBackground) -> Self {
+        self.background = Some(background);
+
+        self
+    }
+
+    /// Makes the text bold.
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+
+        self
+    }
+
+    /// Sets the colour of the text.
+    pub fn colour(mut self, colour: AnsiColour) -> Self {
+        self.colour = Some(colour);
+
+        self
+    }
+
+    /// Underlines the text.
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+
+        self
+    }
+
+    /// Writes the escape sequence selecting the style.
+    fn write_to(&self, s: &mut String) {
+        let mut codes = vec![0];
+
+        if self.bold {
+            codes.push(1);
+        }
+
+        if self.underline {
+            codes.push(4);
+        }
+
+        if let Some(background) = self.background {
+            codes.push(background.code());
+        }
+
+        if let Some(colour) = self.colour {
+            codes.push(colour.code());
+        }
+
+        s.push(ESCAPE);
+        s.push('[');
+
+        for (i, code) in codes.iter().enumerate() {
+            if i > 0 {
+                s.push(';');
+            }
+
+            let _ = write!(s, "{}", code);
+        }
+
+        s.push('m');
+    }
+}
+
+/// A builder for the content of an `ansi` code block, to be pushed through
+/// [`MessageBuilder::push_ansi_codeblock`].
+///
+/// Text pushed is escaped, so that it can neither contain escape sequences
+/// of its own nor end the code block. As text pushed in parts, or set through
+/// the field, may still form a fence, fences are also neutralised once the
+/// whole content is pushed through [`MessageBuilder::push_ansi_codeblock`].
+///
+/// # Examples
+///
+/// Build a leaderboard with the first place highlighted:
+///
+/// ```rust
+/// use serenity::utils::{AnsiBuilder, AnsiColour, AnsiStyle, MessageBuilder};
+///
+/// let leaderboard = AnsiBuilder::new()
+///     .push_styled_line("1. kyoto", AnsiStyle::default().bold().colour(AnsiColour::Yellow))
+///     .push_line("2. fumi")
+///     .push("3. san");
+///
+/// let content = MessageBuilder::new()
+///     .push_ansi_codeblock(leaderboard)
+///     .build();
+///
+/// assert_eq!(content, "```ansi\n\u{1b}[0;1;33m1. kyoto\u{1b}[0m\n2. fumi\n3. san\n```");
+/// ```
+///
+/// [`MessageBuilder::push_ansi_codeblock`]: struct.MessageBuilder.html#method.push_ansi_codeblock
+#[derive(Clone, Debug, Default)]
+pub struct AnsiBuilder(pub String);
+
+impl AnsiBuilder {
+    /// Creates a new, empty builder.
+    pub fn new() -> Self { AnsiBuilder::default() }
+
+    /// Pushes unstyled text.
+    pub fn push(mut self, content: &str) -> Self {
+        push_escaped(&mut self.0, content);
+
+        self
+    }
+
+    /// Pushes unstyled text, followed by a newline.
+    pub fn push_line(mut self, content: &str) -> Self {
+        push_escaped(&mut self.0, content);
+        self.0.push('\n');
+
+        self
+    }
+
+    /// Pushes text in the given style.
+    pub fn push_styled(mut self, content: &str, style: AnsiStyle) -> Self {
+        style.write_to(&mut self.0);
+        push_escaped(&mut self.0, content);
+        AnsiStyle::default().write_to(&mut self.0);
+
+        self
+    }
+
+    /// Pushes text in the given style, followed by a newline.
+    pub fn push_styled_line(self, content: &str, style: AnsiStyle) -> Self {
+        let mut builder = self.push_styled(content, style);
+        builder.0.push('\n');
+
+        builder
+    }
+
+    /// Returns the built content of the code block.
+    pub fn build(self) -> String { self.0 }
+}
+
+fn push_escaped(s: &mut String, content: &str) {
+    s.push_str(&neutralise_fences(&content.replace(ESCAPE, "")));
+}
+
+/// Replaces code block fences in the content, so that it cannot end the code
+/// block it is in.
+pub(crate) fn neutralise_fences(content: &str) -> String { content.replace("```", " ") }
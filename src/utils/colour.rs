@@ -1,6 +1,9 @@
 // Disable this lint to avoid it wanting to change `0xABCDEF` to `0xAB_CDEF`.
 #![allow(unreadable_literal)]
 
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
 macro_rules! colour {
     ($(#[$attr:meta] $constname:ident, $name:ident, $val:expr;)*) => {
         impl Colour {
@@ -16,6 +19,32 @@ macro_rules! colour {
                     Colour::new($val)
                 }
             )*
+
+            /// Looks up one of the preset colours by its name, ignoring case
+            /// and treating spaces and dashes like underscores.
+            ///
+            /// Returns `None` if no preset is named alike.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// use serenity::utils::Colour;
+            ///
+            /// assert_eq!(Colour::from_name("dark green"), Some(Colour::DARK_GREEN));
+            /// assert_eq!(Colour::from_name("Blurple"), Some(Colour::BLURPLE));
+            /// assert_eq!(Colour::from_name("octarine"), None);
+            /// ```
+            pub fn from_name(name: &str) -> Option<Colour> {
+                let name = name.trim().to_lowercase().replace(|c: char| c == ' ' || c == '-', "_");
+
+                $(
+                    if name == stringify!($name) {
+                        return Some(Colour::$constname);
+                    }
+                )*
+
+                None
+            }
         }
     }
 }
@@ -206,6 +235,74 @@ impl Colour {
     pub fn hex(&self) -> String {
         format!("{:06X}", self.0)
     }
+
+    /// Parses a Colour from a hexadecimal string, as produced by [`hex`].
+    ///
+    /// The string may be prefixed with either `#` or `0x`, and the short form
+    /// of three hexadecimal digits is accepted as well.
+    ///
+    /// Returns `None` if the string is not a valid hexadecimal colour.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_hex_str("#1ABC9C"), Some(Colour::TEAL));
+    /// assert_eq!(Colour::from_hex_str("0x1abc9c"), Some(Colour::TEAL));
+    /// assert_eq!(Colour::from_hex_str("#FFF"), Some(Colour::new(0xFFFFFF)));
+    /// assert_eq!(Colour::from_hex_str("#1ABC9"), None);
+    /// ```
+    ///
+    /// [`hex`]: #method.hex
+    pub fn from_hex_str(hex: &str) -> Option<Colour> {
+        let hex = hex.trim();
+        let hex = if hex.starts_with('#') {
+            &hex[1..]
+        } else if hex.starts_with("0x") || hex.starts_with("0X") {
+            &hex[2..]
+        } else {
+            hex
+        };
+
+        if !hex.chars().all(|c| c.is_digit(16)) {
+            return None;
+        }
+
+        match hex.len() {
+            6 => u32::from_str_radix(hex, 16).ok().map(Colour),
+            3 => {
+                let expanded = hex.chars().flat_map(|c| vec![c, c]).collect::<String>();
+
+                u32::from_str_radix(&expanded, 16).ok().map(Colour)
+            },
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Colour {
+    type Err = ();
+
+    /// Parses a Colour from either a hexadecimal string or the name of one of
+    /// the presets, refer to [`from_hex_str`] and [`from_name`] respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!("#E91E63".parse::<Colour>(), Ok(Colour::MAGENTA));
+    /// assert_eq!("magenta".parse::<Colour>(), Ok(Colour::MAGENTA));
+    /// ```
+    ///
+    /// [`from_hex_str`]: #method.from_hex_str
+    /// [`from_name`]: #method.from_name
+    fn from_str(s: &str) -> StdResult<Self, ()> {
+        Colour::from_hex_str(s)
+            .or_else(|| Colour::from_name(s))
+            .ok_or(())
+    }
 }
 
 impl From<i32> for Colour {
@@ -376,4 +473,15 @@ mod test {
         assert_eq!(Colour::from(7u32).0, 7);
         assert_eq!(Colour::from(7u64).0, 7);
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("#336123".parse::<Colour>(), Ok(Colour::new(0x336123)));
+        assert_eq!("336123".parse::<Colour>(), Ok(Colour::new(0x336123)));
+        assert_eq!("#abc".parse::<Colour>(), Ok(Colour::new(0xAABBCC)));
+        assert_eq!("Dark-Teal".parse::<Colour>(), Ok(Colour::DARK_TEAL));
+        assert_eq!("#+12345".parse::<Colour>(), Err(()));
+        assert_eq!("#1234567".parse::<Colour>(), Err(()));
+        assert_eq!("".parse::<Colour>(), Err(()));
+    }
 }
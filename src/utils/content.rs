@@ -0,0 +1,352 @@
+use model::{
+    id::{ChannelId, EmojiId, RoleId, UserId},
+    misc::Mention
+};
+use std::str::SplitWhitespace;
+
+/// The characters trimmed from the end of URLs, as they more likely belong to
+/// the surrounding sentence.
+const URL_TRAILING_PUNCTUATION: &[char] = &['.', ',', ':', ';', '!', '?', ')', '"', '\''];
+
+/// A custom emoji used in a message's content, as found by [`parse_emojis`].
+///
+/// [`parse_emojis`]: fn.parse_emojis.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ContentEmoji<'a> {
+    /// Whether the emoji is animated.
+    pub animated: bool,
+    /// The Id of the emoji.
+    pub id: EmojiId,
+    /// The name of the emoji.
+    pub name: &'a str,
+}
+
+/// A fenced code block in a message's content, as found by
+/// [`parse_code_blocks`].
+///
+/// [`parse_code_blocks`]: fn.parse_code_blocks.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CodeBlock<'a> {
+    /// The language given after the opening fence, if any.
+    pub language: Option<&'a str>,
+    /// The code within the block.
+    pub code: &'a str,
+}
+
+/// An iterator over the user, role, and channel mentions in a message's
+/// content, created by [`parse_mentions`].
+///
+/// [`parse_mentions`]: fn.parse_mentions.html
+#[derive(Clone, Debug)]
+pub struct Mentions<'a> {
+    content: &'a str,
+}
+
+impl<'a> Iterator for Mentions<'a> {
+    type Item = Mention;
+
+    fn next(&mut self) -> Option<Mention> { next_tag(&mut self.content, parse_mention) }
+}
+
+/// An iterator over the custom emojis in a message's content, created by
+/// [`parse_emojis`].
+///
+/// [`parse_emojis`]: fn.parse_emojis.html
+#[derive(Clone, Debug)]
+pub struct Emojis<'a> {
+    content: &'a str,
+}
+
+impl<'a> Iterator for Emojis<'a> {
+    type Item = ContentEmoji<'a>;
+
+    fn next(&mut self) -> Option<ContentEmoji<'a>> { next_tag(&mut self.content, parse_content_emoji) }
+}
+
+/// An iterator over the URLs in a message's content, created by
+/// [`parse_urls`].
+///
+/// [`parse_urls`]: fn.parse_urls.html
+#[derive(Clone, Debug)]
+pub struct Urls<'a> {
+    words: SplitWhitespace<'a>,
+}
+
+impl<'a> Iterator for Urls<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        for word in &mut self.words {
+            let start = match word.find("https://").or_else(|| word.find("http://")) {
+                Some(start) => start,
+                None => continue,
+            };
+
+            let url = &word[start..];
+            let url = url.find(|c: char| c == '<' || c == '>').map_or(url, |end| &url[..end]);
+            let url = url.trim_right_matches(URL_TRAILING_PUNCTUATION);
+
+            // Ignore schemes that are not followed by a host.
+            if url.ends_with("://") {
+                continue;
+            }
+
+            return Some(url);
+        }
+
+        None
+    }
+}
+
+/// An iterator over the fenced code blocks in a message's content, created by
+/// [`parse_code_blocks`].
+///
+/// [`parse_code_blocks`]: fn.parse_code_blocks.html
+#[derive(Clone, Debug)]
+pub struct CodeBlocks<'a> {
+    content: &'a str,
+}
+
+impl<'a> Iterator for CodeBlocks<'a> {
+    type Item = CodeBlock<'a>;
+
+    fn next(&mut self) -> Option<CodeBlock<'a>> {
+        let content = self.content;
+
+        let start = content.find("```")? + 3;
+        let len = match content[start..].find("```") {
+            Some(len) => len,
+            None => {
+                self.content = "";
+
+                return None;
+            },
+        };
+
+        self.content = &content[start + len + 3..];
+
+        let inner = &content[start..start + len];
+
+        // The first line names the language if it is a single word that is
+        // followed by more lines.
+        let (language, code) = match inner.find('\n') {
+            Some(newline) if !inner[..newline].contains(char::is_whitespace) => {
+                let language = &inner[..newline];
+
+                (Some(language).filter(|language| !language.is_empty()), &inner[newline + 1..])
+            },
+            _ => (None, inner),
+        };
+
+        Some(CodeBlock {
+            language,
+            code: code.trim_right_matches('\n'),
+        })
+    }
+}
+
+/// Iterates over the user, role, and channel mentions in message content, in
+/// the order they appear in.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::id::{ChannelId, RoleId, UserId};
+/// use serenity::model::misc::Mention;
+/// use serenity::utils::parse_mentions;
+///
+/// let mentions = parse_mentions("<@1>, <@&2> and <#3>").collect::<Vec<_>>();
+///
+/// assert_eq!(mentions, vec![
+///     Mention::User(UserId(1)),
+///     Mention::Role(RoleId(2)),
+///     Mention::Channel(ChannelId(3)),
+/// ]);
+/// ```
+pub fn parse_mentions(content: &str) -> Mentions {
+    Mentions {
+        content,
+    }
+}
+
+/// Iterates over the custom emojis in message content, in the order they
+/// appear in.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::id::EmojiId;
+/// use serenity::utils::parse_emojis;
+///
+/// let emoji = parse_emojis("hi <a:wave:4>").next().unwrap();
+///
+/// assert!(emoji.animated);
+/// assert_eq!(emoji.id, EmojiId(4));
+/// assert_eq!(emoji.name, "wave");
+/// ```
+pub fn parse_emojis(content: &str) -> Emojis {
+    Emojis {
+        content,
+    }
+}
+
+/// Iterates over the `http` and `https` URLs in message content, including
+/// those wrapped in `<>` to suppress their embeds.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::parse_urls;
+///
+/// let content = "See https://discordapp.com/developers, or <https://docs.rs>.";
+/// let urls = parse_urls(content).collect::<Vec<_>>();
+///
+/// assert_eq!(urls, vec!["https://discordapp.com/developers", "https://docs.rs"]);
+/// ```
+pub fn parse_urls(content: &str) -> Urls {
+    Urls {
+        words: content.split_whitespace(),
+    }
+}
+
+/// Iterates over the code blocks fenced by three backticks in message
+/// content.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::parse_code_blocks;
+///
+/// let content = "```rust\nfn main() {}\n```";
+/// let block = parse_code_blocks(content).next().unwrap();
+///
+/// assert_eq!(block.language, Some("rust"));
+/// assert_eq!(block.code, "fn main() {}");
+/// ```
+pub fn parse_code_blocks(content: &str) -> CodeBlocks {
+    CodeBlocks {
+        content,
+    }
+}
+
+/// Finds the next tag enclosed in `<>` that `parse` accepts, advancing the
+/// content past it.
+fn next_tag<'a, T, F>(content: &mut &'a str, parse: F) -> Option<T>
+    where F: Fn(&'a str) -> Option<T> {
+    let mut rest: &'a str = *content;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        let end = match rest.find('>') {
+            Some(end) => end,
+            None => break,
+        };
+
+        if let Some(item) = parse(&rest[..end]) {
+            *content = &rest[end + 1..];
+
+            return Some(item);
+        }
+    }
+
+    *content = "";
+
+    None
+}
+
+fn parse_snowflake(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+fn parse_mention(tag: &str) -> Option<Mention> {
+    if tag.starts_with("@!") {
+        parse_snowflake(&tag[2..]).map(|id| Mention::User(UserId(id)))
+    } else if tag.starts_with("@&") {
+        parse_snowflake(&tag[2..]).map(|id| Mention::Role(RoleId(id)))
+    } else if tag.starts_with('@') {
+        parse_snowflake(&tag[1..]).map(|id| Mention::User(UserId(id)))
+    } else if tag.starts_with('#') {
+        parse_snowflake(&tag[1..]).map(|id| Mention::Channel(ChannelId(id)))
+    } else {
+        None
+    }
+}
+
+fn parse_content_emoji(tag: &str) -> Option<ContentEmoji> {
+    let (animated, rest) = if tag.starts_with("a:") {
+        (true, &tag[2..])
+    } else if tag.starts_with(':') {
+        (false, &tag[1..])
+    } else {
+        return None;
+    };
+
+    let mut parts = rest.splitn(2, ':');
+    let name = parts.next().filter(|name| !name.is_empty())?;
+    let id = parts.next().and_then(parse_snowflake)?;
+
+    Some(ContentEmoji {
+        animated,
+        id: EmojiId(id),
+        name,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use model::{
+        id::{ChannelId, EmojiId, RoleId, UserId},
+        misc::Mention
+    };
+    use super::*;
+
+    #[test]
+    fn mentions() {
+        let content = "<@1> <@!2> <@&3> <#4> <<@5> <@x> <:emoji:6> <@7";
+        let mentions = parse_mentions(content).collect::<Vec<_>>();
+
+        assert_eq!(mentions, vec![
+            Mention::User(UserId(1)),
+            Mention::User(UserId(2)),
+            Mention::Role(RoleId(3)),
+            Mention::Channel(ChannelId(4)),
+            Mention::User(UserId(5)),
+        ]);
+    }
+
+    #[test]
+    fn emojis() {
+        let content = "<:a:1> <a:b:2> <:c:> <::3> <@4>";
+        let emojis = parse_emojis(content).collect::<Vec<_>>();
+
+        assert_eq!(emojis, vec![
+            ContentEmoji { animated: false, id: EmojiId(1), name: "a" },
+            ContentEmoji { animated: true, id: EmojiId(2), name: "b" },
+        ]);
+    }
+
+    #[test]
+    fn urls() {
+        let content = "(http://a.com/b) https:// <https://c.com>, x";
+        let urls = parse_urls(content).collect::<Vec<_>>();
+
+        assert_eq!(urls, vec!["http://a.com/b", "https://c.com"]);
+    }
+
+    #[test]
+    fn code_blocks() {
+        let content = "```rust\nlet a = 1;\n``` text ```inline``` ```\nplain\n``` ```open";
+        let blocks = parse_code_blocks(content).collect::<Vec<_>>();
+
+        assert_eq!(blocks, vec![
+            CodeBlock { language: Some("rust"), code: "let a = 1;" },
+            CodeBlock { language: None, code: "inline" },
+            CodeBlock { language: None, code: "plain" },
+        ]);
+    }
+}
@@ -0,0 +1,429 @@
+use model::id::{ChannelId, RoleId, UserId};
+use std::fmt::Write;
+use super::{parse_channel, parse_role, parse_username};
+use CACHE;
+
+/// Options selecting what [`content_safe`] neutralises, and what mentions are
+/// replaced with.
+///
+/// [`content_safe`]: fn.content_safe.html
+#[derive(Clone, Debug)]
+pub struct ContentSafeOptions {
+    clean_role: bool,
+    clean_user: bool,
+    clean_channel: bool,
+    clean_here: bool,
+    clean_everyone: bool,
+    clean_invites: bool,
+    clean_markdown: bool,
+    show_discriminator: bool,
+    unknown_user: String,
+    unknown_role: String,
+    unknown_channel: String,
+}
+
+impl ContentSafeOptions {
+    /// Creates the default options, refer to the [`Default`] implementation.
+    ///
+    /// [`Default`]: #impl-Default
+    pub fn new() -> Self {
+        ContentSafeOptions::default()
+    }
+
+    /// Whether role mentions are replaced with the role's name.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn clean_role(mut self, clean_role: bool) -> Self {
+        self.clean_role = clean_role;
+
+        self
+    }
+
+    /// Whether user mentions are replaced with the user's name.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn clean_user(mut self, clean_user: bool) -> Self {
+        self.clean_user = clean_user;
+
+        self
+    }
+
+    /// Whether channel mentions are replaced with the channel's name.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn clean_channel(mut self, clean_channel: bool) -> Self {
+        self.clean_channel = clean_channel;
+
+        self
+    }
+
+    /// Whether `@here` mentions are cancelled.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn clean_here(mut self, clean_here: bool) -> Self {
+        self.clean_here = clean_here;
+
+        self
+    }
+
+    /// Whether `@everyone` mentions are cancelled.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn clean_everyone(mut self, clean_everyone: bool) -> Self {
+        self.clean_everyone = clean_everyone;
+
+        self
+    }
+
+    /// Whether invite links are broken up, so that they are neither clickable
+    /// nor embedded.
+    ///
+    /// **Note**: Defaults to `false`.
+    pub fn clean_invites(mut self, clean_invites: bool) -> Self {
+        self.clean_invites = clean_invites;
+
+        self
+    }
+
+    /// Whether markdown is escaped, so that the content is displayed as is.
+    ///
+    /// **Note**: Defaults to `false`.
+    pub fn clean_markdown(mut self, clean_markdown: bool) -> Self {
+        self.clean_markdown = clean_markdown;
+
+        self
+    }
+
+    /// Whether a user's discriminator is displayed after their name, as in
+    /// `@ferris#0001`.
+    ///
+    /// **Note**: Defaults to `true`.
+    pub fn show_discriminator(mut self, show_discriminator: bool) -> Self {
+        self.show_discriminator = show_discriminator;
+
+        self
+    }
+
+    /// Sets what mentions of users that are not cached are replaced with.
+    ///
+    /// **Note**: Defaults to `@invalid-user`.
+    pub fn unknown_user(mut self, replacement: &str) -> Self {
+        self.unknown_user = replacement.to_string();
+
+        self
+    }
+
+    /// Sets what mentions of roles that are not cached are replaced with.
+    ///
+    /// **Note**: Defaults to `@deleted-role`.
+    pub fn unknown_role(mut self, replacement: &str) -> Self {
+        self.unknown_role = replacement.to_string();
+
+        self
+    }
+
+    /// Sets what mentions of channels that are not cached are replaced with.
+    ///
+    /// **Note**: Defaults to `#deleted-channel`.
+    pub fn unknown_channel(mut self, replacement: &str) -> Self {
+        self.unknown_channel = replacement.to_string();
+
+        self
+    }
+}
+
+impl Default for ContentSafeOptions {
+    /// Creates options cleaning all mentions and showing discriminators, while
+    /// keeping invites and markdown.
+    fn default() -> Self {
+        ContentSafeOptions {
+            clean_role: true,
+            clean_user: true,
+            clean_channel: true,
+            clean_here: true,
+            clean_everyone: true,
+            clean_invites: false,
+            clean_markdown: false,
+            show_discriminator: true,
+            unknown_user: "@invalid-user".to_string(),
+            unknown_role: "@deleted-role".to_string(),
+            unknown_channel: "#deleted-channel".to_string(),
+        }
+    }
+}
+
+/// What [`content_safe`] replaced in the content.
+///
+/// [`content_safe`]: fn.content_safe.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContentSafeReport {
+    /// The users whose mentions were replaced, in order of appearance.
+    pub users: Vec<UserId>,
+    /// The roles whose mentions were replaced, in order of appearance.
+    pub roles: Vec<RoleId>,
+    /// The channels whose mentions were replaced, in order of appearance.
+    pub channels: Vec<ChannelId>,
+    /// Whether an `@everyone` mention was cancelled.
+    pub everyone: bool,
+    /// Whether an `@here` mention was cancelled.
+    pub here: bool,
+    /// The number of invite links that were broken up.
+    pub invites: usize,
+}
+
+impl ContentSafeReport {
+    /// Whether nothing was replaced, not counting escaped markdown.
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+            && self.roles.is_empty()
+            && self.channels.is_empty()
+            && !self.everyone
+            && !self.here
+            && self.invites == 0
+    }
+}
+
+/// Neutralises the mentions in the given content according to the `options`,
+/// replacing user, role, and channel mentions with the names found in the
+/// cache and cancelling `@everyone` and `@here`.
+///
+/// Optionally, invite links are broken up and markdown is escaped as well.
+///
+/// Returns the neutralised content along with a [`ContentSafeReport`] of what
+/// was replaced, e.g. to log attempted mass mentions.
+///
+/// # Examples
+///
+/// Mentions that cannot be found in the cache are replaced, too:
+///
+/// ```rust
+/// use serenity::model::id::UserId;
+/// use serenity::utils::{content_safe, ContentSafeOptions};
+///
+/// let options = ContentSafeOptions::default().unknown_user("@someone");
+/// let (content, report) = content_safe("Hey <@!1> and @everyone!", &options);
+///
+/// assert_eq!(content, "Hey @someone and @\u{200B}everyone!");
+/// assert_eq!(report.users, [UserId(1)]);
+/// assert!(report.everyone);
+/// ```
+///
+/// [`ContentSafeReport`]: struct.ContentSafeReport.html
+pub fn content_safe(content: &str, options: &ContentSafeOptions) -> (String, ContentSafeReport) {
+    let mut report = ContentSafeReport::default();
+    let mut content = clean_mentions(content, options, &mut report);
+
+    if options.clean_everyone && content.contains("@everyone") {
+        content = content.replace("@everyone", "@\u{200B}everyone");
+        report.everyone = true;
+    }
+
+    if options.clean_here && content.contains("@here") {
+        content = content.replace("@here", "@\u{200B}here");
+        report.here = true;
+    }
+
+    if options.clean_invites {
+        for &(invite, broken) in &INVITES {
+            report.invites += content.matches(invite).count();
+            content = content.replace(invite, broken);
+        }
+    }
+
+    if options.clean_markdown {
+        let mut escaped = String::with_capacity(content.len());
+
+        for c in content.chars() {
+            if let '\\' | '*' | '_' | '`' | '~' | '|' | '>' = c {
+                escaped.push('\\');
+            }
+
+            escaped.push(c);
+        }
+
+        content = escaped;
+    }
+
+    (content, report)
+}
+
+/// The invite links broken up, along with what they are replaced with.
+const INVITES: [(&str, &str); 3] = [
+    ("discord.gg/", "discord\u{2024}gg/"),
+    ("discordapp.com/invite", "discordapp\u{2024}com/invite"),
+    ("discord.com/invite", "discord\u{2024}com/invite"),
+];
+
+fn clean_mentions(
+    content: &str,
+    options: &ContentSafeOptions,
+    report: &mut ContentSafeReport,
+) -> String {
+    let cache = CACHE.read();
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find('<') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let mention = rest.find('>').map(|end| &rest[..=end]);
+
+        let replacement = mention.and_then(|mention| {
+            if let Some(id) = parse_role(mention) {
+                if !options.clean_role {
+                    return None;
+                }
+
+                let id = RoleId(id);
+                report.roles.push(id);
+
+                let name = cache.guilds
+                    .values()
+                    .filter_map(|guild| guild.read().roles.get(&id).map(|role| role.name.clone()))
+                    .next();
+
+                Some(name.map_or_else(|| options.unknown_role.clone(), |name| format!("@{}", name)))
+            } else if let Some(id) = parse_username(mention) {
+                if !options.clean_user {
+                    return None;
+                }
+
+                report.users.push(UserId(id));
+
+                Some(match cache.user(UserId(id)) {
+                    Some(user) => {
+                        let user = user.read();
+                        let mut name = format!("@{}", user.name);
+
+                        if options.show_discriminator {
+                            let _ = write!(name, "#{:04}", user.discriminator);
+                        }
+
+                        name
+                    },
+                    None => options.unknown_user.clone(),
+                })
+            } else if let Some(id) = parse_channel(mention) {
+                if !options.clean_channel {
+                    return None;
+                }
+
+                report.channels.push(ChannelId(id));
+
+                Some(match cache.guild_channel(ChannelId(id)) {
+                    Some(channel) => format!("#{}", channel.read().name),
+                    None => options.unknown_channel.clone(),
+                })
+            } else {
+                None
+            }
+        });
+
+        match (mention, replacement) {
+            (Some(mention), Some(replacement)) => {
+                result.push_str(&replacement);
+                rest = &rest[mention.len()..];
+            },
+            _ => {
+                result.push('<');
+                rest = &rest[1..];
+            },
+        }
+    }
+
+    result.push_str(rest);
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use model::id::{ChannelId, RoleId, UserId};
+    use super::*;
+
+    fn clean(content: &str, options: &ContentSafeOptions) -> String {
+        content_safe(content, options).0
+    }
+
+    #[test]
+    fn unknown_mentions() {
+        let options = ContentSafeOptions::default();
+
+        assert_eq!(
+            clean("<@1> <@!2> <@&3> <#4>", &options),
+            "@invalid-user @invalid-user @deleted-role #deleted-channel",
+        );
+        assert_eq!(clean("<<@1>> <@a> <#", &options), "<@invalid-user> <@a> <#");
+    }
+
+    #[test]
+    fn disabled_cleaning() {
+        let options = ContentSafeOptions::default()
+            .clean_user(false)
+            .clean_everyone(false)
+            .unknown_role("@role");
+
+        assert_eq!(clean("<@1> <@&2> @everyone @here", &options), "<@1> @role @everyone @\u{200B}here");
+    }
+
+    #[test]
+    fn invites_and_markdown() {
+        let options = ContentSafeOptions::default()
+            .clean_invites(true)
+            .clean_markdown(true);
+
+        assert_eq!(clean("discord.gg/abc", &options), "discord\u{2024}gg/abc");
+        assert_eq!(clean("**bold** `code`", &options), "\\*\\*bold\\*\\* \\`code\\`");
+    }
+
+    #[test]
+    fn report_mentions() {
+        let options = ContentSafeOptions::default();
+        let (_, report) = content_safe("<@1> <#4> <@&3> <@!2> <@a> @everyone", &options);
+
+        assert_eq!(report.users, [UserId(1), UserId(2)]);
+        assert_eq!(report.roles, [RoleId(3)]);
+        assert_eq!(report.channels, [ChannelId(4)]);
+        assert!(report.everyone);
+        assert!(!report.here);
+        assert_eq!(report.invites, 0);
+        assert!(!report.is_empty());
+    }
+
+    #[test]
+    fn report_disabled_cleaning() {
+        let options = ContentSafeOptions::default()
+            .clean_user(false)
+            .clean_here(false);
+        let (_, report) = content_safe("<@1> @here @everyone discord.gg/abc", &options);
+
+        // Neither kept mentions nor kept invites are reported.
+        assert!(report.users.is_empty());
+        assert!(!report.here);
+        assert!(report.everyone);
+        assert_eq!(report.invites, 0);
+    }
+
+    #[test]
+    fn report_invites() {
+        let options = ContentSafeOptions::default().clean_invites(true);
+        let (_, report) = content_safe(
+            "discord.gg/a discord.com/invite/b discordapp.com/invite/c discord.gg/d",
+            &options,
+        );
+
+        assert_eq!(report.invites, 4);
+        assert!(!report.everyone);
+    }
+
+    #[test]
+    fn report_nothing() {
+        let options = ContentSafeOptions::default().clean_markdown(true);
+        let (content, report) = content_safe("just **text** <here>", &options);
+
+        assert_eq!(content, "just \\*\\*text\\*\\* <here\\>");
+        assert!(report.is_empty());
+        assert_eq!(report, ContentSafeReport::default());
+    }
+}
@@ -1,13 +1,15 @@
 use model::{
     guild::Emoji,
     id::{ChannelId, RoleId, UserId},
-    misc::Mentionable
+    misc::Mentionable,
+    Timestamp
 };
 use std::{
     default::Default,
     fmt::{self, Display, Write},
     ops::Add
 };
+use super::{ansi, AnsiBuilder};
 
 /// The Message Builder is an ergonomic utility to easily build a message,
 /// by adding text and mentioning mentionable structs.
@@ -267,6 +269,21 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes an `ansi` code block, in which Discord renders the styles of
+    /// the text built through an [`AnsiBuilder`].
+    ///
+    /// Code block fences in the content are neutralised, so that it cannot end
+    /// the code block.
+    ///
+    /// [`AnsiBuilder`]: struct.AnsiBuilder.html
+    pub fn push_ansi_codeblock(mut self, content: AnsiBuilder) -> Self {
+        self.0.push_str("```ansi\n");
+        self.0.push_str(&ansi::neutralise_fences(&content.0));
+        self.0.push_str("\n```");
+
+        self
+    }
+
     /// Pushes inlined monospaced text to the content.
     ///
     /// # Examples
@@ -357,6 +374,47 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes the given text as a link to the `url` to the content, which is
+    /// displayed as the text instead of the URL.
+    ///
+    /// **Note**: Masked links are only rendered in embeds and messages sent
+    /// by webhooks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_named_link("the docs", "https://docs.rs/serenity")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "[the docs](https://docs.rs/serenity)");
+    /// ```
+    pub fn push_named_link<D: I, U: I>(mut self, name: D, url: U) -> Self {
+        let _ = write!(self.0, "[{}]({})", name.into().to_string(), url.into().to_string());
+
+        self
+    }
+
+    /// Pushes the given text as a block quote to the content, quoting every
+    /// line of it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_quote("hello\nworld").build();
+    ///
+    /// assert_eq!(content, "> hello\n> world");
+    /// ```
+    pub fn push_quote<D: I>(mut self, content: D) -> Self {
+        self.0.push_str(&quote(&content.into().to_string()));
+
+        self
+    }
+
     /// Pushes the given text with a newline appended to the content.
     ///
     /// # Examples
@@ -477,6 +535,27 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes the given text as a block quote with an added newline to the
+    /// content.
+    ///
+    /// # Examples
+    ///
+    /// Push content and then append a newline:
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_quote_line("hello").push("world").build();
+    ///
+    /// assert_eq!(content, "> hello\nworld");
+    /// ```
+    pub fn push_quote_line<D: I>(mut self, content: D) -> Self {
+        self = self.push_quote(content);
+        self.0.push('\n');
+
+        self
+    }
+
     /// Pushes text to your message, but normalizing content - that means
     /// ensuring that there's no unwanted formatting, mention spam etc.
     pub fn push_safe<C: I>(mut self, content: C) -> Self {
@@ -578,6 +657,53 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a masked link to the content normalizing content, ensuring the
+    /// text cannot end the link early and the URL cannot end it early either.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_named_link_safe("[not] a link", "https://example.com/(path)")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "[ not  a link](https://example.com/%28path%29)");
+    /// ```
+    pub fn push_named_link_safe<D: I, U: I>(mut self, name: D, url: U) -> Self {
+        let name = {
+            let mut c = name.into();
+            c.inner = normalize(&c.inner).replace('[', " ").replace(']', " ");
+            c.to_string()
+        };
+        let url = url.into().to_string()
+            .replace(' ', "%20")
+            .replace('(', "%28")
+            .replace(')', "%29");
+
+        let _ = write!(self.0, "[{}]({})", name, url);
+
+        self
+    }
+
+    /// Pushes a block quote to the content normalizing content.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_quote_safe("@everyone, *look*").build();
+    ///
+    /// assert_eq!(content, "> @\u{200B}everyone, \\*look\\*");
+    /// ```
+    pub fn push_quote_safe<D: I>(self, content: D) -> Self {
+        let content = MessageBuilder::new().push_safe(content).build();
+
+        self.push_quote(content)
+    }
+
     /// Pushes text with a newline appended to the content normalizing content.
     ///
     /// # Examples
@@ -711,6 +837,29 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a block quote with an added newline to the content normalizing
+    /// content.
+    ///
+    /// # Examples
+    ///
+    /// Push content and then append a newline:
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_quote_line_safe("@everyone")
+    ///     .push("world").build();
+    ///
+    /// assert_eq!(content, "> @\u{200B}everyone\nworld");
+    /// ```
+    pub fn push_quote_line_safe<D: I>(mut self, content: D) -> Self {
+        self = self.push_quote_safe(content);
+        self.0.push('\n');
+
+        self
+    }
+
     /// Mentions the [`Role`] in the built message.
     ///
     /// This accepts anything that converts _into_ a [`RoleId`]. Refer to
@@ -728,6 +877,36 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a timestamp, which Discord displays in the reader's timezone
+    /// and locale, formatted according to the given `style`.
+    ///
+    /// # Examples
+    ///
+    /// Announce when an event starts, relative to now:
+    ///
+    /// ```rust
+    /// # extern crate serenity;
+    /// #
+    /// use serenity::model::Timestamp;
+    /// use serenity::utils::{MessageBuilder, TimestampStyle};
+    ///
+    /// # fn main() {
+    /// let start = Timestamp::from_unix_timestamp(1_546_300_800).unwrap();
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("The event starts ")
+    ///     .timestamp(&start, TimestampStyle::RelativeTime)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "The event starts <t:1546300800:R>");
+    /// # }
+    /// ```
+    pub fn timestamp<T: Into<Timestamp>>(mut self, timestamp: T, style: TimestampStyle) -> Self {
+        let _ = write!(self.0, "<t:{}:{}>", timestamp.into().unix_timestamp(), style);
+
+        self
+    }
+
     /// Mentions the [`User`] in the built message.
     ///
     /// This accepts anything that converts _into_ a [`UserId`]. Refer to
@@ -767,6 +946,45 @@ impl Display for MessageBuilder {
 
 /// Formatting modifiers for MessageBuilder content pushes
 ///
+/// The way Discord displays a timestamp pushed via
+/// [`MessageBuilder::timestamp`].
+///
+/// The examples show how the timestamp is displayed for readers using the
+/// `en-US` locale.
+///
+/// [`MessageBuilder::timestamp`]: struct.MessageBuilder.html#method.timestamp
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TimestampStyle {
+    /// E.g. `16:20`.
+    ShortTime,
+    /// E.g. `16:20:30`.
+    LongTime,
+    /// E.g. `20/04/2021`.
+    ShortDate,
+    /// E.g. `20 April 2021`.
+    LongDate,
+    /// E.g. `20 April 2021 16:20`, used by Discord if no style is given.
+    ShortDateTime,
+    /// E.g. `Tuesday, 20 April 2021 16:20`.
+    LongDateTime,
+    /// E.g. `2 months ago`.
+    RelativeTime,
+}
+
+impl Display for TimestampStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            TimestampStyle::ShortTime => "t",
+            TimestampStyle::LongTime => "T",
+            TimestampStyle::ShortDate => "d",
+            TimestampStyle::LongDate => "D",
+            TimestampStyle::ShortDateTime => "f",
+            TimestampStyle::LongDateTime => "F",
+            TimestampStyle::RelativeTime => "R",
+        })
+    }
+}
+
 /// Provides an enum of formatting modifiers for a string, for combination with
 /// string types and Content types.
 ///
@@ -970,6 +1188,14 @@ impl I for Content {
     fn into(self) -> Content { self }
 }
 
+/// Prefixes every line of the `text` with a quote marker.
+fn quote(text: &str) -> String {
+    text.split('\n')
+        .map(|line| format!("> {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn normalize(text: &str) -> String {
     // Remove invite links and popular scam websites, mostly to prevent the
     // current user from triggering various ad detectors and prevent embeds.
@@ -997,6 +1223,7 @@ mod test {
     use super::{
         ContentModifier::*,
         MessageBuilder,
+        TimestampStyle,
     };
 
     macro_rules! gen {
@@ -1017,6 +1244,34 @@ mod test {
         assert_eq!(content, "```rb\ntest\n```");
     }
 
+    #[test]
+    fn ansi_code_blocks() {
+        use utils::{AnsiBackground, AnsiBuilder, AnsiStyle};
+
+        let style = AnsiStyle::default().underline().background(AnsiBackground::Indigo);
+        let content = MessageBuilder::new()
+            .push_ansi_codeblock(AnsiBuilder::new()
+                .push_styled("a\u{1b}[31m", style)
+                .push(" ```b"))
+            .build();
+        assert_eq!(content, "```ansi\n\u{1b}[0;4;45ma[31m\u{1b}[0m  b\n```");
+    }
+
+    #[test]
+    fn ansi_code_block_fences_across_pushes() {
+        use utils::AnsiBuilder;
+
+        let content = MessageBuilder::new()
+            .push_ansi_codeblock(AnsiBuilder::new().push("a``").push("`b"))
+            .build();
+        assert_eq!(content, "```ansi\na b\n```");
+
+        let content = MessageBuilder::new()
+            .push_ansi_codeblock(AnsiBuilder("```\n@everyone".to_string()))
+            .build();
+        assert_eq!(content, "```ansi\n \n@everyone\n```");
+    }
+
     #[test]
     fn safe_content() {
         let content = MessageBuilder::new()
@@ -1053,6 +1308,52 @@ mod test {
         assert_eq!(content_emoji, "<:Rohrkatze:32>");
     }
 
+    #[test]
+    fn named_links() {
+        let content = MessageBuilder::new()
+            .push_named_link("serenity", "https://github.com/serenity-rs/serenity")
+            .build();
+        assert_eq!(content, "[serenity](https://github.com/serenity-rs/serenity)");
+
+        let content = MessageBuilder::new()
+            .push_named_link_safe("a](b) [c", "https://example.com/a b)")
+            .build();
+        assert_eq!(content, "[a (b)  c](https://example.com/a%20b%29)");
+    }
+
+    #[test]
+    fn quotes() {
+        gen! {
+            push_quote => [
+                "" => "> ",
+                "foo" => "> foo",
+                "foo\nbar" => "> foo\n> bar"
+            ],
+            push_quote_line => [
+                "foo" => "> foo\n"
+            ],
+            push_quote_safe => [
+                "@here\n*foo*" => "> @\u{200B}here\n> \\*foo\\*"
+            ],
+            push_quote_line_safe => [
+                "@here" => "> @\u{200B}here\n"
+            ]
+        };
+    }
+
+    #[test]
+    fn timestamps() {
+        let timestamp = Timestamp::from_unix_timestamp(1_618_935_630).unwrap();
+
+        let content = MessageBuilder::new()
+            .timestamp(&timestamp, TimestampStyle::ShortTime)
+            .timestamp(&timestamp, TimestampStyle::LongDateTime)
+            .timestamp(&timestamp, TimestampStyle::RelativeTime)
+            .build();
+
+        assert_eq!(content, "<t:1618935630:t><t:1618935630:F><t:1618935630:R>");
+    }
+
     #[test]
     fn content() {
         let content = Bold + Italic + Code + "Fun!";
@@ -1,15 +1,42 @@
 //! A set of utilities to help with common use cases that are not required to
 //! fully use the library.
 
+mod ansi;
 mod colour;
+mod content;
+#[cfg(feature = "cache")]
+mod content_safe;
 mod message_builder;
+#[cfg(feature = "collector")]
+mod paginator;
+#[cfg(feature = "scheduler")]
+pub mod scheduler;
+mod token;
 mod vec_map;
 
 pub use self::{
+    ansi::{AnsiBackground, AnsiBuilder, AnsiColour, AnsiStyle},
     colour::Colour,
-    message_builder::{Content, ContentModifier, MessageBuilder},
+    content::{
+        parse_code_blocks,
+        parse_emojis,
+        parse_mentions,
+        parse_urls,
+        CodeBlock,
+        CodeBlocks,
+        ContentEmoji,
+        Emojis,
+        Mentions,
+        Urls
+    },
+    message_builder::{Content, ContentModifier, MessageBuilder, TimestampStyle},
+    token::{parse_token, validate_token, InvalidToken, TokenComponents},
     vec_map::VecMap
 };
+#[cfg(feature = "cache")]
+pub use self::content_safe::{content_safe, ContentSafeOptions, ContentSafeReport};
+#[cfg(feature = "collector")]
+pub use self::paginator::Paginator;
 
 use base64;
 use internal::prelude::*;
@@ -446,6 +473,14 @@ pub fn parse_quotes(s: &str) -> Vec<String> {
 /// Calculates the Id of the shard responsible for a guild, given its Id and
 /// total number of shards used.
 ///
+/// This is Discord's formula of `(guild_id >> 22) % shard_count`, which can
+/// be used to route work for a guild to the process running its shard, such
+/// as with [`ShardManager::is_responsible_for`].
+///
+/// # Panics
+///
+/// Panics if the `shard_count` is `0`.
+///
 /// # Examples
 ///
 /// Retrieve the Id of the shard for a guild with Id `81384788765712384`, using
@@ -456,6 +491,8 @@ pub fn parse_quotes(s: &str) -> Vec<String> {
 ///
 /// assert_eq!(utils::shard_id(81384788765712384, 17), 7);
 /// ```
+///
+/// [`ShardManager::is_responsible_for`]: ../client/bridge/gateway/struct.ShardManager.html#method.is_responsible_for
 #[inline]
 pub fn shard_id(guild_id: u64, shard_count: u64) -> u64 { (guild_id >> 22) % shard_count }
 
@@ -550,4 +587,11 @@ mod test {
         assert!(!is_nsfw("général"));
         assert!(is_nsfw("nsfw-général"));
     }
+
+    #[test]
+    fn test_shard_id() {
+        assert_eq!(shard_id(81384788765712384, 1), 0);
+        assert_eq!(shard_id(81384788765712384, 17), 7);
+        assert_eq!(shard_id(41771983423143937, 2), 0);
+    }
 }
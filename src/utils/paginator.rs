@@ -0,0 +1,275 @@
+use builder::CreateEmbed;
+use collector::{ReactionAction, ReactionCollectorBuilder};
+use internal::prelude::*;
+use model::{
+    channel::{Message, ReactionType},
+    id::{ChannelId, UserId}
+};
+use std::time::Duration;
+
+/// Sends a list of embeds as pages of a single message, allowing users to
+/// navigate between them by reacting with the previous and next emojis.
+///
+/// Navigating is possible until the timeout elapses without a page being
+/// turned, or the stop emoji is used. The navigation reactions are removed
+/// afterwards.
+///
+/// If the current user lacks the permission to [Manage Messages], reactions
+/// by users cannot be removed for them. Removing a reaction then turns the
+/// page as well, so that a page can be turned repeatedly by toggling the
+/// reaction. If the current user cannot add reactions, only the first page is
+/// sent.
+///
+/// **Note**: Requires the `collector` feature.
+///
+/// # Examples
+///
+/// Paginating a leaderboard, where only the author of the command can turn the
+/// pages:
+///
+/// ```rust,no_run
+/// # use serenity::model::channel::Message;
+/// #
+/// # fn leaderboard(msg: &Message) -> serenity::Result<()> {
+/// use serenity::builder::CreateEmbed;
+/// use serenity::utils::Paginator;
+/// use std::time::Duration;
+///
+/// let pages = vec![
+///     CreateEmbed::default().title("Leaderboard").description("1. ferris"),
+///     CreateEmbed::default().title("Leaderboard").description("2. crab"),
+/// ];
+///
+/// Paginator::new(pages)
+///     .author_id(msg.author.id)
+///     .timeout(Duration::from_secs(60))
+///     .send(msg.channel_id)?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [Manage Messages]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+#[derive(Clone, Debug)]
+pub struct Paginator {
+    pages: Vec<CreateEmbed>,
+    author_id: Option<UserId>,
+    timeout: Duration,
+    previous: ReactionType,
+    next: ReactionType,
+    stop: Option<ReactionType>,
+}
+
+impl Paginator {
+    /// Creates a paginator for the given pages.
+    ///
+    /// **Note**: The timeout defaults to two minutes, and the emojis to
+    /// ◀, ▶, and ⏹ to stop.
+    pub fn new(pages: Vec<CreateEmbed>) -> Self {
+        Paginator {
+            pages,
+            author_id: None,
+            timeout: Duration::from_secs(120),
+            previous: ReactionType::from('◀'),
+            next: ReactionType::from('▶'),
+            stop: Some(ReactionType::from('⏹')),
+        }
+    }
+
+    /// Only allows the user with the given Id to turn the pages.
+    pub fn author_id<U: Into<UserId>>(mut self, author_id: U) -> Self {
+        self.author_id = Some(author_id.into());
+
+        self
+    }
+
+    /// Sets the duration after which navigating stops if no page was turned.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = duration;
+
+        self
+    }
+
+    /// Sets the emoji turning to the previous page.
+    pub fn previous_emoji<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.previous = emoji.into();
+
+        self
+    }
+
+    /// Sets the emoji turning to the next page.
+    pub fn next_emoji<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.next = emoji.into();
+
+        self
+    }
+
+    /// Sets the emoji stopping the navigation, or `None` to not offer one.
+    pub fn stop_emoji<R: Into<ReactionType>>(mut self, emoji: Option<R>) -> Self {
+        self.stop = emoji.map(Into::into);
+
+        self
+    }
+
+    /// Sends the first page to the channel and blocks the current thread
+    /// while users navigate the pages, returning the message once navigating
+    /// stopped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Other`] if there are no pages, or the error of
+    /// sending the first page.
+    ///
+    /// [`Error::Other`]: ../enum.Error.html#variant.Other
+    pub fn send(self, channel_id: ChannelId) -> Result<Message> {
+        let mut message = match self.pages.first() {
+            Some(page) => channel_id.send_message(|m| m.embed(|_| page.clone()))?,
+            None => return Err(Error::Other("No pages to paginate")),
+        };
+
+        if self.pages.len() == 1 {
+            return Ok(message);
+        }
+
+        let mut emojis = vec![self.previous.clone(), self.next.clone()];
+        emojis.extend(self.stop.clone());
+
+        for emoji in &emojis {
+            if let Err(why) = message.react(emoji.clone()) {
+                warn!("Failed to add pagination reactions: {:?}", why);
+
+                return Ok(message);
+            }
+        }
+
+        let mut can_remove_reactions = true;
+        let mut current = 0;
+
+        loop {
+            let mut collector = ReactionCollectorBuilder::default()
+                .message_id(message.id)
+                .removed(!can_remove_reactions)
+                .timeout(self.timeout);
+
+            collector = match self.author_id {
+                Some(author_id) => collector.author_id(author_id),
+                // Ignore the reactions added by the current user.
+                None => {
+                    let current_user_id = message.author.id;
+
+                    collector.filter(move |reaction| reaction.user_id != current_user_id)
+                },
+            };
+
+            let action = match collector.collect_limit(1).build().next() {
+                Some(action) => action,
+                None => break,
+            };
+
+            let (emoji, user_id) = {
+                let reaction = action.as_inner_ref();
+
+                (reaction.emoji.clone(), reaction.user_id)
+            };
+
+            if let ReactionAction::Added(_) = action {
+                if can_remove_reactions
+                    && channel_id.delete_reaction(message.id, Some(user_id), emoji.clone()).is_err() {
+                    can_remove_reactions = false;
+                }
+            }
+
+            let page = match self.turn(current, &emoji) {
+                Turn::To(page) => page,
+                Turn::Stop => break,
+                Turn::Ignore => continue,
+            };
+
+            if page != current {
+                current = page;
+
+                let page = self.pages[current].clone();
+                message.edit(|m| m.embed(|_| page))?;
+            }
+        }
+
+        if message.delete_reactions().is_err() {
+            // Without the permission to remove all reactions, at least remove
+            // the current user's.
+            for emoji in emojis {
+                let _ = channel_id.delete_reaction(message.id, None, emoji);
+            }
+        }
+
+        Ok(message)
+    }
+
+    /// Determines where reacting with the emoji on the current page leads,
+    /// wrapping around at either end.
+    fn turn(&self, current: usize, emoji: &ReactionType) -> Turn {
+        if *emoji == self.previous {
+            Turn::To(current.checked_sub(1).unwrap_or(self.pages.len() - 1))
+        } else if *emoji == self.next {
+            Turn::To((current + 1) % self.pages.len())
+        } else if Some(emoji) == self.stop.as_ref() {
+            Turn::Stop
+        } else {
+            Turn::Ignore
+        }
+    }
+}
+
+/// The outcome of a reaction to a paginated message.
+#[derive(Debug, PartialEq)]
+enum Turn {
+    /// Show the page at the index.
+    To(usize),
+    /// Stop navigating.
+    Stop,
+    /// The emoji does not navigate.
+    Ignore,
+}
+
+#[cfg(test)]
+mod test {
+    use builder::CreateEmbed;
+    use model::channel::ReactionType;
+    use super::{Paginator, Turn};
+
+    fn paginator() -> Paginator {
+        Paginator::new(vec![CreateEmbed::default(); 3])
+    }
+
+    #[test]
+    fn turns_pages_wrapping_around() {
+        let paginator = paginator();
+
+        assert_eq!(paginator.turn(0, &'▶'.into()), Turn::To(1));
+        assert_eq!(paginator.turn(2, &'▶'.into()), Turn::To(0));
+        assert_eq!(paginator.turn(1, &'◀'.into()), Turn::To(0));
+        assert_eq!(paginator.turn(0, &'◀'.into()), Turn::To(2));
+    }
+
+    #[test]
+    fn stops_and_ignores() {
+        let paginator = paginator();
+
+        assert_eq!(paginator.turn(1, &'⏹'.into()), Turn::Stop);
+        assert_eq!(paginator.turn(1, &'❌'.into()), Turn::Ignore);
+
+        let paginator = paginator.stop_emoji::<ReactionType>(None);
+        assert_eq!(paginator.turn(1, &'⏹'.into()), Turn::Ignore);
+    }
+
+    #[test]
+    fn custom_emojis() {
+        let paginator = paginator()
+            .previous_emoji('⬅')
+            .next_emoji('➡')
+            .stop_emoji(Some('❌'));
+
+        assert_eq!(paginator.turn(0, &'➡'.into()), Turn::To(1));
+        assert_eq!(paginator.turn(1, &'⬅'.into()), Turn::To(0));
+        assert_eq!(paginator.turn(0, &'▶'.into()), Turn::Ignore);
+        assert_eq!(paginator.turn(0, &'❌'.into()), Turn::Stop);
+    }
+}
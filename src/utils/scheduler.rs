@@ -0,0 +1,338 @@
+use builder::CreateMessage;
+use model::id::{ChannelId, MessageId};
+use parking_lot::{Condvar, Mutex};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    fmt::{Debug, Formatter, Result as FmtResult},
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+    thread::Builder as ThreadBuilder,
+    time::{Duration, Instant}
+};
+
+type Task = Box<FnMut() + Send>;
+
+lazy_static! {
+    static ref SCHEDULER: Scheduler = Scheduler::new();
+}
+
+/// A handle to a task scheduled through [`schedule`], [`send_message_after`]
+/// or [`delete_message_after`].
+///
+/// Dropping the handle does _not_ cancel the task, it keeps running for as
+/// long as the process does. Use [`cancel`] to prevent it from running.
+///
+/// [`cancel`]: #method.cancel
+/// [`delete_message_after`]: fn.delete_message_after.html
+/// [`schedule`]: fn.schedule.html
+/// [`send_message_after`]: fn.send_message_after.html
+#[derive(Clone)]
+pub struct ScheduledTask {
+    id: u64,
+    shared: Arc<Shared>,
+}
+
+impl ScheduledTask {
+    /// Cancels the task, returning whether it had not run yet.
+    pub fn cancel(self) -> bool { self.shared.state.lock().tasks.remove(&self.id).is_some() }
+}
+
+impl Debug for ScheduledTask {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("ScheduledTask")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+/// A task in the queue, ordered so that the earliest deadline is the
+/// greatest, as the binary heap is a max-heap.
+#[derive(Eq, PartialEq)]
+struct Entry {
+    due: Instant,
+    id: u64,
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Entry) -> Ordering {
+        other.due.cmp(&self.due).then_with(|| other.id.cmp(&self.id))
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Entry) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    queue: BinaryHeap<Entry>,
+    /// The tasks that neither ran nor were cancelled yet.
+    tasks: HashMap<u64, Task>,
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    /// Notified whenever a task was queued, as its deadline may come first.
+    queued: Condvar,
+}
+
+/// Runs every scheduled task on the same timer thread, waiting for the
+/// earliest deadline in between.
+struct Scheduler {
+    shared: Arc<Shared>,
+}
+
+impl Scheduler {
+    fn new() -> Self {
+        let shared = Arc::new(Shared::default());
+        let timer = Arc::clone(&shared);
+
+        ThreadBuilder::new()
+            .name("serenity scheduler".to_string())
+            .spawn(move || run(&timer))
+            .expect("Error starting the scheduler thread");
+
+        Scheduler {
+            shared,
+        }
+    }
+
+    fn schedule(&self, delay: Duration, task: Task) -> ScheduledTask {
+        let mut state = self.shared.state.lock();
+
+        let id = state.next_id;
+        state.next_id += 1;
+
+        state.queue.push(Entry {
+            due: Instant::now() + delay,
+            id,
+        });
+        state.tasks.insert(id, task);
+
+        self.shared.queued.notify_one();
+
+        ScheduledTask {
+            id,
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+fn run(shared: &Shared) {
+    loop {
+        let mut task = {
+            let mut state = shared.state.lock();
+
+            loop {
+                let due = match state.queue.peek() {
+                    Some(entry) => entry.due,
+                    None => {
+                        shared.queued.wait(&mut state);
+
+                        continue;
+                    },
+                };
+
+                let now = Instant::now();
+
+                if due > now {
+                    shared.queued.wait_for(&mut state, due - now);
+
+                    continue;
+                }
+
+                let id = match state.queue.pop() {
+                    Some(entry) => entry.id,
+                    None => continue,
+                };
+
+                // Cancelled tasks were already removed.
+                if let Some(task) = state.tasks.remove(&id) {
+                    break task;
+                }
+            }
+        };
+
+        // A panicking task must not stop the tasks after it from running.
+        if panic::catch_unwind(AssertUnwindSafe(|| task())).is_err() {
+            warn!("A scheduled task panicked");
+        }
+    }
+}
+
+/// Runs a function on the scheduler's background thread once the delay
+/// elapsed.
+///
+/// All tasks share that thread, so they should hand longer work off to a
+/// thread of their own.
+///
+/// **Note**: Requires the `scheduler` feature.
+///
+/// # Examples
+///
+/// Lifting a temporary mute after ten minutes:
+///
+/// ```rust,no_run
+/// # use serenity::model::id::{GuildId, RoleId, UserId};
+/// #
+/// # let guild_id = GuildId(0);
+/// # let user_id = UserId(0);
+/// # let muted = RoleId(0);
+/// #
+/// use serenity::utils::scheduler;
+/// use std::time::Duration;
+///
+/// let unmute = scheduler::schedule(Duration::from_secs(600), move || {
+///     if let Ok(mut member) = guild_id.member(user_id) {
+///         let _ = member.remove_role(muted);
+///     }
+/// });
+///
+/// // The mute was lifted early.
+/// unmute.cancel();
+/// ```
+pub fn schedule<F: FnOnce() + Send + 'static>(delay: Duration, f: F) -> ScheduledTask {
+    SCHEDULER.schedule(delay, once(f))
+}
+
+/// Wraps the function, as a boxed `FnOnce` can not be called.
+fn once<F: FnOnce() + Send + 'static>(f: F) -> Task {
+    let mut f = Some(f);
+
+    Box::new(move || if let Some(f) = f.take() {
+        f();
+    })
+}
+
+/// Sends a message to the channel once the delay elapsed.
+///
+/// Refer to [`ChannelId::send_message`] for how the message is built. Errors
+/// are logged, as there is nothing to return them to.
+///
+/// **Note**: Requires the `scheduler` feature.
+///
+/// [`ChannelId::send_message`]: ../../model/id/struct.ChannelId.html#method.send_message
+pub fn send_message_after<F>(channel_id: ChannelId, delay: Duration, f: F) -> ScheduledTask
+    where F: FnOnce(CreateMessage) -> CreateMessage + Send + 'static {
+    schedule(delay, move || {
+        if let Err(why) = channel_id.send_message(f) {
+            warn!("Failed to send scheduled message to {}: {:?}", channel_id, why);
+        }
+    })
+}
+
+/// Deletes a message once the delay elapsed, e.g. to remove a response after
+/// it was read.
+///
+/// Errors are logged, as there is nothing to return them to.
+///
+/// **Note**: Requires the `scheduler` feature.
+///
+/// # Examples
+///
+/// Removing a reply after 30 seconds:
+///
+/// ```rust,no_run
+/// # use serenity::model::channel::Message;
+/// #
+/// # fn reply(msg: &Message) -> serenity::Result<()> {
+/// use serenity::utils::scheduler;
+/// use std::time::Duration;
+///
+/// let reply = msg.channel_id.say("This message deletes itself.")?;
+///
+/// scheduler::delete_message_after(reply.channel_id, reply.id, Duration::from_secs(30));
+/// #     Ok(())
+/// # }
+/// ```
+pub fn delete_message_after(channel_id: ChannelId, message_id: MessageId, delay: Duration)
+    -> ScheduledTask {
+    schedule(delay, move || {
+        if let Err(why) = channel_id.delete_message(message_id) {
+            warn!("Failed to delete scheduled message {}: {:?}", message_id, why);
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{mpsc, Arc},
+        time::Duration
+    };
+    use parking_lot::Mutex;
+    use super::{once, Scheduler};
+
+    #[test]
+    fn runs_in_deadline_order() {
+        let scheduler = Scheduler::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let (tx, rx) = mpsc::channel();
+
+        for &(delay, value) in &[(60, 3), (20, 1), (40, 2)] {
+            let order = Arc::clone(&order);
+            let tx = tx.clone();
+
+            scheduler.schedule(Duration::from_millis(delay), once(move || {
+                order.lock().push(value);
+                let _ = tx.send(());
+            }));
+        }
+
+        for _ in 0..3 {
+            rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        }
+
+        assert_eq!(*order.lock(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn cancelled_task_does_not_run() {
+        let scheduler = Scheduler::new();
+        let (tx, rx) = mpsc::channel();
+
+        let cancelled_tx = tx.clone();
+        let cancelled = scheduler.schedule(Duration::from_millis(20), once(move || {
+            let _ = cancelled_tx.send("cancelled");
+        }));
+        scheduler.schedule(Duration::from_millis(60), once(move || {
+            let _ = tx.send("kept");
+        }));
+
+        assert!(cancelled.clone().cancel());
+        // The task is gone, so it can not be cancelled a second time.
+        assert!(!cancelled.cancel());
+
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok("kept"));
+    }
+
+    #[test]
+    fn cancel_after_running() {
+        let scheduler = Scheduler::new();
+        let (tx, rx) = mpsc::channel();
+
+        let task = scheduler.schedule(Duration::from_millis(0), once(move || {
+            let _ = tx.send(());
+        }));
+
+        rx.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        assert!(!task.cancel());
+    }
+
+    #[test]
+    fn survives_panicking_task() {
+        let scheduler = Scheduler::new();
+        let (tx, rx) = mpsc::channel();
+
+        scheduler.schedule(Duration::from_millis(0), once(|| panic!("task")));
+        scheduler.schedule(Duration::from_millis(20), once(move || {
+            let _ = tx.send(());
+        }));
+
+        assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok());
+    }
+}
@@ -0,0 +1,174 @@
+use base64;
+use model::id::UserId;
+use model::Timestamp;
+use std::error::Error as StdError;
+use std::fmt;
+
+/// The offset of timestamps within tokens created before 2015, which count the
+/// seconds since the start of 2011 rather than the Unix epoch.
+const TOKEN_EPOCH: i64 = 1_293_840_000;
+
+/// The reason a token passed to [`validate_token`] or [`parse_token`] is
+/// malformed.
+///
+/// [`parse_token`]: fn.parse_token.html
+/// [`validate_token`]: fn.validate_token.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidToken {
+    /// The token is empty.
+    Empty,
+    /// The token does not consist of three non-empty segments, separated by
+    /// dots.
+    InvalidParts,
+    /// The first segment is not a base64-encoded user Id.
+    InvalidUserId,
+    /// The second segment is not a base64-encoded timestamp.
+    InvalidTimestamp,
+}
+
+impl fmt::Display for InvalidToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.description()) }
+}
+
+impl StdError for InvalidToken {
+    fn description(&self) -> &str {
+        match *self {
+            InvalidToken::Empty => "The token is empty",
+            InvalidToken::InvalidParts => "The token does not consist of three segments",
+            InvalidToken::InvalidUserId => "The token's first segment is not a user Id",
+            InvalidToken::InvalidTimestamp => "The token's second segment is not a timestamp",
+        }
+    }
+}
+
+/// The information a bot token is made of, as returned by [`parse_token`].
+///
+/// [`parse_token`]: fn.parse_token.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenComponents {
+    /// The Id of the bot user the token belongs to, which is also the Id of
+    /// its application.
+    pub bot_user_id: UserId,
+    /// When the token was created.
+    pub creation_time: Timestamp,
+}
+
+/// Validates that a token is likely in a valid format.
+///
+/// This performs the following checks on a given token:
+///
+/// - It is not empty;
+/// - It contains 3 non-empty segments, separated by dots;
+/// - The first segment is a base64-encoded user Id;
+/// - The second segment is a base64-encoded timestamp.
+///
+/// A leading `"Bot "` is ignored.
+///
+/// **Note**: This does not check whether Discord accepts the token.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::{validate_token, InvalidToken};
+///
+/// assert!(validate_token("MTcyMTUwMTgzMjYwMzIzODQw.WzHygA.signature").is_ok());
+/// assert_eq!(validate_token("Bot "), Err(InvalidToken::Empty));
+/// assert_eq!(validate_token("a.b"), Err(InvalidToken::InvalidParts));
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`InvalidToken`] describing the first check that failed.
+///
+/// [`InvalidToken`]: enum.InvalidToken.html
+pub fn validate_token(token: &str) -> Result<(), InvalidToken> {
+    parse_token(token).map(|_| ())
+}
+
+/// Extracts the bot's user Id and the token's creation time from a token.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::id::UserId;
+/// use serenity::utils::parse_token;
+///
+/// let components = parse_token("MTcyMTUwMTgzMjYwMzIzODQw.WzHygA.signature").unwrap();
+///
+/// assert_eq!(components.bot_user_id, UserId(172150183260323840));
+/// assert_eq!(components.creation_time.unix_timestamp(), 1530000000);
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`InvalidToken`] if the token is malformed, refer to
+/// [`validate_token`] for the checks performed.
+///
+/// [`InvalidToken`]: enum.InvalidToken.html
+/// [`validate_token`]: fn.validate_token.html
+pub fn parse_token(token: &str) -> Result<TokenComponents, InvalidToken> {
+    let token = token.trim();
+    let token = token.trim_left_matches("Bot ").trim();
+
+    if token.is_empty() {
+        return Err(InvalidToken::Empty);
+    }
+
+    let parts = token.split('.').collect::<Vec<&str>>();
+
+    if parts.len() != 3 || parts.iter().any(|part| part.is_empty()) {
+        return Err(InvalidToken::InvalidParts);
+    }
+
+    let bot_user_id = decode_segment(parts[0])
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|id| id.parse::<u64>().ok())
+        .map(UserId)
+        .ok_or(InvalidToken::InvalidUserId)?;
+
+    let bytes = decode_segment(parts[1]).ok_or(InvalidToken::InvalidTimestamp)?;
+
+    if bytes.is_empty() || bytes.len() > 8 {
+        return Err(InvalidToken::InvalidTimestamp);
+    }
+
+    let mut timestamp = bytes.iter().fold(0i64, |acc, &byte| (acc << 8) | i64::from(byte));
+
+    if timestamp < TOKEN_EPOCH {
+        timestamp += TOKEN_EPOCH;
+    }
+
+    let creation_time = Timestamp::from_unix_timestamp(timestamp)
+        .map_err(|_| InvalidToken::InvalidTimestamp)?;
+
+    Ok(TokenComponents {
+        bot_user_id,
+        creation_time,
+    })
+}
+
+fn decode_segment(segment: &str) -> Option<Vec<u8>> {
+    base64::decode_config(segment.trim_right_matches('='), base64::URL_SAFE_NO_PAD).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let components = parse_token("Bot MTcyMTUwMTgzMjYwMzIzODQw.DhOEAA.signature").unwrap();
+
+        assert_eq!(components.bot_user_id, UserId(172_150_183_260_323_840));
+        assert_eq!(components.creation_time.unix_timestamp(), 1_530_000_000);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(validate_token(""), Err(InvalidToken::Empty));
+        assert_eq!(validate_token("a..c"), Err(InvalidToken::InvalidParts));
+        assert_eq!(validate_token("a.b.c.d"), Err(InvalidToken::InvalidParts));
+        assert_eq!(validate_token("YWJj.WzHygA.c"), Err(InvalidToken::InvalidUserId));
+        assert_eq!(validate_token("MTcyMTUwMTgzMjYwMzIzODQw.!.c"), Err(InvalidToken::InvalidTimestamp));
+    }
+}
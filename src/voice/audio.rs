@@ -1,5 +1,6 @@
 use parking_lot::Mutex;
 use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
     sync::Arc,
     time::Duration
 };
@@ -16,9 +17,23 @@ pub trait AudioSource: Send {
     fn read_pcm_frame(&mut self, buffer: &mut [i16]) -> Option<usize>;
 
     fn read_opus_frame(&mut self) -> Option<Vec<u8>>;
+
+    /// Moves to the given position from the start of the source, returning
+    /// whether the source supports this.
+    ///
+    /// Returns `false` by default, in which case [`Audio::seek`] can only
+    /// seek forward, by skipping frames.
+    ///
+    /// [`Audio::seek`]: struct.Audio.html#method.seek
+    #[allow(unused_variables)]
+    fn seek(&mut self, position: Duration) -> bool { false }
 }
 
 /// A receiver for incoming audio.
+///
+/// Packets are decrypted and decoded into PCM before being passed to the
+/// receiver. Users are identified by the SSRC of their audio stream, which is
+/// mapped to their Id once they start speaking.
 pub trait AudioReceiver: Send {
     fn speaking_update(&mut self, ssrc: u32, user_id: u64, speaking: bool);
 
@@ -28,6 +43,28 @@ pub trait AudioReceiver: Send {
                     timestamp: u32,
                     stereo: bool,
                     data: &[i16]);
+
+    /// Called in addition to [`voice_packet`] if the SSRC of the packet is
+    /// known to belong to a user.
+    ///
+    /// Does nothing by default.
+    ///
+    /// [`voice_packet`]: #tymethod.voice_packet
+    #[allow(unused_variables)]
+    fn user_voice_packet(&mut self,
+                         user_id: u64,
+                         ssrc: u32,
+                         sequence: u16,
+                         timestamp: u32,
+                         stereo: bool,
+                         data: &[i16]) {}
+
+    /// Called when a user disconnected from the voice channel, after which
+    /// their SSRC is no longer mapped to them.
+    ///
+    /// Does nothing by default.
+    #[allow(unused_variables)]
+    fn client_disconnect(&mut self, user_id: u64) {}
 }
 
 #[derive(Clone, Copy)]
@@ -137,16 +174,70 @@ impl Audio {
         self
     }
 
-    /// Change the position in the stream for subsequent playback.
+    /// Change the position in the stream for subsequent playback, in a
+    /// manner that allows method chaining.
     ///
-    /// Currently a No-op.
+    /// Refer to [`seek`] for when this is supported.
+    ///
+    /// [`seek`]: #method.seek
     pub fn position(&mut self, position: Duration) -> &mut Self {
-        self.position = position;
-        self.position_modified = true;
+        self.seek(position);
 
         self
     }
 
+    /// Changes the position in the stream for subsequent playback, returning
+    /// whether this succeeded.
+    ///
+    /// Seeking forward is always possible, by skipping the frames in between.
+    /// Seeking backward is only possible if the source supports it, through
+    /// [`AudioSource::seek`].
+    ///
+    /// **Note**: Skipping frames reads them as fast as the source allows, while
+    /// the audio is locked.
+    ///
+    /// [`AudioSource::seek`]: trait.AudioSource.html#method.seek
+    pub fn seek(&mut self, position: Duration) -> bool {
+        if self.source.seek(position) {
+            self.position = position;
+            self.position_modified = true;
+
+            return true;
+        }
+
+        if position < self.position {
+            return false;
+        }
+
+        let frame = Duration::from_millis(20);
+        let mut buffer = [0i16; 960 * 2];
+
+        while self.position + frame <= position {
+            let read = match self.source.get_type() {
+                AudioType::Opus => self.source
+                    .read_opus_frame()
+                    .map_or(false, |frame| !frame.is_empty()),
+                AudioType::Pcm => {
+                    let len = if self.source.is_stereo() { 960 * 2 } else { 960 };
+
+                    self.source
+                        .read_pcm_frame(&mut buffer[..len])
+                        .map_or(false, |len| len > 0)
+                },
+            };
+
+            if !read {
+                break;
+            }
+
+            self.position += frame;
+        }
+
+        self.position_modified = true;
+
+        true
+    }
+
     /// Steps playback location forward by one frame.
     ///
     /// *Used internally*, although in future this might affect seek position.
@@ -162,3 +253,178 @@ impl Audio {
 ///
 /// [`Audio`]: struct.Audio.html
 pub type LockedAudio = Arc<Mutex<Audio>>;
+
+/// A handle for controlling playing audio, e.g. from commands.
+///
+/// This is a cheap to clone wrapper over a [`LockedAudio`], so it can be
+/// stored in the [`Client::data`] and retrieved when needed.
+///
+/// # Examples
+///
+/// Storing the handle of the song that is played, to later pause it:
+///
+/// ```rust,no_run
+/// # extern crate serenity;
+/// # extern crate typemap;
+/// #
+/// # use serenity::voice::Handler;
+/// # use serenity::model::id::{GuildId, UserId};
+/// # use std::error::Error;
+/// #
+/// # fn try_main() -> Result<(), Box<Error>> {
+/// #     let mut handler = Handler::standalone(GuildId(0), UserId(0));
+/// #     let mut data = typemap::ShareMap::custom();
+/// #
+/// use serenity::voice::{self, AudioHandle};
+/// use typemap::Key;
+///
+/// struct NowPlaying;
+///
+/// impl Key for NowPlaying {
+///     type Value = AudioHandle;
+/// }
+///
+/// let source = voice::ffmpeg("song.mp3")?;
+/// data.insert::<NowPlaying>(AudioHandle::from(handler.play_returning(source)));
+///
+/// // Later on:
+/// if let Some(audio) = data.get::<NowPlaying>() {
+///     audio.pause();
+///     audio.set_volume(0.5);
+/// }
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     try_main().unwrap();
+/// # }
+/// ```
+///
+/// [`Client::data`]: ../client/struct.Client.html#structfield.data
+/// [`LockedAudio`]: type.LockedAudio.html
+#[derive(Clone)]
+pub struct AudioHandle(LockedAudio);
+
+impl AudioHandle {
+    /// Retrieves the underlying locked audio.
+    pub fn audio(&self) -> &LockedAudio { &self.0 }
+
+    /// Whether the audio reached the end of its stream.
+    pub fn is_finished(&self) -> bool { self.0.lock().finished }
+
+    /// Whether the audio is playing, as opposed to being paused.
+    pub fn is_playing(&self) -> bool { self.0.lock().playing }
+
+    /// Pauses playback.
+    pub fn pause(&self) { self.0.lock().pause(); }
+
+    /// Retrieves how far the audio has been played.
+    pub fn position(&self) -> Duration { self.0.lock().position }
+
+    /// Resumes playback after it was [`pause`]d.
+    ///
+    /// [`pause`]: #method.pause
+    pub fn resume(&self) { self.0.lock().play(); }
+
+    /// Changes the position of playback, returning whether this succeeded.
+    ///
+    /// Refer to [`Audio::seek`] for when this is supported.
+    ///
+    /// [`Audio::seek`]: struct.Audio.html#method.seek
+    pub fn seek(&self, position: Duration) -> bool { self.0.lock().seek(position) }
+
+    /// Sets the volume of playback, where `1.0` is the original volume.
+    pub fn set_volume(&self, volume: f32) { self.0.lock().volume(volume); }
+
+    /// Retrieves the volume of playback.
+    pub fn volume(&self) -> f32 { self.0.lock().volume }
+}
+
+impl Debug for AudioHandle {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let audio = self.0.lock();
+
+        f.debug_struct("AudioHandle")
+            .field("finished", &audio.finished)
+            .field("playing", &audio.playing)
+            .field("position", &audio.position)
+            .field("volume", &audio.volume)
+            .finish()
+    }
+}
+
+impl From<LockedAudio> for AudioHandle {
+    fn from(audio: LockedAudio) -> Self { AudioHandle(audio) }
+}
+
+#[cfg(test)]
+mod test {
+    use parking_lot::Mutex;
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use super::super::pcm;
+    use super::{Audio, AudioHandle};
+
+    // Creates mono audio of the given number of 20ms frames, each filled with
+    // its index.
+    fn audio(frames: usize) -> Audio {
+        let mut bytes = Vec::with_capacity(frames * 960 * 2);
+
+        for frame in 0..frames {
+            for _ in 0..960 {
+                bytes.extend_from_slice(&[frame as u8, 0]);
+            }
+        }
+
+        Audio::new(pcm(false, Cursor::new(bytes)))
+    }
+
+    #[test]
+    fn seeks_forward_by_skipping_frames() {
+        let mut audio = audio(5);
+
+        assert!(audio.seek(Duration::from_millis(40)));
+        assert_eq!(audio.position, Duration::from_millis(40));
+
+        let mut buffer = [0; 960];
+        assert_eq!(audio.source.read_pcm_frame(&mut buffer), Some(960));
+        assert_eq!(buffer[0], 2);
+    }
+
+    #[test]
+    fn seeking_stops_at_the_end() {
+        let mut audio = audio(2);
+
+        assert!(audio.seek(Duration::from_secs(1)));
+        assert_eq!(audio.position, Duration::from_millis(40));
+    }
+
+    #[test]
+    fn cannot_seek_backward_without_support() {
+        let mut audio = audio(5);
+
+        assert!(audio.seek(Duration::from_millis(60)));
+        assert!(!audio.seek(Duration::from_millis(20)));
+        assert_eq!(audio.position, Duration::from_millis(60));
+    }
+
+    #[test]
+    fn handle_controls_audio() {
+        let handle = AudioHandle::from(Arc::new(Mutex::new(audio(5))));
+        let clone = handle.clone();
+
+        assert!(handle.is_playing());
+        clone.pause();
+        assert!(!handle.is_playing());
+        clone.resume();
+        assert!(handle.is_playing());
+
+        clone.set_volume(0.5);
+        assert_eq!(handle.volume(), 0.5);
+
+        assert!(clone.seek(Duration::from_millis(20)));
+        assert_eq!(handle.position(), Duration::from_millis(20));
+        assert!(!handle.is_finished());
+    }
+}
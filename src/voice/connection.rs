@@ -1,11 +1,11 @@
 use byteorder::{
     BigEndian,
     ByteOrder,
-    LittleEndian,
     ReadBytesExt,
     WriteBytesExt
 };
 use constants::VOICE_GATEWAY_VERSION;
+use gateway::GatewayError;
 use internal::prelude::*;
 use internal::{
     ws_impl::{ReceiverExt, SenderExt},
@@ -25,10 +25,10 @@ use opus::{
 };
 use parking_lot::Mutex;
 use serde::Deserialize;
-use sodiumoxide::crypto::secretbox::{self, Key, Nonce};
+use sodiumoxide::crypto::secretbox::Key;
 use std::{
-    collections::HashMap,
-    io::Write,
+    collections::{hash_map::Entry, HashMap},
+    io::{Error as IoError, ErrorKind as IoErrorKind, Write},
     net::{SocketAddr, ToSocketAddrs, UdpSocket},
     sync::{
         mpsc::{
@@ -43,11 +43,12 @@ use std::{
         Builder as ThreadBuilder,
         JoinHandle
     },
-    time::Duration
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH}
 };
 use super::audio::{AudioReceiver, AudioType, HEADER_LEN, SAMPLE_RATE, LockedAudio};
 use super::connection_info::ConnectionInfo;
-use super::{payload, VoiceError, CRYPTO_MODE};
+use super::crypto::CryptoMode;
+use super::{payload, VoiceError};
 use websocket::{
     client::Url as WebsocketUrl,
     sync::{
@@ -58,12 +59,22 @@ use websocket::{
             TlsStream
         },
     },
-    sync::Client as WsClient
+    sync::Client as WsClient,
+    result::WebSocketError
 };
 
 type Client = WsClient<TlsStream<TcpStream>>;
 
+/// How many seconds the voice server may take to echo a UDP keepalive before
+/// the socket is considered to no longer reach it.
+const UDP_KEEPALIVE_TIMEOUT: u64 = 30;
+
 enum ReceiverStatus {
+    /// The websocket was closed, with the close code if one was given.
+    Closed(Option<u16>),
+    /// Receiving from the websocket failed for a reason other than nothing
+    /// arriving within the read timeout.
+    Failed(Error),
     Udp(Vec<u8>),
     Websocket(VoiceEvent),
 }
@@ -81,20 +92,28 @@ struct ThreadItems {
 pub struct Connection {
     audio_timer: Timer,
     client: Arc<Mutex<Client>>,
+    crypto_mode: CryptoMode,
     decoder_map: HashMap<(u32, Channels), OpusDecoder>,
     destination: SocketAddr,
     encoder: OpusEncoder,
     encoder_stereo: bool,
+    /// Whether the last keepalive was acknowledged.
+    heartbeat_acked: bool,
+    info: ConnectionInfo,
     keepalive_timer: Timer,
     key: Key,
+    lite_nonce: u32,
     sequence: u16,
     silence_frames: u8,
     soft_clip: SoftClip,
     speaking: bool,
     ssrc: u32,
+    ssrc_map: HashMap<u32, UserId>,
     thread_items: ThreadItems,
     timestamp: u32,
     udp: UdpSocket,
+    /// When the last UDP keepalive without a packet received since was sent.
+    udp_keepalive_sent: Option<Instant>,
     user_id: UserId,
 }
 
@@ -105,6 +124,11 @@ impl Connection {
         let mut client = ClientBuilder::from_url(&url).connect_secure(None)?;
         client.send_json(&payload::build_identify(&info))?;
 
+        // Since version 4 of the voice gateway, the heartbeat interval is sent
+        // separately from the hello, in any order.
+        let mut heartbeat_interval = None;
+        let mut received_hello = None;
+
         let hello = loop {
             let value = match client.recv_json()? {
                 Some(value) => value,
@@ -112,64 +136,84 @@ impl Connection {
             };
 
             match VoiceEvent::deserialize(value)? {
-                VoiceEvent::Hello(received_hello) => {
-                    break received_hello;
+                VoiceEvent::Hello(hello) => {
+                    if hello.heartbeat_interval != 0 {
+                        heartbeat_interval = Some(hello.heartbeat_interval);
+                    }
+
+                    received_hello = Some(hello);
+                },
+                VoiceEvent::Heartbeat(heartbeat) => {
+                    heartbeat_interval = Some(heartbeat.heartbeat_interval);
                 },
-                VoiceEvent::Heartbeat(_) => continue,
                 other => {
                     debug!("[Voice] Expected hello/heartbeat; got: {:?}", other);
 
                     return Err(Error::Voice(VoiceError::ExpectedHandshake));
                 },
             }
+
+            if let Some(interval) = heartbeat_interval {
+                if let Some(mut hello) = received_hello.take() {
+                    hello.heartbeat_interval = interval;
+
+                    break hello;
+                }
+            }
         };
 
-        if !has_valid_mode(&hello.modes) {
-            return Err(Error::Voice(VoiceError::VoiceModeUnavailable));
-        }
+        let crypto_mode = CryptoMode::negotiate(&hello.modes)
+            .ok_or(Error::Voice(VoiceError::VoiceModeUnavailable))?;
 
         let destination = (&info.endpoint[..], hello.port)
             .to_socket_addrs()?
             .next()
             .ok_or(Error::Voice(VoiceError::HostnameResolve))?;
 
-        // Important to note here: the length of the packet can be of either 4
-        // or 70 bytes. If it is 4 bytes, then we need to send a 70-byte packet
-        // to determine the IP.
+        // IP discovery: the request is a 74-byte packet, consisting of the
+        // type (1 for a request), the length of the remaining data (70), the
+        // SSRC, and 66 bytes that _must_ be completely empty.
         //
-        // Past the initial 4 bytes, the packet _must_ be completely empty data.
-        //
-        // The returned packet will be a null-terminated string of the IP, and
-        // the port encoded in LE in the last two bytes of the packet.
+        // The response has the same layout, with a type of 2, followed by a
+        // null-terminated string of the IP in 64 bytes and the port encoded
+        // in BE in the last two bytes of the packet.
         let udp = UdpSocket::bind("0.0.0.0:0")?;
 
         {
-            let mut bytes = [0; 70];
+            let mut bytes = [0; 74];
+
+            {
+                let mut cursor = &mut bytes[..];
+                cursor.write_u16::<BigEndian>(1)?;
+                cursor.write_u16::<BigEndian>(70)?;
+                cursor.write_u32::<BigEndian>(hello.ssrc)?;
+            }
 
-            (&mut bytes[..]).write_u32::<BigEndian>(hello.ssrc)?;
             udp.send_to(&bytes, destination)?;
 
             let mut bytes = [0; 256];
             let (len, _addr) = udp.recv_from(&mut bytes)?;
 
+            if len < 74 {
+                return Err(Error::Voice(VoiceError::FindingByte));
+            }
+
             // Find the position in the bytes that contains the first byte of 0,
             // indicating the "end of the address".
-            let index = bytes
+            let index = bytes[8..72]
                 .iter()
-                .skip(4)
                 .position(|&x| x == 0)
                 .ok_or(Error::Voice(VoiceError::FindingByte))?;
 
-            let pos = 4 + index;
-            let addr = String::from_utf8_lossy(&bytes[4..pos]);
-            let port_pos = len - 2;
-            let port = (&bytes[port_pos..]).read_u16::<LittleEndian>()?;
+            let pos = 8 + index;
+            let addr = String::from_utf8_lossy(&bytes[8..pos]);
+            let port = (&bytes[72..74]).read_u16::<BigEndian>()?;
 
             client
-                .send_json(&payload::build_select_protocol(addr, port))?;
+                .send_json(&payload::build_select_protocol(addr, port, crypto_mode))?;
         }
 
-        let key = encryption_key(&mut client)?;
+        let key = encryption_key(&mut client, crypto_mode)?;
 
         let _ = client
             .stream_ref()
@@ -184,32 +228,83 @@ impl Connection {
         let encoder = OpusEncoder::new(SAMPLE_RATE, Channels::Mono, CodingMode::Audio)?;
 
         let soft_clip = SoftClip::new(Channels::Stereo);
-
-        // Per discord dev team's current recommendations:
-        // (https://discordapp.com/developers/docs/topics/voice-connections#heartbeating)
-        let temp_heartbeat = (hello.heartbeat_interval as f64 * 0.75) as u64;
+        let user_id = info.user_id;
 
         Ok(Connection {
             audio_timer: Timer::new(1000 * 60 * 4),
             client: mutexed_client,
+            crypto_mode,
             decoder_map: HashMap::new(),
             destination,
             encoder,
             encoder_stereo: false,
+            heartbeat_acked: true,
+            info,
             key,
-            keepalive_timer: Timer::new(temp_heartbeat),
+            keepalive_timer: Timer::new(keepalive_interval(hello.heartbeat_interval)),
+            lite_nonce: 0,
             udp,
             sequence: 0,
             silence_frames: 0,
             soft_clip,
             speaking: false,
             ssrc: hello.ssrc,
+            ssrc_map: HashMap::new(),
             thread_items,
             timestamp: 0,
-            user_id: info.user_id,
+            udp_keepalive_sent: None,
+            user_id,
         })
     }
 
+    /// Resumes the session over a new websocket connection, keeping the UDP
+    /// socket and the encryption key.
+    pub fn resume(&mut self) -> Result<()> {
+        let url = generate_url(&mut self.info.endpoint)?;
+
+        let mut client = ClientBuilder::from_url(&url).connect_secure(None)?;
+        client.send_json(&payload::build_resume(&self.info))?;
+
+        loop {
+            let value = match client.recv_json()? {
+                Some(value) => value,
+                None => continue,
+            };
+
+            match VoiceEvent::deserialize(value)? {
+                VoiceEvent::Resumed => break,
+                VoiceEvent::Heartbeat(heartbeat) => {
+                    let interval = keepalive_interval(heartbeat.heartbeat_interval);
+
+                    self.keepalive_timer = Timer::new(interval);
+                },
+                other => {
+                    debug!("[Voice] Expected resumed/heartbeat; got: {:?}", other);
+
+                    return Err(Error::Voice(VoiceError::ExpectedHandshake));
+                },
+            }
+        }
+
+        let _ = client
+            .stream_ref()
+            .as_tcp()
+            .set_read_timeout(Some(Duration::from_millis(25)));
+
+        *self.client.lock() = client;
+
+        // Anything received before resuming belongs to the old websocket.
+        while self.thread_items.rx.try_recv().is_ok() {}
+
+        self.heartbeat_acked = true;
+        // The speaking state has to be sent again.
+        self.speaking = false;
+
+        info!("[Voice] Resumed connection to: {}", self.info.endpoint);
+
+        Ok(())
+    }
+
     #[allow(unused_variables)]
     pub fn cycle(&mut self,
                  sources: &mut Vec<LockedAudio>,
@@ -219,80 +314,164 @@ impl Connection {
         let mut buffer = [0i16; 960 * 2];
         let mut mix_buffer = [0f32; 960 * 2];
         let mut packet = [0u8; 512];
-        let mut nonce = secretbox::Nonce([0; 24]);
 
         if let Some(receiver) = receiver.as_mut() {
             while let Ok(status) = self.thread_items.rx.try_recv() {
                 match status {
                     ReceiverStatus::Udp(packet) => {
+                        self.udp_keepalive_sent = None;
+
+                        if packet.len() <= HEADER_LEN {
+                            continue;
+                        }
+
                         let mut handle = &packet[2..];
                         let seq = handle.read_u16::<BigEndian>()?;
                         let timestamp = handle.read_u32::<BigEndian>()?;
                         let ssrc = handle.read_u32::<BigEndian>()?;
 
-                        nonce.0[..HEADER_LEN]
-                            .clone_from_slice(&packet[..HEADER_LEN]);
-
-                        if let Ok(mut decrypted) =
-                            secretbox::open(&packet[HEADER_LEN..], &nonce, &self.key) {
-                            let channels = opus_packet::get_nb_channels(&decrypted)?;
-
-                            let entry =
-                                self.decoder_map.entry((ssrc, channels)).or_insert_with(
-                                    || OpusDecoder::new(SAMPLE_RATE, channels).unwrap(),
-                                );
-
-                            // Strip RTP Header Extensions (one-byte)
-                            if decrypted[0] == 0xBE && decrypted[1] == 0xDE {
-                                // Read the length bytes as a big-endian u16.
-                                let header_extension_len = BigEndian::read_u16(&decrypted[2..4]);
-                                let mut offset = 4;
-                                for _ in 0..header_extension_len {
-                                    let byte = decrypted[offset];
-                                    offset += 1;
-                                    if byte == 0 {
-                                        continue;
-                                    }
+                        let decrypted = match self.crypto_mode.decrypt(&packet, &self.key) {
+                            Some(decrypted) => decrypted,
+                            None => {
+                                debug!("[Voice] Failed to decrypt packet from SSRC {}", ssrc);
+
+                                continue;
+                            },
+                        };
+
+                        let payload = match strip_header_extension(&decrypted) {
+                            Some(payload) => payload,
+                            None => {
+                                debug!("[Voice] Malformed header extension from SSRC {}", ssrc);
+
+                                continue;
+                            },
+                        };
+
+                        let channels = match opus_packet::get_nb_channels(payload) {
+                            Ok(channels) => channels,
+                            Err(why) => {
+                                debug!("[Voice] Invalid Opus packet from SSRC {}: {:?}", ssrc, why);
+
+                                continue;
+                            },
+                        };
+
+                        let len = {
+                            let decoder = match self.decoder_map.entry((ssrc, channels)) {
+                                Entry::Occupied(entry) => entry.into_mut(),
+                                Entry::Vacant(entry) => match OpusDecoder::new(SAMPLE_RATE, channels) {
+                                    Ok(decoder) => entry.insert(decoder),
+                                    Err(why) => {
+                                        warn!("[Voice] Failed to create decoder: {:?}", why);
 
-                                    offset += 1 + (0b1111 & (byte >> 4)) as usize;
-                                }
+                                        continue;
+                                    },
+                                },
+                            };
 
-                                while decrypted[offset] == 0 {
-                                    offset += 1;
-                                }
+                            match decoder.decode(payload, &mut buffer, false) {
+                                Ok(len) => len,
+                                Err(why) => {
+                                    debug!("[Voice] Failed to decode packet from SSRC {}: {:?}", ssrc, why);
 
-                                decrypted = decrypted.split_off(offset);
+                                    continue;
+                                },
                             }
+                        };
 
-                            let len = entry.decode(&decrypted, &mut buffer, false)?;
+                        let is_stereo = channels == Channels::Stereo;
 
-                            let is_stereo = channels == Channels::Stereo;
+                        let b = if is_stereo { len * 2 } else { len };
 
-                            let b = if is_stereo { len * 2 } else { len };
+                        receiver
+                            .voice_packet(ssrc, seq, timestamp, is_stereo, &buffer[..b]);
 
-                            receiver
-                                .voice_packet(ssrc, seq, timestamp, is_stereo, &buffer[..b]);
+                        if let Some(user_id) = self.ssrc_map.get(&ssrc) {
+                            receiver.user_voice_packet(
+                                user_id.0,
+                                ssrc,
+                                seq,
+                                timestamp,
+                                is_stereo,
+                                &buffer[..b],
+                            );
                         }
                     },
                     ReceiverStatus::Websocket(VoiceEvent::Speaking(ev)) => {
+                        self.ssrc_map.insert(ev.ssrc, ev.user_id);
+
                         receiver.speaking_update(ev.ssrc, ev.user_id.0, ev.speaking);
                     },
+                    ReceiverStatus::Websocket(VoiceEvent::ClientDisconnect(ev)) => {
+                        let user_id = ev.user_id;
+                        let ssrcs = self.ssrc_map
+                            .iter()
+                            .filter(|&(_, id)| *id == user_id)
+                            .map(|(ssrc, _)| *ssrc)
+                            .collect::<Vec<u32>>();
+
+                        for ssrc in ssrcs {
+                            self.ssrc_map.remove(&ssrc);
+                            self.decoder_map.retain(|&(decoder_ssrc, _), _| decoder_ssrc != ssrc);
+                        }
+
+                        receiver.client_disconnect(user_id.0);
+                    },
+                    ReceiverStatus::Websocket(VoiceEvent::HeartbeatAck) => {
+                        self.heartbeat_acked = true;
+                    },
                     ReceiverStatus::Websocket(other) => {
                         info!("[Voice] Received other websocket data: {:?}", other);
                     },
+                    ReceiverStatus::Closed(code) => {
+                        return Err(Error::Voice(VoiceError::Closed(code)));
+                    },
+                    ReceiverStatus::Failed(why) => return Err(why),
                 }
             }
         } else {
-            loop {
-                if self.thread_items.rx.try_recv().is_err() {
-                    break;
+            while let Ok(status) = self.thread_items.rx.try_recv() {
+                match status {
+                    ReceiverStatus::Websocket(VoiceEvent::HeartbeatAck) => {
+                        self.heartbeat_acked = true;
+                    },
+                    ReceiverStatus::Closed(code) => {
+                        return Err(Error::Voice(VoiceError::Closed(code)));
+                    },
+                    ReceiverStatus::Failed(why) => return Err(why),
+                    ReceiverStatus::Udp(_) => {
+                        self.udp_keepalive_sent = None;
+                    },
+                    _ => {},
                 }
             }
         }
 
         // Send the voice websocket keepalive if it's time
         if self.keepalive_timer.check() {
-            self.client.lock().send_json(&payload::build_keepalive())?;
+            // The connection is considered dead if the previous keepalive was
+            // never acknowledged.
+            if !self.heartbeat_acked {
+                return Err(Error::Voice(VoiceError::HeartbeatTimeout));
+            }
+
+            self.heartbeat_acked = false;
+
+            let nonce = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|time| time.as_secs() * 1000 + u64::from(time.subsec_nanos()) / 1_000_000)
+                .unwrap_or(0);
+
+            self.client.lock().send_json(&payload::build_keepalive(nonce))?;
+        }
+
+        // The voice server echoes UDP keepalives, so the socket is considered
+        // dead if nothing at all arrived since the last one.
+        if let Some(sent) = self.udp_keepalive_sent {
+            if sent.elapsed().as_secs() >= UDP_KEEPALIVE_TIMEOUT {
+                return Err(Error::Voice(VoiceError::UdpTimeout));
+            }
         }
 
         // Send UDP keepalive if it's time
@@ -300,6 +479,10 @@ impl Connection {
             let mut bytes = [0; 4];
             (&mut bytes[..]).write_u32::<BigEndian>(self.ssrc)?;
             self.udp.send_to(&bytes, self.destination)?;
+
+            if self.udp_keepalive_sent.is_none() {
+                self.udp_keepalive_sent = Some(Instant::now());
+            }
         }
 
 
@@ -411,7 +594,18 @@ impl Connection {
 
         self.set_speaking(true)?;
 
-        let index = self.prep_packet(&mut packet, mix_buffer, &opus_frame, nonce)?;
+        let index = match self.prep_packet(&mut packet, mix_buffer, &opus_frame) {
+            Ok(index) => index,
+            // Only the frame is faulty, the connection itself is fine.
+            Err(Error::Voice(VoiceError::FrameTooLong(len))) => {
+                warn!("[Voice] Skipping Opus frame of {} bytes", len);
+
+                audio_timer.await();
+
+                return Ok(());
+            },
+            Err(why) => return Err(why),
+        };
         audio_timer.await();
 
         self.udp.send_to(&packet[..index], self.destination)?;
@@ -423,8 +617,7 @@ impl Connection {
     fn prep_packet(&mut self,
                    packet: &mut [u8; 512],
                    buffer: [f32; 1920],
-                   opus_frame: &[u8],
-                   mut nonce: Nonce)
+                   opus_frame: &[u8])
                    -> Result<usize> {
         {
             let mut cursor = &mut packet[..HEADER_LEN];
@@ -434,10 +627,8 @@ impl Connection {
             cursor.write_u32::<BigEndian>(self.ssrc)?;
         }
 
-        nonce.0[..HEADER_LEN]
-            .clone_from_slice(&packet[..HEADER_LEN]);
-
-        let sl_index = packet.len() - 16;
+        // Leave space for the encryption overhead at the end of the packet.
+        let sl_index = packet.len() - self.crypto_mode.overhead();
         let buffer_len = if self.encoder_stereo { 960 * 2 } else { 960 };
 
         let len = if opus_frame.is_empty() {
@@ -445,22 +636,22 @@ impl Connection {
                 .encode_float(&buffer[..buffer_len], &mut packet[HEADER_LEN..sl_index])?
         } else {
             let len = opus_frame.len();
+
+            if HEADER_LEN + len > sl_index {
+                return Err(Error::Voice(VoiceError::FrameTooLong(len)));
+            }
+
             packet[HEADER_LEN..HEADER_LEN + len]
                 .clone_from_slice(opus_frame);
             len
         };
 
-        let crypted = {
-            let slice = &packet[HEADER_LEN..HEADER_LEN + len];
-            secretbox::seal(slice, &nonce, &self.key)
-        };
-        let index = HEADER_LEN + crypted.len();
-        packet[HEADER_LEN..index].clone_from_slice(&crypted);
+        let index = self.crypto_mode.encrypt(&mut packet[..], len, &self.key, &mut self.lite_nonce);
 
         self.sequence = self.sequence.wrapping_add(1);
         self.timestamp = self.timestamp.wrapping_add(960);
 
-        Ok(HEADER_LEN + crypted.len())
+        Ok(index)
     }
 
     fn set_speaking(&mut self, speaking: bool) -> Result<()> {
@@ -470,7 +661,7 @@ impl Connection {
 
         self.speaking = speaking;
 
-        self.client.lock().send_json(&payload::build_speaking(speaking))
+        self.client.lock().send_json(&payload::build_speaking(speaking, self.ssrc))
     }
 }
 
@@ -509,8 +700,14 @@ fn generate_url(endpoint: &mut String) -> Result<WebsocketUrl> {
         .or(Err(Error::Voice(VoiceError::EndpointUrl)))
 }
 
+// Per discord dev team's current recommendations:
+// (https://discordapp.com/developers/docs/topics/voice-connections#heartbeating)
+fn keepalive_interval(heartbeat_interval: u64) -> u64 {
+    (heartbeat_interval as f64 * 0.75) as u64
+}
+
 #[inline]
-fn encryption_key(client: &mut Client) -> Result<Key> {
+fn encryption_key(client: &mut Client, crypto_mode: CryptoMode) -> Result<Key> {
     loop {
         let value = match client.recv_json()? {
             Some(value) => value,
@@ -519,7 +716,7 @@ fn encryption_key(client: &mut Client) -> Result<Key> {
 
         match VoiceEvent::deserialize(value)? {
             VoiceEvent::Ready(ready) => {
-                if ready.mode != CRYPTO_MODE {
+                if ready.mode != crypto_mode.name() {
                     return Err(Error::Voice(VoiceError::VoiceModeInvalid));
                 }
 
@@ -538,15 +735,28 @@ fn encryption_key(client: &mut Client) -> Result<Key> {
     }
 }
 
-#[inline]
-fn has_valid_mode<T, It> (modes: It) -> bool
-where T: for<'a> PartialEq<&'a str>,
-      It : IntoIterator<Item=T>
-{
-    modes.into_iter().any(|s| s == CRYPTO_MODE)
+// Strips the one-byte RTP header extensions from a decrypted payload,
+// returning `None` if they exceed the payload.
+fn strip_header_extension(payload: &[u8]) -> Option<&[u8]> {
+    if payload.len() < 4 || payload[0] != 0xBE || payload[1] != 0xDE {
+        return Some(payload);
+    }
+
+    // The length is given in 32-bit words, which include any padding.
+    let words = BigEndian::read_u16(&payload[2..4]) as usize;
+
+    payload.get(4 + words * 4..)
 }
 
 #[inline]
+#[inline]
+fn is_timeout(why: &IoError) -> bool {
+    match why.kind() {
+        IoErrorKind::WouldBlock | IoErrorKind::TimedOut => true,
+        _ => false,
+    }
+}
+
 fn start_threads(client: Arc<Mutex<Client>>, udp: &UdpSocket) -> Result<ThreadItems> {
     let (udp_close_sender, udp_close_reader) = mpsc::channel();
     let (ws_close_sender, ws_close_reader) = mpsc::channel();
@@ -582,7 +792,33 @@ fn start_threads(client: Arc<Mutex<Client>>, udp: &UdpSocket) -> Result<ThreadIt
     let ws_thread = ThreadBuilder::new()
         .name(format!("{} WS", thread_name))
         .spawn(move || loop {
-            while let Ok(Some(value)) = client.lock().recv_json() {
+            loop {
+                let value = match client.lock().recv_json() {
+                    Ok(Some(value)) => value,
+                    // A ping or pong was received.
+                    Ok(None) => break,
+                    Err(Error::Gateway(GatewayError::Closed(data))) => {
+                        let code = data.map(|data| data.status_code);
+
+                        if tx_clone.send(ReceiverStatus::Closed(code)).is_err() {
+                            return;
+                        }
+
+                        break;
+                    },
+                    // The payload was malformed, which was already logged.
+                    Err(Error::Json(_)) => break,
+                    // Nothing was received within the read timeout.
+                    Err(Error::WebSocket(WebSocketError::IoError(ref why))) if is_timeout(why) => break,
+                    Err(why) => {
+                        if tx_clone.send(ReceiverStatus::Failed(why)).is_err() {
+                            return;
+                        }
+
+                        break;
+                    },
+                };
+
                 let msg = match VoiceEvent::deserialize(value) {
                     Ok(msg) => msg,
                     Err(why) => {
@@ -612,3 +848,30 @@ fn start_threads(client: Arc<Mutex<Client>>, udp: &UdpSocket) -> Result<ThreadIt
         ws_thread,
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::strip_header_extension;
+
+    #[test]
+    fn payload_without_header_extension() {
+        let payload = [0xFC, 0xFF, 0xFE];
+
+        assert_eq!(strip_header_extension(&payload), Some(&payload[..]));
+    }
+
+    #[test]
+    fn strips_header_extension_by_length() {
+        // One word of extensions, including padding, followed by the Opus data.
+        let payload = [0xBE, 0xDE, 0x00, 0x01, 0x10, 0xAB, 0xCD, 0x00, 0xFC, 0xFF];
+
+        assert_eq!(strip_header_extension(&payload), Some(&[0xFC, 0xFF][..]));
+    }
+
+    #[test]
+    fn header_extension_exceeding_payload() {
+        let payload = [0xBE, 0xDE, 0x00, 0x02, 0x10, 0xAB, 0xCD, 0x00];
+
+        assert_eq!(strip_header_extension(&payload), None);
+    }
+}
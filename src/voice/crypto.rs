@@ -0,0 +1,275 @@
+use byteorder::{BigEndian, ByteOrder};
+use sodiumoxide::crypto::aead::xchacha20poly1305_ietf as aead;
+use sodiumoxide::crypto::secretbox::{self, Key, Nonce, MACBYTES, NONCEBYTES};
+use super::audio::HEADER_LEN;
+
+/// The encryption modes for voice packets supported by the library, from the
+/// most to the least preferred.
+const PREFERRED_MODES: [CryptoMode; 4] = [
+    CryptoMode::XChaCha20Poly1305RtpSize,
+    CryptoMode::Lite,
+    CryptoMode::Suffix,
+    CryptoMode::Normal,
+];
+
+/// The mode used to encrypt and decrypt voice packets, which is negotiated
+/// with the voice server after connecting.
+///
+/// All modes encrypt the payload of a packet, following its RTP header. The
+/// `xsalsa20_poly1305` modes only differ in how the nonce is built.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum CryptoMode {
+    /// The nonce is the RTP header of the packet.
+    Normal,
+    /// The nonce is a random 24-byte value, appended to the packet.
+    Suffix,
+    /// The nonce is an incrementing 4-byte value, appended to the packet.
+    Lite,
+    /// The packet is encrypted with AEAD XChaCha20-Poly1305, which also
+    /// authenticates the RTP header, including the CSRCs and the profile and
+    /// length of a header extension. The nonce is an incrementing 4-byte
+    /// value, appended to the packet.
+    XChaCha20Poly1305RtpSize,
+}
+
+impl CryptoMode {
+    /// Selects the most preferred of the modes offered by the voice server.
+    pub fn negotiate<T: AsRef<str>>(modes: &[T]) -> Option<Self> {
+        PREFERRED_MODES
+            .iter()
+            .cloned()
+            .find(|mode| modes.iter().any(|offered| offered.as_ref() == mode.name()))
+    }
+
+    /// The name of the mode, as sent to and received from the voice server.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            CryptoMode::Normal => "xsalsa20_poly1305",
+            CryptoMode::Suffix => "xsalsa20_poly1305_suffix",
+            CryptoMode::Lite => "xsalsa20_poly1305_lite",
+            CryptoMode::XChaCha20Poly1305RtpSize => "aead_xchacha20_poly1305_rtpsize",
+        }
+    }
+
+    /// The number of bytes the nonce takes up at the end of a packet.
+    pub fn nonce_len(&self) -> usize {
+        match *self {
+            CryptoMode::Normal => 0,
+            CryptoMode::Suffix => NONCEBYTES,
+            CryptoMode::Lite | CryptoMode::XChaCha20Poly1305RtpSize => 4,
+        }
+    }
+
+    /// The number of bytes a packet grows by when encrypting its payload.
+    pub fn overhead(&self) -> usize {
+        let tag_len = match *self {
+            CryptoMode::XChaCha20Poly1305RtpSize => aead::TAGBYTES,
+            _ => MACBYTES,
+        };
+
+        tag_len + self.nonce_len()
+    }
+
+    /// Decrypts the payload of a received packet.
+    ///
+    /// Returns `None` if the packet is too short or could not be decrypted.
+    pub fn decrypt(&self, packet: &[u8], key: &Key) -> Option<Vec<u8>> {
+        if packet.len() <= HEADER_LEN + self.nonce_len() {
+            return None;
+        }
+
+        let end = packet.len() - self.nonce_len();
+        let mut nonce = Nonce([0; NONCEBYTES]);
+
+        match *self {
+            CryptoMode::Normal => {
+                nonce.0[..HEADER_LEN].clone_from_slice(&packet[..HEADER_LEN]);
+            },
+            CryptoMode::Suffix | CryptoMode::Lite => {
+                nonce.0[..self.nonce_len()].clone_from_slice(&packet[end..]);
+            },
+            CryptoMode::XChaCha20Poly1305RtpSize => {
+                return decrypt_rtpsize(&packet[..end], &packet[end..], key);
+            },
+        }
+
+        secretbox::open(&packet[HEADER_LEN..end], &nonce, key).ok()
+    }
+
+    /// Encrypts the payload of `len` bytes following the RTP header of the
+    /// packet in place, appending the nonce if required by the mode.
+    ///
+    /// `lite_nonce` is the counter used as the nonce by the `Lite` and AEAD
+    /// modes, which is incremented for every packet.
+    ///
+    /// Returns the length of the packet to send.
+    pub fn encrypt(&self, packet: &mut [u8], len: usize, key: &Key, lite_nonce: &mut u32) -> usize {
+        let mut nonce = Nonce([0; NONCEBYTES]);
+
+        match *self {
+            CryptoMode::Normal => {
+                nonce.0[..HEADER_LEN].clone_from_slice(&packet[..HEADER_LEN]);
+            },
+            CryptoMode::Suffix => nonce = secretbox::gen_nonce(),
+            CryptoMode::Lite => {
+                BigEndian::write_u32(&mut nonce.0[..4], *lite_nonce);
+                *lite_nonce = lite_nonce.wrapping_add(1);
+            },
+            CryptoMode::XChaCha20Poly1305RtpSize => {
+                BigEndian::write_u32(&mut nonce.0[..4], *lite_nonce);
+                *lite_nonce = lite_nonce.wrapping_add(1);
+
+                let crypted = aead::seal(
+                    &packet[HEADER_LEN..HEADER_LEN + len],
+                    Some(&packet[..HEADER_LEN]),
+                    &aead::Nonce(nonce.0),
+                    &aead::Key(key.0),
+                );
+                let index = HEADER_LEN + crypted.len();
+                packet[HEADER_LEN..index].clone_from_slice(&crypted);
+                packet[index..index + 4].clone_from_slice(&nonce.0[..4]);
+
+                return index + 4;
+            },
+        }
+
+        let crypted = secretbox::seal(&packet[HEADER_LEN..HEADER_LEN + len], &nonce, key);
+        let index = HEADER_LEN + crypted.len();
+        packet[HEADER_LEN..index].clone_from_slice(&crypted);
+
+        let nonce_len = self.nonce_len();
+        packet[index..index + nonce_len].clone_from_slice(&nonce.0[..nonce_len]);
+
+        index + nonce_len
+    }
+}
+
+/// Decrypts the payload of a packet received in an AEAD mode, of which the
+/// 4-byte nonce was split off already.
+///
+/// The data of a header extension is encrypted along with the payload, and
+/// removed from the decrypted payload.
+fn decrypt_rtpsize(packet: &[u8], counter: &[u8], key: &Key) -> Option<Vec<u8>> {
+    let csrcs = (packet[0] & 0x0F) as usize;
+    let has_extension = packet[0] & 0x10 != 0;
+    let header_len = HEADER_LEN + csrcs * 4 + if has_extension { 4 } else { 0 };
+
+    if packet.len() < header_len {
+        return None;
+    }
+
+    let mut nonce = aead::Nonce([0; aead::NONCEBYTES]);
+    nonce.0[..4].clone_from_slice(counter);
+
+    let mut payload = aead::open(
+        &packet[header_len..],
+        Some(&packet[..header_len]),
+        &nonce,
+        &aead::Key(key.0),
+    ).ok()?;
+
+    if has_extension {
+        // The length is given in 32-bit words.
+        let len = BigEndian::read_u16(&packet[header_len - 2..header_len]) as usize * 4;
+
+        if payload.len() < len {
+            return None;
+        }
+
+        payload.drain(..len);
+    }
+
+    Some(payload)
+}
+
+#[cfg(test)]
+mod test {
+    use byteorder::{BigEndian, ByteOrder};
+    use sodiumoxide::crypto::aead::xchacha20poly1305_ietf as aead;
+    use sodiumoxide::crypto::secretbox::Key;
+    use super::super::audio::HEADER_LEN;
+    use super::CryptoMode;
+
+    const KEY: Key = Key([7; 32]);
+
+    fn packet(payload: &[u8]) -> [u8; 512] {
+        let mut packet = [0; 512];
+        packet[..HEADER_LEN].clone_from_slice(&[0x80, 0x78, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3]);
+        packet[HEADER_LEN..HEADER_LEN + payload.len()].clone_from_slice(payload);
+
+        packet
+    }
+
+    #[test]
+    fn negotiate_prefers_aead() {
+        let offered = [
+            "xsalsa20_poly1305",
+            "aead_xchacha20_poly1305_rtpsize",
+            "xsalsa20_poly1305_lite",
+        ];
+
+        assert_eq!(CryptoMode::negotiate(&offered), Some(CryptoMode::XChaCha20Poly1305RtpSize));
+        assert_eq!(CryptoMode::negotiate(&offered[..1]), Some(CryptoMode::Normal));
+        assert_eq!(CryptoMode::negotiate(&["aead_aes256_gcm"]), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let modes = [
+            CryptoMode::Normal,
+            CryptoMode::Suffix,
+            CryptoMode::Lite,
+            CryptoMode::XChaCha20Poly1305RtpSize,
+        ];
+
+        for mode in &modes {
+            let mut packet = packet(b"opus");
+            let mut nonce = 5;
+
+            let len = mode.encrypt(&mut packet, 4, &KEY, &mut nonce);
+            assert_eq!(len, HEADER_LEN + 4 + mode.overhead(), "{:?}", mode);
+
+            let decrypted = mode.decrypt(&packet[..len], &KEY);
+            assert_eq!(decrypted, Some(b"opus".to_vec()), "{:?}", mode);
+
+            if mode.nonce_len() == 4 {
+                assert_eq!(nonce, 6);
+            }
+        }
+    }
+
+    #[test]
+    fn aead_authenticates_header() {
+        let mode = CryptoMode::XChaCha20Poly1305RtpSize;
+        let mut packet = packet(b"opus");
+
+        let len = mode.encrypt(&mut packet, 4, &KEY, &mut 0);
+        packet[3] ^= 1;
+
+        assert_eq!(mode.decrypt(&packet[..len], &KEY), None);
+    }
+
+    #[test]
+    fn aead_strips_header_extension() {
+        // A header extension of one word, of which only the profile and
+        // length are unencrypted.
+        let mut packet = vec![0x90, 0x78, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0xBE, 0xDE, 0, 1];
+        let mut nonce = aead::Nonce([0; aead::NONCEBYTES]);
+        BigEndian::write_u32(&mut nonce.0[..4], 9);
+
+        let crypted = aead::seal(b"\x10\x01\0\0opus", Some(&packet), &nonce, &aead::Key(KEY.0));
+        packet.extend_from_slice(&crypted);
+        packet.extend_from_slice(&nonce.0[..4]);
+
+        let decrypted = CryptoMode::XChaCha20Poly1305RtpSize.decrypt(&packet, &KEY);
+        assert_eq!(decrypted, Some(b"opus".to_vec()));
+    }
+
+    #[test]
+    fn decrypt_short_packets() {
+        for mode in &[CryptoMode::Lite, CryptoMode::XChaCha20Poly1305RtpSize] {
+            assert_eq!(mode.decrypt(&[0x80; HEADER_LEN + 4], &KEY), None);
+            assert_eq!(mode.decrypt(&[0x8F; HEADER_LEN + 8], &KEY), None);
+        }
+    }
+}
@@ -6,27 +6,41 @@ use std::{
 
 /// An error returned from the voice module.
 // Errors which are not visible to the end user are hidden.
+#[allow(deprecated)]
 #[derive(Debug)]
 pub enum VoiceError {
+    /// The voice server closed the connection, with the close code if one
+    /// was given.
+    Closed(Option<u16>),
     /// An indicator that an endpoint URL was invalid.
     EndpointUrl,
     #[doc(hidden)] ExpectedHandshake,
     #[doc(hidden)] FindingByte,
+    /// An Opus frame passed through from an audio source does not fit into a
+    /// voice packet, along with its length.
+    FrameTooLong(usize),
+    #[doc(hidden)] HeartbeatTimeout,
     #[doc(hidden)] HostnameResolve,
     #[doc(hidden)] KeyGen,
     /// An error occurred while checking if a path is stereo.
     Streams,
+    /// The voice server did not echo a UDP keepalive in time.
+    UdpTimeout,
     #[doc(hidden)] VoiceModeInvalid,
     #[doc(hidden)] VoiceModeUnavailable,
-    /// An error occurred while running `youtube-dl`.
+    /// `youtube-dl` exited without downloading anything.
+    ///
+    /// Its output is given, where `stderr` contains the reason.
     YouTubeDLRun(Output),
     /// An error occurred while processing the JSON output from `youtube-dl`.
     ///
     /// The JSON output is given.
+    #[deprecated(since = "0.5.9", note = "The JSON output of youtube-dl is no longer used")]
     YouTubeDLProcessing(Value),
     /// The `url` field of the `youtube-dl` JSON output was not present.
     ///
     /// The JSON output is given.
+    #[deprecated(since = "0.5.9", note = "The JSON output of youtube-dl is no longer used")]
     YouTubeDLUrl(Value),
 }
 
@@ -14,7 +14,17 @@ use std::sync::{
     Arc
 };
 use super::connection_info::ConnectionInfo;
-use super::{Audio, AudioReceiver, AudioSource, Status as VoiceStatus, threading, LockedAudio};
+use super::{
+    Audio,
+    AudioReceiver,
+    AudioSource,
+    ConnectionEvent,
+    LockedAudio,
+    Status as VoiceStatus,
+    threading,
+    TrackMetadata,
+    TrackQueue
+};
 
 /// The handler is responsible for "handling" a single voice connection, acting
 /// as a clean API above the inner connection.
@@ -73,6 +83,10 @@ pub struct Handler {
     ///
     /// [`mute`]: #method.mute
     pub self_mute: bool,
+    /// The queue of tracks played through [`enqueue`].
+    ///
+    /// [`enqueue`]: #method.enqueue
+    queue: TrackQueue,
     /// The internal sender to the voice connection monitor thread.
     sender: MpscSender<VoiceStatus>,
     /// The session Id of the current voice connection, if any.
@@ -245,6 +259,16 @@ impl Handler {
         }
     }
 
+    /// Registers a function to call whenever the state of the connection
+    /// changed, e.g. when a lost connection was re-established.
+    ///
+    /// **Note**: The function is called on the voice connection's thread, so
+    /// it should not block.
+    pub fn on_connection_event<F>(&mut self, f: F)
+        where F: Fn(&ConnectionEvent) + Send + 'static {
+        self.send(VoiceStatus::AddConnectionHook(Box::new(f)));
+    }
+
     /// Plays audio from a source.
     ///
     /// This can be a source created via [`voice::ffmpeg`] or [`voice::ytdl`].
@@ -277,6 +301,23 @@ impl Handler {
         player
     }
 
+    /// Adds a track to the end of the handler's [`queue`], starting playback
+    /// of the queue if it is not playing.
+    ///
+    /// [`queue`]: #method.queue
+    pub fn enqueue(&mut self, source: Box<AudioSource>, metadata: TrackMetadata) {
+        let queue = self.queue.clone();
+
+        queue.enqueue_with(source, metadata, |source| self.play_returning(source));
+    }
+
+    /// Retrieves the queue of tracks played through [`enqueue`], used to skip,
+    /// pause, and inspect its tracks.
+    ///
+    /// [`enqueue`]: #method.enqueue
+    #[inline]
+    pub fn queue(&self) -> &TrackQueue { &self.queue }
+
     /// Stops playing audio from a source, if one is set.
     pub fn stop(&mut self) { self.send(VoiceStatus::SetSender(None)) }
 
@@ -337,7 +378,11 @@ impl Handler {
                 self.connect();
             }
         } else {
-            self.leave();
+            // The voice server is being reallocated, e.g. after a region
+            // change, and a new endpoint follows once it is available.
+            self.endpoint = None;
+
+            self.send(VoiceStatus::Disconnect);
         }
     }
 
@@ -384,6 +429,7 @@ impl Handler {
             guild_id,
             self_deaf: false,
             self_mute: false,
+            queue: TrackQueue::new(),
             sender: tx,
             session_id: None,
             token: None,
@@ -3,17 +3,20 @@
 mod audio;
 mod connection;
 mod connection_info;
+mod crypto;
 mod dca;
 mod error;
 mod manager;
 mod handler;
 mod payload;
+mod queue;
 mod streamer;
 mod threading;
 
 pub use self::{
     audio::{
         Audio,
+        AudioHandle,
         AudioReceiver,
         AudioSource,
         AudioType,
@@ -23,6 +26,7 @@ pub use self::{
     error::{DcaError, VoiceError},
     handler::Handler,
     manager::Manager,
+    queue::{TrackMetadata, TrackQueue},
     streamer::{
         dca,
         ffmpeg,
@@ -35,11 +39,33 @@ pub use self::{
 
 use self::connection_info::ConnectionInfo;
 
-const CRYPTO_MODE: &'static str = "xsalsa20_poly1305";
+/// A change in the state of a voice connection, as given to the functions
+/// registered through [`Handler::on_connection_event`].
+///
+/// Lost connections are re-established automatically. As a new connection
+/// starts off silent, this can be used to restore playback.
+///
+/// [`Handler::on_connection_event`]: struct.Handler.html#method.on_connection_event
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConnectionEvent {
+    /// A connection to a voice server was established after joining a
+    /// channel.
+    Connected,
+    /// The connection was lost, and could not be re-established.
+    Disconnected,
+    /// The connection was lost or the voice server of the guild changed, and
+    /// it was re-established with a new session.
+    Reconnected,
+    /// The connection was lost, and the session was resumed.
+    Resumed,
+}
+
+pub(crate) type ConnectionHook = Box<Fn(&ConnectionEvent) + Send>;
 
 pub(crate) enum Status {
+    AddConnectionHook(ConnectionHook),
     Connect(ConnectionInfo),
-    #[allow(dead_code)] Disconnect,
+    Disconnect,
     SetReceiver(Option<Box<AudioReceiver>>),
     SetSender(Option<LockedAudio>),
     AddSender(LockedAudio),
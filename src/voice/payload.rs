@@ -1,6 +1,7 @@
 use constants::VoiceOpCode;
 use serde_json::Value;
 use super::connection_info::ConnectionInfo;
+use super::crypto::CryptoMode;
 
 #[inline]
 pub fn build_identify(info: &ConnectionInfo) -> Value {
@@ -16,22 +17,34 @@ pub fn build_identify(info: &ConnectionInfo) -> Value {
 }
 
 #[inline]
-pub fn build_keepalive() -> Value {
+pub fn build_keepalive(nonce: u64) -> Value {
     json!({
         "op": VoiceOpCode::KeepAlive.num(),
-        "d": Value::Null,
+        "d": nonce,
     })
 }
 
 #[inline]
-pub fn build_select_protocol(address: ::std::borrow::Cow<str>, port: u16) -> Value {
+pub fn build_resume(info: &ConnectionInfo) -> Value {
+    json!({
+        "op": VoiceOpCode::Resume.num(),
+        "d": {
+            "server_id": info.guild_id.0,
+            "session_id": &info.session_id,
+            "token": &info.token,
+        }
+    })
+}
+
+#[inline]
+pub fn build_select_protocol(address: ::std::borrow::Cow<str>, port: u16, mode: CryptoMode) -> Value {
     json!({
         "op": VoiceOpCode::SelectProtocol.num(),
         "d": {
             "protocol": "udp",
             "data": {
                 "address": address,
-                "mode": super::CRYPTO_MODE,
+                "mode": mode.name(),
                 "port": port,
             }
         }
@@ -39,12 +52,13 @@ pub fn build_select_protocol(address: ::std::borrow::Cow<str>, port: u16) -> Val
 }
 
 #[inline]
-pub fn build_speaking(speaking: bool) -> Value {
+pub fn build_speaking(speaking: bool, ssrc: u32) -> Value {
     json!({
         "op": VoiceOpCode::Speaking.num(),
         "d": {
             "delay": 0,
-            "speaking": speaking,
+            "speaking": if speaking { 1 } else { 0 },
+            "ssrc": ssrc,
         }
     })
 }
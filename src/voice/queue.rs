@@ -0,0 +1,519 @@
+use parking_lot::Mutex;
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::{Arc, Weak},
+    time::Duration
+};
+use super::{Audio, AudioSource, AudioType, LockedAudio};
+
+/// Opus frame of silence, used while switching between tracks of different
+/// audio types.
+const SILENCE_FRAME: [u8; 3] = [0xf8, 0xff, 0xfe];
+
+/// Information about a track in a [`TrackQueue`], as given when enqueueing it.
+///
+/// [`TrackQueue`]: struct.TrackQueue.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TrackMetadata {
+    /// The duration of the track, if known.
+    pub duration: Option<Duration>,
+    /// The title of the track.
+    pub title: Option<String>,
+    /// The URL the track is played from.
+    pub url: Option<String>,
+}
+
+struct QueuedTrack {
+    /// The number of 20ms frames that were played.
+    frames: u32,
+    metadata: TrackMetadata,
+    source: Box<AudioSource>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    /// The audio playing the queue, if it is attached to a connection.
+    audio: Option<Weak<Mutex<Audio>>>,
+    current: Option<QueuedTrack>,
+    upcoming: VecDeque<QueuedTrack>,
+}
+
+type TrackEndHook = Box<Fn(&TrackMetadata) + Send>;
+
+/// A queue of tracks played one after another by a voice [`Handler`].
+///
+/// Each handler has its own queue, which is retrieved through
+/// [`Handler::queue`]. Tracks are added through [`Handler::enqueue`]. The
+/// whole queue is played as a single audio source, so starting other audio
+/// through [`Handler::play_only`] or [`Handler::stop`] detaches it until the
+/// next track is enqueued.
+///
+/// # Examples
+///
+/// Queueing a song and announcing when it ended:
+///
+/// ```rust,no_run
+/// # use serenity::model::id::{GuildId, UserId};
+/// # use serenity::voice::Handler;
+/// #
+/// # fn try_main() -> Result<(), Box<std::error::Error>> {
+/// #     let mut handler = Handler::standalone(GuildId(0), UserId(0));
+/// #
+/// use serenity::voice::{self, TrackMetadata};
+///
+/// let url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+///
+/// handler.queue().on_track_end(|track| {
+///     println!("Finished playing {:?}", track.title);
+/// });
+///
+/// handler.enqueue(voice::ytdl(url)?, TrackMetadata {
+///     title: Some("Never Gonna Give You Up".to_string()),
+///     url: Some(url.to_string()),
+///     ..Default::default()
+/// });
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     try_main().unwrap();
+/// # }
+/// ```
+///
+/// [`Handler`]: struct.Handler.html
+/// [`Handler::enqueue`]: struct.Handler.html#method.enqueue
+/// [`Handler::play_only`]: struct.Handler.html#method.play_only
+/// [`Handler::queue`]: struct.Handler.html#method.queue
+/// [`Handler::stop`]: struct.Handler.html#method.stop
+#[derive(Clone, Default)]
+pub struct TrackQueue {
+    hooks: Arc<Mutex<Vec<TrackEndHook>>>,
+    state: Arc<Mutex<QueueState>>,
+}
+
+impl TrackQueue {
+    /// Creates a new, empty queue.
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds a track to the end of the queue, attaching the queue through
+    /// `play` if it is not playing.
+    pub(crate) fn enqueue_with<F>(&self, source: Box<AudioSource>, metadata: TrackMetadata, play: F)
+        where F: FnOnce(Box<AudioSource>) -> LockedAudio {
+        let mut state = self.state.lock();
+
+        state.upcoming.push_back(QueuedTrack {
+            frames: 0,
+            metadata,
+            source,
+        });
+
+        let attached = state.audio
+            .as_ref()
+            .and_then(Weak::upgrade)
+            .is_some();
+
+        if !attached {
+            let audio = play(Box::new(QueueSource {
+                hooks: Arc::clone(&self.hooks),
+                state: Arc::clone(&self.state),
+            }));
+
+            state.audio = Some(Arc::downgrade(&audio));
+        }
+    }
+
+    /// Whether no track is playing or upcoming.
+    pub fn is_empty(&self) -> bool {
+        let state = self.state.lock();
+
+        state.current.is_none() && state.upcoming.is_empty()
+    }
+
+    /// The number of tracks in the queue, including the one playing.
+    pub fn len(&self) -> usize {
+        let state = self.state.lock();
+
+        state.upcoming.len() + state.current.as_ref().map_or(0, |_| 1)
+    }
+
+    /// Retrieves the metadata of the track that is playing.
+    pub fn now_playing(&self) -> Option<TrackMetadata> {
+        self.state.lock().current.as_ref().map(|track| track.metadata.clone())
+    }
+
+    /// Registers a function to call whenever a track ended or was skipped.
+    ///
+    /// **Note**: When a track ended, the function is called on the voice
+    /// connection's thread, so it should not block. It must not [`pause`] or
+    /// [`resume`] the queue, as the playing audio is locked while it runs.
+    ///
+    /// [`pause`]: #method.pause
+    /// [`resume`]: #method.resume
+    pub fn on_track_end<F: Fn(&TrackMetadata) + Send + 'static>(&self, f: F) {
+        self.hooks.lock().push(Box::new(f));
+    }
+
+    /// Pauses playback of the queue.
+    pub fn pause(&self) {
+        if let Some(audio) = self.audio() {
+            audio.lock().pause();
+        }
+    }
+
+    /// Retrieves how far the playing track has been played.
+    pub fn position(&self) -> Option<Duration> {
+        self.state
+            .lock()
+            .current
+            .as_ref()
+            .map(|track| Duration::from_millis(20) * track.frames)
+    }
+
+    /// Resumes playback of the queue after it was [`pause`]d.
+    ///
+    /// [`pause`]: #method.pause
+    pub fn resume(&self) {
+        if let Some(audio) = self.audio() {
+            audio.lock().play();
+        }
+    }
+
+    /// Skips the track that is playing, returning its metadata.
+    ///
+    /// The next track is played from the following frame on.
+    pub fn skip(&self) -> Option<TrackMetadata> {
+        let skipped = self.state.lock().current.take().map(|track| track.metadata);
+
+        if let Some(ref metadata) = skipped {
+            run_hooks(&self.hooks, metadata);
+        }
+
+        skipped
+    }
+
+    /// Stops playback, removing all tracks from the queue.
+    pub fn stop(&self) {
+        let mut state = self.state.lock();
+
+        state.current = None;
+        state.upcoming.clear();
+    }
+
+    /// Retrieves the metadata of the tracks that are played after the current
+    /// one, in order.
+    pub fn upcoming(&self) -> Vec<TrackMetadata> {
+        self.state
+            .lock()
+            .upcoming
+            .iter()
+            .map(|track| track.metadata.clone())
+            .collect()
+    }
+
+    // The state must not be locked while locking the audio, as the voice
+    // thread locks them in the opposite order.
+    fn audio(&self) -> Option<LockedAudio> {
+        self.state.lock().audio.as_ref().and_then(Weak::upgrade)
+    }
+}
+
+impl Debug for TrackQueue {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let state = self.state.lock();
+
+        f.debug_struct("TrackQueue")
+            .field("current", &state.current.as_ref().map(|track| &track.metadata))
+            .field("upcoming", &state.upcoming.len())
+            .finish()
+    }
+}
+
+fn run_hooks(hooks: &Mutex<Vec<TrackEndHook>>, metadata: &TrackMetadata) {
+    for hook in hooks.lock().iter() {
+        hook(metadata);
+    }
+}
+
+/// The audio source playing the tracks of a queue.
+struct QueueSource {
+    hooks: Arc<Mutex<Vec<TrackEndHook>>>,
+    state: Arc<Mutex<QueueState>>,
+}
+
+/// What reading a frame from the current track resulted in.
+enum Frame<T> {
+    /// The track ended, with the given metadata.
+    Ended(TrackMetadata),
+    /// The queue ran out of tracks.
+    Empty,
+    /// A frame was read.
+    Read(T),
+    /// The current track is of another audio type.
+    Mismatch,
+}
+
+impl QueueSource {
+    fn read<T, F>(&mut self, kind: AudioType, mut read: F) -> Frame<T>
+        where F: FnMut(&mut AudioSource) -> Option<T> {
+        let mut state = self.state.lock();
+
+        if state.current.is_none() {
+            state.current = state.upcoming.pop_front();
+        }
+
+        let read = match state.current {
+            Some(ref mut track) => {
+                if !same_type(track.source.get_type(), kind) {
+                    return Frame::Mismatch;
+                }
+
+                let frame = read(&mut *track.source);
+
+                if frame.is_some() {
+                    track.frames += 1;
+                }
+
+                frame
+            },
+            None => {
+                // The audio is removed once the source returns nothing.
+                state.audio = None;
+
+                return Frame::Empty;
+            },
+        };
+
+        match read {
+            Some(frame) => Frame::Read(frame),
+            None => match state.current.take() {
+                Some(track) => Frame::Ended(track.metadata),
+                None => Frame::Empty,
+            },
+        }
+    }
+}
+
+impl AudioSource for QueueSource {
+    fn is_stereo(&mut self) -> bool {
+        match self.state.lock().current {
+            Some(ref mut track) => track.source.is_stereo(),
+            None => true,
+        }
+    }
+
+    fn get_type(&self) -> AudioType {
+        match self.state.lock().current {
+            Some(ref track) => track.source.get_type(),
+            None => AudioType::Pcm,
+        }
+    }
+
+    fn read_pcm_frame(&mut self, buffer: &mut [i16]) -> Option<usize> {
+        loop {
+            let frame = self.read(AudioType::Pcm, |source| match source.read_pcm_frame(buffer) {
+                Some(0) | None => None,
+                Some(len) => Some(len),
+            });
+
+            match frame {
+                Frame::Read(len) => return Some(len),
+                Frame::Ended(metadata) => run_hooks(&self.hooks, &metadata),
+                Frame::Empty => return None,
+                Frame::Mismatch => {
+                    for sample in buffer.iter_mut() {
+                        *sample = 0;
+                    }
+
+                    return Some(buffer.len());
+                },
+            }
+        }
+    }
+
+    fn read_opus_frame(&mut self) -> Option<Vec<u8>> {
+        loop {
+            let frame = self.read(AudioType::Opus, |source| match source.read_opus_frame() {
+                Some(ref frame) if frame.is_empty() => None,
+                other => other,
+            });
+
+            match frame {
+                Frame::Read(frame) => return Some(frame),
+                Frame::Ended(metadata) => run_hooks(&self.hooks, &metadata),
+                Frame::Empty => return None,
+                Frame::Mismatch => return Some(SILENCE_FRAME.to_vec()),
+            }
+        }
+    }
+}
+
+fn same_type(a: AudioType, b: AudioType) -> bool {
+    match (a, b) {
+        (AudioType::Opus, AudioType::Opus) | (AudioType::Pcm, AudioType::Pcm) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use parking_lot::Mutex;
+    use std::sync::Arc;
+    use super::super::{Audio, AudioSource, AudioType, LockedAudio};
+    use super::{TrackMetadata, TrackQueue, SILENCE_FRAME};
+
+    /// A source of the given number of Opus frames, each holding its tag.
+    struct Frames {
+        kind: AudioType,
+        remaining: u8,
+        tag: u8,
+    }
+
+    impl AudioSource for Frames {
+        fn is_stereo(&mut self) -> bool { true }
+
+        fn get_type(&self) -> AudioType { self.kind }
+
+        fn read_pcm_frame(&mut self, buffer: &mut [i16]) -> Option<usize> {
+            if self.remaining == 0 {
+                return None;
+            }
+
+            self.remaining -= 1;
+
+            for sample in buffer.iter_mut() {
+                *sample = i16::from(self.tag);
+            }
+
+            Some(buffer.len())
+        }
+
+        fn read_opus_frame(&mut self) -> Option<Vec<u8>> {
+            if self.remaining == 0 {
+                return None;
+            }
+
+            self.remaining -= 1;
+
+            Some(vec![self.tag])
+        }
+    }
+
+    fn track(tag: u8, frames: u8) -> Box<AudioSource> {
+        Box::new(Frames {
+            kind: AudioType::Opus,
+            remaining: frames,
+            tag,
+        })
+    }
+
+    fn metadata(title: &str) -> TrackMetadata {
+        TrackMetadata {
+            title: Some(title.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Enqueues the track, returning the audio if the queue was attached.
+    fn enqueue(queue: &TrackQueue, source: Box<AudioSource>, title: &str) -> Option<LockedAudio> {
+        let mut attached = None;
+
+        queue.enqueue_with(source, metadata(title), |source| {
+            let audio = Arc::new(Mutex::new(Audio::new(source)));
+            attached = Some(Arc::clone(&audio));
+
+            audio
+        });
+
+        attached
+    }
+
+    fn read(audio: &LockedAudio) -> Option<Vec<u8>> {
+        audio.lock().source.read_opus_frame()
+    }
+
+    #[test]
+    fn plays_in_order() {
+        let queue = TrackQueue::new();
+        let ended = Arc::new(Mutex::new(Vec::new()));
+
+        {
+            let ended = Arc::clone(&ended);
+            queue.on_track_end(move |track| ended.lock().push(track.title.clone().unwrap()));
+        }
+
+        let audio = enqueue(&queue, track(1, 2), "first").unwrap();
+        assert!(enqueue(&queue, track(2, 1), "second").is_none());
+
+        assert_eq!(queue.len(), 2);
+        assert_eq!(read(&audio), Some(vec![1]));
+        assert_eq!(queue.now_playing(), Some(metadata("first")));
+        assert_eq!(queue.upcoming(), vec![metadata("second")]);
+        assert_eq!(read(&audio), Some(vec![1]));
+        assert_eq!(read(&audio), Some(vec![2]));
+        assert_eq!(read(&audio), None);
+
+        assert_eq!(*ended.lock(), vec!["first".to_string(), "second".to_string()]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn plays_one_track_at_a_time() {
+        let queue = TrackQueue::new();
+
+        let audio = enqueue(&queue, track(1, 1), "first").unwrap();
+        // While attached, tracks only queue up behind the playing one.
+        assert!(enqueue(&queue, track(2, 1), "second").is_none());
+        assert!(enqueue(&queue, track(3, 1), "third").is_none());
+
+        assert_eq!(read(&audio), Some(vec![1]));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.position(), Some(::std::time::Duration::from_millis(20)));
+    }
+
+    #[test]
+    fn skip() {
+        let queue = TrackQueue::new();
+
+        let audio = enqueue(&queue, track(1, 5), "first").unwrap();
+        enqueue(&queue, track(2, 1), "second");
+
+        assert_eq!(read(&audio), Some(vec![1]));
+        assert_eq!(queue.skip(), Some(metadata("first")));
+        assert_eq!(read(&audio), Some(vec![2]));
+    }
+
+    #[test]
+    fn stop_drains_and_detaches() {
+        let queue = TrackQueue::new();
+
+        let audio = enqueue(&queue, track(1, 5), "first").unwrap();
+        enqueue(&queue, track(2, 5), "second");
+
+        assert_eq!(read(&audio), Some(vec![1]));
+        queue.stop();
+
+        assert!(queue.is_empty());
+        assert_eq!(read(&audio), None);
+
+        // The ended audio is removed from the connection, so the next track
+        // is played through a new one.
+        drop(audio);
+        assert!(enqueue(&queue, track(3, 1), "third").is_some());
+    }
+
+    #[test]
+    fn silence_while_switching_audio_types() {
+        let queue = TrackQueue::new();
+
+        let audio = enqueue(&queue, track(1, 1), "first").unwrap();
+        enqueue(&queue, Box::new(Frames {
+            kind: AudioType::Pcm,
+            remaining: 1,
+            tag: 2,
+        }), "second");
+
+        assert_eq!(read(&audio), Some(vec![1]));
+        assert_eq!(read(&audio), Some(SILENCE_FRAME.to_vec()));
+    }
+}
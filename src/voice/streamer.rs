@@ -5,17 +5,20 @@ use std::{
     ffi::OsStr,
     fs::File,
     io::{
+        self,
         BufReader,
         ErrorKind as IoErrorKind,
         Read,
-        Result as IoResult
+        Result as IoResult,
+        Write
     },
     process::{
         Child,
         Command,
         Stdio
     },
-    result::Result as StdResult
+    result::Result as StdResult,
+    thread
 };
 use super::{
     AudioSource,
@@ -25,18 +28,26 @@ use super::{
     VoiceError
 };
 
-struct ChildContainer(Child);
+/// A pipeline of child processes, reading from the output of the last one.
+///
+/// All processes are killed once the container is dropped.
+struct ChildContainer(Vec<Child>);
 
 impl Read for ChildContainer {
     fn read(&mut self, buffer: &mut [u8]) -> IoResult<usize> {
-        self.0.stdout.as_mut().unwrap().read(buffer)
+        match self.0.last_mut().and_then(|child| child.stdout.as_mut()) {
+            Some(stdout) => stdout.read(buffer),
+            None => Ok(0),
+        }
     }
 }
 
 impl Drop for ChildContainer {
     fn drop (&mut self) {
-        if let Err(e) = self.0.kill() {
-            debug!("[Voice] Error awaiting child process: {:?}", e);
+        for child in &mut self.0 {
+            if let Err(e) = child.kill() {
+                debug!("[Voice] Error awaiting child process: {:?}", e);
+            }
         }
     }
 }
@@ -166,7 +177,7 @@ fn _ffmpeg_optioned(path: &OsStr, args: &[&str]) -> Result<Box<AudioSource>> {
         .stdout(Stdio::piped())
         .spawn()?;
 
-    Ok(pcm(is_stereo, ChildContainer(command)))
+    Ok(pcm(is_stereo, BufReader::new(ChildContainer(vec![command]))))
 }
 
 /// Creates a streamed audio source from a DCA file.
@@ -217,6 +228,9 @@ fn _dca(path: &OsStr) -> StdResult<Box<AudioSource>, DcaError> {
 /// Creates an Opus audio source. This makes certain assumptions: namely, that the input stream
 /// is composed ONLY of opus frames of the variety that Discord expects.
 ///
+/// Each frame is expected to be prefixed by its length as a little-endian
+/// `i16`, as in DCA files.
+///
 /// If you want to decode a `.opus` file, use [`ffmpeg`]
 ///
 /// [`ffmpeg`]: fn.ffmpeg.html
@@ -229,6 +243,15 @@ pub fn opus<R: Read + Send + 'static>(is_stereo: bool, reader: R) -> Box<AudioSo
 }
 
 /// Creates a PCM audio source.
+///
+/// The input stream must consist of signed 16-bit little-endian samples at
+/// 48kHz, interleaved if `is_stereo` is `true`. Reading happens on the audio
+/// thread, so slow readers should be wrapped in a `BufReader`.
+///
+/// For sources that are neither PCM nor Opus streams, implement
+/// [`AudioSource`] directly.
+///
+/// [`AudioSource`]: trait.AudioSource.html
 pub fn pcm<R: Read + Send + 'static>(is_stereo: bool, reader: R) -> Box<AudioSource> {
     Box::new(InputSource {
         stereo: is_stereo,
@@ -238,40 +261,102 @@ pub fn pcm<R: Read + Send + 'static>(is_stereo: bool, reader: R) -> Box<AudioSou
 }
 
 /// Creates a streamed audio source with `youtube-dl` and `ffmpeg`.
+///
+/// The output of `youtube-dl` is piped into `ffmpeg` as it is downloaded, so
+/// playback starts without waiting for the whole download. The audio is
+/// always streamed as stereo.
+///
+/// # Errors
+///
+/// Returns a [`VoiceError::YouTubeDLRun`] with the output of `youtube-dl` if
+/// it exits without downloading anything, such as for an unsupported `uri`.
+///
+/// [`VoiceError::YouTubeDLRun`]: enum.VoiceError.html#variant.YouTubeDLRun
 pub fn ytdl(uri: &str) -> Result<Box<AudioSource>> {
-    let args = [
+    let ytdl_args = [
         "-f",
         "webm[abr>0]/bestaudio/best",
         "--no-playlist",
-        "--print-json",
-        "--skip-download",
+        "--ignore-config",
+        "--quiet",
+        "--no-warnings",
+        "-o",
+        "-",
         uri,
     ];
 
-    let out = Command::new("youtube-dl")
-        .args(&args)
+    let mut youtube_dl = Command::new("youtube-dl")
+        .args(&ytdl_args)
+        .stderr(Stdio::piped())
         .stdin(Stdio::null())
-        .output()?;
+        .stdout(Stdio::piped())
+        .spawn()?;
 
-    if !out.status.success() {
-        return Err(Error::Voice(VoiceError::YouTubeDLRun(out)));
-    }
+    // The output is always available, as it is piped.
+    let mut downloaded = youtube_dl.stdout.take().unwrap();
+
+    // Wait for the download to start, as `youtube-dl` exits without any
+    // output if it fails.
+    let mut start = vec![0; 8192];
+    let read = match downloaded.read(&mut start) {
+        Ok(read) => read,
+        Err(why) => {
+            let _ = youtube_dl.kill();
 
-    let value = serde_json::from_reader(&out.stdout[..])?;
-    let mut obj = match value {
-        Value::Object(obj) => obj,
-        other => return Err(Error::Voice(VoiceError::YouTubeDLProcessing(other))),
+            return Err(Error::Io(why));
+        },
     };
 
-    let uri = match obj.remove("url") {
-        Some(v) => match v {
-            Value::String(uri) => uri,
-            other => return Err(Error::Voice(VoiceError::YouTubeDLUrl(other))),
+    if read == 0 {
+        let output = youtube_dl.wait_with_output()?;
+
+        return Err(Error::Voice(VoiceError::YouTubeDLRun(output)));
+    }
+
+    start.truncate(read);
+
+    let ffmpeg = Command::new("ffmpeg")
+        .args(&["-i", "-"])
+        .args(&[
+            "-f",
+            "s16le",
+            "-ac",
+            "2",
+            "-ar",
+            "48000",
+            "-acodec",
+            "pcm_s16le",
+            "-",
+        ])
+        .stderr(Stdio::null())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn();
+
+    let mut ffmpeg = match ffmpeg {
+        Ok(ffmpeg) => ffmpeg,
+        Err(why) => {
+            let _ = youtube_dl.kill();
+
+            return Err(Error::Io(why));
         },
-        None => return Err(Error::Voice(VoiceError::YouTubeDLUrl(Value::Object(obj)))),
     };
 
-    ffmpeg(&uri)
+    // The input is always available, as it is piped.
+    let mut input = ffmpeg.stdin.take().unwrap();
+
+    // Copying stops once either process is killed.
+    thread::spawn(move || {
+        let copied = input
+            .write_all(&start)
+            .and_then(|_| io::copy(&mut downloaded, &mut input));
+
+        if let Err(why) = copied {
+            debug!("[Voice] Error piping youtube-dl into ffmpeg: {:?}", why);
+        }
+    });
+
+    Ok(pcm(true, BufReader::new(ChildContainer(vec![youtube_dl, ffmpeg]))))
 }
 
 fn is_stereo(path: &OsStr) -> Result<bool> {
@@ -301,3 +386,70 @@ fn is_stereo(path: &OsStr) -> Result<bool> {
 
     Ok(check)
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::{Cursor, Read};
+    use super::super::AudioType;
+    use super::{opus, pcm};
+
+    #[test]
+    fn pcm_frames() {
+        let samples = vec![1, 0, 2, 0, 3, 0, 0xFF, 0xFF, 5, 0];
+        let mut source = pcm(true, Cursor::new(samples));
+        let mut buffer = [0; 4];
+
+        assert!(source.is_stereo());
+        assert!(match source.get_type() { AudioType::Pcm => true, _ => false });
+        assert_eq!(source.read_pcm_frame(&mut buffer), Some(4));
+        assert_eq!(buffer, [1, 2, 3, -1]);
+
+        // The last frame is only partially filled.
+        assert_eq!(source.read_pcm_frame(&mut buffer), Some(1));
+        assert_eq!(buffer[0], 5);
+    }
+
+    #[test]
+    fn opus_frames() {
+        let frames = vec![2, 0, 0xFC, 0xFF, 1, 0, 0xF8];
+        let mut source = opus(false, Cursor::new(frames));
+
+        assert!(match source.get_type() { AudioType::Opus => true, _ => false });
+        assert_eq!(source.read_opus_frame(), Some(vec![0xFC, 0xFF]));
+        assert_eq!(source.read_opus_frame(), Some(vec![0xF8]));
+        assert_eq!(source.read_opus_frame(), Some(Vec::new()));
+    }
+
+    #[test]
+    fn opus_frame_with_invalid_size() {
+        let mut source = opus(false, Cursor::new(vec![0xFF, 0xFF]));
+
+        assert_eq!(source.read_opus_frame(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn child_pipeline_reads_last_output() {
+        use std::process::{Command, Stdio};
+        use super::ChildContainer;
+
+        let mut printf = Command::new("printf")
+            .arg("abc")
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let output = printf.stdout.take().unwrap();
+
+        let cat = Command::new("cat")
+            .stdin(Stdio::from(output))
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut read = String::new();
+        ChildContainer(vec![printf, cat]).read_to_string(&mut read).unwrap();
+
+        assert_eq!(read, "abc");
+        assert_eq!(ChildContainer(Vec::new()).read(&mut [0; 4]).unwrap(), 0);
+    }
+}
@@ -1,4 +1,5 @@
 use internal::Timer;
+use internal::prelude::*;
 use model::id::GuildId;
 use std::{
     sync::mpsc::{Receiver as MpscReceiver, TryRecvError},
@@ -6,9 +7,54 @@ use std::{
 };
 use super::{
     connection::Connection,
-    Status
+    connection_info::ConnectionInfo,
+    ConnectionEvent,
+    ConnectionHook,
+    Status,
+    VoiceError
 };
 
+/// The close code sent when the session is no longer valid.
+const SESSION_INVALID: u16 = 4006;
+/// The close code sent when the session timed out.
+const SESSION_TIMEOUT: u16 = 4009;
+/// The close code sent when the token was not accepted.
+const AUTHENTICATION_FAILED: u16 = 4004;
+/// The close code sent when the voice server of the guild was not found.
+const SERVER_NOT_FOUND: u16 = 4011;
+/// The close code sent when the current user was disconnected from the voice
+/// channel, after which the connection must not be re-established.
+const DISCONNECTED: u16 = 4014;
+/// The close code sent when the selected encryption mode is not supported.
+const UNKNOWN_ENCRYPTION_MODE: u16 = 4016;
+
+/// How the runner recovers from an error while updating a connection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Recovery {
+    /// Resume the session over a new websocket, keeping the UDP socket.
+    Resume,
+    /// Establish an entirely new connection.
+    Reconnect,
+    /// Give up on the connection, as a new one would fail the same way.
+    Fatal,
+}
+
+fn recovery(why: &Error) -> Recovery {
+    match *why {
+        Error::Voice(VoiceError::Closed(Some(code))) => match code {
+            AUTHENTICATION_FAILED
+            | SERVER_NOT_FOUND
+            | DISCONNECTED
+            | UNKNOWN_ENCRYPTION_MODE => Recovery::Fatal,
+            SESSION_INVALID | SESSION_TIMEOUT => Recovery::Reconnect,
+            _ => Recovery::Resume,
+        },
+        // Only the UDP socket is used directly, which a resume would keep.
+        Error::Io(_) | Error::Voice(VoiceError::UdpTimeout) => Recovery::Reconnect,
+        _ => Recovery::Resume,
+    }
+}
+
 pub(crate) fn start(guild_id: GuildId, rx: MpscReceiver<Status>) {
     let name = format!("Serenity Voice (G{})", guild_id);
 
@@ -22,23 +68,43 @@ fn runner(rx: &MpscReceiver<Status>) {
     let mut senders = Vec::new();
     let mut receiver = None;
     let mut connection = None;
+    let mut info = None;
+    // Whether the connection was dropped as the voice server is moving.
+    let mut moving = false;
+    let mut hooks = Vec::new();
     let mut timer = Timer::new(20);
 
     'runner: loop {
         loop {
             match rx.try_recv() {
-                Ok(Status::Connect(info)) => {
-                    connection = match Connection::new(info) {
-                        Ok(connection) => Some(connection),
-                        Err(why) => {
-                            warn!("[Voice] Error connecting: {:?}", why);
-
-                            None
-                        },
-                    };
+                Ok(Status::Connect(new_info)) => {
+                    let replacing = connection.is_some() || moving;
+                    moving = false;
+
+                    connection = connect(new_info.clone());
+
+                    if connection.is_some() {
+                        let event = if replacing {
+                            ConnectionEvent::Reconnected
+                        } else {
+                            ConnectionEvent::Connected
+                        };
+
+                        run_hooks(&hooks, &event);
+                    } else if replacing {
+                        run_hooks(&hooks, &ConnectionEvent::Disconnected);
+                    }
+
+                    info = Some(new_info);
                 },
                 Ok(Status::Disconnect) => {
+                    moving |= connection.is_some();
+
                     connection = None;
+                    info = None;
+                },
+                Ok(Status::AddConnectionHook(hook)) => {
+                    hooks.push(hook);
                 },
                 Ok(Status::SetReceiver(r)) => {
                     receiver = r;
@@ -66,8 +132,8 @@ fn runner(rx: &MpscReceiver<Status>) {
         // Overall here, check if there's an error.
         //
         // If there is a connection, try to send an update. This should not
-        // error. If there is though for some spurious reason, then set `error`
-        // to `true`.
+        // error. If there is though for some spurious reason, then keep the
+        // error to recover from it.
         //
         // Otherwise, wait out the timer and do _not_ error and wait to receive
         // another event.
@@ -76,28 +142,122 @@ fn runner(rx: &MpscReceiver<Status>) {
                 let cycle = connection.cycle(&mut senders, &mut receiver, &mut timer);
 
                 match cycle {
-                    Ok(()) => false,
+                    Ok(()) => None,
                     Err(why) => {
                         error!(
                             "(╯°□°）╯︵ ┻━┻ Error updating connection: {:?}",
                             why
                         );
 
-                        true
+                        Some(why)
                     },
                 }
             },
             None => {
                 timer.await();
 
-                false
+                None
             },
         };
 
-        // If there was an error, then just reset the connection and try to get
-        // another.
-        if error {
-            connection = None;
+        // If there was an error, then try to resume the connection where
+        // possible, and otherwise to establish a new one.
+        if let Some(why) = error {
+            let recovery = recovery(&why);
+
+            if recovery == Recovery::Fatal {
+                connection = None;
+                info = None;
+
+                run_hooks(&hooks, &ConnectionEvent::Disconnected);
+
+                continue;
+            }
+
+            if recovery == Recovery::Resume {
+                let resumed = match connection.as_mut().map(Connection::resume) {
+                    Some(Ok(())) => true,
+                    Some(Err(why)) => {
+                        warn!("[Voice] Error resuming: {:?}", why);
+
+                        false
+                    },
+                    None => false,
+                };
+
+                if resumed {
+                    run_hooks(&hooks, &ConnectionEvent::Resumed);
+
+                    continue;
+                }
+            }
+
+            // Drop the old connection first, closing its threads and socket.
+            drop(connection.take());
+
+            connection = info.clone().and_then(connect);
+
+            let event = if connection.is_some() {
+                ConnectionEvent::Reconnected
+            } else {
+                ConnectionEvent::Disconnected
+            };
+
+            run_hooks(&hooks, &event);
         }
     }
 }
+
+fn connect(info: ConnectionInfo) -> Option<Connection> {
+    match Connection::new(info) {
+        Ok(connection) => Some(connection),
+        Err(why) => {
+            warn!("[Voice] Error connecting: {:?}", why);
+
+            None
+        },
+    }
+}
+
+fn run_hooks(hooks: &[ConnectionHook], event: &ConnectionEvent) {
+    for hook in hooks {
+        hook(event);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use internal::prelude::*;
+    use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+    use super::super::VoiceError;
+    use super::{recovery, Recovery};
+
+    fn closed(code: u16) -> Error {
+        Error::Voice(VoiceError::Closed(Some(code)))
+    }
+
+    #[test]
+    fn fatal() {
+        assert_eq!(recovery(&closed(4004)), Recovery::Fatal);
+        assert_eq!(recovery(&closed(4011)), Recovery::Fatal);
+        assert_eq!(recovery(&closed(4014)), Recovery::Fatal);
+        assert_eq!(recovery(&closed(4016)), Recovery::Fatal);
+    }
+
+    #[test]
+    fn reconnect() {
+        assert_eq!(recovery(&closed(4006)), Recovery::Reconnect);
+        assert_eq!(recovery(&closed(4009)), Recovery::Reconnect);
+        assert_eq!(recovery(&Error::Voice(VoiceError::UdpTimeout)), Recovery::Reconnect);
+
+        let io = Error::Io(IoError::new(IoErrorKind::ConnectionRefused, "refused"));
+        assert_eq!(recovery(&io), Recovery::Reconnect);
+    }
+
+    #[test]
+    fn resume() {
+        assert_eq!(recovery(&closed(4015)), Recovery::Resume);
+        assert_eq!(recovery(&Error::Voice(VoiceError::Closed(None))), Recovery::Resume);
+        assert_eq!(recovery(&Error::Voice(VoiceError::HeartbeatTimeout)), Recovery::Resume);
+    }
+}
@@ -16,6 +16,45 @@ macro_rules! p {
     }};
 }
 
+#[test]
+fn auto_moderation_action_execution() {
+    let event = p!(AutoModActionExecutionEvent, "auto_moderation_action_execution_1");
+    assert_eq!(event.rule_id, AutoModRuleId(1016464486519554098));
+    assert_eq!(event.rule_trigger_type, AutoModTriggerType::Keyword);
+    assert_eq!(event.action.kind, AutoModActionType::BlockMessage);
+    assert_eq!(event.action.metadata.custom_message.as_ref().map(String::as_str), Some("Please do not spoil the ending."));
+    assert!(event.message_id.is_none());
+    assert_eq!(event.matched_keyword.as_ref().map(String::as_str), Some("*spoiler*"));
+}
+
+#[test]
+fn auto_moderation_rule_create() {
+    let event = p!(AutoModRuleCreateEvent, "auto_moderation_rule_create_1");
+    assert_eq!(event.rule.id, AutoModRuleId(1016464486519554098));
+    assert_eq!(event.rule.event_type, AutoModEventType::MessageSend);
+    assert_eq!(event.rule.trigger_metadata.keyword_filter, vec!["*spoiler*".to_string()]);
+    assert_eq!(event.rule.actions.len(), 2);
+    assert_eq!(event.rule.exempt_roles, vec![RoleId(244567637332328451)]);
+}
+
+#[test]
+fn current_application_info_team() {
+    let info = p!(CurrentApplicationInfo, "current_application_info_team_1");
+    let team = info.team.as_ref().unwrap();
+    assert_eq!(team.owner_user_id, UserId(114941315417899012));
+    assert_eq!(team.members[2].membership_state, MembershipState::Invited);
+
+    let owners = info.owner_ids(false);
+    assert_eq!(owners.len(), 1);
+    assert!(owners.contains(&UserId(114941315417899012)));
+
+    // Invited members that did not accept yet are no owners.
+    let owners = info.owner_ids(true);
+    assert_eq!(owners.len(), 2);
+    assert!(owners.contains(&UserId(249608457672458240)));
+    assert!(!owners.contains(&UserId(81384788765712384)));
+}
+
 #[test]
 fn channel_create() {
     p!(ChannelCreateEvent, "channel_create_1");
@@ -29,6 +68,10 @@ fn channel_delete() {
 #[test]
 fn channel_pins_update() {
     p!(ChannelPinsUpdateEvent, "channel_pins_update_1");
+
+    let event = p!(ChannelPinsUpdateEvent, "channel_pins_update_2");
+    assert_eq!(event.guild_id, Some(GuildId(244567637332328448)));
+    assert!(event.last_pin_timestamp.is_none());
 }
 
 #[test]
@@ -47,6 +90,18 @@ fn game() {
     p!(Game, "game_1");
 }
 
+// A game with assets, party, secrets, and timestamps.
+#[test]
+fn game_full() {
+    let game: Game = p!(Game, "game_2");
+
+    assert_eq!(game.kind, GameType::Listening);
+    assert_eq!(game.sync_id.as_ref().map(|s| &s[..]), Some("4uLU6hMCjMI75M1A2tKUQC"));
+    assert_eq!(game.party.and_then(|party| party.size), Some([1, 4]));
+    assert_eq!(game.timestamps.and_then(|timestamps| timestamps.start), Some(1577836800000));
+    assert_eq!(game.buttons, vec!["Listen along".to_owned()]);
+}
+
 #[test]
 fn guild_ban_add() {
     p!(GuildBanAddEvent, "guild_ban_add_1");
@@ -63,6 +118,16 @@ fn guild_channel_1_rest() {
     p!(GuildChannel, "guild_channel_rest_1");
 }
 
+#[test]
+fn guild_channel_rtc_region() {
+    let channel = p!(GuildChannel, "guild_channel_voice_1");
+    assert_eq!(channel.kind, ChannelType::Voice);
+    assert_eq!(channel.rtc_region.as_ref().map(String::as_str), Some("rotterdam"));
+
+    let channel = p!(GuildChannel, "guild_channel_rest_1");
+    assert!(channel.rtc_region.is_none());
+}
+
 // A guild that has some application ID.
 #[test]
 fn guild_some_application_id() {
@@ -80,6 +145,15 @@ fn guild_delete() {
     p!(GuildDeleteEvent, "guild_delete_1");
 }
 
+#[test]
+fn guild_discovery_metadata() {
+    let metadata = p!(DiscoveryMetadata, "guild_discovery_metadata_1");
+    assert_eq!(metadata.guild_id, GuildId(244567637332328448));
+    assert_eq!(metadata.category_ids, vec![14, 49]);
+    assert!(metadata.partner_actioned_timestamp.is_none());
+    assert!(metadata.partner_application_timestamp.is_some());
+}
+
 #[test]
 fn guild_emojis_update() {
     p!(GuildEmojisUpdateEvent, "guild_emojis_update_1");
@@ -100,6 +174,28 @@ fn guild_member_update() {
     p!(GuildMemberUpdateEvent, "guild_member_update_1");
 }
 
+#[test]
+fn guild_onboarding() {
+    let onboarding = p!(Onboarding, "guild_onboarding_1");
+    assert_eq!(onboarding.default_channel_ids.len(), 2);
+    assert_eq!(onboarding.mode, OnboardingMode::Default);
+
+    let prompt = &onboarding.prompts[0];
+    assert_eq!(prompt.id, 1067461047608422473);
+    assert_eq!(prompt.kind, OnboardingPromptType::MultipleChoice);
+
+    let options = &prompt.options;
+    match options[0].emoji {
+        Some(ReactionType::Custom { id, .. }) => assert_eq!(id, EmojiId(1070002302032826408)),
+        ref other => panic!("unexpected emoji: {:?}", other),
+    }
+    match options[1].emoji {
+        Some(ReactionType::Unicode(ref name)) => assert_eq!(name, "😀"),
+        ref other => panic!("unexpected emoji: {:?}", other),
+    }
+    assert!(options[2].emoji.is_none());
+}
+
 #[test]
 fn guild_role_create() {
     p!(GuildRoleCreateEvent, "guild_role_create_1");
@@ -120,6 +216,148 @@ fn guild_update() {
     p!(GuildUpdateEvent, "guild_update_1");
 }
 
+#[test]
+fn interaction_create() {
+    let event = p!(InteractionCreateEvent, "interaction_create_1");
+    let interaction = event.interaction;
+    assert_eq!(interaction.kind, InteractionType::ApplicationCommand);
+    assert_eq!(interaction.member.as_ref().map(|m| m.guild_id), Some(GuildId(244567637332328449)));
+    assert_eq!(interaction.invoker().map(|u| u.id), Some(UserId(114941315417899012)));
+
+    match interaction.data {
+        Some(InteractionData::ApplicationCommand(ref data)) => {
+            assert_eq!(data.name, "greet");
+            assert_eq!(data.options[0].value, Some(Value::String("114941315417899012".to_string())));
+        },
+        _ => panic!("expected application command data"),
+    }
+
+    let command = p!(ApplicationCommand, "application_command_1");
+    assert_eq!(command.id, CommandId(771825006014889984));
+    assert_eq!(command.version, 771825006014889985);
+    assert_eq!(command.options[0].kind, ApplicationCommandOptionType::User);
+    assert!(command.options[0].required);
+    assert_eq!(command.options[1].choices.len(), 2);
+    assert!(!command.options[1].required);
+
+    // use of a select menu in a direct message
+    let event = p!(InteractionCreateEvent, "interaction_create_2");
+    let interaction = event.interaction;
+    assert_eq!(interaction.kind, InteractionType::MessageComponent);
+    assert_eq!(interaction.invoker().map(|u| u.id), Some(UserId(114941315417899012)));
+
+    match interaction.data {
+        Some(InteractionData::MessageComponent(ref data)) => {
+            assert_eq!(data.component_type, ComponentType::SelectMenu);
+            assert_eq!(data.custom_id, "colour");
+            assert_eq!(data.values, vec!["red".to_string(), "blue".to_string()]);
+        },
+        _ => panic!("expected message component data"),
+    }
+
+    let message = interaction.message.unwrap();
+    assert_eq!(message.components.len(), 2);
+
+    match message.components[0].components[0] {
+        Component::SelectMenu(ref menu) => {
+            assert_eq!(menu.options.len(), 2);
+            assert!(menu.options[0].default);
+            assert!(!menu.options[1].default);
+        },
+        _ => panic!("expected a select menu"),
+    }
+
+    match message.components[1].components[1] {
+        Component::Button(ref button) => {
+            assert_eq!(button.style, ButtonStyle::Link);
+            assert!(button.custom_id.is_none());
+        },
+        _ => panic!("expected a button"),
+    }
+
+    // use of a component of a type the library does not know
+    let event = p!(InteractionCreateEvent, "interaction_create_4");
+    let interaction = event.interaction;
+
+    match interaction.data {
+        Some(InteractionData::MessageComponent(ref data)) => {
+            assert_eq!(data.component_type, ComponentType::Unknown(99));
+            assert_eq!(data.custom_id, "future");
+        },
+        _ => panic!("expected message component data"),
+    }
+
+    let message = interaction.message.unwrap();
+
+    match message.components[0].components[0] {
+        Component::Unknown(ref component) => {
+            assert_eq!(component["type"], 99);
+            assert_eq!(component["unheard_of"], true);
+        },
+        _ => panic!("expected an unknown component"),
+    }
+
+    match message.components[1].components[0] {
+        Component::Button(ref button) => assert_eq!(button.style, ButtonStyle::Danger),
+        _ => panic!("expected a button"),
+    }
+
+    // use of select menus filled in by Discord
+    let event = p!(InteractionCreateEvent, "interaction_create_5");
+    let interaction = event.interaction;
+
+    match interaction.data {
+        Some(InteractionData::MessageComponent(ref data)) => {
+            assert_eq!(data.component_type, ComponentType::MentionableSelect);
+            assert_eq!(data.values.len(), 2);
+
+            let resolved = data.resolved.as_ref().unwrap();
+            let user_id = UserId(114941315417899012);
+            assert_eq!(resolved.users[&user_id].name, "zeyla");
+            assert_eq!(resolved.members[&user_id].nick, Some("zey".to_string()));
+            assert_eq!(resolved.roles[&RoleId(302917455983771649)].name, "helpers");
+            assert!(resolved.channels.is_empty());
+        },
+        _ => panic!("expected message component data"),
+    }
+
+    let message = interaction.message.unwrap();
+
+    match message.components[0].components[0] {
+        Component::SelectMenu(ref menu) => {
+            assert_eq!(menu.kind, ComponentType::MentionableSelect);
+            assert!(menu.options.is_empty());
+            assert_eq!(menu.default_values, vec![SelectMenuDefaultValue::User(UserId(249608457672458240))]);
+        },
+        _ => panic!("expected a select menu"),
+    }
+
+    match message.components[1].components[0] {
+        Component::SelectMenu(ref menu) => {
+            assert_eq!(menu.kind, ComponentType::ChannelSelect);
+            assert_eq!(menu.channel_types, vec![ChannelType::Text, ChannelType::News]);
+            assert_eq!(menu.default_values, vec![SelectMenuDefaultValue::Channel(ChannelId(302917639565475841))]);
+        },
+        _ => panic!("expected a select menu"),
+    }
+
+    // submission of a modal
+    let event = p!(InteractionCreateEvent, "interaction_create_3");
+    let interaction = event.interaction;
+    assert_eq!(interaction.kind, InteractionType::ModalSubmit);
+
+    match interaction.data {
+        Some(InteractionData::ModalSubmit(ref data)) => {
+            assert_eq!(data.custom_id, "feedback");
+            assert_eq!(data.value("title"), Some("Great bot"));
+            assert_eq!(data.value("body"), Some("It answers quickly."));
+            assert_eq!(data.value("rating"), None);
+            assert_eq!(data.values().count(), 2);
+        },
+        _ => panic!("expected modal submit data"),
+    }
+}
+
 #[test]
 fn message_create() {
     // standard
@@ -130,6 +368,46 @@ fn message_create() {
 
     // message from guild with partial member data
     p!(MessageCreateEvent, "message_create_3");
+
+    // response to an application command
+    let event = p!(MessageCreateEvent, "message_create_4");
+    let interaction = event.message.interaction.unwrap();
+    assert_eq!(interaction.kind, InteractionType::ApplicationCommand);
+    assert_eq!(interaction.name, "ping");
+    assert_eq!(interaction.user.id, UserId(400000000000000000));
+
+    // attachments with and without a description
+    let event = p!(MessageCreateEvent, "message_create_6");
+    let attachments = event.message.attachments;
+    assert_eq!(attachments[0].description.as_ref().map(String::as_str), Some("A crab waving its claws"));
+    assert!(attachments[1].description.is_none());
+
+    // stickers
+    let event = p!(MessageCreateEvent, "message_create_7");
+    let stickers = event.message.sticker_items;
+    assert_eq!(stickers[0].id, StickerId(749054660769218631));
+    assert_eq!(stickers[0].format_type, StickerFormatType::Png);
+    assert_eq!(stickers[1].format_type, StickerFormatType::Lottie);
+}
+
+#[test]
+fn message_poll() {
+    let event = p!(MessageCreateEvent, "message_create_5");
+    let poll = event.message.poll.unwrap();
+    assert_eq!(poll.question.text.as_ref().map(String::as_str), Some("Which language should we use?"));
+    assert_eq!(poll.layout_type, PollLayoutType::Default);
+    assert_eq!(poll.answer_count(3), Some(1));
+
+    let winners = poll
+        .winning_answers()
+        .iter()
+        .map(|answer| answer.answer_id)
+        .collect::<Vec<_>>();
+    assert_eq!(winners, vec![1, 2]);
+
+    let event = p!(PollVoteAddEvent, "message_poll_vote_add_1");
+    assert_eq!(event.guild_id, Some(GuildId(244567637332328448)));
+    assert_eq!(event.answer_id, 2);
 }
 
 #[test]
@@ -154,16 +432,81 @@ fn message_reaction_remove_all() {
     p!(ReactionRemoveAllEvent, "message_reaction_remove_all_1");
 }
 
+#[test]
+fn presence_update() {
+    // partial user
+    let event = p!(PresenceUpdateEvent, "presence_update_1");
+    assert_eq!(event.guild_id, Some(GuildId(200000000000000000)));
+    assert_eq!(event.roles, Some(vec![RoleId(381891844067557378)]));
+    assert_eq!(event.presence.user_id, UserId(300000000000000000));
+    assert!(event.presence.user.is_none());
+
+    // full user
+    let event = p!(PresenceUpdateEvent, "presence_update_2");
+    assert_eq!(event.presence.status, OnlineStatus::Idle);
+    assert_eq!(event.presence.user.unwrap().read().discriminator, 1234);
+}
+
 #[test]
 fn ready() {
     p!(ReadyEvent, "ready_1");
 }
 
+#[test]
+fn stage_instance_create() {
+    let event = p!(StageInstanceCreateEvent, "stage_instance_create_1");
+    let stage = event.stage_instance;
+    assert_eq!(stage.id, StageInstanceId(840647391636226060));
+    assert_eq!(stage.channel_id, ChannelId(302918912255983618));
+    assert_eq!(stage.topic, "Weekly town hall");
+    assert_eq!(stage.privacy_level, StagePrivacyLevel::GuildOnly);
+}
+
+#[test]
+fn thread_create() {
+    let event = p!(ThreadCreateEvent, "thread_create_1");
+    let thread = event.thread;
+    assert_eq!(thread.kind, ChannelType::PublicThread);
+    assert!(thread.kind.is_thread());
+    assert_eq!(thread.category_id, Some(ChannelId(302918912255983616)));
+    assert_eq!(thread.owner_id, Some(UserId(114941315417899012)));
+
+    let metadata = thread.thread_metadata.unwrap();
+    assert!(!metadata.archived);
+    assert_eq!(metadata.auto_archive_duration, 1440);
+    assert_eq!(metadata.invitable, None);
+}
+
 #[test]
 fn typing_start() {
     p!(TypingStartEvent, "typing_start_1");
 }
 
+#[test]
+fn voice_region() {
+    let region = p!(VoiceRegion, "voice_region_1");
+    assert_eq!(region.id, "rotterdam");
+    assert!(region.optimal);
+    assert!(!region.vip);
+    assert_eq!(region.sample_hostname, "");
+    assert_eq!(region.sample_port, 0);
+}
+
+#[test]
+fn voice_speaking() {
+    let speaking = p!(VoiceSpeaking, "voice_speaking_1");
+    assert!(speaking.speaking);
+    assert_eq!(speaking.user_id, UserId(114941315417899012));
+}
+
+#[test]
+fn voice_client_disconnect() {
+    match p!(VoiceEvent, "voice_client_disconnect_1") {
+        VoiceEvent::ClientDisconnect(ev) => assert_eq!(ev.user_id, UserId(114941315417899012)),
+        other => panic!("expected a client disconnect, found {:?}", other),
+    }
+}
+
 #[test]
 fn voice_state_update() {
     p!(VoiceStateUpdateEvent, "voice_state_update_1");
@@ -185,7 +528,10 @@ fn message_type_7() {
 // Change made due to a new feature being added and not being in the enum.
 #[test]
 fn guild_features_deser() {
-    p!(GuildCreateEvent, "guild_create_features");
+    let event: GuildCreateEvent = p!(GuildCreateEvent, "guild_create_features");
+
+    assert!(event.guild.features.contains(&GuildFeature::VanityUrl));
+    assert!(event.guild.features.contains(&GuildFeature::Verified));
 }
 
 // Ensure that `Guild`s still deserialize despite the `system_channel_id` key